@@ -0,0 +1,196 @@
+// ---------------- [ File: workspacer-add-new-crate-to-workspace/src/add_new_crate_from_template.rs ]
+crate::ix!();
+
+/// How `ws add crate` builds the new crate.
+#[derive(Getters,Builder,Debug,Clone)]
+#[getset(get="pub")]
+pub struct NewCrateOptions {
+    /// A template name resolved through `registry`
+    #[builder(default = "DEFAULT_CRATE_TEMPLATE.to_string()")]
+    template:     String,
+
+    /// Join this prefix group whatever the crate is called; otherwise the group is detected
+    /// from the crate name as in [`AddNewCrateToWorkspace`]
+    #[builder(default)]
+    prefix_group: Option<String>,
+
+    #[builder(default = "CrateTemplateRegistryBuilder::default().build().unwrap()")]
+    registry:     CrateTemplateRegistry,
+}
+
+/// Adds one or more crates rendered from a named [`CrateTemplate`], then, in the same step,
+/// registers the primary crate (the one named `new_crate_name`) in its prefix group: the
+/// facade re-exports it if it has a `src/lib.rs`, and it depends on the group's `-3p` crate
+/// if it has a `src/imports.rs`.
+#[async_trait]
+pub trait AddNewCrateFromTemplate<P,H>
+where
+    for<'async_trait> P: Debug + Clone + From<PathBuf> + AsRef<Path> + Send + Sync + 'async_trait,
+    H: CrateHandleInterface<P> + Debug + Send + Sync,
+{
+    type Error;
+
+    async fn add_new_crate_from_template(
+        &mut self,
+        new_crate_name: &str,
+        options:        &NewCrateOptions,
+    ) -> Result<H, Self::Error>;
+}
+
+#[async_trait]
+impl<P,H,T> AddNewCrateFromTemplate<P,H> for T
+where
+    for<'async_trait> P: Debug + Clone + From<PathBuf> + AsRef<Path> + Send + Sync + 'async_trait,
+    H: CrateHandleInterface<P> + Debug + Send + Sync + Clone,
+    T: ScanPrefixGroups<P,H, Error = WorkspaceError>
+        + RegisterInPrefixGroup<P,H,Error = WorkspaceError>
+        + AddInternalDependency<P,H,Error = WorkspaceError>
+        + AddToWorkspaceMembers<P>
+        + WorkspaceInterface<P,H>
+        + AsRef<Path>
+        + Sync
+        + Send,
+{
+    type Error = WorkspaceError;
+
+    async fn add_new_crate_from_template(
+        &mut self,
+        new_crate_name: &str,
+        options:        &NewCrateOptions,
+    ) -> Result<H, Self::Error> {
+        info!("add_new_crate_from_template('{}', template='{}') - start", new_crate_name, options.template());
+
+        let template = options.registry().find(options.template()).await?;
+
+        // 1) Settle the prefix group before rendering: templates may use `{{prefix}}`
+        let groups = self.scan().await?;
+        let prefix = match options.prefix_group() {
+            Some(prefix) => {
+                if !groups.iter().any(|g| g.prefix() == prefix) {
+                    return Err(CrateTemplateError::PrefixGroupNotFound { prefix: prefix.clone() }.into());
+                }
+                Some(prefix.clone())
+            }
+            None => find_single_prefix_match(new_crate_name, &groups),
+        };
+        if *template.requires_prefix_group() && prefix.is_none() {
+            return Err(CrateTemplateError::PrefixGroupRequired { template: template.name().clone() }.into());
+        }
+
+        // 2) Render and write every file, then make each rendered crate a member
+        let rendered = template.render(&TemplatePlaceholders::for_crate(new_crate_name, prefix.as_deref()))?;
+        let primary_dir = PathBuf::from(new_crate_name);
+        if !rendered.crate_dirs().contains(&primary_dir) {
+            return Err(CrateTemplateError::TemplateHasNoCrate { template: template.name().clone() }.into());
+        }
+        rendered.write_to(self.as_ref()).await?;
+
+        let mut primary = None;
+        for dir in rendered.crate_dirs() {
+            let crate_path = P::from(self.as_ref().join(&dir));
+            self.add_to_workspace_members(&crate_path).await?;
+
+            let handle = H::new(&crate_path).await?;
+            self.crates_mut().push(Arc::new(AsyncMutex::new(handle.clone())));
+            debug!("added crate '{}' from template '{}'", handle.name(), template.name());
+
+            if dir == primary_dir {
+                primary = Some(handle);
+            }
+        }
+        let primary = primary.expect("the primary crate directory was checked above");
+
+        // 3) Join the prefix group
+        if let Some(grp) = prefix.as_ref().and_then(|p| groups.iter().find(|g| g.prefix() == p)) {
+            if rendered.has_file(primary_dir.join("src").join("lib.rs")) {
+                if let Some(facade_cr) = grp.prefix_crate() {
+                    self.register_in_prefix_crate(facade_cr, &primary).await?;
+                }
+            }
+            if rendered.has_file(primary_dir.join("src").join("imports.rs")) {
+                match grp.three_p_crate() {
+                    Some(three_p) => self.add_internal_dependency(&primary, three_p).await?,
+                    None          => warn!("Group '{}' has no 3p crate => '{}' gets no 3p dependency", grp.prefix(), new_crate_name),
+                }
+            }
+        }
+
+        info!("add_new_crate_from_template('{}') - done.", new_crate_name);
+        Ok(primary)
+    }
+}
+
+#[cfg(test)]
+mod test_add_new_crate_from_template {
+    use super::*;
+
+    type MyWorkspace = Workspace<PathBuf, CrateHandle>;
+
+    fn builtins_only() -> NewCrateOptionsBuilder {
+        let mut builder = NewCrateOptionsBuilder::default();
+        builder.registry(CrateTemplateRegistryBuilder::default().search_dirs(vec![]).user_dir(None).build().unwrap());
+        builder
+    }
+
+    #[traced_test]
+    async fn explicit_prefix_group_registers_in_facade_and_3p() {
+        let workspace_path: PathBuf = create_mock_workspace(vec![
+            CrateConfig::new("batch-mode").with_src_files(),
+            CrateConfig::new("batch-mode-3p").with_src_files(),
+        ]).await.expect("mock workspace");
+        let mut ws = MyWorkspace::new(&workspace_path).await.expect("workspace");
+
+        let options = builtins_only()
+            .template("prefix-group-member".to_string())
+            .prefix_group(Some("batch-mode".to_string()))
+            .build()
+            .unwrap();
+        let handle = ws.add_new_crate_from_template("json-codec", &options).await.expect("created from template");
+
+        let lib_rs = fs::read_to_string(handle.as_ref().join("src/lib.rs")).await.unwrap();
+        assert!(lib_rs.contains("x!{json_codec}"));
+
+        let facade_cargo = fs::read_to_string(workspace_path.join("batch-mode/Cargo.toml")).await.unwrap();
+        assert!(facade_cargo.contains("json-codec"), "facade depends on the new crate");
+
+        let new_cargo = fs::read_to_string(handle.as_ref().join("Cargo.toml")).await.unwrap();
+        assert!(new_cargo.contains("batch-mode-3p"), "new crate depends on the group's 3p crate");
+
+        let members = fs::read_to_string(workspace_path.join("Cargo.toml")).await.unwrap();
+        assert!(members.contains("json-codec"));
+    }
+
+    #[traced_test]
+    async fn proc_macro_template_adds_both_crates_as_members() {
+        let workspace_path: PathBuf = create_mock_workspace(vec![
+            CrateConfig::new("unrelated").with_src_files(),
+        ]).await.expect("mock workspace");
+        let mut ws = MyWorkspace::new(&workspace_path).await.expect("workspace");
+        let crates_before = ws.n_crates();
+
+        let options = builtins_only().template("proc-macro".to_string()).build().unwrap();
+        let handle = ws.add_new_crate_from_template("describe", &options).await.expect("created from template");
+        assert_eq!(handle.name(), "describe");
+        assert_eq!(ws.n_crates(), crates_before + 2);
+
+        let members = fs::read_to_string(workspace_path.join("Cargo.toml")).await.unwrap();
+        assert!(members.contains("describe-derive"));
+    }
+
+    #[traced_test]
+    async fn group_templates_refuse_to_run_without_a_group() {
+        let workspace_path: PathBuf = create_mock_workspace(vec![
+            CrateConfig::new("unrelated").with_src_files(),
+        ]).await.expect("mock workspace");
+        let mut ws = MyWorkspace::new(&workspace_path).await.expect("workspace");
+
+        let options = builtins_only().template("prefix-group-member".to_string()).build().unwrap();
+        let result = ws.add_new_crate_from_template("loner", &options).await;
+        assert!(matches!(result, Err(WorkspaceError::CrateTemplateError(CrateTemplateError::PrefixGroupRequired { .. }))));
+        assert!(!workspace_path.join("loner").exists(), "nothing is written before the checks pass");
+
+        let options = builtins_only().prefix_group(Some("missing".to_string())).build().unwrap();
+        let result = ws.add_new_crate_from_template("loner", &options).await;
+        assert!(matches!(result, Err(WorkspaceError::CrateTemplateError(CrateTemplateError::PrefixGroupNotFound { .. }))));
+    }
+}
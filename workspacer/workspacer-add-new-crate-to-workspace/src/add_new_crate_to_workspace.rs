@@ -366,10 +366,11 @@ mod test_add_new_crate_to_workspace_with_real_data {
         #[cfg(target_os = "linux")]
         {
             // We'll try to create a workspace at "/dev/null"
-            let ws = MyWorkspace {
-                path: PathBuf::from("/dev/null").into(),
-                crates: vec![],
-            };
+            let mut ws: MyWorkspace = WorkspaceBuilder::default()
+                .path(PathBuf::from("/dev/null"))
+                .crates(vec![])
+                .build()
+                .unwrap();
 
             // The code likely fails to create a directory "/dev/null/new_crate"
             let result = ws.add_new_crate_to_workspace("any_name").await;
@@ -412,7 +413,7 @@ mod test_add_new_crate_to_workspace_with_real_data {
             fs::set_permissions(&cargo_toml, perms).await.unwrap();
 
             // Now we have a valid workspace but can't update the top-level cargo
-            let ws = MyWorkspace::new(&workspace_path).await
+            let mut ws = MyWorkspace::new(&workspace_path).await
                 .expect("Should parse the workspace initially");
 
             // Attempt to add new crate
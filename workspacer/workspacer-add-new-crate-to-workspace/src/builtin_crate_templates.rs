@@ -0,0 +1,236 @@
+// ---------------- [ File: workspacer-add-new-crate-to-workspace/src/builtin_crate_templates.rs ]
+crate::ix!();
+
+/// The template `ws add crate` uses when none is named.
+pub const DEFAULT_CRATE_TEMPLATE: &str = "library";
+
+fn template_files(files: &[(&str, &str)]) -> BTreeMap<PathBuf, String> {
+    files.iter().map(|(path, text)| (PathBuf::from(path), text.to_string())).collect()
+}
+
+const LIBRARY_CARGO_TOML: &str = indoc! {r#"
+    [package]
+    name = "{{crate_name}}"
+    version = "0.1.0"
+    authors = ["YourName <you@example.com>"]
+    license = "MIT"
+    edition = "2024"
+    description = "todo: write a description here"
+
+    # keywords = []
+    # categories = []
+
+    [dependencies]
+"#};
+
+const README_MD: &str = indoc! {r#"
+    # {{crate_name}}
+
+    TODO: fill description.
+"#};
+
+/// The same skeleton `create_new_crate_skeleton` writes.
+fn library() -> CrateTemplate {
+    CrateTemplate::new(
+        "library",
+        "A library crate using the `x!{}` module layout",
+        false,
+        template_files(&[
+            ("{{crate_name}}/Cargo.toml", LIBRARY_CARGO_TOML),
+            ("{{crate_name}}/README.md",  README_MD),
+            ("{{crate_name}}/src/lib.rs", indoc! {r#"
+                #[macro_use] mod imports; use imports::*;
+
+                x!{{{crate_ident}}}
+            "#}),
+            ("{{crate_name}}/src/imports.rs", indoc! {r#"
+                // If we belong to a prefix group, we'd do `pub(crate) use prefix_3p::*;`
+                // For now, placeholder comment.
+            "#}),
+            ("{{crate_name}}/src/{{crate_ident}}.rs", indoc! {r#"
+                crate::ix!();
+            "#}),
+        ]),
+    )
+}
+
+/// A library meant to live in a prefix group; the group's `-3p` import is added on creation.
+fn prefix_group_member() -> CrateTemplate {
+    CrateTemplate::new(
+        "prefix-group-member",
+        "A member crate of a prefix group, re-exported by its facade",
+        true,
+        template_files(&[
+            ("{{crate_name}}/Cargo.toml", LIBRARY_CARGO_TOML),
+            ("{{crate_name}}/README.md",  README_MD),
+            ("{{crate_name}}/src/lib.rs", indoc! {r#"
+                // ---------------- [ File: {{crate_name}}/src/lib.rs ]
+                #[macro_use] mod imports; use imports::*;
+
+                x!{{{crate_ident}}}
+            "#}),
+            ("{{crate_name}}/src/imports.rs", indoc! {r#"
+                // ---------------- [ File: {{crate_name}}/src/imports.rs ]
+            "#}),
+            ("{{crate_name}}/src/{{crate_ident}}.rs", indoc! {r#"
+                // ---------------- [ File: {{crate_name}}/src/{{crate_ident}}.rs ]
+                crate::ix!();
+            "#}),
+        ]),
+    )
+}
+
+/// A command-line binary with a `structopt` argument struct.
+fn binary() -> CrateTemplate {
+    CrateTemplate::new(
+        "binary",
+        "A command-line binary with a structopt CLI",
+        false,
+        template_files(&[
+            ("{{crate_name}}/Cargo.toml", indoc! {r#"
+                [package]
+                name = "{{crate_name}}"
+                version = "0.1.0"
+                authors = ["YourName <you@example.com>"]
+                license = "MIT"
+                edition = "2024"
+                description = "todo: write a description here"
+
+                [dependencies]
+                structopt = "0.3"
+            "#}),
+            ("{{crate_name}}/README.md",   README_MD),
+            ("{{crate_name}}/src/main.rs", indoc! {r#"
+                use structopt::StructOpt;
+
+                #[derive(Debug, StructOpt)]
+                #[structopt(name = "{{crate_name}}")]
+                struct Cli {
+                    /// Print what is being done
+                    #[structopt(short, long)]
+                    verbose: bool,
+                }
+
+                fn main() {
+                    let cli = Cli::from_args();
+                    if cli.verbose {
+                        eprintln!("{:?}", cli);
+                    }
+                }
+            "#}),
+        ]),
+    )
+}
+
+/// A trait crate plus its `-derive` proc-macro crate; the trait crate re-exports the derive.
+fn proc_macro() -> CrateTemplate {
+    CrateTemplate::new(
+        "proc-macro",
+        "A trait crate and its paired `-derive` proc-macro crate",
+        false,
+        template_files(&[
+            ("{{crate_name}}/Cargo.toml", indoc! {r#"
+                [package]
+                name = "{{crate_name}}"
+                version = "0.1.0"
+                authors = ["YourName <you@example.com>"]
+                license = "MIT"
+                edition = "2024"
+                description = "todo: write a description here"
+
+                [dependencies.{{crate_name}}-derive]
+                path = "../{{crate_name}}-derive"
+                version = "0.1.0"
+            "#}),
+            ("{{crate_name}}/README.md",  README_MD),
+            ("{{crate_name}}/src/lib.rs", indoc! {r#"
+                pub use {{crate_ident}}_derive::{{crate_type}};
+
+                /// Implement with `#[derive({{crate_type}})]`.
+                pub trait {{crate_type}} {
+                    fn type_name() -> &'static str;
+                }
+            "#}),
+            ("{{crate_name}}-derive/Cargo.toml", indoc! {r#"
+                [package]
+                name = "{{crate_name}}-derive"
+                version = "0.1.0"
+                authors = ["YourName <you@example.com>"]
+                license = "MIT"
+                edition = "2024"
+                description = "Derive macro for {{crate_name}}"
+
+                [lib]
+                proc-macro = true
+
+                [dependencies]
+                quote = "1"
+                syn = { version = "2", features = ["full"] }
+            "#}),
+            ("{{crate_name}}-derive/README.md", indoc! {r#"
+                # {{crate_name}}-derive
+
+                `#[derive({{crate_type}})]`; use it through the `{{crate_name}}` crate.
+            "#}),
+            ("{{crate_name}}-derive/src/lib.rs", indoc! {r#"
+                use proc_macro::TokenStream;
+                use quote::quote;
+                use syn::{parse_macro_input, DeriveInput};
+
+                #[proc_macro_derive({{crate_type}})]
+                pub fn derive_{{crate_ident}}(input: TokenStream) -> TokenStream {
+                    let input = parse_macro_input!(input as DeriveInput);
+                    let name = &input.ident;
+                    let name_str = name.to_string();
+                    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+                    let expanded = quote! {
+                        impl #impl_generics ::{{crate_ident}}::{{crate_type}} for #name #ty_generics #where_clause {
+                            fn type_name() -> &'static str {
+                                #name_str
+                            }
+                        }
+                    };
+                    expanded.into()
+                }
+            "#}),
+        ]),
+    )
+}
+
+/// Templates shipped with workspacer; a user or workspace template of the same name wins.
+pub fn builtin_crate_templates() -> Vec<CrateTemplate> {
+    vec![library(), prefix_group_member(), binary(), proc_macro()]
+}
+
+#[cfg(test)]
+mod test_builtin_crate_templates {
+    use super::*;
+
+    #[traced_test]
+    fn every_builtin_renders() {
+        let placeholders = TemplatePlaceholders::for_crate("batch-mode-json", Some("batch-mode"));
+        for template in builtin_crate_templates() {
+            let rendered = template.render(&placeholders)
+                .unwrap_or_else(|e| panic!("template '{}' failed to render: {:?}", template.name(), e));
+            assert!(rendered.crate_dirs().contains(&PathBuf::from("batch-mode-json")), "template '{}'", template.name());
+        }
+    }
+
+    #[traced_test]
+    fn library_matches_the_plain_skeleton() {
+        let rendered = library().render(&TemplatePlaceholders::for_crate("my-crate", None)).unwrap();
+        assert_eq!(rendered.files()[Path::new("my-crate/src/lib.rs")], "#[macro_use] mod imports; use imports::*;\n\nx!{my_crate}\n");
+        assert!(rendered.has_file("my-crate/src/my_crate.rs"));
+    }
+
+    #[traced_test]
+    fn proc_macro_pairs_the_trait_and_derive_crates() {
+        let rendered = proc_macro().render(&TemplatePlaceholders::for_crate("describe", None)).unwrap();
+        assert_eq!(rendered.crate_dirs(), vec![PathBuf::from("describe"), PathBuf::from("describe-derive")]);
+
+        let lib = &rendered.files()[Path::new("describe/src/lib.rs")];
+        assert!(lib.starts_with("pub use describe_derive::Describe;"));
+        assert!(rendered.files()[Path::new("describe-derive/src/lib.rs")].contains("::describe::Describe for #name"));
+    }
+}
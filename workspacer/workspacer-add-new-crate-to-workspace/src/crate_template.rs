@@ -0,0 +1,196 @@
+// ---------------- [ File: workspacer-add-new-crate-to-workspace/src/crate_template.rs ]
+crate::ix!();
+
+/// Optional file at the top of a template directory; every other file is template content.
+pub const TEMPLATE_MANIFEST_FILE_NAME: &str = "template.toml";
+
+/// ```toml
+/// description  = "A batch-mode member crate"
+/// prefix-group = true
+/// ```
+#[derive(Default,Debug,Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+struct TemplateManifest {
+    description:  Option<String>,
+    prefix_group: bool,
+}
+
+/// A named set of files, laid out relative to the workspace root, that `ws add crate` renders
+/// with [`TemplatePlaceholders`]. Paths are templated too, so a template producing two crates
+/// has files under both `{{crate_name}}/` and `{{crate_name}}-derive/`. Every top-level
+/// directory holding a `Cargo.toml` becomes a workspace member.
+#[derive(Getters,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct CrateTemplate {
+    name:                  String,
+    description:           String,
+    /// The new crate must join a prefix group (given or detected from its name)
+    requires_prefix_group: bool,
+    files:                 BTreeMap<PathBuf, String>,
+}
+
+/// The files of a template once rendered for one crate name.
+#[derive(Getters,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct RenderedCrateTemplate {
+    files: BTreeMap<PathBuf, String>,
+}
+
+fn unreadable(path: &Path) -> impl FnOnce(io::Error) -> CrateTemplateError + '_ {
+    move |io| CrateTemplateError::UnreadableTemplate { path: path.to_path_buf(), io: Arc::new(io) }
+}
+
+impl CrateTemplate {
+
+    pub fn new(
+        name:                  &str,
+        description:           &str,
+        requires_prefix_group: bool,
+        files:                 BTreeMap<PathBuf, String>,
+    ) -> Self {
+        Self {
+            name:        name.to_string(),
+            description: description.to_string(),
+            requires_prefix_group,
+            files,
+        }
+    }
+
+    /// Reads a template directory: its optional `template.toml` and every other file, as text.
+    pub async fn from_dir(name: &str, dir: &Path) -> Result<Self, CrateTemplateError> {
+        let manifest_path = dir.join(TEMPLATE_MANIFEST_FILE_NAME);
+        let manifest = match fs::read_to_string(&manifest_path).await {
+            Ok(text) => toml::from_str::<TemplateManifest>(&text).map_err(|e| {
+                CrateTemplateError::InvalidTemplateManifest { path: manifest_path.clone(), message: e.to_string() }
+            })?,
+            Err(e) if e.kind() == ErrorKind::NotFound => TemplateManifest::default(),
+            Err(e) => return Err(unreadable(&manifest_path)(e)),
+        };
+
+        let mut files = BTreeMap::new();
+        let mut dirs  = vec![dir.to_path_buf()];
+        while let Some(current) = dirs.pop() {
+            let mut entries = fs::read_dir(&current).await.map_err(unreadable(&current))?;
+            while let Some(entry) = entries.next_entry().await.map_err(unreadable(&current))? {
+                let path = entry.path();
+                if path == manifest_path {
+                    continue;
+                }
+                let file_type = entry.file_type().await.map_err(unreadable(&path))?;
+                if file_type.is_dir() {
+                    dirs.push(path);
+                } else if file_type.is_file() {
+                    let text = fs::read_to_string(&path).await.map_err(unreadable(&path))?;
+                    let relative = path.strip_prefix(dir).unwrap_or(&path).to_path_buf();
+                    files.insert(relative, text);
+                }
+            }
+        }
+
+        debug!("read template '{}' with {} files from {:?}", name, files.len(), dir);
+        Ok(Self {
+            name:                  name.to_string(),
+            description:           manifest.description.unwrap_or_default(),
+            requires_prefix_group: manifest.prefix_group,
+            files,
+        })
+    }
+
+    /// Renders paths and contents. A rendered path must stay inside the workspace.
+    pub fn render(&self, placeholders: &TemplatePlaceholders) -> Result<RenderedCrateTemplate, CrateTemplateError> {
+        let mut files = BTreeMap::new();
+        for (path, text) in &self.files {
+            let rendered_path = PathBuf::from(placeholders.render(&self.name, &path.to_string_lossy())?);
+            let stays_inside = rendered_path.components().all(|c| matches!(c, std::path::Component::Normal(_)));
+            if !stays_inside || rendered_path.as_os_str().is_empty() {
+                return Err(CrateTemplateError::InvalidTemplateManifest {
+                    path:    path.clone(),
+                    message: format!("renders to {:?}, which is outside the workspace", rendered_path),
+                });
+            }
+            files.insert(rendered_path, placeholders.render(&self.name, text)?);
+        }
+
+        let rendered = RenderedCrateTemplate { files };
+        if rendered.crate_dirs().is_empty() {
+            return Err(CrateTemplateError::TemplateHasNoCrate { template: self.name.clone() });
+        }
+        Ok(rendered)
+    }
+}
+
+impl RenderedCrateTemplate {
+
+    /// Top-level directories with a `Cargo.toml` directly inside: one per crate created.
+    pub fn crate_dirs(&self) -> Vec<PathBuf> {
+        self.files
+            .keys()
+            .filter(|path| path.components().count() == 2 && path.ends_with("Cargo.toml"))
+            .filter_map(|path| path.parent().map(Path::to_path_buf))
+            .collect()
+    }
+
+    pub fn has_file(&self, relative: impl AsRef<Path>) -> bool {
+        self.files.contains_key(relative.as_ref())
+    }
+
+    /// Writes every file under `workspace_root`, refusing to touch an existing crate directory.
+    pub async fn write_to(&self, workspace_root: &Path) -> Result<(), WorkspaceError> {
+        for dir in self.crate_dirs() {
+            let path = workspace_root.join(&dir);
+            if path.exists() {
+                return Err(CrateTemplateError::CrateDirectoryExists { path }.into());
+            }
+        }
+
+        for (relative, text) in &self.files {
+            let path = workspace_root.join(relative);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).await.map_err(|e| WorkspaceError::IoError {
+                    io_error: Arc::new(e),
+                    context:  format!("creating directory {:?}", parent),
+                })?;
+            }
+            fs::write(&path, text).await.map_err(|e| WorkspaceError::IoError {
+                io_error: Arc::new(e),
+                context:  format!("writing template file {:?}", path),
+            })?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_crate_template {
+    use super::*;
+
+    #[traced_test]
+    async fn reads_a_template_directory_and_renders_paths() {
+        let tmp = tempdir().unwrap();
+        let dir = tmp.path();
+        fs::create_dir_all(dir.join("{{crate_name}}/src")).await.unwrap();
+        fs::write(dir.join("template.toml"), "description = \"team crate\"\nprefix-group = true\n").await.unwrap();
+        fs::write(dir.join("{{crate_name}}/Cargo.toml"), "[package]\nname = \"{{crate_name}}\"\n").await.unwrap();
+        fs::write(dir.join("{{crate_name}}/src/lib.rs"), "pub use {{prefix_ident}}_3p::*;\n").await.unwrap();
+
+        let template = CrateTemplate::from_dir("team", dir).await.unwrap();
+        assert_eq!(template.description(), "team crate");
+        assert!(template.requires_prefix_group());
+        assert_eq!(template.files().len(), 2, "template.toml is not content");
+
+        let rendered = template.render(&TemplatePlaceholders::for_crate("batch-mode-json", Some("batch-mode"))).unwrap();
+        assert_eq!(rendered.crate_dirs(), vec![PathBuf::from("batch-mode-json")]);
+        assert_eq!(rendered.files()[Path::new("batch-mode-json/src/lib.rs")], "pub use batch_mode_3p::*;\n");
+    }
+
+    #[traced_test]
+    async fn rejects_templates_without_a_crate_or_escaping_the_workspace() {
+        let placeholders = TemplatePlaceholders::for_crate("x", None);
+
+        let no_crate = CrateTemplate::new("t", "", false, BTreeMap::from([(PathBuf::from("notes.md"), String::new())]));
+        assert!(matches!(no_crate.render(&placeholders), Err(CrateTemplateError::TemplateHasNoCrate { .. })));
+
+        let escaping = CrateTemplate::new("t", "", false, BTreeMap::from([(PathBuf::from("../{{crate_name}}/Cargo.toml"), String::new())]));
+        assert!(matches!(escaping.render(&placeholders), Err(CrateTemplateError::InvalidTemplateManifest { .. })));
+    }
+}
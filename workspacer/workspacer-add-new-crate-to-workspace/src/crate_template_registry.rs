@@ -0,0 +1,207 @@
+// ---------------- [ File: workspacer-add-new-crate-to-workspace/src/crate_template_registry.rs ]
+crate::ix!();
+
+/// Overrides the user's template directory.
+pub const TEMPLATES_DIR_ENV_VAR: &str = "WORKSPACER_TEMPLATES";
+
+/// Where `ws template register` puts templates: `$WORKSPACER_TEMPLATES`, else
+/// `<config dir>/workspacer/templates` (`~/.config/workspacer/templates` on Linux).
+pub fn user_templates_dir() -> Option<PathBuf> {
+    std::env::var_os(TEMPLATES_DIR_ENV_VAR)
+        .map(PathBuf::from)
+        .or_else(|| dirs::config_dir().map(|dir| dir.join("workspacer").join("templates")))
+}
+
+/// Where a template comes from, for `ws template list`.
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub enum CrateTemplateSource {
+    Directory(PathBuf),
+    Builtin,
+}
+
+impl fmt::Display for CrateTemplateSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrateTemplateSource::Directory(dir) => write!(f, "{}", dir.display()),
+            CrateTemplateSource::Builtin        => write!(f, "builtin"),
+        }
+    }
+}
+
+/// Template lookup. Each search directory holds one sub-directory per template, named after
+/// it; the first directory with a match wins and the builtins come last. The workspace's
+/// `[templates] dirs` are searched before the user directory so a team can pin its layout.
+#[derive(Getters,Builder,Debug,Clone)]
+#[getset(get="pub")]
+pub struct CrateTemplateRegistry {
+    #[builder(default = "user_templates_dir().into_iter().collect()")]
+    search_dirs: Vec<PathBuf>,
+
+    /// Target of [`CrateTemplateRegistry::register`]
+    #[builder(default = "user_templates_dir()")]
+    user_dir:    Option<PathBuf>,
+}
+
+impl CrateTemplateRegistry {
+
+    pub fn for_workspace(settings: &TemplateSettings, workspace_root: &Path) -> Self {
+        let user_dir = user_templates_dir();
+        let search_dirs = settings
+            .dirs()
+            .iter()
+            .map(|dir| workspace_root.join(dir))
+            .chain(user_dir.clone())
+            .collect();
+        Self { search_dirs, user_dir }
+    }
+
+    pub async fn find(&self, name: &str) -> Result<CrateTemplate, CrateTemplateError> {
+        for dir in &self.search_dirs {
+            let candidate = dir.join(name);
+            if candidate.is_dir() {
+                debug!("using template '{}' from {:?}", name, candidate);
+                return CrateTemplate::from_dir(name, &candidate).await;
+            }
+        }
+        builtin_crate_templates()
+            .into_iter()
+            .find(|t| t.name() == name)
+            .ok_or_else(|| CrateTemplateError::UnknownTemplate {
+                name:     name.to_string(),
+                searched: self.search_dirs.clone(),
+            })
+    }
+
+    /// Every template reachable by name, with the source that wins for it.
+    pub async fn list(&self) -> Result<Vec<(CrateTemplate, CrateTemplateSource)>, CrateTemplateError> {
+        let mut found: BTreeMap<String, (CrateTemplate, CrateTemplateSource)> = BTreeMap::new();
+
+        for dir in &self.search_dirs {
+            let mut entries = match fs::read_dir(dir).await {
+                Ok(entries) => entries,
+                Err(e) if e.kind() == ErrorKind::NotFound => continue,
+                Err(e) => return Err(CrateTemplateError::UnreadableTemplate { path: dir.clone(), io: Arc::new(e) }),
+            };
+            let unreadable = |e| CrateTemplateError::UnreadableTemplate { path: dir.clone(), io: Arc::new(e) };
+            while let Some(entry) = entries.next_entry().await.map_err(unreadable)? {
+                let name = entry.file_name().to_string_lossy().to_string();
+                if !entry.path().is_dir() || found.contains_key(&name) {
+                    continue;
+                }
+                let template = CrateTemplate::from_dir(&name, &entry.path()).await?;
+                found.insert(name, (template, CrateTemplateSource::Directory(dir.clone())));
+            }
+        }
+        for template in builtin_crate_templates() {
+            found.entry(template.name().clone()).or_insert((template, CrateTemplateSource::Builtin));
+        }
+
+        Ok(found.into_values().collect())
+    }
+
+    /// Copies the template at `from` into the user directory as `name`. The copy must render,
+    /// and an existing template of that name is only replaced with `force`.
+    pub async fn register(&self, name: &str, from: &Path, force: bool) -> Result<PathBuf, WorkspaceError> {
+        let template = CrateTemplate::from_dir(name, from).await?;
+        template.render(&TemplatePlaceholders::for_crate("template-check", Some("template")))?;
+
+        let user_dir = self.user_dir.clone().ok_or_else(|| WorkspaceError::IoError {
+            io_error: Arc::new(io::Error::new(ErrorKind::NotFound, "no user config directory")),
+            context:  format!("finding a place to register template '{}'; set {}", name, TEMPLATES_DIR_ENV_VAR),
+        })?;
+        let target = user_dir.join(name);
+
+        if target.exists() {
+            if !force {
+                return Err(CrateTemplateError::TemplateAlreadyRegistered { name: name.to_string(), path: target }.into());
+            }
+            fs::remove_dir_all(&target).await.map_err(|e| WorkspaceError::IoError {
+                io_error: Arc::new(e),
+                context:  format!("replacing template at {:?}", target),
+            })?;
+        }
+
+        let manifest = from.join(TEMPLATE_MANIFEST_FILE_NAME);
+        let mut copies: Vec<(PathBuf, PathBuf)> = template
+            .files()
+            .keys()
+            .map(|relative| (from.join(relative), target.join(relative)))
+            .collect();
+        if manifest.is_file() {
+            copies.push((manifest, target.join(TEMPLATE_MANIFEST_FILE_NAME)));
+        }
+
+        for (source, destination) in copies {
+            if let Some(parent) = destination.parent() {
+                fs::create_dir_all(parent).await.map_err(|e| WorkspaceError::IoError {
+                    io_error: Arc::new(e),
+                    context:  format!("creating {:?}", parent),
+                })?;
+            }
+            fs::copy(&source, &destination).await.map_err(|e| WorkspaceError::IoError {
+                io_error: Arc::new(e),
+                context:  format!("copying {:?} to {:?}", source, destination),
+            })?;
+        }
+
+        info!("registered template '{}' at {:?}", name, target);
+        Ok(target)
+    }
+}
+
+#[cfg(test)]
+mod test_crate_template_registry {
+    use super::*;
+
+    async fn write_template(dir: &Path, marker: &str) {
+        fs::create_dir_all(dir.join("{{crate_name}}/src")).await.unwrap();
+        fs::write(dir.join("{{crate_name}}/Cargo.toml"), "[package]\nname = \"{{crate_name}}\"\n").await.unwrap();
+        fs::write(dir.join("{{crate_name}}/src/lib.rs"), marker).await.unwrap();
+    }
+
+    #[traced_test]
+    async fn search_dirs_shadow_builtins_and_unknown_names_fail() {
+        let tmp = tempdir().unwrap();
+        write_template(&tmp.path().join("library"), "// team library\n").await;
+
+        let registry = CrateTemplateRegistryBuilder::default()
+            .search_dirs(vec![tmp.path().to_path_buf()])
+            .user_dir(None)
+            .build()
+            .unwrap();
+
+        let library = registry.find("library").await.unwrap();
+        assert_eq!(library.files()[Path::new("{{crate_name}}/src/lib.rs")], "// team library\n");
+        assert!(registry.find("binary").await.is_ok(), "builtins remain reachable");
+        assert!(matches!(registry.find("nope").await, Err(CrateTemplateError::UnknownTemplate { .. })));
+
+        let listed = registry.list().await.unwrap();
+        let library_source = listed.iter().find(|(t, _)| t.name() == "library").map(|(_, s)| s.clone());
+        assert_eq!(library_source, Some(CrateTemplateSource::Directory(tmp.path().to_path_buf())));
+        assert_eq!(listed.len(), builtin_crate_templates().len());
+    }
+
+    #[traced_test]
+    async fn register_copies_into_the_user_dir_and_refuses_to_overwrite() {
+        let source = tempdir().unwrap();
+        let user   = tempdir().unwrap();
+        write_template(source.path(), "// v1\n").await;
+
+        let registry = CrateTemplateRegistryBuilder::default()
+            .search_dirs(vec![user.path().to_path_buf()])
+            .user_dir(Some(user.path().to_path_buf()))
+            .build()
+            .unwrap();
+
+        registry.register("team-lib", source.path(), false).await.unwrap();
+        assert!(registry.find("team-lib").await.is_ok());
+
+        let again = registry.register("team-lib", source.path(), false).await;
+        assert!(matches!(again, Err(WorkspaceError::CrateTemplateError(CrateTemplateError::TemplateAlreadyRegistered { .. }))));
+
+        write_template(source.path(), "// v2\n").await;
+        registry.register("team-lib", source.path(), true).await.unwrap();
+        let replaced = registry.find("team-lib").await.unwrap();
+        assert_eq!(replaced.files()[Path::new("{{crate_name}}/src/lib.rs")], "// v2\n");
+    }
+}
@@ -343,10 +343,11 @@ mod test_create_crate_skeleton {
         #[cfg(target_os = "linux")]
         {
             let ws_path = PathBuf::from("/dev/null");
-            let ws = MyWorkspace {
-                path: ws_path.clone(),
-                crates: vec![], // minimal
-            };
+            let ws: MyWorkspace = WorkspaceBuilder::default()
+                .path(ws_path.clone())
+                .crates(vec![]) // minimal
+                .build()
+                .unwrap();
             let crate_name = "no_way";
             let result = ws.create_new_crate_skeleton(crate_name).await;
             // Expect an IoError
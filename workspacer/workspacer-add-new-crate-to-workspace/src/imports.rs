@@ -1,6 +1,7 @@
 // ---------------- [ File: workspacer-add-new-crate-to-workspace/src/imports.rs ]
 pub(crate) use workspacer_3p::*;
 pub(crate) use workspacer_add_internal_dep::*;
+pub(crate) use workspacer_config::*;
 pub(crate) use workspacer_crate::*;
 pub(crate) use workspacer_crate_interface::*;
 pub(crate) use workspacer_errors::*;
@@ -9,3 +10,4 @@ pub(crate) use workspacer_register_internal_crate_in_prefix_group::*;
 pub(crate) use workspacer_scan_for_prefix_groups::*;
 pub(crate) use workspacer_workspace::*;
 pub(crate) use workspacer_workspace_interface::*;
+pub(crate) use ::serde_derive::Deserialize;
@@ -6,3 +6,8 @@ x!{find_single_prefix_match}
 x!{create_crate_skeleton}
 x!{dash_to_snake_case}
 x!{add_to_workspace_members}
+x!{template_placeholders}
+x!{crate_template}
+x!{builtin_crate_templates}
+x!{crate_template_registry}
+x!{add_new_crate_from_template}
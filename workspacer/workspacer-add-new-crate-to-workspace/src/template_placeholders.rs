@@ -0,0 +1,101 @@
+// ---------------- [ File: workspacer-add-new-crate-to-workspace/src/template_placeholders.rs ]
+crate::ix!();
+
+/// The `{{name}}` values a crate template is rendered with, in file paths and file contents:
+///
+/// | placeholder       | `batch-mode-json` in group `batch-mode` |
+/// |-------------------|-----------------------------------------|
+/// | `crate_name`      | `batch-mode-json`                       |
+/// | `crate_ident`     | `batch_mode_json`                       |
+/// | `crate_type`      | `BatchModeJson`                         |
+/// | `prefix`          | `batch-mode` (empty outside a group)    |
+/// | `prefix_ident`    | `batch_mode`                            |
+#[derive(Getters,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct TemplatePlaceholders {
+    values: BTreeMap<String, String>,
+}
+
+/// `batch-mode-json` => `BatchModeJson`
+pub fn dash_to_pascal_case(input: &str) -> String {
+    input
+        .split(['-', '_'])
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None        => String::new(),
+            }
+        })
+        .collect()
+}
+
+impl TemplatePlaceholders {
+
+    pub fn for_crate(crate_name: &str, prefix: Option<&str>) -> Self {
+        let prefix = prefix.unwrap_or_default();
+        let values = [
+            ("crate_name",   crate_name.to_string()),
+            ("crate_ident",  dash_to_snake_case(crate_name)),
+            ("crate_type",   dash_to_pascal_case(crate_name)),
+            ("prefix",       prefix.to_string()),
+            ("prefix_ident", dash_to_snake_case(prefix)),
+        ];
+        Self { values: values.into_iter().map(|(k, v)| (k.to_string(), v)).collect() }
+    }
+
+    /// Replaces every `{{name}}`. A `{{` not followed by `name}}` is left alone, so
+    /// `x!{{{crate_ident}}}` renders to `x!{batch_mode_json}`; an unknown name is an error.
+    pub fn render(&self, template: &str, text: &str) -> Result<String, CrateTemplateError> {
+        let is_name_char = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_';
+
+        let mut out  = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find("{{") {
+            out.push_str(&rest[..start]);
+            let after = &rest[start + 2..];
+            let name_len = after.find(|c: char| !is_name_char(c)).unwrap_or(after.len());
+
+            if name_len > 0 && after[name_len..].starts_with("}}") {
+                let name = &after[..name_len];
+                let value = self.values.get(name).ok_or_else(|| CrateTemplateError::UnknownPlaceholder {
+                    template:    template.to_string(),
+                    placeholder: name.to_string(),
+                })?;
+                out.push_str(value);
+                rest = &after[name_len + 2..];
+            } else {
+                out.push('{');
+                rest = &rest[start + 1..];
+            }
+        }
+        out.push_str(rest);
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test_template_placeholders {
+    use super::*;
+
+    #[traced_test]
+    fn renders_names_and_leaves_rust_braces_alone() {
+        let p = TemplatePlaceholders::for_crate("batch-mode-json", Some("batch-mode"));
+        assert_eq!(p.render("t", "x!{{{crate_ident}}}").unwrap(), "x!{batch_mode_json}");
+        assert_eq!(p.render("t", "pub use {{prefix_ident}}_3p::*; // {{crate_type}}").unwrap(), "pub use batch_mode_3p::*; // BatchModeJson");
+        assert_eq!(p.render("t", "fn f() { {} } {{ not a placeholder }}").unwrap(), "fn f() { {} } {{ not a placeholder }}");
+
+        assert!(matches!(
+            p.render("t", "{{crate_nmae}}"),
+            Err(CrateTemplateError::UnknownPlaceholder { placeholder, .. }) if placeholder == "crate_nmae"
+        ));
+    }
+
+    #[traced_test]
+    fn pascal_cases_dashed_and_snake_names() {
+        assert_eq!(dash_to_pascal_case("batch-mode-json"), "BatchModeJson");
+        assert_eq!(dash_to_pascal_case("my_new_crate"), "MyNewCrate");
+        assert_eq!(TemplatePlaceholders::for_crate("solo", None).values()["prefix"], "");
+    }
+}
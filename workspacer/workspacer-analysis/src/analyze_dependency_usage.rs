@@ -0,0 +1,319 @@
+// ---------------- [ File: workspacer-analysis/src/analyze_dependency_usage.rs ]
+crate::ix!();
+
+/// Compares declared dependencies against the crates each member's sources reference.
+#[async_trait]
+pub trait AnalyzeDependencyUsage {
+    type Report;
+    type Error;
+
+    async fn analyze_dependency_usage(&self) -> Result<Self::Report, Self::Error>;
+}
+
+#[async_trait]
+impl AnalyzeDependencyUsage for CrateHandle {
+    type Report = CrateDependencyUsage;
+    type Error  = CrateError;
+
+    async fn analyze_dependency_usage(&self) -> Result<Self::Report, Self::Error> {
+        let crate_name = self.name().to_string();
+        let crate_root = self.root_dir_path_buf();
+
+        info!("analyzing dependency usage for crate='{}'", crate_name);
+
+        let cargo_toml_content = {
+            let cargo_toml = self.cargo_toml();
+            let guard      = cargo_toml.lock().await;
+            guard.get_content().clone()
+        };
+
+        let lib_names = match self.get_cargo_metadata().await {
+            Ok(metadata) => lib_names_from_metadata(&metadata),
+            Err(e) => {
+                warn!("cargo metadata failed for crate='{}' ({:?}); assuming lib names from Cargo.toml keys", crate_name, e);
+                BTreeMap::new()
+            }
+        };
+
+        let declared = declared_dependencies(&cargo_toml_content, &lib_names);
+
+        let src_files = self.source_files_excluding(&[]).await?;
+        let test_files = if self.has_tests_directory() {
+            self.test_files().await?
+        } else {
+            vec![]
+        };
+
+        let src_roots  = gather_referenced_crate_roots(&src_files).await?;
+        let test_roots = gather_referenced_crate_roots(&test_files).await?;
+
+        let known_crates = match find_lockfile_dir(&crate_root) {
+            Some(dir) => build_lock_versions(&dir)
+                .await?
+                .into_keys()
+                .map(|k| k.replace('-', "_"))
+                .collect::<BTreeSet<_>>(),
+            None => {
+                warn!("no Cargo.lock found above {:?}; skipping missing-dependency detection", crate_root);
+                BTreeSet::new()
+            }
+        };
+
+        let facade_reexports = gather_facade_reexports(&crate_root, &declared).await;
+
+        let mut macro_token_names = src_roots.macro_token_names().clone();
+        macro_token_names.extend(test_roots.macro_token_names().iter().cloned());
+
+        Ok(classify_dependency_usage(
+            &crate_name,
+            &declared,
+            &src_roots.external_roots(),
+            &test_roots.external_roots(),
+            &known_crates,
+            &facade_reexports,
+            &macro_token_names,
+        ))
+    }
+}
+
+#[async_trait]
+impl<P, H> AnalyzeDependencyUsage for Workspace<P, H>
+where
+    for<'async_trait> P: From<PathBuf> + AsRef<Path> + Send + Sync + 'async_trait,
+    for<'async_trait> H: CrateHandleInterface<P>
+        + AnalyzeDependencyUsage<Report=CrateDependencyUsage, Error=CrateError>
+        + Send
+        + Sync
+        + 'async_trait,
+{
+    type Report = WorkspaceDependencyUsage;
+    type Error  = WorkspaceError;
+
+    async fn analyze_dependency_usage(&self) -> Result<Self::Report, Self::Error> {
+        let mut reports = Vec::new();
+
+        for crate_handle in self.crates() {
+            let guard = crate_handle.lock().await;
+            reports.push(guard.analyze_dependency_usage().await?);
+        }
+
+        Ok(WorkspaceDependencyUsage::new(reports))
+    }
+}
+
+/// Decides, for one crate, which declared dependencies are unused and which used crates are undeclared.
+///
+/// `facade_reexports` maps the Cargo.toml key of each local dependency to the crate roots its
+/// `lib.rs` re-exports with `pub use`. A crate used by path that is not declared, but is
+/// re-exported by one of those facades, is reported in `provided_by_facade` instead of
+/// `missing_dependencies`.
+///
+/// A dependency whose name appears in `macro_token_names` is never reported
+/// as unused: macros may reach for it in ways the token scan cannot follow
+/// (`error_tree!{}` expanding to `error_tree::...`, for one).
+pub fn classify_dependency_usage(
+    crate_name:       &str,
+    declared:         &[DeclaredDependency],
+    src_roots:        &BTreeSet<String>,
+    test_roots:       &BTreeSet<String>,
+    known_crates:     &BTreeSet<String>,
+    facade_reexports: &BTreeMap<String, BTreeSet<String>>,
+    macro_token_names: &BTreeSet<String>,
+) -> CrateDependencyUsage {
+
+    let mut unused_dependencies             = Vec::new();
+    let mut dependencies_only_used_in_tests = Vec::new();
+    let mut unused_dev_dependencies         = Vec::new();
+
+    for dep in declared {
+        if macro_token_names.contains(dep.lib_name()) {
+            continue;
+        }
+        let in_src   = src_roots.contains(dep.lib_name());
+        let in_tests = test_roots.contains(dep.lib_name());
+
+        match dep.kind() {
+            DependencyKind::Normal => {
+                if in_src {
+                    continue;
+                }
+                if in_tests {
+                    dependencies_only_used_in_tests.push(dep.toml_key().clone());
+                } else {
+                    unused_dependencies.push(dep.toml_key().clone());
+                }
+            }
+            DependencyKind::Dev => {
+                // `#[cfg(test)]` modules live in src/, so either location counts
+                if !in_src && !in_tests {
+                    unused_dev_dependencies.push(dep.toml_key().clone());
+                }
+            }
+        }
+    }
+
+    let own_lib_name  = crate_name.replace('-', "_");
+    let declared_libs = declared.iter().map(|d| d.lib_name()).collect::<BTreeSet<_>>();
+
+    let mut missing_dependencies: Vec<MissingDependency>    = Vec::new();
+    let mut provided_by_facade:   BTreeMap<String, String> = BTreeMap::new();
+
+    let candidates = src_roots
+        .iter()
+        .map(|r| (r, DependencyKind::Normal))
+        .chain(test_roots.iter().map(|r| (r, DependencyKind::Dev)));
+
+    for (root, kind) in candidates {
+        if *root == own_lib_name || declared_libs.contains(root) || !known_crates.contains(root) {
+            continue;
+        }
+        if provided_by_facade.contains_key(root)
+            || missing_dependencies.iter().any(|m| m.crate_name() == root)
+        {
+            continue;
+        }
+        if let Some((facade, _)) = facade_reexports.iter().find(|(_, roots)| roots.contains(root)) {
+            provided_by_facade.insert(root.clone(), facade.clone());
+            continue;
+        }
+        missing_dependencies.push(MissingDependency::new(root, kind));
+    }
+
+    CrateDependencyUsageBuilder::default()
+        .crate_name(crate_name)
+        .unused_dependencies(unused_dependencies)
+        .dependencies_only_used_in_tests(dependencies_only_used_in_tests)
+        .unused_dev_dependencies(unused_dev_dependencies)
+        .missing_dependencies(missing_dependencies)
+        .provided_by_facade(provided_by_facade)
+        .build()
+        .expect("all CrateDependencyUsage fields were provided")
+}
+
+/// Walks upward from `start` looking for the directory holding `Cargo.lock`.
+fn find_lockfile_dir(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir| dir.join("Cargo.lock").is_file())
+        .map(|dir| dir.to_path_buf())
+}
+
+/// For each local (`path = ...`) dependency, collects what its `src/lib.rs` re-exports.
+async fn gather_facade_reexports(
+    crate_root: &Path,
+    declared:   &[DeclaredDependency],
+) -> BTreeMap<String, BTreeSet<String>> {
+
+    let mut out = BTreeMap::new();
+
+    for dep in declared {
+        let Some(rel) = dep.path() else { continue };
+        let lib_rs = crate_root.join(rel).join("src").join("lib.rs");
+
+        match tokio::fs::read_to_string(&lib_rs).await {
+            Ok(text) => {
+                let roots = ReferencedCrateRoots::from_source_text(&text);
+                if !roots.reexported_roots().is_empty() {
+                    out.insert(dep.toml_key().clone(), roots.reexported_roots().clone());
+                }
+            }
+            Err(e) => {
+                debug!("could not read facade candidate {:?}: {:?}", lib_rs, e);
+            }
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod test_classify_dependency_usage {
+    use super::*;
+
+    fn dep(key: &str, kind: DependencyKind) -> DeclaredDependency {
+        DeclaredDependencyBuilder::default()
+            .toml_key(key)
+            .lib_name(key.replace('-', "_"))
+            .kind(kind)
+            .build()
+            .unwrap()
+    }
+
+    fn set(items: &[&str]) -> BTreeSet<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn unused_and_test_only_dependencies_are_separated() {
+        let declared = vec![
+            dep("serde-json", DependencyKind::Normal),
+            dep("regex",      DependencyKind::Normal),
+            dep("tempfile",   DependencyKind::Normal),
+            dep("quickcheck", DependencyKind::Dev),
+        ];
+
+        let usage = classify_dependency_usage(
+            "my-crate",
+            &declared,
+            &set(&["serde_json"]),
+            &set(&["tempfile"]),
+            &BTreeSet::new(),
+            &BTreeMap::new(),
+            &BTreeSet::new(),
+        );
+
+        assert_eq!(usage.unused_dependencies(), &vec!["regex".to_string()]);
+        assert_eq!(usage.dependencies_only_used_in_tests(), &vec!["tempfile".to_string()]);
+        assert_eq!(usage.unused_dev_dependencies(), &vec!["quickcheck".to_string()]);
+        assert!(!usage.is_clean());
+    }
+
+    #[test]
+    fn undeclared_crates_are_missing_unless_a_facade_reexports_them() {
+        let declared = vec![dep("workspacer-3p", DependencyKind::Normal)];
+
+        let mut facades = BTreeMap::new();
+        facades.insert("workspacer-3p".to_string(), set(&["tokio"]));
+
+        let usage = classify_dependency_usage(
+            "my-crate",
+            &declared,
+            &set(&["workspacer_3p", "tokio", "indexmap", "my_crate", "NotACrate"]),
+            &set(&["proptest"]),
+            &set(&["tokio", "indexmap", "proptest", "workspacer_3p", "my_crate"]),
+            &facades,
+            &BTreeSet::new(),
+        );
+
+        assert_eq!(usage.provided_by_facade().get("tokio"), Some(&"workspacer-3p".to_string()));
+        assert_eq!(
+            usage.missing_dependencies(),
+            &vec![
+                MissingDependency::new("indexmap", DependencyKind::Normal),
+                MissingDependency::new("proptest", DependencyKind::Dev),
+            ]
+        );
+        assert!(usage.unused_dependencies().is_empty());
+    }
+
+    #[test]
+    fn dependencies_named_inside_macros_are_not_unused() {
+        let declared = vec![
+            dep("error-tree",  DependencyKind::Normal),
+            dep("lazy_static", DependencyKind::Normal),
+            dep("regex",       DependencyKind::Normal),
+        ];
+
+        let usage = classify_dependency_usage(
+            "my-crate",
+            &declared,
+            &BTreeSet::new(),
+            &BTreeSet::new(),
+            &BTreeSet::new(),
+            &BTreeMap::new(),
+            &set(&["error_tree", "lazy_static", "info"]),
+        );
+
+        assert_eq!(usage.unused_dependencies(), &vec!["regex".to_string()]);
+    }
+}
@@ -1,9 +1,13 @@
 // ---------------- [ File: workspacer-analysis/src/crate_analysis.rs ]
 crate::ix!();
 
-#[derive(Debug,Clone)]
+#[derive(Serialize,Debug,Clone)]
 pub struct CrateAnalysis {
 
+    /// Set by whoever knows which crate was analyzed (`CrateAnalysis::new` only sees files)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crate_name:          Option<String>,
+
     /// Total size of files in bytes
     total_file_size:     u64,       
 
@@ -72,6 +76,7 @@ impl CrateAnalysis {
         }
 
         Ok(CrateAnalysis {
+            crate_name: None,
             total_file_size,
             total_lines_of_code,
             total_source_files,
@@ -81,7 +86,16 @@ impl CrateAnalysis {
         })
     }
 
+    pub fn with_crate_name(mut self, crate_name: impl Into<String>) -> Self {
+        self.crate_name = Some(crate_name.into());
+        self
+    }
+
     // --- Getters ---
+    pub fn crate_name(&self) -> Option<&str> {
+        self.crate_name.as_deref()
+    }
+
     pub fn total_file_size(&self) -> u64 {
         self.total_file_size
     }
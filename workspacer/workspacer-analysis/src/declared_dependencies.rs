@@ -0,0 +1,173 @@
+// ---------------- [ File: workspacer-analysis/src/declared_dependencies.rs ]
+crate::ix!();
+
+#[derive(Serialize,Debug,Clone,Copy,PartialEq,Eq,PartialOrd,Ord,Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum DependencyKind {
+    Normal,
+    Dev,
+}
+
+impl DependencyKind {
+
+    /// The Cargo.toml table this kind of dependency lives in.
+    pub fn table_name(&self) -> &'static str {
+        match self {
+            DependencyKind::Normal => "dependencies",
+            DependencyKind::Dev    => "dev-dependencies",
+        }
+    }
+}
+
+/// One entry of `[dependencies]` or `[dev-dependencies]`.
+#[derive(Builder,Getters,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+#[builder(setter(into))]
+pub struct DeclaredDependency {
+
+    /// The key as written in Cargo.toml (e.g. `workspacer-3p`)
+    toml_key: String,
+
+    /// The identifier source code uses for it (e.g. `workspacer_3p`), honoring renames
+    lib_name: String,
+
+    kind: DependencyKind,
+
+    /// The `path = "..."` value, when this is a local dependency
+    #[builder(default)]
+    path: Option<PathBuf>,
+}
+
+/// Reads `[dependencies]` and `[dev-dependencies]` from an already-parsed Cargo.toml.
+///
+/// `lib_names` maps Cargo.toml keys to the names cargo resolved for them (see
+/// [`lib_names_from_metadata`]); keys it does not cover are snake-cased into `lib_name`.
+pub fn declared_dependencies(
+    cargo_toml: &toml::Value,
+    lib_names:  &BTreeMap<String, String>,
+) -> Vec<DeclaredDependency> {
+    let mut out = Vec::new();
+
+    for kind in [DependencyKind::Normal, DependencyKind::Dev] {
+        let Some(table) = cargo_toml.get(kind.table_name()).and_then(|v| v.as_table()) else {
+            continue;
+        };
+
+        for (key, value) in table.iter() {
+            let path = value
+                .as_table()
+                .and_then(|t| t.get("path"))
+                .and_then(|p| p.as_str())
+                .map(PathBuf::from);
+
+            out.push(DeclaredDependency {
+                toml_key: key.clone(),
+                lib_name: lib_names.get(key).cloned().unwrap_or_else(|| key.replace('-', "_")),
+                kind,
+                path,
+            });
+        }
+    }
+
+    out
+}
+
+/// Maps the Cargo.toml key of each dependency of the package at the root of `metadata` to the
+/// name its source code uses, as cargo resolved it: the key itself for renamed dependencies
+/// (`foo = { package = "real-foo" }`), otherwise the dependency's `[lib] name`.
+///
+/// Dependencies cargo did not resolve (disabled optional ones, say) are left out.
+pub fn lib_names_from_metadata(metadata: &Metadata) -> BTreeMap<String, String> {
+    let mut out = BTreeMap::new();
+
+    let Some(package) = metadata.root_package() else {
+        return out;
+    };
+    let Some(node) = metadata
+        .resolve
+        .as_ref()
+        .and_then(|resolve| resolve.nodes.iter().find(|n| n.id == package.id))
+    else {
+        return out;
+    };
+
+    for node_dep in &node.deps {
+        let resolved = &metadata[&node_dep.pkg];
+        let key = package
+            .dependencies
+            .iter()
+            .filter(|d| d.name == resolved.name)
+            .find_map(|d| match &d.rename {
+                Some(rename) if rename.replace('-', "_") == node_dep.name => Some(rename.clone()),
+                Some(_)                                                   => None,
+                None                                                      => Some(d.name.clone()),
+            });
+        if let Some(key) = key {
+            out.insert(key, node_dep.name.clone());
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod test_declared_dependencies {
+    use super::*;
+
+    #[test]
+    fn reads_both_tables_and_paths() {
+        let toml: toml::Value = toml::from_str(r#"
+            [package]
+            name = "x"
+
+            [dependencies]
+            serde-json = "1"
+            workspacer-3p = { path = "../workspacer-3p" }
+
+            [dependencies.regex]
+            version = "1"
+
+            [dev-dependencies]
+            tempfile = "3"
+        "#).unwrap();
+
+        let deps = declared_dependencies(&toml, &BTreeMap::new());
+        assert_eq!(deps.len(), 4);
+
+        let three_p = deps.iter().find(|d| d.toml_key() == "workspacer-3p").unwrap();
+        assert_eq!(three_p.lib_name(), "workspacer_3p");
+        assert_eq!(three_p.path(), &Some(PathBuf::from("../workspacer-3p")));
+        assert_eq!(*three_p.kind(), DependencyKind::Normal);
+
+        let tempfile = deps.iter().find(|d| d.toml_key() == "tempfile").unwrap();
+        assert_eq!(*tempfile.kind(), DependencyKind::Dev);
+    }
+
+    #[test]
+    fn missing_tables_yield_nothing() {
+        let toml: toml::Value = toml::from_str("[package]\nname = \"x\"\n").unwrap();
+        assert!(declared_dependencies(&toml, &BTreeMap::new()).is_empty());
+    }
+
+    #[test]
+    fn resolved_lib_names_win_over_the_key() {
+        let toml: toml::Value = toml::from_str(r#"
+            [package]
+            name = "x"
+
+            [dependencies]
+            rust-ini = "0.19"
+            json = { package = "serde_json", version = "1" }
+        "#).unwrap();
+
+        let lib_names = [
+            ("rust-ini".to_string(), "ini".to_string()),
+            ("json".to_string(),     "json".to_string()),
+        ].into_iter().collect();
+
+        let deps = declared_dependencies(&toml, &lib_names);
+        let lib_of = |key: &str| deps.iter().find(|d| d.toml_key() == key).unwrap().lib_name().clone();
+        assert_eq!(lib_of("rust-ini"), "ini");
+        assert_eq!(lib_of("json"), "json");
+    }
+}
@@ -0,0 +1,124 @@
+// ---------------- [ File: workspacer-analysis/src/dependency_usage_report.rs ]
+crate::ix!();
+
+/// A crate the sources reach for without declaring it.
+#[derive(Serialize,Getters,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct MissingDependency {
+
+    /// The crate name as it appears in source (snake case)
+    crate_name: String,
+
+    /// `Normal` if referenced from `src/`, `Dev` if only from `tests/`
+    kind: DependencyKind,
+}
+
+impl MissingDependency {
+
+    pub fn new(crate_name: impl Into<String>, kind: DependencyKind) -> Self {
+        Self { crate_name: crate_name.into(), kind }
+    }
+}
+
+/// The outcome of comparing one crate's Cargo.toml against what its sources actually use.
+#[derive(Serialize,Builder,Getters,Debug,Clone)]
+#[getset(get="pub")]
+#[builder(setter(into))]
+pub struct CrateDependencyUsage {
+
+    crate_name: String,
+
+    /// `[dependencies]` keys never referenced anywhere
+    #[builder(default)]
+    unused_dependencies: Vec<String>,
+
+    /// `[dependencies]` keys referenced only from `tests/` (candidates for `[dev-dependencies]`)
+    #[builder(default)]
+    dependencies_only_used_in_tests: Vec<String>,
+
+    /// `[dev-dependencies]` keys never referenced anywhere
+    #[builder(default)]
+    unused_dev_dependencies: Vec<String>,
+
+    /// Crates used by path which are only present transitively, through no facade
+    #[builder(default)]
+    missing_dependencies: Vec<MissingDependency>,
+
+    /// Crates used by path which arrive through a facade's `pub use` (crate name => facade key)
+    #[builder(default)]
+    provided_by_facade: BTreeMap<String, String>,
+}
+
+impl CrateDependencyUsage {
+
+    /// True when there is nothing to remove, move, or add.
+    pub fn is_clean(&self) -> bool {
+        self.unused_dependencies.is_empty()
+            && self.dependencies_only_used_in_tests.is_empty()
+            && self.unused_dev_dependencies.is_empty()
+            && self.missing_dependencies.is_empty()
+    }
+}
+
+impl fmt::Display for CrateDependencyUsage {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "crate: {}", self.crate_name)?;
+
+        if self.is_clean() {
+            return writeln!(f, "  dependencies look consistent");
+        }
+
+        for dep in &self.unused_dependencies {
+            writeln!(f, "  unused dependency:           {}", dep)?;
+        }
+        for dep in &self.dependencies_only_used_in_tests {
+            writeln!(f, "  only used in tests:          {}", dep)?;
+        }
+        for dep in &self.unused_dev_dependencies {
+            writeln!(f, "  unused dev-dependency:       {}", dep)?;
+        }
+        for missing in &self.missing_dependencies {
+            let label = match missing.kind() {
+                DependencyKind::Normal => "missing dependency:          ",
+                DependencyKind::Dev    => "missing dev-dependency:      ",
+            };
+            writeln!(f, "  {}{}", label, missing.crate_name())?;
+        }
+        Ok(())
+    }
+}
+
+/// Per-crate usage reports for a whole workspace.
+#[derive(Serialize,Getters,Debug,Clone,Default)]
+#[getset(get="pub")]
+pub struct WorkspaceDependencyUsage {
+    crates: Vec<CrateDependencyUsage>,
+}
+
+impl WorkspaceDependencyUsage {
+
+    pub fn new(crates: Vec<CrateDependencyUsage>) -> Self {
+        Self { crates }
+    }
+
+    /// Only the crates that have something to report.
+    pub fn crates_with_findings(&self) -> impl Iterator<Item=&CrateDependencyUsage> {
+        self.crates.iter().filter(|c| !c.is_clean())
+    }
+}
+
+impl fmt::Display for WorkspaceDependencyUsage {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut any = false;
+        for usage in self.crates_with_findings() {
+            any = true;
+            write!(f, "{}", usage)?;
+        }
+        if !any {
+            writeln!(f, "all {} crates have consistent dependencies", self.crates.len())?;
+        }
+        Ok(())
+    }
+}
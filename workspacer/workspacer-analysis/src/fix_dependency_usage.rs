@@ -0,0 +1,139 @@
+// ---------------- [ File: workspacer-analysis/src/fix_dependency_usage.rs ]
+crate::ix!();
+
+/// Applies the safe half of a `CrateDependencyUsage` report to Cargo.toml:
+/// unused entries are removed, and test-only `[dependencies]` move to `[dev-dependencies]`.
+///
+/// Missing dependencies are never added automatically, since picking a version is a human decision.
+#[async_trait]
+pub trait FixDependencyUsage {
+    type Error;
+
+    /// Returns the Cargo.toml keys that were touched.
+    async fn fix_dependency_usage(&self, usage: &CrateDependencyUsage) -> Result<Vec<String>, Self::Error>;
+}
+
+#[async_trait]
+impl FixDependencyUsage for CrateHandle {
+    type Error = CrateError;
+
+    async fn fix_dependency_usage(&self, usage: &CrateDependencyUsage) -> Result<Vec<String>, Self::Error> {
+        let cargo_toml = self.cargo_toml();
+        let mut guard  = cargo_toml.lock().await;
+
+        let mut doc = guard.document_clone().await?;
+        let touched = apply_dependency_usage_fixes(&mut doc, usage);
+
+        if touched.is_empty() {
+            debug!("no dependency fixes needed for crate='{}'", usage.crate_name());
+            return Ok(touched);
+        }
+
+        info!("rewriting Cargo.toml for crate='{}': {:?}", usage.crate_name(), touched);
+        guard.write_document_back(&doc).await?;
+
+        Ok(touched)
+    }
+}
+
+/// Edits `doc` in place, preserving its formatting and comments.
+///
+/// Dependencies named inside macro token trees never reach this point:
+/// `classify_dependency_usage` keeps them out of the report, matching on
+/// each dependency's resolved library name.
+pub fn apply_dependency_usage_fixes(doc: &mut toml_edit::Document, usage: &CrateDependencyUsage) -> Vec<String> {
+    let mut touched = Vec::new();
+
+    for key in usage.unused_dependencies() {
+        if remove_dependency_entry(doc, DependencyKind::Normal, key).is_some() {
+            touched.push(key.clone());
+        }
+    }
+
+    for key in usage.unused_dev_dependencies() {
+        if remove_dependency_entry(doc, DependencyKind::Dev, key).is_some() {
+            touched.push(key.clone());
+        }
+    }
+
+    for key in usage.dependencies_only_used_in_tests() {
+        let Some(item) = remove_dependency_entry(doc, DependencyKind::Normal, key) else {
+            continue;
+        };
+
+        let dev_table_name = DependencyKind::Dev.table_name();
+        if doc.get(dev_table_name).is_none() {
+            doc[dev_table_name] = toml_edit::Item::Table(toml_edit::Table::new());
+        }
+        if let Some(dev) = doc.get_mut(dev_table_name).and_then(|i| i.as_table_like_mut()) {
+            dev.insert(key, item);
+            touched.push(key.clone());
+        }
+    }
+
+    touched
+}
+
+fn remove_dependency_entry(doc: &mut toml_edit::Document, kind: DependencyKind, key: &str) -> Option<toml_edit::Item> {
+    doc.get_mut(kind.table_name())
+        .and_then(|table| table.as_table_like_mut())
+        .and_then(|table| table.remove(key))
+}
+
+#[cfg(test)]
+mod test_apply_dependency_usage_fixes {
+    use super::*;
+
+    #[test]
+    fn removes_and_moves_entries_without_touching_the_rest() {
+        let original = r#"# keep me
+[package]
+name = "demo"
+
+[dependencies]
+regex = "1"    # unused
+serde = "1"
+tempfile = "3"
+
+[dependencies.indoc]
+version = "2"
+
+[dev-dependencies]
+quickcheck = "1"
+"#;
+        let mut doc: toml_edit::Document = original.parse().unwrap();
+
+        let usage = CrateDependencyUsageBuilder::default()
+            .crate_name("demo")
+            .unused_dependencies(vec!["regex".to_string(), "indoc".to_string()])
+            .dependencies_only_used_in_tests(vec!["tempfile".to_string()])
+            .unused_dev_dependencies(vec!["quickcheck".to_string()])
+            .build()
+            .unwrap();
+
+        let touched = apply_dependency_usage_fixes(&mut doc, &usage);
+        assert_eq!(touched.len(), 4);
+
+        let rendered = doc.to_string();
+        assert!(rendered.starts_with("# keep me"));
+        assert!(rendered.contains("serde = \"1\""));
+        assert!(!rendered.contains("regex"));
+        assert!(!rendered.contains("indoc"));
+        assert!(!rendered.contains("quickcheck"));
+
+        let reparsed: toml::Value = toml::from_str(&rendered).unwrap();
+        assert!(reparsed["dev-dependencies"].get("tempfile").is_some());
+        assert!(reparsed["dependencies"].get("tempfile").is_none());
+    }
+
+    #[test]
+    fn absent_keys_are_not_reported() {
+        let mut doc: toml_edit::Document = "[package]\nname = \"x\"\n".parse().unwrap();
+        let usage = CrateDependencyUsageBuilder::default()
+            .crate_name("x")
+            .unused_dependencies(vec!["nope".to_string()])
+            .build()
+            .unwrap();
+        assert!(apply_dependency_usage_fixes(&mut doc, &usage).is_empty());
+    }
+}
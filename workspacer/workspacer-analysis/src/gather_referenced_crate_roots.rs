@@ -0,0 +1,340 @@
+// ---------------- [ File: workspacer-analysis/src/gather_referenced_crate_roots.rs ]
+crate::ix!();
+
+/// Roots which can never name an external crate.
+const BUILTIN_PATH_ROOTS: &[&str] = &[
+    "std", "core", "alloc", "proc_macro", "test", "crate", "self", "super", "Self",
+];
+
+/// What one source file tells us about which crates it reaches for.
+#[derive(Default,Getters,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct ReferencedCrateRoots {
+
+    /// Leftmost segments of multi-segment paths, `use` trees and `extern crate` items.
+    roots: BTreeSet<String>,
+
+    /// Names declared locally (modules, items, `use .. as` renames), which shadow crate names.
+    local_names: BTreeSet<String>,
+
+    /// Crate roots re-exported with `pub use` (the `-3p`/facade pattern).
+    reexported_roots: BTreeSet<String>,
+
+    /// Every identifier inside a macro invocation or attribute token tree,
+    /// plus the names of invoked macros (`error_tree!`, `lazy_static!`).
+    /// Macro input is never parsed into paths, so a crate used only there
+    /// leaves nothing but these tokens behind.
+    macro_token_names: BTreeSet<String>,
+}
+
+impl ReferencedCrateRoots {
+
+    /// Parses `text` and collects the path roots it references.
+    ///
+    /// Only the leftmost segment of a *qualified* path counts (`tokio::fs`),
+    /// along with the top-level path of a `use` tree (`use serde_json;`).
+    /// Bare single-segment paths (`#[async_trait]`, `Regex::new`) are ignored,
+    /// since in this workspace they almost always arrive through a glob import
+    /// in `imports.rs` rather than naming a crate directly.
+    pub fn from_source_text(text: &str) -> Self {
+        let parse = SourceFile::parse(text, Edition::Edition2021);
+        let file  = parse.tree();
+
+        let mut out = Self::default();
+
+        for node in file.syntax().descendants() {
+
+            if let Some(path) = ast::Path::cast(node.clone()) {
+                if let Some(root) = top_level_root_of_path(&path) {
+                    out.roots.insert(root);
+                }
+                if let Some(name) = macro_call_name(&path) {
+                    out.macro_token_names.insert(name);
+                }
+                continue;
+            }
+
+            if let Some(token_tree) = ast::TokenTree::cast(node.clone()) {
+                // nested trees are covered by the outermost one
+                let nested = token_tree.syntax().parent().map(|p| ast::TokenTree::can_cast(p.kind())).unwrap_or(false);
+                if !nested {
+                    scan_token_tree(&token_tree, &mut out);
+                }
+                continue;
+            }
+
+            if let Some(extern_crate) = ast::ExternCrate::cast(node.clone()) {
+                if let Some(name_ref) = extern_crate.name_ref() {
+                    out.roots.insert(name_ref.text().to_string());
+                }
+                continue;
+            }
+
+            if let Some(use_item) = ast::Use::cast(node.clone()) {
+                let is_pub = use_item.visibility().map(|v| v.syntax().text().to_string() == "pub").unwrap_or(false);
+                if is_pub {
+                    if let Some(root) = use_item.use_tree().and_then(|t| t.path()).and_then(|p| leftmost_segment_name(&p)) {
+                        out.reexported_roots.insert(root);
+                    }
+                }
+                continue;
+            }
+
+            if let Some(rename) = ast::Rename::cast(node.clone()) {
+                if let Some(name) = rename.name() {
+                    out.local_names.insert(name.text().to_string());
+                }
+                continue;
+            }
+
+            if let Some(name) = declared_item_name(&node) {
+                out.local_names.insert(name);
+            }
+        }
+
+        for builtin in BUILTIN_PATH_ROOTS {
+            out.roots.remove(*builtin);
+        }
+
+        out
+    }
+
+    /// Folds another file's findings into this one.
+    pub fn merge(&mut self, other: ReferencedCrateRoots) {
+        self.roots.extend(other.roots);
+        self.local_names.extend(other.local_names);
+        self.reexported_roots.extend(other.reexported_roots);
+        self.macro_token_names.extend(other.macro_token_names);
+    }
+
+    /// Roots that are not shadowed by something declared in the crate itself.
+    pub fn external_roots(&self) -> BTreeSet<String> {
+        self.roots
+            .iter()
+            .filter(|r| !self.local_names.contains(*r))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Reads and parses every file, merging the results.
+pub async fn gather_referenced_crate_roots(files: &[PathBuf])
+    -> Result<ReferencedCrateRoots, CrateError>
+{
+    let mut merged = ReferencedCrateRoots::default();
+
+    for file in files {
+        let text = tokio::fs::read_to_string(file).await.map_err(|io_err| {
+            error!("failed to read source file {:?}: {:?}", file, io_err);
+            CrateError::IoError {
+                io_error: Arc::new(io_err),
+                context:  format!("reading {:?} for dependency usage analysis", file),
+            }
+        })?;
+        merged.merge(ReferencedCrateRoots::from_source_text(&text));
+    }
+
+    Ok(merged)
+}
+
+fn leftmost_segment_name(path: &ast::Path) -> Option<String> {
+    let mut current = path.clone();
+    while let Some(q) = current.qualifier() {
+        current = q;
+    }
+    current
+        .segment()
+        .and_then(|s| s.name_ref())
+        .map(|n| n.text().to_string())
+}
+
+/// Returns the root name if `path` is the leftmost segment of something that can name a crate.
+fn top_level_root_of_path(path: &ast::Path) -> Option<String> {
+    if path.qualifier().is_some() {
+        return None;
+    }
+
+    // climb to the full path we are the leftmost segment of
+    let mut full = path.syntax().clone();
+    let mut qualifies = false;
+    while let Some(parent) = full.parent().filter(|p| ast::Path::can_cast(p.kind())) {
+        full = parent;
+        qualifies = true;
+    }
+    let parent = full.parent()?;
+
+    let counts = if let Some(use_tree) = ast::UseTree::cast(parent) {
+        // only the outermost use tree names a crate; nested trees are relative to it
+        use_tree
+            .syntax()
+            .parent()
+            .map(|p| !ast::UseTreeList::can_cast(p.kind()))
+            .unwrap_or(true)
+    } else {
+        // a multi-segment path outside a `use`
+        qualifies
+    };
+
+    if !counts {
+        return None;
+    }
+
+    path.segment()
+        .and_then(|s| s.name_ref())
+        .map(|n| n.text().to_string())
+}
+
+/// The name of an invoked macro, when `path` is the whole path of a macro call.
+fn macro_call_name(path: &ast::Path) -> Option<String> {
+    if path.qualifier().is_some() || !ast::MacroCall::can_cast(path.syntax().parent()?.kind()) {
+        return None;
+    }
+    path.segment()
+        .and_then(|s| s.name_ref())
+        .map(|n| n.text().to_string())
+}
+
+/// Records every identifier in `token_tree`, and as a root each identifier
+/// that starts a `a::b` path (not one following `::` or a `$` metavariable).
+fn scan_token_tree(token_tree: &ast::TokenTree, out: &mut ReferencedCrateRoots) {
+    let tokens: Vec<SyntaxToken> = token_tree
+        .syntax()
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter(|token| !token.kind().is_trivia())
+        .collect();
+
+    let kind_at = |i: usize| tokens.get(i).map(|t| t.kind());
+
+    // inside token trees `::` may come as one COLON2 or as two COLONs
+    let path_sep_after = |i: usize| {
+        kind_at(i + 1) == Some(SyntaxKind::COLON2)
+            || (kind_at(i + 1) == Some(SyntaxKind::COLON) && kind_at(i + 2) == Some(SyntaxKind::COLON))
+    };
+    let path_sep_before = |i: usize| {
+        i >= 1 && (kind_at(i - 1) == Some(SyntaxKind::COLON2)
+            || (i >= 2 && kind_at(i - 1) == Some(SyntaxKind::COLON) && kind_at(i - 2) == Some(SyntaxKind::COLON)))
+    };
+
+    for (i, token) in tokens.iter().enumerate() {
+        if token.kind() != SyntaxKind::IDENT {
+            continue;
+        }
+        let name = token.text().to_string();
+
+        let metavariable = i >= 1 && kind_at(i - 1) == Some(SyntaxKind::DOLLAR);
+        if path_sep_after(i) && !path_sep_before(i) && !metavariable {
+            out.roots.insert(name.clone());
+        }
+        out.macro_token_names.insert(name);
+    }
+}
+
+fn declared_item_name(node: &SyntaxNode) -> Option<String> {
+    fn name_of<N: HasName>(n: N) -> Option<String> {
+        n.name().map(|x| x.text().to_string())
+    }
+
+    if let Some(n) = ast::Module::cast(node.clone())     { return name_of(n); }
+    if let Some(n) = ast::Struct::cast(node.clone())     { return name_of(n); }
+    if let Some(n) = ast::Enum::cast(node.clone())       { return name_of(n); }
+    if let Some(n) = ast::Union::cast(node.clone())      { return name_of(n); }
+    if let Some(n) = ast::Trait::cast(node.clone())      { return name_of(n); }
+    if let Some(n) = ast::Fn::cast(node.clone())         { return name_of(n); }
+    if let Some(n) = ast::TypeAlias::cast(node.clone())  { return name_of(n); }
+    if let Some(n) = ast::Const::cast(node.clone())      { return name_of(n); }
+    if let Some(n) = ast::Static::cast(node.clone())     { return name_of(n); }
+    if let Some(n) = ast::MacroRules::cast(node.clone()) { return name_of(n); }
+    None
+}
+
+#[cfg(test)]
+mod test_gather_referenced_crate_roots {
+    use super::*;
+
+    #[test]
+    fn qualified_paths_and_use_roots_are_collected() {
+        let src = r#"
+            use serde_json;
+            use regex::{Regex, RegexBuilder};
+            fn f() { let _ = tokio::fs::read("x"); }
+        "#;
+        let r = ReferencedCrateRoots::from_source_text(src);
+        assert!(r.roots().contains("serde_json"));
+        assert!(r.roots().contains("regex"));
+        assert!(r.roots().contains("tokio"));
+    }
+
+    #[test]
+    fn single_segment_paths_and_builtins_are_ignored() {
+        let src = r#"
+            use std::path::PathBuf;
+            #[async_trait]
+            impl Foo for Bar {}
+            fn g() { let _ = Regex::new("a"); crate::h(); }
+        "#;
+        let r = ReferencedCrateRoots::from_source_text(src);
+        assert!(!r.roots().contains("async_trait"));
+        assert!(!r.roots().contains("std"));
+        assert!(!r.roots().contains("crate"));
+    }
+
+    #[test]
+    fn crates_used_only_inside_macros_are_seen() {
+        let src = r#"
+            error_tree!{
+                pub enum E { Io(std::io::Error), Parse(serde_json::Error) }
+            }
+            lazy_static!{ static ref RE: regex::Regex = regex::Regex::new("a").unwrap(); }
+            fn f() { info!("{:?}", chrono::Utc::now()); }
+            macro_rules! m { ($x:ident) => { $x::go() } }
+        "#;
+        let r = ReferencedCrateRoots::from_source_text(src);
+        assert!(r.roots().contains("serde_json"));
+        assert!(r.roots().contains("regex"));
+        assert!(r.roots().contains("chrono"));
+        assert!(!r.roots().contains("Regex"));
+        assert!(!r.roots().contains("Utc"));
+        assert!(!r.roots().contains("x"));
+        assert!(r.macro_token_names().contains("error_tree"));
+        assert!(r.macro_token_names().contains("lazy_static"));
+        assert!(r.macro_token_names().contains("info"));
+    }
+
+    #[test]
+    fn nested_use_trees_do_not_produce_roots() {
+        let src = "use workspacer_3p::{tokio::fs, regex::Regex};";
+        let r = ReferencedCrateRoots::from_source_text(src);
+        assert_eq!(r.roots().iter().cloned().collect::<Vec<_>>(), vec!["workspacer_3p".to_string()]);
+    }
+
+    #[test]
+    fn local_modules_shadow_roots() {
+        let src = r#"
+            mod regex { pub fn go() {} }
+            fn f() { regex::go(); }
+        "#;
+        let r = ReferencedCrateRoots::from_source_text(src);
+        assert!(r.roots().contains("regex"));
+        assert!(!r.external_roots().contains("regex"));
+    }
+
+    #[test]
+    fn pub_use_marks_reexports() {
+        let src = r#"
+            pub use tokio;
+            pub use serde_json::Value;
+            pub(crate) use regex::*;
+        "#;
+        let r = ReferencedCrateRoots::from_source_text(src);
+        assert!(r.reexported_roots().contains("tokio"));
+        assert!(r.reexported_roots().contains("serde_json"));
+        assert!(!r.reexported_roots().contains("regex"));
+    }
+
+    #[test]
+    fn extern_crate_counts_as_a_root() {
+        let r = ReferencedCrateRoots::from_source_text("extern crate libc;");
+        assert!(r.roots().contains("libc"));
+    }
+}
@@ -1,7 +1,12 @@
 // ---------------- [ File: workspacer-analysis/src/imports.rs ]
 pub(crate) use get_file_size::*;
 pub(crate) use workspacer_3p::*;
+pub(crate) use workspacer_crate::*;
 pub(crate) use workspacer_crate_interface::*;
 pub(crate) use workspacer_errors::*;
+pub(crate) use workspacer_lock::*;
+pub(crate) use workspacer_metadata::*;
+pub(crate) use workspacer_toml_interface::*;
 pub(crate) use workspacer_workspace::*;
 pub(crate) use workspacer_workspace_interface::*;
+pub(crate) use serde_derive::Serialize;
@@ -3,3 +3,8 @@
 
 x!{workspace_analysis}
 x!{crate_analysis}
+x!{declared_dependencies}
+x!{gather_referenced_crate_roots}
+x!{dependency_usage_report}
+x!{analyze_dependency_usage}
+x!{fix_dependency_usage}
@@ -37,7 +37,8 @@ where
             // we can call `CrateAnalysis::new(&*crate_handle)`.
             // The `&*crate_handle` turns the `Arc<Mutex<H>>` reference into something
             // that implements `HasTestsDirectory + GetTestFiles + GetSourceFilesWithExclusions`.
-            let crate_analysis = CrateAnalysis::new(&*crate_handle).await?;
+            let crate_name     = crate_handle.lock().await.name().to_string();
+            let crate_analysis = CrateAnalysis::new(&*crate_handle).await?.with_crate_name(crate_name);
             builder.add_crate_analysis(crate_analysis);
         }
 
@@ -45,7 +46,7 @@ where
     }
 }
 
-#[derive(Debug,Clone)]
+#[derive(Serialize,Debug,Clone)]
 pub struct WorkspaceSizeAnalysis {
     crate_analyses: Vec<CrateAnalysis>, // Collection of crate analyses
 
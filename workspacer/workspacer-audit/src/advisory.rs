@@ -0,0 +1,127 @@
+// ---------------- [ File: workspacer-audit/src/advisory.rs ]
+crate::ix!();
+
+/// One RustSec advisory: the ```` ```toml ```` front matter of `crates/<name>/<id>.md` plus the
+/// markdown title under it.
+#[derive(Getters,Debug,Clone,PartialEq)]
+#[getset(get="pub")]
+pub struct Advisory {
+    id:            String,
+    package:       String,
+    title:         String,
+
+    /// `unmaintained`, `unsound`, `notice`; `None` for a vulnerability
+    informational: Option<String>,
+    withdrawn:     bool,
+    patched:       Vec<semver::VersionReq>,
+    unaffected:    Vec<semver::VersionReq>,
+}
+
+#[derive(Deserialize)]
+struct AdvisoryFrontMatter {
+    advisory: AdvisoryTable,
+    #[serde(default)]
+    versions: AdvisoryVersions,
+}
+
+#[derive(Deserialize)]
+struct AdvisoryTable {
+    id:            String,
+    package:       String,
+    informational: Option<String>,
+    withdrawn:     Option<toml::Value>,
+}
+
+#[derive(Deserialize,Default)]
+struct AdvisoryVersions {
+    #[serde(default)]
+    patched:    Vec<String>,
+    #[serde(default)]
+    unaffected: Vec<String>,
+}
+
+impl Advisory {
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let start = text.find("```toml").ok_or("no ```toml front matter")?;
+        let body  = &text[start + "```toml".len()..];
+        let end   = body.find("```").ok_or("unterminated ```toml front matter")?;
+
+        let front: AdvisoryFrontMatter = toml::from_str(&body[..end]).map_err(|e| e.to_string())?;
+        let title = body[end + 3..]
+            .lines()
+            .find_map(|l| l.trim().strip_prefix("# "))
+            .unwrap_or_default()
+            .trim()
+            .to_string();
+
+        let reqs = |v: &[String]| -> Result<Vec<semver::VersionReq>, String> {
+            v.iter().map(|r| semver::VersionReq::parse(r).map_err(|e| format!("version requirement '{}': {}", r, e))).collect()
+        };
+
+        Ok(Self {
+            id:            front.advisory.id,
+            package:       front.advisory.package,
+            title,
+            informational: front.advisory.informational,
+            withdrawn:     front.advisory.withdrawn.is_some(),
+            patched:       reqs(&front.versions.patched)?,
+            unaffected:    reqs(&front.versions.unaffected)?,
+        })
+    }
+
+    /// Whether `version` is neither patched nor unaffected. A withdrawn advisory affects nothing.
+    pub fn affects(&self, version: &SemverVersion) -> bool {
+        !self.withdrawn
+            && !self.patched.iter().any(|r| r.matches(version))
+            && !self.unaffected.iter().any(|r| r.matches(version))
+    }
+
+    pub fn is_vulnerability(&self) -> bool {
+        self.informational.is_none()
+    }
+}
+
+#[cfg(test)]
+mod test_advisory {
+    use super::*;
+
+    const ADVISORY: &str = r#"```toml
+[advisory]
+id = "RUSTSEC-2020-0071"
+package = "time"
+date = 2020-11-18
+
+[versions]
+patched = [">= 0.2.23"]
+unaffected = ["= 0.2.0", "= 0.2.1", "= 0.2.2", "= 0.2.3", "= 0.2.4", "= 0.2.5", "= 0.2.6"]
+```
+
+# Potential segfault in the time crate
+
+Unix-like operating systems may segfault ...
+"#;
+
+    #[traced_test]
+    fn parses_front_matter_and_matches_versions() {
+        let advisory = Advisory::parse(ADVISORY).unwrap();
+        assert_eq!(advisory.id(), "RUSTSEC-2020-0071");
+        assert_eq!(advisory.package(), "time");
+        assert_eq!(advisory.title(), "Potential segfault in the time crate");
+        assert!(advisory.is_vulnerability());
+
+        let v = |s: &str| SemverVersion::parse(s).unwrap();
+        assert!(advisory.affects(&v("0.1.45")));
+        assert!(advisory.affects(&v("0.2.22")));
+        assert!(!advisory.affects(&v("0.2.3")));
+        assert!(!advisory.affects(&v("0.3.0")));
+    }
+
+    #[traced_test]
+    fn withdrawn_advisories_affect_nothing() {
+        let text = "```toml\n[advisory]\nid = \"RUSTSEC-2099-0001\"\npackage = \"x\"\nwithdrawn = 2099-01-02\n```\n# Never mind\n";
+        let advisory = Advisory::parse(text).unwrap();
+        assert!(!advisory.affects(&SemverVersion::parse("1.0.0").unwrap()));
+        assert!(Advisory::parse("# no front matter").is_err());
+    }
+}
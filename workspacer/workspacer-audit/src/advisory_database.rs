@@ -0,0 +1,78 @@
+// ---------------- [ File: workspacer-audit/src/advisory_database.rs ]
+crate::ix!();
+
+/// A checkout of the RustSec advisory database (`github.com/rustsec/advisory-db`), read
+/// straight from disk: `crates/<package>/<id>.md`.
+#[derive(Getters,Debug,Clone)]
+#[getset(get="pub")]
+pub struct AdvisoryDatabase {
+    root: PathBuf,
+}
+
+impl AdvisoryDatabase {
+
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Every advisory filed against `package`, sorted by id.
+    pub async fn advisories_for(&self, package: &str) -> Result<Vec<Advisory>, AuditError> {
+        let dir = self.root.join("crates").join(package);
+        let mut entries = match tokio::fs::read_dir(&dir).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(AuditError::UnreadableSnapshot { path: dir, io: Arc::new(e) }),
+        };
+
+        let mut advisories = vec![];
+        loop {
+            let entry = match entries.next_entry().await {
+                Ok(Some(entry)) => entry,
+                Ok(None)        => break,
+                Err(e)          => return Err(AuditError::UnreadableSnapshot { path: dir, io: Arc::new(e) }),
+            };
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("md") {
+                continue;
+            }
+
+            let text = tokio::fs::read_to_string(&path).await.map_err(|e| {
+                AuditError::UnreadableSnapshot { path: path.clone(), io: Arc::new(e) }
+            })?;
+            let advisory = Advisory::parse(&text).map_err(|message| {
+                AuditError::MalformedAdvisory { path: path.clone(), message }
+            })?;
+            advisories.push(advisory);
+        }
+
+        advisories.sort_by(|a, b| a.id().cmp(b.id()));
+        Ok(advisories)
+    }
+}
+
+#[cfg(test)]
+mod test_advisory_database {
+    use super::*;
+
+    #[traced_test]
+    async fn reads_one_crates_advisories() {
+        let tmp = tempdir().unwrap();
+        let dir = tmp.path().join("crates/smallvec");
+        tokio::fs::create_dir_all(&dir).await.unwrap();
+        for (id, patched) in [("RUSTSEC-2021-0003", ">= 1.6.1"), ("RUSTSEC-2019-0009", ">= 0.6.10")] {
+            tokio::fs::write(
+                dir.join(format!("{}.md", id)),
+                format!("```toml\n[advisory]\nid = \"{}\"\npackage = \"smallvec\"\n\n[versions]\npatched = [\"{}\"]\n```\n\n# Buffer overflow\n", id, patched),
+            ).await.unwrap();
+        }
+        tokio::fs::write(dir.join("README.txt"), "not an advisory").await.unwrap();
+
+        let db = AdvisoryDatabase::new(tmp.path());
+        let ids: Vec<String> = db.advisories_for("smallvec").await.unwrap().iter().map(|a| a.id().clone()).collect();
+        assert_eq!(ids, vec!["RUSTSEC-2019-0009", "RUSTSEC-2021-0003"]);
+        assert!(db.advisories_for("serde").await.unwrap().is_empty());
+
+        tokio::fs::write(dir.join("RUSTSEC-2000-0000.md"), "garbage").await.unwrap();
+        assert!(matches!(db.advisories_for("smallvec").await, Err(AuditError::MalformedAdvisory { .. })));
+    }
+}
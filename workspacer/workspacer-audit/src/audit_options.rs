@@ -0,0 +1,61 @@
+// ---------------- [ File: workspacer-audit/src/audit_options.rs ]
+crate::ix!();
+
+/// What `ws audit` checks against. Every snapshot is optional: a check whose snapshot is not
+/// given is skipped and named in the report, never fetched.
+#[derive(Builder,Getters,Debug,Clone,Default)]
+#[getset(get="pub")]
+#[builder(setter(into), default)]
+pub struct AuditOptions {
+    policy:            LicensePolicy,
+    ignore_advisories: Vec<String>,
+
+    /// A RustSec advisory-db checkout
+    advisory_db:       Option<PathBuf>,
+
+    /// A crates.io index copy
+    index:             Option<PathBuf>,
+
+    /// Unpacked crate sources; empty means cargo's registry cache
+    sources:           Vec<PathBuf>,
+}
+
+impl AuditOptionsBuilder {
+
+    /// Starts from the `[audit]` settings, resolving their relative paths against the
+    /// workspace root.
+    pub fn from_settings(settings: &AuditSettings, workspace_root: &Path) -> Self {
+        let resolve = |p: &PathBuf| workspace_root.join(p);
+
+        let mut builder = Self::default();
+        builder
+            .policy(LicensePolicy::new(settings.allow_licenses().clone(), settings.deny_licenses().clone()))
+            .ignore_advisories(settings.ignore_advisories().clone())
+            .advisory_db(settings.advisory_db().as_ref().map(resolve))
+            .index(settings.index().as_ref().map(resolve))
+            .sources(settings.sources().iter().map(resolve).collect::<Vec<_>>());
+        builder
+    }
+}
+
+#[cfg(test)]
+mod test_audit_options {
+    use super::*;
+
+    #[traced_test]
+    fn settings_paths_resolve_against_the_workspace() {
+        let settings = WorkspaceSettings::from_workspacer_toml(
+            "[audit]\ndeny-licenses = [\"GPL-3.0-only\"]\nadvisory-db = \"snapshots/advisory-db\"\nindex = \"/srv/crates.io-index\"\n",
+        ).unwrap();
+
+        let options = AuditOptionsBuilder::from_settings(settings.audit(), Path::new("/ws"))
+            .index(Some(PathBuf::from("/elsewhere")))
+            .build()
+            .unwrap();
+
+        assert_eq!(options.advisory_db(), &Some(PathBuf::from("/ws/snapshots/advisory-db")));
+        assert_eq!(options.index(), &Some(PathBuf::from("/elsewhere")));
+        assert!(options.policy().denies("GPL-3.0-only"));
+        assert!(options.sources().is_empty());
+    }
+}
@@ -0,0 +1,189 @@
+// ---------------- [ File: workspacer-audit/src/audit_report.rs ]
+crate::ix!();
+
+/// One third-party package of Cargo.lock and how its license fares against the policy.
+#[derive(Builder,Getters,Serialize,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+#[builder(setter(into))]
+pub struct ThirdPartyLicense {
+    package: LockedPackage,
+
+    #[builder(default)]
+    license: Option<String>,
+
+    /// Set when there is no `license` expression to judge
+    #[builder(default)]
+    license_file: Option<String>,
+
+    verdict: LicenseVerdict,
+
+    /// The member crates depending on it, directly or not
+    #[builder(default)]
+    used_by: Vec<String>,
+}
+
+/// One member crate's third-party dependencies, grouped by license expression (`unknown` when
+/// the sources were not found or only carry a license file).
+#[derive(Getters,Serialize,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct MemberLicenseInventory {
+    crate_name: String,
+    licenses:   BTreeMap<String, Vec<String>>,
+}
+
+impl MemberLicenseInventory {
+    pub fn new(crate_name: impl Into<String>, licenses: BTreeMap<String, Vec<String>>) -> Self {
+        Self { crate_name: crate_name.into(), licenses }
+    }
+}
+
+/// A RustSec advisory matching a locked package version.
+#[derive(Builder,Getters,Serialize,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+#[builder(setter(into))]
+pub struct AdvisoryMatch {
+    id:      String,
+    package: LockedPackage,
+    title:   String,
+
+    /// `unmaintained`, `unsound`, `notice`: reported, but not a problem
+    #[builder(default)]
+    informational: Option<String>,
+
+    /// Listed in `[audit] ignore-advisories`
+    #[builder(default)]
+    ignored: bool,
+}
+
+impl AdvisoryMatch {
+    pub fn is_problem(&self) -> bool {
+        self.informational.is_none() && !self.ignored
+    }
+}
+
+impl fmt::Display for AdvisoryMatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}: {}", self.id, self.package, self.title)?;
+        if let Some(kind) = &self.informational {
+            write!(f, " ({})", kind)?;
+        }
+        if self.ignored {
+            write!(f, " (ignored)")?;
+        }
+        Ok(())
+    }
+}
+
+/// `ws audit`: licenses, advisories, yanked versions and member metadata, all from local
+/// snapshots.
+#[derive(Getters,Serialize,Debug,Clone,Default,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct AuditReport {
+    licenses:       Vec<ThirdPartyLicense>,
+    inventory:      Vec<MemberLicenseInventory>,
+    advisories:     Vec<AdvisoryMatch>,
+    yanked:         Vec<LockedPackage>,
+    metadata:       Vec<MetadataFinding>,
+
+    /// Checks that had no snapshot to run against
+    skipped_checks: Vec<String>,
+}
+
+impl AuditReport {
+
+    pub fn new(
+        licenses:       Vec<ThirdPartyLicense>,
+        inventory:      Vec<MemberLicenseInventory>,
+        advisories:     Vec<AdvisoryMatch>,
+        yanked:         Vec<LockedPackage>,
+        metadata:       Vec<MetadataFinding>,
+        skipped_checks: Vec<String>,
+    ) -> Self {
+        Self { licenses, inventory, advisories, yanked, metadata, skipped_checks }
+    }
+
+    pub fn license_problems(&self) -> impl Iterator<Item=&ThirdPartyLicense> {
+        self.licenses.iter().filter(|l| !l.verdict.is_allowed())
+    }
+
+    /// Rejected or unknown licenses, unignored vulnerabilities, yanked versions and metadata
+    /// findings.
+    pub fn problem_count(&self) -> usize {
+        self.license_problems().count()
+            + self.advisories.iter().filter(|a| a.is_problem()).count()
+            + self.yanked.len()
+            + self.metadata.len()
+    }
+}
+
+impl fmt::Display for AuditReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for member in &self.inventory {
+            writeln!(f, "{}", member.crate_name)?;
+            for (license, packages) in &member.licenses {
+                writeln!(f, "    {:<28} {}", license, packages.join(", "))?;
+            }
+        }
+
+        let sections: [(&str, Vec<String>); 4] = [
+            ("licenses", self.license_problems().map(|l| format!("{}: {}", l.package, l.verdict)).collect()),
+            ("advisories", self.advisories.iter().map(|a| a.to_string()).collect()),
+            ("yanked", self.yanked.iter().map(|p| p.to_string()).collect()),
+            ("metadata", self.metadata.iter().map(|m| m.to_string()).collect()),
+        ];
+        for (title, lines) in sections.iter().filter(|(_, lines)| !lines.is_empty()) {
+            writeln!(f, "{}:", title)?;
+            for line in lines {
+                writeln!(f, "  - {}", line)?;
+            }
+        }
+
+        for check in &self.skipped_checks {
+            writeln!(f, "skipped: {}", check)?;
+        }
+
+        match self.problem_count() {
+            0 => writeln!(f, "no problems in {} third-party packages", self.licenses.len()),
+            n => writeln!(f, "{} problems in {} third-party packages", n, self.licenses.len()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_audit_report {
+    use super::*;
+
+    #[traced_test]
+    fn counts_problems_but_not_informational_or_ignored_advisories() {
+        let package = |name: &str| LockedPackage::new(name, SemverVersion::parse("1.0.0").unwrap(), Some("registry+https://github.com/rust-lang/crates.io-index".into()));
+        let advisory = |id: &str, informational: Option<&str>, ignored: bool| {
+            AdvisoryMatchBuilder::default()
+                .id(id)
+                .package(package("a"))
+                .title("t")
+                .informational(informational.map(str::to_string))
+                .ignored(ignored)
+                .build()
+                .unwrap()
+        };
+
+        let report = AuditReport::new(
+            vec![
+                ThirdPartyLicenseBuilder::default().package(package("a")).license(Some("MIT".to_string())).verdict(LicenseVerdict::Allowed).build().unwrap(),
+                ThirdPartyLicenseBuilder::default().package(package("b")).verdict(LicenseVerdict::Unknown).build().unwrap(),
+            ],
+            vec![],
+            vec![advisory("RUSTSEC-1", None, false), advisory("RUSTSEC-2", Some("unmaintained"), false), advisory("RUSTSEC-3", None, true)],
+            vec![package("c")],
+            vec![],
+            vec!["yanked versions (no crates.io index snapshot)".into()],
+        );
+
+        assert_eq!(report.problem_count(), 3);
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["licenses"][1]["verdict"]["verdict"], "unknown");
+        assert_eq!(json["yanked"][0]["name"], "c");
+        assert!(report.to_string().ends_with("3 problems in 2 third-party packages\n"));
+    }
+}
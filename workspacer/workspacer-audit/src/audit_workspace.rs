@@ -0,0 +1,149 @@
+// ---------------- [ File: workspacer-audit/src/audit_workspace.rs ]
+crate::ix!();
+
+/// `ws audit`: reads Cargo.lock and checks every third-party package the members pull in
+/// against the license policy and whichever snapshots `options` names. Nothing touches the
+/// network.
+///
+/// Findings go into the report; an `Err` means the audit itself could not run.
+#[async_trait]
+pub trait AuditWorkspace {
+    async fn audit(&self, options: &AuditOptions) -> Result<AuditReport, WorkspaceError>;
+}
+
+async fn read_manifest(path: &Path) -> Result<toml::Value, WorkspaceError> {
+    let text = tokio::fs::read_to_string(path).await.map_err(|io| WorkspaceError::IoError {
+        io_error: Arc::new(io),
+        context:  format!("reading {:?} for the audit", path),
+    })?;
+    toml::from_str(&text).map_err(|e| {
+        CrateError::CargoTomlError(CargoTomlError::TomlParseError {
+            cargo_toml_file:  path.to_path_buf(),
+            toml_parse_error: e,
+        }).into()
+    })
+}
+
+#[async_trait]
+impl<P,H> AuditWorkspace for Workspace<P,H>
+where
+    for<'async_trait> P: From<PathBuf> + AsRef<Path> + Send + Sync + 'async_trait,
+    H: CrateHandleInterface<P> + Send + Sync,
+{
+    async fn audit(&self, options: &AuditOptions) -> Result<AuditReport, WorkspaceError> {
+        let root     = self.as_ref().to_path_buf();
+        let graph    = LockfileGraph::from_lockfile(&read_lockfile(&root).await?);
+        let root_toml = read_manifest(&root.join("Cargo.toml")).await?;
+        let workspace_package = root_toml.get("workspace").and_then(|w| w.get("package"));
+
+        // 1) who uses what
+        let mut members  = vec![];
+        let mut closures = vec![];
+        let mut used_by: BTreeMap<LockedPackage, Vec<String>> = BTreeMap::new();
+        for crate_handle in self {
+            let guard      = crate_handle.lock().await;
+            let crate_name = guard.name().to_string();
+            let manifest   = read_manifest(&guard.root_dir_path_buf().join("Cargo.toml")).await?;
+            members.push(MemberMetadata::from_manifest(&crate_name, &manifest, workspace_package));
+
+            let closure = match graph.find(&crate_name) {
+                Some(locked) => graph.third_party_closure(locked),
+                None => {
+                    warn!("crate '{}' is not in Cargo.lock; run `cargo generate-lockfile`", crate_name);
+                    BTreeSet::new()
+                }
+            };
+            for package in &closure {
+                used_by.entry(package.clone()).or_default().push(crate_name.clone());
+            }
+            closures.push((crate_name, closure));
+        }
+
+        // 2) licenses
+        let sources = if options.sources().is_empty() {
+            CrateSources::cargo_registry_cache().await
+        } else {
+            CrateSources::new(options.sources().clone())
+        };
+
+        let mut licenses  = vec![];
+        let mut by_package: BTreeMap<&LockedPackage, String> = BTreeMap::new();
+        for (package, users) in &used_by {
+            let found   = sources.license_of(package).await?.unwrap_or_default();
+            let verdict = options.policy().judge(found.license().as_deref());
+            by_package.insert(package, found.license().clone().unwrap_or_else(|| "unknown".to_string()));
+
+            licenses.push(
+                ThirdPartyLicenseBuilder::default()
+                    .package(package.clone())
+                    .license(found.license().clone())
+                    .license_file(found.license_file().clone())
+                    .verdict(verdict)
+                    .used_by(users.clone())
+                    .build()
+                    .unwrap(),
+            );
+        }
+
+        let inventory = closures
+            .iter()
+            .map(|(crate_name, closure)| {
+                let mut grouped: BTreeMap<String, Vec<String>> = BTreeMap::new();
+                for package in closure {
+                    grouped.entry(by_package[package].clone()).or_default().push(package.to_string());
+                }
+                MemberLicenseInventory::new(crate_name.clone(), grouped)
+            })
+            .collect();
+
+        let mut skipped_checks = vec![];
+        let from_crates_io: Vec<&LockedPackage> = used_by.keys().filter(|p| p.is_from_crates_io()).collect();
+
+        // 3) advisories
+        let mut advisories = vec![];
+        match options.advisory_db() {
+            Some(path) => {
+                let db = AdvisoryDatabase::new(path);
+                for package in &from_crates_io {
+                    for advisory in db.advisories_for(package.name()).await? {
+                        if !advisory.affects(package.version()) {
+                            continue;
+                        }
+                        advisories.push(
+                            AdvisoryMatchBuilder::default()
+                                .id(advisory.id().clone())
+                                .package((*package).clone())
+                                .title(advisory.title().clone())
+                                .informational(advisory.informational().clone())
+                                .ignored(options.ignore_advisories().contains(advisory.id()))
+                                .build()
+                                .unwrap(),
+                        );
+                    }
+                }
+            }
+            None => skipped_checks.push("advisories (no advisory-db snapshot given)".to_string()),
+        }
+
+        // 4) yanked versions
+        let mut yanked = vec![];
+        match options.index() {
+            Some(path) => {
+                let index = CratesIndexSnapshot::new(path);
+                for package in &from_crates_io {
+                    if index.is_yanked(package.name(), package.version()).await? == Some(true) {
+                        yanked.push((*package).clone());
+                    }
+                }
+            }
+            None => skipped_checks.push("yanked versions (no crates.io index snapshot given)".to_string()),
+        }
+
+        // 5) our own metadata
+        let metadata = metadata_findings(&members);
+
+        let report = AuditReport::new(licenses, inventory, advisories, yanked, metadata, skipped_checks);
+        info!("audit: {} third-party packages, {} problems", report.licenses().len(), report.problem_count());
+        Ok(report)
+    }
+}
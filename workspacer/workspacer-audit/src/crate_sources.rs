@@ -0,0 +1,122 @@
+// ---------------- [ File: workspacer-audit/src/crate_sources.rs ]
+crate::ix!();
+
+/// The `license` and `license-file` fields of a third-party crate's manifest.
+#[derive(Getters,Serialize,Debug,Clone,Default,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct PackageLicense {
+    license:      Option<String>,
+    license_file: Option<String>,
+}
+
+impl PackageLicense {
+    pub fn new(license: Option<String>, license_file: Option<String>) -> Self {
+        Self { license, license_file }
+    }
+}
+
+/// Directories of unpacked crate sources, searched for third-party manifests: cargo's registry
+/// cache (`<name>-<version>/`) or a `cargo vendor` directory (`<name>/` or
+/// `<name>-<version>/`).
+#[derive(Getters,Debug,Clone,Default)]
+#[getset(get="pub")]
+pub struct CrateSources {
+    dirs: Vec<PathBuf>,
+}
+
+impl CrateSources {
+
+    pub fn new(dirs: Vec<PathBuf>) -> Self {
+        Self { dirs }
+    }
+
+    /// Every `$CARGO_HOME/registry/src/<registry>/` directory (`~/.cargo` without `CARGO_HOME`).
+    pub async fn cargo_registry_cache() -> Self {
+        let cargo_home = std::env::var_os("CARGO_HOME")
+            .map(PathBuf::from)
+            .or_else(|| dirs::home_dir().map(|h| h.join(".cargo")));
+
+        let mut dirs = vec![];
+        if let Some(src) = cargo_home.map(|h| h.join("registry").join("src")) {
+            if let Ok(mut entries) = tokio::fs::read_dir(&src).await {
+                while let Ok(Some(entry)) = entries.next_entry().await {
+                    if entry.path().is_dir() {
+                        dirs.push(entry.path());
+                    }
+                }
+            }
+        }
+        dirs.sort();
+        debug!("crate sources from the registry cache: {:?}", dirs);
+        Self { dirs }
+    }
+
+    /// The license fields of `package`, or `None` when no directory holds its sources.
+    pub async fn license_of(&self, package: &LockedPackage) -> Result<Option<PackageLicense>, AuditError> {
+        let versioned = format!("{}-{}", package.name(), package.version());
+
+        for dir in &self.dirs {
+            for candidate in [dir.join(&versioned), dir.join(package.name())] {
+                let manifest = candidate.join("Cargo.toml");
+                let text = match tokio::fs::read_to_string(&manifest).await {
+                    Ok(text) => text,
+                    Err(e) if e.kind() == ErrorKind::NotFound => continue,
+                    Err(e) => return Err(AuditError::UnreadableSnapshot { path: manifest, io: Arc::new(e) }),
+                };
+
+                if let Some(license) = parse_package_license(&text, package.version()) {
+                    return Ok(Some(license));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// The license fields of a manifest whose `package.version` is `version`; `None` for an
+/// unparseable manifest or a different version (a vendor directory holds only one).
+fn parse_package_license(manifest: &str, version: &SemverVersion) -> Option<PackageLicense> {
+    let value: toml::Value = toml::from_str(manifest).ok()?;
+    let package = value.get("package")?;
+
+    let found = package.get("version")?.as_str()?;
+    if SemverVersion::parse(found).ok().as_ref() != Some(version) {
+        return None;
+    }
+
+    let field = |key: &str| package.get(key).and_then(|v| v.as_str()).map(str::to_string);
+    Some(PackageLicense::new(field("license"), field("license-file")))
+}
+
+#[cfg(test)]
+mod test_crate_sources {
+    use super::*;
+
+    #[traced_test]
+    async fn finds_registry_and_vendored_layouts() {
+        let tmp = tempdir().unwrap();
+        tokio::fs::create_dir_all(tmp.path().join("itoa-1.0.0")).await.unwrap();
+        tokio::fs::write(
+            tmp.path().join("itoa-1.0.0/Cargo.toml"),
+            "[package]\nname = \"itoa\"\nversion = \"1.0.0\"\nlicense = \"MIT OR Apache-2.0\"\n",
+        ).await.unwrap();
+        tokio::fs::create_dir_all(tmp.path().join("ring")).await.unwrap();
+        tokio::fs::write(
+            tmp.path().join("ring/Cargo.toml"),
+            "[package]\nname = \"ring\"\nversion = \"0.17.8\"\nlicense-file = \"LICENSE\"\n",
+        ).await.unwrap();
+
+        let sources = CrateSources::new(vec![tmp.path().to_path_buf()]);
+        let v = |s: &str| SemverVersion::parse(s).unwrap();
+
+        let itoa = sources.license_of(&LockedPackage::new("itoa", v("1.0.0"), None)).await.unwrap().unwrap();
+        assert_eq!(itoa.license().as_deref(), Some("MIT OR Apache-2.0"));
+
+        let ring = sources.license_of(&LockedPackage::new("ring", v("0.17.8"), None)).await.unwrap().unwrap();
+        assert_eq!(ring.license(), &None);
+        assert_eq!(ring.license_file().as_deref(), Some("LICENSE"));
+
+        // the vendored copy is a different version
+        assert!(sources.license_of(&LockedPackage::new("ring", v("0.16.0"), None)).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,76 @@
+// ---------------- [ File: workspacer-audit/src/crates_index_snapshot.rs ]
+crate::ix!();
+
+/// A copy of the crates.io index (a git checkout, or the same layout on disk): one file per
+/// crate, one JSON line per published version.
+#[derive(Getters,Debug,Clone)]
+#[getset(get="pub")]
+pub struct CratesIndexSnapshot {
+    root: PathBuf,
+}
+
+impl CratesIndexSnapshot {
+
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Where the index keeps `name`: `1/a`, `2/ab`, `3/a/abc`, `se/rd/serde`.
+    pub fn relative_path(name: &str) -> PathBuf {
+        let name = name.to_lowercase();
+        match name.len() {
+            1 => PathBuf::from("1").join(&name),
+            2 => PathBuf::from("2").join(&name),
+            3 => PathBuf::from("3").join(&name[..1]).join(&name),
+            _ => PathBuf::from(&name[..2]).join(&name[2..4]).join(&name),
+        }
+    }
+
+    /// `Some(true)` if the snapshot lists the version as yanked; `None` if it does not know
+    /// the crate or version at all (say, the snapshot is older than the lockfile).
+    pub async fn is_yanked(&self, name: &str, version: &SemverVersion) -> Result<Option<bool>, AuditError> {
+        let path = self.root.join(Self::relative_path(name));
+        let text = match tokio::fs::read_to_string(&path).await {
+            Ok(text) => text,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(AuditError::UnreadableSnapshot { path, io: Arc::new(e) }),
+        };
+
+        let wanted = version.to_string();
+        Ok(text
+            .lines()
+            .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+            .find(|entry| entry["vers"].as_str() == Some(wanted.as_str()))
+            .map(|entry| entry["yanked"].as_bool().unwrap_or(false)))
+    }
+}
+
+#[cfg(test)]
+mod test_crates_index_snapshot {
+    use super::*;
+
+    #[traced_test]
+    fn lays_out_paths_like_the_index() {
+        assert_eq!(CratesIndexSnapshot::relative_path("a"), PathBuf::from("1/a"));
+        assert_eq!(CratesIndexSnapshot::relative_path("ab"), PathBuf::from("2/ab"));
+        assert_eq!(CratesIndexSnapshot::relative_path("abc"), PathBuf::from("3/a/abc"));
+        assert_eq!(CratesIndexSnapshot::relative_path("Serde"), PathBuf::from("se/rd/serde"));
+    }
+
+    #[traced_test]
+    async fn reads_the_yanked_flag_of_one_version() {
+        let tmp = tempdir().unwrap();
+        tokio::fs::create_dir_all(tmp.path().join("it/oa")).await.unwrap();
+        tokio::fs::write(
+            tmp.path().join("it/oa/itoa"),
+            "{\"name\":\"itoa\",\"vers\":\"1.0.0\",\"yanked\":false}\n{\"name\":\"itoa\",\"vers\":\"1.0.1\",\"yanked\":true}\n",
+        ).await.unwrap();
+
+        let index = CratesIndexSnapshot::new(tmp.path());
+        let v = |s: &str| SemverVersion::parse(s).unwrap();
+        assert_eq!(index.is_yanked("itoa", &v("1.0.0")).await.unwrap(), Some(false));
+        assert_eq!(index.is_yanked("itoa", &v("1.0.1")).await.unwrap(), Some(true));
+        assert_eq!(index.is_yanked("itoa", &v("2.0.0")).await.unwrap(), None);
+        assert_eq!(index.is_yanked("serde", &v("1.0.0")).await.unwrap(), None);
+    }
+}
@@ -0,0 +1,8 @@
+// ---------------- [ File: workspacer-audit/src/imports.rs ]
+pub(crate) use workspacer_3p::*;
+pub(crate) use workspacer_config::*;
+pub(crate) use workspacer_crate_interface::*;
+pub(crate) use workspacer_errors::*;
+pub(crate) use workspacer_lock::*;
+pub(crate) use workspacer_workspace::*;
+pub(crate) use ::serde_derive::{Serialize,Deserialize};
@@ -0,0 +1,15 @@
+// ---------------- [ File: workspacer-audit/src/lib.rs ]
+#[macro_use] mod imports; use imports::*;
+
+x!{license_expression}
+x!{license_policy}
+x!{locked_package}
+x!{lockfile_graph}
+x!{crate_sources}
+x!{crates_index_snapshot}
+x!{advisory}
+x!{advisory_database}
+x!{member_metadata}
+x!{audit_options}
+x!{audit_report}
+x!{audit_workspace}
@@ -0,0 +1,132 @@
+// ---------------- [ File: workspacer-audit/src/license_expression.rs ]
+crate::ix!();
+
+/// A parsed SPDX license expression, as found in a manifest's `license` field. The legacy
+/// `MIT/Apache-2.0` form reads as `MIT OR Apache-2.0`, and `X WITH exception` stays one license.
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub enum LicenseExpression {
+    License(String),
+    And(Vec<LicenseExpression>),
+    Or(Vec<LicenseExpression>),
+}
+
+impl LicenseExpression {
+
+    /// `None` for anything that is not a well-formed expression.
+    pub fn parse(text: &str) -> Option<Self> {
+        let spaced = text.replace('(', " ( ").replace(')', " ) ").replace('/', " OR ");
+        let tokens: Vec<&str> = spaced.split_whitespace().collect();
+
+        let mut pos = 0;
+        let expr = parse_or(&tokens, &mut pos)?;
+        (pos == tokens.len()).then_some(expr)
+    }
+
+    /// Every license the expression mentions.
+    pub fn licenses(&self) -> BTreeSet<&str> {
+        match self {
+            LicenseExpression::License(id) => [id.as_str()].into_iter().collect(),
+            LicenseExpression::And(v) | LicenseExpression::Or(v) => v.iter().flat_map(|e| e.licenses()).collect(),
+        }
+    }
+
+    /// Whether the terms can be met using only licenses `accept` agrees to: one side of each
+    /// `OR`, both sides of each `AND`.
+    pub fn satisfiable(&self, accept: &impl Fn(&str) -> bool) -> bool {
+        match self {
+            LicenseExpression::License(id) => accept(id),
+            LicenseExpression::And(v) => v.iter().all(|e| e.satisfiable(accept)),
+            LicenseExpression::Or(v)  => v.iter().any(|e| e.satisfiable(accept)),
+        }
+    }
+}
+
+fn is_keyword(token: &str, keyword: &str) -> bool {
+    token.eq_ignore_ascii_case(keyword)
+}
+
+fn parse_or(tokens: &[&str], pos: &mut usize) -> Option<LicenseExpression> {
+    let mut terms = vec![parse_and(tokens, pos)?];
+    while tokens.get(*pos).is_some_and(|t| is_keyword(t, "OR")) {
+        *pos += 1;
+        terms.push(parse_and(tokens, pos)?);
+    }
+    Some(if terms.len() == 1 { terms.remove(0) } else { LicenseExpression::Or(terms) })
+}
+
+fn parse_and(tokens: &[&str], pos: &mut usize) -> Option<LicenseExpression> {
+    let mut terms = vec![parse_atom(tokens, pos)?];
+    while tokens.get(*pos).is_some_and(|t| is_keyword(t, "AND")) {
+        *pos += 1;
+        terms.push(parse_atom(tokens, pos)?);
+    }
+    Some(if terms.len() == 1 { terms.remove(0) } else { LicenseExpression::And(terms) })
+}
+
+fn parse_atom(tokens: &[&str], pos: &mut usize) -> Option<LicenseExpression> {
+    let token = *tokens.get(*pos)?;
+    *pos += 1;
+
+    if token == "(" {
+        let inner = parse_or(tokens, pos)?;
+        if tokens.get(*pos) != Some(&")") {
+            return None;
+        }
+        *pos += 1;
+        return Some(inner);
+    }
+
+    if token == ")" || ["AND", "OR", "WITH"].iter().any(|k| is_keyword(token, k)) {
+        return None;
+    }
+
+    if tokens.get(*pos).is_some_and(|t| is_keyword(t, "WITH")) {
+        let exception = tokens.get(*pos + 1)?;
+        *pos += 2;
+        return Some(LicenseExpression::License(format!("{} WITH {}", token, exception)));
+    }
+
+    Some(LicenseExpression::License(token.to_string()))
+}
+
+#[cfg(test)]
+mod test_license_expression {
+    use super::*;
+
+    fn lic(id: &str) -> LicenseExpression {
+        LicenseExpression::License(id.to_string())
+    }
+
+    #[traced_test]
+    fn parses_common_manifest_forms() {
+        assert_eq!(LicenseExpression::parse("MIT"), Some(lic("MIT")));
+        assert_eq!(
+            LicenseExpression::parse("MIT/Apache-2.0"),
+            Some(LicenseExpression::Or(vec![lic("MIT"), lic("Apache-2.0")]))
+        );
+        assert_eq!(
+            LicenseExpression::parse("(MIT OR Apache-2.0) AND Unicode-DFS-2016"),
+            Some(LicenseExpression::And(vec![
+                LicenseExpression::Or(vec![lic("MIT"), lic("Apache-2.0")]),
+                lic("Unicode-DFS-2016"),
+            ]))
+        );
+        assert_eq!(
+            LicenseExpression::parse("Apache-2.0 WITH LLVM-exception OR MIT"),
+            Some(LicenseExpression::Or(vec![lic("Apache-2.0 WITH LLVM-exception"), lic("MIT")]))
+        );
+        assert_eq!(LicenseExpression::parse("MIT OR"), None);
+        assert_eq!(LicenseExpression::parse("(MIT"), None);
+        assert_eq!(LicenseExpression::parse(""), None);
+    }
+
+    #[traced_test]
+    fn satisfiable_takes_either_side_of_or_and_both_of_and() {
+        let expr   = LicenseExpression::parse("(MIT OR GPL-3.0-only) AND BSD-3-Clause").unwrap();
+        let accept = |allowed: &'static [&'static str]| move |id: &str| allowed.contains(&id);
+
+        assert!(expr.satisfiable(&accept(&["MIT", "BSD-3-Clause"])));
+        assert!(!expr.satisfiable(&accept(&["MIT"])));
+        assert_eq!(expr.licenses().len(), 3);
+    }
+}
@@ -0,0 +1,124 @@
+// ---------------- [ File: workspacer-audit/src/license_policy.rs ]
+crate::ix!();
+
+/// The `[audit]` allow and deny lists. An empty allow list accepts every license not denied.
+/// `X WITH exception` is accepted or denied along with `X` unless listed on its own.
+#[derive(Getters,Debug,Clone,Default,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct LicensePolicy {
+    allow: Vec<String>,
+    deny:  Vec<String>,
+}
+
+/// How a third-party crate's license fares against the policy.
+#[derive(Serialize,Debug,Clone,PartialEq,Eq)]
+#[serde(tag = "verdict", rename_all = "kebab-case")]
+pub enum LicenseVerdict {
+    Allowed,
+
+    /// Every way of meeting the terms needs one of these denied licenses
+    Denied {
+        licenses: Vec<String>,
+    },
+
+    /// The terms cannot be met with allowed licenses alone
+    NotAllowed,
+
+    /// No `license` field (`license-file` only), or the crate's sources were not found
+    Unknown,
+
+    /// The `license` field is not an SPDX expression
+    Unparseable,
+}
+
+impl LicenseVerdict {
+    pub fn is_allowed(&self) -> bool {
+        matches!(self, LicenseVerdict::Allowed)
+    }
+}
+
+impl fmt::Display for LicenseVerdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LicenseVerdict::Allowed             => write!(f, "allowed"),
+            LicenseVerdict::Denied { licenses } => write!(f, "denied ({})", licenses.join(", ")),
+            LicenseVerdict::NotAllowed          => write!(f, "not in the allow list"),
+            LicenseVerdict::Unknown             => write!(f, "unknown license"),
+            LicenseVerdict::Unparseable         => write!(f, "unparseable license expression"),
+        }
+    }
+}
+
+impl LicensePolicy {
+
+    pub fn new(allow: Vec<String>, deny: Vec<String>) -> Self {
+        Self { allow, deny }
+    }
+
+    fn base(id: &str) -> &str {
+        id.split(" WITH ").next().unwrap_or(id)
+    }
+
+    fn listed(list: &[String], id: &str) -> bool {
+        list.iter().any(|l| l.eq_ignore_ascii_case(id) || l.eq_ignore_ascii_case(Self::base(id)))
+    }
+
+    pub fn denies(&self, id: &str) -> bool {
+        Self::listed(&self.deny, id)
+    }
+
+    pub fn accepts(&self, id: &str) -> bool {
+        !self.denies(id) && (self.allow.is_empty() || Self::listed(&self.allow, id))
+    }
+
+    pub fn judge(&self, license: Option<&str>) -> LicenseVerdict {
+        let Some(license) = license else {
+            return LicenseVerdict::Unknown;
+        };
+        let Some(expr) = LicenseExpression::parse(license) else {
+            return LicenseVerdict::Unparseable;
+        };
+
+        if expr.satisfiable(&|id| self.accepts(id)) {
+            return LicenseVerdict::Allowed;
+        }
+
+        let denied: Vec<String> = expr.licenses().into_iter().filter(|id| self.denies(id)).map(str::to_string).collect();
+        if denied.is_empty() {
+            LicenseVerdict::NotAllowed
+        } else {
+            LicenseVerdict::Denied { licenses: denied }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_license_policy {
+    use super::*;
+
+    fn strings(v: &[&str]) -> Vec<String> {
+        v.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[traced_test]
+    fn judges_against_allow_and_deny_lists() {
+        let policy = LicensePolicy::new(strings(&["MIT", "Apache-2.0"]), strings(&["GPL-3.0-only"]));
+
+        assert_eq!(policy.judge(Some("MIT OR GPL-3.0-only")), LicenseVerdict::Allowed);
+        assert_eq!(policy.judge(Some("Apache-2.0 WITH LLVM-exception")), LicenseVerdict::Allowed);
+        assert_eq!(
+            policy.judge(Some("GPL-3.0-only")),
+            LicenseVerdict::Denied { licenses: strings(&["GPL-3.0-only"]) }
+        );
+        assert_eq!(policy.judge(Some("MPL-2.0")), LicenseVerdict::NotAllowed);
+        assert_eq!(policy.judge(Some("MIT AND")), LicenseVerdict::Unparseable);
+        assert_eq!(policy.judge(None), LicenseVerdict::Unknown);
+    }
+
+    #[traced_test]
+    fn an_empty_allow_list_accepts_anything_not_denied() {
+        let policy = LicensePolicy::new(vec![], strings(&["AGPL-3.0-only"]));
+        assert!(policy.judge(Some("MPL-2.0")).is_allowed());
+        assert!(!policy.judge(Some("AGPL-3.0-only")).is_allowed());
+    }
+}
@@ -0,0 +1,40 @@
+// ---------------- [ File: workspacer-audit/src/locked_package.rs ]
+crate::ix!();
+
+/// One `[[package]]` of Cargo.lock.
+#[derive(Getters,Serialize,Debug,Clone,PartialEq,Eq,PartialOrd,Ord,Hash)]
+#[getset(get="pub")]
+pub struct LockedPackage {
+    name:    String,
+    version: SemverVersion,
+
+    /// `registry+...`, `sparse+...` or `git+...`; `None` for path packages such as the
+    /// workspace's own crates
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source:  Option<String>,
+}
+
+impl LockedPackage {
+
+    pub fn new(name: impl Into<String>, version: SemverVersion, source: Option<String>) -> Self {
+        Self { name: name.into(), version, source }
+    }
+
+    pub fn is_path(&self) -> bool {
+        self.source.is_none()
+    }
+
+    /// Whether the package comes from crates.io, the only registry the index snapshot covers.
+    pub fn is_from_crates_io(&self) -> bool {
+        self.source.as_deref().is_some_and(|s| {
+            s.starts_with("registry+https://github.com/rust-lang/crates.io-index")
+                || s.starts_with("sparse+https://index.crates.io/")
+        })
+    }
+}
+
+impl fmt::Display for LockedPackage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}@{}", self.name, self.version)
+    }
+}
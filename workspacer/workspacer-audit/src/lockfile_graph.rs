@@ -0,0 +1,127 @@
+// ---------------- [ File: workspacer-audit/src/lockfile_graph.rs ]
+crate::ix!();
+
+/// Cargo.lock as a dependency graph. The lockfile does not tell normal, build and dev
+/// dependencies apart, so everything reachable counts.
+#[derive(Getters,Debug,Clone,Default)]
+#[getset(get="pub")]
+pub struct LockfileGraph {
+    packages:     BTreeSet<LockedPackage>,
+    dependencies: BTreeMap<LockedPackage, Vec<LockedPackage>>,
+}
+
+impl LockfileGraph {
+
+    pub fn from_lockfile(lockfile: &cargo_lock::Lockfile) -> Self {
+        let source = |s: &Option<cargo_lock::SourceId>| s.as_ref().map(|s| s.to_string());
+
+        let packages: BTreeSet<LockedPackage> = lockfile
+            .packages
+            .iter()
+            .map(|p| LockedPackage::new(p.name.as_str(), p.version.clone(), source(&p.source)))
+            .collect();
+
+        let mut dependencies = BTreeMap::new();
+        for p in &lockfile.packages {
+            let from = LockedPackage::new(p.name.as_str(), p.version.clone(), source(&p.source));
+            let to   = p
+                .dependencies
+                .iter()
+                .filter_map(|d| {
+                    packages
+                        .iter()
+                        .find(|c| c.name() == d.name.as_str() && c.version() == &d.version)
+                        .cloned()
+                })
+                .collect();
+            dependencies.insert(from, to);
+        }
+
+        Self { packages, dependencies }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        cargo_lock::Lockfile::from_str(text).map(|l| Self::from_lockfile(&l)).map_err(|e| e.to_string())
+    }
+
+    pub fn find(&self, name: &str) -> Option<&LockedPackage> {
+        self.packages.iter().find(|p| p.name() == name && p.is_path())
+    }
+
+    /// Every non-path package in the lockfile.
+    pub fn third_party(&self) -> impl Iterator<Item = &LockedPackage> {
+        self.packages.iter().filter(|p| !p.is_path())
+    }
+
+    /// The non-path packages `root` pulls in, directly or through other path packages.
+    pub fn third_party_closure(&self, root: &LockedPackage) -> BTreeSet<LockedPackage> {
+        let mut seen  = BTreeSet::new();
+        let mut queue = VecDeque::from([root.clone()]);
+
+        while let Some(p) = queue.pop_front() {
+            for dep in self.dependencies.get(&p).into_iter().flatten() {
+                if seen.insert(dep.clone()) {
+                    queue.push_back(dep.clone());
+                }
+            }
+        }
+
+        seen.into_iter().filter(|p| !p.is_path()).collect()
+    }
+}
+
+#[cfg(test)]
+mod test_lockfile_graph {
+    use super::*;
+
+    const LOCKFILE: &str = r#"
+version = 3
+
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = [
+ "core",
+ "serde",
+]
+
+[[package]]
+name = "core"
+version = "0.1.0"
+dependencies = [
+ "itoa 1.0.0",
+]
+
+[[package]]
+name = "itoa"
+version = "0.4.8"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "itoa"
+version = "1.0.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "serde"
+version = "1.0.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+dependencies = [
+ "itoa 0.4.8",
+]
+"#;
+
+    #[traced_test]
+    fn closure_follows_path_crates_and_picks_the_locked_version() {
+        let graph = LockfileGraph::parse(LOCKFILE).unwrap();
+        assert_eq!(graph.third_party().count(), 3);
+
+        let app: Vec<String> = graph.third_party_closure(graph.find("app").unwrap()).iter().map(|p| p.to_string()).collect();
+        assert_eq!(app, vec!["itoa@0.4.8", "itoa@1.0.0", "serde@1.0.0"]);
+
+        let core: Vec<String> = graph.third_party_closure(graph.find("core").unwrap()).iter().map(|p| p.to_string()).collect();
+        assert_eq!(core, vec!["itoa@1.0.0"]);
+        assert!(graph.find("serde").is_none());
+        assert!(graph.third_party().all(LockedPackage::is_from_crates_io));
+    }
+}
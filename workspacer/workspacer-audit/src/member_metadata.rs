@@ -0,0 +1,134 @@
+// ---------------- [ File: workspacer-audit/src/member_metadata.rs ]
+crate::ix!();
+
+/// The package fields every member crate should agree on, after resolving
+/// `field.workspace = true` against the root `[workspace.package]`.
+#[derive(Getters,Serialize,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct MemberMetadata {
+    crate_name: String,
+    license:    Option<String>,
+    authors:    Vec<String>,
+    repository: Option<String>,
+}
+
+impl MemberMetadata {
+
+    pub fn from_manifest(crate_name: &str, manifest: &toml::Value, workspace_package: Option<&toml::Value>) -> Self {
+        let field = |key: &str| -> Option<toml::Value> {
+            let value = manifest.get("package")?.get(key)?;
+            let inherited = value.get("workspace").and_then(|w| w.as_bool()).unwrap_or(false);
+            if inherited {
+                workspace_package?.get(key).cloned()
+            } else {
+                Some(value.clone())
+            }
+        };
+
+        let string = |key: &str| field(key).and_then(|v| v.as_str().map(str::to_string));
+        let authors = field("authors")
+            .and_then(|v| v.as_array().cloned())
+            .map(|a| a.iter().filter_map(|v| v.as_str().map(str::to_string)).collect())
+            .unwrap_or_default();
+
+        Self {
+            crate_name: crate_name.to_string(),
+            license:    string("license"),
+            authors,
+            repository: string("repository"),
+        }
+    }
+
+    fn fields(&self) -> [(&'static str, Option<String>); 3] {
+        [
+            ("license",    self.license.clone()),
+            ("authors",    (!self.authors.is_empty()).then(|| self.authors.join(", "))),
+            ("repository", self.repository.clone()),
+        ]
+    }
+}
+
+#[derive(Serialize,Debug,Clone,PartialEq,Eq)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum MetadataFinding {
+    Missing {
+        crate_name: String,
+        field:      String,
+    },
+
+    /// Members disagree; each distinct value with the crates using it
+    Inconsistent {
+        field:  String,
+        values: BTreeMap<String, Vec<String>>,
+    },
+}
+
+impl fmt::Display for MetadataFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MetadataFinding::Missing { crate_name, field } => {
+                write!(f, "{}: no `{}`", crate_name, field)
+            }
+            MetadataFinding::Inconsistent { field, values } => {
+                write!(f, "`{}` differs between members:", field)?;
+                for (value, crates) in values {
+                    write!(f, " \"{}\" ({})", value, crates.join(", "))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Missing fields per crate, then one finding per field the members set differently.
+pub fn metadata_findings(members: &[MemberMetadata]) -> Vec<MetadataFinding> {
+    let mut missing = vec![];
+    let mut values: BTreeMap<&'static str, BTreeMap<String, Vec<String>>> = BTreeMap::new();
+
+    for member in members {
+        for (field, value) in member.fields() {
+            match value {
+                None => missing.push(MetadataFinding::Missing {
+                    crate_name: member.crate_name.clone(),
+                    field:      field.to_string(),
+                }),
+                Some(value) => values.entry(field).or_default().entry(value).or_default().push(member.crate_name.clone()),
+            }
+        }
+    }
+
+    let inconsistent = values
+        .into_iter()
+        .filter(|(_, by_value)| by_value.len() > 1)
+        .map(|(field, by_value)| MetadataFinding::Inconsistent { field: field.to_string(), values: by_value });
+
+    missing.into_iter().chain(inconsistent).collect()
+}
+
+#[cfg(test)]
+mod test_member_metadata {
+    use super::*;
+
+    fn member(name: &str, manifest: &str, workspace: &toml::Value) -> MemberMetadata {
+        let manifest: toml::Value = toml::from_str(manifest).unwrap();
+        MemberMetadata::from_manifest(name, &manifest, workspace.get("workspace").and_then(|w| w.get("package")))
+    }
+
+    #[traced_test]
+    fn resolves_inherited_fields_and_reports_differences() {
+        let workspace: toml::Value = toml::from_str(
+            "[workspace.package]\nlicense = \"MIT\"\nauthors = [\"A <a@x>\"]\nrepository = \"https://x/r\"\n",
+        ).unwrap();
+
+        let a = member("a", "[package]\nlicense.workspace = true\nauthors.workspace = true\nrepository.workspace = true\n", &workspace);
+        assert_eq!(a.license().as_deref(), Some("MIT"));
+        assert_eq!(a.authors(), &vec!["A <a@x>".to_string()]);
+
+        let b = member("b", "[package]\nlicense = \"GPL-3.0\"\nauthors = [\"A <a@x>\"]\n", &workspace);
+
+        let findings = metadata_findings(&[a, b]);
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0], MetadataFinding::Missing { crate_name: "b".into(), field: "repository".into() });
+        assert_eq!(findings[1].to_string(), "`license` differs between members: \"GPL-3.0\" (b) \"MIT\" (a)");
+    }
+}
@@ -44,6 +44,9 @@ macro_rules! gen_bump_all_for_workspace {
                     release
                 );
 
+                let settings = WorkspaceSettings::load(self.as_ref()).await?;
+                settings.validate_against_members(&self.get_all_crate_names().await)?;
+
                 let mut updated_versions = std::collections::HashMap::<String, String>::new();
 
                 // --- First pass: bump each crate
@@ -52,6 +55,11 @@ macro_rules! gen_bump_all_for_workspace {
                     let crate_name: String = guard.name().to_string(); 
                     // (Fix Cow<str> -> String)
 
+                    if *settings.for_crate(&crate_name).skip_bump() {
+                        tracing::info!("Skipping crate '{}': bump skip is set in the workspace settings", crate_name);
+                        continue;
+                    }
+
                     match guard.bump(release.clone()).await {
                         Ok(()) => {
                             // retrieve new version
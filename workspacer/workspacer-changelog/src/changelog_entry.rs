@@ -0,0 +1,108 @@
+// ---------------- [ File: workspacer-changelog/src/changelog_entry.rs ]
+crate::ix!();
+
+/// One `## [x.y.z] - date` section of a Keep-a-Changelog `CHANGELOG.md`.
+#[derive(Getters,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct ChangelogEntry {
+    version:  String,
+    date:     String,
+    sections: BTreeMap<ChangelogSection, Vec<String>>,
+}
+
+impl ChangelogEntry {
+
+    pub fn new(version: impl Into<String>, date: impl Into<String>) -> Self {
+        Self { version: version.into(), date: date.into(), sections: BTreeMap::new() }
+    }
+
+    pub fn push(&mut self, section: ChangelogSection, line: impl Into<String>) {
+        self.sections.entry(section).or_default().push(line.into());
+    }
+
+    /// Commits go under the section their subject suggests; public items the interface diff
+    /// found go under Added and Removed.
+    pub fn from_release(
+        version: impl Into<String>,
+        date:    impl Into<String>,
+        commits: &[CommitSummary],
+        diff:    Option<&InterfaceDiff>,
+    ) -> Self {
+        let mut entry = Self::new(version, date);
+
+        for commit in commits {
+            entry.push(commit.section(), format!("{} ({})", commit.description(), commit.hash()));
+        }
+
+        if let Some(diff) = diff {
+            for added in diff.added() {
+                entry.push(ChangelogSection::Added, format!("`{}`", added));
+            }
+            for removed in diff.removed() {
+                entry.push(ChangelogSection::Removed, format!("`{}`", removed));
+            }
+        }
+
+        entry
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sections.values().all(Vec::is_empty)
+    }
+}
+
+impl fmt::Display for ChangelogEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "## [{}] - {}", self.version, self.date)?;
+
+        if self.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "No changes recorded.")?;
+        }
+
+        for (section, lines) in self.sections.iter().filter(|(_, l)| !l.is_empty()) {
+            writeln!(f)?;
+            writeln!(f, "### {}", section)?;
+            writeln!(f)?;
+            for line in lines {
+                writeln!(f, "- {}", line)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_changelog_entry {
+    use super::*;
+
+    #[traced_test]
+    fn renders_sections_in_keep_a_changelog_order() {
+        let commits = vec![
+            CommitSummary::new("b2", "Fix off-by-one in pager"),
+            CommitSummary::new("a1", "Add --json"),
+        ];
+        let diff = InterfaceDiff::between_signatures(
+            &["pub fn old()".to_string()].into_iter().collect(),
+            &["pub fn new()".to_string()].into_iter().collect(),
+        );
+
+        let rendered = ChangelogEntry::from_release("0.2.0", "2026-10-18", &commits, Some(&diff)).to_string();
+        assert_eq!(rendered, indoc! {"
+            ## [0.2.0] - 2026-10-18
+
+            ### Added
+
+            - Add --json (a1)
+            - `pub fn new()`
+
+            ### Removed
+
+            - `pub fn old()`
+
+            ### Fixed
+
+            - Fix off-by-one in pager (b2)
+        "});
+    }
+}
@@ -0,0 +1,27 @@
+// ---------------- [ File: workspacer-changelog/src/changelog_section.rs ]
+crate::ix!();
+
+/// The Keep-a-Changelog change types, declared in the order an entry lists them.
+#[derive(Debug,Clone,Copy,PartialEq,Eq,PartialOrd,Ord,Hash)]
+pub enum ChangelogSection {
+    Added,
+    Changed,
+    Deprecated,
+    Removed,
+    Fixed,
+    Security,
+}
+
+impl fmt::Display for ChangelogSection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            ChangelogSection::Added      => "Added",
+            ChangelogSection::Changed    => "Changed",
+            ChangelogSection::Deprecated => "Deprecated",
+            ChangelogSection::Removed    => "Removed",
+            ChangelogSection::Fixed      => "Fixed",
+            ChangelogSection::Security   => "Security",
+        };
+        write!(f, "{}", s)
+    }
+}
@@ -0,0 +1,104 @@
+// ---------------- [ File: workspacer-changelog/src/commit_summary.rs ]
+crate::ix!();
+
+/// One commit touching a crate since its last release tag.
+#[derive(Getters,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct CommitSummary {
+    hash:    String,
+    subject: String,
+}
+
+impl CommitSummary {
+
+    pub fn new(hash: impl Into<String>, subject: impl Into<String>) -> Self {
+        Self { hash: hash.into(), subject: subject.into() }
+    }
+
+    /// Parses `git log --format=%h%x09%s` output, one commit per line.
+    pub fn parse_log(stdout: &str) -> Vec<Self> {
+        stdout
+            .lines()
+            .filter_map(|line| line.split_once('\t'))
+            .map(|(hash, subject)| Self::new(hash.trim(), subject.trim()))
+            .filter(|c| !c.subject.is_empty())
+            .collect()
+    }
+
+    /// The subject with any leading `[tag]` and conventional-commit `type(scope):` prefix removed.
+    pub fn description(&self) -> &str {
+        let mut s = self.subject.trim();
+        if s.starts_with('[') {
+            if let Some(end) = s.find(']') {
+                s = s[end + 1..].trim_start();
+            }
+        }
+        match conventional_prefix(s) {
+            Some((_, rest)) => rest,
+            None            => s,
+        }
+    }
+
+    /// Which changelog section the commit belongs under, guessed from its subject: a
+    /// conventional-commit type if it has one, its leading verb otherwise.
+    pub fn section(&self) -> ChangelogSection {
+        let mut s = self.subject.trim();
+        if s.starts_with('[') {
+            if let Some(end) = s.find(']') {
+                s = s[end + 1..].trim_start();
+            }
+        }
+
+        if let Some((kind, _)) = conventional_prefix(s) {
+            return match kind {
+                "feat"     => ChangelogSection::Added,
+                "fix"      => ChangelogSection::Fixed,
+                "security" => ChangelogSection::Security,
+                _          => ChangelogSection::Changed,
+            };
+        }
+
+        let verb = s.split_whitespace().next().unwrap_or_default().to_lowercase();
+        match verb.as_str() {
+            "add" | "adds" | "added" | "introduce" | "introduces"       => ChangelogSection::Added,
+            "fix" | "fixes" | "fixed"                                   => ChangelogSection::Fixed,
+            "remove" | "removes" | "removed" | "delete" | "drop"        => ChangelogSection::Removed,
+            "deprecate" | "deprecates" | "deprecated"                   => ChangelogSection::Deprecated,
+            _                                                           => ChangelogSection::Changed,
+        }
+    }
+}
+
+/// `feat(cli): add x` => `("feat", "add x")`; `!` breaking markers are accepted.
+fn conventional_prefix(s: &str) -> Option<(&str, &str)> {
+    let (head, rest) = s.split_once(':')?;
+    let kind = head.split('(').next()?.trim_end_matches('!');
+    if kind.is_empty() || !kind.chars().all(|c| c.is_ascii_lowercase()) {
+        return None;
+    }
+    Some((kind, rest.trim_start()))
+}
+
+#[cfg(test)]
+mod test_commit_summary {
+    use super::*;
+
+    #[traced_test]
+    fn classifies_subjects() {
+        let section = |s: &str| CommitSummary::new("abc", s).section();
+        assert_eq!(section("Add --dry-run to ws bump"), ChangelogSection::Added);
+        assert_eq!(section("[user-12] Fix race in watcher"), ChangelogSection::Fixed);
+        assert_eq!(section("feat(cli): json output"), ChangelogSection::Added);
+        assert_eq!(section("fix!: stop panicking"), ChangelogSection::Fixed);
+        assert_eq!(section("Remove the legacy runner"), ChangelogSection::Removed);
+        assert_eq!(section("Refactor parser"), ChangelogSection::Changed);
+        assert_eq!(section("Note: this is not conventional"), ChangelogSection::Changed);
+    }
+
+    #[traced_test]
+    fn strips_tags_and_prefixes_from_descriptions() {
+        assert_eq!(CommitSummary::new("a", "[user-12] Fix race").description(), "Fix race");
+        assert_eq!(CommitSummary::new("a", "feat(cli): json output").description(), "json output");
+        assert_eq!(CommitSummary::parse_log("a1b2\tAdd x\n\nc3d4\tFix y\n").len(), 2);
+    }
+}
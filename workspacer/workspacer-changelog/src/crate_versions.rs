@@ -0,0 +1,82 @@
+// ---------------- [ File: workspacer-changelog/src/crate_versions.rs ]
+crate::ix!();
+
+/// Every member crate's directory and version at one point in time. Taken before and after
+/// `ws bump`, the two tell which crates the bump released.
+#[derive(Getters,Debug,Clone,Default,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct CrateVersions {
+    versions: BTreeMap<String, (PathBuf, SemverVersion)>,
+}
+
+/// A crate `ws bump` moved from `old_version` to `new_version`.
+#[derive(Getters,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct BumpedCrate {
+    crate_name:  String,
+    crate_dir:   PathBuf,
+    old_version: SemverVersion,
+    new_version: SemverVersion,
+}
+
+impl CrateVersions {
+
+    pub async fn capture<P,H>(workspace: &Workspace<P,H>) -> Result<Self, WorkspaceError>
+    where
+        for<'async_trait> P: From<PathBuf> + AsRef<Path> + Send + Sync + 'async_trait,
+        H: CrateHandleInterface<P> + Send + Sync,
+    {
+        let mut versions = Self::default();
+        for crate_handle in workspace {
+            let guard = crate_handle.lock().await;
+            versions.insert(guard.name().to_string(), guard.root_dir_path_buf(), guard.version()?);
+        }
+        Ok(versions)
+    }
+
+    pub fn insert(&mut self, crate_name: String, crate_dir: PathBuf, version: SemverVersion) {
+        self.versions.insert(crate_name, (crate_dir, version));
+    }
+
+    /// Crates whose version differs in `after`, by name. Crates missing on either side are left
+    /// out: they were added or removed, not bumped.
+    pub fn bumped_to(&self, after: &CrateVersions) -> Vec<BumpedCrate> {
+        after
+            .versions
+            .iter()
+            .filter_map(|(name, (dir, new_version))| {
+                let (_, old_version) = self.versions.get(name)?;
+                (old_version != new_version).then(|| BumpedCrate {
+                    crate_name:  name.clone(),
+                    crate_dir:   dir.clone(),
+                    old_version: old_version.clone(),
+                    new_version: new_version.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test_crate_versions {
+    use super::*;
+
+    #[traced_test]
+    fn reports_only_crates_whose_version_moved() {
+        let v = |s: &str| SemverVersion::parse(s).unwrap();
+
+        let mut before = CrateVersions::default();
+        before.insert("a".into(), "ws/a".into(), v("0.1.0"));
+        before.insert("b".into(), "ws/b".into(), v("1.0.0"));
+
+        let mut after = before.clone();
+        after.insert("a".into(), "ws/a".into(), v("0.1.1"));
+        after.insert("c".into(), "ws/c".into(), v("0.1.0"));
+
+        let bumped = before.bumped_to(&after);
+        assert_eq!(bumped.len(), 1);
+        assert_eq!(bumped[0].crate_name(), "a");
+        assert_eq!(bumped[0].old_version(), &v("0.1.0"));
+        assert_eq!(bumped[0].new_version(), &v("0.1.1"));
+    }
+}
@@ -0,0 +1,154 @@
+// ---------------- [ File: workspacer-changelog/src/git_release_history.rs ]
+crate::ix!();
+
+/// The git side of recording a release, run from inside the repository holding the workspace.
+#[derive(Getters,Debug,Clone)]
+#[getset(get="pub")]
+pub struct GitReleaseHistory {
+    repo_root: PathBuf,
+}
+
+impl GitReleaseHistory {
+
+    pub async fn open(dir: &Path) -> Result<Self, GitError> {
+        Ok(Self { repo_root: git_repo_root(dir).await? })
+    }
+
+    /// `path` relative to the repository root; git runs from there.
+    fn relative(&self, path: &Path) -> PathBuf {
+        let path = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        path.strip_prefix(&self.repo_root).map(Path::to_path_buf).unwrap_or(path)
+    }
+
+    pub async fn tag_exists(&self, tag: &str) -> Result<bool, GitError> {
+        let out = run_git(&self.repo_root, &["tag", "--list", tag]).await?;
+        Ok(out.lines().any(|l| l.trim() == tag))
+    }
+
+    /// The highest-versioned `crate-name-vX.Y.Z` tag, if the crate was ever released.
+    pub async fn latest_release_tag(&self, crate_name: &str) -> Result<Option<String>, GitError> {
+        let pattern = format!("{}-v*", crate_name);
+        let out     = run_git(&self.repo_root, &["tag", "--list", &pattern]).await?;
+
+        Ok(out
+            .lines()
+            .map(str::trim)
+            .filter_map(|tag| version_from_release_tag(crate_name, tag).map(|v| (v, tag)))
+            .max_by(|a, b| a.0.cmp(&b.0))
+            .map(|(_, tag)| tag.to_string()))
+    }
+
+    /// Commits touching `crate_dir` after `since` (all of them when `since` is `None`), newest
+    /// first.
+    pub async fn commits_touching(&self, crate_dir: &Path, since: Option<&str>) -> Result<Vec<CommitSummary>, GitError> {
+        let range   = since.map(|tag| format!("{}..HEAD", tag)).unwrap_or_else(|| "HEAD".to_string());
+        let rel_dir = self.relative(crate_dir).to_string_lossy().to_string();
+        let rel_dir = if rel_dir.is_empty() { ".".to_string() } else { rel_dir };
+
+        let out = run_git(&self.repo_root, &["log", "--no-merges", "--format=%h%x09%s", &range, "--", &rel_dir]).await?;
+        Ok(CommitSummary::parse_log(&out))
+    }
+
+    /// Writes `crate_dir` as it was at `tag` into `dest`, so the old version can be consolidated
+    /// like any crate on disk.
+    pub async fn checkout_crate_at(&self, tag: &str, crate_dir: &Path, dest: &Path) -> Result<(), GitError> {
+        let rel_dir = self.relative(crate_dir);
+        let listing = run_git(
+            &self.repo_root,
+            &["ls-tree", "-r", "--name-only", tag, "--", &rel_dir.to_string_lossy()],
+        ).await?;
+
+        for file in listing.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            let spec     = format!("{}:{}", tag, file);
+            let contents = run_git_bytes(&self.repo_root, &["show", &spec]).await?;
+
+            let target = dest.join(Path::new(file).strip_prefix(&rel_dir).unwrap_or(Path::new(file)));
+            if let Some(parent) = target.parent() {
+                tokio::fs::create_dir_all(parent).await.map_err(|e| io_error(e, parent))?;
+            }
+            tokio::fs::write(&target, contents).await.map_err(|e| io_error(e, &target))?;
+        }
+        Ok(())
+    }
+
+    /// Commits `paths` (and only those) with `message`.
+    pub async fn commit_paths(&self, paths: &[PathBuf], message: &str) -> Result<(), GitError> {
+        let rel: Vec<String> = paths.iter().map(|p| self.relative(p).to_string_lossy().to_string()).collect();
+
+        let mut add = vec!["add", "--"];
+        add.extend(rel.iter().map(String::as_str));
+        run_git(&self.repo_root, &add).await?;
+
+        let mut commit = vec!["commit", "-q", "-m", message, "--"];
+        commit.extend(rel.iter().map(String::as_str));
+        run_git(&self.repo_root, &commit).await?;
+        Ok(())
+    }
+
+    pub async fn create_tag(&self, tag: &str) -> Result<(), GitError> {
+        if self.tag_exists(tag).await? {
+            return Err(GitError::ReleaseTagAlreadyExists { tag: tag.to_string() });
+        }
+        run_git(&self.repo_root, &["tag", tag]).await?;
+        Ok(())
+    }
+}
+
+fn io_error(e: std::io::Error, path: &Path) -> GitError {
+    GitError::IoError {
+        io:      Arc::new(e),
+        context: format!("writing {:?}", path),
+    }
+}
+
+#[cfg(test)]
+mod test_git_release_history {
+    use super::*;
+
+    async fn git(dir: &Path, args: &[&str]) {
+        let out = Command::new("git").args(args).current_dir(dir).output().await.expect("spawn git");
+        assert!(out.status.success(), "git {:?} failed: {}", args, String::from_utf8_lossy(&out.stderr));
+    }
+
+    #[tokio::test]
+    async fn finds_commits_since_the_latest_release_tag() {
+        let tmp  = tempdir().expect("tempdir");
+        let root = tokio::fs::canonicalize(tmp.path()).await.unwrap();
+
+        git(&root, &["init", "-q", "."]).await;
+        git(&root, &["config", "user.email", "t@example.com"]).await;
+        git(&root, &["config", "user.name", "t"]).await;
+
+        tokio::fs::create_dir_all(root.join("a/src")).await.unwrap();
+        tokio::fs::create_dir_all(root.join("b")).await.unwrap();
+        tokio::fs::write(root.join("a/src/lib.rs"), "pub fn one() {}\n").await.unwrap();
+        git(&root, &["add", "."]).await;
+        git(&root, &["commit", "-q", "-m", "Add a"]).await;
+        git(&root, &["tag", "a-v0.1.0"]).await;
+        git(&root, &["tag", "a-v0.10.0-rc.1"]).await;
+
+        tokio::fs::write(root.join("a/src/lib.rs"), "pub fn two() {}\n").await.unwrap();
+        git(&root, &["commit", "-q", "-am", "Fix a"]).await;
+        tokio::fs::write(root.join("b/x"), "x").await.unwrap();
+        git(&root, &["add", "."]).await;
+        git(&root, &["commit", "-q", "-m", "Touch b only"]).await;
+
+        let history = GitReleaseHistory::open(&root.join("a")).await.unwrap();
+        assert_eq!(history.latest_release_tag("a").await.unwrap().as_deref(), Some("a-v0.10.0-rc.1"));
+        assert_eq!(history.latest_release_tag("b").await.unwrap(), None);
+
+        let commits = history.commits_touching(&root.join("a"), Some("a-v0.1.0")).await.unwrap();
+        let subjects: Vec<&str> = commits.iter().map(|c| c.subject().as_str()).collect();
+        assert_eq!(subjects, vec!["Fix a"]);
+
+        let dest = tempdir().expect("tempdir");
+        history.checkout_crate_at("a-v0.1.0", &root.join("a"), dest.path()).await.unwrap();
+        let old = tokio::fs::read_to_string(dest.path().join("src/lib.rs")).await.unwrap();
+        assert_eq!(old, "pub fn one() {}\n");
+
+        assert!(matches!(
+            history.create_tag("a-v0.1.0").await,
+            Err(GitError::ReleaseTagAlreadyExists { .. })
+        ));
+    }
+}
@@ -0,0 +1,8 @@
+// ---------------- [ File: workspacer-changelog/src/imports.rs ]
+pub(crate) use workspacer_3p::*;
+pub(crate) use workspacer_consolidate::*;
+pub(crate) use workspacer_crate::*;
+pub(crate) use workspacer_crate_interface::*;
+pub(crate) use workspacer_errors::*;
+pub(crate) use workspacer_git::*;
+pub(crate) use workspacer_workspace::*;
@@ -0,0 +1,59 @@
+// ---------------- [ File: workspacer-changelog/src/insert_changelog_entry.rs ]
+crate::ix!();
+
+pub const CHANGELOG_HEADER: &str = "# Changelog\n\nAll notable changes to this crate are documented in this file.\n\nThe format is based on [Keep a Changelog](https://keepachangelog.com/en/1.1.0/),\nand this crate adheres to [Semantic Versioning](https://semver.org/spec/v2.0.0.html).\n";
+
+/// Returns `existing` (or a fresh Keep-a-Changelog file) with `entry` as the newest release:
+/// after the `## [Unreleased]` section if there is one, before every other `## [` heading.
+pub fn insert_changelog_entry(existing: Option<&str>, entry: &ChangelogEntry) -> String {
+    let existing = existing.unwrap_or(CHANGELOG_HEADER);
+    let rendered = entry.to_string();
+
+    let mut offset = 0;
+    let mut insert_at = None;
+    for line in existing.split_inclusive('\n') {
+        if line.starts_with("## [") && !line.to_lowercase().starts_with("## [unreleased]") {
+            insert_at = Some(offset);
+            break;
+        }
+        offset += line.len();
+    }
+
+    match insert_at {
+        Some(at) => format!("{}{}\n{}", &existing[..at], rendered, &existing[at..]),
+        None => {
+            let head = existing.trim_end_matches('\n');
+            format!("{}\n\n{}", head, rendered)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_insert_changelog_entry {
+    use super::*;
+
+    fn entry(version: &str) -> ChangelogEntry {
+        let mut e = ChangelogEntry::new(version, "2026-10-18");
+        e.push(ChangelogSection::Changed, "something");
+        e
+    }
+
+    #[traced_test]
+    fn creates_a_file_when_there_is_none() {
+        let text = insert_changelog_entry(None, &entry("0.1.1"));
+        assert!(text.starts_with("# Changelog\n"));
+        assert!(text.ends_with("## [0.1.1] - 2026-10-18\n\n### Changed\n\n- something\n"));
+    }
+
+    #[traced_test]
+    fn puts_the_entry_below_unreleased_and_above_older_releases() {
+        let existing = "# Changelog\n\n## [Unreleased]\n\n- wip\n\n## [0.1.0] - 2026-01-01\n\n- first\n";
+        let text = insert_changelog_entry(Some(existing), &entry("0.1.1"));
+
+        let unreleased = text.find("## [Unreleased]").unwrap();
+        let new        = text.find("## [0.1.1]").unwrap();
+        let old        = text.find("## [0.1.0]").unwrap();
+        assert!(unreleased < new && new < old, "{}", text);
+        assert!(text.contains("- something\n\n## [0.1.0]"), "{}", text);
+    }
+}
@@ -0,0 +1,12 @@
+// ---------------- [ File: workspacer-changelog/src/lib.rs ]
+#[macro_use] mod imports; use imports::*;
+
+x!{changelog_section}
+x!{commit_summary}
+x!{changelog_entry}
+x!{insert_changelog_entry}
+x!{release_tag}
+x!{release_date}
+x!{git_release_history}
+x!{crate_versions}
+x!{record_releases}
@@ -0,0 +1,149 @@
+// ---------------- [ File: workspacer-changelog/src/record_releases.rs ]
+crate::ix!();
+
+/// What `ws bump` records about the crates it bumped.
+#[derive(Builder,Getters,Debug,Clone)]
+#[getset(get="pub")]
+#[builder(setter(into))]
+pub struct ReleaseRecordOptions {
+    /// Append a Keep-a-Changelog entry to each bumped crate's `CHANGELOG.md`
+    #[builder(default="true")]
+    write_changelog: bool,
+
+    /// Commit the bump and tag it `crate-name-vX.Y.Z` for every bumped crate
+    #[builder(default="true")]
+    tag: bool,
+
+    /// The date entries are stamped with
+    #[builder(default="today_utc()")]
+    date: String,
+}
+
+/// Records the release of every crate whose version changed since `before` was captured.
+#[async_trait]
+pub trait RecordReleases {
+    async fn record_releases(
+        &self,
+        before:  &CrateVersions,
+        options: &ReleaseRecordOptions,
+    ) -> Result<Vec<BumpedCrate>, WorkspaceError>;
+}
+
+#[async_trait]
+impl<P,H> RecordReleases for Workspace<P,H>
+where
+    for<'async_trait> P: From<PathBuf> + AsRef<Path> + Send + Sync + 'async_trait,
+    H: CrateHandleInterface<P> + Send + Sync,
+{
+    async fn record_releases(
+        &self,
+        before:  &CrateVersions,
+        options: &ReleaseRecordOptions,
+    ) -> Result<Vec<BumpedCrate>, WorkspaceError> {
+        let after  = CrateVersions::capture(self).await?;
+        let bumped = before.bumped_to(&after);
+
+        if bumped.is_empty() || (!options.write_changelog && !options.tag) {
+            return Ok(bumped);
+        }
+
+        let history   = GitReleaseHistory::open(self.as_ref()).await?;
+        let mut paths = vec![];
+
+        if options.write_changelog {
+            for release in &bumped {
+                paths.push(write_changelog_entry(&history, release, &options.date).await?);
+            }
+        }
+
+        if options.tag {
+            // every manifest, since downstream crates had their requirements rewritten
+            paths.extend(after.versions().values().map(|(dir, _)| dir.join("Cargo.toml")));
+
+            let message = format!(
+                "Release {}",
+                bumped.iter().map(|b| format!("{} v{}", b.crate_name(), b.new_version())).collect::<Vec<_>>().join(", ")
+            );
+            history.commit_paths(&paths, &message).await?;
+
+            for release in &bumped {
+                let tag = release_tag(release.crate_name(), &release.new_version().to_string());
+                history.create_tag(&tag).await?;
+                info!("tagged {}", tag);
+            }
+        }
+
+        Ok(bumped)
+    }
+}
+
+/// Builds the entry from the commits touching the crate since its previous release tag and
+/// from how its public interface moved since then, and prepends it to `CHANGELOG.md`.
+/// Returns the changelog's path.
+async fn write_changelog_entry(
+    history: &GitReleaseHistory,
+    release: &BumpedCrate,
+    date:    &str,
+) -> Result<PathBuf, WorkspaceError> {
+    let name     = release.crate_name();
+    let old_tag  = release_tag(name, &release.old_version().to_string());
+    let previous = if history.tag_exists(&old_tag).await? {
+        Some(old_tag)
+    } else {
+        history.latest_release_tag(name).await?
+    };
+    debug!("crate '{}': previous release tag {:?}", name, previous);
+
+    let commits = history.commits_touching(release.crate_dir(), previous.as_deref()).await?;
+    let diff    = match &previous {
+        Some(tag) => interface_diff_since(history, tag, release.crate_dir()).await,
+        None      => None,
+    };
+
+    let entry = ChangelogEntry::from_release(release.new_version().to_string(), date, &commits, diff.as_ref());
+    let path  = release.crate_dir().join("CHANGELOG.md");
+
+    let existing = match tokio::fs::read_to_string(&path).await {
+        Ok(text) => Some(text),
+        Err(e) if e.kind() == ErrorKind::NotFound => None,
+        Err(e) => return Err(changelog_io_error(e, &path)),
+    };
+    tokio::fs::write(&path, insert_changelog_entry(existing.as_deref(), &entry))
+        .await
+        .map_err(|e| changelog_io_error(e, &path))?;
+
+    info!("wrote {} entry to {:?}", release.new_version(), path);
+    Ok(path)
+}
+
+/// The public-interface changes since `tag`. A crate that cannot be loaded or consolidated at
+/// `tag` (say, its manifest moved) gets no interface section rather than failing the bump.
+async fn interface_diff_since(history: &GitReleaseHistory, tag: &str, crate_dir: &Path) -> Option<InterfaceDiff> {
+    let options = ConsolidationOptions::new();
+
+    let old = async {
+        let snapshot = tempdir().ok()?;
+        history.checkout_crate_at(tag, crate_dir, snapshot.path()).await.ok()?;
+        let handle = CrateHandle::new(&snapshot.path().to_path_buf()).await.ok()?;
+        handle.consolidate_crate_interface(&options).await.ok()
+    };
+    let new = async {
+        let handle = CrateHandle::new(&crate_dir.to_path_buf()).await.ok()?;
+        handle.consolidate_crate_interface(&options).await.ok()
+    };
+
+    match (old.await, new.await) {
+        (Some(old), Some(new)) => Some(InterfaceDiff::between(&old, &new)),
+        _ => {
+            warn!("could not consolidate {:?} at {} and now; leaving interface changes out", crate_dir, tag);
+            None
+        }
+    }
+}
+
+fn changelog_io_error(e: std::io::Error, path: &Path) -> WorkspaceError {
+    WorkspaceError::IoError {
+        io_error: Arc::new(e),
+        context:  format!("updating {:?}", path),
+    }
+}
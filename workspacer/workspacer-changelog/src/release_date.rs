@@ -0,0 +1,40 @@
+// ---------------- [ File: workspacer-changelog/src/release_date.rs ]
+crate::ix!();
+
+/// Today's UTC date as `YYYY-MM-DD`, the form Keep-a-Changelog headings use.
+pub fn today_utc() -> String {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let (y, m, d) = civil_date_from_unix_days((secs / 86_400) as i64);
+    format!("{:04}-{:02}-{:02}", y, m, d)
+}
+
+/// Days since 1970-01-01 to a proleptic Gregorian `(year, month, day)` (Howard Hinnant's
+/// `civil_from_days`).
+pub fn civil_date_from_unix_days(days: i64) -> (i64, u32, u32) {
+    let z   = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp  = (5 * doy + 2) / 153;
+    let d   = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m   = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let y   = yoe + era * 400 + if m <= 2 { 1 } else { 0 };
+    (y, m, d)
+}
+
+#[cfg(test)]
+mod test_release_date {
+    use super::*;
+
+    #[traced_test]
+    fn converts_known_days() {
+        assert_eq!(civil_date_from_unix_days(0), (1970, 1, 1));
+        assert_eq!(civil_date_from_unix_days(11_016), (2000, 2, 29));
+        assert_eq!(civil_date_from_unix_days(20_744), (2026, 10, 18));
+        assert_eq!(civil_date_from_unix_days(-1), (1969, 12, 31));
+    }
+}
@@ -0,0 +1,27 @@
+// ---------------- [ File: workspacer-changelog/src/release_tag.rs ]
+crate::ix!();
+
+/// The git tag marking a crate's release: `crate-name-vX.Y.Z`.
+pub fn release_tag(crate_name: &str, version: &str) -> String {
+    format!("{}-v{}", crate_name, version)
+}
+
+/// The version a release tag of `crate_name` names, if `tag` is one. Requiring a valid semver
+/// keeps `foo-v1.0.0` from being taken as a tag of crate `foo-v1` and vice versa.
+pub fn version_from_release_tag(crate_name: &str, tag: &str) -> Option<SemverVersion> {
+    let version = tag.strip_prefix(crate_name)?.strip_prefix("-v")?;
+    SemverVersion::parse(version).ok()
+}
+
+#[cfg(test)]
+mod test_release_tag {
+    use super::*;
+
+    #[traced_test]
+    fn round_trips_versions() {
+        let tag = release_tag("workspacer-bump", "0.2.0");
+        assert_eq!(tag, "workspacer-bump-v0.2.0");
+        assert_eq!(version_from_release_tag("workspacer-bump", &tag), Some(SemverVersion::new(0, 2, 0)));
+        assert_eq!(version_from_release_tag("workspacer", &tag), None);
+    }
+}
@@ -0,0 +1,218 @@
+// ---------------- [ File: workspacer-check-publish-ready/src/audit_manifest.rs ]
+crate::ix!();
+
+/// The checks that only need `Cargo.toml`: path dependencies without a version, crates.io
+/// category/keyword rules (the ones `ws prune` enforces) and `include`/`exclude` conflicts.
+pub fn audit_manifest(manifest: &toml::Value) -> Vec<PublishAuditFinding> {
+    let mut findings = vec![];
+
+    // dev-dependencies are stripped by `cargo package`, so they may stay path-only
+    for table in ["dependencies", "build-dependencies"] {
+        findings.extend(path_dependencies_without_version(manifest.get(table), table));
+    }
+    if let Some(targets) = manifest.get("target").and_then(|t| t.as_table()) {
+        for (cfg, target) in targets {
+            for table in ["dependencies", "build-dependencies"] {
+                let name = format!("target.'{}'.{}", cfg, table);
+                findings.extend(path_dependencies_without_version(target.get(table), &name));
+            }
+        }
+    }
+
+    let package = manifest.get("package");
+    let strings = |key: &str| -> Vec<String> {
+        package
+            .and_then(|p| p.get(key))
+            .and_then(|v| v.as_array())
+            .map(|a| a.iter().filter_map(|v| v.as_str().map(str::to_string)).collect())
+            .unwrap_or_default()
+    };
+
+    let categories = strings("categories");
+    if categories.len() > MAX_CRATES_IO_CATEGORIES {
+        findings.push(PublishAuditFinding::TooManyCategories { count: categories.len() });
+    }
+    for category in categories.into_iter().filter(|c| !is_legal_category(c)) {
+        findings.push(PublishAuditFinding::InvalidCategory { category });
+    }
+
+    let keywords = strings("keywords");
+    if keywords.len() > MAX_CRATES_IO_KEYWORDS {
+        findings.push(PublishAuditFinding::TooManyKeywords { count: keywords.len() });
+    }
+    for keyword in keywords.into_iter().filter(|k| !is_legal_keyword(k)) {
+        findings.push(PublishAuditFinding::InvalidKeyword { keyword });
+    }
+
+    if package.and_then(|p| p.get("include")).is_some() && package.and_then(|p| p.get("exclude")).is_some() {
+        findings.push(PublishAuditFinding::ExcludeIgnoredBecauseOfInclude);
+    }
+
+    findings
+}
+
+fn path_dependencies_without_version(table: Option<&toml::Value>, table_name: &str) -> Vec<PublishAuditFinding> {
+    let Some(deps) = table.and_then(|t| t.as_table()) else {
+        return vec![];
+    };
+    deps.iter()
+        .filter(|(_, spec)| spec.get("path").is_some() && spec.get("version").is_none())
+        .map(|(name, _)| PublishAuditFinding::PathDependencyWithoutVersion {
+            table:      table_name.to_string(),
+            dependency: name.clone(),
+        })
+        .collect()
+}
+
+/// The checks that need the list of packaged files (`cargo package --list`, which is exactly
+/// what goes into the tarball): the readme and license file made it in, `include`/`exclude`
+/// did not drop the crate's entry point, and every `include` pattern matches something.
+pub fn audit_package_listing(
+    manifest:   &toml::Value,
+    crate_root: &Path,
+    packaged:   &BTreeSet<String>,
+) -> Vec<PublishAuditFinding> {
+    let mut findings = vec![];
+    let package = manifest.get("package");
+    let field   = |key: &str| package.and_then(|p| p.get(key));
+
+    // cargo picks up README.md on its own when `readme` is unset, and skips it for `readme = false`
+    let readme = match field("readme") {
+        Some(v) => v.as_str().map(str::to_string),
+        None    => crate_root.join("README.md").exists().then(|| "README.md".to_string()),
+    };
+    let license_file = field("license-file").and_then(|v| v.as_str()).map(str::to_string);
+
+    for (key, file) in [("readme", readme), ("license-file", license_file)] {
+        let Some(file) = file else { continue };
+        if !is_packaged(&file, packaged) {
+            findings.push(PublishAuditFinding::MissingFromPackage { field: key.to_string(), file });
+        }
+    }
+
+    for entry_point in ["src/lib.rs", "src/main.rs"] {
+        if crate_root.join(entry_point).exists() && !packaged.contains(entry_point) {
+            findings.push(PublishAuditFinding::EntryPointNotPackaged { file: entry_point.to_string() });
+        }
+    }
+
+    let includes = field("include").and_then(|v| v.as_array()).into_iter().flatten().filter_map(|v| v.as_str());
+    for pattern in includes {
+        if !packaged.iter().any(|file| include_pattern_matches(pattern, file)) {
+            findings.push(PublishAuditFinding::IncludePatternMatchesNothing { pattern: pattern.to_string() });
+        }
+    }
+
+    findings
+}
+
+/// Files from outside the crate (`readme = "../README.md"`) are packaged at the crate root.
+fn is_packaged(file: &str, packaged: &BTreeSet<String>) -> bool {
+    let normalized = file.trim_start_matches("./");
+    let file_name  = Path::new(normalized).file_name().map(|n| n.to_string_lossy().to_string());
+    packaged.contains(normalized) || file_name.is_some_and(|n| packaged.contains(&n))
+}
+
+/// gitignore-style matching, as cargo applies it to `include`: `*` and `?` stay within one
+/// path component, `**` spans several, a pattern without a `/` matches at any depth, and a
+/// pattern naming a directory matches everything below it.
+pub fn include_pattern_matches(pattern: &str, path: &str) -> bool {
+    let anchored = pattern.starts_with('/') || pattern.trim_end_matches('/').contains('/');
+    let pattern  = pattern.trim_start_matches('/').trim_end_matches('/');
+
+    let mut re = String::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    re.push_str("(?:.*/)?");
+                } else {
+                    re.push_str(".*");
+                }
+            }
+            '*' => re.push_str("[^/]*"),
+            '?' => re.push_str("[^/]"),
+            c   => re.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+
+    let prefix = if anchored { "^" } else { "^(?:.*/)?" };
+    Regex::new(&format!("{}{}(?:/.*)?$", prefix, re)).is_ok_and(|r| r.is_match(path))
+}
+
+#[cfg(test)]
+mod test_audit_manifest {
+    use super::*;
+
+    fn manifest(text: &str) -> toml::Value {
+        toml::from_str(text).expect("valid toml")
+    }
+
+    #[traced_test]
+    fn flags_path_dependencies_categories_and_keywords() {
+        let m = manifest(r#"
+            [package]
+            name       = "a"
+            categories = ["development-tools", "tooling"]
+            keywords   = ["one", "two words", "three", "four", "five", "six"]
+            include    = ["src/**"]
+            exclude    = ["tests"]
+
+            [dependencies]
+            b = { path = "../b" }
+            c = { path = "../c", version = "0.1" }
+            serde = "1"
+
+            [dev-dependencies]
+            d = { path = "../d" }
+
+            [target.'cfg(unix)'.dependencies]
+            e = { path = "../e" }
+        "#);
+
+        let findings = audit_manifest(&m);
+        assert_eq!(findings, vec![
+            PublishAuditFinding::PathDependencyWithoutVersion { table: "dependencies".into(), dependency: "b".into() },
+            PublishAuditFinding::PathDependencyWithoutVersion { table: "target.'cfg(unix)'.dependencies".into(), dependency: "e".into() },
+            PublishAuditFinding::InvalidCategory { category: "tooling".into() },
+            PublishAuditFinding::TooManyKeywords { count: 6 },
+            PublishAuditFinding::InvalidKeyword { keyword: "two words".into() },
+            PublishAuditFinding::ExcludeIgnoredBecauseOfInclude,
+        ]);
+    }
+
+    #[traced_test]
+    fn checks_the_packaged_file_list() {
+        let dir = tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("src")).unwrap();
+        std::fs::write(dir.path().join("src/lib.rs"), "").unwrap();
+        std::fs::write(dir.path().join("README.md"), "").unwrap();
+
+        let m = manifest(r#"
+            [package]
+            name         = "a"
+            license-file = "../LICENSE"
+            include      = ["Cargo.toml", "/src/main.rs", "docs/"]
+        "#);
+        let packaged: BTreeSet<String> = ["Cargo.toml", "LICENSE", "src/lib.rs"].iter().map(|s| s.to_string()).collect();
+
+        assert_eq!(audit_package_listing(&m, dir.path(), &packaged), vec![
+            PublishAuditFinding::MissingFromPackage { field: "readme".into(), file: "README.md".into() },
+            PublishAuditFinding::IncludePatternMatchesNothing { pattern: "/src/main.rs".into() },
+            PublishAuditFinding::IncludePatternMatchesNothing { pattern: "docs/".into() },
+        ]);
+    }
+
+    #[traced_test]
+    fn include_patterns_follow_gitignore_rules() {
+        assert!(include_pattern_matches("src/**", "src/a/b.rs"));
+        assert!(include_pattern_matches("*.md", "docs/guide.md"));
+        assert!(!include_pattern_matches("/*.md", "docs/guide.md"));
+        assert!(include_pattern_matches("src", "src/lib.rs"));
+        assert!(include_pattern_matches("**/mod.rs", "src/x/mod.rs"));
+        assert!(!include_pattern_matches("src/*.rs", "src/x/mod.rs"));
+    }
+}
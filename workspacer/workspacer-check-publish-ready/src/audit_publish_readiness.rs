@@ -0,0 +1,136 @@
+// ---------------- [ File: workspacer-check-publish-ready/src/audit_publish_readiness.rs ]
+crate::ix!();
+
+/// The deep counterpart of `ReadyForCargoPublish`: builds the `.crate` tarball offline and
+/// checks it, and the manifest, against what crates.io will accept.
+///
+/// Findings go into the report; an `Err` means the audit itself could not run.
+#[async_trait]
+pub trait AuditPublishReadiness {
+    type Report;
+    type Error;
+
+    async fn audit_publish_readiness(&self) -> Result<Self::Report, Self::Error>;
+}
+
+/// `cargo package` for one manifest, never touching the network or requiring a clean tree
+/// (`ws publish` checks git on its own).
+fn cargo_package_command(manifest_path: &Path) -> tokio::process::Command {
+    let mut cmd = tokio::process::Command::new("cargo");
+    cmd.arg("package")
+        .arg("--offline")
+        .arg("--allow-dirty")
+        .arg("--quiet")
+        .arg("--manifest-path")
+        .arg(manifest_path);
+    cmd
+}
+
+async fn run_cargo_package(cmd: &mut tokio::process::Command, crate_name: &str) -> Result<std::process::Output, CrateError> {
+    cmd.output().await.map_err(|io| CrateError::IoError {
+        io_error: Arc::new(io),
+        context:  format!("running `cargo package` for crate '{}'", crate_name),
+    })
+}
+
+#[async_trait]
+impl AuditPublishReadiness for CrateHandle {
+    type Report = CratePublishAudit;
+    type Error  = CrateError;
+
+    async fn audit_publish_readiness(&self) -> Result<CratePublishAudit, CrateError> {
+        let crate_root    = self.root_dir_path_buf();
+        let manifest_path = crate_root.join("Cargo.toml");
+        let crate_name    = self.name().to_string();
+
+        let manifest = self.cargo_toml().lock().await.get_content().clone();
+        let version  = manifest
+            .get("package")
+            .and_then(|p| p.get("version"))
+            .and_then(|v| v.as_str())
+            .unwrap_or("?")
+            .to_string();
+
+        let mut audit = CratePublishAuditBuilder::default();
+        audit.crate_name(crate_name.clone()).version(version.clone());
+
+        if self.is_private().await? {
+            debug!("crate '{}' is not published; skipping its audit", crate_name);
+            return Ok(audit.skipped(true).build().unwrap());
+        }
+
+        let mut findings = audit_manifest(&manifest);
+
+        // 1) what would go into the tarball
+        let listing = run_cargo_package(cargo_package_command(&manifest_path).arg("--list"), &crate_name).await?;
+        if !listing.status.success() {
+            findings.push(PublishAuditFinding::PackagingFailed {
+                stderr: String::from_utf8_lossy(&listing.stderr).to_string(),
+            });
+            return Ok(audit.findings(findings).build().unwrap());
+        }
+
+        let packaged: BTreeSet<String> = String::from_utf8_lossy(&listing.stdout)
+            .lines()
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty())
+            .collect();
+        findings.extend(audit_package_listing(&manifest, &crate_root, &packaged));
+
+        // 2) the tarball itself, built into a throwaway target dir
+        let target_dir = tempdir().map_err(|io| CrateError::IoError {
+            io_error: Arc::new(io),
+            context:  "creating a target dir for `cargo package`".to_string(),
+        })?;
+        let built = run_cargo_package(
+            cargo_package_command(&manifest_path).arg("--no-verify").arg("--target-dir").arg(target_dir.path()),
+            &crate_name,
+        ).await?;
+
+        let mut package_bytes = None;
+        if built.status.success() {
+            let tarball = target_dir.path().join("package").join(format!("{}-{}.crate", crate_name, version));
+            let bytes = tokio::fs::metadata(&tarball).await.map_err(|io| CrateError::IoError {
+                io_error: Arc::new(io),
+                context:  format!("reading the size of {:?}", tarball),
+            })?.len();
+
+            if bytes > CRATES_IO_MAX_PACKAGE_BYTES {
+                findings.push(PublishAuditFinding::PackageTooLarge { bytes, limit: CRATES_IO_MAX_PACKAGE_BYTES });
+            }
+            package_bytes = Some(bytes);
+        } else {
+            findings.push(PublishAuditFinding::PackagingFailed {
+                stderr: String::from_utf8_lossy(&built.stderr).to_string(),
+            });
+        }
+
+        info!("publish audit for '{}': {} finding(s)", crate_name, findings.len());
+        Ok(audit
+            .package_bytes(package_bytes)
+            .packaged_files(packaged.into_iter().collect::<Vec<_>>())
+            .findings(findings)
+            .build()
+            .unwrap())
+    }
+}
+
+#[async_trait]
+impl<P,H> AuditPublishReadiness for Workspace<P,H>
+where
+    for<'async_trait> P: From<PathBuf> + AsRef<Path> + Send + Sync + 'async_trait,
+    H: CrateHandleInterface<P> + AuditPublishReadiness<Report=CratePublishAudit, Error=CrateError>,
+{
+    type Report = PublishAuditReport;
+    type Error  = WorkspaceError;
+
+    /// Audits every crate, one at a time (`cargo package` takes the package lock anyway).
+    async fn audit_publish_readiness(&self) -> Result<PublishAuditReport, WorkspaceError> {
+        let mut audits = vec![];
+        for crate_handle in self {
+            let guard = crate_handle.lock().await;
+            audits.push(guard.audit_publish_readiness().await?);
+        }
+        Ok(PublishAuditReport::new(audits))
+    }
+}
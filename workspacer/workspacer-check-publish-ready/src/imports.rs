@@ -6,6 +6,8 @@ pub(crate) use workspacer_check_private::*;
 pub(crate) use workspacer_crate::*;
 pub(crate) use workspacer_crate_interface::*;
 pub(crate) use workspacer_errors::*;
+pub(crate) use workspacer_prune_bad_category_slugs::*;
 pub(crate) use workspacer_toml::*;
 pub(crate) use workspacer_toml_interface::*;
 pub(crate) use workspacer_workspace::*;
+pub(crate) use serde_derive::Serialize;
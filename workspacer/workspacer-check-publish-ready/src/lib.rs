@@ -4,3 +4,7 @@
 x!{ready_for_cargo_publish}
 x!{toml_ready_for_cargo_publish}
 x!{workspace_check_publish_ready}
+x!{publish_audit_finding}
+x!{publish_audit_report}
+x!{audit_manifest}
+x!{audit_publish_readiness}
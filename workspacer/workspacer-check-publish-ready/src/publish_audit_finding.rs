@@ -0,0 +1,99 @@
+// ---------------- [ File: workspacer-check-publish-ready/src/publish_audit_finding.rs ]
+crate::ix!();
+
+/// The largest `.crate` file crates.io accepts by default.
+pub const CRATES_IO_MAX_PACKAGE_BYTES: u64 = 10 * 1024 * 1024;
+
+/// One reason a crate would not make it onto crates.io as it stands.
+#[derive(Serialize,Debug,Clone,PartialEq,Eq)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum PublishAuditFinding {
+    /// `cargo package` itself failed; nothing after it could be checked
+    PackagingFailed {
+        stderr: String,
+    },
+
+    /// A file the manifest points at (`readme`, `license-file`) is not in the package
+    MissingFromPackage {
+        field: String,
+        file:  String,
+    },
+
+    /// `src/lib.rs` or `src/main.rs` exists but `include`/`exclude` leave it out
+    EntryPointNotPackaged {
+        file: String,
+    },
+
+    /// crates.io refuses path dependencies that do not also name a version
+    PathDependencyWithoutVersion {
+        table:      String,
+        dependency: String,
+    },
+
+    InvalidCategory {
+        category: String,
+    },
+    InvalidKeyword {
+        keyword: String,
+    },
+    TooManyCategories {
+        count: usize,
+    },
+    TooManyKeywords {
+        count: usize,
+    },
+
+    PackageTooLarge {
+        bytes: u64,
+        limit: u64,
+    },
+
+    /// An `include` pattern no packaged file matches, usually a typo
+    IncludePatternMatchesNothing {
+        pattern: String,
+    },
+
+    /// cargo ignores `exclude` whenever `include` is set
+    ExcludeIgnoredBecauseOfInclude,
+}
+
+impl fmt::Display for PublishAuditFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use PublishAuditFinding::*;
+        match self {
+            PackagingFailed { stderr } => {
+                write!(f, "`cargo package` failed: {}", stderr.trim())
+            }
+            MissingFromPackage { field, file } => {
+                write!(f, "`{}` names '{}', which is not in the package", field, file)
+            }
+            EntryPointNotPackaged { file } => {
+                write!(f, "'{}' exists but include/exclude leave it out of the package", file)
+            }
+            PathDependencyWithoutVersion { table, dependency } => {
+                write!(f, "[{}] '{}' is a path dependency without a version", table, dependency)
+            }
+            InvalidCategory { category } => {
+                write!(f, "category '{}' is not a crates.io category slug", category)
+            }
+            InvalidKeyword { keyword } => {
+                write!(f, "keyword '{}' is not a valid crates.io keyword", keyword)
+            }
+            TooManyCategories { count } => {
+                write!(f, "{} categories; crates.io allows at most {}", count, MAX_CRATES_IO_CATEGORIES)
+            }
+            TooManyKeywords { count } => {
+                write!(f, "{} keywords; crates.io allows at most {}", count, MAX_CRATES_IO_KEYWORDS)
+            }
+            PackageTooLarge { bytes, limit } => {
+                write!(f, "package is {} bytes; the limit is {}", bytes, limit)
+            }
+            IncludePatternMatchesNothing { pattern } => {
+                write!(f, "include pattern '{}' matches no packaged file", pattern)
+            }
+            ExcludeIgnoredBecauseOfInclude => {
+                write!(f, "both include and exclude are set; cargo ignores exclude")
+            }
+        }
+    }
+}
@@ -0,0 +1,108 @@
+// ---------------- [ File: workspacer-check-publish-ready/src/publish_audit_report.rs ]
+crate::ix!();
+
+/// What `ws publish-ready` found for one crate.
+#[derive(Builder,Getters,Serialize,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+#[builder(setter(into))]
+pub struct CratePublishAudit {
+    crate_name: String,
+    version:    String,
+
+    /// `publish = false` crates are listed but not audited
+    #[builder(default)]
+    skipped: bool,
+
+    /// Size of the `.crate` file, when packaging got that far
+    #[builder(default)]
+    package_bytes: Option<u64>,
+
+    #[builder(default)]
+    packaged_files: Vec<String>,
+
+    #[builder(default)]
+    findings: Vec<PublishAuditFinding>,
+}
+
+impl CratePublishAudit {
+    pub fn is_ready(&self) -> bool {
+        self.skipped || self.findings.is_empty()
+    }
+}
+
+impl fmt::Display for CratePublishAudit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.skipped {
+            return writeln!(f, "skip  {} {} (not published)", self.crate_name, self.version);
+        }
+
+        let status = if self.is_ready() { "ok  " } else { "FAIL" };
+        write!(f, "{}  {} {}", status, self.crate_name, self.version)?;
+        if let Some(bytes) = self.package_bytes {
+            write!(f, " ({} files, {} bytes)", self.packaged_files.len(), bytes)?;
+        }
+        writeln!(f)?;
+
+        for finding in &self.findings {
+            writeln!(f, "      - {}", finding)?;
+        }
+        Ok(())
+    }
+}
+
+/// `ws publish-ready` for the whole workspace: one entry per crate, in workspace order.
+#[derive(Getters,Serialize,Debug,Clone,Default,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct PublishAuditReport {
+    crates: Vec<CratePublishAudit>,
+}
+
+impl PublishAuditReport {
+
+    pub fn new(crates: Vec<CratePublishAudit>) -> Self {
+        Self { crates }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.crates.iter().all(CratePublishAudit::is_ready)
+    }
+
+    pub fn failing_crates(&self) -> impl Iterator<Item=&CratePublishAudit> {
+        self.crates.iter().filter(|c| !c.is_ready())
+    }
+}
+
+impl fmt::Display for PublishAuditReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for audit in &self.crates {
+            write!(f, "{}", audit)?;
+        }
+        let failing = self.failing_crates().count();
+        if failing == 0 {
+            writeln!(f, "all {} crates are ready to publish", self.crates.len())
+        } else {
+            writeln!(f, "{} of {} crates are not ready to publish", failing, self.crates.len())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_publish_audit_report {
+    use super::*;
+
+    #[traced_test]
+    fn serializes_findings_with_their_kind() {
+        let audit = CratePublishAuditBuilder::default()
+            .crate_name("a")
+            .version("0.1.0")
+            .findings(vec![PublishAuditFinding::InvalidKeyword { keyword: "Bad Word".into() }])
+            .build()
+            .unwrap();
+        let report = PublishAuditReport::new(vec![audit]);
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["crates"][0]["crate_name"], "a");
+        assert_eq!(json["crates"][0]["findings"][0]["kind"], "invalid-keyword");
+        assert_eq!(json["crates"][0]["findings"][0]["keyword"], "Bad Word");
+    }
+}
@@ -15,12 +15,12 @@ pub enum AddSubcommand {
 /// Next, in your `AddSubcommand::run` method (where the lifetime error appeared),
 /// simply clone the `crate_name` to pass as owned `String` to the helper.
 impl AddSubcommand {
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         trace!("Entering AddSubcommand::run with {:?}", self);
 
         match self {
-            AddSubcommand::Crate(cmd) => { cmd.run().await? }
-            AddSubcommand::InternalDep(cmd)  => { cmd.run().await? }
+            AddSubcommand::Crate(cmd) => { cmd.run(git).await? }
+            AddSubcommand::InternalDep(cmd)  => { cmd.run(git).await? }
         }
 
         Ok(())
@@ -15,30 +15,50 @@ pub struct AddCrateCommand {
     /// If true, we skip the Git clean check (i.e., do not require a clean repo)
     #[structopt(long = "skip-git-check")]
     skip_git_check: bool,
+
+    /// The crate template to start from (`ws template list` shows them all)
+    #[structopt(long = "template", default_value = "library")]
+    template: String,
+
+    /// Join this prefix group (registered in its facade, depending on its -3p crate)
+    /// instead of the one matching the crate name
+    #[structopt(long = "prefix-group")]
+    prefix_group: Option<String>,
 }
 
 impl AddCrateCommand {
 
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         trace!(
-            "AddSubcommand::Crate invoked with crate_name='{}', workspace_path='{:?}', skip_git_check={}",
+            "AddSubcommand::Crate invoked with crate_name='{}', workspace_path='{:?}', skip_git_check={}, template='{}', prefix_group={:?}",
             self.crate_name(),
             self.workspace_path(),
             self.skip_git_check(),
+            self.template(),
+            self.prefix_group(),
         );
 
         // We create an owned String from the crate_name field
         let crate_name_owned = self.crate_name().clone();
+        let template         = self.template().clone();
+        let prefix_group     = self.prefix_group().clone();
 
         // Now we pass that owned string into our helper
         run_with_workspace_and_crate_name(
             self.workspace_path().clone(),
-            *self.skip_git_check(),
+            (!*self.skip_git_check()).then_some(git),
             crate_name_owned, // <-- pass the owned String
-            |ws, new_crate_name| {
+            move |ws, new_crate_name| {
                 Box::pin(async move {
-                    info!("Now performing 'add new crate to workspace' for '{}'", new_crate_name);
-                    ws.add_new_crate_to_workspace(new_crate_name).await?;
+                    info!("Now performing 'add new crate to workspace' for '{}' from template '{}'", new_crate_name, template);
+                    let settings = WorkspaceSettings::load(ws.as_ref()).await?;
+                    let options = NewCrateOptionsBuilder::default()
+                        .template(template)
+                        .prefix_group(prefix_group)
+                        .registry(CrateTemplateRegistry::for_workspace(settings.templates(), ws.as_ref()))
+                        .build()
+                        .unwrap();
+                    ws.add_new_crate_from_template(new_crate_name, &options).await?;
                     debug!("Successfully added crate='{}' via subcommand logic", new_crate_name);
                     Ok(())
                 })
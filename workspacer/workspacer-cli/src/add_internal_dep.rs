@@ -26,7 +26,7 @@ pub struct AddInternalDepCommand {
 
 impl AddInternalDepCommand {
     /// This method drives the logic for adding a dependency from one crate to another.
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         // We'll use a new helper function (shown below) that is nearly identical
         // to `run_with_workspace_and_crate_name`, but handles *two* crate names.
         //
@@ -41,7 +41,7 @@ impl AddInternalDepCommand {
         // Step 2) Call the new helper
         run_with_workspace_and_two_crate_names(
             self.workspace_path().clone(),
-            *self.skip_git_check(),
+            (!*self.skip_git_check()).then_some(git),
             target_name_owned,
             dep_name_owned,
             |ws, target_name, dep_name| {
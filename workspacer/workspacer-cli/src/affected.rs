@@ -0,0 +1,82 @@
+// ---------------- [ File: workspacer-cli/src/affected.rs ]
+crate::ix!();
+
+/// `ws affected [--path <ws>] [--since <git-range>] [--files <f>...] [--test]`
+///
+/// Maps changed files (given directly, or taken from a git diff range) to the crates owning
+/// them, adds every crate depending on those, and prints the result in build order. With
+/// `--test`, runs `cargo build -p` / `cargo test -p` for exactly that set.
+#[derive(Getters,Debug,StructOpt)]
+#[getset(get="pub")]
+pub struct AffectedSubcommand {
+    /// Path to the workspace directory (defaults to the current directory)
+    #[structopt(long = "path")]
+    workspace_path: Option<PathBuf>,
+
+    /// A git diff range (`main..HEAD`), or a single ref to compare with the working tree
+    #[structopt(long = "since")]
+    since: Option<String>,
+
+    /// Changed files, absolute or relative to the current directory
+    #[structopt(long = "files")]
+    files: Vec<PathBuf>,
+
+    /// Build and test the affected crates instead of only listing them
+    #[structopt(long = "test")]
+    test: bool,
+}
+
+impl AffectedSubcommand {
+    pub async fn run(&self) -> Result<(), WorkspaceError> {
+        if self.since.is_none() && self.files.is_empty() {
+            error!("`ws affected` needs --since <git-range> and/or --files <path>...");
+            return Err(WorkspaceError::IoError {
+                io_error: Arc::new(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "no changed files given",
+                )),
+                context: "ws affected".to_string(),
+            });
+        }
+
+        let cwd = std::env::current_dir().map_err(|io| WorkspaceError::IoError {
+            io_error: Arc::new(io),
+            context:  "reading the current directory for `ws affected --files`".to_string(),
+        })?;
+
+        let since = self.since.clone();
+        let files: Vec<PathBuf> = self.files.iter().map(|f| cwd.join(f)).collect();
+        let test  = self.test;
+
+        run_with_workspace(self.workspace_path.clone(), /*git_check=*/None, move |ws| {
+            Box::pin(async move {
+                let mut changed = files;
+                if let Some(range) = &since {
+                    changed.extend(git_changed_files(ws.as_ref(), range).await?);
+                }
+
+                let affected = ws.affected_crates_for_files(&changed).await?;
+                print!("{}", affected);
+
+                for file in affected.unowned_files() {
+                    debug!("changed file belongs to no crate: {:?}", file);
+                }
+
+                if !test {
+                    return Ok(());
+                }
+
+                let runner = DefaultCommandRunner;
+                if *affected.workspace_wide() {
+                    ws.rebuild_or_test(&runner).await
+                } else if affected.is_empty() {
+                    println!("nothing to build or test");
+                    Ok(())
+                } else {
+                    rebuild_or_test_packages(&runner, ws.as_ref(), affected.affected()).await
+                }
+            })
+        })
+        .await
+    }
+}
@@ -17,17 +17,24 @@ pub enum AnalyzeSubcommand {
     /// Analyze the entire workspace
     #[structopt(name = "workspace")]
     Workspace(AnalyzeWorkspaceCommand),
+
+    /// Find unused and undeclared dependencies, optionally fixing Cargo.toml
+    #[structopt(name = "deps")]
+    Deps(AnalyzeDependenciesCommand),
 }
 
 impl AnalyzeSubcommand {
     #[tracing::instrument(level="trace", skip(self))]
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         match self {
             AnalyzeSubcommand::Crate(cmd) => {
-                cmd.run().await?;
+                cmd.run(git).await?;
             }
             AnalyzeSubcommand::Workspace(cmd) => {
-                cmd.run().await?;
+                cmd.run(git).await?;
+            }
+            AnalyzeSubcommand::Deps(cmd) => {
+                cmd.run(git).await?;
             }
         }
         Ok(())
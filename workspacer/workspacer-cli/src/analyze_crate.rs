@@ -23,14 +23,14 @@ pub struct AnalyzeCrateCommand {
 
 impl AnalyzeCrateCommand {
     #[tracing::instrument(level="trace", skip(self))]
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         let crate_name_owned = self.crate_name().clone();
 
         // 1) We reuse our existing helper `run_with_workspace_and_crate_name`.
         //    That loads the workspace, optionally checks Git, etc.
         run_with_workspace_and_crate_name(
             self.workspace_path().clone(),
-            *self.skip_git_check(),
+            (!*self.skip_git_check()).then_some(git),
             crate_name_owned,
             // 2) Provide a closure that finds the crate, calls `CrateAnalysis::new(...)`,
             //    and then prints or logs the result.
@@ -50,9 +50,10 @@ impl AnalyzeCrateCommand {
                     let handle = arc_h.lock().await.clone();
                     
                     // c) create the crate analysis
-                    let analysis = CrateAnalysis::new(&handle).await?;
-                    info!("Crate analysis complete for '{}'\n{:#?}", crate_name,analysis);
-                    Ok(())
+                    let analysis = CrateAnalysis::new(&handle).await?.with_crate_name(crate_name);
+                    emit_report(&analysis, || {
+                        info!("Crate analysis complete for '{}'\n{:#?}", crate_name, analysis);
+                    })
                 })
             },
         )
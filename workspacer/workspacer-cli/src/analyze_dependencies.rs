@@ -0,0 +1,77 @@
+// ---------------- [ File: workspacer-cli/src/analyze_dependencies.rs ]
+crate::ix!();
+
+/// Reports unused `[dependencies]`/`[dev-dependencies]` entries and crates used by path
+/// that are only available transitively. With `--crate`, only that crate is analyzed.
+/// With `--fix`, unused entries are removed and test-only dependencies are moved to
+/// `[dev-dependencies]`, editing each Cargo.toml in place.
+#[derive(Debug, StructOpt, Getters, Setters)]
+#[getset(get="pub")]
+pub struct AnalyzeDependenciesCommand {
+    /// Restrict the analysis to a single crate
+    #[structopt(long = "crate")]
+    crate_name: Option<String>,
+
+    /// If provided, we use this as the workspace root
+    #[structopt(long = "path")]
+    workspace_path: Option<PathBuf>,
+
+    /// Skip the Git clean check `--fix` does before editing
+    #[structopt(long = "skip-git-check")]
+    skip_git_check: bool,
+
+    /// Rewrite Cargo.toml files to drop unused entries and move test-only ones
+    #[structopt(long = "fix")]
+    fix: bool,
+}
+
+impl AnalyzeDependenciesCommand {
+    #[tracing::instrument(level="trace", skip(self))]
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
+        let crate_filter = self.crate_name().clone();
+        let fix          = *self.fix();
+
+        // only `--fix` edits Cargo.toml files, so only it needs a clean tree
+        let git_check = (fix && !*self.skip_git_check()).then_some(git);
+
+        run_with_workspace(
+            self.workspace_path().clone(),
+            git_check,
+            move |ws| {
+                Box::pin(async move {
+                    let mut reports = Vec::new();
+
+                    for arc_h in ws.crates() {
+                        let handle = arc_h.lock().await.clone();
+                        if let Some(only) = &crate_filter {
+                            if handle.name() != only.as_str() {
+                                continue;
+                            }
+                        }
+
+                        let usage = handle.analyze_dependency_usage().await?;
+
+                        if fix && !usage.is_clean() {
+                            let touched = handle.fix_dependency_usage(&usage).await?;
+                            info!("fixed {} Cargo.toml entries in crate='{}'", touched.len(), handle.name());
+                        }
+
+                        reports.push(usage);
+                    }
+
+                    if let Some(only) = &crate_filter {
+                        if reports.is_empty() {
+                            error!("No crate named '{}' found in workspace", only);
+                            return Err(CrateError::CrateNotFoundInWorkspace {
+                                crate_name: only.to_owned(),
+                            }.into());
+                        }
+                    }
+
+                    let usage = WorkspaceDependencyUsage::new(reports);
+                    emit_report(&usage, || println!("{}", usage))
+                })
+            },
+        ).await
+    }
+}
@@ -17,20 +17,21 @@ pub struct AnalyzeWorkspaceCommand {
 
 impl AnalyzeWorkspaceCommand {
     #[tracing::instrument(level="trace", skip(self))]
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         // We define a simpler helper that loads the workspace
         // and optionally checks Git, but does not require a crate name.
         // We'll call it `run_with_workspace`.
         run_with_workspace(
             self.workspace_path().clone(),
-            *self.skip_git_check(),
+            (!*self.skip_git_check()).then_some(git),
             |ws| {
                 Box::pin(async move {
                     // Here we do a full workspace analysis via `ws.analyze()`.
                     // That returns a `WorkspaceSizeAnalysis`.
                     let analysis = ws.analyze().await?;
-                    info!("Workspace analysis complete.\n{:#?}",analysis);
-                    Ok(())
+                    emit_report(&analysis, || {
+                        info!("Workspace analysis complete.\n{:#?}", analysis);
+                    })
                 })
             },
         ).await
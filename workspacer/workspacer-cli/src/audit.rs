@@ -0,0 +1,63 @@
+// ---------------- [ File: workspacer-cli/src/audit.rs ]
+crate::ix!();
+
+/// `ws audit [--path <ws>] [--advisory-db <dir>] [--index <dir>] [--sources <dir>]...`
+///
+/// Offline license and supply-chain audit: a license inventory per member crate checked
+/// against the `[audit]` allow/deny lists, RustSec advisories and yanked versions matched
+/// against local snapshots, and `license`/`authors`/`repository` consistency across members.
+/// The flags override the snapshot paths from `[audit]`. Fails if the report lists problems.
+#[derive(Getters,Debug,StructOpt)]
+#[getset(get="pub")]
+pub struct AuditCommand {
+    /// Path to the workspace directory (defaults to the current directory)
+    #[structopt(long = "path")]
+    workspace_path: Option<PathBuf>,
+
+    /// A checkout of the RustSec advisory database
+    #[structopt(long = "advisory-db")]
+    advisory_db: Option<PathBuf>,
+
+    /// A copy of the crates.io index, for yanked versions
+    #[structopt(long = "index")]
+    index: Option<PathBuf>,
+
+    /// A directory of unpacked crate sources to read licenses from (repeatable)
+    #[structopt(long = "sources")]
+    sources: Vec<PathBuf>,
+}
+
+impl AuditCommand {
+    pub async fn run(&self) -> Result<(), WorkspaceError> {
+        let advisory_db = self.advisory_db.clone();
+        let index       = self.index.clone();
+        let sources     = self.sources.clone();
+
+        run_with_workspace(self.workspace_path.clone(), /*git_check=*/None, move |ws| {
+            Box::pin(async move {
+                let settings = WorkspaceSettings::load(ws.as_ref()).await?;
+
+                let mut builder = AuditOptionsBuilder::from_settings(settings.audit(), ws.as_ref());
+                if advisory_db.is_some() {
+                    builder.advisory_db(advisory_db);
+                }
+                if index.is_some() {
+                    builder.index(index);
+                }
+                if !sources.is_empty() {
+                    builder.sources(sources);
+                }
+                let options = builder.build().unwrap();
+
+                let report = ws.audit(&options).await?;
+                emit(&report)?;
+
+                match report.problem_count() {
+                    0     => Ok(()),
+                    count => Err(AuditError::ProblemsFound { count }.into()),
+                }
+            })
+        })
+        .await
+    }
+}
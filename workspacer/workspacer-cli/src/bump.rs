@@ -23,11 +23,11 @@ pub enum BumpSubcommand {
 }
 
 impl BumpSubcommand {
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         match self {
-            BumpSubcommand::Workspace(cmd)        => cmd.run().await,
-            BumpSubcommand::SingleCrate(cmd)      => cmd.run().await,
-            BumpSubcommand::CrateDownstreams(cmd) => cmd.run().await,
+            BumpSubcommand::Workspace(cmd)        => cmd.run(git).await,
+            BumpSubcommand::SingleCrate(cmd)      => cmd.run(git).await,
+            BumpSubcommand::CrateDownstreams(cmd) => cmd.run(git).await,
         }
     }
 }
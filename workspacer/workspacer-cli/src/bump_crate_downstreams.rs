@@ -21,21 +21,27 @@ pub struct BumpCrateDownstreamsCommand {
     /// The release type to apply (major, minor, patch, alpha[=N])
     #[structopt(long = "release", default_value = "patch")]
     release_arg: ReleaseArg,
+
+    #[structopt(flatten)]
+    release_record: ReleaseRecordFlags,
 }
 
 impl BumpCrateDownstreamsCommand {
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         let crate_name_owned = self.crate_name().clone();
         let ReleaseArg(release_type) = self.release_arg().clone();
+        let record_options = self.release_record().options();
 
         // We'll do `run_with_workspace_and_crate_name`, find the crate,
         // but *then* we call `bump_crate_and_downstreams` on the workspace.
         run_with_workspace_and_crate_name(
             self.workspace_path().clone(),
-            *self.skip_git_check(),
+            (!*self.skip_git_check()).then_some(git),
             crate_name_owned,
             move |ws, found_crate_name| {
                 Box::pin(async move {
+                    let before = CrateVersions::capture(ws).await?;
+
                     // find the crate
                     let arc_crate = ws.find_crate_by_name(found_crate_name).await.ok_or_else(|| {
                         error!("No crate named '{}' found in workspace", found_crate_name);
@@ -57,6 +63,8 @@ impl BumpCrateDownstreamsCommand {
                             err
                         })?;
 
+                    ws.record_releases(&before, &record_options).await?;
+
                     info!(
                         "Successfully bumped crate='{}' + downstreams => release={:?}",
                         found_crate_name, release_type
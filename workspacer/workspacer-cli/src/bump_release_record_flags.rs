@@ -0,0 +1,26 @@
+// ---------------- [ File: workspacer-cli/src/bump_release_record_flags.rs ]
+crate::ix!();
+
+/// What the `ws bump` commands record about the crates they bump. By default each bumped crate
+/// gets a `CHANGELOG.md` entry, and the bump is committed and tagged `crate-name-vX.Y.Z`.
+#[derive(Debug, Clone, StructOpt, Getters)]
+#[getset(get = "pub")]
+pub struct ReleaseRecordFlags {
+    /// Do not append entries to the bumped crates' CHANGELOG.md
+    #[structopt(long = "no-changelog")]
+    no_changelog: bool,
+
+    /// Do not commit the bump or create crate-name-vX.Y.Z tags
+    #[structopt(long = "no-tag")]
+    no_tag: bool,
+}
+
+impl ReleaseRecordFlags {
+    pub fn options(&self) -> ReleaseRecordOptions {
+        ReleaseRecordOptionsBuilder::default()
+            .write_changelog(!self.no_changelog)
+            .tag(!self.no_tag)
+            .build()
+            .unwrap()
+    }
+}
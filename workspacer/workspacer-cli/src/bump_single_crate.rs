@@ -21,20 +21,26 @@ pub struct BumpSingleCrateCommand {
     /// The release type to apply (major, minor, patch, alpha[=N])
     #[structopt(long = "release", default_value = "patch")]
     release_arg: ReleaseArg,
+
+    #[structopt(flatten)]
+    release_record: ReleaseRecordFlags,
 }
 
 impl BumpSingleCrateCommand {
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         let crate_name_owned = self.crate_name().clone();
         let ReleaseArg(release_type) = self.release_arg().clone();
+        let record_options = self.release_record().options();
 
         // We'll do `run_with_workspace_and_crate_name` => load workspace => find crate => apply Bump
         run_with_workspace_and_crate_name(
             self.workspace_path().clone(),
-            *self.skip_git_check(),
+            (!*self.skip_git_check()).then_some(git),
             crate_name_owned,
             move |ws, found_crate_name| {
                 Box::pin(async move {
+                    let before = CrateVersions::capture(ws).await?;
+
                     let arc_crate = ws.find_crate_by_name(found_crate_name).await.ok_or_else(|| {
                         error!("No crate named '{}' found in workspace", found_crate_name);
                         CrateError::CrateNotFoundInWorkspace {
@@ -55,6 +61,8 @@ impl BumpSingleCrateCommand {
                         }
                     })?;
 
+                    ws.record_releases(&before, &record_options).await?;
+
                     info!(
                         "Successfully bumped single crate='{}' => release={:?}",
                         found_crate_name, release_type
@@ -16,17 +16,23 @@ pub struct BumpWorkspaceCommand {
     /// The release type to apply (major, minor, patch, alpha[=N])
     #[structopt(long = "release", default_value = "patch")]
     release_arg: ReleaseArg,
+
+    #[structopt(flatten)]
+    release_record: ReleaseRecordFlags,
 }
 
 impl BumpWorkspaceCommand {
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         let ReleaseArg(release_type) = self.release_arg().clone();
+        let record_options = self.release_record().options();
         // We'll do `run_with_workspace` => load the workspace => call `bump_all(release_type)`
         run_with_workspace(
             self.workspace_path().clone(),
-            *self.skip_git_check(),
+            (!*self.skip_git_check()).then_some(git),
             move |ws| {
                 Box::pin(async move {
+                    let before = CrateVersions::capture(ws).await?;
+
                     // `ws` is a &mut Workspace<...>
                     // We call `BumpAll::bump_all` on it
                     ws.bump_all(release_type.clone()).await.map_err(|bump_err| {
@@ -35,6 +41,8 @@ impl BumpWorkspaceCommand {
                         WorkspaceError::from(bump_err)
                     })?;
 
+                    ws.record_releases(&before, &record_options).await?;
+
                     info!("Successfully bumped entire workspace with release={:?}", release_type);
                     Ok(())
                 })
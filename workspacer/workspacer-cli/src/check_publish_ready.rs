@@ -22,10 +22,10 @@ pub enum CheckPublishReadySubcommand {
 }
 
 impl CheckPublishReadySubcommand {
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         match self {
-            CheckPublishReadySubcommand::Crate(cmd) => cmd.run().await,
-            CheckPublishReadySubcommand::Workspace(cmd) => cmd.run().await,
+            CheckPublishReadySubcommand::Crate(cmd) => cmd.run(git).await,
+            CheckPublishReadySubcommand::Workspace(cmd) => cmd.run(git).await,
         }
     }
 }
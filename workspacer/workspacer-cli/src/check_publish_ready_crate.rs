@@ -23,13 +23,13 @@ pub struct CheckPublishReadyCrateCommand {
 
 impl CheckPublishReadyCrateCommand {
     #[tracing::instrument(level = "trace", skip(self))]
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         let crate_name_owned = self.crate_name().clone();
 
         // We do our usual pattern: load the workspace, optionally check Git, validate integrity, etc.
         run_with_workspace_and_crate_name(
             self.workspace_path().clone(),
-            *self.skip_git_check(),
+            (!*self.skip_git_check()).then_some(git),
             crate_name_owned,
             |ws, name| {
                 Box::pin(async move {
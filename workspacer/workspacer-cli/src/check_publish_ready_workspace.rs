@@ -18,11 +18,11 @@ pub struct CheckPublishReadyWorkspaceCommand {
 
 impl CheckPublishReadyWorkspaceCommand {
     #[tracing::instrument(level = "trace", skip(self))]
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         // We do `run_with_workspace` => obtains &mut Workspace => call `ws.ready_for_cargo_publish().await`
         run_with_workspace(
             self.workspace_path().clone(),
-            *self.skip_git_check(),
+            (!*self.skip_git_check()).then_some(git),
             |ws| {
                 Box::pin(async move {
                     // This calls the library trait on the entire workspace
@@ -16,10 +16,10 @@ pub enum CleanupSubcommand {
 }
 
 impl CleanupSubcommand {
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         match self {
-            CleanupSubcommand::Crate(cmd) => cmd.run().await,
-            CleanupSubcommand::Workspace(cmd) => cmd.run().await,
+            CleanupSubcommand::Crate(cmd) => cmd.run(git).await,
+            CleanupSubcommand::Workspace(cmd) => cmd.run(git).await,
         }
     }
 }
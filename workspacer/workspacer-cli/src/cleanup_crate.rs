@@ -19,14 +19,14 @@ pub struct CleanupCrateCommand {
 }
 
 impl CleanupCrateCommand {
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         let crate_name_owned = self.crate_name().clone();
 
         // 1) Load the workspace, optionally ensure Git is clean,
         //    find the crate, and pass it to a closure that calls `cleanup_crate()`.
         run_with_workspace_and_crate_name(
             self.workspace_path().clone(),
-            *self.skip_git_check(),
+            (!*self.skip_git_check()).then_some(git),
             crate_name_owned,
             |ws, name| {
                 Box::pin(async move {
@@ -15,11 +15,11 @@ pub struct CleanupWorkspaceCommand {
 }
 
 impl CleanupWorkspaceCommand {
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         // 1) load the workspace, optionally ensure Git is clean
         run_with_workspace(
             self.workspace_path().clone(),
-            *self.skip_git_check(),
+            (!*self.skip_git_check()).then_some(git),
             move |ws| {
                 Box::pin(async move {
                     // 2) call `ws.cleanup_workspace().await`
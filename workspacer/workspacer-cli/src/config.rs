@@ -0,0 +1,39 @@
+// ---------------- [ File: workspacer-cli/src/config.rs ]
+crate::ix!();
+
+/// `ws config show [--path <ws>]`
+///
+/// Prints the workspace settings (`workspacer.toml` or `[workspace.metadata.workspacer]`)
+/// with every per-crate override resolved, after checking that every crate they name exists.
+#[derive(Debug, StructOpt)]
+pub enum ConfigSubcommand {
+    /// Print the effective settings, workspace-wide and per crate
+    #[structopt(name = "show")]
+    Show {
+        /// Path to the workspace directory (defaults to the current directory)
+        #[structopt(long = "path")]
+        workspace_path: Option<PathBuf>,
+    },
+}
+
+impl ConfigSubcommand {
+    pub async fn run(&self) -> Result<(), WorkspaceError> {
+        match self {
+            ConfigSubcommand::Show { workspace_path } => {
+                run_with_workspace(workspace_path.clone(), /*git_check=*/None, move |ws| {
+                    Box::pin(async move {
+                        let settings = WorkspaceSettings::load(ws.as_ref()).await?;
+
+                        let mut members = ws.get_all_crate_names().await;
+                        members.sort();
+                        settings.validate_against_members(&members)?;
+
+                        print!("{}", settings.render_effective(&members));
+                        Ok(())
+                    })
+                })
+                .await
+            }
+        }
+    }
+}
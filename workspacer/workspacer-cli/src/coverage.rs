@@ -18,10 +18,42 @@ pub enum CoverageSubcommand {
 }
 
 impl CoverageSubcommand {
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         match self {
-            CoverageSubcommand::Crate(cmd) => cmd.run().await,
-            CoverageSubcommand::Workspace(cmd) => cmd.run().await,
+            CoverageSubcommand::Crate(cmd) => cmd.run(git).await,
+            CoverageSubcommand::Workspace(cmd) => cmd.run(git).await,
         }
     }
 }
+
+/// Shared tail of `ws coverage crate` and `ws coverage workspace`: narrow the report to a git
+/// diff if asked, print the breakdown, write LCOV/HTML, then enforce the configured thresholds.
+pub async fn finish_detailed_coverage(
+    mut report:     DetailedCoverageReport,
+    workspace_root: &Path,
+    changed_since:  Option<&str>,
+    lcov:           Option<&Path>,
+    html:           Option<&Path>,
+) -> Result<(), WorkspaceError> {
+    if let Some(git_ref) = changed_since {
+        let changed = ChangedLines::since(workspace_root, git_ref).await?;
+        report.restrict_to_changed(git_ref, &changed);
+    }
+
+    emit(&report)?;
+
+    if let Some(path) = lcov {
+        report.write_lcov(path).await?;
+        info!("wrote lcov tracefile to {:?}", path);
+    }
+
+    if let Some(path) = html {
+        report.write_html(path).await?;
+        info!("wrote html coverage report to {:?}", path);
+    }
+
+    let thresholds = CoverageThresholds::load(workspace_root).await?;
+    report.check_thresholds(&thresholds)?;
+
+    Ok(())
+}
@@ -1,7 +1,8 @@
 // ---------------- [ File: workspacer-cli/src/coverage_crate.rs ]
 crate::ix!();
 
-/// Subcommand for `ws coverage crate --crate <NAME> [--workspace ...] [--skip-git-check]`
+/// Subcommand for `ws coverage crate --crate <NAME> [--workspace ...] [--skip-git-check]
+/// [--changed-since <REF>] [--lcov <FILE>] [--html <FILE>]`
 #[derive(Debug,StructOpt,Getters,Setters)]
 #[getset(get="pub")]
 pub struct CoverageCrateCommand {
@@ -16,17 +17,32 @@ pub struct CoverageCrateCommand {
     /// skip git check
     #[structopt(long = "skip-git-check")]
     skip_git_check: bool,
+
+    /// only report functions and lines changed since this git ref
+    #[structopt(long = "changed-since")]
+    changed_since: Option<String>,
+
+    /// write an LCOV tracefile here
+    #[structopt(long = "lcov")]
+    lcov: Option<PathBuf>,
+
+    /// write an HTML report here
+    #[structopt(long = "html")]
+    html: Option<PathBuf>,
 }
 
 impl CoverageCrateCommand {
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         let crate_name_owned = self.crate_name().clone();
+        let changed_since    = self.changed_since().clone();
+        let lcov             = self.lcov().clone();
+        let html             = self.html().clone();
 
         run_with_workspace_and_crate_name(
             self.workspace_path().clone(),
-            *self.skip_git_check(),
+            (!*self.skip_git_check()).then_some(git),
             crate_name_owned,
-            |ws, name| {
+            move |ws, name| {
                 Box::pin(async move {
                     // 1) find crate by name
                     let arc_crate = ws.find_crate_by_name(name).await.ok_or_else(|| {
@@ -39,28 +55,25 @@ impl CoverageCrateCommand {
                     // 2) lock handle
                     let handle = arc_crate.lock().await.clone();
 
-                    // 3) run coverage for just this crate => from new trait
-                    let coverage_report = handle.run_tests_with_coverage().await?;
+                    // 3) run coverage for just this crate, keeping the per-file data
+                    let report  = handle.run_detailed_test_coverage().await?;
+                    let summary = report.summary();
 
                     info!(
                         "Coverage for crate='{}': total_coverage={}%, covered_lines={}/{}",
                         handle.name(),
-                        coverage_report.total_coverage(),
-                        coverage_report.covered_lines(),
-                        coverage_report.total_lines(),
-                    );
-
-                    // optionally, print or store the coverage
-                    println!(
-                        "Coverage for crate '{}' => {:.2}% (covered: {}, missed: {}, total: {})",
-                        handle.name(),
-                        coverage_report.total_coverage(),
-                        coverage_report.covered_lines(),
-                        coverage_report.missed_lines(),
-                        coverage_report.total_lines()
+                        summary.total_coverage(),
+                        summary.covered_lines(),
+                        summary.total_lines(),
                     );
 
-                    Ok(())
+                    finish_detailed_coverage(
+                        report,
+                        ws.as_ref(),
+                        changed_since.as_deref(),
+                        lcov.as_deref(),
+                        html.as_deref(),
+                    ).await
                 })
             },
         )
@@ -1,7 +1,8 @@
 // ---------------- [ File: workspacer-cli/src/coverage_workspace.rs ]
 crate::ix!();
 
-/// Subcommand for `ws coverage workspace [--path ...] [--skip-git-check]`
+/// Subcommand for `ws coverage workspace [--path ...] [--skip-git-check]
+/// [--changed-since <REF>] [--lcov <FILE>] [--html <FILE>]`
 #[derive(Debug,StructOpt,Getters,Setters)]
 #[getset(get="pub")]
 pub struct CoverageWorkspaceCommand {
@@ -12,28 +13,42 @@ pub struct CoverageWorkspaceCommand {
     /// skip git check
     #[structopt(long = "skip-git-check")]
     skip_git_check: bool,
+
+    /// only report functions and lines changed since this git ref
+    #[structopt(long = "changed-since")]
+    changed_since: Option<String>,
+
+    /// write an LCOV tracefile here
+    #[structopt(long = "lcov")]
+    lcov: Option<PathBuf>,
+
+    /// write an HTML report here
+    #[structopt(long = "html")]
+    html: Option<PathBuf>,
 }
 
 impl CoverageWorkspaceCommand {
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
-        // We'll do `run_with_workspace(...)` => load => call `ws.run_tests_with_coverage()`.
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
+        let changed_since = self.changed_since().clone();
+        let lcov          = self.lcov().clone();
+        let html          = self.html().clone();
+
+        // We'll do `run_with_workspace(...)` => load => gather per-crate, per-file coverage.
         run_with_workspace(
             self.workspace_path().clone(),
-            *self.skip_git_check(),
-            |ws| {
+            (!*self.skip_git_check()).then_some(git),
+            move |ws| {
                 Box::pin(async move {
-                    let coverage_report = ws.run_tests_with_coverage().await?;
-                    info!("Workspace coverage: {coverage_report:?}");
-
-                    println!(
-                        "Workspace coverage => {:.2}% (covered: {}, missed: {}, total: {})",
-                        coverage_report.total_coverage(),
-                        coverage_report.covered_lines(),
-                        coverage_report.missed_lines(),
-                        coverage_report.total_lines()
-                    );
-
-                    Ok(())
+                    let report = ws.run_detailed_test_coverage().await?;
+                    info!("Workspace coverage: {:?}", report.summary());
+
+                    finish_detailed_coverage(
+                        report,
+                        ws.as_ref(),
+                        changed_since.as_deref(),
+                        lcov.as_deref(),
+                        html.as_deref(),
+                    ).await
                 })
             },
         )
@@ -13,10 +13,10 @@ pub enum DetectCyclesSubcommand {
 }
 
 impl DetectCyclesSubcommand {
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         match self {
-            DetectCyclesSubcommand::Crate(cmd) => cmd.run().await,
-            DetectCyclesSubcommand::Workspace(cmd) => cmd.run().await,
+            DetectCyclesSubcommand::Crate(cmd) => cmd.run(git).await,
+            DetectCyclesSubcommand::Workspace(cmd) => cmd.run(git).await,
         }
     }
 }
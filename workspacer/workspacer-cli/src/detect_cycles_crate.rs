@@ -22,7 +22,7 @@ pub struct DetectCyclesCrateCommand {
 }
 
 impl DetectCyclesCrateCommand {
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         // Clone out each needed field so that the closure can be `'static`:
         let crate_name_owned = self.crate_name.clone();
         let workspace_path_owned = self.workspace_path.clone();
@@ -31,7 +31,7 @@ impl DetectCyclesCrateCommand {
 
         run_with_workspace_and_crate_name(
             workspace_path_owned,
-            skip_git_check_flag,
+            (!skip_git_check_flag).then_some(git),
             crate_name_owned,
             move |ws, name| {
                 Box::pin(async move {
@@ -18,7 +18,7 @@ pub struct DetectCyclesWorkspaceCommand {
 }
 
 impl DetectCyclesWorkspaceCommand {
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         // Again, copy out fields
         let workspace_path_owned = self.workspace_path.clone();
         let verbose_flag = self.verbose;
@@ -26,7 +26,7 @@ impl DetectCyclesWorkspaceCommand {
 
         run_with_workspace(
             workspace_path_owned,
-            skip_check,
+            (!skip_check).then_some(git),
             move |ws| {
                 Box::pin(async move {
                     if verbose_flag {
@@ -0,0 +1,43 @@
+// ---------------- [ File: workspacer-cli/src/edition.rs ]
+crate::ix!();
+
+#[derive(Debug, StructOpt)]
+pub enum EditionSubcommand {
+    /// Run `cargo fix --edition` crate by crate in topological order, bumping each crate's
+    /// `edition` and rolling back the crates that fail
+    Migrate {
+        /// Path to the workspace directory (defaults to the current directory)
+        #[structopt(long = "path")]
+        workspace_path: Option<PathBuf>,
+
+        /// The edition to end up on
+        #[structopt(long = "to", default_value = "2024")]
+        to: RustEdition,
+    },
+}
+
+impl EditionSubcommand {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
+        match self {
+            EditionSubcommand::Migrate { workspace_path, to } => {
+                let target = *to;
+
+                // rewrites sources; a clean tree keeps the result reviewable
+                run_with_workspace(workspace_path.clone(), Some(git), move |ws| {
+                    Box::pin(async move {
+                        let report = ws.migrate_edition(target).await?;
+                        emit(&report)?;
+
+                        let rolled_back = report.rolled_back_crates();
+                        if rolled_back.is_empty() {
+                            Ok(())
+                        } else {
+                            Err(MsrvError::EditionMigrationRolledBack { crates: rolled_back }.into())
+                        }
+                    })
+                })
+                .await
+            }
+        }
+    }
+}
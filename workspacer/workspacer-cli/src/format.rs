@@ -12,10 +12,10 @@ pub enum FormatSubcommand {
 }
 
 impl FormatSubcommand {
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         match self {
-            FormatSubcommand::Imports(cmd) => cmd.run().await,
-            FormatSubcommand::AllImports(cmd) => cmd.run().await,
+            FormatSubcommand::Imports(cmd) => cmd.run(git).await,
+            FormatSubcommand::AllImports(cmd) => cmd.run(git).await,
         }
     }
 }
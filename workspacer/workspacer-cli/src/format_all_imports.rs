@@ -14,12 +14,12 @@ pub struct FormatAllImportsCommand {
 }
 
 impl FormatAllImportsCommand {
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         let ws_path = self.workspace_path.clone();
         let skip_flag = self.skip_git_check;
 
         // Use our standard "run_with_workspace" helper that doesn't need a crate name
-        run_with_workspace(ws_path, skip_flag, move |ws| {
+        run_with_workspace(ws_path, (!skip_flag).then_some(git), move |ws| {
             Box::pin(async move {
                 // The `SortAndFormatImports` trait is also implemented for Workspaces
                 ws.sort_and_format_imports().await.map_err(|err| {
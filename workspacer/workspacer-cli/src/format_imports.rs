@@ -18,7 +18,7 @@ pub struct FormatImportsCommand {
 }
 
 impl FormatImportsCommand {
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         let crate_name_owned = self.crate_name.clone();
         let skip_flag = self.skip_git_check;
         let ws_path = self.workspace_path.clone();
@@ -26,7 +26,7 @@ impl FormatImportsCommand {
         // Use our standard helper that loads a workspace + optional crate name
         run_with_workspace_and_crate_name(
             ws_path,
-            skip_flag,
+            (!skip_flag).then_some(git),
             crate_name_owned,
             move |ws, the_crate_name| {
                 Box::pin(async move {
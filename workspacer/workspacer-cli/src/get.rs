@@ -24,7 +24,7 @@ pub enum GetSubcommand {
 
 impl GetSubcommand {
     /// Main entrypoint: routes to the chosen variant's logic.
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         match self {
             // 1) If user typed `ws get lock-versions ...`
             GetSubcommand::LockVersions {
@@ -33,7 +33,8 @@ impl GetSubcommand {
             } => {
                 get_lock_versions_flow(
                     workspace_path.clone(),
-                    *skip_git_check
+                    *skip_git_check,
+                    git,
                 ).await
             },
 
@@ -42,6 +43,7 @@ impl GetSubcommand {
                 get_toml_section_flow(
                     section.clone(),
                     crate_name.clone(),
+                    git,
                 ).await
             }
         }
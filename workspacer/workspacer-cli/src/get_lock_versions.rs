@@ -5,11 +5,12 @@ crate::ix!();
 pub async fn get_lock_versions_flow(
     workspace_path: Option<PathBuf>,
     skip_git_check: bool,
+    git: &GitOperationSettings,
 ) -> Result<(), WorkspaceError> 
 {
     // We can re-use your run_with_workspace(...) helper if you want to
     // ensure we have a valid workspace, do Git checks, etc.
-    run_with_workspace(workspace_path, skip_git_check, |ws| {
+    run_with_workspace(workspace_path, (!skip_git_check).then_some(git), |ws| {
         Box::pin(async move {
             let root_dir = ws.as_ref();  // the workspace root path
 
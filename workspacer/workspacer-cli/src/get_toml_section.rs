@@ -11,11 +11,12 @@ crate::ix!();
 pub async fn get_toml_section_flow(
     section: String,
     crate_name: Option<String>,
+    git: &GitOperationSettings,
 ) -> Result<(), WorkspaceError> {
     // We'll reuse `run_with_workspace(...)` so we load the workspace from the current dir
     // (or whichever default logic that function provides).
     // We won't do a Git check here, to keep it simple. If you want a Git check, adapt as needed.
-    run_with_workspace(None, false, move |ws| {
+    run_with_workspace(None, Some(git), move |ws| {
         Box::pin(async move {
             match crate_name {
                 Some(ref name) => {
@@ -6,16 +6,68 @@ crate::ix!();
 pub enum GitSubcommand {
     /// Perform a git commit
     Commit,
+
+    /// List the member crates with uncommitted changes under their directory
+    Status {
+        /// Path to the workspace directory (defaults to the current directory)
+        #[structopt(long = "path")]
+        workspace_path: Option<PathBuf>,
+    },
+
+    /// Revert the last change made with `--commit-each` as one commit; repeat to go further back
+    Undo {
+        /// Path to the workspace directory (defaults to the current directory)
+        #[structopt(long = "path")]
+        workspace_path: Option<PathBuf>,
+    },
+}
+
+/// `{"dirty-crates": [..]}`, the JSON form of `ws git status`.
+#[derive(Serialize,Debug)]
+#[serde(rename_all = "kebab-case")]
+struct DirtyCratesReport<'a> {
+    dirty_crates: &'a [String],
 }
 
 impl GitSubcommand {
     pub async fn run(&self) -> Result<(),WorkspaceError> {
-        todo!(
-            "This functionality is not implemented yet. 
-            This command will scan the changes to be committed and partition them sets corresponding to their crate of origin.
-            Treating these sets in crate topology order, this command will use a language model to craft a maximally useful commit message for the set of changes, aware of the interface to the crate (via workspacer-consolidate).
-            The command will then apply the ai generated commits in playback/dependency order such that we can meaningfully scroll through our commit history and see what is going on at each step.
-            To help implement this functionality, please visit https://github.com/klebs6/klebs-general to submit a PR"
-        );
+        match self {
+            GitSubcommand::Commit => {
+                todo!(
+                    "This functionality is not implemented yet. 
+                    This command will scan the changes to be committed and partition them sets corresponding to their crate of origin.
+                    Treating these sets in crate topology order, this command will use a language model to craft a maximally useful commit message for the set of changes, aware of the interface to the crate (via workspacer-consolidate).
+                    The command will then apply the ai generated commits in playback/dependency order such that we can meaningfully scroll through our commit history and see what is going on at each step.
+                    To help implement this functionality, please visit https://github.com/klebs6/klebs-general to submit a PR"
+                );
+            }
+            GitSubcommand::Status { workspace_path } => {
+                run_with_workspace(workspace_path.clone(), /*git_check=*/None, |ws| {
+                    Box::pin(async move {
+                        let dirty = ws.dirty_crates().await?;
+                        emit_report(&DirtyCratesReport { dirty_crates: &dirty }, || {
+                            if dirty.is_empty() {
+                                println!("every crate is clean");
+                            }
+                            for name in &dirty {
+                                println!("{}", name);
+                            }
+                        })
+                    })
+                })
+                .await
+            }
+            GitSubcommand::Undo { workspace_path } => {
+                let dir = match workspace_path {
+                    Some(path) => path.clone(),
+                    None       => std::env::current_dir().map_err(|e| WorkspaceError::IoError {
+                        io_error: Arc::new(e),
+                        context:  "reading the current directory".to_string(),
+                    })?,
+                };
+                let reverted = revert_last_workspacer_operation(&dir).await?;
+                emit(&reverted)
+            }
+        }
     }
 }
@@ -6,3 +6,4 @@ pub(crate) use workspacer_prune_bad_category_slugs::*;pub(crate) use workspacer_
 pub(crate) use workspacer_run::*;
 pub(crate) use workspacer_file_filter::*;
 pub(crate) use workspacer_either::*;
+pub(crate) use ::serde_derive::Serialize;
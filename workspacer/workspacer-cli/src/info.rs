@@ -44,53 +44,45 @@ impl InfoSubcommand {
                     WorkspaceError::CrateError(err)
                 })?;
 
-                println!("Crate path='{}'", crate_name.display());
-                println!("  name='{}'", name);
-                println!("  version='{}'", version);
-                println!("  private?={}", is_priv);
-
                 // 3) Check if tests/ directory is present, list test files if so
-                if handle.has_tests_directory() {
+                let test_files = if handle.has_tests_directory() {
                     let test_files = handle.test_files().await.map_err(|err| {
                         error!("Crate '{}' => test_files() failed: {:?}", name, err);
                         WorkspaceError::CrateError(err)
                     })?;
                     info!("Crate '{}' => found {} test file(s)", name, test_files.len());
-                    println!("  tests directory is present. Test files: {:?}", test_files);
+                    Some(test_files)
                 } else {
-                    println!("  no tests/ directory found.");
-                }
+                    None
+                };
 
                 // 4) Check for README
-                match handle.readme_path().await {
-                    Ok(Some(readme)) => {
-                        info!("Crate '{}' => found README at '{}'", name, readme.display());
-                        println!("  README present at '{}'", readme.display());
-                    }
-                    Ok(None) => {
-                        warn!("Crate '{}' => no README.md found", name);
-                        println!("  no README.md present");
-                    }
-                    Err(e) => {
-                        error!("Crate '{}' => error checking readme_path: {:?}", name, e);
-                        return Err(WorkspaceError::CrateError(e));
-                    }
+                let readme = handle.readme_path().await.map_err(|e| {
+                    error!("Crate '{}' => error checking readme_path: {:?}", name, e);
+                    WorkspaceError::CrateError(e)
+                })?;
+                match &readme {
+                    Some(readme) => info!("Crate '{}' => found README at '{}'", name, readme.display()),
+                    None         => warn!("Crate '{}' => no README.md found", name),
                 }
 
                 // 5) Optionally, gather bin target names if any
-                match handle.gather_bin_target_names().await {
-                    Ok(bin_targets) if !bin_targets.is_empty() => {
-                        debug!("Crate '{}' => found bin targets: {:?}", name, bin_targets);
-                        println!("  bin targets: {:?}", bin_targets);
-                    }
-                    Ok(_) => {
-                        println!("  no [bin] targets found");
-                    }
-                    Err(e) => {
-                        error!("Crate '{}' => gather_bin_target_names failed: {:?}", name, e);
-                        return Err(WorkspaceError::CrateError(e));
-                    }
-                }
+                let bin_targets = handle.gather_bin_target_names().await.map_err(|e| {
+                    error!("Crate '{}' => gather_bin_target_names failed: {:?}", name, e);
+                    WorkspaceError::CrateError(e)
+                })?;
+                debug!("Crate '{}' => found bin targets: {:?}", name, bin_targets);
+
+                let report = CrateInfoReport::new(
+                    crate_name.clone(),
+                    name.to_string(),
+                    version.to_string(),
+                    is_priv,
+                    test_files,
+                    readme,
+                    bin_targets,
+                );
+                emit(&report)?;
 
                 info!("Finished printing info for crate='{}'", name);
                 Ok(())
@@ -105,13 +97,7 @@ impl InfoSubcommand {
                     err
                 })?;
 
-                // 2) Print basic workspace details
-                let crate_count = ws.n_crates();
-                println!("Workspace at '{}':", path.display());
-                println!("  number of crates: {}", crate_count);
-
-                // 3) Print each crate’s name, version, etc.
-                //    We’ll do a quick pass: name + (private?).
+                // 2) Gather each crate’s name, version and private flag.
                 //    If you want more details, you can do an additional lock + gather info.
                 let all_names = ws.get_all_crate_names().await;
                 info!(
@@ -120,34 +106,39 @@ impl InfoSubcommand {
                     all_names
                 );
 
-                println!("Crates in this workspace:");
+                let mut crates = vec![];
                 for crate_name in all_names {
                     // We can attempt to find the crate quickly and check if it’s private
-                    if let Some(arc_crate) = ws.find_crate_by_name(&crate_name).await {
-                        let guard = arc_crate.lock().await;
-                        let ver = match guard.version() {
-                            Ok(v) => v.to_string(),
-                            Err(e) => {
-                                warn!("Crate '{}' => cannot retrieve version: {:?}", crate_name, e);
-                                "(unknown)".to_string()
-                            }
-                        };
-                        let priv_status = match guard.is_private().await {
-                            Ok(b) => b,
-                            Err(e) => {
-                                warn!("Crate '{}' => is_private() errored: {:?}", crate_name, e);
-                                false
-                            }
-                        };
-                        println!(
-                            "  - name='{}', version='{}', private?={}",
-                            crate_name, ver, priv_status
-                        );
-                    } else {
-                        println!("  - name='{}' => error finding crate handle", crate_name);
-                    }
+                    let entry = match ws.find_crate_by_name(&crate_name).await {
+                        Some(arc_crate) => {
+                            let guard = arc_crate.lock().await;
+                            let ver = match guard.version() {
+                                Ok(v) => Some(v.to_string()),
+                                Err(e) => {
+                                    warn!("Crate '{}' => cannot retrieve version: {:?}", crate_name, e);
+                                    None
+                                }
+                            };
+                            let priv_status = match guard.is_private().await {
+                                Ok(b) => b,
+                                Err(e) => {
+                                    warn!("Crate '{}' => is_private() errored: {:?}", crate_name, e);
+                                    false
+                                }
+                            };
+                            WorkspaceCrateInfo::Found { name: crate_name, version: ver, private: priv_status }
+                        }
+                        None => WorkspaceCrateInfo::Missing {
+                            name:  crate_name,
+                            error: "could not find crate handle".to_string(),
+                        },
+                    };
+                    crates.push(entry);
                 }
 
+                let report = WorkspaceInfoReport::new(path.clone(), ws.n_crates(), crates);
+                emit(&report)?;
+
                 // That’s enough for a “general info” view.
                 info!("Completed workspace info for path='{}'", path.display());
                 Ok(())
@@ -0,0 +1,113 @@
+// ---------------- [ File: workspacer-cli/src/info_report.rs ]
+crate::ix!();
+
+/// What `ws info crate` reports about one crate.
+#[derive(Getters,Serialize,Debug,Clone)]
+#[getset(get="pub")]
+pub struct CrateInfoReport {
+    path:        PathBuf,
+    name:        String,
+    version:     String,
+    private:     bool,
+
+    /// `None` when the crate has no tests/ directory
+    test_files:  Option<Vec<PathBuf>>,
+    readme:      Option<PathBuf>,
+    bin_targets: Vec<String>,
+}
+
+impl CrateInfoReport {
+
+    pub fn new(
+        path:        PathBuf,
+        name:        String,
+        version:     String,
+        private:     bool,
+        test_files:  Option<Vec<PathBuf>>,
+        readme:      Option<PathBuf>,
+        bin_targets: Vec<String>,
+    ) -> Self {
+        Self { path, name, version, private, test_files, readme, bin_targets }
+    }
+}
+
+impl fmt::Display for CrateInfoReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Crate path='{}'", self.path.display())?;
+        writeln!(f, "  name='{}'", self.name)?;
+        writeln!(f, "  version='{}'", self.version)?;
+        writeln!(f, "  private?={}", self.private)?;
+
+        match &self.test_files {
+            Some(test_files) => writeln!(f, "  tests directory is present. Test files: {:?}", test_files)?,
+            None             => writeln!(f, "  no tests/ directory found.")?,
+        }
+
+        match &self.readme {
+            Some(readme) => writeln!(f, "  README present at '{}'", readme.display())?,
+            None         => writeln!(f, "  no README.md present")?,
+        }
+
+        if self.bin_targets.is_empty() {
+            writeln!(f, "  no [bin] targets found")
+        } else {
+            writeln!(f, "  bin targets: {:?}", self.bin_targets)
+        }
+    }
+}
+
+/// One member crate in `ws info workspace`.
+#[derive(Serialize,Debug,Clone)]
+#[serde(untagged)]
+pub enum WorkspaceCrateInfo {
+    Found {
+        name:    String,
+
+        /// `None` when the version could not be read
+        version: Option<String>,
+        private: bool,
+    },
+    Missing {
+        name:  String,
+        error: String,
+    },
+}
+
+/// What `ws info workspace` reports.
+#[derive(Getters,Serialize,Debug,Clone)]
+#[getset(get="pub")]
+pub struct WorkspaceInfoReport {
+    path:     PathBuf,
+    n_crates: usize,
+    crates:   Vec<WorkspaceCrateInfo>,
+}
+
+impl WorkspaceInfoReport {
+
+    pub fn new(path: PathBuf, n_crates: usize, crates: Vec<WorkspaceCrateInfo>) -> Self {
+        Self { path, n_crates, crates }
+    }
+}
+
+impl fmt::Display for WorkspaceInfoReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Workspace at '{}':", self.path.display())?;
+        writeln!(f, "  number of crates: {}", self.n_crates)?;
+        writeln!(f, "Crates in this workspace:")?;
+        for entry in &self.crates {
+            match entry {
+                WorkspaceCrateInfo::Found { name, version, private } => writeln!(
+                    f,
+                    "  - name='{}', version='{}', private?={}",
+                    name,
+                    version.as_deref().unwrap_or("(unknown)"),
+                    private,
+                )?,
+                WorkspaceCrateInfo::Missing { name, .. } => {
+                    writeln!(f, "  - name='{}' => error finding crate handle", name)?
+                }
+            }
+        }
+        Ok(())
+    }
+}
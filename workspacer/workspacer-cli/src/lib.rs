@@ -4,20 +4,25 @@
 x!{add}
 x!{add_crate}
 x!{add_internal_dep}
+x!{affected}
 x!{analyze}
 x!{analyze_crate}
 x!{analyze_workspace}
+x!{analyze_dependencies}
+x!{audit}
 x!{bump}
 x!{bump_workspace}
 x!{bump_crate_downstreams}
 x!{bump_single_crate}
 x!{bump_release_arg}
+x!{bump_release_record_flags}
 x!{check_publish_ready}
 x!{check_publish_ready_crate}
 x!{check_publish_ready_workspace}
 x!{cleanup}
 x!{cleanup_crate}
 x!{cleanup_workspace}
+x!{config}
 x!{coverage}
 x!{coverage_crate}
 x!{coverage_workspace}
@@ -25,6 +30,7 @@ x!{detect_cycles}
 x!{detect_cycles_crate}
 x!{detect_cycles_workspace}
 x!{document}
+x!{edition}
 x!{format}
 x!{format_all_imports}
 x!{format_imports}
@@ -33,14 +39,23 @@ x!{get_toml_section}
 x!{get_lock_versions}
 x!{git}
 x!{info}
+x!{info_report}
 x!{lint}
 x!{meta}
+x!{move_item}
+x!{msrv}
 x!{name}
 x!{organize}
+x!{output_format}
 x!{pin}
+x!{prefix_group}
 x!{publish}
+x!{publish_ready}
+x!{query}
 x!{register}
 x!{show}
+x!{split_crate}
+x!{template}
 x!{tree}
 x!{upgrade}
 x!{validate}
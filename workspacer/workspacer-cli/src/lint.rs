@@ -2,29 +2,62 @@
 crate::ix!();
 
 /// Now we can refactor our LintSubcommand to use `run_with_crate` for the `Crate` variant:
+///
+/// Both variants accept:
+///  - `--update-baseline`: record every current warning in each crate's `lint-baseline.json`
+///  - `--fix`: apply clippy's machine-applicable suggestions first (workspace: in topological order)
 #[derive(Debug, StructOpt)]
 pub enum LintSubcommand {
     Crate {
         #[structopt(long = "crate")]
         crate_name: PathBuf,
+
+        #[structopt(long = "update-baseline")]
+        update_baseline: bool,
+
+        #[structopt(long = "fix")]
+        fix: bool,
     },
     Workspace {
         #[structopt(long = "path")]
         path: PathBuf,
+
+        #[structopt(long = "update-baseline")]
+        update_baseline: bool,
+
+        #[structopt(long = "fix")]
+        fix: bool,
     },
 }
 
 impl LintSubcommand {
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         trace!("Entering LintSubcommand::run with {:?}", self);
 
         match self {
-            LintSubcommand::Crate { crate_name } => {
+            LintSubcommand::Crate { crate_name, update_baseline, fix } => {
                 info!("Linting single crate at path='{}'", crate_name.display());
 
-                // We call our new `run_with_crate` function:
-                run_with_crate(crate_name.clone(), false, move |handle| {
+                let update_baseline = *update_baseline;
+                let fix             = *fix;
+
+                // only `--fix` and `--update-baseline` write files, so only they need a clean tree
+                let git_check = (fix || update_baseline).then_some(git);
+
+                run_with_crate(crate_name.clone(), git_check, move |handle| {
                     Box::pin(async move {
+                        if fix {
+                            handle.fix_lints().await.map_err(WorkspaceError::LintingError)?;
+                        }
+
+                        if update_baseline {
+                            let report = handle.collect_lint_report().await.map_err(WorkspaceError::LintingError)?;
+                            let baseline = LintBaseline::from_diagnostics(report.all_diagnostics());
+                            baseline.save(handle.crate_path()).await.map_err(WorkspaceError::LintingError)?;
+                            info!("recorded {} baselined diagnostics for crate='{}'", baseline.entries().len(), handle.name());
+                            return Ok(());
+                        }
+
                         // Inside this closure, we have a &CrateHandle to do the lint:
                         let report = handle.run_linting().await.map_err(|lint_err| {
                             error!(
@@ -40,34 +73,56 @@ impl LintSubcommand {
                             handle.name(),
                             report.success()
                         );
-                        println!("stdout:\n{}", report.stdout());
-                        println!("stderr:\n{}", report.stderr());
+                        emit_report(&report, || print!("{}", report.rendered()))?;
                         Ok(())
                     })
                 })
                 .await
             }
 
-            LintSubcommand::Workspace { path } => {
+            LintSubcommand::Workspace { path, update_baseline, fix } => {
                 info!("Linting entire workspace at '{}'", path.display());
 
+                let update_baseline = *update_baseline;
+                let fix             = *fix;
+
                 // We can reuse our existing `run_with_workspace` helper
-                // which loads the entire workspace, checks Git, etc.
-                run_with_workspace(Some(path.clone()), false, move |ws| {
+                // which loads the entire workspace, checks Git when we are about to write, etc.
+                let git_check = (fix || update_baseline).then_some(git);
+
+                run_with_workspace(Some(path.clone()), git_check, move |ws| {
                     Box::pin(async move {
+                        if fix {
+                            let outcomes = ws.fix_lints().await?;
+                            info!("applied clippy fixes to {} crates", outcomes.len());
+                        }
+
+                        if update_baseline {
+                            let report = ws.collect_lint_report().await.map_err(WorkspaceError::LintingError)?;
+                            for crate_arc in ws.crates() {
+                                let handle = crate_arc.lock().await;
+                                let name   = handle.name().to_string();
+                                let baseline = LintBaseline::from_diagnostics(
+                                    report.all_diagnostics().filter(|d| d.crate_name() == &name)
+                                );
+                                baseline.save(handle.crate_path()).await.map_err(WorkspaceError::LintingError)?;
+                                info!("recorded {} baselined diagnostics for crate='{}'", baseline.entries().len(), name);
+                            }
+                            return Ok(());
+                        }
+
                         let report = ws.run_linting().await.map_err(|lint_err| {
                             error!("Workspace linting failed: {:?}", lint_err);
                             WorkspaceError::LintingError(lint_err)
                         })?;
 
                         info!(
-                            "Workspace lint success?={}, stdout len={}, stderr len={}",
+                            "Workspace lint success?={}, {} new diagnostics, {} baselined",
                             report.success(),
-                            report.stdout().len(),
-                            report.stderr().len()
+                            report.diagnostics().len(),
+                            report.baselined_diagnostics().len()
                         );
-                        println!("Lint STDOUT:\n{}", report.stdout());
-                        println!("Lint STDERR:\n{}", report.stderr());
+                        emit_report(&report, || print!("{}", report.rendered()))?;
 
                         Ok(())
                     })
@@ -20,7 +20,7 @@ impl MetaSubcommand {
             MetaSubcommand::Crate { crate_name } => {
                 // a) Use `run_with_crate` or do it inline. 
                 //    We'll demonstrate the `run_with_crate` approach:
-                run_with_crate(crate_name.clone(), false, move |handle| {
+                run_with_crate(crate_name.clone(), None, move |handle| {
                     Box::pin(async move {
                         // b) Now call your trait:
                         let metadata = handle.get_cargo_metadata().await.map_err(|crate_err| {
@@ -38,7 +38,7 @@ impl MetaSubcommand {
 
             MetaSubcommand::Workspace { path } => {
                 // a) Use `run_with_workspace`
-                run_with_workspace(Some(path.clone()), false, move |ws| {
+                run_with_workspace(Some(path.clone()), None, move |ws| {
                     Box::pin(async move {
                         // b) call the trait
                         let metadata = ws.get_cargo_metadata().await.map_err(|we| {
@@ -0,0 +1,57 @@
+// ---------------- [ File: workspacer-cli/src/move_item.rs ]
+crate::ix!();
+
+/// `ws move-item --from <crate> --to <crate> [--path <ws>] [--dry-run] [--skip-git-check] <Item>...`
+///
+/// Moves each item, with its impl blocks and `test_<item>` modules, into the target crate:
+/// `x!{}` registration, internal dependencies and `use` paths across the workspace follow.
+/// Refuses moves that would create a dependency cycle; `--dry-run` prints the plan only.
+#[derive(Getters,Debug,StructOpt)]
+#[getset(get="pub")]
+pub struct MoveItemCommand {
+    /// The crate the items live in now
+    #[structopt(long = "from")]
+    from: String,
+
+    /// The crate receiving them
+    #[structopt(long = "to")]
+    to: String,
+
+    /// Path to the workspace directory (defaults to the current directory)
+    #[structopt(long = "path")]
+    workspace_path: Option<PathBuf>,
+
+    /// Print what would change without touching anything
+    #[structopt(long = "dry-run")]
+    dry_run: bool,
+
+    /// If true, we skip the Git clean check (i.e., do not require a clean repo)
+    #[structopt(long = "skip-git-check")]
+    skip_git_check: bool,
+
+    /// The names of the items to move (structs, enums, traits, functions, ...)
+    #[structopt(required = true)]
+    items: Vec<String>,
+}
+
+impl MoveItemCommand {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
+        let from    = self.from.clone();
+        let to      = self.to.clone();
+        let items   = self.items.clone();
+        let dry_run = self.dry_run;
+
+        run_with_workspace(self.workspace_path.clone(), (!(self.skip_git_check || dry_run)).then_some(git), move |ws| {
+            Box::pin(async move {
+                let plan = if dry_run {
+                    ws.plan_move_items(&from, &to, &items).await?
+                } else {
+                    ws.move_items(&from, &to, &items).await?
+                };
+                print!("{}", plan);
+                Ok(())
+            })
+        })
+        .await
+    }
+}
@@ -0,0 +1,54 @@
+// ---------------- [ File: workspacer-cli/src/msrv.rs ]
+crate::ix!();
+
+/// `ws msrv [--path <ws>] [--toolchain <name>]... [--cargo-check]`
+///
+/// Reports each crate's edition, `rust-version` and `#![feature]` gates, and whether every
+/// toolchain in `[msrv] toolchains` (or the `--toolchain` flags) can build it. Fails if any
+/// crate does not build on one of them.
+#[derive(Getters,Debug,StructOpt)]
+#[getset(get="pub")]
+pub struct MsrvCommand {
+    /// Path to the workspace directory (defaults to the current directory)
+    #[structopt(long = "path")]
+    workspace_path: Option<PathBuf>,
+
+    /// `stable`, `beta`, `nightly`, `nightly-YYYY-MM-DD` or a release like `1.85` (repeatable;
+    /// replaces the configured list)
+    #[structopt(long = "toolchain")]
+    toolchains: Vec<String>,
+
+    /// Also run `cargo +<toolchain> check` wherever the static checks pass
+    #[structopt(long = "cargo-check")]
+    cargo_check: bool,
+}
+
+impl MsrvCommand {
+    pub async fn run(&self) -> Result<(), WorkspaceError> {
+        let toolchains  = self.toolchains.clone();
+        let cargo_check = self.cargo_check;
+
+        run_with_workspace(self.workspace_path.clone(), /*git_check=*/None, move |ws| {
+            Box::pin(async move {
+                let settings = WorkspaceSettings::load(ws.as_ref()).await?;
+
+                let mut builder = MsrvOptionsBuilder::from_settings(settings.msrv())?;
+                if !toolchains.is_empty() {
+                    builder.toolchains(parse_toolchains(&toolchains)?);
+                }
+                let options = builder.cargo_check(cargo_check).build().unwrap();
+
+                let report = ws.check_msrv(&options).await?;
+                emit(&report)?;
+
+                let failing: Vec<String> = report.failing_crates().map(|c| c.crate_name().clone()).collect();
+                if failing.is_empty() {
+                    Ok(())
+                } else {
+                    Err(MsrvError::CratesFailToolchainCheck { crates: failing }.into())
+                }
+            })
+        })
+        .await
+    }
+}
@@ -17,7 +17,7 @@ pub enum NameSubcommand {
 }
 
 impl NameSubcommand {
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         match self {
             // --------------------------------------
             // 1) Single Crate
@@ -27,7 +27,7 @@ impl NameSubcommand {
 
                 // We'll use our standard `run_with_crate` helper
                 // to construct a CrateHandle, optionally check Git, then run our closure.
-                run_with_crate(crate_name.clone(), /*skip_git_check=*/false, move |handle| {
+                run_with_crate(crate_name.clone(), Some(git), move |handle| {
                     Box::pin(async move {
                         // Using the `NameAllFiles` trait implemented for CrateHandle
                         handle.name_all_files().await.map_err(|crate_err| {
@@ -51,7 +51,7 @@ impl NameSubcommand {
 
                 // We'll use `run_with_workspace` so that we automatically load the workspace,
                 // optionally check Git cleanliness, etc.
-                run_with_workspace(Some(path.clone()), /*skip_git_check=*/false, move |ws| {
+                run_with_workspace(Some(path.clone()), Some(git), move |ws| {
                     Box::pin(async move {
                         // The trait `NameAllFiles` is also impl’d for `Workspace<P,H>`.
                         ws.name_all_files().await.map_err(|we| {
@@ -0,0 +1,119 @@
+// ---------------- [ File: workspacer-cli/src/output_format.rs ]
+crate::ix!();
+
+/// How `ws` prints its results, chosen once by the global `--format` flag.
+#[derive(Debug,Clone,Copy,Default,PartialEq,Eq)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            other  => Err(format!("unknown output format '{}' (expected json or text)", other)),
+        }
+    }
+}
+
+static OUTPUT_FORMAT: std::sync::OnceLock<OutputFormat> = std::sync::OnceLock::new();
+
+/// Called once by the `ws` binary after parsing its arguments; later calls are ignored.
+pub fn set_output_format(format: OutputFormat) {
+    let _ = OUTPUT_FORMAT.set(format);
+}
+
+pub fn output_format() -> OutputFormat {
+    OUTPUT_FORMAT.get().copied().unwrap_or_default()
+}
+
+pub fn json_output() -> bool {
+    output_format() == OutputFormat::Json
+}
+
+/// Prints `report` as pretty JSON on stdout, or, in text mode, whatever `text` renders.
+pub fn emit_report<T: Serialize + ?Sized>(report: &T, text: impl FnOnce()) -> Result<(), WorkspaceError> {
+    match output_format() {
+        OutputFormat::Text => text(),
+        OutputFormat::Json => println!("{}", to_json_output(report)?),
+    }
+    Ok(())
+}
+
+/// `emit_report` for reports whose `Display` is their text form.
+pub fn emit<T: Serialize + fmt::Display + ?Sized>(report: &T) -> Result<(), WorkspaceError> {
+    emit_report(report, || print!("{}", report))
+}
+
+pub fn to_json_output<T: Serialize + ?Sized>(report: &T) -> Result<String, WorkspaceError> {
+    serde_json::to_string_pretty(report).map_err(|e| WorkspaceError::JsonOutputError {
+        message: e.to_string(),
+    })
+}
+
+/// The object `ws --format json` writes to stderr when a command fails:
+/// `{"error": {"kind": "CrateError::CrateNotFoundInWorkspace", "detail": "..."}}`.
+#[derive(Getters,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct ErrorOutput {
+    kind:   String,
+    detail: String,
+}
+
+impl ErrorOutput {
+
+    pub fn new(error: &WorkspaceError) -> Self {
+        Self { kind: error.kind(), detail: format!("{:?}", error) }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::json!({ "error": { "kind": self.kind, "detail": self.detail } }).to_string()
+    }
+}
+
+/// Reports a failed command on stderr: one JSON line in JSON mode, the `Debug` form otherwise.
+pub fn emit_error(error: &WorkspaceError) {
+    match output_format() {
+        OutputFormat::Text => eprintln!("Error: {:?}", error),
+        OutputFormat::Json => eprintln!("{}", ErrorOutput::new(error).to_json()),
+    }
+}
+
+#[cfg(test)]
+mod test_output_format {
+    use super::*;
+
+    #[traced_test]
+    fn parses_formats() {
+        assert_eq!("json".parse::<OutputFormat>(), Ok(OutputFormat::Json));
+        assert_eq!("text".parse::<OutputFormat>(), Ok(OutputFormat::Text));
+        assert!("yaml".parse::<OutputFormat>().is_err());
+    }
+
+    #[traced_test]
+    fn error_kinds_follow_wrapper_variants() {
+        let error = WorkspaceError::CrateError(CrateError::CrateNotFoundInWorkspace { crate_name: "x".into() });
+        assert_eq!(ErrorOutput::new(&error).kind(), "CrateError::CrateNotFoundInWorkspace");
+
+        assert_eq!(WorkspaceError::WorkspaceNotReadyForCargoPublish.kind(), "WorkspaceNotReadyForCargoPublish");
+
+        let error = WorkspaceError::LintingError(LintingError::UnknownError { stderr: None, stdout: None });
+        assert_eq!(error.kind(), "LintingError::UnknownError");
+
+        let error = WorkspaceError::InvalidCargoToml(CargoTomlError::MissingPackageSection { cargo_toml_file: "Cargo.toml".into() });
+        assert_eq!(error.kind(), "InvalidCargoToml::MissingPackageSection");
+    }
+
+    #[traced_test]
+    fn error_output_is_one_json_object() {
+        let error = WorkspaceError::InvalidSymbolQuery { symbol: "a::".into() };
+        let json: serde_json::Value = serde_json::from_str(&ErrorOutput::new(&error).to_json()).unwrap();
+        assert_eq!(json["error"]["kind"], "InvalidSymbolQuery");
+        assert!(json["error"]["detail"].as_str().unwrap().contains("a::"));
+    }
+}
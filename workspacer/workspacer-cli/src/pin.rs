@@ -17,7 +17,7 @@ pub enum PinSubcommand {
 }
 
 impl PinSubcommand {
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         match self {
             // ----------------------------------------------------
             // 1) Single crate
@@ -27,7 +27,7 @@ impl PinSubcommand {
 
                 // We'll use the `run_with_crate` helper so we load the crate,
                 // optionally check Git, etc., then call `pin_all_wildcard_dependencies()`.
-                run_with_crate(crate_name.clone(), /*skip_git_check=*/false, |handle| {
+                run_with_crate(crate_name.clone(), Some(git), |handle| {
                     Box::pin(async move {
 
                         // The `PinAllWildcardDependencies` trait is implemented for `CrateHandle`
@@ -51,7 +51,7 @@ impl PinSubcommand {
 
                 // We'll use `run_with_workspace` to load the workspace,
                 // optionally check Git, then call `pin_all_wildcard_dependencies()`.
-                run_with_workspace(Some(path.clone()), /*skip_git_check=*/false, |ws| {
+                run_with_workspace(Some(path.clone()), Some(git), |ws| {
                     Box::pin(async move {
                         // The `PinAllWildcardDependencies` trait is implemented for `Workspace<P,H>`
                         ws.pin_all_wildcard_dependencies().await.map_err(|we| {
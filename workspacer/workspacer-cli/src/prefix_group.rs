@@ -0,0 +1,69 @@
+// ---------------- [ File: workspacer-cli/src/prefix_group.rs ]
+crate::ix!();
+
+/// `ws prefix-group new <prefix>` / `ws prefix-group fix <prefix>`
+///
+/// `new` creates the `<prefix>` facade, the `<prefix>-3p` crate and `u/create-<prefix>-crate`.
+/// `fix` adds what `ValidatePrefixGroupCohesion` warns about: `-3p` dependencies, facade
+/// re-exports and workspace member entries. With `--dry-run` both print a diff instead.
+#[derive(Debug, StructOpt)]
+pub enum PrefixGroupSubcommand {
+    /// Scaffold a new prefix group
+    #[structopt(name = "new")]
+    New {
+        prefix: String,
+
+        /// Path to the workspace directory (defaults to the current directory)
+        #[structopt(long = "path")]
+        workspace_path: Option<PathBuf>,
+
+        /// Print the diff without writing anything
+        #[structopt(long = "dry-run")]
+        dry_run: bool,
+    },
+
+    /// Repair an existing prefix group
+    #[structopt(name = "fix")]
+    Fix {
+        prefix: String,
+
+        /// Path to the workspace directory (defaults to the current directory)
+        #[structopt(long = "path")]
+        workspace_path: Option<PathBuf>,
+
+        /// Print the diff without writing anything
+        #[structopt(long = "dry-run")]
+        dry_run: bool,
+    },
+}
+
+impl PrefixGroupSubcommand {
+    pub async fn run(&self) -> Result<(), WorkspaceError> {
+        let (prefix, workspace_path, dry_run, fix) = match self {
+            PrefixGroupSubcommand::New { prefix, workspace_path, dry_run } => (prefix.clone(), workspace_path.clone(), *dry_run, false),
+            PrefixGroupSubcommand::Fix { prefix, workspace_path, dry_run } => (prefix.clone(), workspace_path.clone(), *dry_run, true),
+        };
+
+        run_with_workspace(workspace_path, /*git_check=*/None, move |ws| {
+            Box::pin(async move {
+                let plan = if fix {
+                    ws.plan_prefix_group_fix(&prefix).await?
+                } else {
+                    ws.plan_new_prefix_group(&prefix).await?
+                };
+
+                if dry_run {
+                    print!("{}", plan.diff());
+                    for note in plan.notes() {
+                        println!("note: {}", note);
+                    }
+                } else {
+                    plan.apply().await?;
+                    print!("{}", plan);
+                }
+                Ok(())
+            })
+        })
+        .await
+    }
+}
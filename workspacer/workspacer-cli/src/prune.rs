@@ -17,10 +17,10 @@ pub enum PruneSubcommand {
 
 impl PruneSubcommand {
     /// Entrypoint for `ws prune ...`
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         match self {
-            PruneSubcommand::Crate(cmd) => cmd.run().await,
-            PruneSubcommand::Workspace(cmd) => cmd.run().await,
+            PruneSubcommand::Crate(cmd) => cmd.run(git).await,
+            PruneSubcommand::Workspace(cmd) => cmd.run(git).await,
         }
     }
 }
@@ -43,14 +43,14 @@ pub struct PruneCrateCommand {
 }
 
 impl PruneCrateCommand {
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         let crate_name_owned = self.crate_name().clone();
 
         // Use the existing helper that loads the workspace, 
         // finds the crate, checks Git cleanliness (if not skipped), etc.
         run_with_workspace_and_crate_name(
             self.workspace_path().clone(),
-            *self.skip_git_check(),
+            (!*self.skip_git_check()).then_some(git),
             crate_name_owned,
             |ws, crate_name_str| {
                 Box::pin(async move {
@@ -91,13 +91,13 @@ pub struct PruneWorkspaceCommand {
 }
 
 impl PruneWorkspaceCommand {
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         // We'll do the same approach as e.g. FormatAllImportsCommand
         // => load the workspace, optionally ensure Git is clean, then call the trait.
         let ws_path = self.workspace_path.clone();
         let skip_flag = self.skip_git_check;
 
-        run_with_workspace(ws_path, skip_flag, move |ws| {
+        run_with_workspace(ws_path, (!skip_flag).then_some(git), move |ws| {
             Box::pin(async move {
                 // The `PruneInvalidCategorySlugsFromSubstructures` trait is implemented for Workspace
                 let removed_total = ws.prune_invalid_category_slugs_from_members().await?;
@@ -17,14 +17,14 @@ pub enum PublishSubcommand {
 }
 
 impl PublishSubcommand {
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         match self {
             PublishSubcommand::Crate { crate_name } => {
                 trace!("Publishing single crate at '{}'", crate_name.display());
 
                 // Use `run_with_crate` to build a CrateHandle and optionally check Git, etc.
                 // Then call the `TryPublish` trait method on it.
-                run_with_crate(crate_name.clone(), /*skip_git_check=*/false, |handle| {
+                run_with_crate(crate_name.clone(), Some(git), |handle| {
                     Box::pin(async move {
                         // If you want a `dry_run` or other flags, you can pass them here.
                         // For example, handle.try_publish(dry_run).await. We’ll use false for a real publish.
@@ -44,7 +44,7 @@ impl PublishSubcommand {
                 trace!("Publishing entire workspace at '{}'", path.display());
 
                 // Use `run_with_workspace` to load the workspace, check Git, etc. Then call `TryPublish`.
-                run_with_workspace(Some(path.clone()), /*skip_git_check=*/false, |ws| {
+                run_with_workspace(Some(path.clone()), Some(git), |ws| {
                     Box::pin(async move {
                         // If you want to do a "dry run" or pass flags, you can do that here.
                         // We'll do a real publish with `dry_run=false`.
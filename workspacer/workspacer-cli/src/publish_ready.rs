@@ -0,0 +1,50 @@
+// ---------------- [ File: workspacer-cli/src/publish_ready.rs ]
+crate::ix!();
+
+/// `ws publish-ready [--path <ws>] [--crate <name>]`
+///
+/// Packages every crate offline (`cargo package --no-verify --offline`) and audits the result:
+/// readme/license files inside the tarball, path dependencies without a version, crates.io
+/// category and keyword rules, package size and `include`/`exclude` mistakes. Prints one
+/// report and fails if any crate has findings.
+#[derive(Getters,Debug,StructOpt)]
+#[getset(get="pub")]
+pub struct PublishReadyCommand {
+    /// Path to the workspace directory (defaults to the current directory)
+    #[structopt(long = "path")]
+    workspace_path: Option<PathBuf>,
+
+    /// Audit only this crate
+    #[structopt(long = "crate")]
+    crate_name: Option<String>,
+}
+
+impl PublishReadyCommand {
+    pub async fn run(&self) -> Result<(), WorkspaceError> {
+        let crate_name = self.crate_name.clone();
+
+        run_with_workspace(self.workspace_path.clone(), /*git_check=*/None, move |ws| {
+            Box::pin(async move {
+                let report = match &crate_name {
+                    Some(name) => {
+                        let crate_arc = ws.find_crate_by_name(name).await.ok_or_else(|| {
+                            CrateError::CrateNotFoundInWorkspace { crate_name: name.clone() }
+                        })?;
+                        let audit = crate_arc.lock().await.audit_publish_readiness().await?;
+                        PublishAuditReport::new(vec![audit])
+                    }
+                    None => ws.audit_publish_readiness().await?,
+                };
+
+                emit(&report)?;
+
+                if report.is_ready() {
+                    Ok(())
+                } else {
+                    Err(WorkspaceError::WorkspaceNotReadyForCargoPublish)
+                }
+            })
+        })
+        .await
+    }
+}
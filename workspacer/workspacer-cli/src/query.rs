@@ -0,0 +1,87 @@
+// ---------------- [ File: workspacer-cli/src/query.rs ]
+crate::ix!();
+
+/// `ws query <kind> <symbol> [--path <ws>] [--json] [--no-tests]`
+///
+/// Builds a symbol index over every member crate and answers one question about `<symbol>`,
+/// written as `name` or `Type::name` (any trailing part of a path matches).
+#[derive(Debug,StructOpt)]
+pub enum QuerySubcommand {
+    /// Where the symbol is defined
+    #[structopt(name = "defs")]
+    Definitions(QueryFlags),
+
+    /// Every use of the symbol: calls, types, macro invocations and other paths
+    #[structopt(name = "refs")]
+    References(QueryFlags),
+
+    /// Call sites of a fn or method, with the fn each call is made from
+    #[structopt(name = "callers")]
+    Callers(QueryFlags),
+
+    /// Calls made from inside a fn or method
+    #[structopt(name = "callees")]
+    Callees(QueryFlags),
+
+    /// `impl <Trait> for ..` blocks across the workspace
+    #[structopt(name = "impls")]
+    Implementors(QueryFlags),
+
+    /// The `pub use` chain carrying an item from its defining crate to the crates re-exporting it
+    #[structopt(name = "reexports")]
+    ReExports(QueryFlags),
+}
+
+#[derive(Getters,Debug,StructOpt)]
+#[getset(get="pub")]
+pub struct QueryFlags {
+    /// `name`, `Type::name` or `Trait`
+    symbol: String,
+
+    /// Path to the workspace directory (defaults to the current directory)
+    #[structopt(long = "path")]
+    workspace_path: Option<PathBuf>,
+
+    /// Print the result as JSON
+    #[structopt(long = "json")]
+    json: bool,
+
+    /// Leave out hits in `#[cfg(test)]` modules and `tests/`
+    #[structopt(long = "no-tests")]
+    no_tests: bool,
+}
+
+impl QuerySubcommand {
+
+    pub async fn run(&self) -> Result<(), WorkspaceError> {
+        let (kind, flags) = match self {
+            QuerySubcommand::Definitions(f)  => (SymbolQueryKind::Definitions,  f),
+            QuerySubcommand::References(f)   => (SymbolQueryKind::References,   f),
+            QuerySubcommand::Callers(f)      => (SymbolQueryKind::Callers,      f),
+            QuerySubcommand::Callees(f)      => (SymbolQueryKind::Callees,      f),
+            QuerySubcommand::Implementors(f) => (SymbolQueryKind::Implementors, f),
+            QuerySubcommand::ReExports(f)    => (SymbolQueryKind::ReExports,    f),
+        };
+
+        let pattern = SymbolPattern::parse(&flags.symbol).ok_or_else(|| WorkspaceError::InvalidSymbolQuery {
+            symbol: flags.symbol.clone(),
+        })?;
+        let json          = flags.json || json_output();
+        let include_tests = !flags.no_tests;
+
+        run_with_workspace(flags.workspace_path.clone(), /*git_check=*/None, move |ws| {
+            Box::pin(async move {
+                let index  = ws.build_symbol_index().await?;
+                let result = index.query(kind, &pattern, include_tests);
+
+                if json {
+                    println!("{}", result.to_json());
+                } else {
+                    print!("{}", result);
+                }
+                Ok(())
+            })
+        })
+        .await
+    }
+}
@@ -52,7 +52,7 @@ pub enum RegisterSubcommand {
 }
 
 impl RegisterSubcommand {
-    pub async fn run(&self) -> Result<(), WorkspaceError> {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         match self {
             // ------------------------------------------------------------
             // 1) Single crate “register” => ensure_all_source_files_are_registered
@@ -67,7 +67,7 @@ impl RegisterSubcommand {
 
                 let crate_name_owned = crate_name.clone();
                 let skip_git = *skip_git_check;
-                run_with_workspace_and_crate_name(Some(crate_name_owned), skip_git, crate_name.to_string_lossy().to_string(),
+                run_with_workspace_and_crate_name(Some(crate_name_owned), (!skip_git).then_some(git), crate_name.to_string_lossy().to_string(),
                     |ws, crate_name_str| {
                         Box::pin(async move {
                             // Find that crate in the workspace
@@ -100,7 +100,7 @@ impl RegisterSubcommand {
                 let ws_path = Some(workspace_path.clone());
                 let skip_git = *skip_git_check;
 
-                run_with_workspace(ws_path, skip_git, move |ws| {
+                run_with_workspace(ws_path, (!skip_git).then_some(git), move |ws| {
                     Box::pin(async move {
                         ws.ensure_all_source_files_are_registered().await?;
 
@@ -126,7 +126,7 @@ impl RegisterSubcommand {
                 let prefix_crate_str = prefix_crate.to_string_lossy().to_string();
                 let new_crate_str    = new_crate.to_string_lossy().to_string();
 
-                run_with_workspace(ws_path, skip_git, move |ws| {
+                run_with_workspace(ws_path, (!skip_git).then_some(git), move |ws| {
                     Box::pin(async move {
                         // We do `ws.find_crate_by_name(...)` or by path. 
                         // Or you can do the `CrateHandle::new(&prefix_crate_path).await` 
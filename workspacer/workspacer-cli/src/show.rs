@@ -26,6 +26,16 @@ impl ShowSubcommand {
     #[tracing::instrument(level = "trace", skip(self))]
     pub async fn run(&self) -> Result<(), WorkspaceError> {
         trace!("Entering ShowSubcommand::run");
+
+        if json_output() {
+            let interfaces = match self {
+                ShowSubcommand::Crate(flags)     => vec![crate_interface(flags).await?],
+                ShowSubcommand::CrateTree(flags) => crate_tree_interfaces(flags).await?,
+                ShowSubcommand::Workspace(flags) => workspace_interfaces(flags).await?,
+            };
+            return emit_report(&interfaces, || {});
+        }
+
         let mut final_output = String::new();
 
         match self {
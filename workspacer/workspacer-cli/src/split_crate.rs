@@ -0,0 +1,51 @@
+// ---------------- [ File: workspacer-cli/src/split_crate.rs ]
+crate::ix!();
+
+/// `ws split-crate --from <crate> --into <new-crate> [--path <ws>] [--dry-run] [--skip-git-check] <Item>...`
+///
+/// Creates `<new-crate>` and moves the given items into it, exactly as `ws move-item` would.
+#[derive(Getters,Debug,StructOpt)]
+#[getset(get="pub")]
+pub struct SplitCrateCommand {
+    /// The crate being split
+    #[structopt(long = "from")]
+    from: String,
+
+    /// The name of the crate to create
+    #[structopt(long = "into")]
+    into: String,
+
+    /// Path to the workspace directory (defaults to the current directory)
+    #[structopt(long = "path")]
+    workspace_path: Option<PathBuf>,
+
+    /// Print what would change without creating the crate or touching anything
+    #[structopt(long = "dry-run")]
+    dry_run: bool,
+
+    /// If true, we skip the Git clean check (i.e., do not require a clean repo)
+    #[structopt(long = "skip-git-check")]
+    skip_git_check: bool,
+
+    /// The names of the items to move into the new crate
+    #[structopt(required = true)]
+    items: Vec<String>,
+}
+
+impl SplitCrateCommand {
+    pub async fn run(&self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
+        let from    = self.from.clone();
+        let into    = self.into.clone();
+        let items   = self.items.clone();
+        let dry_run = self.dry_run;
+
+        run_with_workspace(self.workspace_path.clone(), (!(self.skip_git_check || dry_run)).then_some(git), move |ws| {
+            Box::pin(async move {
+                let plan = ws.split_crate(&from, &into, &items, dry_run).await?;
+                print!("{}", plan);
+                Ok(())
+            })
+        })
+        .await
+    }
+}
@@ -0,0 +1,88 @@
+// ---------------- [ File: workspacer-cli/src/template.rs ]
+crate::ix!();
+
+/// Crate templates for `ws add crate --template`. They are looked up in the workspace's
+/// `[templates] dirs`, then the user template directory (`$WORKSPACER_TEMPLATES`, else
+/// `~/.config/workspacer/templates`), then the builtins.
+#[derive(Debug, StructOpt)]
+pub enum TemplateSubcommand {
+    /// Show every template name and where it is read from
+    List {
+        /// Path to the workspace directory (defaults to the current directory)
+        #[structopt(long = "path")]
+        workspace_path: Option<PathBuf>,
+    },
+    /// Copy a template directory into the user template directory under `name`
+    Register {
+        #[structopt(long = "name")]
+        name: String,
+
+        /// Files laid out relative to the workspace root, with `{{crate_name}}`-style
+        /// placeholders in paths and contents, and an optional `template.toml`
+        #[structopt(long = "dir")]
+        dir: PathBuf,
+
+        /// Replace a template already registered under this name
+        #[structopt(long = "force")]
+        force: bool,
+    },
+}
+
+/// One row of `ws template list --format json`.
+#[derive(Serialize,Debug)]
+#[serde(rename_all = "kebab-case")]
+struct TemplateListing<'a> {
+    name:                  &'a str,
+    description:           &'a str,
+    requires_prefix_group: bool,
+    source:                String,
+}
+
+/// `{"templates": [..]}`, the JSON form of `ws template list`.
+#[derive(Serialize,Debug)]
+struct TemplateListReport<'a> {
+    templates: Vec<TemplateListing<'a>>,
+}
+
+impl TemplateSubcommand {
+    pub async fn run(&self) -> Result<(), WorkspaceError> {
+        match self {
+            TemplateSubcommand::List { workspace_path } => {
+                let root = match workspace_path {
+                    Some(path) => path.clone(),
+                    None       => std::env::current_dir().map_err(|e| WorkspaceError::IoError {
+                        io_error: Arc::new(e),
+                        context:  "reading the current directory".to_string(),
+                    })?,
+                };
+                let settings = WorkspaceSettings::load(&root).await?;
+                let registry = CrateTemplateRegistry::for_workspace(settings.templates(), &root);
+                let listed   = registry.list().await?;
+
+                let templates = listed
+                    .iter()
+                    .map(|(template, source)| TemplateListing {
+                        name:                  template.name(),
+                        description:           template.description(),
+                        requires_prefix_group: *template.requires_prefix_group(),
+                        source:                source.to_string(),
+                    })
+                    .collect();
+
+                emit_report(&TemplateListReport { templates }, || {
+                    for (template, source) in &listed {
+                        let group = if *template.requires_prefix_group() { " [prefix group]" } else { "" };
+                        println!("{:<22} {}{} ({})", template.name(), template.description(), group, source);
+                    }
+                })
+            }
+            TemplateSubcommand::Register { name, dir, force } => {
+                let registry = CrateTemplateRegistryBuilder::default().build().unwrap();
+                let target   = registry.register(name, dir, *force).await?;
+                info!("template '{}' registered at {}", name, target.display());
+                println!("registered template '{}' at {}", name, target.display());
+                Ok(())
+            }
+        }
+    }
+}
@@ -78,7 +78,7 @@ impl TopoSubcommand {
         let c_name_cloned = crate_name.to_string();
 
         // 1) Load the workspace
-        run_with_workspace(Some(ws_path), /*skip_git_check=*/true, move |ws| {
+        run_with_workspace(Some(ws_path), /*git_check=*/None, move |ws| {
             // clone relevant fields
             let layered_flag = layered;
             let reverse_flag = reverse;
@@ -120,15 +120,11 @@ impl TopoSubcommand {
                 if layered_flag {
                     let layers = handle.layered_topological_order_upto_self(&config).await?;
                     info!("CrateDeps => layered => crate='{}' => {} layers", handle.name(), layers.len());
-                    for (i, layer) in layers.iter().enumerate() {
-                        println!("Layer {} => {:?}", i, layer);
-                    }
+                    emit_topological_layers(&layers)?;
                 } else {
                     let sorted = handle.topological_sort_internal_deps(&config).await?;
                     info!("CrateDeps => flat => crate='{}' => {:?}", handle.name(), sorted);
-                    for c in sorted {
-                        println!("{}", c);
-                    }
+                    emit_topological_order(&sorted)?;
                 }
                 Ok(())
             })
@@ -154,7 +150,7 @@ impl TopoSubcommand {
         let show_3p = self.include_externals;
         let focus_crate = crate_name.to_string();
 
-        run_with_workspace(Some(ws_path), /*skip_git_check=*/true, move |ws| {
+        run_with_workspace(Some(ws_path), /*git_check=*/None, move |ws| {
             Box::pin(async move {
                 // gather known crates
                 let crate_arcs = ws.crates();
@@ -186,15 +182,11 @@ impl TopoSubcommand {
                 if layered_flag {
                     let layers = ws.layered_topological_order_upto_crate(&config, &focus_crate).await?;
                     info!("Focus layered => total {} layers => crate='{}'", layers.len(), focus_crate);
-                    for (i, layer) in layers.iter().enumerate() {
-                        println!("Layer {} => {:?}", i, layer);
-                    }
+                    emit_topological_layers(&layers)?;
                 } else {
                     let partial = ws.topological_order_upto_crate(&config, &focus_crate).await?;
                     info!("Focus flat => partial => crate='{}': {:?}", focus_crate, partial);
-                    for c in partial {
-                        println!("{}", c);
-                    }
+                    emit_topological_order(&partial)?;
                 }
                 Ok(())
             })
@@ -218,7 +210,7 @@ impl TopoSubcommand {
         let excl_substr = self.exclude_substring.clone();
         let show_3p = self.include_externals;
 
-        run_with_workspace(Some(ws_path), /*skip_git_check=*/true, move |ws| {
+        run_with_workspace(Some(ws_path), /*git_check=*/None, move |ws| {
             Box::pin(async move {
                 let crate_arcs = ws.crates();
                 let mut known_set = HashSet::new();
@@ -248,15 +240,11 @@ impl TopoSubcommand {
                 if layered_flag {
                     let layered = ws.layered_topological_order_crate_names(&config).await?;
                     info!("Workspace layering => total {} layers", layered.len());
-                    for (i, layer) in layered.iter().enumerate() {
-                        println!("Layer {} => {:?}", i, layer);
-                    }
+                    emit_topological_layers(&layered)?;
                 } else {
                     let sorted = ws.topological_order_crate_names(&config).await?;
                     info!("Workspace flat => sorted crates: {:?}", sorted);
-                    for c in sorted {
-                        println!("{}", c);
-                    }
+                    emit_topological_order(&sorted)?;
                 }
                 Ok(())
             })
@@ -273,3 +261,34 @@ impl TopoSubcommand {
         }
     }
 }
+
+/// `{"layers": [[..], ..]}`, the JSON form of `ws topo --layered`.
+#[derive(Serialize,Debug)]
+struct TopologicalLayersReport<'a> {
+    layers: &'a [Vec<String>],
+}
+
+/// `{"order": [..]}`, the JSON form of `ws topo`.
+#[derive(Serialize,Debug)]
+struct TopologicalOrderReport<'a> {
+    order: &'a [String],
+}
+
+/// `ws topo --layered`: `Layer <n> => [..]` lines, or a `TopologicalLayersReport` with
+/// `--format json`.
+fn emit_topological_layers(layers: &[Vec<String>]) -> Result<(), WorkspaceError> {
+    emit_report(&TopologicalLayersReport { layers }, || {
+        for (i, layer) in layers.iter().enumerate() {
+            println!("Layer {} => {:?}", i, layer);
+        }
+    })
+}
+
+/// `ws topo`: one crate per line, or a `TopologicalOrderReport` with `--format json`.
+fn emit_topological_order(order: &[String]) -> Result<(), WorkspaceError> {
+    emit_report(&TopologicalOrderReport { order }, || {
+        for c in order {
+            println!("{}", c);
+        }
+    })
+}
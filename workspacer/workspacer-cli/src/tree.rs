@@ -77,9 +77,8 @@ impl TreeSubcommand {
             ws.build_workspace_tree(*self.levels(), *self.verbose()).await?
         };
 
-        let output_str = tree.render(*self.show_version(), *self.show_path());
-        println!("{}", output_str);
-
-        Ok(())
+        emit_report(&tree, || {
+            println!("{}", tree.render(*self.show_version(), *self.show_path()));
+        })
     }
 }
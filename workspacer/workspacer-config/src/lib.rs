@@ -4,3 +4,5 @@
 x!{config}
 x!{directory}
 x!{fallback_error}
+x!{workspace_settings}
+x!{workspace_settings_error}
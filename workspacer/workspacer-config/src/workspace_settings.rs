@@ -0,0 +1,682 @@
+// ---------------- [ File: workspacer-config/src/workspace_settings.rs ]
+crate::ix!();
+
+/// File name of the standalone settings file at the workspace root.
+pub const WORKSPACE_SETTINGS_FILE_NAME: &str = "workspacer.toml";
+
+/// Per-workspace settings honored by the `ws` subcommands, read from `<root>/workspacer.toml`
+/// or, equivalently, from `[workspace.metadata.workspacer]` in the root Cargo.toml:
+///
+/// ```toml
+/// private-crates = ["internal-tools"]
+///
+/// [lint]
+/// clippy-args = ["-W", "clippy::pedantic"]
+///
+/// [publish]
+/// skip = ["examples"]
+///
+/// [bump]
+/// skip = ["examples"]
+///
+/// [coverage]
+/// tool         = "tarpaulin"
+/// min-coverage = 60.0
+///
+/// [readme]
+/// model = "gpt-4o"
+/// skip  = ["workspacer-3p"]
+///
+/// [prefix-groups]
+/// ignore      = ["workspacer-cli"]
+/// min-members = 2
+///
+/// [audit]
+/// allow-licenses    = ["MIT", "Apache-2.0", "BSD-3-Clause"]
+/// deny-licenses     = ["GPL-3.0-only"]
+/// ignore-advisories = ["RUSTSEC-2020-0071"]
+/// advisory-db       = "../advisory-db"
+/// index             = "../crates.io-index"
+///
+/// [msrv]
+/// toolchains = ["stable", "1.85", "nightly"]
+///
+/// [templates]
+/// dirs = ["tools/crate-templates"]
+///
+/// [crates.my-core-crate]
+/// min-coverage = 85.0
+/// clippy-args  = ["-D", "clippy::unwrap_used"]
+/// ```
+///
+/// Everything is optional; no settings at all means the historical behavior.
+#[derive(Getters,Debug,Clone,PartialEq,Serialize,Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+#[getset(get="pub")]
+pub struct WorkspaceSettings {
+    /// Treated like `publish = false`, whatever the crate's Cargo.toml says
+    private_crates: Vec<String>,
+    lint:           LintSettings,
+    publish:        PublishSettings,
+    bump:           BumpSettings,
+    coverage:       CoverageSettings,
+    readme:         ReadmeSettings,
+    prefix_groups:  PrefixGroupSettings,
+    audit:          AuditSettings,
+    msrv:           MsrvSettings,
+    templates:      TemplateSettings,
+
+    /// Per-crate overrides, keyed by package name
+    crates:         BTreeMap<String, CrateSettingsOverride>,
+
+    /// Where these settings were read from; `None` for the defaults
+    #[serde(skip)]
+    source:         Option<PathBuf>,
+}
+
+#[derive(Getters,Default,Debug,Clone,PartialEq,Serialize,Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+#[getset(get="pub")]
+pub struct LintSettings {
+    /// Lint flags passed to clippy after `--`
+    clippy_args: Vec<String>,
+}
+
+#[derive(Getters,Default,Debug,Clone,PartialEq,Serialize,Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+#[getset(get="pub")]
+pub struct PublishSettings {
+    skip: Vec<String>,
+}
+
+#[derive(Getters,Default,Debug,Clone,PartialEq,Serialize,Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+#[getset(get="pub")]
+pub struct BumpSettings {
+    skip: Vec<String>,
+}
+
+#[derive(Copy,Clone,Default,Debug,PartialEq,Eq,Serialize,Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CoverageTool {
+    #[default]
+    Tarpaulin,
+}
+
+impl fmt::Display for CoverageTool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoverageTool::Tarpaulin => write!(f, "tarpaulin"),
+        }
+    }
+}
+
+#[derive(Getters,Default,Debug,Clone,PartialEq,Serialize,Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+#[getset(get="pub")]
+pub struct CoverageSettings {
+    tool:         CoverageTool,
+
+    /// Minimum line coverage in percent for every crate without its own `min-coverage`
+    min_coverage: Option<f32>,
+}
+
+#[derive(Getters,Default,Debug,Clone,PartialEq,Serialize,Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+#[getset(get="pub")]
+pub struct ReadmeSettings {
+    /// Language model name as the readme-writer spells it (`gpt-4o`, `o1-mini`, ...)
+    model: Option<String>,
+    skip:  Vec<String>,
+}
+
+#[derive(Getters,Debug,Clone,PartialEq,Serialize,Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+#[getset(get="pub")]
+pub struct PrefixGroupSettings {
+    /// Crates never considered part of a prefix group
+    ignore:      Vec<String>,
+
+    /// Crates sharing a prefix (facade included) needed to form a group
+    min_members: usize,
+}
+
+impl Default for PrefixGroupSettings {
+    fn default() -> Self {
+        Self { ignore: vec![], min_members: 2 }
+    }
+}
+
+/// Policy and offline snapshots for `ws audit`. Relative paths are taken from the workspace root.
+#[derive(Getters,Default,Debug,Clone,PartialEq,Serialize,Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+#[getset(get="pub")]
+pub struct AuditSettings {
+    /// SPDX ids third-party crates may be used under; empty allows anything not denied
+    allow_licenses:    Vec<String>,
+    deny_licenses:     Vec<String>,
+
+    /// Advisory ids that are known and accepted
+    ignore_advisories: Vec<String>,
+
+    /// A checkout of the RustSec advisory database
+    advisory_db:       Option<PathBuf>,
+
+    /// A copy of the crates.io index, for yanked versions
+    index:             Option<PathBuf>,
+
+    /// Directories holding unpacked crate sources (`cargo vendor` output, a registry `src/` dir)
+    /// to read third-party licenses from; defaults to cargo's registry cache
+    sources:           Vec<PathBuf>,
+}
+
+/// The toolchains `ws msrv` checks every crate against: `stable`, `beta`, `nightly`, a dated
+/// `nightly-YYYY-MM-DD` or a release such as `1.85`.
+#[derive(Getters,Debug,Clone,PartialEq,Serialize,Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+#[getset(get="pub")]
+pub struct MsrvSettings {
+    toolchains: Vec<String>,
+}
+
+impl Default for MsrvSettings {
+    fn default() -> Self {
+        Self { toolchains: vec!["stable".to_string()] }
+    }
+}
+
+/// Extra directories of crate templates for `ws add crate --template`, searched before the
+/// user's template directory. Relative paths are taken from the workspace root.
+#[derive(Getters,Default,Debug,Clone,PartialEq,Serialize,Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+#[getset(get="pub")]
+pub struct TemplateSettings {
+    dirs: Vec<PathBuf>,
+}
+
+/// `[crates.<name>]`: anything set here wins over the workspace-wide value.
+#[derive(Getters,Default,Debug,Clone,PartialEq,Serialize,Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+#[getset(get="pub")]
+pub struct CrateSettingsOverride {
+    private:      Option<bool>,
+    skip_publish: Option<bool>,
+    skip_bump:    Option<bool>,
+    skip_lint:    Option<bool>,
+
+    /// Replaces `[lint] clippy-args` when this crate is linted on its own
+    clippy_args:  Option<Vec<String>>,
+    min_coverage: Option<f32>,
+    readme_model: Option<String>,
+    skip_readme:  Option<bool>,
+}
+
+/// The settings that apply to one crate once overrides are resolved.
+#[derive(Getters,Debug,Clone,PartialEq,Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[getset(get="pub")]
+pub struct EffectiveCrateSettings {
+    #[serde(skip)]
+    crate_name:   String,
+    private:      bool,
+    skip_publish: bool,
+    skip_bump:    bool,
+    skip_lint:    bool,
+    clippy_args:  Vec<String>,
+    min_coverage: Option<f32>,
+    readme_model: Option<String>,
+    skip_readme:  bool,
+}
+
+impl Default for WorkspaceSettings {
+    fn default() -> Self {
+        Self {
+            private_crates: vec![],
+            lint:           LintSettings::default(),
+            publish:        PublishSettings::default(),
+            bump:           BumpSettings::default(),
+            coverage:       CoverageSettings::default(),
+            readme:         ReadmeSettings::default(),
+            prefix_groups:  PrefixGroupSettings::default(),
+            audit:          AuditSettings::default(),
+            msrv:           MsrvSettings::default(),
+            templates:      TemplateSettings::default(),
+            crates:         BTreeMap::new(),
+            source:         None,
+        }
+    }
+}
+
+impl WorkspaceSettings {
+
+    /// Reads the settings of the workspace at `workspace_root`.
+    ///
+    /// No `workspacer.toml` and no `[workspace.metadata.workspacer]` table gives the defaults;
+    /// both at once is an error, as is any value failing `validate_values`.
+    pub async fn load(workspace_root: &Path) -> Result<Self, WorkspaceSettingsError> {
+        let toml_file  = workspace_root.join(WORKSPACE_SETTINGS_FILE_NAME);
+        let cargo_toml = workspace_root.join("Cargo.toml");
+
+        let from_file = match read_if_exists(&toml_file).await? {
+            Some(text) => Some(Self::from_workspacer_toml(&text).map_err(|message| {
+                WorkspaceSettingsError::Parse { path: toml_file.clone(), message }
+            })?),
+            None => None,
+        };
+
+        let from_cargo = match read_if_exists(&cargo_toml).await? {
+            Some(text) => Self::from_cargo_toml(&text).map_err(|message| {
+                WorkspaceSettingsError::Parse { path: cargo_toml.clone(), message }
+            })?,
+            None => None,
+        };
+
+        let (mut settings, path) = match (from_file, from_cargo) {
+            (Some(_), Some(_)) => {
+                return Err(WorkspaceSettingsError::ConflictingSources { toml_file, cargo_toml });
+            }
+            (Some(s), None) => (s, toml_file),
+            (None, Some(s)) => (s, cargo_toml),
+            (None, None) => {
+                trace!("no workspace settings under {:?}; using defaults", workspace_root);
+                return Ok(Self::default());
+            }
+        };
+
+        let problems = settings.validate_values();
+        if !problems.is_empty() {
+            return Err(WorkspaceSettingsError::Invalid { path, problems });
+        }
+
+        debug!("loaded workspace settings from {:?}", path);
+        settings.source = Some(path);
+        Ok(settings)
+    }
+
+    /// Reads the settings of the workspace `crate_dir` belongs to; the defaults if it belongs to none.
+    pub async fn load_for_crate(crate_dir: &Path) -> Result<Self, WorkspaceSettingsError> {
+        match find_workspace_root(crate_dir).await? {
+            Some(workspace_root) => Self::load(&workspace_root).await,
+            None => {
+                trace!("{:?} is not inside a workspace; using default settings", crate_dir);
+                Ok(Self::default())
+            }
+        }
+    }
+
+    /// Parses the contents of a standalone `workspacer.toml`.
+    pub fn from_workspacer_toml(text: &str) -> Result<Self, String> {
+        toml::from_str(text).map_err(|e| e.to_string())
+    }
+
+    /// Parses `[workspace.metadata.workspacer]` out of a Cargo.toml; `None` if it has no such table.
+    pub fn from_cargo_toml(text: &str) -> Result<Option<Self>, String> {
+        let value: toml::Value = toml::from_str(text).map_err(|e| e.to_string())?;
+
+        let Some(table) = value
+            .get("workspace")
+            .and_then(|w| w.get("metadata"))
+            .and_then(|m| m.get("workspacer"))
+        else {
+            return Ok(None);
+        };
+
+        table.clone().try_into().map(Some).map_err(|e: toml::de::Error| e.to_string())
+    }
+
+    /// Checks what can be checked without knowing the workspace members.
+    pub fn validate_values(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+
+        let mut check_percent = |what: String, v: Option<f32>| {
+            if let Some(v) = v {
+                if !(0.0..=100.0).contains(&v) {
+                    problems.push(format!("{} must be between 0 and 100, got {}", what, v));
+                }
+            }
+        };
+        check_percent("coverage.min-coverage".to_string(), self.coverage.min_coverage);
+        for (name, o) in &self.crates {
+            check_percent(format!("crates.{}.min-coverage", name), o.min_coverage);
+        }
+
+        if self.prefix_groups.min_members < 2 {
+            problems.push(format!("prefix-groups.min-members must be at least 2, got {}", self.prefix_groups.min_members));
+        }
+
+        let empty_model = |m: &Option<String>| m.as_deref().is_some_and(|m| m.trim().is_empty());
+        if empty_model(&self.readme.model) {
+            problems.push("readme.model must not be empty".to_string());
+        }
+        for (name, o) in &self.crates {
+            if empty_model(&o.readme_model) {
+                problems.push(format!("crates.{}.readme-model must not be empty", name));
+            }
+        }
+
+        for id in self.audit.allow_licenses.iter().filter(|id| self.audit.deny_licenses.contains(id)) {
+            problems.push(format!("audit: license '{}' is both allowed and denied", id));
+        }
+
+        if self.msrv.toolchains.is_empty() {
+            problems.push("msrv.toolchains must name at least one toolchain".to_string());
+        }
+
+        // the arguments already go after `--`
+        let has_separator = |args: &[String]| args.iter().any(|a| a == "--");
+        if has_separator(&self.lint.clippy_args) {
+            problems.push("lint.clippy-args must not contain `--`".to_string());
+        }
+        for (name, o) in &self.crates {
+            if o.clippy_args.as_deref().is_some_and(has_separator) {
+                problems.push(format!("crates.{}.clippy-args must not contain `--`", name));
+            }
+        }
+
+        problems
+    }
+
+    /// Every crate name the settings mention which is not one of `members`.
+    ///
+    /// A misspelled name in a skip list silently does nothing, which for `publish.skip` means
+    /// publishing a crate that was meant to stay put; callers acting on crates check this first.
+    pub fn unknown_crate_names(&self, members: &[String]) -> Vec<String> {
+        let known: BTreeSet<&str> = members.iter().map(String::as_str).collect();
+
+        let mut problems = Vec::new();
+        let lists: [(&str, &Vec<String>); 5] = [
+            ("private-crates",       &self.private_crates),
+            ("publish.skip",         &self.publish.skip),
+            ("bump.skip",            &self.bump.skip),
+            ("readme.skip",          &self.readme.skip),
+            ("prefix-groups.ignore", &self.prefix_groups.ignore),
+        ];
+        for (key, names) in lists {
+            for name in names.iter().filter(|n| !known.contains(n.as_str())) {
+                problems.push(format!("{} names unknown crate '{}'", key, name));
+            }
+        }
+        for name in self.crates.keys().filter(|n| !known.contains(n.as_str())) {
+            problems.push(format!("[crates.{}] names an unknown crate", name));
+        }
+
+        problems
+    }
+
+    /// `validate_values` has run in `load`; this adds the member check.
+    pub fn validate_against_members(&self, members: &[String]) -> Result<(), WorkspaceSettingsError> {
+        let problems = self.unknown_crate_names(members);
+        if problems.is_empty() {
+            return Ok(());
+        }
+        Err(WorkspaceSettingsError::Invalid {
+            path: self.source.clone().unwrap_or_default(),
+            problems,
+        })
+    }
+
+    /// Resolves the workspace-wide values and the `[crates.<name>]` override for one crate.
+    pub fn for_crate(&self, crate_name: &str) -> EffectiveCrateSettings {
+        let o = self.crates.get(crate_name).cloned().unwrap_or_default();
+        let listed = |names: &[String]| names.iter().any(|n| n == crate_name);
+
+        let private = o.private.unwrap_or_else(|| listed(&self.private_crates));
+
+        EffectiveCrateSettings {
+            crate_name:   crate_name.to_string(),
+            private,
+            skip_publish: private || o.skip_publish.unwrap_or_else(|| listed(&self.publish.skip)),
+            skip_bump:    o.skip_bump.unwrap_or_else(|| listed(&self.bump.skip)),
+            skip_lint:    o.skip_lint.unwrap_or(false),
+            clippy_args:  o.clippy_args.unwrap_or_else(|| self.lint.clippy_args.clone()),
+            min_coverage: o.min_coverage.or(self.coverage.min_coverage),
+            readme_model: o.readme_model.or_else(|| self.readme.model.clone()),
+            skip_readme:  o.skip_readme.unwrap_or_else(|| listed(&self.readme.skip)),
+        }
+    }
+
+    /// Whether `crate_name` is excluded from prefix-group scans.
+    pub fn ignores_for_prefix_groups(&self, crate_name: &str) -> bool {
+        self.prefix_groups.ignore.iter().any(|n| n == crate_name)
+    }
+
+    /// Renders the effective settings: the workspace-wide values, then one table per member.
+    pub fn render_effective(&self, members: &[String]) -> String {
+        #[derive(Serialize)]
+        #[serde(rename_all = "kebab-case")]
+        struct Effective<'a> {
+            private_crates: &'a Vec<String>,
+            lint:           &'a LintSettings,
+            publish:        &'a PublishSettings,
+            bump:           &'a BumpSettings,
+            coverage:       &'a CoverageSettings,
+            readme:         &'a ReadmeSettings,
+            prefix_groups:  &'a PrefixGroupSettings,
+            audit:          &'a AuditSettings,
+            msrv:           &'a MsrvSettings,
+            templates:      &'a TemplateSettings,
+            crates:         BTreeMap<&'a str, EffectiveCrateSettings>,
+        }
+
+        let effective = Effective {
+            private_crates: &self.private_crates,
+            lint:           &self.lint,
+            publish:        &self.publish,
+            bump:           &self.bump,
+            coverage:       &self.coverage,
+            readme:         &self.readme,
+            prefix_groups:  &self.prefix_groups,
+            audit:          &self.audit,
+            msrv:           &self.msrv,
+            templates:      &self.templates,
+            crates:         members.iter().map(|m| (m.as_str(), self.for_crate(m))).collect(),
+        };
+
+        let source = match &self.source {
+            Some(path) => format!("# settings from {}\n", path.display()),
+            None       => "# no workspace settings found; showing defaults\n".to_string(),
+        };
+
+        match toml::to_string_pretty(&effective) {
+            Ok(text) => source + &text,
+            Err(e)   => format!("{}# could not render settings: {}\n{:#?}\n", source, e, self),
+        }
+    }
+}
+
+/// The nearest directory at or above `start` whose Cargo.toml has a `[workspace]` table.
+pub async fn find_workspace_root(start: &Path) -> Result<Option<PathBuf>, WorkspaceSettingsError> {
+    let start = tokio::fs::canonicalize(start).await.unwrap_or_else(|_| start.to_path_buf());
+
+    for dir in start.ancestors() {
+        let cargo_toml = dir.join("Cargo.toml");
+        let Some(text) = read_if_exists(&cargo_toml).await? else {
+            continue;
+        };
+        let value: toml::Value = toml::from_str(&text).map_err(|e| {
+            WorkspaceSettingsError::Parse { path: cargo_toml.clone(), message: e.to_string() }
+        })?;
+        if value.get("workspace").is_some() {
+            return Ok(Some(dir.to_path_buf()));
+        }
+    }
+    Ok(None)
+}
+
+async fn read_if_exists(path: &Path) -> Result<Option<String>, WorkspaceSettingsError> {
+    match tokio::fs::read_to_string(path).await {
+        Ok(text) => Ok(Some(text)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(WorkspaceSettingsError::Io { path: path.to_path_buf(), io: Arc::new(e) }),
+    }
+}
+
+#[cfg(test)]
+mod test_workspace_settings {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+        private-crates = ["internal"]
+
+        [lint]
+        clippy-args = ["-W", "clippy::pedantic"]
+
+        [publish]
+        skip = ["examples"]
+
+        [coverage]
+        min-coverage = 60
+
+        [readme]
+        model = "gpt-4o"
+
+        [crates.core]
+        min-coverage = 85.5
+        clippy-args  = []
+        readme-model = "o1-mini"
+
+        [crates.internal]
+        private = false
+    "#;
+
+    fn members() -> Vec<String> {
+        ["core", "internal", "examples", "app"].iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn overrides_win_over_workspace_values() {
+        let s = WorkspaceSettings::from_workspacer_toml(SAMPLE).unwrap();
+        assert!(s.validate_values().is_empty());
+
+        let core = s.for_crate("core");
+        assert_eq!(core.min_coverage(), &Some(85.5));
+        assert!(core.clippy_args().is_empty());
+        assert_eq!(core.readme_model().as_deref(), Some("o1-mini"));
+
+        let app = s.for_crate("app");
+        assert_eq!(app.min_coverage(), &Some(60.0));
+        assert_eq!(app.clippy_args(), &vec!["-W".to_string(), "clippy::pedantic".to_string()]);
+        assert!(!app.skip_publish());
+
+        assert!(s.for_crate("examples").skip_publish());
+        assert!(!s.for_crate("examples").private());
+
+        // listed as private, but the per-crate table says otherwise
+        assert!(!s.for_crate("internal").private());
+    }
+
+    #[test]
+    fn private_crates_are_never_published() {
+        let s = WorkspaceSettings::from_workspacer_toml("private-crates = [\"a\"]").unwrap();
+        assert!(s.for_crate("a").skip_publish());
+        assert!(!s.for_crate("a").skip_bump());
+    }
+
+    #[test]
+    fn reads_the_cargo_metadata_table() {
+        let cargo = r#"
+            [workspace]
+            members = ["a"]
+
+            [workspace.metadata.workspacer.coverage]
+            min-coverage = 40
+        "#;
+        let s = WorkspaceSettings::from_cargo_toml(cargo).unwrap().unwrap();
+        assert_eq!(s.coverage().min_coverage(), &Some(40.0));
+        assert_eq!(s.coverage().tool(), &CoverageTool::Tarpaulin);
+
+        assert_eq!(WorkspaceSettings::from_cargo_toml("[workspace]\nmembers = []").unwrap(), None);
+    }
+
+    #[test]
+    fn unknown_keys_and_bad_values_are_rejected() {
+        assert!(WorkspaceSettings::from_workspacer_toml("[lint]\nclipy-args = []").is_err());
+        assert!(WorkspaceSettings::from_workspacer_toml("[coverage]\ntool = \"gcov\"").is_err());
+
+        let s = WorkspaceSettings::from_workspacer_toml(r#"
+            [coverage]
+            min-coverage = 120
+            [prefix-groups]
+            min-members = 1
+            [crates.a]
+            clippy-args = ["--", "-D", "warnings"]
+        "#).unwrap();
+        assert_eq!(s.validate_values().len(), 3);
+    }
+
+    #[test]
+    fn reads_audit_policy() {
+        let s = WorkspaceSettings::from_workspacer_toml(r#"
+            [audit]
+            allow-licenses = ["MIT", "Apache-2.0"]
+            deny-licenses  = ["MIT"]
+            advisory-db    = "../advisory-db"
+        "#).unwrap();
+        assert_eq!(s.audit().allow_licenses().len(), 2);
+        assert_eq!(s.audit().advisory_db().as_deref(), Some(Path::new("../advisory-db")));
+        assert!(s.audit().sources().is_empty());
+        assert_eq!(s.validate_values().len(), 1);
+    }
+
+    #[test]
+    fn msrv_toolchains_default_to_stable() {
+        assert_eq!(WorkspaceSettings::default().msrv().toolchains(), &vec!["stable".to_string()]);
+
+        let s = WorkspaceSettings::from_workspacer_toml("[msrv]\ntoolchains = []\n").unwrap();
+        assert_eq!(s.validate_values(), vec!["msrv.toolchains must name at least one toolchain".to_string()]);
+    }
+
+    #[test]
+    fn misspelled_crate_names_are_reported() {
+        let s = WorkspaceSettings::from_workspacer_toml(SAMPLE).unwrap();
+        assert!(s.validate_against_members(&members()).is_ok());
+
+        let s = WorkspaceSettings::from_workspacer_toml("[publish]\nskip = [\"exmaples\"]\n[crates.cor]").unwrap();
+        let problems = s.unknown_crate_names(&members());
+        assert_eq!(problems.len(), 2);
+        assert!(problems[0].contains("exmaples"));
+    }
+
+    #[tokio::test]
+    async fn load_reads_either_source_but_not_both() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(WorkspaceSettings::load(dir.path()).await.unwrap(), WorkspaceSettings::default());
+
+        tokio::fs::write(dir.path().join("Cargo.toml"), "[workspace.metadata.workspacer]\nprivate-crates = [\"a\"]\n").await.unwrap();
+        let s = WorkspaceSettings::load(dir.path()).await.unwrap();
+        assert_eq!(s.private_crates(), &vec!["a".to_string()]);
+        assert_eq!(s.source().as_deref(), Some(dir.path().join("Cargo.toml").as_path()));
+
+        tokio::fs::write(dir.path().join(WORKSPACE_SETTINGS_FILE_NAME), "").await.unwrap();
+        assert!(matches!(
+            WorkspaceSettings::load(dir.path()).await,
+            Err(WorkspaceSettingsError::ConflictingSources { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn crate_settings_come_from_the_nearest_workspace_root() {
+        let dir  = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let member = root.join("crates").join("a");
+        tokio::fs::create_dir_all(&member).await.unwrap();
+
+        tokio::fs::write(root.join("Cargo.toml"), "[workspace]\nmembers = [\"crates/a\"]\n\n[workspace.metadata.workspacer]\nprivate-crates = [\"a\"]\n").await.unwrap();
+        tokio::fs::write(member.join("Cargo.toml"), "[package]\nname = \"a\"\nversion = \"0.1.0\"\n").await.unwrap();
+
+        let found = find_workspace_root(&member).await.unwrap().unwrap();
+        assert_eq!(found, tokio::fs::canonicalize(root).await.unwrap());
+
+        let s = WorkspaceSettings::load_for_crate(&member).await.unwrap();
+        assert_eq!(s.private_crates(), &vec!["a".to_string()]);
+    }
+
+    #[test]
+    fn renders_effective_settings_per_member() {
+        let s = WorkspaceSettings::from_workspacer_toml(SAMPLE).unwrap();
+        let text = s.render_effective(&members());
+        assert!(text.contains("[crates.core]"));
+        assert!(text.contains("min-coverage = 85.5"));
+        assert!(text.contains("[crates.examples]"));
+    }
+}
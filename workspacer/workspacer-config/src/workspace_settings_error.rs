@@ -0,0 +1,32 @@
+// ---------------- [ File: workspacer-config/src/workspace_settings_error.rs ]
+crate::ix!();
+
+error_tree!{
+
+    #[derive(Clone)]
+    pub enum WorkspaceSettingsError {
+        #[display("could not read workspace settings from {path:?}: {io}")]
+        Io {
+            path: PathBuf,
+            io:   Arc<std::io::Error>,
+        },
+
+        #[display("could not parse workspace settings in {path:?}: {message}")]
+        Parse {
+            path:    PathBuf,
+            message: String,
+        },
+
+        #[display("workspace settings are given both in {toml_file:?} and in [workspace.metadata.workspacer] of {cargo_toml:?}; keep only one")]
+        ConflictingSources {
+            toml_file:  PathBuf,
+            cargo_toml: PathBuf,
+        },
+
+        #[display("invalid workspace settings in {path:?}: {problems:?}")]
+        Invalid {
+            path:     PathBuf,
+            problems: Vec<String>,
+        },
+    }
+}
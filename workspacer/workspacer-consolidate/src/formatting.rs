@@ -202,6 +202,10 @@ pub fn leading_spaces(line: &str) -> usize {
 mod formatting_tests {
     use super::*;
 
+    fn owned(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|l| l.to_string()).collect()
+    }
+
     // ---------------------------------------
     // strip_outer_braces
     // ---------------------------------------
@@ -281,7 +285,7 @@ mod formatting_tests {
     #[test]
     fn test_conditional_dedent_all_no_dedent_flag() {
         let lines = &["   a", "     b", "\t   c"];
-        let result = conditional_dedent_all(lines, false);
+        let result = conditional_dedent_all(&owned(lines), false);
         // Should return them unchanged:
         assert_eq!(result, vec!["   a", "     b", "\t   c"]);
     }
@@ -290,7 +294,7 @@ mod formatting_tests {
     fn test_conditional_dedent_all_simple() {
         let lines = &["    fn hello() {", "        println!(\"hi\");", "    }"];
         // Without blank lines, we rely on the min leading spaces among non-blank:
-        let result = conditional_dedent_all(lines, true);
+        let result = conditional_dedent_all(&owned(lines), true);
         // The min indent among those lines is 4
         assert_eq!(
             result,
@@ -311,7 +315,7 @@ mod formatting_tests {
         // Non-blank lines have indentation 8 or 12. The minimum is 8,
         // so lines that are empty or have fewer leading spaces are unaffected
         // after we check the min among the non-empty lines.
-        let result = conditional_dedent_all(lines, true);
+        let result = conditional_dedent_all(&owned(lines), true);
         assert_eq!(
             result,
             vec![
@@ -329,7 +333,7 @@ mod formatting_tests {
     fn test_conditional_dedent_all_all_blank() {
         let lines = &["", "   ", "\t"];
         // All lines effectively blank => min_indent stays usize::MAX => set to 0 => no change
-        let result = conditional_dedent_all(lines, true);
+        let result = conditional_dedent_all(&owned(lines), true);
         // Our function explicitly sets min_indent=0 if everything is blank,
         // then we "empty them out" because line.trim() is empty => we produce "" lines
         // (which is consistent with the existing logic).
@@ -506,7 +510,7 @@ mod test_show_crate_and_workspace {
         let stripped = strip_outer_braces(snippet);
         let lines: Vec<&str> = stripped.lines().collect();
         // no normalization for this test
-        let dedented = conditional_dedent_all(&lines, true);
+        let dedented = conditional_dedent_all(&owned(&lines), true);
 
         // We'll check the resulting lines
         // ...
@@ -558,7 +562,7 @@ mod test_show_crate_and_workspace {
             "    }",
             "}",
         ];
-        let dedented = conditional_dedent_all(input, true);
+        let dedented = conditional_dedent_all(&owned(input), true);
 
         // Expect:
         // [ 
@@ -604,7 +608,7 @@ mod test_show_crate_and_workspace {
         // - line[3] => 4 => remain 4
         // - line[4] => 12 => become 4
 
-        let out = clamp_indent_at_4(input, true);
+        let out = clamp_indent_at_4(&owned(input), true);
 
         // Let's assert some expectations
         assert_eq!(leading_spaces(&out[0]), 0);
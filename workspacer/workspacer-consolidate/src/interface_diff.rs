@@ -0,0 +1,153 @@
+// ---------------- [ File: workspacer-consolidate/src/interface_diff.rs ]
+crate::ix!();
+
+/// Public items that appeared in or disappeared from a crate's consolidated interface between two
+/// versions, one signature line each. A changed signature shows up as one removal plus one
+/// addition.
+#[derive(Serialize,Getters,Debug,Clone,Default,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct InterfaceDiff {
+    added:   Vec<String>,
+    removed: Vec<String>,
+}
+
+impl InterfaceDiff {
+
+    pub fn between(old: &ConsolidatedCrateInterface, new: &ConsolidatedCrateInterface) -> Self {
+        Self::between_signatures(&old.interface_signatures(), &new.interface_signatures())
+    }
+
+    pub fn between_signatures(old: &BTreeSet<String>, new: &BTreeSet<String>) -> Self {
+        Self {
+            added:   new.difference(old).cloned().collect(),
+            removed: old.difference(new).cloned().collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+impl ConsolidatedCrateInterface {
+
+    /// One whitespace-normalized line per item, without docs or bodies: what `InterfaceDiff`
+    /// compares. Impl methods carry their impl header, module items the module path, and
+    /// macro invocations (`x!{..}`) are left out since they are not interface.
+    pub fn interface_signatures(&self) -> BTreeSet<String> {
+        let mut out = BTreeSet::new();
+
+        self.fns().iter().for_each(|i| { out.insert(signature_line(i, true)); });
+        self.structs().iter().for_each(|i| { out.insert(signature_line(i, true)); });
+        self.enums().iter().for_each(|i| { out.insert(signature_line(i, true)); });
+        self.traits().iter().for_each(|i| { out.insert(signature_line(i, true)); });
+        self.type_aliases().iter().for_each(|i| { out.insert(signature_line(i, true)); });
+        self.macros().iter().for_each(|i| { out.insert(signature_line(i, false)); });
+        self.impls().iter().for_each(|ib| impl_signature_lines(ib, "", &mut out));
+        self.modules().iter().for_each(|mi| module_signature_lines(mi, "", &mut out));
+
+        out
+    }
+}
+
+fn normalize_signature(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// `fully_expand` shows struct fields, enum variants and trait items, which are interface; for
+/// `macro_rules!` it would show the macro body, which is not.
+fn signature_line<T: GenerateSignature>(item: &CrateInterfaceItem<T>, fully_expand: bool) -> String {
+    let opts = SignatureOptionsBuilder::default()
+        .include_docs(false)
+        .fully_expand(fully_expand)
+        .build()
+        .unwrap();
+    normalize_signature(&item.item().generate_signature_with_opts(&opts))
+}
+
+fn in_module(line: String, module_path: &str) -> String {
+    if module_path.is_empty() {
+        line
+    } else {
+        format!("{} (in mod {})", line, module_path)
+    }
+}
+
+fn impl_signature_lines(ib: &ImplBlockInterface, module_path: &str, out: &mut BTreeSet<String>) {
+    let header = normalize_signature(ib.signature_text());
+    out.insert(in_module(header.clone(), module_path));
+
+    for method in ib.methods() {
+        out.insert(in_module(format!("{} {{ {} }}", header, signature_line(method, true)), module_path));
+    }
+    for alias in ib.type_aliases() {
+        out.insert(in_module(format!("{} {{ {} }}", header, signature_line(alias, true)), module_path));
+    }
+}
+
+fn module_signature_lines(mi: &ModuleInterface, parent_path: &str, out: &mut BTreeSet<String>) {
+    let path = if parent_path.is_empty() {
+        mi.mod_name().clone()
+    } else {
+        format!("{}::{}", parent_path, mi.mod_name())
+    };
+
+    for item in mi.items() {
+        match item {
+            ConsolidatedItem::Fn(i)        => { out.insert(in_module(signature_line(i, true), &path)); }
+            ConsolidatedItem::Struct(i)    => { out.insert(in_module(signature_line(i, true), &path)); }
+            ConsolidatedItem::Enum(i)      => { out.insert(in_module(signature_line(i, true), &path)); }
+            ConsolidatedItem::Trait(i)     => { out.insert(in_module(signature_line(i, true), &path)); }
+            ConsolidatedItem::TypeAlias(i) => { out.insert(in_module(signature_line(i, true), &path)); }
+            ConsolidatedItem::Macro(i)     => { out.insert(in_module(signature_line(i, false), &path)); }
+            ConsolidatedItem::ImplBlock(ib) => impl_signature_lines(ib, &path, out),
+            ConsolidatedItem::Module(inner) => module_signature_lines(inner, &path, out),
+            ConsolidatedItem::MacroCall(_) | ConsolidatedItem::MockTest(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_interface_diff {
+    use super::*;
+
+    fn interface(snippet: &str) -> ConsolidatedCrateInterface {
+        let sf   = SourceFile::parse(snippet, Edition::Edition2021).tree();
+        let opts = ConsolidationOptions::new();
+        let mut cci = ConsolidatedCrateInterface::new();
+
+        for item in gather_crate_items(&sf, &opts, &PathBuf::from("src/lib.rs"), &PathBuf::from("crate")) {
+            match item {
+                ConsolidatedItem::Fn(i)         => cci.add_fn(i),
+                ConsolidatedItem::Struct(i)     => cci.add_struct(i),
+                ConsolidatedItem::Enum(i)       => cci.add_enum(i),
+                ConsolidatedItem::Trait(i)      => cci.add_trait(i),
+                ConsolidatedItem::TypeAlias(i)  => cci.add_type_alias(i),
+                ConsolidatedItem::Macro(i)      => cci.add_macro(i),
+                ConsolidatedItem::MacroCall(i)  => cci.add_macro_call(i),
+                ConsolidatedItem::ImplBlock(ib) => cci.add_impl(ib),
+                ConsolidatedItem::Module(mi)    => cci.add_module(mi),
+                ConsolidatedItem::MockTest(_)   => {}
+            }
+        }
+        cci
+    }
+
+    #[traced_test]
+    fn reports_added_and_removed_public_items() {
+        let old = interface("pub fn keep() {}\npub fn gone(x: u8) {}\npub struct S;\n");
+        let new = interface("pub fn keep() { let _ = 1; }\npub fn gone(x: u16) {}\npub struct S;\nimpl S { pub fn fresh(&self) {} }\n");
+
+        let diff = InterfaceDiff::between(&old, &new);
+        assert!(diff.removed().iter().any(|l| l.contains("gone(x: u8)")), "{:?}", diff);
+        assert!(diff.added().iter().any(|l| l.contains("gone(x: u16)")), "{:?}", diff);
+        assert!(diff.added().iter().any(|l| l.contains("impl S") && l.contains("fresh")), "{:?}", diff);
+        assert!(!diff.added().iter().chain(diff.removed()).any(|l| l.contains("keep")), "{:?}", diff);
+    }
+
+    #[traced_test]
+    fn identical_interfaces_have_no_diff() {
+        let text = "pub trait T { fn f(&self); }\npub enum E { A, B }\n";
+        assert!(InterfaceDiff::between(&interface(text), &interface(text)).is_empty());
+    }
+}
@@ -20,6 +20,7 @@ x!{generate_impl_signature}
 x!{guess_is_function}
 x!{has_cfg_test_attr}
 x!{impl_block_interface}
+x!{interface_diff}
 x!{interstitial_segment}
 x!{is_in_test_module}
 x!{leading_spaces}
@@ -0,0 +1,309 @@
+// ---------------- [ File: workspacer-errors/src/error_kind.rs ]
+crate::ix!();
+
+/// The machine-readable name of an error: the chain of wrapper variants leading to the
+/// actual error, e.g. `CrateError::CrateNotFoundInWorkspace`.
+///
+/// `ws --format json` reports this as the error's `kind`.
+pub trait ErrorKindName {
+    fn kind(&self) -> String;
+}
+
+/// Implements `ErrorKindName` with one explicit match arm per variant, so adding a variant
+/// without naming it here fails to compile. Variants listed after `wraps` hold another
+/// error whose kind is appended to theirs.
+macro_rules! impl_error_kind {
+    ($(
+        $ty:ident {
+            $($leaf:ident),* $(,)?
+            $(; wraps $($wrap:ident),+ $(,)?)?
+        }
+    )*) => {
+        $(
+            impl ErrorKindName for $ty {
+                fn kind(&self) -> String {
+                    match self {
+                        $(Self::$leaf { .. } => stringify!($leaf).to_string(),)*
+                        $($(Self::$wrap(inner) => format!("{}::{}", stringify!($wrap), inner.kind()),)+)?
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_error_kind!{
+
+    WorkspaceError {
+        TomlSerErr,
+        CycleDetectedInWorkspaceDependencyGraph,
+        ActuallyInSingleCrate,
+        CratePinFailed,
+        TokioJoinError,
+        IoError,
+        InvalidLockfile,
+        FileNotFound,
+        InvalidWorkspace,
+        CircularDependency,
+        CoverageParseError,
+        DirectoryRemovalError,
+        FileRemovalError,
+        MultipleErrors,
+        WorkspaceNotReadyForCargoPublish,
+        InvalidSymbolQuery,
+        JsonOutputError,
+        FileWatchError,
+        TestTimeout,
+        FileFilterError,
+        MockBuildTestFailedWithStatus,
+        BumpError;
+        wraps
+        SourceFileRegistrationError,
+        GitError,
+        CrateError,
+        WorkspaceSettingsError,
+        ItemRelocationError,
+        PrefixGroupError,
+        AuditError,
+        MsrvError,
+        CrateTemplateError,
+        CargoDocError,
+        CrateWriteError,
+        ReadmeWriteError,
+        TokioError,
+        CargoMetadataError,
+        InvalidCargoToml,
+        CargoTomlWriteError,
+        LintingError,
+        TestCoverageError,
+        TestFailure,
+        WatchError,
+        BuildError,
+        FileError,
+        DirectoryError,
+    }
+
+    CrateError {
+        CrateVersionNotFound,
+        CargoTomlIsLocked,
+        CouldNotLockMockCargoTomlInVersion,
+        CouldNotSetPackageVersionBecausePackageIsNotATable,
+        SimulatedIntegrityFailureInMockCrate,
+        SimulatedInvalidVersionFormat,
+        SemverError,
+        CrateIsPrivate,
+        SortAndFormatImportsInTextError,
+        FailedToRunCargoPublish,
+        CargoPublishFailedForCrateWithExitCode,
+        FailedtoRunCargoPublish,
+        CrateAlreadyPublishedOnCratesIo,
+        FailedCratesIoCheck,
+        LockfileParseFailed,
+        FileNotFound,
+        DirectoryNotFound,
+        FailedToGetFileNameForPath,
+        IoError,
+        TokioJoinError,
+        CrateNotFoundInWorkspace;
+        wraps
+        CargoMetadataError,
+        ReadmeWriteError,
+        DirectoryError,
+        CargoTomlError,
+        BuildError,
+        TestFailure,
+        WatchError,
+        WorkspaceError,
+    }
+
+    SourceFileRegistrationError {
+        EncounteredAnXMacroAfterWeAlreadySawANonAttributeItem,
+        LibRsSyntaxErrors,
+        LibRsParseTreeError,
+        FoundAnUnhandlableTopLevelMacroCallWithAttributes,
+        MultipleItemsInXMacroUnsupported,
+        FoundARawModNameWhichWeDontHandlePleaseRemoveOrUnifyWithXMacros,
+        EncounteredAnXMacroAfterWeAlreadySawANonAttributeItem_NotRewritingSafely;
+        wraps
+        CrateError,
+    }
+
+    CargoTomlWriteError {
+        WriteWorkspaceHeaderError,
+        OpenWorkspaceMembersFieldError,
+        WritePackageSectionError,
+        WriteWorkspaceMember,
+        CloseWorkspaceMembersFieldError,
+        WriteError,
+    }
+
+    CargoTomlError {
+        TomlSerializeError,
+        IoWriteError,
+        TopLevelNotATable,
+        ReadError,
+        MissingRequiredFieldForPublishing,
+        MissingRequiredFieldForIntegrity,
+        InvalidToml,
+        InvalidVersionFormat,
+        MissingPackageSection,
+        MissingVersionKey,
+        TomlParseError,
+        TomlEditError,
+        FileNotFound,
+        FileIsNotAFile,
+        SemverError;
+        wraps
+        WorkspacerFallbackError,
+        CargoTomlWriteError,
+    }
+
+    TestFailure {
+        UnknownError,
+    }
+
+    TokioError {
+        JoinError,
+    }
+
+    DirectoryError {
+        CreateDirAllError,
+        ReadDirError,
+        GetNextEntryError,
+    }
+
+    ReadmeWriteError {
+        AiReadmeWriterError,
+        WriteBlankReadmeError,
+    }
+
+    CrateWriteError {
+        WriteDummyMainError,
+        WriteDummyTestError,
+        WriteLibRsFileError,
+        WriteMainFnError;
+        wraps
+        ReadmeWriteError,
+    }
+
+    TestCoverageError {
+        TestFailure,
+        UnknownError,
+        CoverageParseError,
+        CommandError,
+        ReportIoError,
+        InvalidCoverageConfig,
+        GitDiffFailed,
+        CoverageBelowThreshold,
+    }
+
+    CargoDocError {
+        CommandError,
+        UnknownError,
+    }
+
+    LintingError {
+        CommandError,
+        UnknownError,
+        NewLintWarnings,
+        BaselineIoError,
+        BaselineParseError,
+        FixFailed;
+        wraps
+        WorkspaceSettingsError,
+    }
+
+    CargoMetadataError {
+        MetadataError,
+        CircularDependency,
+        CyclicPackageDependency,
+    }
+
+    BuildError {
+        CommandError,
+        BuildFailed,
+    }
+
+    WatchError {
+        NotifyError,
+        IoError,
+        ChannelRecvError,
+    }
+
+    GitError {
+        FailedToRunGitStatusMakeSureGitIsInstalled,
+        WorkingDirectoryIsNotCleanAborting,
+        IoError,
+        DiffFailed,
+        CommandFailed,
+        ReleaseTagAlreadyExists,
+        CratesNotClean,
+        StashPopFailed,
+        NoWorkspacerOperationToRevert,
+        RevertFailed,
+    }
+
+    ItemRelocationError {
+        SameCrate,
+        CrateAlreadyExists,
+        ItemNotFound,
+        AmbiguousItem,
+        SourceParseError,
+        TargetFileExists,
+        WouldCreateDependencyCycle,
+    }
+
+    PrefixGroupError {
+        InvalidPrefix,
+        PrefixGroupAlreadyExists,
+        NoSuchPrefixGroup,
+        UnparseableCargoToml,
+    }
+
+    AuditError {
+        UnreadableSnapshot,
+        MalformedAdvisory,
+        ProblemsFound,
+    }
+
+    MsrvError {
+        InvalidToolchain,
+        UnknownEdition,
+        CratesFailToolchainCheck,
+        EditionMigrationRolledBack,
+    }
+
+    CrateTemplateError {
+        UnknownTemplate,
+        UnknownPlaceholder,
+        TemplateHasNoCrate,
+        UnreadableTemplate,
+        InvalidTemplateManifest,
+        TemplateAlreadyRegistered,
+        CrateDirectoryExists,
+        PrefixGroupRequired,
+        PrefixGroupNotFound,
+    }
+
+    FileError {
+        CreationError,
+        WriteError,
+        GetMetadataError,
+        OpenError,
+        GetNextLineError,
+    }
+
+    WorkspaceSettingsError {
+        Io,
+        Parse,
+        ConflictingSources,
+        Invalid,
+    }
+
+    WorkspacerFallbackError {
+        Io,
+        TomlParse,
+        MissingConfig,
+        NoHomeDirectory,
+    }
+}
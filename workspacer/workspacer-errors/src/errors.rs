@@ -178,6 +178,21 @@ error_tree!{
         CommandError {
             io: Arc<io::Error>,
         },
+        ReportIoError {
+            path: PathBuf,
+            io:   Arc<io::Error>,
+        },
+        InvalidCoverageConfig {
+            path:    PathBuf,
+            message: String,
+        },
+        GitDiffFailed {
+            git_ref: String,
+            stderr:  String,
+        },
+        CoverageBelowThreshold {
+            rendered: String,
+        },
     }
 
     #[derive(Clone)]
@@ -199,7 +214,24 @@ error_tree!{
         UnknownError {
             stdout: Option<String>,
             stderr: Option<String>,
-        }
+        },
+        NewLintWarnings {
+            count:    usize,
+            rendered: String,
+        },
+        BaselineIoError {
+            path: PathBuf,
+            io:   Arc<io::Error>,
+        },
+        BaselineParseError {
+            path:    PathBuf,
+            message: String,
+        },
+        FixFailed {
+            crate_name: String,
+            stderr:     String,
+        },
+        WorkspaceSettingsError(WorkspaceSettingsError),
     }
 
     #[derive(Clone)]
@@ -353,7 +385,146 @@ error_tree!{
         IoError {
             io:      Arc<io::Error>,
             context: String,
-        }
+        },
+        DiffFailed {
+            range:  String,
+            stderr: String,
+        },
+        CommandFailed {
+            args:   String,
+            stderr: String,
+        },
+        ReleaseTagAlreadyExists {
+            tag: String,
+        },
+        CratesNotClean {
+            crates: Vec<String>,
+        },
+        StashPopFailed {
+            stash:  String,
+            stderr: String,
+        },
+        NoWorkspacerOperationToRevert,
+        RevertFailed {
+            commit: String,
+            stderr: String,
+        },
+    }
+
+    #[derive(Clone)]
+    pub enum ItemRelocationError {
+        SameCrate {
+            crate_name: String,
+        },
+        CrateAlreadyExists {
+            crate_name: String,
+        },
+        ItemNotFound {
+            crate_name: String,
+            item_name:  String,
+        },
+        AmbiguousItem {
+            crate_name: String,
+            item_name:  String,
+            files:      Vec<PathBuf>,
+        },
+        SourceParseError {
+            path:   PathBuf,
+            errors: Vec<String>,
+        },
+        TargetFileExists {
+            path: PathBuf,
+        },
+        WouldCreateDependencyCycle {
+            dependent:  String,
+            dependency: String,
+        },
+    }
+
+    #[derive(Clone)]
+    pub enum PrefixGroupError {
+        InvalidPrefix {
+            prefix: String,
+        },
+        PrefixGroupAlreadyExists {
+            prefix: String,
+            path:   PathBuf,
+        },
+        NoSuchPrefixGroup {
+            prefix: String,
+        },
+        UnparseableCargoToml {
+            path:    PathBuf,
+            message: String,
+        },
+    }
+
+    #[derive(Clone)]
+    pub enum AuditError {
+        UnreadableSnapshot {
+            path: PathBuf,
+            io:   Arc<io::Error>,
+        },
+        MalformedAdvisory {
+            path:    PathBuf,
+            message: String,
+        },
+        ProblemsFound {
+            count: usize,
+        },
+    }
+
+    #[derive(Clone)]
+    pub enum MsrvError {
+        InvalidToolchain {
+            toolchain: String,
+        },
+        UnknownEdition {
+            crate_name: String,
+            edition:    String,
+        },
+        CratesFailToolchainCheck {
+            crates: Vec<String>,
+        },
+        EditionMigrationRolledBack {
+            crates: Vec<String>,
+        },
+    }
+
+    #[derive(Clone)]
+    pub enum CrateTemplateError {
+        UnknownTemplate {
+            name:     String,
+            searched: Vec<PathBuf>,
+        },
+        UnknownPlaceholder {
+            template:    String,
+            placeholder: String,
+        },
+        TemplateHasNoCrate {
+            template: String,
+        },
+        UnreadableTemplate {
+            path: PathBuf,
+            io:   Arc<io::Error>,
+        },
+        InvalidTemplateManifest {
+            path:    PathBuf,
+            message: String,
+        },
+        TemplateAlreadyRegistered {
+            name: String,
+            path: PathBuf,
+        },
+        CrateDirectoryExists {
+            path: PathBuf,
+        },
+        PrefixGroupRequired {
+            template: String,
+        },
+        PrefixGroupNotFound {
+            prefix: String,
+        },
     }
 
     // Enum representing possible errors in the `workspace-detail` crate.
@@ -369,6 +540,12 @@ error_tree!{
         },
         GitError(GitError),
         CrateError(CrateError),
+        WorkspaceSettingsError(WorkspaceSettingsError),
+        ItemRelocationError(ItemRelocationError),
+        PrefixGroupError(PrefixGroupError),
+        AuditError(AuditError),
+        MsrvError(MsrvError),
+        CrateTemplateError(CrateTemplateError),
         CratePinFailed {
             crate_path: PathBuf,
             source:     Box<CrateError>,
@@ -418,6 +595,12 @@ error_tree!{
         FileError(FileError),
         DirectoryError(DirectoryError),
         WorkspaceNotReadyForCargoPublish,
+        InvalidSymbolQuery {
+            symbol: String,
+        },
+        JsonOutputError {
+            message: String,
+        },
         FileWatchError,
         TestTimeout,
         FileFilterError,
@@ -7,11 +7,14 @@ pub use workspacer_register::*;
 pub use workspacer_register_internal_crate_in_prefix_group::*;
 pub use workspacer_add_internal_dep::*;
 pub use workspacer_analysis::*;
+pub use workspacer_audit::*;
 pub use workspacer_bump::*;
+pub use workspacer_changelog::*;
 pub use workspacer_tree::*;
 pub use workspacer_format_imports::*;
 pub use workspacer_lock::*;
 pub use workspacer_cleanup::*;
+pub use workspacer_config::*;
 pub use workspacer_consolidate::*;
 pub use workspacer_crate::*;
 pub use workspacer_detect_circular_deps::*;
@@ -20,10 +23,14 @@ pub use workspacer_git::*;
 pub use workspacer_interface::*;
 pub use workspacer_linting::*;
 pub use workspacer_metadata::*;
+pub use workspacer_move_item::*;
+pub use workspacer_msrv::*;
 pub use workspacer_name_all_files::*;
 pub use workspacer_pin::*;
+pub use workspacer_prefix_group::*;
 pub use workspacer_publish::*;
 pub use workspacer_rebuild_or_test::*;
+pub use workspacer_symbol_index::*;
 pub use workspacer_syntax::*;
 pub use workspacer_test_coverage::*;
 pub use workspacer_toml::*;
@@ -76,15 +76,8 @@ mod test_ensure_git_clean_for_workspace {
         let tmp_dir = tempdir().expect("failed to create temp dir");
         let path = tmp_dir.path();
 
-        // 2) Initialize a git repo
-        //    We'll skip if `git` is not installed, or just let it fail with "Failed to spawn"
-        run_in_dir(path, "git", &["init", "."]).await.expect("git init should succeed");
-        // 3) Create a file
-        let file_path = path.join("hello.txt");
-        tokio::fs::write(&file_path, b"hello").await.expect("write file");
-        // 4) git add + commit
-        run_in_dir(path, "git", &["add", "."]).await.expect("git add .");
-        run_in_dir(path, "git", &["commit", "-m", "Initial commit"]).await.expect("git commit");
+        // 2) Initialize a git repo whose one commit holds a file
+        init_repo(path, &[("hello.txt", "hello")]).await;
 
         // 5) Build or mock your workspace that references `path`.
         //    For demonstration, let's define a small struct implementing `AsRef<Path>`.
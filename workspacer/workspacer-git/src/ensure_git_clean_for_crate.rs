@@ -5,31 +5,65 @@ crate::ix!();
 impl EnsureGitClean for CrateHandle {
     type Error = GitError;
 
+    /// Only changes under the crate's own directory count; the rest of the repository may be dirty.
     async fn ensure_git_clean(&self) -> Result<(), Self::Error> {
         info!("Ensuring Git is clean (single crate) at {:?}", self.as_ref());
-        // If you want the same logic as workspace, you can copy-paste. 
-        // Or `todo!()` if you prefer. 
-        let output = Command::new("git")
-            .args(["status", "--porcelain"])
-            .current_dir(self.as_ref())  // important: run in crate's directory
-            .output()
-            .await
-            .map_err(|e| GitError::IoError { 
-                io: Arc::new(e),
-                context: format!("could not run git status --porcelain in current directory: {:?}", self.as_ref()),
-            })?;
-
-        if !output.status.success() {
-            return Err(GitError::FailedToRunGitStatusMakeSureGitIsInstalled);
-        }
-        let stdout_str = String::from_utf8_lossy(&output.stdout);
-        if !stdout_str.trim().is_empty() {
+        let dirty = git_status(self.as_ref(), /*whole_repo=*/false).await?;
+        if !dirty.is_empty() {
+            debug!("{} uncommitted paths under {:?}", dirty.len(), self.as_ref());
             return Err(GitError::WorkingDirectoryIsNotCleanAborting);
         }
         Ok(())
     }
 }
 
+/// Per-crate cleanliness, for operations that only rewrite some members.
+#[async_trait]
+pub trait EnsureCratesGitClean {
+    /// Names of the member crates with uncommitted changes under their directory.
+    async fn dirty_crates(&self) -> Result<Vec<String>, GitError>;
+
+    /// Fails with the dirty ones among `crate_names`, however dirty the rest of the tree is.
+    async fn ensure_git_clean_for_crates(&self, crate_names: &[String]) -> Result<(), GitError>;
+}
+
+#[async_trait]
+impl<P,H> EnsureCratesGitClean for Workspace<P,H>
+where
+    for<'async_trait> P: From<PathBuf> + AsRef<Path> + Clone + Send + Sync + 'async_trait,
+    H: CrateHandleInterface<P> + Send + Sync,
+{
+    async fn dirty_crates(&self) -> Result<Vec<String>, GitError> {
+        let dirty = git_status(self.as_ref(), /*whole_repo=*/false).await?;
+
+        let mut names = vec![];
+        for crate_handle in self {
+            let guard = crate_handle.lock().await;
+            let root  = guard.root_dir_path_buf();
+            let root  = std::fs::canonicalize(&root).unwrap_or(root);
+            if dirty.iter().any(|entry| entry.path().starts_with(&root)) {
+                names.push(guard.name().to_string());
+            }
+        }
+        names.sort();
+        Ok(names)
+    }
+
+    async fn ensure_git_clean_for_crates(&self, crate_names: &[String]) -> Result<(), GitError> {
+        let crates: Vec<String> = self
+            .dirty_crates()
+            .await?
+            .into_iter()
+            .filter(|name| crate_names.contains(name))
+            .collect();
+
+        match crates.is_empty() {
+            true  => Ok(()),
+            false => Err(GitError::CratesNotClean { crates }),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test_ensure_git_clean_for_crate_handle {
     use super::*;
@@ -66,6 +100,8 @@ mod test_ensure_git_clean_for_crate_handle {
         f.write_all(cargo_toml.as_bytes())
             .await
             .expect("Failed to write Cargo.toml");
+        // tokio finishes the write in the background unless flushed
+        f.flush().await.expect("Failed to flush Cargo.toml");
     }
 
     /// Helper to run a command in the given directory, returning Ok(()) if exit code == 0.
@@ -130,6 +166,8 @@ mod test_ensure_git_clean_for_crate_handle {
         // Write Cargo.toml, init, commit, etc.
         write_minimal_cargo_toml(tmp_dir.path()).await;
         run_in_dir("git", &["init"], tmp_dir.path()).await.unwrap();
+        run_in_dir("git", &["config", "user.email", "t@example.com"], tmp_dir.path()).await.unwrap();
+        run_in_dir("git", &["config", "user.name", "t"], tmp_dir.path()).await.unwrap();
         run_in_dir("git", &["add", "."], tmp_dir.path()).await.unwrap();
         run_in_dir("git", &["commit", "-m", "Initial commit"], tmp_dir.path()).await.unwrap();
 
@@ -207,4 +245,24 @@ mod test_ensure_git_clean_for_crate_handle {
             "Expected WorkingDirectoryIsNotCleanAborting, got: {result:?}"
         );
     }
+
+    #[tokio::test]
+    async fn test_ensure_git_clean_ignores_changes_outside_the_crate() {
+        let (temp_crate_dir, repo_path) = setup_git_repo_for_crate().await;
+        let crate_dir = repo_path.join("member");
+        write_minimal_cargo_toml(&crate_dir).await;
+        run_in_dir("git", &["add", "."], &repo_path).await.expect("git add");
+        run_in_dir("git", &["commit", "-q", "-m", "add member"], &repo_path).await.expect("git commit");
+
+        // a change next to the crate, not in it
+        tokio::fs::write(repo_path.join("unrelated.txt"), "dirty").await.unwrap();
+
+        let handle = CrateHandle::new(&crate_dir)
+            .await
+            .expect("Failed to create CrateHandle");
+        handle.ensure_git_clean().await.expect("changes outside the crate do not count");
+
+        tokio::fs::write(crate_dir.join("notes.txt"), "dirty").await.unwrap();
+        assert!(matches!(handle.ensure_git_clean().await, Err(GitError::WorkingDirectoryIsNotCleanAborting)));
+    }
 }
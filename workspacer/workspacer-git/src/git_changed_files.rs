@@ -0,0 +1,67 @@
+// ---------------- [ File: workspacer-git/src/git_changed_files.rs ]
+crate::ix!();
+
+/// Lists the files changed in a git diff range, as absolute paths.
+///
+/// `range` is anything `git diff` accepts: `main..HEAD` or `HEAD~3...HEAD` compare two commits,
+/// while a single ref (`main`) compares it against the working tree. For a single ref, untracked
+/// files are included too, since they are just as much "changed" for a rebuild.
+pub async fn git_changed_files(dir: &Path, range: &str) -> Result<Vec<PathBuf>, GitError> {
+    let diff_failed = |e| match e {
+        GitError::CommandFailed { stderr, .. } => GitError::DiffFailed { range: range.to_string(), stderr },
+        other                                  => other,
+    };
+
+    let repo_root = git_repo_root(dir).await.map_err(diff_failed)?;
+
+    let diff = run_git(&repo_root, &["diff", "--name-only", "--no-renames", range, "--"]).await.map_err(diff_failed)?;
+    let mut files = parse_name_only_output(&repo_root, &diff);
+
+    if !range.contains("..") {
+        let untracked = run_git(&repo_root, &["ls-files", "--others", "--exclude-standard"]).await.map_err(diff_failed)?;
+        files.extend(parse_name_only_output(&repo_root, &untracked));
+    }
+
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+fn parse_name_only_output(repo_root: &Path, stdout: &str) -> Vec<PathBuf> {
+    stdout
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(|l| repo_root.join(l))
+        .collect()
+}
+
+#[cfg(test)]
+mod test_git_changed_files {
+    use super::*;
+
+    #[tokio::test]
+    async fn lists_committed_modified_and_untracked_files() {
+        let tmp  = tempdir().expect("tempdir");
+        let root = tokio::fs::canonicalize(tmp.path()).await.unwrap();
+
+        init_repo(&root, &[("a/src/lib.rs", "fn a() {}"), ("README.md", "hi")]).await;
+
+        tokio::fs::write(root.join("a/src/lib.rs"), "fn a() { }").await.unwrap();
+        git(&root, &["commit", "-q", "-am", "two"]).await;
+
+        let range = git_changed_files(&root, "HEAD~1..HEAD").await.unwrap();
+        assert_eq!(range, vec![root.join("a/src/lib.rs")]);
+
+        tokio::fs::write(root.join("new.rs"), "").await.unwrap();
+        tokio::fs::write(root.join("README.md"), "changed").await.unwrap();
+        let working = git_changed_files(&root, "HEAD").await.unwrap();
+        assert_eq!(working, vec![root.join("README.md"), root.join("new.rs")]);
+    }
+
+    #[test]
+    fn name_only_lines_are_joined_to_the_repo_root() {
+        let files = parse_name_only_output(Path::new("/w"), "a/src/lib.rs\n\nCargo.lock\n");
+        assert_eq!(files, vec![PathBuf::from("/w/a/src/lib.rs"), PathBuf::from("/w/Cargo.lock")]);
+    }
+}
@@ -0,0 +1,40 @@
+// ---------------- [ File: workspacer-git/src/git_command.rs ]
+crate::ix!();
+
+/// Runs `git <args>` in `dir`; a non-zero exit becomes `GitError::CommandFailed` with git's stderr.
+pub async fn run_git(dir: &Path, args: &[&str]) -> Result<String, GitError> {
+    let stdout = run_git_bytes(dir, args).await?;
+    Ok(String::from_utf8_lossy(&stdout).to_string())
+}
+
+/// `run_git` for output that need not be UTF-8, such as file contents from `git show`.
+pub async fn run_git_bytes(dir: &Path, args: &[&str]) -> Result<Vec<u8>, GitError> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .await
+        .map_err(|e| GitError::IoError {
+            io:      Arc::new(e),
+            context: format!("could not run git {:?} in {:?}", args, dir),
+        })?;
+
+    if !output.status.success() {
+        return Err(GitError::CommandFailed {
+            args:   args.join(" "),
+            stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+        });
+    }
+
+    Ok(output.stdout)
+}
+
+/// The top of the repository holding `dir`.
+pub async fn git_repo_root(dir: &Path) -> Result<PathBuf, GitError> {
+    let toplevel = run_git(dir, &["rev-parse", "--show-toplevel"]).await?;
+    Ok(PathBuf::from(toplevel.trim()))
+}
+
+pub async fn git_head(dir: &Path) -> Result<String, GitError> {
+    Ok(run_git(dir, &["rev-parse", "HEAD"]).await?.trim().to_string())
+}
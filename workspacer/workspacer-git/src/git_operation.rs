@@ -0,0 +1,164 @@
+// ---------------- [ File: workspacer-git/src/git_operation.rs ]
+crate::ix!();
+
+/// Trailer marking a commit made by a `--commit-each` operation; its value is the operation.
+pub const WORKSPACER_OPERATION_TRAILER: &str = "Workspacer-Operation";
+
+/// Trailer on the commit undoing an operation; its value is the reverted commit.
+pub const WORKSPACER_REVERTS_TRAILER: &str = "Workspacer-Reverts";
+
+/// What a mutating command does about uncommitted work and about its own changes.
+#[derive(Getters,Setters,Copy,Clone,Default,Debug,PartialEq,Eq)]
+#[getset(get="pub", set="pub")]
+pub struct GitOperationPolicy {
+    /// Stash uncommitted changes first and restore them afterwards, instead of refusing to run
+    stash:       bool,
+
+    /// Commit the command's changes on their own, with a generated message
+    commit_each: bool,
+}
+
+/// One mutating command, bracketed by [`GitOperation::begin`] and [`GitOperation::finish`]
+/// (or [`GitOperation::abandon`] when it fails). Everything under `dir` belongs to it.
+#[derive(Getters,Debug)]
+#[getset(get="pub")]
+pub struct GitOperation {
+    dir:    PathBuf,
+    label:  String,
+    policy: GitOperationPolicy,
+    stash:  Option<GitStash>,
+}
+
+impl GitOperation {
+
+    /// Without `stash`, uncommitted changes under `dir` are an error as they always were.
+    pub async fn begin(dir: &Path, label: &str, policy: GitOperationPolicy) -> Result<Self, GitError> {
+        let stash = if *policy.stash() {
+            GitStash::push_if_dirty(dir, &format!("workspacer: before `{}`", label)).await?
+        } else {
+            if !git_status(dir, /*whole_repo=*/false).await?.is_empty() {
+                return Err(GitError::WorkingDirectoryIsNotCleanAborting);
+            }
+            None
+        };
+
+        Ok(Self { dir: dir.to_path_buf(), label: label.to_string(), policy, stash })
+    }
+
+    /// Commits the operation's changes (with `commit_each`), then restores stashed work.
+    /// Returns the new commit, if one was made.
+    pub async fn finish(self) -> Result<Option<String>, GitError> {
+        let commit = match *self.policy.commit_each() {
+            true  => self.commit_changes().await?,
+            false => None,
+        };
+        if let Some(stash) = self.stash {
+            stash.pop().await?;
+        }
+        Ok(commit)
+    }
+
+    /// The command failed: leave its partial changes for inspection and try to put stashed
+    /// work back. A stash that does not apply cleanly is kept and reported, not dropped.
+    pub async fn abandon(self) {
+        if let Some(stash) = self.stash {
+            if let Err(e) = stash.pop().await {
+                warn!("could not restore stashed changes after `{}` failed: {:?}", self.label, e);
+            }
+        }
+    }
+
+    async fn commit_changes(&self) -> Result<Option<String>, GitError> {
+        run_git(&self.dir, &["add", "--all", "--", "."]).await?;
+
+        let staged = run_git(&self.dir, &["diff", "--cached", "--name-only", "--relative"]).await?;
+        let files: Vec<&str> = staged.lines().map(str::trim).filter(|l| !l.is_empty()).collect();
+        if files.is_empty() {
+            debug!("`{}` changed nothing; no commit", self.label);
+            return Ok(None);
+        }
+
+        let message = operation_commit_message(&self.label, &files);
+        // `-- .` leaves anything staged outside the operation's directory alone
+        run_git(&self.dir, &["commit", "-q", "-m", &message, "--", "."]).await?;
+
+        let head = git_head(&self.dir).await?;
+        info!("committed `{}` as {}", self.label, head);
+        Ok(Some(head))
+    }
+}
+
+/// `ws <label>`, the top-level directories touched, and the operation trailer.
+pub fn operation_commit_message(label: &str, files: &[&str]) -> String {
+    let mut touched: Vec<&str> = files
+        .iter()
+        .map(|f| match f.split_once('/') {
+            Some((dir, _)) => dir,
+            None           => ".",
+        })
+        .collect();
+    touched.sort();
+    touched.dedup();
+
+    let plural = if files.len() == 1 { "" } else { "s" };
+    format!(
+        "ws {}\n\n{} file{} changed in {}\n\n{}: {}\n",
+        label,
+        files.len(),
+        plural,
+        touched.join(", "),
+        WORKSPACER_OPERATION_TRAILER,
+        label,
+    )
+}
+
+#[cfg(test)]
+mod test_git_operation {
+    use super::*;
+
+    #[test]
+    fn commit_messages_name_the_touched_directories() {
+        let message = operation_commit_message("format imports", &["a/src/lib.rs", "b/src/imports.rs", "Cargo.toml"]);
+        assert_eq!(message, "ws format imports\n\n3 files changed in ., a, b\n\nWorkspacer-Operation: format imports\n");
+    }
+
+    #[tokio::test]
+    async fn refuses_a_dirty_tree_unless_stashing() {
+        let tmp  = tempdir().expect("tempdir");
+        let root = tmp.path();
+        init_repo(root, &[("a/src/lib.rs", "fn a() {}\n"), ("notes.txt", "one\n")]).await;
+        tokio::fs::write(root.join("notes.txt"), "mine\n").await.unwrap();
+
+        let refused = GitOperation::begin(root, "format imports", GitOperationPolicy::default()).await;
+        assert!(matches!(refused, Err(GitError::WorkingDirectoryIsNotCleanAborting)));
+
+        let mut policy = GitOperationPolicy::default();
+        policy.set_stash(true);
+        let op = GitOperation::begin(root, "format imports", policy).await.unwrap();
+        assert_eq!(tokio::fs::read_to_string(root.join("notes.txt")).await.unwrap(), "one\n");
+
+        op.abandon().await;
+        assert_eq!(tokio::fs::read_to_string(root.join("notes.txt")).await.unwrap(), "mine\n");
+    }
+
+    #[tokio::test]
+    async fn commit_each_commits_only_the_operation_and_restores_stashed_work() {
+        let tmp  = tempdir().expect("tempdir");
+        let root = tmp.path();
+        init_repo(root, &[("a/src/lib.rs", "fn a() {}\n"), ("notes.txt", "one\n")]).await;
+        tokio::fs::write(root.join("notes.txt"), "mine\n").await.unwrap();
+
+        let mut policy = GitOperationPolicy::default();
+        policy.set_stash(true);
+        policy.set_commit_each(true);
+        let op = GitOperation::begin(root, "format imports", policy).await.unwrap();
+
+        tokio::fs::write(root.join("a/src/lib.rs"), "fn a() { }\n").await.unwrap();
+        let commit = op.finish().await.unwrap().expect("a commit for the changes");
+
+        let shown = git(root, &["show", "--name-only", "--format=%B", &commit]).await;
+        assert!(shown.contains("Workspacer-Operation: format imports"));
+        assert!(shown.contains("a/src/lib.rs") && !shown.contains("notes.txt"));
+        assert_eq!(git(root, &["status", "--porcelain"]).await, " M notes.txt\n");
+    }
+}
@@ -0,0 +1,83 @@
+// ---------------- [ File: workspacer-git/src/git_stash.rs ]
+crate::ix!();
+
+/// Uncommitted work under a directory, set aside with `git stash` while workspacer rewrites
+/// files there. Untracked files go too, and [`GitStash::pop`] restores the index as it was,
+/// so partially staged changes stay partially staged.
+#[derive(Getters,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct GitStash {
+    dir:    PathBuf,
+    /// The stash commit; its position in `git stash list` moves as other stashes come and go
+    commit: String,
+}
+
+impl GitStash {
+
+    /// Stashes changes under `dir`, or returns `None` when there is nothing to set aside.
+    pub async fn push_if_dirty(dir: &Path, message: &str) -> Result<Option<Self>, GitError> {
+        if git_status(dir, /*whole_repo=*/false).await?.is_empty() {
+            return Ok(None);
+        }
+
+        run_git(dir, &["stash", "push", "--include-untracked", "-m", message, "--", "."]).await?;
+        let commit = run_git(dir, &["rev-parse", "stash@{0}"]).await?.trim().to_string();
+        info!("stashed uncommitted changes under {:?} as {}", dir, commit);
+
+        Ok(Some(Self { dir: dir.to_path_buf(), commit }))
+    }
+
+    /// Applies the stash back onto the working tree and drops it. On a conflict the stash is
+    /// kept, so nothing is lost; `GitError::StashPopFailed` names it.
+    pub async fn pop(self) -> Result<(), GitError> {
+        let listed = run_git(&self.dir, &["stash", "list", "--format=%H"]).await?;
+        let index  = listed
+            .lines()
+            .position(|sha| sha.trim() == self.commit)
+            .ok_or_else(|| GitError::StashPopFailed {
+                stash:  self.commit.clone(),
+                stderr: "the stash is no longer in `git stash list`".to_string(),
+            })?;
+
+        let stash_ref = format!("stash@{{{}}}", index);
+        run_git(&self.dir, &["stash", "pop", "--index", &stash_ref]).await.map_err(|e| match e {
+            GitError::CommandFailed { stderr, .. } => GitError::StashPopFailed { stash: self.commit.clone(), stderr },
+            other => other,
+        })?;
+
+        info!("restored stashed changes {} under {:?}", self.commit, self.dir);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_git_stash {
+    use super::*;
+
+    #[tokio::test]
+    async fn clean_trees_are_not_stashed() {
+        let tmp = tempdir().expect("tempdir");
+        init_repo(tmp.path(), &[("a.txt", "one\n"), ("b.txt", "one\n")]).await;
+        assert!(GitStash::push_if_dirty(tmp.path(), "test").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn pop_restores_partial_staging_and_untracked_files() {
+        let tmp  = tempdir().expect("tempdir");
+        let root = tmp.path();
+        init_repo(root, &[("a.txt", "one\n"), ("b.txt", "one\n")]).await;
+
+        tokio::fs::write(root.join("a.txt"), "staged\n").await.unwrap();
+        git(root, &["add", "a.txt"]).await;
+        tokio::fs::write(root.join("b.txt"), "unstaged\n").await.unwrap();
+        tokio::fs::write(root.join("c.txt"), "untracked\n").await.unwrap();
+        let before = git(root, &["status", "--porcelain"]).await;
+
+        let stash = GitStash::push_if_dirty(root, "test").await.unwrap().expect("dirty tree is stashed");
+        assert!(git(root, &["status", "--porcelain"]).await.is_empty());
+
+        stash.pop().await.unwrap();
+        assert_eq!(git(root, &["status", "--porcelain"]).await, before);
+        assert!(git(root, &["stash", "list"]).await.is_empty());
+    }
+}
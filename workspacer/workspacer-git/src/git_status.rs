@@ -0,0 +1,85 @@
+// ---------------- [ File: workspacer-git/src/git_status.rs ]
+crate::ix!();
+
+/// One line of `git status --porcelain`: the two-letter `XY` code (index, then worktree)
+/// and the absolute path. For a rename or copy, `path` is the new name.
+#[derive(Getters,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct GitStatusEntry {
+    code: String,
+    path: PathBuf,
+}
+
+impl GitStatusEntry {
+
+    pub fn is_untracked(&self) -> bool {
+        self.code == "??"
+    }
+
+    /// Something is staged for this path (the `X` half of the code).
+    pub fn is_staged(&self) -> bool {
+        !matches!(self.code.chars().next(), Some(' ') | Some('?') | Some('!') | None)
+    }
+}
+
+/// Parses `git status --porcelain -z`. Paths are relative to the repository root there,
+/// whatever directory git ran in; a rename carries its old name as an extra field.
+pub fn parse_porcelain_z(repo_root: &Path, stdout: &str) -> Vec<GitStatusEntry> {
+    let mut entries = vec![];
+    let mut fields  = stdout.split('\0').filter(|f| !f.is_empty());
+
+    while let Some(field) = fields.next() {
+        if field.len() < 4 {
+            continue;
+        }
+        let (code, path) = field.split_at(2);
+        if code.contains('R') || code.contains('C') {
+            fields.next();
+        }
+        entries.push(GitStatusEntry {
+            code: code.to_string(),
+            path: repo_root.join(&path[1..]),
+        });
+    }
+    entries
+}
+
+/// Uncommitted changes (untracked files included) under `dir`, or in the whole repository
+/// when `whole_repo` is set. Outside a repository this fails the way `EnsureGitClean` does.
+pub async fn git_status(dir: &Path, whole_repo: bool) -> Result<Vec<GitStatusEntry>, GitError> {
+    let status_failed = |e| match e {
+        GitError::CommandFailed { .. } => GitError::FailedToRunGitStatusMakeSureGitIsInstalled,
+        other                          => other,
+    };
+
+    let repo_root = git_repo_root(dir).await.map_err(status_failed)?;
+    let mut args  = vec!["status", "--porcelain", "-z", "--untracked-files=all"];
+    if !whole_repo {
+        args.extend(["--", "."]);
+    }
+    let stdout = run_git(dir, &args).await.map_err(status_failed)?;
+    Ok(parse_porcelain_z(&repo_root, &stdout))
+}
+
+#[cfg(test)]
+mod test_git_status {
+    use super::*;
+
+    #[test]
+    fn parses_modified_untracked_staged_and_renamed_entries() {
+        let out = " M a/src/lib.rs\0?? new file.rs\0R  b/new.rs\0b/old.rs\0MM c/Cargo.toml\0";
+        let entries = parse_porcelain_z(Path::new("/w"), out);
+
+        let paths: Vec<_> = entries.iter().map(|e| e.path().clone()).collect();
+        assert_eq!(paths, vec![
+            PathBuf::from("/w/a/src/lib.rs"),
+            PathBuf::from("/w/new file.rs"),
+            PathBuf::from("/w/b/new.rs"),
+            PathBuf::from("/w/c/Cargo.toml"),
+        ]);
+        assert!(!entries[0].is_staged());
+        assert!(entries[1].is_untracked() && !entries[1].is_staged());
+        assert!(entries[2].is_staged());
+        assert!(entries[3].is_staged());
+    }
+}
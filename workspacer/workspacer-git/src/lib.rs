@@ -3,3 +3,11 @@
 
 x!{ensure_git_clean}
 x!{ensure_git_clean_for_crate}
+x!{git_changed_files}
+x!{git_command}
+x!{git_status}
+x!{git_stash}
+x!{git_operation}
+x!{revert_workspacer_operation}
+
+#[cfg(test)] x!{test_git_repo}
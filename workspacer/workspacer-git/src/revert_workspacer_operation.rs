@@ -0,0 +1,135 @@
+// ---------------- [ File: workspacer-git/src/revert_workspacer_operation.rs ]
+crate::ix!();
+
+/// The commit `ws git undo` made.
+#[derive(Getters,Debug,Clone,PartialEq,Eq,Serialize)]
+#[getset(get="pub")]
+pub struct RevertedOperation {
+    label:         String,
+    reverted:      String,
+    revert_commit: String,
+}
+
+impl fmt::Display for RevertedOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "reverted `ws {}` ({}) in {}", self.label, self.reverted, self.revert_commit)
+    }
+}
+
+/// A commit carrying one of the workspacer trailers, as read from `git log`.
+#[derive(Debug,Clone,PartialEq,Eq)]
+struct OperationCommit {
+    sha:       String,
+    operation: Option<String>,
+    reverts:   Option<String>,
+}
+
+fn trailer<'a>(body: &'a str, key: &str) -> Option<&'a str> {
+    body.lines()
+        .filter_map(|line| line.trim().strip_prefix(key)?.strip_prefix(':'))
+        .map(str::trim)
+        .last()
+}
+
+/// Records are `sha 0x1f body 0x1e`, newest first.
+fn parse_operation_log(stdout: &str) -> Vec<OperationCommit> {
+    stdout
+        .split('\x1e')
+        .filter_map(|record| {
+            let (sha, body) = record.trim().split_once('\x1f')?;
+            Some(OperationCommit {
+                sha:       sha.trim().to_string(),
+                operation: trailer(body, WORKSPACER_OPERATION_TRAILER).map(str::to_string),
+                reverts:   trailer(body, WORKSPACER_REVERTS_TRAILER).map(str::to_string),
+            })
+        })
+        .collect()
+}
+
+/// The newest operation commit not already undone.
+fn last_unreverted_operation(log: &[OperationCommit]) -> Option<&OperationCommit> {
+    let mut reverted: HashSet<&str> = HashSet::new();
+    for commit in log {
+        if let Some(target) = &commit.reverts {
+            reverted.insert(target.as_str());
+        } else if commit.operation.is_some() && !reverted.contains(commit.sha.as_str()) {
+            return Some(commit);
+        }
+    }
+    None
+}
+
+/// Undoes the most recent `--commit-each` operation on the current branch with one revert
+/// commit. Reverted operations are skipped, so repeated calls walk further back. The
+/// working tree must be clean; a conflicting revert is aborted and reported.
+pub async fn revert_last_workspacer_operation(dir: &Path) -> Result<RevertedOperation, GitError> {
+    if !git_status(dir, /*whole_repo=*/true).await?.is_empty() {
+        return Err(GitError::WorkingDirectoryIsNotCleanAborting);
+    }
+
+    let grep = format!("--grep=^({}|{}):", WORKSPACER_OPERATION_TRAILER, WORKSPACER_REVERTS_TRAILER);
+    let log  = run_git(dir, &["log", "-E", &grep, "--format=%H%x1f%B%x1e"]).await?;
+    let log  = parse_operation_log(&log);
+
+    let target = last_unreverted_operation(&log).ok_or(GitError::NoWorkspacerOperationToRevert)?;
+    let label  = target.operation.clone().unwrap_or_default();
+
+    if let Err(e) = run_git(dir, &["revert", "--no-commit", &target.sha]).await {
+        let _ = run_git(dir, &["revert", "--abort"]).await;
+        return Err(match e {
+            GitError::CommandFailed { stderr, .. } => GitError::RevertFailed { commit: target.sha.clone(), stderr },
+            other => other,
+        });
+    }
+
+    let message = format!("Revert `ws {}`\n\n{}: {}\n", label, WORKSPACER_REVERTS_TRAILER, target.sha);
+    run_git(dir, &["commit", "-q", "-m", &message]).await?;
+
+    Ok(RevertedOperation {
+        label,
+        reverted:      target.sha.clone(),
+        revert_commit: git_head(dir).await?,
+    })
+}
+
+#[cfg(test)]
+mod test_revert_workspacer_operation {
+    use super::*;
+
+    #[test]
+    fn reverted_operations_are_skipped() {
+        let log = parse_operation_log(
+            "c3\x1fRevert `ws b`\n\nWorkspacer-Reverts: c2\n\x1e\n\
+             c2\x1fws b\n\nWorkspacer-Operation: b\n\x1e\n\
+             c1\x1fws a\n\nWorkspacer-Operation: a\n\x1e\n"
+        );
+        assert_eq!(log.len(), 3);
+        assert_eq!(last_unreverted_operation(&log).map(|c| c.sha.as_str()), Some("c1"));
+        assert_eq!(last_unreverted_operation(&log[2..]).and_then(|c| c.operation.as_deref()), Some("a"));
+        assert_eq!(last_unreverted_operation(&log[..1]), None);
+    }
+
+    #[tokio::test]
+    async fn undo_reverts_operations_newest_first() {
+        let tmp  = tempdir().expect("tempdir");
+        let root = tmp.path();
+        init_repo(root, &[("lib.rs", "v0\n")]).await;
+
+        let mut policy = GitOperationPolicy::default();
+        policy.set_commit_each(true);
+        for version in ["v1\n", "v2\n"] {
+            let op = GitOperation::begin(root, "rewrite", policy).await.unwrap();
+            tokio::fs::write(root.join("lib.rs"), version).await.unwrap();
+            op.finish().await.unwrap();
+        }
+
+        revert_last_workspacer_operation(root).await.unwrap();
+        assert_eq!(tokio::fs::read_to_string(root.join("lib.rs")).await.unwrap(), "v1\n");
+
+        let second = revert_last_workspacer_operation(root).await.unwrap();
+        assert_eq!(second.label(), "rewrite");
+        assert_eq!(tokio::fs::read_to_string(root.join("lib.rs")).await.unwrap(), "v0\n");
+
+        assert!(matches!(revert_last_workspacer_operation(root).await, Err(GitError::NoWorkspacerOperationToRevert)));
+    }
+}
@@ -0,0 +1,25 @@
+// ---------------- [ File: workspacer-git/src/test_git_repo.rs ]
+crate::ix!();
+
+/// Runs `git args..` in `dir` for a test, panicking on failure; returns stdout.
+pub(crate) async fn git(dir: &Path, args: &[&str]) -> String {
+    let out = Command::new("git").args(args).current_dir(dir).output().await.expect("spawn git");
+    assert!(out.status.success(), "git {:?} failed: {}", args, String::from_utf8_lossy(&out.stderr));
+    String::from_utf8_lossy(&out.stdout).to_string()
+}
+
+/// Makes `root` a repository whose one commit holds `files` (`(path, contents)` pairs).
+pub(crate) async fn init_repo(root: &Path, files: &[(&str, &str)]) {
+    git(root, &["init", "-q", "."]).await;
+    git(root, &["config", "user.email", "t@example.com"]).await;
+    git(root, &["config", "user.name", "t"]).await;
+    for (path, contents) in files {
+        let path = root.join(path);
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await.unwrap();
+        }
+        tokio::fs::write(path, contents).await.unwrap();
+    }
+    git(root, &["add", "."]).await;
+    git(root, &["commit", "-q", "-m", "init"]).await;
+}
@@ -0,0 +1,102 @@
+// ---------------- [ File: workspacer-linting/src/fix_lints.rs ]
+crate::ix!();
+
+/// What happened when `cargo clippy --fix` ran for one crate.
+#[derive(Getters,Debug,Clone)]
+#[getset(get="pub")]
+pub struct LintFixOutcome {
+    crate_name: String,
+    success:    bool,
+    stderr:     String,
+}
+
+/// Applies clippy's machine-applicable suggestions.
+///
+/// For a workspace this goes crate by crate in topological order (dependencies first), so a
+/// fix that changes a public signature is in place before any dependent is rewritten, and
+/// stops at the first crate whose fix fails.
+#[async_trait]
+pub trait FixLints {
+    type Error;
+    async fn fix_lints(&self) -> Result<Vec<LintFixOutcome>, Self::Error>;
+}
+
+#[async_trait]
+impl FixLints for CrateHandle {
+    type Error = LintingError;
+
+    async fn fix_lints(&self) -> Result<Vec<LintFixOutcome>, Self::Error> {
+        let crate_name    = self.name().to_string();
+        let manifest_path = self.crate_path().join("Cargo.toml");
+
+        info!("applying machine-applicable clippy fixes to crate='{}'", crate_name);
+
+        // `cargo fix` only ever applies `MachineApplicable` suggestions
+        let output = tokio::process::Command::new("cargo")
+            .arg("clippy")
+            .arg("--fix")
+            .arg("--allow-dirty")
+            .arg("--allow-staged")
+            .arg("--no-deps")
+            .arg("--all-targets")
+            .arg("--manifest-path")
+            .arg(&manifest_path)
+            .output()
+            .await
+            .map_err(|io_err| {
+                error!("Failed to spawn cargo clippy --fix for crate='{}': {io_err}", crate_name);
+                LintingError::CommandError { io: io_err.into() }
+            })?;
+
+        let outcome = LintFixOutcome {
+            crate_name,
+            success: output.status.success(),
+            stderr:  String::from_utf8_lossy(&output.stderr).to_string(),
+        };
+
+        if !outcome.success {
+            return Err(LintingError::FixFailed {
+                crate_name: outcome.crate_name,
+                stderr:     outcome.stderr,
+            });
+        }
+
+        Ok(vec![outcome])
+    }
+}
+
+#[async_trait]
+impl<P, H> FixLints for Workspace<P,H>
+where
+    for<'async_trait> P: From<PathBuf> + AsRef<Path> + Send + Sync + 'async_trait,
+    for<'async_trait> H: CrateHandleInterface<P> + FixLints<Error=LintingError> + Send + Sync + 'async_trait,
+    Self: BasicTopologicalSort,
+{
+    type Error = WorkspaceError;
+
+    async fn fix_lints(&self) -> Result<Vec<LintFixOutcome>, Self::Error> {
+        let members: HashSet<String> = self.get_all_crate_names().await.into_iter().collect();
+
+        let only_members = Arc::new(move |name: &str| members.contains(name))
+            as Arc<dyn Fn(&str) -> bool + Send + Sync>;
+
+        let config = TopologicalSortConfigBuilder::default()
+            .filter_fn(Some(only_members))
+            .build()
+            .expect("topological sort config has defaults for every other field");
+
+        let order = self.topological_order_crate_names(&config).await?;
+        debug!("fixing lints in topological order: {:?}", order);
+
+        let mut outcomes = Vec::new();
+        for crate_name in order {
+            let Some(handle) = self.find_crate_by_name(&crate_name).await else {
+                continue;
+            };
+            let guard = handle.lock().await;
+            outcomes.extend(guard.fix_lints().await?);
+        }
+
+        Ok(outcomes)
+    }
+}
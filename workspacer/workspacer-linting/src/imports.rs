@@ -4,3 +4,7 @@ pub(crate) use workspacer_crate::*;
 pub(crate) use workspacer_crate_interface::*;
 pub(crate) use workspacer_errors::*;
 pub(crate) use workspacer_workspace::*;
+pub(crate) use workspacer_workspace_interface::*;
+pub(crate) use workspacer_topo::*;
+pub(crate) use serde_derive::{Serialize,Deserialize};
+pub(crate) use workspacer_config::*;
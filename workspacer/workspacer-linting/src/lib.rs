@@ -1,5 +1,8 @@
 // ---------------- [ File: workspacer-linting/src/lib.rs ]
 #[macro_use] mod imports; use imports::*;
 
+x!{lint_baseline}
+x!{lint_diagnostic}
 x!{lint_report}
 x!{linting}
+x!{fix_lints}
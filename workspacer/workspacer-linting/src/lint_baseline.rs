@@ -0,0 +1,154 @@
+// ---------------- [ File: workspacer-linting/src/lint_baseline.rs ]
+crate::ix!();
+
+/// The file, kept next to each crate's Cargo.toml, listing warnings that are tolerated.
+pub const LINT_BASELINE_FILE_NAME: &str = "lint-baseline.json";
+
+/// One tolerated diagnostic. Line numbers are deliberately left out so that unrelated edits
+/// above a known warning do not turn it into a "new" one.
+#[derive(Serialize,Deserialize,Getters,Debug,Clone,PartialEq,Eq,PartialOrd,Ord,Hash)]
+#[getset(get="pub")]
+pub struct LintBaselineEntry {
+    lint_name: Option<String>,
+    file:      Option<PathBuf>,
+    message:   String,
+}
+
+impl From<&LintDiagnostic> for LintBaselineEntry {
+    fn from(d: &LintDiagnostic) -> Self {
+        Self {
+            lint_name: d.lint_name().clone(),
+            file:      d.span().as_ref().map(|s| s.file().clone()),
+            message:   d.message().clone(),
+        }
+    }
+}
+
+/// A committed list of known warnings for a single crate.
+#[derive(Serialize,Deserialize,Getters,Default,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct LintBaseline {
+    entries: Vec<LintBaselineEntry>,
+}
+
+impl LintBaseline {
+
+    /// Captures every failing diagnostic in `diagnostics` as tolerated.
+    pub fn from_diagnostics<'a>(diagnostics: impl IntoIterator<Item=&'a LintDiagnostic>) -> Self {
+        let mut entries: Vec<LintBaselineEntry> = diagnostics
+            .into_iter()
+            .filter(|d| d.level().is_failure())
+            .map(LintBaselineEntry::from)
+            .collect();
+        entries.sort();
+        Self { entries }
+    }
+
+    pub fn path_for_crate(crate_root: &Path) -> PathBuf {
+        crate_root.join(LINT_BASELINE_FILE_NAME)
+    }
+
+    /// Loads the baseline for a crate; a missing file is an empty baseline.
+    pub async fn load(crate_root: &Path) -> Result<Self, LintingError> {
+        let path = Self::path_for_crate(crate_root);
+
+        if !path.exists() {
+            trace!("no lint baseline at {:?}", path);
+            return Ok(Self::default());
+        }
+
+        let text = tokio::fs::read_to_string(&path)
+            .await
+            .map_err(|io| LintingError::BaselineIoError { path: path.clone(), io: io.into() })?;
+
+        serde_json::from_str(&text).map_err(|e| LintingError::BaselineParseError {
+            path,
+            message: e.to_string(),
+        })
+    }
+
+    pub async fn save(&self, crate_root: &Path) -> Result<(), LintingError> {
+        let path = Self::path_for_crate(crate_root);
+
+        let mut text = serde_json::to_string_pretty(self).map_err(|e| LintingError::BaselineParseError {
+            path: path.clone(),
+            message: e.to_string(),
+        })?;
+        text.push('\n');
+
+        tokio::fs::write(&path, text)
+            .await
+            .map_err(|io| LintingError::BaselineIoError { path, io: io.into() })
+    }
+
+    /// Splits `diagnostics` into (new, baselined).
+    ///
+    /// Matching is by multiset: if the baseline tolerates two identical warnings and three
+    /// now appear, one of them is new.
+    pub fn partition(&self, diagnostics: Vec<LintDiagnostic>) -> (Vec<LintDiagnostic>, Vec<LintDiagnostic>) {
+        let mut budget: HashMap<&LintBaselineEntry, usize> = HashMap::new();
+        for entry in &self.entries {
+            *budget.entry(entry).or_default() += 1;
+        }
+
+        let mut fresh     = Vec::new();
+        let mut baselined = Vec::new();
+
+        for diag in diagnostics {
+            let key = LintBaselineEntry::from(&diag);
+            match budget.get_mut(&key) {
+                Some(n) if *n > 0 => {
+                    *n -= 1;
+                    baselined.push(diag);
+                }
+                _ => fresh.push(diag),
+            }
+        }
+
+        (fresh, baselined)
+    }
+}
+
+#[cfg(test)]
+mod test_lint_baseline {
+    use super::*;
+
+    fn diag(lint: &str, file: &str, line: usize) -> LintDiagnostic {
+        let json = format!(
+            r#"{{"reason":"compiler-message","package_id":"demo 0.1.0 (path+file:///w)","message":{{"rendered":null,"code":{{"code":"{lint}"}},"level":"warning","message":"msg for {lint}","spans":[{{"file_name":"{file}","line_start":{line},"line_end":{line},"column_start":1,"column_end":2,"is_primary":true}}],"children":[]}}}}"#
+        );
+        parse_cargo_json_diagnostics(&json).pop().unwrap()
+    }
+
+    #[test]
+    fn known_warnings_survive_line_moves() {
+        let baseline = LintBaseline::from_diagnostics(&[diag("clippy::a", "src/lib.rs", 3)]);
+        let (fresh, old) = baseline.partition(vec![
+            diag("clippy::a", "src/lib.rs", 40),
+            diag("clippy::b", "src/lib.rs", 41),
+        ]);
+        assert_eq!(old.len(), 1);
+        assert_eq!(fresh.len(), 1);
+        assert_eq!(fresh[0].lint_name().as_deref(), Some("clippy::b"));
+    }
+
+    #[test]
+    fn duplicates_are_counted() {
+        let baseline = LintBaseline::from_diagnostics(&[diag("clippy::a", "src/lib.rs", 1)]);
+        let (fresh, old) = baseline.partition(vec![
+            diag("clippy::a", "src/lib.rs", 1),
+            diag("clippy::a", "src/lib.rs", 2),
+        ]);
+        assert_eq!((fresh.len(), old.len()), (1, 1));
+    }
+
+    #[tokio::test]
+    async fn round_trips_through_disk_and_missing_file_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(LintBaseline::load(dir.path()).await.unwrap(), LintBaseline::default());
+
+        let baseline = LintBaseline::from_diagnostics(&[diag("clippy::a", "src/lib.rs", 1)]);
+        baseline.save(dir.path()).await.unwrap();
+        assert_eq!(LintBaseline::load(dir.path()).await.unwrap(), baseline);
+    }
+}
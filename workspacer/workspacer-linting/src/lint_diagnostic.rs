@@ -0,0 +1,250 @@
+// ---------------- [ File: workspacer-linting/src/lint_diagnostic.rs ]
+crate::ix!();
+
+#[derive(Serialize,Deserialize,Debug,Clone,Copy,PartialEq,Eq,PartialOrd,Ord,Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum LintLevel {
+    Error,
+    Warning,
+    Note,
+    Help,
+    Other,
+}
+
+impl From<&str> for LintLevel {
+    fn from(s: &str) -> Self {
+        match s {
+            "error" | "error: internal compiler error" => LintLevel::Error,
+            "warning"                                  => LintLevel::Warning,
+            "note"                                     => LintLevel::Note,
+            "help"                                     => LintLevel::Help,
+            _                                          => LintLevel::Other,
+        }
+    }
+}
+
+impl LintLevel {
+
+    /// Warnings and errors are what make a lint run fail.
+    pub fn is_failure(&self) -> bool {
+        matches!(self, LintLevel::Error | LintLevel::Warning)
+    }
+}
+
+/// Location of the primary span of a diagnostic.
+#[derive(Serialize,Deserialize,Getters,Debug,Clone,PartialEq,Eq,Hash)]
+#[getset(get="pub")]
+pub struct LintSpan {
+    file:         PathBuf,
+    line_start:   usize,
+    line_end:     usize,
+    column_start: usize,
+    column_end:   usize,
+}
+
+/// A replacement offered by clippy or rustc for some span.
+#[derive(Serialize,Deserialize,Getters,Debug,Clone,PartialEq,Eq,Hash)]
+#[getset(get="pub")]
+pub struct LintSuggestion {
+    span:          LintSpan,
+    replacement:   String,
+
+    /// e.g. `MachineApplicable`, `MaybeIncorrect`, `HasPlaceholders`, `Unspecified`
+    applicability: String,
+}
+
+impl LintSuggestion {
+
+    /// Only these are safe for `cargo clippy --fix` to apply unattended.
+    pub fn is_machine_applicable(&self) -> bool {
+        self.applicability == "MachineApplicable"
+    }
+}
+
+/// One compiler/clippy diagnostic, as parsed from `cargo clippy --message-format=json`.
+#[derive(Serialize,Deserialize,Getters,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct LintDiagnostic {
+    crate_name:  String,
+
+    /// e.g. `clippy::needless_return` or `unused_variables`; `None` for uncoded errors
+    lint_name:   Option<String>,
+
+    level:       LintLevel,
+    message:     String,
+    span:        Option<LintSpan>,
+    suggestions: Vec<LintSuggestion>,
+
+    /// The human-readable text rustc would have printed
+    rendered:    Option<String>,
+}
+
+impl LintDiagnostic {
+
+    pub fn has_machine_applicable_suggestion(&self) -> bool {
+        self.suggestions.iter().any(|s| s.is_machine_applicable())
+    }
+}
+
+/// Parses the line-delimited JSON cargo writes to stdout with `--message-format=json`.
+///
+/// Non-diagnostic lines (`compiler-artifact`, `build-finished`, plain text) are skipped, as are
+/// the summary diagnostics without any span ("3 warnings emitted").
+pub fn parse_cargo_json_diagnostics(stdout: &str) -> Vec<LintDiagnostic> {
+    let mut out = Vec::new();
+
+    for line in stdout.lines() {
+        let line = line.trim();
+        if !line.starts_with('{') {
+            continue;
+        }
+
+        let Ok(value) = serde_json::from_str::<serde_json::Value>(line) else {
+            trace!("skipping unparsable cargo json line");
+            continue;
+        };
+
+        if value.get("reason").and_then(|r| r.as_str()) != Some("compiler-message") {
+            continue;
+        }
+
+        let crate_name = value
+            .get("package_id")
+            .and_then(|p| p.as_str())
+            .map(crate_name_from_package_id)
+            .unwrap_or_default();
+
+        let Some(message) = value.get("message") else { continue };
+
+        if let Some(diag) = diagnostic_from_message(&crate_name, message) {
+            out.push(diag);
+        }
+    }
+
+    out
+}
+
+fn diagnostic_from_message(crate_name: &str, message: &serde_json::Value) -> Option<LintDiagnostic> {
+    let spans = message.get("spans").and_then(|s| s.as_array())?;
+    let primary = spans
+        .iter()
+        .find(|s| s.get("is_primary").and_then(|p| p.as_bool()) == Some(true))
+        .or_else(|| spans.first())
+        .and_then(span_from_json)?;
+
+    let mut suggestions = Vec::new();
+    collect_suggestions(message, &mut suggestions);
+
+    Some(LintDiagnostic {
+        crate_name:  crate_name.to_string(),
+        lint_name:   message
+            .get("code")
+            .and_then(|c| c.get("code"))
+            .and_then(|c| c.as_str())
+            .map(str::to_string),
+        level:       LintLevel::from(message.get("level").and_then(|l| l.as_str()).unwrap_or("")),
+        message:     message.get("message").and_then(|m| m.as_str()).unwrap_or_default().to_string(),
+        span:        Some(primary),
+        suggestions,
+        rendered:    message.get("rendered").and_then(|r| r.as_str()).map(str::to_string),
+    })
+}
+
+fn collect_suggestions(message: &serde_json::Value, out: &mut Vec<LintSuggestion>) {
+    if let Some(spans) = message.get("spans").and_then(|s| s.as_array()) {
+        for span in spans {
+            let Some(replacement) = span.get("suggested_replacement").and_then(|r| r.as_str()) else {
+                continue;
+            };
+            let Some(location) = span_from_json(span) else { continue };
+            out.push(LintSuggestion {
+                span:          location,
+                replacement:   replacement.to_string(),
+                applicability: span
+                    .get("suggestion_applicability")
+                    .and_then(|a| a.as_str())
+                    .unwrap_or("Unspecified")
+                    .to_string(),
+            });
+        }
+    }
+
+    if let Some(children) = message.get("children").and_then(|c| c.as_array()) {
+        for child in children {
+            collect_suggestions(child, out);
+        }
+    }
+}
+
+fn span_from_json(span: &serde_json::Value) -> Option<LintSpan> {
+    let num = |k: &str| span.get(k).and_then(|v| v.as_u64()).map(|v| v as usize);
+    Some(LintSpan {
+        file:         PathBuf::from(span.get("file_name")?.as_str()?),
+        line_start:   num("line_start")?,
+        line_end:     num("line_end")?,
+        column_start: num("column_start")?,
+        column_end:   num("column_end")?,
+    })
+}
+
+/// Extracts the package name from either package-id format cargo has used:
+///
+/// - `my-crate 0.1.0 (path+file:///work/my-crate)`
+/// - `path+file:///work/dir#my-crate@0.1.0`
+/// - `path+file:///work/my-crate#0.1.0` (name omitted when it matches the directory)
+pub fn crate_name_from_package_id(package_id: &str) -> String {
+    if let Some((url, fragment)) = package_id.split_once('#') {
+        if let Some((name, _version)) = fragment.split_once('@') {
+            return name.to_string();
+        }
+        return url
+            .trim_end_matches('/')
+            .rsplit('/')
+            .next()
+            .unwrap_or(url)
+            .to_string();
+    }
+
+    package_id
+        .split_whitespace()
+        .next()
+        .unwrap_or(package_id)
+        .to_string()
+}
+
+#[cfg(test)]
+mod test_parse_cargo_json_diagnostics {
+    use super::*;
+
+    const SAMPLE: &str = r#"{"reason":"compiler-artifact","package_id":"path+file:///w/dep#0.1.0"}
+{"reason":"compiler-message","package_id":"path+file:///w#demo@0.1.0","message":{"rendered":"warning: unneeded `return` statement\n","code":{"code":"clippy::needless_return","explanation":null},"level":"warning","message":"unneeded `return` statement","spans":[{"file_name":"src/lib.rs","byte_start":10,"byte_end":20,"line_start":3,"line_end":3,"column_start":5,"column_end":15,"is_primary":true,"label":null,"suggested_replacement":null,"suggestion_applicability":null}],"children":[{"level":"help","message":"remove `return`","code":null,"rendered":null,"children":[],"spans":[{"file_name":"src/lib.rs","byte_start":10,"byte_end":20,"line_start":3,"line_end":3,"column_start":5,"column_end":15,"is_primary":true,"label":null,"suggested_replacement":"x","suggestion_applicability":"MachineApplicable"}]}]}}
+{"reason":"compiler-message","package_id":"demo 0.1.0 (path+file:///w)","message":{"rendered":"warning: 1 warning emitted\n","code":null,"level":"warning","message":"1 warning emitted","spans":[],"children":[]}}
+{"reason":"build-finished","success":true}
+"#;
+
+    #[test]
+    fn parses_diagnostics_and_skips_summaries() {
+        let diags = parse_cargo_json_diagnostics(SAMPLE);
+        assert_eq!(diags.len(), 1);
+
+        let d = &diags[0];
+        assert_eq!(d.crate_name(), "demo");
+        assert_eq!(d.lint_name().as_deref(), Some("clippy::needless_return"));
+        assert_eq!(*d.level(), LintLevel::Warning);
+        assert_eq!(d.span().as_ref().unwrap().line_start(), &3);
+        assert_eq!(d.suggestions().len(), 1);
+        assert!(d.has_machine_applicable_suggestion());
+    }
+
+    #[test]
+    fn package_id_formats() {
+        assert_eq!(crate_name_from_package_id("my-crate 0.1.0 (path+file:///w/my-crate)"), "my-crate");
+        assert_eq!(crate_name_from_package_id("path+file:///w/dir#my-crate@0.1.0"), "my-crate");
+        assert_eq!(crate_name_from_package_id("path+file:///w/my-crate#0.1.0"), "my-crate");
+    }
+
+    #[test]
+    fn plain_text_is_ignored() {
+        assert!(parse_cargo_json_diagnostics("warning: not json\n").is_empty());
+    }
+}
@@ -5,7 +5,15 @@ crate::ix!();
 pub struct LintReport {
     stdout: String,
     stderr: String,
-    success: bool,
+
+    /// Whether cargo itself exited successfully
+    process_success: bool,
+
+    /// Diagnostics that are not covered by any baseline
+    diagnostics: Vec<LintDiagnostic>,
+
+    /// Diagnostics tolerated by a committed baseline
+    baselined: Vec<LintDiagnostic>,
 }
 
 impl LintReport {
@@ -18,18 +26,118 @@ impl LintReport {
         &self.stderr
     }
 
+    /// True when cargo succeeded and no warning or error remains outside the baseline.
     pub fn success(&self) -> bool {
-        self.success
+        self.process_success && self.new_failure_count() == 0
+    }
+
+    pub fn diagnostics(&self) -> &[LintDiagnostic] {
+        &self.diagnostics
+    }
+
+    pub fn baselined_diagnostics(&self) -> &[LintDiagnostic] {
+        &self.baselined
+    }
+
+    /// Every diagnostic, baselined or not; this is what `--update-baseline` records.
+    pub fn all_diagnostics(&self) -> impl Iterator<Item=&LintDiagnostic> {
+        self.diagnostics.iter().chain(self.baselined.iter())
+    }
+
+    pub fn new_failure_count(&self) -> usize {
+        self.diagnostics.iter().filter(|d| d.level().is_failure()).count()
+    }
+
+    pub fn diagnostics_by_crate(&self) -> BTreeMap<String, Vec<&LintDiagnostic>> {
+        let mut map: BTreeMap<String, Vec<&LintDiagnostic>> = BTreeMap::new();
+        for d in &self.diagnostics {
+            map.entry(d.crate_name().clone()).or_default().push(d);
+        }
+        map
+    }
+
+    /// Keeps only diagnostics emitted for `crate_name`.
+    pub fn retain_crate(&mut self, crate_name: &str) {
+        self.diagnostics.retain(|d| d.crate_name() == crate_name);
+        self.baselined.retain(|d| d.crate_name() == crate_name);
+    }
+
+    /// Drops every diagnostic emitted for `crate_name` (crates with `skip-lint` set).
+    pub fn drop_crate(&mut self, crate_name: &str) {
+        self.diagnostics.retain(|d| d.crate_name() != crate_name);
+        self.baselined.retain(|d| d.crate_name() != crate_name);
+    }
+
+    /// Folds the report of another clippy run (a crate linted with its own clippy-args) into this one.
+    pub fn merge(&mut self, other: LintReport) {
+        self.stdout.push_str(&other.stdout);
+        self.stderr.push_str(&other.stderr);
+        self.process_success &= other.process_success;
+        self.diagnostics.extend(other.diagnostics);
+        self.baselined.extend(other.baselined);
+    }
+
+    /// Moves the diagnostics of `crate_name` which `baseline` tolerates out of the failing set.
+    pub fn apply_baseline(&mut self, crate_name: &str, baseline: &LintBaseline) {
+        let (ours, others): (Vec<_>, Vec<_>) = std::mem::take(&mut self.diagnostics)
+            .into_iter()
+            .partition(|d| d.crate_name() == crate_name);
+
+        let (fresh, tolerated) = baseline.partition(ours);
+
+        self.diagnostics = others;
+        self.diagnostics.extend(fresh);
+        self.baselined.extend(tolerated);
+    }
+
+    /// The rustc-style text of every new diagnostic, grouped by crate.
+    pub fn rendered(&self) -> String {
+        let mut out = String::new();
+        for (crate_name, diags) in self.diagnostics_by_crate() {
+            out.push_str(&format!("== {} ({} diagnostics)\n", crate_name, diags.len()));
+            for d in diags {
+                match d.rendered() {
+                    Some(text) => out.push_str(text),
+                    None       => out.push_str(&format!("{:?}: {}\n", d.level(), d.message())),
+                }
+            }
+        }
+        if !self.baselined.is_empty() {
+            out.push_str(&format!("({} baselined diagnostics not shown)\n", self.baselined.len()));
+        }
+        out
+    }
+}
+
+/// The `--format json` shape: the verdict and the diagnostics, without cargo's raw stdout
+/// (one JSON message per line, already parsed into `diagnostics`).
+impl ::serde::Serialize for LintReport {
+
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use ::serde::ser::SerializeStruct;
+
+        let mut s = serializer.serialize_struct("LintReport", 6)?;
+        s.serialize_field("success",           &self.success())?;
+        s.serialize_field("process_success",   &self.process_success)?;
+        s.serialize_field("new_failure_count", &self.new_failure_count())?;
+        s.serialize_field("diagnostics",       &self.diagnostics)?;
+        s.serialize_field("baselined",         &self.baselined)?;
+        s.serialize_field("stderr",            &self.stderr)?;
+        s.end()
     }
 }
 
 impl From<std::process::Output> for LintReport {
 
     fn from(output: std::process::Output) -> Self {
+        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+        let diagnostics = parse_cargo_json_diagnostics(&stdout);
         Self {
-            stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+            stdout,
             stderr: String::from_utf8_lossy(&output.stderr).to_string(),
-            success: output.status.success(),
+            process_success: output.status.success(),
+            diagnostics,
+            baselined: vec![],
         }
     }
 }
@@ -39,13 +147,21 @@ impl MaybeThrow for LintReport {
     type Error = LintingError;
 
     fn maybe_throw(&self) -> Result<(),Self::Error> {
-        if !self.success() {
+        if !self.process_success {
             return Err(LintingError::UnknownError {
                 stderr: Some(self.stderr.clone()),
                 stdout: Some(self.stdout.clone()),
             });
         }
 
+        let count = self.new_failure_count();
+        if count > 0 {
+            return Err(LintingError::NewLintWarnings {
+                count,
+                rendered: self.rendered(),
+            });
+        }
+
         Ok(())
     }
 }
@@ -9,66 +9,156 @@ pub trait RunLinting {
     async fn run_linting(&self) -> Result<Self::Report, Self::Error>;
 }
 
-/// Implementation for the entire workspace.
-/// (unchanged from your original approach).
+/// Runs clippy and returns the parsed report with baselines applied, without failing on warnings.
+///
+/// `RunLinting` is this plus `maybe_throw`; callers that want to look at (or re-baseline)
+/// the diagnostics use this directly.
 #[async_trait]
-impl<P, H> RunLinting for Workspace<P,H>
+pub trait CollectLintReport {
+    async fn collect_lint_report(&self) -> Result<LintReport, LintingError>;
+}
+
+/// The clippy invocation shared by the workspace and crate implementations.
+///
+/// We no longer pass `-D warnings`: that makes rustc stop at the first crate with a
+/// warning, and warnings covered by a baseline must not fail the run anyway.
+///
+/// `clippy_args` come from the workspace settings and go after `--`, so cargo options must
+/// all be added here rather than by the caller.
+fn clippy_json_command(scope: ClippyScope<'_>, clippy_args: &[String]) -> tokio::process::Command {
+    let mut cmd = tokio::process::Command::new("cargo");
+    cmd.arg("clippy")
+        .arg("--all-targets")
+        .arg("--message-format=json")
+        .arg("--quiet");
+
+    match scope {
+        ClippyScope::Workspace { excluded } => {
+            // without `--workspace`, a root manifest with a [package] lints only that package
+            cmd.arg("--workspace");
+            for name in excluded {
+                cmd.arg("--exclude").arg(name);
+            }
+        }
+        ClippyScope::Crate { manifest_path } => {
+            cmd.arg("--manifest-path").arg(manifest_path);
+        }
+    }
+    if !clippy_args.is_empty() {
+        cmd.arg("--").args(clippy_args);
+    }
+    cmd
+}
+
+/// What one clippy run covers.
+enum ClippyScope<'a> {
+    /// Every member of the workspace in the current directory but `excluded`, which are
+    /// linted on their own with their `clippy-args` override
+    Workspace { excluded: &'a [String] },
+    Crate { manifest_path: &'a Path },
+}
+
+#[async_trait]
+impl<P, H> CollectLintReport for Workspace<P,H>
 where
     H: CrateHandleInterface<P>,
     for<'async_trait> P: From<PathBuf> + AsRef<Path> + Send + Sync + 'async_trait,
 {
-    type Report = LintReport;
-    type Error  = LintingError;
-
-    async fn run_linting(&self) -> Result<Self::Report, Self::Error> {
+    async fn collect_lint_report(&self) -> Result<LintReport, LintingError> {
         let workspace_path = self.as_ref(); 
+        let settings       = WorkspaceSettings::load(workspace_path).await?;
+
+        // crates overriding clippy-args need a clippy run of their own; the rest share one
+        let mut skipped    = Vec::new();
+        let mut overridden = Vec::new();
+        let mut baselines  = Vec::new();
+
+        for crate_handle in self.crates() {
+            let guard          = crate_handle.lock().await;
+            let name           = guard.name().to_string();
+            let crate_settings = settings.for_crate(&name);
+
+            if *crate_settings.skip_lint() {
+                skipped.push(name);
+                continue;
+            }
+            if crate_settings.clippy_args() != settings.lint().clippy_args() {
+                let manifest_path = guard.root_dir_path_buf().join("Cargo.toml");
+                overridden.push((name.clone(), manifest_path, crate_settings.clippy_args().clone()));
+            }
+            baselines.push((name, LintBaseline::load(&guard.root_dir_path_buf()).await?));
+        }
+
+        let excluded: Vec<String> = overridden.iter().map(|(name, ..)| name.clone()).collect();
 
-        let output = tokio::process::Command::new("cargo")
-            .arg("clippy")
-            .arg("--all-targets")
-            .arg("--message-format=short")
-            .arg("--quiet")
-            .arg("--")
-            .arg("-D")
-            .arg("warnings")
+        let output = clippy_json_command(ClippyScope::Workspace { excluded: &excluded }, settings.lint().clippy_args())
             .current_dir(workspace_path)
             .output()
             .await
             .map_err(|e| LintingError::CommandError { io: e.into() })?;
 
-        let report = LintReport::from(output);
-        report.maybe_throw()?;
+        let mut report = LintReport::from(output);
+
+        for (name, manifest_path, clippy_args) in &overridden {
+            debug!("linting crate='{}' on its own with clippy-args {:?}", name, clippy_args);
+
+            let output = clippy_json_command(ClippyScope::Crate { manifest_path }, clippy_args)
+                .current_dir(workspace_path)
+                .output()
+                .await
+                .map_err(|e| LintingError::CommandError { io: e.into() })?;
+
+            let mut crate_report = LintReport::from(output);
+            crate_report.retain_crate(name);
+            report.drop_crate(name);
+            report.merge(crate_report);
+        }
+
+        for name in &skipped {
+            debug!("skip-lint is set for crate='{}'; dropping its diagnostics", name);
+            report.drop_crate(name);
+        }
+        for (name, baseline) in &baselines {
+            report.apply_baseline(name, baseline);
+        }
+
         Ok(report)
     }
 }
 
-/// Now **do not** implement `RunLinting` in a generic way for all `C: CrateHandleInterface`.
-/// Instead, implement it for your **actual concrete crate type**—for example, `CrateHandle`.
-///
-/// That ensures there is no overlap with `Workspace<P,H>` in the compiler's eyes.
-///
+/// Implementation for the entire workspace.
 #[async_trait]
-impl RunLinting for CrateHandle {
+impl<P, H> RunLinting for Workspace<P,H>
+where
+    H: CrateHandleInterface<P>,
+    for<'async_trait> P: From<PathBuf> + AsRef<Path> + Send + Sync + 'async_trait,
+{
     type Report = LintReport;
     type Error  = LintingError;
 
     async fn run_linting(&self) -> Result<Self::Report, Self::Error> {
+        let report = self.collect_lint_report().await?;
+        report.maybe_throw()?;
+        Ok(report)
+    }
+}
+
+#[async_trait]
+impl CollectLintReport for CrateHandle {
+
+    async fn collect_lint_report(&self) -> Result<LintReport, LintingError> {
         // 1) We lock CargoToml to find the actual `Cargo.toml` path
         let cargo_toml_arc = self.cargo_toml_direct(); 
         let cargo_toml_guard = cargo_toml_arc.lock().await;
         let manifest_path = cargo_toml_guard.as_ref().to_path_buf();
+        drop(cargo_toml_guard);
 
-        // 2) Run cargo clippy with `--manifest-path` ...
-        let output = tokio::process::Command::new("cargo")
-            .arg("clippy")
-            .arg("--manifest-path")
-            .arg(&manifest_path)
-            .arg("--all-targets")
-            .arg("--message-format=short")
-            .arg("--quiet")
-            .arg("--")
-            .arg("-D")
-            .arg("warnings")
+        // 2) Settings live at the root of the workspace this crate belongs to
+        let settings = WorkspaceSettings::load_for_crate(self.crate_path()).await?;
+        let crate_settings = settings.for_crate(&self.name());
+
+        // 3) Run cargo clippy with `--manifest-path` ...
+        let output = clippy_json_command(ClippyScope::Crate { manifest_path: &manifest_path }, crate_settings.clippy_args())
             .output()
             .await
             .map_err(|io_err| {
@@ -79,24 +169,46 @@ impl RunLinting for CrateHandle {
                 LintingError::CommandError { io: io_err.into() }
             })?;
 
-        let report = LintReport::from(output);
+        // 4) Path dependencies may be linted along the way; only our own diagnostics matter here
+        let crate_name = self.name().to_string();
+        let mut report = LintReport::from(output);
+        report.retain_crate(&crate_name);
+
+        let baseline = LintBaseline::load(self.crate_path()).await?;
+        report.apply_baseline(&crate_name, &baseline);
+
+        Ok(report)
+    }
+}
+
+/// Now **do not** implement `RunLinting` in a generic way for all `C: CrateHandleInterface`.
+/// Instead, implement it for your **actual concrete crate type**—for example, `CrateHandle`.
+///
+/// That ensures there is no overlap with `Workspace<P,H>` in the compiler's eyes.
+///
+#[async_trait]
+impl RunLinting for CrateHandle {
+    type Report = LintReport;
+    type Error  = LintingError;
+
+    async fn run_linting(&self) -> Result<Self::Report, Self::Error> {
+        let report = self.collect_lint_report().await?;
+
         if !report.success() {
             warn!(
-                "Lint failed for crate='{}'. Stderr:\n{}",
+                "Lint failed for crate='{}' with {} new diagnostics. Stderr:\n{}",
                 self.name(),
+                report.new_failure_count(),
                 report.stderr()
             );
-            return Err(LintingError::UnknownError {
-                stderr: Some(report.stderr().to_owned()),
-                stdout: Some(report.stdout().to_owned()),
-            });
+            report.maybe_throw()?;
         }
 
         debug!(
-            "Lint successful for crate='{}' => {} bytes stdout, {} bytes stderr",
+            "Lint successful for crate='{}' => {} diagnostics, {} baselined",
             self.name(),
-            report.stdout().len(),
-            report.stderr().len()
+            report.diagnostics().len(),
+            report.baselined_diagnostics().len()
         );
         Ok(report)
     }
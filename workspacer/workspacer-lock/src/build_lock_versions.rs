@@ -5,6 +5,23 @@ crate::ix!();
 pub async fn build_lock_versions<P>(
     root: &P
 ) -> Result<BTreeMap<String, BTreeSet<cargo_lock::Version>>, CrateError>
+where
+    P: AsRef<Path> + Send + Sync,
+{
+    let lockfile = read_lockfile(root).await?;
+
+    let mut map: BTreeMap<String, BTreeSet<cargo_lock::Version>> = BTreeMap::new();
+    for cargo_lock::Package { name, version, .. } in &lockfile.packages {
+        map.entry(name.as_str().to_owned())
+            .or_default()
+            .insert(version.clone());
+    }
+    debug!("build_lock_versions: created map with {} crates", map.len());
+    Ok(map)
+}
+
+/// Reads and parses `Cargo.lock` from `root`.
+pub async fn read_lockfile<P>(root: &P) -> Result<cargo_lock::Lockfile, CrateError>
 where
     P: AsRef<Path> + Send + Sync,
 {
@@ -22,21 +39,12 @@ where
             context:  format!("Failed to read Cargo.lock at {:?}", lock_path),
         })?;
 
-    let lockfile = cargo_lock::Lockfile::from_str(&lockfile_str).map_err(|e| {
+    cargo_lock::Lockfile::from_str(&lockfile_str).map_err(|e| {
         CrateError::LockfileParseFailed {
             path: lock_path.clone(),
             message: format!("{e}"),
         }
-    })?;
-
-    let mut map: BTreeMap<String, BTreeSet<cargo_lock::Version>> = BTreeMap::new();
-    for cargo_lock::Package { name, version, .. } in &lockfile.packages {
-        map.entry(name.as_str().to_owned())
-            .or_default()
-            .insert(version.clone());
-    }
-    debug!("build_lock_versions: created map with {} crates", map.len());
-    Ok(map)
+    })
 }
 
 #[cfg(test)]
@@ -0,0 +1,66 @@
+// ---------------- [ File: workspacer-move-item/src/crate_sources.rs ]
+crate::ix!();
+
+/// An in-memory snapshot of one crate's `src/` tree; relocation plans are computed on these
+/// so that nothing touches the disk before every check has passed.
+#[derive(Getters,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct CrateSources {
+    name:  String,
+    root:  PathBuf,
+
+    /// Absolute path => contents, `lib.rs` and `imports.rs` included
+    files: BTreeMap<PathBuf, String>,
+}
+
+impl CrateSources {
+
+    pub fn new(name: impl Into<String>, root: impl Into<PathBuf>, files: BTreeMap<PathBuf, String>) -> Self {
+        Self { name: name.into(), root: root.into(), files }
+    }
+
+    /// A crate that does not exist yet (the target of `ws split-crate --dry-run`).
+    pub fn empty(name: impl Into<String>, root: impl Into<PathBuf>) -> Self {
+        Self::new(name, root, BTreeMap::new())
+    }
+
+    pub async fn read<H>(handle: &H) -> Result<Self, WorkspaceError>
+    where
+        H: GetSourceFilesWithExclusions + RootDirPathBuf + Named + Sync,
+    {
+        let mut files = BTreeMap::new();
+        for path in handle.source_files_excluding(&[]).await? {
+            let text = tokio::fs::read_to_string(&path).await.map_err(|io| WorkspaceError::IoError {
+                io_error: Arc::new(io),
+                context:  format!("reading {:?} to plan an item move", path),
+            })?;
+            files.insert(path, text);
+        }
+        Ok(Self::new(handle.name().to_string(), handle.root_dir_path_buf(), files))
+    }
+
+    /// The name used in `use` paths: `my-crate` => `my_crate`.
+    pub fn path_name(&self) -> String {
+        self.name.replace('-', "_")
+    }
+
+    pub fn src_dir(&self) -> PathBuf {
+        self.root.join("src")
+    }
+
+    pub fn lib_rs(&self) -> PathBuf {
+        self.src_dir().join("lib.rs")
+    }
+
+    /// Files holding items: everything except `lib.rs`, `main.rs`, `imports.rs` and `src/bin/`.
+    pub fn item_files(&self) -> impl Iterator<Item=(&PathBuf, &String)> {
+        let src_dir = self.src_dir();
+        self.files.iter().filter(move |(path, _)| {
+            let special = matches!(
+                path.file_name().and_then(|n| n.to_str()),
+                Some("lib.rs" | "main.rs" | "imports.rs")
+            );
+            !special && !path.starts_with(src_dir.join("bin"))
+        })
+    }
+}
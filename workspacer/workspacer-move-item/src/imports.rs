@@ -0,0 +1,11 @@
+// ---------------- [ File: workspacer-move-item/src/imports.rs ]
+pub(crate) use workspacer_3p::*;
+pub(crate) use workspacer_add_internal_dep::*;
+pub(crate) use workspacer_add_new_crate_to_workspace::*;
+pub(crate) use workspacer_crate::*;
+pub(crate) use workspacer_crate_interface::*;
+pub(crate) use workspacer_detect_circular_deps::*;
+pub(crate) use workspacer_errors::*;
+pub(crate) use workspacer_register::*;
+pub(crate) use workspacer_workspace::*;
+pub(crate) use workspacer_workspace_interface::*;
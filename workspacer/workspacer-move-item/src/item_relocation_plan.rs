@@ -0,0 +1,413 @@
+// ---------------- [ File: workspacer-move-item/src/item_relocation_plan.rs ]
+crate::ix!();
+
+/// Everything a move will write, delete and depend on, computed before anything is touched.
+#[derive(Getters,Debug,Clone,Default,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct ItemRelocationPlan {
+    items:            Vec<String>,
+    from_crate:       String,
+    to_crate:         String,
+
+    /// File => new contents, for every file created or changed
+    writes:           BTreeMap<PathBuf, String>,
+
+    /// The files of `writes` that are new in the target crate (registered in its lib.rs)
+    created:          Vec<PathBuf>,
+    removals:         Vec<PathBuf>,
+
+    /// `(dependent, dependency)` pairs that do not exist yet
+    new_dependencies: Vec<(String, String)>,
+}
+
+impl fmt::Display for ItemRelocationPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "move {} from '{}' to '{}':", self.items.join(", "), self.from_crate, self.to_crate)?;
+        for path in self.writes.keys() {
+            let verb = if self.created.contains(path) { "create" } else { "modify" };
+            writeln!(f, "  {} {}", verb, path.display())?;
+        }
+        for path in &self.removals {
+            writeln!(f, "  remove {}", path.display())?;
+        }
+        for (dependent, dependency) in &self.new_dependencies {
+            writeln!(f, "  add dependency {} -> {}", dependent, dependency)?;
+        }
+        Ok(())
+    }
+}
+
+/// One piece of moved code, in the order it appeared in the source crate.
+struct Snippet {
+    item:  String,
+    file:  PathBuf,
+    range: std::ops::Range<usize>,
+}
+
+/// Plans moving `items` (each with its impl blocks and test modules) from `from` to `to`.
+///
+/// `others` are the remaining workspace crates, whose `use` paths may need rewriting and which
+/// may need a dependency on `to`; `graph` is `GenerateDependencyTree`'s (A -> B: A depends on B).
+/// Fails without side effects if an item is missing or ambiguous, a target file exists, or a
+/// new dependency would close a cycle.
+pub fn plan_item_relocation(
+    items:  &[String],
+    from:   &CrateSources,
+    to:     &CrateSources,
+    others: &[CrateSources],
+    graph:  &Graph<String, ()>,
+) -> Result<ItemRelocationPlan, ItemRelocationError> {
+    if from.name() == to.name() {
+        return Err(ItemRelocationError::SameCrate { crate_name: from.name().clone() });
+    }
+
+    // 1) find every item, its impls and its tests
+    let mut snippets      = Vec::<Snippet>::new();
+    let mut defining_file = BTreeMap::<String, PathBuf>::new();
+
+    for item in items {
+        let mut defining = Vec::new();
+        for (path, text) in from.item_files() {
+            let found = locate_item_in_file(text, item).map_err(|errors| {
+                ItemRelocationError::SourceParseError { path: path.clone(), errors }
+            })?;
+            if *found.defines() {
+                defining.push(path.clone());
+            }
+            for range in found.ranges() {
+                snippets.push(Snippet { item: item.clone(), file: path.clone(), range: range.clone() });
+            }
+        }
+
+        match defining.len() {
+            0 => return Err(ItemRelocationError::ItemNotFound { crate_name: from.name().clone(), item_name: item.clone() }),
+            1 => { defining_file.insert(item.clone(), defining.remove(0)); }
+            _ => return Err(ItemRelocationError::AmbiguousItem { crate_name: from.name().clone(), item_name: item.clone(), files: defining }),
+        }
+    }
+
+    // 2) what is left of each source file
+    let mut ranges_by_file = BTreeMap::<PathBuf, Vec<std::ops::Range<usize>>>::new();
+    for s in &snippets {
+        ranges_by_file.entry(s.file.clone()).or_default().push(s.range.clone());
+    }
+
+    let mut from_files: BTreeMap<PathBuf, String> = from.files().clone();
+    let mut plan = ItemRelocationPlan {
+        items:      items.to_vec(),
+        from_crate: from.name().clone(),
+        to_crate:   to.name().clone(),
+        ..Default::default()
+    };
+    let mut emptied = BTreeSet::<PathBuf>::new();
+
+    for (path, ranges) in &mut ranges_by_file {
+        ranges.sort_by_key(|r| r.start);
+        ranges.dedup();
+
+        let remaining = remove_ranges(&from.files()[path], ranges);
+        let without_uses = remove_ranges(&remaining, &use_item_ranges(&remaining));
+
+        if is_effectively_empty(&without_uses) {
+            emptied.insert(path.clone());
+            from_files.remove(path);
+            plan.removals.push(path.clone());
+
+            let stem = file_stem(path);
+            if let Some(lib) = from_files.get(&from.lib_rs()).and_then(|lib| remove_x_macro(lib, &stem)) {
+                from_files.insert(from.lib_rs(), lib);
+            }
+        } else {
+            from_files.insert(path.clone(), remaining);
+        }
+    }
+
+    // 3) one new file per item in the target crate
+    let to_dir_name = to.root().file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_else(|| to.name().clone());
+    let mut moved_text = String::new();
+    let mut reused_stems = BTreeSet::<String>::new();
+
+    for item in items {
+        let defining = &defining_file[item];
+
+        // a file that moves as a whole keeps its name, once
+        let stem = if emptied.contains(defining) && reused_stems.insert(file_stem(defining)) {
+            file_stem(defining)
+        } else {
+            item.to_snake_case()
+        };
+
+        let target = to.src_dir().join(format!("{}.rs", stem));
+        if to.files().contains_key(&target) || plan.writes.contains_key(&target) {
+            return Err(ItemRelocationError::TargetFileExists { path: target });
+        }
+
+        let mut body = format!("// ---------------- [ File: {}/src/{}.rs ]\ncrate::ix!();\n", to_dir_name, stem);
+
+        // `use` items of the files the code came from, in case the moved code relies on them
+        let mut uses = BTreeSet::<String>::new();
+        let ordered = snippets
+            .iter()
+            .filter(|s| &s.item == item)
+            .filter(|s| &s.file == defining)
+            .chain(snippets.iter().filter(|s| &s.item == item && &s.file != defining));
+
+        let mut pieces = Vec::new();
+        for s in ordered {
+            let text = &from.files()[&s.file];
+            for r in use_item_ranges(text) {
+                uses.insert(text[r].trim_end().to_string());
+            }
+            pieces.push(text[s.range.clone()].trim_end().to_string());
+        }
+
+        if !uses.is_empty() {
+            body.push('\n');
+            for u in &uses {
+                body.push_str(u);
+                body.push('\n');
+            }
+        }
+        for piece in pieces {
+            body.push('\n');
+            body.push_str(&piece);
+            body.push('\n');
+            moved_text.push_str(&piece);
+            moved_text.push('\n');
+        }
+
+        plan.created.push(target.clone());
+        plan.writes.insert(target, body);
+    }
+
+    // 4) paths through the old location; `crate::Item` in the source crate now needs the target
+    let from_path = from.path_name();
+    let to_path   = to.path_name();
+
+    for (path, text) in from_files.iter_mut() {
+        for item in items {
+            if let Some(new) = rewrite_use_paths(text, "crate", &to_path, item) {
+                *text = new;
+            }
+        }
+        if from.files().get(path).map(String::as_str) != Some(text.as_str()) {
+            plan.writes.insert(path.clone(), text.clone());
+        }
+    }
+
+    for (path, text) in to.files() {
+        let mut new = text.clone();
+        for item in items {
+            if let Some(rewritten) = rewrite_use_paths(&new, &from_path, "crate", item) {
+                new = rewritten;
+            }
+        }
+        if &new != text {
+            plan.writes.insert(path.clone(), new);
+        }
+    }
+
+    // 5) dependencies the move introduces
+    let mut edges = Vec::<(String, String)>::new();
+
+    let still_used = from_files.values().any(|text| items.iter().any(|i| mentions_identifier(text, i)));
+    if still_used {
+        edges.push((from.name().clone(), to.name().clone()));
+    }
+
+    let moved: BTreeSet<&String> = items.iter().collect();
+    let left_behind: BTreeSet<String> = from_files
+        .iter()
+        .filter(|(path, _)| from.item_files().any(|(p, _)| p == *path))
+        .flat_map(|(_, text)| top_level_item_names(text))
+        .filter(|name| !moved.contains(name))
+        .collect();
+    if left_behind.iter().any(|name| mentions_identifier(&moved_text, name)) {
+        edges.push((to.name().clone(), from.name().clone()));
+    }
+
+    for other in others.iter().filter(|c| c.name() != from.name() && c.name() != to.name()) {
+        if !has_direct_edge(graph, other.name(), from.name()) {
+            continue;
+        }
+
+        let mut uses_moved = false;
+        for (path, text) in other.files() {
+            uses_moved |= items.iter().any(|i| mentions_identifier(text, i));
+
+            let mut new = text.clone();
+            for item in items {
+                if let Some(rewritten) = rewrite_use_paths(&new, &from_path, &to_path, item) {
+                    new = rewritten;
+                }
+            }
+            if &new != text {
+                plan.writes.insert(path.clone(), new);
+            }
+        }
+        if uses_moved {
+            edges.push((other.name().clone(), to.name().clone()));
+        }
+    }
+
+    edges.retain(|(a, b)| !has_direct_edge(graph, a, b));
+
+    // 6) nothing may close a cycle
+    check_new_edges_for_cycles(graph, &edges)?;
+
+    plan.new_dependencies = edges;
+    Ok(plan)
+}
+
+fn file_stem(path: &Path) -> String {
+    path.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default()
+}
+
+fn node_named(graph: &Graph<String, ()>, name: &str) -> Option<NodeIndex> {
+    graph.node_indices().find(|i| graph[*i] == name)
+}
+
+fn has_direct_edge(graph: &Graph<String, ()>, from: &str, to: &str) -> bool {
+    match (node_named(graph, from), node_named(graph, to)) {
+        (Some(a), Some(b)) => graph.contains_edge(a, b),
+        _                  => false,
+    }
+}
+
+/// Adds `edges` to a copy of `graph` and rejects the first one whose dependency already
+/// (transitively) depends on its dependent.
+fn check_new_edges_for_cycles(graph: &Graph<String, ()>, edges: &[(String, String)]) -> Result<(), ItemRelocationError> {
+    let mut g = graph.clone();
+    let node = |g: &mut Graph<String, ()>, name: &str| node_named(g, name).unwrap_or_else(|| g.add_node(name.to_string()));
+
+    let mut added = Vec::new();
+    for (a, b) in edges {
+        let (na, nb) = (node(&mut g, a), node(&mut g, b));
+        g.add_edge(na, nb, ());
+        added.push((a, b, na, nb));
+    }
+
+    for (a, b, na, nb) in added {
+        if petgraph::algo::has_path_connecting(&g, nb, na, None) {
+            return Err(ItemRelocationError::WouldCreateDependencyCycle {
+                dependent:  a.clone(),
+                dependency: b.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test_plan_item_relocation {
+    use super::*;
+
+    fn krate(name: &str, files: &[(&str, &str)]) -> CrateSources {
+        let root = PathBuf::from("/w").join(name);
+        let files = files.iter().map(|(f, t)| (root.join("src").join(f), t.to_string())).collect();
+        CrateSources::new(name, root, files)
+    }
+
+    fn graph(edges: &[(&str, &str)]) -> Graph<String, ()> {
+        let mut g = Graph::new();
+        let mut idx = BTreeMap::new();
+        for (a, b) in edges {
+            let na = *idx.entry(*a).or_insert_with(|| g.add_node(a.to_string()));
+            let nb = *idx.entry(*b).or_insert_with(|| g.add_node(b.to_string()));
+            g.add_edge(na, nb, ());
+        }
+        g
+    }
+
+    const WIDGET: &str = "\
+// ---------------- [ File: a/src/widget.rs ]
+crate::ix!();
+
+pub struct Widget;
+
+impl Widget {
+    pub fn new() -> Self { Widget }
+}
+
+#[cfg(test)]
+mod test_widget {
+    use super::*;
+}
+";
+
+    fn source() -> CrateSources {
+        krate("a", &[
+            ("lib.rs",    "#[macro_use] mod imports; use imports::*;\n\nx!{user}\nx!{widget}\n"),
+            ("widget.rs", WIDGET),
+            ("user.rs",   "crate::ix!();\n\npub fn make() -> crate::Widget { Widget::new() }\n"),
+        ])
+    }
+
+    #[test]
+    fn whole_file_moves_and_source_depends_on_target() {
+        let from   = source();
+        let to     = krate("b", &[("lib.rs", "#[macro_use] mod imports; use imports::*;\n")]);
+        let app    = krate("app", &[("main.rs", "use a::Widget;\nfn main() { Widget::new(); }\n")]);
+        let g      = graph(&[("app", "a")]);
+
+        let plan = plan_item_relocation(&["Widget".to_string()], &from, &to, &[app], &g).unwrap();
+
+        let target = PathBuf::from("/w/b/src/widget.rs");
+        assert_eq!(plan.created(), &vec![target.clone()]);
+        let body = &plan.writes()[&target];
+        assert!(body.starts_with("// ---------------- [ File: b/src/widget.rs ]\ncrate::ix!();\n\npub struct Widget;"));
+        assert!(body.contains("mod test_widget"));
+
+        assert_eq!(plan.removals(), &vec![PathBuf::from("/w/a/src/widget.rs")]);
+        assert_eq!(plan.writes()[&PathBuf::from("/w/a/src/lib.rs")], "#[macro_use] mod imports; use imports::*;\n\nx!{user}\n");
+        assert!(plan.writes()[&PathBuf::from("/w/a/src/user.rs")].contains("-> b::Widget"));
+        assert_eq!(plan.writes()[&PathBuf::from("/w/app/src/main.rs")], "use b::Widget;\nfn main() { Widget::new(); }\n");
+
+        assert_eq!(plan.new_dependencies(), &vec![
+            ("a".to_string(), "b".to_string()),
+            ("app".to_string(), "b".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn moved_code_using_what_stays_behind_is_a_cycle() {
+        let from = krate("a", &[
+            ("lib.rs",    "x!{widget}\n"),
+            ("widget.rs", "crate::ix!();\n\npub struct Widget(Helper);\n\npub struct Helper;\n\npub fn uses() -> Widget { todo!() }\n"),
+        ]);
+        let to = krate("b", &[]);
+
+        let err = plan_item_relocation(&["Widget".to_string()], &from, &to, &[], &Graph::new()).unwrap_err();
+        assert!(matches!(err, ItemRelocationError::WouldCreateDependencyCycle { .. }));
+
+        // moving both is fine, and the source no longer needs anything from the target
+        let plan = plan_item_relocation(&["Widget".to_string(), "Helper".to_string(), "uses".to_string()], &from, &to, &[], &Graph::new()).unwrap();
+        assert!(plan.new_dependencies().is_empty());
+        assert_eq!(plan.created().len(), 3);
+        assert_eq!(plan.removals().len(), 1);
+    }
+
+    #[test]
+    fn existing_reverse_dependency_blocks_the_move() {
+        let to = krate("b", &[]);
+        // b already depends on a, and a keeps using Widget => a -> b closes a cycle
+        let err = plan_item_relocation(&["Widget".to_string()], &source(), &to, &[], &graph(&[("b", "a")])).unwrap_err();
+        assert!(matches!(
+            err,
+            ItemRelocationError::WouldCreateDependencyCycle { ref dependent, ref dependency } if dependent == "a" && dependency == "b"
+        ));
+    }
+
+    #[test]
+    fn missing_item_and_existing_target_file() {
+        let to = krate("b", &[("widget.rs", "")]);
+        assert!(matches!(
+            plan_item_relocation(&["Gadget".to_string()], &source(), &to, &[], &Graph::new()),
+            Err(ItemRelocationError::ItemNotFound { .. })
+        ));
+        assert!(matches!(
+            plan_item_relocation(&["Widget".to_string()], &source(), &to, &[], &Graph::new()),
+            Err(ItemRelocationError::TargetFileExists { .. })
+        ));
+    }
+}
@@ -0,0 +1,10 @@
+// ---------------- [ File: workspacer-move-item/src/lib.rs ]
+#[macro_use] mod imports; use imports::*;
+
+x!{crate_sources}
+x!{locate_item}
+x!{rewrite_use_paths}
+x!{remove_x_macro}
+x!{item_relocation_plan}
+x!{move_item}
+x!{split_crate}
@@ -0,0 +1,230 @@
+// ---------------- [ File: workspacer-move-item/src/locate_item.rs ]
+crate::ix!();
+
+/// What one source file contributes to an item being moved.
+#[derive(Getters,Debug,Clone,Default,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct ItemRangesInFile {
+    /// The file holds the item's own definition (not just impls of it)
+    defines: bool,
+
+    /// Line-aligned byte ranges of the definition, its impl blocks and its test modules, in
+    /// source order
+    ranges:  Vec<std::ops::Range<usize>>,
+}
+
+impl ItemRangesInFile {
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+}
+
+/// Finds `item_name` in one file: its top-level definition, every `impl` whose self type is the
+/// item (inherent or trait impls), and the `#[cfg(test)]` modules named after it
+/// (`test_<snake_name>`, `tests_<snake_name>`, ...).
+///
+/// Items generated by macros (`error_tree!{}` and the like) are invisible here.
+pub fn locate_item_in_file(text: &str, item_name: &str) -> Result<ItemRangesInFile, Vec<String>> {
+    let parse = SourceFile::parse(text, Edition::Edition2021);
+    let errors: Vec<String> = parse.errors().iter().map(|e| e.to_string()).collect();
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let snake = item_name.to_snake_case();
+    let mut found = ItemRangesInFile::default();
+
+    for item in parse.tree().items() {
+        let take = match &item {
+            ast::Item::Impl(imp) => impl_subject_name(imp).as_deref() == Some(item_name),
+            ast::Item::Module(module) if is_cfg_test(module) => {
+                module.name().is_some_and(|n| named_after(&n.text().to_string(), &snake))
+            }
+            other => {
+                let defines = top_level_item_name(other).as_deref() == Some(item_name);
+                found.defines |= defines;
+                defines
+            }
+        };
+        if take {
+            found.ranges.push(line_aligned(text, item.syntax().text_range()));
+        }
+    }
+
+    Ok(found)
+}
+
+/// The name a top-level item introduces, for the item kinds that can be moved.
+pub fn top_level_item_name(item: &ast::Item) -> Option<String> {
+    let name = match item {
+        ast::Item::Fn(x)         => x.name(),
+        ast::Item::Struct(x)     => x.name(),
+        ast::Item::Enum(x)       => x.name(),
+        ast::Item::Union(x)      => x.name(),
+        ast::Item::Trait(x)      => x.name(),
+        ast::Item::TypeAlias(x)  => x.name(),
+        ast::Item::Const(x)      => x.name(),
+        ast::Item::Static(x)     => x.name(),
+        ast::Item::MacroRules(x) => x.name(),
+        _                        => None,
+    };
+    name.map(|n| n.text().to_string())
+}
+
+/// `impl<T> Foo<T>`, `impl Display for crate::Foo` => `Foo`.
+fn impl_subject_name(imp: &ast::Impl) -> Option<String> {
+    let self_ty = imp.self_ty()?.syntax().text().to_string();
+    let without_generics = self_ty.split('<').next()?.trim();
+    without_generics.rsplit("::").next().map(|s| s.trim().to_string())
+}
+
+/// `test_widget`, `tests_widget` => `widget`.
+fn named_after(module_name: &str, snake_item_name: &str) -> bool {
+    module_name
+        .split_once('_')
+        .is_some_and(|(prefix, rest)| prefix.starts_with("test") && rest == snake_item_name)
+}
+
+fn is_cfg_test(module: &ast::Module) -> bool {
+    module
+        .attrs()
+        .any(|a| a.syntax().text().to_string().replace(' ', "") == "#[cfg(test)]")
+}
+
+/// Widens `range` to whole lines: from the start of its first line (when only indentation
+/// precedes it) through the newline ending its last line.
+fn line_aligned(text: &str, range: TextRange) -> std::ops::Range<usize> {
+    let mut start = usize::from(range.start());
+    let mut end   = usize::from(range.end());
+
+    let line_start = text[..start].rfind('\n').map_or(0, |i| i + 1);
+    if text[line_start..start].trim().is_empty() {
+        start = line_start;
+    }
+    if let Some(nl) = text[end..].find('\n') {
+        if text[end..end + nl].trim().is_empty() {
+            end += nl + 1;
+        }
+    } else {
+        end = text.len();
+    }
+    start..end
+}
+
+/// Whether any identifier token in `text` is `ident` (macro bodies included; comments and
+/// string literals are not identifiers, so they do not count).
+pub fn mentions_identifier(text: &str, ident: &str) -> bool {
+    SourceFile::parse(text, Edition::Edition2021)
+        .syntax_node()
+        .descendants_with_tokens()
+        .filter_map(|e| e.into_token())
+        .any(|t| t.kind() == SyntaxKind::IDENT && t.text() == ident)
+}
+
+/// The names of the movable top-level items of `text`.
+pub fn top_level_item_names(text: &str) -> BTreeSet<String> {
+    SourceFile::parse(text, Edition::Edition2021)
+        .tree()
+        .items()
+        .filter_map(|item| top_level_item_name(&item))
+        .collect()
+}
+
+/// Line-aligned ranges of the top-level `use` items of `text`; the moved code may need them.
+pub fn use_item_ranges(text: &str) -> Vec<std::ops::Range<usize>> {
+    SourceFile::parse(text, Edition::Edition2021)
+        .tree()
+        .items()
+        .filter(|item| matches!(item, ast::Item::Use(_)))
+        .map(|item| line_aligned(text, item.syntax().text_range()))
+        .collect()
+}
+
+/// Cuts `ranges` (sorted, non-overlapping) out of `text` and tidies the blank lines left behind.
+pub fn remove_ranges(text: &str, ranges: &[std::ops::Range<usize>]) -> String {
+    let mut out  = String::with_capacity(text.len());
+    let mut last = 0;
+    for r in ranges {
+        out.push_str(&text[last..r.start]);
+        last = r.end;
+    }
+    out.push_str(&text[last..]);
+
+    while out.contains("\n\n\n") {
+        out = out.replace("\n\n\n", "\n\n");
+    }
+    out
+}
+
+/// Nothing left but comments, `crate::ix!();` and blank lines.
+pub fn is_effectively_empty(text: &str) -> bool {
+    text.lines().map(str::trim).all(|l| l.is_empty() || l.starts_with("//") || l == "crate::ix!();")
+}
+
+#[cfg(test)]
+mod test_locate_item {
+    use super::*;
+
+    const TEXT: &str = "\
+// ---------------- [ File: a/src/widget.rs ]
+crate::ix!();
+
+/// A widget.
+#[derive(Debug)]
+pub struct Widget {
+    size: usize,
+}
+
+impl Widget {
+    pub fn new() -> Self { Self { size: 0 } }
+}
+
+impl fmt::Display for Widget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, \"w\") }
+}
+
+pub fn helper() -> usize { 1 }
+
+#[cfg(test)]
+mod test_widget {
+    use super::*;
+}
+";
+
+    #[test]
+    fn finds_definition_impls_and_tests() {
+        let found = locate_item_in_file(TEXT, "Widget").unwrap();
+        assert!(found.defines());
+        assert_eq!(found.ranges().len(), 4);
+
+        let first = &TEXT[found.ranges()[0].clone()];
+        assert!(first.starts_with("/// A widget."));
+        assert!(first.ends_with("}\n"));
+
+        let rest = remove_ranges(TEXT, found.ranges());
+        assert!(rest.contains("pub fn helper()"));
+        assert!(!rest.contains("Widget"));
+        assert!(!rest.contains("\n\n\n"));
+        assert!(!is_effectively_empty(&rest));
+
+        let helper = locate_item_in_file(&rest, "helper").unwrap();
+        assert!(is_effectively_empty(&remove_ranges(&rest, helper.ranges())));
+    }
+
+    #[test]
+    fn impls_elsewhere_do_not_define() {
+        let found = locate_item_in_file("impl Default for Widget { fn default() -> Self { todo!() } }\n", "Widget").unwrap();
+        assert!(!found.defines());
+        assert_eq!(found.ranges().len(), 1);
+
+        assert!(locate_item_in_file(TEXT, "Gadget").unwrap().is_empty());
+    }
+
+    #[test]
+    fn identifiers_ignore_comments_and_strings() {
+        assert!(mentions_identifier("fn f() { let w = Widget::new(); }", "Widget"));
+        assert!(mentions_identifier("x!{ Widget }", "Widget"));
+        assert!(!mentions_identifier("// Widget\nfn f() { println!(\"Widget\"); }", "Widget"));
+        assert_eq!(top_level_item_names(TEXT), ["Widget", "helper"].iter().map(|s| s.to_string()).collect());
+    }
+}
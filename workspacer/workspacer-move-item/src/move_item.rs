@@ -0,0 +1,135 @@
+// ---------------- [ File: workspacer-move-item/src/move_item.rs ]
+crate::ix!();
+
+#[async_trait]
+pub trait MoveItem {
+    type Error;
+
+    /// Computes what moving `items` from `from_crate` to `to_crate` would do, touching nothing.
+    async fn plan_move_items(
+        &self,
+        from_crate: &str,
+        to_crate:   &str,
+        items:      &[String],
+    ) -> Result<ItemRelocationPlan, Self::Error>;
+
+    /// Moves `items` (with their impl blocks and test modules) from `from_crate` to `to_crate`:
+    /// files are written or removed, the target's `lib.rs` registers the new files, `use` paths
+    /// are rewritten and missing internal dependencies are added. Refuses to introduce a cycle.
+    async fn move_items(
+        &self,
+        from_crate: &str,
+        to_crate:   &str,
+        items:      &[String],
+    ) -> Result<ItemRelocationPlan, Self::Error>;
+}
+
+#[async_trait]
+impl<P,H> MoveItem for Workspace<P,H>
+where
+    for<'async_trait> P: Debug + Clone + From<PathBuf> + AsRef<Path> + Send + Sync + 'async_trait,
+    H: CrateHandleInterface<P>
+        + EnsureAllSourceFilesAreRegistered<Error=SourceFileRegistrationError>
+        + Debug
+        + Clone
+        + Send
+        + Sync,
+{
+    type Error = WorkspaceError;
+
+    async fn plan_move_items(
+        &self,
+        from_crate: &str,
+        to_crate:   &str,
+        items:      &[String],
+    ) -> Result<ItemRelocationPlan, WorkspaceError> {
+        let snapshot = snapshot_crates(self).await?;
+        let from     = snapshot.sources(from_crate)?;
+        let to       = snapshot.sources(to_crate)?;
+        let graph    = self.generate_dependency_tree().await?;
+
+        Ok(plan_item_relocation(items, from, to, &snapshot.sources, &graph)?)
+    }
+
+    async fn move_items(
+        &self,
+        from_crate: &str,
+        to_crate:   &str,
+        items:      &[String],
+    ) -> Result<ItemRelocationPlan, WorkspaceError> {
+        let snapshot = snapshot_crates(self).await?;
+        let from     = snapshot.sources(from_crate)?;
+        let to       = snapshot.sources(to_crate)?;
+        let graph    = self.generate_dependency_tree().await?;
+
+        let plan = plan_item_relocation(items, from, to, &snapshot.sources, &graph)?;
+        info!("applying item relocation plan:\n{}", plan);
+
+        for (path, contents) in plan.writes() {
+            if let Some(parent) = path.parent() {
+                tokio::fs::create_dir_all(parent).await.map_err(|io| WorkspaceError::IoError {
+                    io_error: Arc::new(io),
+                    context:  format!("creating {:?} for a moved item", parent),
+                })?;
+            }
+            tokio::fs::write(path, contents).await.map_err(|io| WorkspaceError::IoError {
+                io_error: Arc::new(io),
+                context:  format!("writing {:?} while moving items", path),
+            })?;
+        }
+
+        for path in plan.removals() {
+            tokio::fs::remove_file(path).await.map_err(|io| WorkspaceError::IoError {
+                io_error: Arc::new(io),
+                context:  format!("removing {:?}, emptied by the move", path),
+            })?;
+        }
+
+        snapshot.handle(to_crate)?.ensure_all_source_files_are_registered().await?;
+
+        for (dependent, dependency) in plan.new_dependencies() {
+            debug!("adding internal dependency {} -> {}", dependent, dependency);
+            self.add_internal_dependency(snapshot.handle(dependent)?, snapshot.handle(dependency)?).await?;
+        }
+
+        Ok(plan)
+    }
+}
+
+/// Every crate of the workspace, read once: handles for applying, sources for planning.
+pub(crate) struct CrateSnapshot<H> {
+    pub(crate) handles: Vec<H>,
+    pub(crate) sources: Vec<CrateSources>,
+}
+
+impl<H> CrateSnapshot<H> {
+
+    fn position(&self, crate_name: &str) -> Result<usize, WorkspaceError> {
+        self.sources
+            .iter()
+            .position(|s| s.name() == crate_name)
+            .ok_or_else(|| CrateError::CrateNotFoundInWorkspace { crate_name: crate_name.to_string() }.into())
+    }
+
+    pub(crate) fn sources(&self, crate_name: &str) -> Result<&CrateSources, WorkspaceError> {
+        Ok(&self.sources[self.position(crate_name)?])
+    }
+
+    pub(crate) fn handle(&self, crate_name: &str) -> Result<&H, WorkspaceError> {
+        Ok(&self.handles[self.position(crate_name)?])
+    }
+}
+
+pub(crate) async fn snapshot_crates<P,H>(workspace: &Workspace<P,H>) -> Result<CrateSnapshot<H>, WorkspaceError>
+where
+    for<'async_trait> P: From<PathBuf> + AsRef<Path> + Send + Sync + 'async_trait,
+    H: CrateHandleInterface<P> + Clone,
+{
+    let mut snapshot = CrateSnapshot { handles: vec![], sources: vec![] };
+    for crate_arc in workspace.crates() {
+        let handle = crate_arc.lock().await.clone();
+        snapshot.sources.push(CrateSources::read(&handle).await?);
+        snapshot.handles.push(handle);
+    }
+    Ok(snapshot)
+}
@@ -0,0 +1,37 @@
+// ---------------- [ File: workspacer-move-item/src/remove_x_macro.rs ]
+crate::ix!();
+
+/// Drops the `x!{stem}` registration of a module whose file is going away.
+///
+/// Registration of new files is `EnsureAllSourceFilesAreRegistered`'s job; it only ever adds,
+/// so removal lives here. Returns `None` when `lib_rs` has no such line.
+pub fn remove_x_macro(lib_rs: &str, stem: &str) -> Option<String> {
+    let registration = format!("x!{{{}}}", stem);
+
+    let mut removed = false;
+    let kept: Vec<&str> = lib_rs
+        .split_inclusive('\n')
+        .filter(|line| {
+            let matches = line.trim().replace(' ', "") == registration;
+            removed |= matches;
+            !matches
+        })
+        .collect();
+
+    removed.then(|| kept.concat())
+}
+
+#[cfg(test)]
+mod test_remove_x_macro {
+    use super::*;
+
+    #[test]
+    fn removes_only_the_exact_stem() {
+        let lib = "#[macro_use] mod imports; use imports::*;\n\nx!{widget}\nx!{ widget_impls }\nx!{gadget}\n";
+        assert_eq!(
+            remove_x_macro(lib, "widget").as_deref(),
+            Some("#[macro_use] mod imports; use imports::*;\n\nx!{ widget_impls }\nx!{gadget}\n")
+        );
+        assert_eq!(remove_x_macro(lib, "gizmo"), None);
+    }
+}
@@ -0,0 +1,233 @@
+// ---------------- [ File: workspacer-move-item/src/rewrite_use_paths.rs ]
+crate::ix!();
+
+/// Rewrites paths naming `item` through its old crate so they name it through the new one:
+///
+/// - `old::Item` anywhere (`use old::Item;`, `old::Item::new()`) becomes `new::Item`
+/// - a group `use old::{A, Item, B};`, on one line or many, loses `Item`, and
+///   `use new::Item;` follows it
+///
+/// `old_root` / `new_root` are path roots: a crate's `use` name, or `crate`.
+/// Glob imports (`use old::*;`) are left alone; they are served by the new dependency's
+/// `imports.rs` line instead. The rewrite works on the syntax tree, so string literals,
+/// comments, macro bodies (`$crate::` paths included) and paths relative to an outer
+/// `use` group are never touched. Returns `None` when nothing changed.
+pub fn rewrite_use_paths(text: &str, old_root: &str, new_root: &str, item: &str) -> Option<String> {
+    let parse = SourceFile::parse(text, Edition::Edition2021);
+    let file  = parse.tree();
+
+    let mut edits: Vec<(TextRange, String)> = Vec::new();
+
+    for node in file.syntax().descendants() {
+        if let Some(path) = ast::Path::cast(node.clone()) {
+            if let Some(root) = old_root_of_item_path(&path, old_root, item) {
+                edits.push((root.syntax().text_range(), new_root.to_string()));
+            }
+            continue;
+        }
+        if let Some(use_item) = ast::Use::cast(node) {
+            edits.extend(split_item_out_of_group(text, &use_item, old_root, new_root, item));
+        }
+    }
+
+    if edits.is_empty() {
+        return None;
+    }
+
+    // apply back to front so earlier offsets stay valid; an insertion at the
+    // end of a `use` sorts after any edit inside it
+    edits.sort_by_key(|(range, _)| (range.start(), range.end()));
+    let mut out = text.to_string();
+    for (range, replacement) in edits.into_iter().rev() {
+        out.replace_range(usize::from(range.start())..usize::from(range.end()), &replacement);
+    }
+
+    (out != text).then_some(out)
+}
+
+/// For a path of the shape `old_root::item`, the `old_root` part, unless the path sits
+/// inside a `use` group and is therefore relative to that group's prefix.
+fn old_root_of_item_path(path: &ast::Path, old_root: &str, item: &str) -> Option<ast::Path> {
+    let root = path.qualifier()?;
+    if root.qualifier().is_some() || segment_text(&root)? != old_root {
+        return None;
+    }
+    if path.segment()?.name_ref()?.text() != item {
+        return None;
+    }
+
+    let mut outermost = path.clone();
+    while let Some(parent) = outermost.syntax().parent().and_then(ast::Path::cast) {
+        outermost = parent;
+    }
+    if let Some(use_tree) = outermost.syntax().parent().and_then(ast::UseTree::cast) {
+        let relative = use_tree
+            .syntax()
+            .parent()
+            .is_some_and(|p| ast::UseTreeList::can_cast(p.kind()));
+        if relative {
+            return None;
+        }
+    }
+
+    Some(root)
+}
+
+fn segment_text(path: &ast::Path) -> Option<String> {
+    Some(path.segment()?.syntax().text().to_string())
+}
+
+/// Edits taking `item` out of `use old_root::{..}` and adding `use new_root::item;`
+/// right after, with the same attributes, visibility and indentation.
+fn split_item_out_of_group(
+    text:     &str,
+    use_item: &ast::Use,
+    old_root: &str,
+    new_root: &str,
+    item:     &str,
+) -> Vec<(TextRange, String)> {
+    let Some(tree) = use_item.use_tree() else { return vec![] };
+    let (Some(prefix), Some(list)) = (tree.path(), tree.use_tree_list()) else { return vec![] };
+    if prefix.qualifier().is_some() || segment_text(&prefix).as_deref() != Some(old_root) {
+        return vec![];
+    }
+
+    let children: Vec<ast::UseTree> = list.use_trees().collect();
+    let Some(moved) = children.iter().find(|child| {
+        child.use_tree_list().is_none()
+            && child.star_token().is_none()
+            && child.path().is_some_and(|p| p.qualifier().is_none() && segment_text(&p).as_deref() == Some(item))
+    }) else {
+        return vec![];
+    };
+
+    let item_range  = use_item.syntax().text_range();
+    let item_start  = usize::from(item_range.start());
+    let head_end    = use_item.use_token().map(|t| usize::from(t.text_range().start())).unwrap_or(item_start);
+    let head        = &text[item_start..head_end];
+    let line_start  = text[..item_start].rfind('\n').map_or(0, |i| i + 1);
+    let indent      = &text[line_start..item_start];
+    let moved_use   = format!("{}use {}::{};", head, new_root, moved.syntax());
+
+    if children.len() == 1 {
+        return vec![(item_range, moved_use)];
+    }
+
+    vec![
+        (group_entry_range(moved), String::new()),
+        (TextRange::empty(item_range.end()), format!("\n{}{}", indent, moved_use)),
+    ]
+}
+
+/// The range covering `child` and one separating comma (the one after it, or for the
+/// last entry the one before it), so the rest of the group keeps its layout.
+fn group_entry_range(child: &ast::UseTree) -> TextRange {
+    let range = child.syntax().text_range();
+
+    let leading_ws = child
+        .syntax()
+        .prev_sibling_or_token()
+        .filter(|e| e.kind() == SyntaxKind::WHITESPACE);
+
+    let mut next = child.syntax().next_sibling_or_token();
+    while let Some(element) = next.clone() {
+        match element.kind() {
+            SyntaxKind::WHITESPACE => next = element.next_sibling_or_token(),
+            SyntaxKind::COMMA      => {
+                // `A, Item, B` / one entry per line: drop the whitespace before the entry;
+                // `{Item, B}`: drop the whitespace after the comma instead
+                return match leading_ws {
+                    Some(ws) => TextRange::new(ws.text_range().start(), element.text_range().end()),
+                    None => {
+                        let trailing_ws = element
+                            .next_sibling_or_token()
+                            .filter(|e| e.kind() == SyntaxKind::WHITESPACE);
+                        let end = trailing_ws.map_or(element.text_range().end(), |ws| ws.text_range().end());
+                        TextRange::new(range.start(), end)
+                    }
+                };
+            }
+            _ => break,
+        }
+    }
+
+    let mut prev = child.syntax().prev_sibling_or_token();
+    while let Some(element) = prev.clone() {
+        match element.kind() {
+            SyntaxKind::WHITESPACE => prev = element.prev_sibling_or_token(),
+            SyntaxKind::COMMA      => return TextRange::new(element.text_range().start(), range.end()),
+            _ => break,
+        }
+    }
+
+    range
+}
+
+#[cfg(test)]
+mod test_rewrite_use_paths {
+    use super::*;
+
+    #[test]
+    fn rewrites_plain_paths_and_groups() {
+        let text = "\
+use old_crate::Widget;
+use old_crate::{Gadget, Widget, Gizmo};
+pub(crate) use old_crate::{Widget};
+use old_crate::*;
+fn f() { let _ = old_crate::Widget::new(); let _ = my_old_crate::Widget::new(); }
+";
+        let out = rewrite_use_paths(text, "old_crate", "new_crate", "Widget").unwrap();
+        assert_eq!(out, "\
+use new_crate::Widget;
+use old_crate::{Gadget, Gizmo};
+use new_crate::Widget;
+pub(crate) use new_crate::Widget;
+use old_crate::*;
+fn f() { let _ = new_crate::Widget::new(); let _ = my_old_crate::Widget::new(); }
+");
+    }
+
+    #[test]
+    fn rewrites_multi_line_and_nested_groups() {
+        let text = "\
+mod m {
+    use old_crate::{
+        Gadget,
+        sub::{Part, Widget},
+        Widget,
+    };
+}
+";
+        let out = rewrite_use_paths(text, "old_crate", "new_crate", "Widget").unwrap();
+        assert_eq!(out, "\
+mod m {
+    use old_crate::{
+        Gadget,
+        sub::{Part, Widget},
+    };
+    use new_crate::Widget;
+}
+");
+    }
+
+    #[test]
+    fn leaves_strings_comments_macros_and_relative_paths_alone() {
+        let text = r#"
+// old_crate::Widget is documented here
+use other::{old_crate::Widget};
+macro_rules! w { () => { $crate::Widget::new() } }
+fn f() { let s = "old_crate::Widget"; info!("{}", old_crate::Widget::NAME); }
+"#;
+        assert_eq!(rewrite_use_paths(text, "old_crate", "new_crate", "Widget"), None);
+        assert_eq!(rewrite_use_paths(text, "crate", "new_crate", "Widget"), None);
+    }
+
+    #[test]
+    fn untouched_text_gives_none() {
+        assert_eq!(rewrite_use_paths("use old_crate::Widgets;\n", "old_crate", "new_crate", "Widget"), None);
+        assert_eq!(
+            rewrite_use_paths("fn f() { let w = crate::Widget::new(); }", "crate", "new_crate", "Widget").as_deref(),
+            Some("fn f() { let w = new_crate::Widget::new(); }")
+        );
+    }
+}
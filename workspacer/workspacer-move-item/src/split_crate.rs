@@ -0,0 +1,60 @@
+// ---------------- [ File: workspacer-move-item/src/split_crate.rs ]
+crate::ix!();
+
+#[async_trait]
+pub trait SplitCrate {
+    type Error;
+
+    /// Moves `items` out of `from_crate` into a new crate `new_crate`, created next to the others
+    /// (and registered in its prefix group, if any). With `dry_run` only the plan is computed,
+    /// against the crate as it would be created.
+    async fn split_crate(
+        &mut self,
+        from_crate: &str,
+        new_crate:  &str,
+        items:      &[String],
+        dry_run:    bool,
+    ) -> Result<ItemRelocationPlan, Self::Error>;
+}
+
+#[async_trait]
+impl<P,H> SplitCrate for Workspace<P,H>
+where
+    for<'async_trait> P: Debug + Clone + From<PathBuf> + AsRef<Path> + Send + Sync + 'async_trait,
+    H: CrateHandleInterface<P>
+        + EnsureAllSourceFilesAreRegistered<Error=SourceFileRegistrationError>
+        + Debug
+        + Clone
+        + Send
+        + Sync,
+    Self: AddNewCrateToWorkspace<P,H,Error=WorkspaceError> + MoveItem<Error=WorkspaceError>,
+{
+    type Error = WorkspaceError;
+
+    async fn split_crate(
+        &mut self,
+        from_crate: &str,
+        new_crate:  &str,
+        items:      &[String],
+        dry_run:    bool,
+    ) -> Result<ItemRelocationPlan, WorkspaceError> {
+        if self.find_crate_by_name(new_crate).await.is_some() {
+            return Err(ItemRelocationError::CrateAlreadyExists { crate_name: new_crate.to_string() }.into());
+        }
+
+        // check everything against the crate-to-be before creating it
+        let snapshot = snapshot_crates(self).await?;
+        let from     = snapshot.sources(from_crate)?;
+        let to       = CrateSources::empty(new_crate, self.as_ref().join(new_crate));
+        let graph    = self.generate_dependency_tree().await?;
+        let plan     = plan_item_relocation(items, from, &to, &snapshot.sources, &graph)?;
+
+        if dry_run {
+            return Ok(plan);
+        }
+
+        info!("splitting {:?} out of '{}' into new crate '{}'", items, from_crate, new_crate);
+        self.add_new_crate_to_workspace(new_crate).await?;
+        self.move_items(from_crate, new_crate, items).await
+    }
+}
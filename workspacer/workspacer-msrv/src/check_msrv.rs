@@ -0,0 +1,165 @@
+// ---------------- [ File: workspacer-msrv/src/check_msrv.rs ]
+crate::ix!();
+
+/// What `ws msrv` checks every crate against.
+#[derive(Builder,Getters,Debug,Clone)]
+#[getset(get="pub")]
+#[builder(setter(into))]
+pub struct MsrvOptions {
+    #[builder(default="vec![RustToolchain::Stable]")]
+    toolchains:  Vec<RustToolchain>,
+
+    /// Also run `cargo +<toolchain> check` wherever the static checks pass
+    #[builder(default="false")]
+    cargo_check: bool,
+}
+
+impl MsrvOptionsBuilder {
+
+    /// Starts from the `[msrv]` toolchain list.
+    pub fn from_settings(settings: &MsrvSettings) -> Result<Self, MsrvError> {
+        let mut builder = Self::default();
+        builder.toolchains(parse_toolchains(settings.toolchains())?);
+        Ok(builder)
+    }
+}
+
+/// Reports, per crate, the edition it declares, the feature gates it uses and whether each
+/// configured toolchain can build it. A crate depending on a nightly-only workspace crate is
+/// nightly-only too, so crates are visited in topological order.
+///
+/// Findings go into the report; an `Err` means the check itself could not run.
+#[async_trait]
+pub trait CheckMsrv {
+    async fn check_msrv(&self, options: &MsrvOptions) -> Result<MsrvReport, WorkspaceError>;
+}
+
+pub(crate) async fn read_manifest(path: &Path) -> Result<toml::Value, WorkspaceError> {
+    let text = tokio::fs::read_to_string(path).await.map_err(|io| WorkspaceError::IoError {
+        io_error: Arc::new(io),
+        context:  format!("reading {:?}", path),
+    })?;
+    Ok(toml::from_str(&text).map_err(|e| {
+        CrateError::CargoTomlError(CargoTomlError::TomlParseError {
+            cargo_toml_file:  path.to_path_buf(),
+            toml_parse_error: e,
+        })
+    })?)
+}
+
+/// The root manifest's `[workspace.package]`, which members may inherit fields from.
+pub(crate) async fn read_workspace_package(root: &Path) -> Result<Option<toml::Value>, WorkspaceError> {
+    let manifest = read_manifest(&root.join("Cargo.toml")).await?;
+    Ok(manifest.get("workspace").and_then(|w| w.get("package")).cloned())
+}
+
+/// Dependencies first, restricted to workspace members.
+pub(crate) async fn member_topological_order<P,H>(ws: &Workspace<P,H>) -> Result<Vec<String>, WorkspaceError>
+where
+    for<'async_trait> P: From<PathBuf> + AsRef<Path> + Send + Sync + 'async_trait,
+    H: CrateHandleInterface<P> + Send + Sync,
+    Workspace<P,H>: BasicTopologicalSort,
+{
+    let members: HashSet<String> = ws.get_all_crate_names().await.into_iter().collect();
+    let only_members = Arc::new(move |name: &str| members.contains(name))
+        as Arc<dyn Fn(&str) -> bool + Send + Sync>;
+
+    let config = TopologicalSortConfigBuilder::default()
+        .filter_fn(Some(only_members))
+        .build()
+        .expect("topological sort config has defaults for every other field");
+
+    ws.topological_order_crate_names(&config).await
+}
+
+async fn cargo_check_on(toolchain: &RustToolchain, manifest_path: &Path) -> Result<Option<String>, WorkspaceError> {
+    let output = tokio::process::Command::new("cargo")
+        .arg(format!("+{}", toolchain))
+        .arg("check")
+        .arg("--all-targets")
+        .arg("--quiet")
+        .arg("--manifest-path")
+        .arg(manifest_path)
+        .output()
+        .await
+        .map_err(|io| WorkspaceError::IoError {
+            io_error: Arc::new(io),
+            context:  format!("running `cargo +{} check` for {:?}", toolchain, manifest_path),
+        })?;
+
+    Ok((!output.status.success()).then(|| String::from_utf8_lossy(&output.stderr).to_string()))
+}
+
+#[async_trait]
+impl<P,H> CheckMsrv for Workspace<P,H>
+where
+    for<'async_trait> P: From<PathBuf> + AsRef<Path> + Send + Sync + 'async_trait,
+    H: CrateHandleInterface<P> + Send + Sync,
+    Self: BasicTopologicalSort,
+{
+    async fn check_msrv(&self, options: &MsrvOptions) -> Result<MsrvReport, WorkspaceError> {
+        let workspace_package = read_workspace_package(self.as_ref()).await?;
+        let order             = member_topological_order(self).await?;
+
+        let mut nightly_only: HashSet<String> = HashSet::new();
+        let mut profiles = vec![];
+
+        for crate_name in order {
+            let Some(handle) = self.find_crate_by_name(&crate_name).await else {
+                continue;
+            };
+            let guard      = handle.lock().await;
+            let crate_root = guard.root_dir_path_buf();
+            let manifest   = read_manifest(&crate_root.join("Cargo.toml")).await?;
+
+            let (edition, rust_version) = declared_edition_and_rust_version(&crate_name, &manifest, workspace_package.as_ref())?;
+
+            let sources = guard.source_files_excluding(&[]).await?;
+            let tests   = if guard.has_tests_directory() { guard.test_files().await? } else { vec![] };
+            let mut feature_gates = vec![];
+            for path in sources.iter().chain(tests.iter()) {
+                let text = tokio::fs::read_to_string(path).await.map_err(|io| WorkspaceError::IoError {
+                    io_error: Arc::new(io),
+                    context:  format!("reading {:?} for feature gates", path),
+                })?;
+                let relative = path.strip_prefix(&crate_root).unwrap_or(path).to_string_lossy().to_string();
+                feature_gates.extend(scan_feature_gates(&relative, &text));
+            }
+
+            let nightly_only_dependencies: Vec<String> = guard
+                .internal_dependencies()
+                .await?
+                .into_iter()
+                .filter(|d| nightly_only.contains(d))
+                .collect();
+
+            let mut profile = CrateToolchainProfileBuilder::default()
+                .crate_name(crate_name.clone())
+                .edition(edition)
+                .rust_version(rust_version)
+                .feature_gates(feature_gates)
+                .nightly_only_dependencies(nightly_only_dependencies)
+                .build()
+                .unwrap();
+
+            let mut verdicts: Vec<ToolchainVerdict> = options.toolchains().iter().map(|t| profile.judge(t)).collect();
+            if *options.cargo_check() {
+                for verdict in verdicts.iter_mut().filter(|v| v.builds()) {
+                    if let Some(stderr) = cargo_check_on(verdict.toolchain(), &crate_root.join("Cargo.toml")).await? {
+                        verdict.push_issue(ToolchainIssue::CheckFailed { stderr });
+                    }
+                }
+            }
+            *profile.verdicts_mut() = verdicts;
+
+            if profile.needs_nightly() {
+                nightly_only.insert(crate_name);
+            }
+            profiles.push(profile);
+        }
+
+        let report = MsrvReport::new(options.toolchains().clone(), profiles);
+        info!("msrv: {} crates, {} need nightly", report.crates().len(), report.nightly_only_crates().count());
+        Ok(report)
+    }
+}
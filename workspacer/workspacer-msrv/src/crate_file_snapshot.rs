@@ -0,0 +1,99 @@
+// ---------------- [ File: workspacer-msrv/src/crate_file_snapshot.rs ]
+crate::ix!();
+
+/// Every file under a crate's directory (minus `target/`), held in memory so a failed
+/// edition migration can put the crate back exactly as it was, uncommitted edits included.
+#[derive(Getters,Debug,Clone)]
+#[getset(get="pub")]
+pub struct CrateFileSnapshot {
+    root:  PathBuf,
+    files: BTreeMap<PathBuf, Vec<u8>>,
+}
+
+fn io_error(context: String) -> impl FnOnce(io::Error) -> WorkspaceError {
+    move |io| WorkspaceError::IoError { io_error: Arc::new(io), context }
+}
+
+/// Files under `root`, skipping build output and VCS metadata.
+async fn list_files(root: &Path) -> Result<Vec<PathBuf>, WorkspaceError> {
+    let mut files = vec![];
+    let mut dirs  = vec![root.to_path_buf()];
+
+    while let Some(dir) = dirs.pop() {
+        let mut entries = tokio::fs::read_dir(&dir).await.map_err(io_error(format!("listing {:?}", dir)))?;
+        while let Some(entry) = entries.next_entry().await.map_err(io_error(format!("listing {:?}", dir)))? {
+            let path = entry.path();
+            let name = entry.file_name();
+            if name == "target" || name == ".git" {
+                continue;
+            }
+            let file_type = entry.file_type().await.map_err(io_error(format!("inspecting {:?}", path)))?;
+            if file_type.is_dir() {
+                dirs.push(path);
+            } else if file_type.is_file() {
+                files.push(path);
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+impl CrateFileSnapshot {
+
+    pub async fn capture(root: &Path) -> Result<Self, WorkspaceError> {
+        let mut files = BTreeMap::new();
+        for path in list_files(root).await? {
+            let bytes = tokio::fs::read(&path).await.map_err(io_error(format!("snapshotting {:?}", path)))?;
+            files.insert(path, bytes);
+        }
+        debug!("snapshotted {} files under {:?}", files.len(), root);
+        Ok(Self { root: root.to_path_buf(), files })
+    }
+
+    /// Writes every snapshotted file back and deletes files created since.
+    pub async fn restore(&self) -> Result<(), WorkspaceError> {
+        for path in list_files(&self.root).await? {
+            if !self.files.contains_key(&path) {
+                tokio::fs::remove_file(&path).await.map_err(io_error(format!("removing {:?}", path)))?;
+            }
+        }
+        for (path, bytes) in &self.files {
+            if let Some(parent) = path.parent() {
+                tokio::fs::create_dir_all(parent).await.map_err(io_error(format!("creating {:?}", parent)))?;
+            }
+            tokio::fs::write(path, bytes).await.map_err(io_error(format!("restoring {:?}", path)))?;
+        }
+        info!("restored {} files under {:?}", self.files.len(), self.root);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_crate_file_snapshot {
+    use super::*;
+
+    #[traced_test]
+    async fn restores_edits_and_removes_new_files() {
+        let tmp  = tempdir().unwrap();
+        let root = tmp.path();
+        tokio::fs::create_dir_all(root.join("src")).await.unwrap();
+        tokio::fs::create_dir_all(root.join("target/debug")).await.unwrap();
+        tokio::fs::write(root.join("Cargo.toml"), "[package]\nedition = \"2021\"\n").await.unwrap();
+        tokio::fs::write(root.join("src/lib.rs"), "pub fn a() {}\n").await.unwrap();
+        tokio::fs::write(root.join("target/debug/out"), "build output").await.unwrap();
+
+        let snapshot = CrateFileSnapshot::capture(root).await.unwrap();
+        assert_eq!(snapshot.files().len(), 2);
+
+        tokio::fs::write(root.join("Cargo.toml"), "[package]\nedition = \"2024\"\n").await.unwrap();
+        tokio::fs::remove_file(root.join("src/lib.rs")).await.unwrap();
+        tokio::fs::write(root.join("src/new.rs"), "").await.unwrap();
+
+        snapshot.restore().await.unwrap();
+        assert_eq!(tokio::fs::read_to_string(root.join("Cargo.toml")).await.unwrap(), "[package]\nedition = \"2021\"\n");
+        assert_eq!(tokio::fs::read_to_string(root.join("src/lib.rs")).await.unwrap(), "pub fn a() {}\n");
+        assert!(!root.join("src/new.rs").exists());
+        assert!(root.join("target/debug/out").exists());
+    }
+}
@@ -0,0 +1,210 @@
+// ---------------- [ File: workspacer-msrv/src/crate_toolchain_profile.rs ]
+crate::ix!();
+
+/// Why one crate will not build on one toolchain.
+#[derive(Serialize,Debug,Clone,PartialEq,Eq)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum ToolchainIssue {
+    /// `#![feature]` gates, which only nightly accepts
+    FeatureGates {
+        features: Vec<String>,
+    },
+    EditionTooNew {
+        edition:  RustEdition,
+        requires: String,
+    },
+
+    /// The toolchain is older than the crate's declared `rust-version`
+    BelowRustVersion {
+        rust_version: String,
+    },
+
+    /// Workspace crates this one depends on that only build on nightly
+    NightlyOnlyDependencies {
+        dependencies: Vec<String>,
+    },
+
+    /// `cargo +<toolchain> check` failed (only with `--cargo-check`)
+    CheckFailed {
+        stderr: String,
+    },
+}
+
+impl fmt::Display for ToolchainIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ToolchainIssue::FeatureGates { features } => {
+                write!(f, "needs nightly for {}", features.join(", "))
+            }
+            ToolchainIssue::EditionTooNew { edition, requires } => {
+                write!(f, "edition {} needs Rust {}", edition, requires)
+            }
+            ToolchainIssue::BelowRustVersion { rust_version } => {
+                write!(f, "below the declared rust-version {}", rust_version)
+            }
+            ToolchainIssue::NightlyOnlyDependencies { dependencies } => {
+                write!(f, "depends on nightly-only {}", dependencies.join(", "))
+            }
+            ToolchainIssue::CheckFailed { stderr } => {
+                let first = stderr.lines().find(|l| l.starts_with("error")).unwrap_or("cargo check failed");
+                write!(f, "{}", first)
+            }
+        }
+    }
+}
+
+#[derive(Getters,Serialize,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct ToolchainVerdict {
+    toolchain: RustToolchain,
+    issues:    Vec<ToolchainIssue>,
+}
+
+impl ToolchainVerdict {
+
+    pub fn new(toolchain: RustToolchain, issues: Vec<ToolchainIssue>) -> Self {
+        Self { toolchain, issues }
+    }
+
+    pub fn builds(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    pub fn push_issue(&mut self, issue: ToolchainIssue) {
+        self.issues.push(issue);
+    }
+}
+
+/// A crate's edition, declared `rust-version` and feature gates, and how each configured
+/// toolchain fares with them.
+#[derive(Builder,Getters,Serialize,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+#[builder(setter(into))]
+pub struct CrateToolchainProfile {
+    crate_name:    String,
+    edition:       RustEdition,
+
+    #[builder(default)]
+    rust_version:  Option<SemverVersion>,
+
+    #[builder(default)]
+    feature_gates: Vec<FeatureGate>,
+
+    /// Workspace crates it depends on that need nightly
+    #[builder(default)]
+    nightly_only_dependencies: Vec<String>,
+
+    #[builder(default)]
+    verdicts:      Vec<ToolchainVerdict>,
+}
+
+impl CrateToolchainProfile {
+
+    /// Unconditional gates, deduplicated, in the order they first appear.
+    pub fn required_features(&self) -> Vec<String> {
+        let mut features: Vec<String> = vec![];
+        for gate in self.feature_gates.iter().filter(|g| !g.is_conditional()) {
+            if !features.contains(gate.name()) {
+                features.push(gate.name().clone());
+            }
+        }
+        features
+    }
+
+    /// Whether the crate, or a workspace crate it depends on, cannot build on stable.
+    pub fn needs_nightly(&self) -> bool {
+        !self.required_features().is_empty() || !self.nightly_only_dependencies.is_empty()
+    }
+
+    pub fn builds_everywhere(&self) -> bool {
+        self.verdicts.iter().all(ToolchainVerdict::builds)
+    }
+
+    pub fn verdicts_mut(&mut self) -> &mut Vec<ToolchainVerdict> {
+        &mut self.verdicts
+    }
+
+    /// Checks what the manifest and sources say against `toolchain`, without running it.
+    pub fn judge(&self, toolchain: &RustToolchain) -> ToolchainVerdict {
+        let mut issues = vec![];
+
+        let features = self.required_features();
+        if !features.is_empty() && !toolchain.allows_feature_gates() {
+            issues.push(ToolchainIssue::FeatureGates { features });
+        }
+        if !self.nightly_only_dependencies.is_empty() && !toolchain.allows_feature_gates() {
+            issues.push(ToolchainIssue::NightlyOnlyDependencies { dependencies: self.nightly_only_dependencies.clone() });
+        }
+
+        let first_release = self.edition.first_release();
+        if !toolchain.is_at_least(&first_release) {
+            issues.push(ToolchainIssue::EditionTooNew {
+                edition:  self.edition,
+                requires: format!("{}.{}", first_release.major, first_release.minor),
+            });
+        }
+        if let Some(rust_version) = &self.rust_version {
+            if !toolchain.is_at_least(rust_version) {
+                issues.push(ToolchainIssue::BelowRustVersion { rust_version: rust_version.to_string() });
+            }
+        }
+
+        ToolchainVerdict::new(toolchain.clone(), issues)
+    }
+}
+
+impl fmt::Display for CrateToolchainProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = if self.builds_everywhere() { "ok  " } else { "FAIL" };
+        write!(f, "{}  {} (edition {}", status, self.crate_name, self.edition)?;
+        if let Some(v) = &self.rust_version {
+            write!(f, ", rust-version {}", v)?;
+        }
+        let features = self.required_features();
+        if !features.is_empty() {
+            write!(f, ", features {}", features.join(", "))?;
+        }
+        writeln!(f, ")")?;
+
+        for verdict in self.verdicts.iter().filter(|v| !v.builds()) {
+            for issue in &verdict.issues {
+                writeln!(f, "      - {}: {}", verdict.toolchain, issue)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_crate_toolchain_profile {
+    use super::*;
+
+    #[traced_test]
+    fn judges_gates_editions_and_rust_version() {
+        let profile = CrateToolchainProfileBuilder::default()
+            .crate_name("world-region-db")
+            .edition(RustEdition::E2024)
+            .feature_gates(scan_feature_gates("src/lib.rs", "#![feature(trait_alias)]\n#![cfg_attr(docsrs, feature(doc_cfg))]\n"))
+            .build()
+            .unwrap();
+        assert!(profile.needs_nightly());
+        assert_eq!(profile.required_features(), vec!["trait_alias"]);
+
+        let stable = profile.judge(&RustToolchain::Stable);
+        assert_eq!(stable.issues(), &vec![ToolchainIssue::FeatureGates { features: vec!["trait_alias".into()] }]);
+
+        let old_nightly_free = CrateToolchainProfileBuilder::default()
+            .crate_name("a")
+            .edition(RustEdition::E2021)
+            .rust_version(Some(SemverVersion::new(1, 74, 0)))
+            .build()
+            .unwrap();
+        let v = old_nightly_free.judge(&"1.70".parse().unwrap());
+        assert_eq!(v.issues(), &vec![ToolchainIssue::BelowRustVersion { rust_version: "1.74.0".into() }]);
+        assert!(old_nightly_free.judge(&"1.74".parse().unwrap()).builds());
+
+        let too_old = profile.judge(&"1.80".parse().unwrap());
+        assert!(too_old.issues().iter().any(|i| i.to_string() == "edition 2024 needs Rust 1.85"));
+        assert!(profile.judge(&RustToolchain::Nightly { date: None }).builds());
+    }
+}
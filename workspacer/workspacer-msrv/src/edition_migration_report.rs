@@ -0,0 +1,126 @@
+// ---------------- [ File: workspacer-msrv/src/edition_migration_report.rs ]
+crate::ix!();
+
+#[derive(Serialize,Debug,Clone,PartialEq,Eq)]
+#[serde(tag = "outcome", rename_all = "kebab-case")]
+pub enum EditionMigrationOutcome {
+    AlreadyCurrent {
+        edition: RustEdition,
+    },
+    Migrated {
+        from: RustEdition,
+        to:   RustEdition,
+    },
+
+    /// A step failed; the crate's files were put back as they were before the migration
+    RolledBack {
+        from:      RustEdition,
+        failed_at: RustEdition,
+        command:   String,
+        stderr:    String,
+    },
+}
+
+#[derive(Getters,Serialize,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct CrateEditionMigration {
+    crate_name: String,
+
+    #[serde(flatten)]
+    outcome:    EditionMigrationOutcome,
+}
+
+impl CrateEditionMigration {
+
+    pub fn new(crate_name: impl Into<String>, outcome: EditionMigrationOutcome) -> Self {
+        Self { crate_name: crate_name.into(), outcome }
+    }
+
+    pub fn is_rolled_back(&self) -> bool {
+        matches!(self.outcome, EditionMigrationOutcome::RolledBack { .. })
+    }
+}
+
+impl fmt::Display for CrateEditionMigration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.outcome {
+            EditionMigrationOutcome::AlreadyCurrent { edition } => {
+                writeln!(f, "skip  {} (already on {})", self.crate_name, edition)
+            }
+            EditionMigrationOutcome::Migrated { from, to } => {
+                writeln!(f, "ok    {} {} -> {}", self.crate_name, from, to)
+            }
+            EditionMigrationOutcome::RolledBack { from, failed_at, command, stderr } => {
+                writeln!(f, "FAIL  {} {} -> {}: `{}` failed; rolled back", self.crate_name, from, failed_at, command)?;
+                for line in stderr.lines().filter(|l| l.starts_with("error")).take(5) {
+                    writeln!(f, "      {}", line)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// `ws edition migrate`: one entry per crate, in the topological order they were migrated in.
+#[derive(Getters,Serialize,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct EditionMigrationReport {
+    target: RustEdition,
+    crates: Vec<CrateEditionMigration>,
+}
+
+impl EditionMigrationReport {
+
+    pub fn new(target: RustEdition, crates: Vec<CrateEditionMigration>) -> Self {
+        Self { target, crates }
+    }
+
+    pub fn rolled_back_crates(&self) -> Vec<String> {
+        self.crates.iter().filter(|c| c.is_rolled_back()).map(|c| c.crate_name.clone()).collect()
+    }
+}
+
+impl fmt::Display for EditionMigrationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for migration in &self.crates {
+            write!(f, "{}", migration)?;
+        }
+        let migrated = self.crates.iter().filter(|c| matches!(c.outcome, EditionMigrationOutcome::Migrated { .. })).count();
+        writeln!(
+            f,
+            "{} crates migrated to edition {}, {} rolled back",
+            migrated,
+            self.target,
+            self.rolled_back_crates().len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod test_edition_migration_report {
+    use super::*;
+
+    #[traced_test]
+    fn flattens_outcomes_into_each_crate() {
+        let report = EditionMigrationReport::new(RustEdition::E2024, vec![
+            CrateEditionMigration::new("a", EditionMigrationOutcome::Migrated { from: RustEdition::E2021, to: RustEdition::E2024 }),
+            CrateEditionMigration::new("b", EditionMigrationOutcome::RolledBack {
+                from:      RustEdition::E2018,
+                failed_at: RustEdition::E2021,
+                command:   "cargo check".into(),
+                stderr:    "error[E0425]: cannot find value `x`\n".into(),
+            }),
+        ]);
+
+        assert_eq!(report.rolled_back_crates(), vec!["b"]);
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["crates"][0]["crate_name"], "a");
+        assert_eq!(json["crates"][0]["outcome"], "migrated");
+        assert_eq!(json["crates"][1]["failed_at"], "2021");
+
+        let text = report.to_string();
+        assert!(text.contains("FAIL  b 2018 -> 2021: `cargo check` failed; rolled back\n      error[E0425]"));
+        assert!(text.ends_with("1 crates migrated to edition 2024, 1 rolled back\n"));
+    }
+}
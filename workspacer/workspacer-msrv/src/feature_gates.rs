@@ -0,0 +1,164 @@
+// ---------------- [ File: workspacer-msrv/src/feature_gates.rs ]
+crate::ix!();
+
+/// One `#![feature(..)]` gate, or one inside `#![cfg_attr(<cfg>, feature(..))]`.
+#[derive(Getters,Serialize,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct FeatureGate {
+    name: String,
+    file: String,
+    line: usize,
+
+    /// The `cfg_attr` condition; a conditional gate does not keep the crate off stable
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cfg:  Option<String>,
+}
+
+impl FeatureGate {
+    pub fn is_conditional(&self) -> bool {
+        self.cfg.is_some()
+    }
+}
+
+impl fmt::Display for FeatureGate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}:{})", self.name, self.file, self.line)?;
+        if let Some(cfg) = &self.cfg {
+            write!(f, " when {}", cfg)?;
+        }
+        Ok(())
+    }
+}
+
+/// Splits `a, b(c, d), e` at the commas outside any parentheses.
+fn split_top_level(text: &str) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(text[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(text[start..].trim());
+    parts.into_iter().filter(|p| !p.is_empty()).collect()
+}
+
+/// The names inside `feature(a, b)`, or `None` if `attr` is some other attribute.
+fn feature_names(attr: &str) -> Option<Vec<String>> {
+    let args = attr.strip_prefix("feature")?.trim_start().strip_prefix('(')?.strip_suffix(')')?;
+    Some(split_top_level(args).into_iter().map(str::to_string).collect())
+}
+
+/// Every feature gate in one source file. Comments and string literals are skipped; the text
+/// is not otherwise parsed, so a file that does not compile still gets scanned.
+pub fn scan_feature_gates(file: &str, text: &str) -> Vec<FeatureGate> {
+    let bytes = text.as_bytes();
+    let mut gates = vec![];
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let rest = &text[i..];
+
+        if rest.starts_with("//") {
+            i += rest.find('\n').unwrap_or(rest.len());
+        } else if rest.starts_with("/*") {
+            i += rest[2..].find("*/").map(|e| e + 4).unwrap_or(rest.len());
+        } else if rest.starts_with("'\"'") {
+            // a `'"'` char literal, not the start of a string
+            i += 3;
+        } else if rest.starts_with('"') {
+            let mut j = 1;
+            while j < rest.len() && rest.as_bytes()[j] != b'"' {
+                j += if rest.as_bytes()[j] == b'\\' { 2 } else { 1 };
+            }
+            i += (j + 1).min(rest.len());
+        } else if rest.starts_with("#![") {
+            // find the `]` closing this attribute
+            let mut depth = 0usize;
+            let mut end   = None;
+            for (k, c) in rest.char_indices().skip(2) {
+                match c {
+                    '[' | '(' => depth += 1,
+                    ']' | ')' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            end = Some(k);
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            let Some(end) = end else { break };
+
+            let line = text[..i].matches('\n').count() + 1;
+            let attr = rest[3..end].split_whitespace().collect::<Vec<_>>().join(" ");
+            gates.extend(gates_in_attribute(file, line, &attr));
+            i += end + 1;
+        } else {
+            i += rest.chars().next().map(char::len_utf8).unwrap_or(1);
+        }
+    }
+
+    gates
+}
+
+fn gates_in_attribute(file: &str, line: usize, attr: &str) -> Vec<FeatureGate> {
+    let gate = |name: String, cfg: Option<String>| FeatureGate { name, file: file.to_string(), line, cfg };
+
+    if let Some(names) = feature_names(attr) {
+        return names.into_iter().map(|n| gate(n, None)).collect();
+    }
+
+    let Some(args) = attr.strip_prefix("cfg_attr").map(str::trim_start).and_then(|a| a.strip_prefix('(')).and_then(|a| a.strip_suffix(')')) else {
+        return vec![];
+    };
+    let parts = split_top_level(args);
+    let Some((cfg, attrs)) = parts.split_first() else {
+        return vec![];
+    };
+
+    attrs
+        .iter()
+        .filter_map(|a| feature_names(a))
+        .flatten()
+        .map(|n| gate(n, Some(cfg.to_string())))
+        .collect()
+}
+
+#[cfg(test)]
+mod test_feature_gates {
+    use super::*;
+
+    #[traced_test]
+    fn finds_plain_and_conditional_gates() {
+        let text = r##"#![feature(trait_alias)]
+#![allow(dead_code)]
+#![feature(
+    let_chains,
+    try_blocks,
+)]
+// #![feature(commented_out)]
+/* #![feature(also_commented)] */
+const DOC: &str = "#![feature(in_a_string)]";
+#![cfg_attr(feature = "nightly", feature(portable_simd), allow(unused))]
+"##;
+        let gates = scan_feature_gates("src/lib.rs", text);
+        let found: Vec<(String, usize, bool)> = gates.iter().map(|g| (g.name().clone(), *g.line(), g.is_conditional())).collect();
+        assert_eq!(found, vec![
+            ("trait_alias".to_string(), 1, false),
+            ("let_chains".to_string(), 3, false),
+            ("try_blocks".to_string(), 3, false),
+            ("portable_simd".to_string(), 10, true),
+        ]);
+        assert_eq!(gates[3].cfg().as_deref(), Some("feature = \"nightly\""));
+        assert_eq!(gates[3].to_string(), "portable_simd (src/lib.rs:10) when feature = \"nightly\"");
+    }
+}
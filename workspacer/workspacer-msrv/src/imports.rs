@@ -0,0 +1,9 @@
+// ---------------- [ File: workspacer-msrv/src/imports.rs ]
+pub(crate) use workspacer_3p::*;
+pub(crate) use workspacer_config::*;
+pub(crate) use workspacer_crate_interface::*;
+pub(crate) use workspacer_errors::*;
+pub(crate) use workspacer_topo::*;
+pub(crate) use workspacer_workspace::*;
+pub(crate) use workspacer_workspace_interface::*;
+pub(crate) use ::serde_derive::Serialize;
@@ -0,0 +1,12 @@
+// ---------------- [ File: workspacer-msrv/src/lib.rs ]
+#[macro_use] mod imports; use imports::*;
+
+x!{rust_edition}
+x!{rust_toolchain}
+x!{feature_gates}
+x!{crate_toolchain_profile}
+x!{msrv_report}
+x!{check_msrv}
+x!{crate_file_snapshot}
+x!{edition_migration_report}
+x!{migrate_edition}
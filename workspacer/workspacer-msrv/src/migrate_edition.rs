@@ -0,0 +1,146 @@
+// ---------------- [ File: workspacer-msrv/src/migrate_edition.rs ]
+crate::ix!();
+
+/// Moves crates to a newer edition one edition at a time: `cargo fix --edition`, then the new
+/// `edition` in Cargo.toml, then `cargo check`.
+///
+/// For a workspace this goes crate by crate in topological order (dependencies first). A crate
+/// whose migration fails at any step is restored from a snapshot taken before its first step,
+/// and the remaining crates are still migrated.
+#[async_trait]
+pub trait MigrateEdition {
+    async fn migrate_edition(&self, target: RustEdition) -> Result<EditionMigrationReport, WorkspaceError>;
+}
+
+/// Sets `package.edition`, replacing an inherited `edition.workspace = true`, and keeps the
+/// rest of the manifest as written.
+pub fn set_manifest_edition(manifest: &str, edition: RustEdition) -> Result<String, String> {
+    let mut doc = manifest.parse::<toml_edit::DocumentMut>().map_err(|e| e.to_string())?;
+    let package = doc.get_mut("package").and_then(|p| p.as_table_like_mut()).ok_or("no [package] table")?;
+    package.insert("edition", toml_edit::value(edition.as_str()));
+    Ok(doc.to_string())
+}
+
+async fn run_cargo(args: &[&str], manifest_path: &Path) -> Result<Option<String>, WorkspaceError> {
+    let output = tokio::process::Command::new("cargo")
+        .args(args)
+        .arg("--manifest-path")
+        .arg(manifest_path)
+        .output()
+        .await
+        .map_err(|io| WorkspaceError::IoError {
+            io_error: Arc::new(io),
+            context:  format!("running `cargo {}` for {:?}", args.join(" "), manifest_path),
+        })?;
+
+    Ok((!output.status.success()).then(|| String::from_utf8_lossy(&output.stderr).to_string()))
+}
+
+/// Steps one crate from `from` up to `target`, returning the edition and command that failed.
+async fn migrate_steps(manifest_path: &Path, from: RustEdition, target: RustEdition) -> Result<Option<(RustEdition, String, String)>, WorkspaceError> {
+    const FIX:   &[&str] = &["fix", "--edition", "--allow-dirty", "--allow-staged", "--all-targets"];
+    const CHECK: &[&str] = &["check", "--all-targets"];
+
+    let mut current = from;
+    while current < target {
+        let next = current.next().expect("an edition below the target has a successor");
+
+        if let Some(stderr) = run_cargo(FIX, manifest_path).await? {
+            return Ok(Some((next, format!("cargo {}", FIX.join(" ")), stderr)));
+        }
+
+        let text = tokio::fs::read_to_string(manifest_path).await.map_err(|io| WorkspaceError::IoError {
+            io_error: Arc::new(io),
+            context:  format!("reading {:?}", manifest_path),
+        })?;
+        let updated = match set_manifest_edition(&text, next) {
+            Ok(updated) => updated,
+            Err(message) => return Ok(Some((next, "set edition in Cargo.toml".to_string(), message))),
+        };
+        tokio::fs::write(manifest_path, updated).await.map_err(|io| WorkspaceError::IoError {
+            io_error: Arc::new(io),
+            context:  format!("writing {:?}", manifest_path),
+        })?;
+
+        if let Some(stderr) = run_cargo(CHECK, manifest_path).await? {
+            return Ok(Some((next, format!("cargo {}", CHECK.join(" ")), stderr)));
+        }
+
+        debug!("{:?} is on edition {}", manifest_path, next);
+        current = next;
+    }
+    Ok(None)
+}
+
+#[async_trait]
+impl<P,H> MigrateEdition for Workspace<P,H>
+where
+    for<'async_trait> P: From<PathBuf> + AsRef<Path> + Send + Sync + 'async_trait,
+    H: CrateHandleInterface<P> + Send + Sync,
+    Self: BasicTopologicalSort,
+{
+    async fn migrate_edition(&self, target: RustEdition) -> Result<EditionMigrationReport, WorkspaceError> {
+        let workspace_package = read_workspace_package(self.as_ref()).await?;
+        let order             = member_topological_order(self).await?;
+        debug!("migrating to edition {} in topological order: {:?}", target, order);
+
+        let mut migrations = vec![];
+        for crate_name in order {
+            let Some(handle) = self.find_crate_by_name(&crate_name).await else {
+                continue;
+            };
+            let guard         = handle.lock().await;
+            let crate_root    = guard.root_dir_path_buf();
+            let manifest_path = crate_root.join("Cargo.toml");
+
+            let manifest  = read_manifest(&manifest_path).await?;
+            let (from, _) = declared_edition_and_rust_version(&crate_name, &manifest, workspace_package.as_ref())?;
+            if from >= target {
+                migrations.push(CrateEditionMigration::new(crate_name, EditionMigrationOutcome::AlreadyCurrent { edition: from }));
+                continue;
+            }
+
+            info!("migrating crate='{}' from edition {} to {}", crate_name, from, target);
+            let snapshot = CrateFileSnapshot::capture(&crate_root).await?;
+
+            let outcome = match migrate_steps(&manifest_path, from, target).await {
+                Ok(None) => EditionMigrationOutcome::Migrated { from, to: target },
+                Ok(Some((failed_at, command, stderr))) => {
+                    warn!("edition migration of crate='{}' failed at {}; rolling back", crate_name, failed_at);
+                    snapshot.restore().await?;
+                    EditionMigrationOutcome::RolledBack { from, failed_at, command, stderr }
+                }
+                Err(e) => {
+                    // cargo could not be run or the manifest could not be read/written:
+                    // leave the crate as it was before giving up
+                    warn!("edition migration of crate='{}' errored: {:?}; rolling back", crate_name, e);
+                    snapshot.restore().await?;
+                    return Err(e);
+                }
+            };
+            migrations.push(CrateEditionMigration::new(crate_name, outcome));
+        }
+
+        Ok(EditionMigrationReport::new(target, migrations))
+    }
+}
+
+#[cfg(test)]
+mod test_migrate_edition {
+    use super::*;
+
+    #[traced_test]
+    fn sets_edition_and_keeps_the_rest() {
+        let manifest = "[package]\nname = \"a\"  # the crate\nedition = \"2021\"\n\n[dependencies]\nserde = \"1\"\n";
+        assert_eq!(
+            set_manifest_edition(manifest, RustEdition::E2024).unwrap(),
+            "[package]\nname = \"a\"  # the crate\nedition = \"2024\"\n\n[dependencies]\nserde = \"1\"\n"
+        );
+
+        let inherited = "[package]\nname = \"b\"\nedition.workspace = true\n";
+        let updated: toml::Value = toml::from_str(&set_manifest_edition(inherited, RustEdition::E2024).unwrap()).unwrap();
+        assert_eq!(updated["package"]["edition"].as_str(), Some("2024"));
+
+        assert!(set_manifest_edition("[workspace]\n", RustEdition::E2024).is_err());
+    }
+}
@@ -0,0 +1,77 @@
+// ---------------- [ File: workspacer-msrv/src/msrv_report.rs ]
+crate::ix!();
+
+/// `ws msrv`: one profile per crate, in topological order.
+#[derive(Getters,Serialize,Debug,Clone,Default,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct MsrvReport {
+    toolchains: Vec<RustToolchain>,
+    crates:     Vec<CrateToolchainProfile>,
+}
+
+impl MsrvReport {
+
+    pub fn new(toolchains: Vec<RustToolchain>, crates: Vec<CrateToolchainProfile>) -> Self {
+        Self { toolchains, crates }
+    }
+
+    /// Crates that fail on at least one configured toolchain.
+    pub fn failing_crates(&self) -> impl Iterator<Item=&CrateToolchainProfile> {
+        self.crates.iter().filter(|c| !c.builds_everywhere())
+    }
+
+    /// Crates that cannot build on stable at all, whatever the configured list says.
+    pub fn nightly_only_crates(&self) -> impl Iterator<Item=&CrateToolchainProfile> {
+        self.crates.iter().filter(|c| c.needs_nightly())
+    }
+}
+
+impl fmt::Display for MsrvReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for profile in &self.crates {
+            write!(f, "{}", profile)?;
+        }
+
+        let toolchains: Vec<String> = self.toolchains.iter().map(|t| t.to_string()).collect();
+        let nightly_only = self.nightly_only_crates().count();
+        if nightly_only > 0 {
+            writeln!(f, "{} of {} crates need nightly", nightly_only, self.crates.len())?;
+        }
+
+        match self.failing_crates().count() {
+            0 => writeln!(f, "all {} crates build on {}", self.crates.len(), toolchains.join(", ")),
+            n => writeln!(f, "{} of {} crates do not build on every toolchain of {}", n, self.crates.len(), toolchains.join(", ")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_msrv_report {
+    use super::*;
+
+    #[traced_test]
+    fn serializes_toolchains_and_issues() {
+        let mut profile = CrateToolchainProfileBuilder::default()
+            .crate_name("a")
+            .edition(RustEdition::E2024)
+            .feature_gates(scan_feature_gates("src/lib.rs", "#![feature(trait_alias)]"))
+            .build()
+            .unwrap();
+        let toolchains = vec![RustToolchain::Stable, RustToolchain::Nightly { date: None }];
+        let verdicts: Vec<ToolchainVerdict> = toolchains.iter().map(|t| profile.judge(t)).collect();
+        *profile.verdicts_mut() = verdicts;
+
+        let report = MsrvReport::new(toolchains, vec![profile]);
+        assert_eq!(report.failing_crates().count(), 1);
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["toolchains"][1], "nightly");
+        assert_eq!(json["crates"][0]["edition"], "2024");
+        assert_eq!(json["crates"][0]["verdicts"][0]["issues"][0]["kind"], "feature-gates");
+        assert_eq!(json["crates"][0]["feature_gates"][0]["line"], 1);
+
+        let text = report.to_string();
+        assert!(text.contains("FAIL  a (edition 2024, features trait_alias)\n      - stable: needs nightly for trait_alias\n"));
+        assert!(text.ends_with("1 of 1 crates do not build on every toolchain of stable, nightly\n"));
+    }
+}
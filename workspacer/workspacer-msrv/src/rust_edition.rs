@@ -0,0 +1,137 @@
+// ---------------- [ File: workspacer-msrv/src/rust_edition.rs ]
+crate::ix!();
+
+/// A Rust edition as Cargo.toml spells it. A crate without `edition` is on 2015.
+#[derive(Debug,Clone,Copy,PartialEq,Eq,PartialOrd,Ord,Hash)]
+pub enum RustEdition {
+    E2015,
+    E2018,
+    E2021,
+    E2024,
+}
+
+impl RustEdition {
+
+    pub const LATEST: RustEdition = RustEdition::E2024;
+
+    /// The first stable release that accepts this edition.
+    pub fn first_release(&self) -> SemverVersion {
+        match self {
+            RustEdition::E2015 => SemverVersion::new(1, 0, 0),
+            RustEdition::E2018 => SemverVersion::new(1, 31, 0),
+            RustEdition::E2021 => SemverVersion::new(1, 56, 0),
+            RustEdition::E2024 => SemverVersion::new(1, 85, 0),
+        }
+    }
+
+    /// The edition `cargo fix --edition` migrates this one to.
+    pub fn next(&self) -> Option<RustEdition> {
+        match self {
+            RustEdition::E2015 => Some(RustEdition::E2018),
+            RustEdition::E2018 => Some(RustEdition::E2021),
+            RustEdition::E2021 => Some(RustEdition::E2024),
+            RustEdition::E2024 => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RustEdition::E2015 => "2015",
+            RustEdition::E2018 => "2018",
+            RustEdition::E2021 => "2021",
+            RustEdition::E2024 => "2024",
+        }
+    }
+}
+
+impl FromStr for RustEdition {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "2015" => Ok(RustEdition::E2015),
+            "2018" => Ok(RustEdition::E2018),
+            "2021" => Ok(RustEdition::E2021),
+            "2024" => Ok(RustEdition::E2024),
+            other  => Err(format!("unknown edition '{}' (expected 2015, 2018, 2021 or 2024)", other)),
+        }
+    }
+}
+
+impl fmt::Display for RustEdition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Serialize for RustEdition {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// The edition and `rust-version` a manifest declares, with `field.workspace = true` resolved
+/// against the root `[workspace.package]`.
+pub fn declared_edition_and_rust_version(
+    crate_name:        &str,
+    manifest:          &toml::Value,
+    workspace_package: Option<&toml::Value>,
+) -> Result<(RustEdition, Option<SemverVersion>), MsrvError> {
+    let field = |key: &str| -> Option<String> {
+        let value = manifest.get("package")?.get(key)?;
+        let value = match value.get("workspace").and_then(|w| w.as_bool()) {
+            Some(true) => workspace_package?.get(key)?,
+            _          => value,
+        };
+        value.as_str().map(str::to_string)
+    };
+
+    let edition = match field("edition") {
+        None    => RustEdition::E2015,
+        Some(e) => e.parse().map_err(|_| MsrvError::UnknownEdition {
+            crate_name: crate_name.to_string(),
+            edition:    e.clone(),
+        })?,
+    };
+
+    // `rust-version = "1.70"` leaves out the patch number
+    let rust_version = field("rust-version").and_then(|v| {
+        let full = if v.matches('.').count() == 1 { format!("{}.0", v) } else { v.clone() };
+        SemverVersion::parse(&full).ok()
+    });
+
+    Ok((edition, rust_version))
+}
+
+#[cfg(test)]
+mod test_rust_edition {
+    use super::*;
+
+    #[traced_test]
+    fn steps_through_editions_in_order() {
+        assert_eq!("2021".parse::<RustEdition>(), Ok(RustEdition::E2021));
+        assert!("2027".parse::<RustEdition>().is_err());
+        assert_eq!(RustEdition::E2018.next(), Some(RustEdition::E2021));
+        assert_eq!(RustEdition::LATEST.next(), None);
+        assert!(RustEdition::E2021 < RustEdition::E2024);
+        assert_eq!(RustEdition::E2024.first_release(), SemverVersion::new(1, 85, 0));
+    }
+
+    #[traced_test]
+    fn resolves_inherited_edition_and_short_rust_version() {
+        let root: toml::Value = toml::from_str("[workspace.package]\nedition = \"2024\"\nrust-version = \"1.85\"\n").unwrap();
+        let workspace_package = root.get("workspace").and_then(|w| w.get("package"));
+
+        let inherited: toml::Value = toml::from_str("[package]\nedition.workspace = true\nrust-version.workspace = true\n").unwrap();
+        assert_eq!(
+            declared_edition_and_rust_version("a", &inherited, workspace_package).unwrap(),
+            (RustEdition::E2024, Some(SemverVersion::new(1, 85, 0)))
+        );
+
+        let bare: toml::Value = toml::from_str("[package]\nname = \"b\"\n").unwrap();
+        assert_eq!(declared_edition_and_rust_version("b", &bare, None).unwrap(), (RustEdition::E2015, None));
+
+        let bad: toml::Value = toml::from_str("[package]\nedition = \"2019\"\n").unwrap();
+        assert!(matches!(declared_edition_and_rust_version("c", &bad, None), Err(MsrvError::UnknownEdition { .. })));
+    }
+}
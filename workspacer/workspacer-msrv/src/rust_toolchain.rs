@@ -0,0 +1,115 @@
+// ---------------- [ File: workspacer-msrv/src/rust_toolchain.rs ]
+crate::ix!();
+
+/// A toolchain from `[msrv] toolchains`, named the way `cargo +<toolchain>` takes it.
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub enum RustToolchain {
+    Stable,
+    Beta,
+
+    /// `nightly`, or a dated `nightly-YYYY-MM-DD`
+    Nightly {
+        date: Option<String>,
+    },
+
+    /// A pinned release such as `1.85` or `1.85.1`
+    Release(SemverVersion),
+}
+
+impl RustToolchain {
+
+    /// Only nightly accepts `#![feature]`.
+    pub fn allows_feature_gates(&self) -> bool {
+        matches!(self, RustToolchain::Nightly { .. })
+    }
+
+    /// Whether the toolchain is known to be at least `version`. The channels track the latest
+    /// release, so they are assumed to be.
+    pub fn is_at_least(&self, version: &SemverVersion) -> bool {
+        match self {
+            RustToolchain::Release(release) => (release.major, release.minor) >= (version.major, version.minor),
+            _                               => true,
+        }
+    }
+}
+
+impl FromStr for RustToolchain {
+    type Err = MsrvError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || MsrvError::InvalidToolchain { toolchain: s.to_string() };
+        let s = s.trim();
+
+        match s {
+            "stable"  => return Ok(RustToolchain::Stable),
+            "beta"    => return Ok(RustToolchain::Beta),
+            "nightly" => return Ok(RustToolchain::Nightly { date: None }),
+            _         => {}
+        }
+
+        if let Some(date) = s.strip_prefix("nightly-") {
+            let well_formed = date.len() == 10
+                && date.split('-').map(str::len).eq([4, 2, 2])
+                && date.chars().all(|c| c.is_ascii_digit() || c == '-');
+            return if well_formed { Ok(RustToolchain::Nightly { date: Some(date.to_string()) }) } else { Err(invalid()) };
+        }
+
+        let full = if s.matches('.').count() == 1 { format!("{}.0", s) } else { s.to_string() };
+        match SemverVersion::parse(&full) {
+            Ok(v) if v.major == 1 && v.pre.is_empty() => Ok(RustToolchain::Release(v)),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl fmt::Display for RustToolchain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RustToolchain::Stable                     => write!(f, "stable"),
+            RustToolchain::Beta                       => write!(f, "beta"),
+            RustToolchain::Nightly { date: None }     => write!(f, "nightly"),
+            RustToolchain::Nightly { date: Some(d) }  => write!(f, "nightly-{}", d),
+            RustToolchain::Release(v) if v.patch == 0 => write!(f, "{}.{}", v.major, v.minor),
+            RustToolchain::Release(v)                 => write!(f, "{}", v),
+        }
+    }
+}
+
+impl Serialize for RustToolchain {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Parses the `[msrv] toolchains` list, failing on the first name `cargo +` would not take.
+pub fn parse_toolchains(names: &[String]) -> Result<Vec<RustToolchain>, MsrvError> {
+    names.iter().map(|n| n.parse()).collect()
+}
+
+#[cfg(test)]
+mod test_rust_toolchain {
+    use super::*;
+
+    #[traced_test]
+    fn parses_channels_dated_nightlies_and_releases() {
+        assert_eq!("stable".parse::<RustToolchain>().unwrap(), RustToolchain::Stable);
+        assert_eq!("1.85".parse::<RustToolchain>().unwrap(), RustToolchain::Release(SemverVersion::new(1, 85, 0)));
+        assert_eq!("1.85.1".parse::<RustToolchain>().unwrap().to_string(), "1.85.1");
+        assert_eq!("nightly-2026-01-31".parse::<RustToolchain>().unwrap().to_string(), "nightly-2026-01-31");
+
+        for bad in ["nightly-26-01-31", "2.0", "1.85.0-beta.1", "latest"] {
+            assert!(bad.parse::<RustToolchain>().is_err(), "{}", bad);
+        }
+    }
+
+    #[traced_test]
+    fn only_nightly_allows_feature_gates() {
+        assert!(RustToolchain::Nightly { date: None }.allows_feature_gates());
+        assert!(!RustToolchain::Stable.allows_feature_gates());
+
+        let old = RustToolchain::Release(SemverVersion::new(1, 70, 0));
+        assert!(!old.is_at_least(&RustEdition::E2024.first_release()));
+        assert!(old.is_at_least(&SemverVersion::new(1, 70, 3)));
+        assert!(RustToolchain::Beta.is_at_least(&SemverVersion::new(1, 99, 0)));
+    }
+}
@@ -0,0 +1,5 @@
+// ---------------- [ File: workspacer-prefix-group/src/imports.rs ]
+pub(crate) use workspacer_3p::*;
+pub(crate) use workspacer_crate_interface::*;
+pub(crate) use workspacer_errors::*;
+pub(crate) use workspacer_workspace::*;
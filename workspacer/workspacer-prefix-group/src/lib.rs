@@ -0,0 +1,9 @@
+// ---------------- [ File: workspacer-prefix-group/src/lib.rs ]
+#[macro_use] mod imports; use imports::*;
+
+x!{unified_diff}
+x!{manifest_edits}
+x!{prefix_group_edit_plan}
+x!{plan_new_prefix_group}
+x!{plan_prefix_group_fix}
+x!{prefix_group_scaffolding}
@@ -0,0 +1,149 @@
+// ---------------- [ File: workspacer-prefix-group/src/manifest_edits.rs ]
+crate::ix!();
+
+fn parse_manifest(path: &Path, text: &str) -> Result<TomlEditDocument, PrefixGroupError> {
+    text.parse::<TomlEditDocument>().map_err(|e| PrefixGroupError::UnparseableCargoToml {
+        path:    path.to_path_buf(),
+        message: e.to_string(),
+    })
+}
+
+/// Whether the manifest at `path` lists `dep_name` under `[dependencies]`.
+pub fn manifest_has_dependency(path: &Path, text: &str, dep_name: &str) -> Result<bool, PrefixGroupError> {
+    let doc = parse_manifest(path, text)?;
+    Ok(doc.get("dependencies").and_then(|d| d.as_table_like()).is_some_and(|d| d.contains_key(dep_name)))
+}
+
+/// Adds `[dependencies.<dep_name>]` with `path` (and `version`, when known, so the crate stays
+/// publishable), the layout the workspace crates use. Returns `None` when the dependency is
+/// already there.
+pub fn add_path_dependency(
+    path:     &Path,
+    text:     &str,
+    dep_name: &str,
+    rel_path: &Path,
+    version:  Option<&str>,
+) -> Result<Option<String>, PrefixGroupError> {
+    let mut doc = parse_manifest(path, text)?;
+
+    if doc.get("dependencies").is_none() {
+        let mut deps = toml_edit::Table::new();
+        deps.set_implicit(true);
+        doc["dependencies"] = toml_edit::Item::Table(deps);
+    }
+    let Some(deps) = doc["dependencies"].as_table_like_mut() else {
+        return Err(PrefixGroupError::UnparseableCargoToml {
+            path:    path.to_path_buf(),
+            message: "[dependencies] is not a table".to_string(),
+        });
+    };
+    if deps.contains_key(dep_name) {
+        return Ok(None);
+    }
+
+    let mut dep = toml_edit::Table::new();
+    dep.insert("path", toml_edit::value(rel_path.to_string_lossy().to_string()));
+    if let Some(version) = version {
+        dep.insert("version", toml_edit::value(version));
+    }
+    deps.insert(dep_name, toml_edit::Item::Table(dep));
+
+    Ok(Some(doc.to_string()))
+}
+
+/// Appends `members` missing from `[workspace].members` of the root manifest. Returns `None`
+/// when every one is already listed.
+pub fn add_workspace_members(path: &Path, text: &str, members: &[String]) -> Result<Option<String>, PrefixGroupError> {
+    let mut doc = parse_manifest(path, text)?;
+
+    let Some(arr) = doc
+        .get_mut("workspace")
+        .and_then(|w| w.as_table_like_mut())
+        .and_then(|w| {
+            if w.get("members").is_none() {
+                w.insert("members", TomlEditItem::Value(TomlEditValue::Array(TomlEditArray::new())));
+            }
+            w.get_mut("members")
+        })
+        .and_then(|m| m.as_array_mut())
+    else {
+        return Err(PrefixGroupError::UnparseableCargoToml {
+            path:    path.to_path_buf(),
+            message: "no [workspace].members array".to_string(),
+        });
+    };
+
+    let mut changed = false;
+    for member in members {
+        if !arr.iter().any(|v| v.as_str() == Some(member.as_str())) {
+            arr.push(member.as_str());
+            changed = true;
+        }
+    }
+    Ok(changed.then(|| doc.to_string()))
+}
+
+/// The `[workspace.dependencies]` entry names of the root manifest.
+pub fn workspace_dependency_names(path: &Path, text: &str) -> Result<BTreeSet<String>, PrefixGroupError> {
+    let doc = parse_manifest(path, text)?;
+    Ok(doc
+        .get("workspace")
+        .and_then(|w| w.get("dependencies"))
+        .and_then(|d| d.as_table_like())
+        .map(|d| d.iter().map(|(k, _)| k.to_string()).collect())
+        .unwrap_or_default())
+}
+
+/// Appends `line` unless a non-comment line of `text` already reads exactly that.
+pub fn ensure_line(text: &str, line: &str) -> Option<String> {
+    let present = text
+        .lines()
+        .map(str::trim)
+        .any(|l| !l.starts_with("//") && l == line.trim());
+    if present {
+        return None;
+    }
+
+    let mut out = text.to_string();
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+    out.push_str(line.trim());
+    out.push('\n');
+    Some(out)
+}
+
+#[cfg(test)]
+mod test_manifest_edits {
+    use super::*;
+
+    #[traced_test]
+    fn adds_dependency_tables_once() {
+        let path = Path::new("foo-bar/Cargo.toml");
+        let text = "[package]\nname = \"foo-bar\"\n";
+
+        let once = add_path_dependency(path, text, "foo-3p", Path::new("../foo-3p"), Some("0.1.0")).unwrap().unwrap();
+        assert!(once.contains("[dependencies.foo-3p]\npath = \"../foo-3p\"\nversion = \"0.1.0\""), "{}", once);
+        assert!(manifest_has_dependency(path, &once, "foo-3p").unwrap());
+        assert_eq!(add_path_dependency(path, &once, "foo-3p", Path::new("../foo-3p"), None).unwrap(), None);
+
+        let inline = "[package]\nname = \"x\"\n\n[dependencies]\nfoo-3p = { path = \"../foo-3p\" }\n";
+        assert_eq!(add_path_dependency(path, inline, "foo-3p", Path::new("../foo-3p"), None).unwrap(), None);
+    }
+
+    #[traced_test]
+    fn adds_missing_workspace_members() {
+        let path = Path::new("Cargo.toml");
+        let text = "[workspace]\nmembers = [\"a\", \"foo\"]\n";
+        let out = add_workspace_members(path, text, &["foo".into(), "foo-3p".into()]).unwrap().unwrap();
+        assert_eq!(out, "[workspace]\nmembers = [\"a\", \"foo\", \"foo-3p\"]\n");
+        assert_eq!(add_workspace_members(path, &out, &["foo-3p".into()]).unwrap(), None);
+    }
+
+    #[traced_test]
+    fn ensures_lines() {
+        assert_eq!(ensure_line("pub use a::*;", "pub use b::*;").as_deref(), Some("pub use a::*;\npub use b::*;\n"));
+        assert_eq!(ensure_line("  pub use b::*;\n", "pub use b::*;"), None);
+        assert_eq!(ensure_line("// pub use b::*;\n", "pub use b::*;").as_deref(), Some("// pub use b::*;\npub use b::*;\n"));
+    }
+}
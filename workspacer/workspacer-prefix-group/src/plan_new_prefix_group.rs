@@ -0,0 +1,178 @@
+// ---------------- [ File: workspacer-prefix-group/src/plan_new_prefix_group.rs ]
+crate::ix!();
+
+/// `name` is the facade `prefix` itself or one of its `prefix-*` crates.
+pub fn belongs_to_prefix(name: &str, prefix: &str) -> bool {
+    name == prefix || name.strip_prefix(prefix).is_some_and(|rest| rest.starts_with('-'))
+}
+
+pub fn validate_prefix(prefix: &str) -> Result<(), PrefixGroupError> {
+    let valid = Regex::new(r"^[a-z0-9_]+(-[a-z0-9_]+)*$").expect("valid regex");
+    if valid.is_match(prefix) && !prefix.ends_with("-3p") {
+        Ok(())
+    } else {
+        Err(PrefixGroupError::InvalidPrefix { prefix: prefix.to_string() })
+    }
+}
+
+/// The script members get created with; it only wraps `ws add crate`, which registers the
+/// new crate in the facade and makes it depend on the `-3p` crate.
+pub fn create_member_script(prefix: &str) -> String {
+    formatdoc! {r#"
+        #!/bin/sh
+        # Creates `{prefix}-<name>`, registered in the `{prefix}` facade and depending on `{prefix}-3p`.
+        set -e
+        [ -n "$1" ] || {{ echo "usage: $0 <name>" >&2; exit 1; }}
+        exec ws add crate --crate "{prefix}-$1" --workspace "$(dirname "$0")/.."
+    "#}
+}
+
+fn package_manifest(name: &str, description: &str, dependencies: &str) -> String {
+    formatdoc! {r#"
+        [package]
+        name = "{name}"
+        version = "0.1.0"
+        authors = ["YourName <you@example.com>"]
+        license = "MIT"
+        edition = "2024"
+        description = "{description}"
+
+        # keywords = []
+        # categories = []
+
+        [dependencies]
+        {dependencies}"#}
+}
+
+/// Plans the layout batch-mode and hydro2 use for a prefix group: the `<prefix>` facade,
+/// `<prefix>-3p` for third-party re-exports, and a `u/create-<prefix>-crate` script, all
+/// added to `[workspace].members`. Only the missing parts are created.
+pub fn plan_new_prefix_group(
+    root:          &Path,
+    prefix:        &str,
+    root_manifest: &str,
+) -> Result<PrefixGroupEditPlan, PrefixGroupError> {
+    validate_prefix(prefix)?;
+
+    let facade  = prefix.to_string();
+    let three_p = format!("{}-3p", prefix);
+    let script  = root.join("u").join(format!("create-{}-crate", prefix));
+
+    let facade_exists  = root.join(&facade).exists();
+    let three_p_exists = root.join(&three_p).exists();
+    if facade_exists && three_p_exists && script.exists() {
+        return Err(PrefixGroupError::PrefixGroupAlreadyExists { prefix: prefix.to_string(), path: root.join(&facade) });
+    }
+
+    let root_manifest_path = root.join("Cargo.toml");
+    let mut plan    = PrefixGroupEditPlan::new(root);
+    let mut created = vec![];
+
+    if !facade_exists {
+        let dir = root.join(&facade);
+        plan.create(
+            dir.join("Cargo.toml"),
+            package_manifest(&facade, &format!("Facade crate re-exporting the `{}` crates.", prefix), ""),
+            false,
+        );
+        plan.create(dir.join("src").join("lib.rs"), format!("// ---------------- [ File: {}/src/lib.rs ]\n", facade), false);
+        plan.create(dir.join("README.md"), format!("# {}\n\nTODO: fill description.\n", facade), false);
+        created.push(facade.clone());
+    }
+
+    if !three_p_exists {
+        // members are created with `x!{}`-style lib.rs files, which need export-magic
+        let has_export_magic = workspace_dependency_names(&root_manifest_path, root_manifest)?.contains("export-magic");
+        let (deps, reexports) = if has_export_magic {
+            ("export-magic.workspace = true\n", "pub use export_magic::*;\n")
+        } else {
+            plan.note(format!(
+                "[workspace.dependencies] has no export-magic; add it and re-export it from {} before creating members",
+                three_p
+            ));
+            ("", "")
+        };
+
+        let dir = root.join(&three_p);
+        plan.create(
+            dir.join("Cargo.toml"),
+            package_manifest(&three_p, &format!("Third-party dependencies of the `{}` crates.", prefix), deps),
+            false,
+        );
+        plan.create(
+            dir.join("src").join("lib.rs"),
+            format!("// ---------------- [ File: {}/src/lib.rs ]\n{}", three_p, reexports),
+            false,
+        );
+        plan.create(dir.join("README.md"), format!("# {}\n\nTODO: fill description.\n", three_p), false);
+        created.push(three_p.clone());
+    }
+
+    if !script.exists() {
+        plan.create(script, create_member_script(prefix), true);
+    }
+
+    if let Some(manifest) = add_workspace_members(&root_manifest_path, root_manifest, &created)? {
+        plan.write(root_manifest_path, root_manifest, manifest);
+    }
+
+    if facade_exists || three_p_exists {
+        plan.note(format!("`ws prefix-group fix {}` wires up the existing members", prefix));
+    }
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod test_plan_new_prefix_group {
+    use super::*;
+
+    const ROOT_MANIFEST: &str = "[workspace]\nmembers = [\"other\"]\n\n[workspace.dependencies]\nexport-magic = \"0.3.6\"\n";
+
+    #[traced_test]
+    fn plans_facade_three_p_script_and_members() {
+        let root = tempdir().unwrap();
+        let plan = plan_new_prefix_group(root.path(), "foo", ROOT_MANIFEST).unwrap();
+
+        let created: Vec<String> = plan.edits().keys().map(|p| p.strip_prefix(root.path()).unwrap().display().to_string()).collect();
+        assert_eq!(created, vec![
+            "Cargo.toml",
+            "foo/Cargo.toml",
+            "foo/README.md",
+            "foo/src/lib.rs",
+            "foo-3p/Cargo.toml",
+            "foo-3p/README.md",
+            "foo-3p/src/lib.rs",
+            "u/create-foo-crate",
+        ]);
+
+        let edits = plan.edits();
+        assert!(edits[&root.path().join("Cargo.toml")].contents().contains("members = [\"other\", \"foo\", \"foo-3p\"]"));
+        assert!(edits[&root.path().join("foo-3p/Cargo.toml")].contents().ends_with("[dependencies]\nexport-magic.workspace = true\n"));
+        assert!(edits[&root.path().join("foo-3p/src/lib.rs")].contents().contains("pub use export_magic::*;"));
+        assert!(*edits[&root.path().join("u/create-foo-crate")].executable());
+        assert!(plan.notes().is_empty());
+
+        let diff = plan.diff();
+        assert!(diff.contains("--- /dev/null\n+++ b/foo/Cargo.toml\n"));
+        assert!(diff.contains("-members = [\"other\"]\n+members = [\"other\", \"foo\", \"foo-3p\"]\n"));
+    }
+
+    #[traced_test]
+    fn refuses_bad_or_existing_prefixes() {
+        let root = tempdir().unwrap();
+        assert!(matches!(plan_new_prefix_group(root.path(), "Foo Bar", ROOT_MANIFEST), Err(PrefixGroupError::InvalidPrefix { .. })));
+        assert!(matches!(plan_new_prefix_group(root.path(), "foo-3p", ROOT_MANIFEST), Err(PrefixGroupError::InvalidPrefix { .. })));
+
+        for dir in ["foo", "foo-3p", "u/create-foo-crate"] {
+            std::fs::create_dir_all(root.path().join(dir)).unwrap();
+        }
+        assert!(matches!(plan_new_prefix_group(root.path(), "foo", ROOT_MANIFEST), Err(PrefixGroupError::PrefixGroupAlreadyExists { .. })));
+    }
+
+    #[traced_test]
+    fn membership_is_prefix_then_dash() {
+        assert!(belongs_to_prefix("foo", "foo"));
+        assert!(belongs_to_prefix("foo-bar", "foo"));
+        assert!(!belongs_to_prefix("foobar", "foo"));
+    }
+}
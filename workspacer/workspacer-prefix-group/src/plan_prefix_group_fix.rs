@@ -0,0 +1,216 @@
+// ---------------- [ File: workspacer-prefix-group/src/plan_prefix_group_fix.rs ]
+crate::ix!();
+
+/// The files of one group crate that `fix` may edit.
+#[derive(Builder,Getters,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+#[builder(setter(into))]
+pub struct GroupCrateSources {
+    name:       String,
+    dir:        PathBuf,
+    cargo_toml: String,
+
+    #[builder(default)]
+    version:    Option<String>,
+    #[builder(default)]
+    lib_rs:     Option<String>,
+    #[builder(default)]
+    imports_rs: Option<String>,
+}
+
+impl GroupCrateSources {
+
+    /// Reads `dir`; the name and version come from its manifest.
+    pub async fn read(dir: &Path) -> Result<Self, WorkspaceError> {
+        let manifest_path = dir.join("Cargo.toml");
+        let cargo_toml = tokio::fs::read_to_string(&manifest_path).await.map_err(|io| WorkspaceError::IoError {
+            io_error: Arc::new(io),
+            context:  format!("reading {:?}", manifest_path),
+        })?;
+
+        let manifest: toml::Value = toml::from_str(&cargo_toml).map_err(|e| PrefixGroupError::UnparseableCargoToml {
+            path:    manifest_path.clone(),
+            message: e.to_string(),
+        })?;
+        let field = |key: &str| manifest.get("package").and_then(|p| p.get(key)).and_then(|v| v.as_str()).map(str::to_string);
+
+        let name = field("name").ok_or_else(|| PrefixGroupError::UnparseableCargoToml {
+            path:    manifest_path.clone(),
+            message: "no package.name".to_string(),
+        })?;
+
+        Ok(GroupCrateSourcesBuilder::default()
+            .name(name)
+            .dir(dir)
+            .cargo_toml(cargo_toml)
+            .version(field("version"))
+            .lib_rs(tokio::fs::read_to_string(dir.join("src").join("lib.rs")).await.ok())
+            .imports_rs(tokio::fs::read_to_string(dir.join("src").join("imports.rs")).await.ok())
+            .build()
+            .unwrap())
+    }
+
+    fn manifest_path(&self) -> PathBuf {
+        self.dir.join("Cargo.toml")
+    }
+
+    fn path_name(&self) -> String {
+        self.name.replace('-', "_")
+    }
+}
+
+/// Plans the repairs `ValidatePrefixGroupCohesion` only warns about, for the group `prefix`:
+///
+/// - crates of the group found on disk but missing from `[workspace].members` are added
+/// - every member depends on `<prefix>-3p` and glob-imports it in `src/imports.rs`
+/// - the facade depends on every member and re-exports it from `src/lib.rs`
+///
+/// `members` are the workspace crates of the group; `unlisted` the ones found only on disk.
+/// A missing facade or `-3p` crate is reported, not created (`ws prefix-group new` does that).
+pub fn plan_prefix_group_fix(
+    root:          &Path,
+    prefix:        &str,
+    root_manifest: &str,
+    members:       &[GroupCrateSources],
+    unlisted:      &[GroupCrateSources],
+) -> Result<PrefixGroupEditPlan, PrefixGroupError> {
+    let group: Vec<&GroupCrateSources> = members.iter().chain(unlisted).collect();
+    if group.is_empty() {
+        return Err(PrefixGroupError::NoSuchPrefixGroup { prefix: prefix.to_string() });
+    }
+
+    let mut plan = PrefixGroupEditPlan::new(root);
+
+    // 1) workspace membership
+    let root_manifest_path = root.join("Cargo.toml");
+    let missing_members: Vec<String> = unlisted
+        .iter()
+        .map(|c| c.dir.strip_prefix(root).unwrap_or(&c.dir).display().to_string())
+        .collect();
+    if let Some(manifest) = add_workspace_members(&root_manifest_path, root_manifest, &missing_members)? {
+        plan.write(root_manifest_path, root_manifest, manifest);
+    }
+
+    let three_p_name = format!("{}-3p", prefix);
+    let facade  = group.iter().find(|c| c.name == prefix).copied();
+    let three_p = group.iter().find(|c| c.name == three_p_name).copied();
+
+    if facade.is_none() {
+        plan.note(format!("no facade crate '{}'; `ws prefix-group new {}` creates it", prefix, prefix));
+    }
+    if three_p.is_none() {
+        plan.note(format!("no '{}' crate; `ws prefix-group new {}` creates it", three_p_name, prefix));
+    }
+
+    let relative = |to: &GroupCrateSources, from: &GroupCrateSources| {
+        pathdiff::diff_paths(&to.dir, &from.dir).unwrap_or_else(|| to.dir.clone())
+    };
+
+    for member in group.iter().filter(|c| c.name != prefix && c.name != three_p_name) {
+        // 2) members depend on, and glob-import, the -3p crate
+        if let Some(three_p) = three_p {
+            let path = member.manifest_path();
+            let text = plan.text(&path, &member.cargo_toml).to_string();
+            if let Some(new) = add_path_dependency(&path, &text, &three_p.name, &relative(three_p, member), three_p.version.as_deref())? {
+                plan.write(path, &member.cargo_toml, new);
+            }
+
+            let glob_import = format!("pub(crate) use {}::*;", three_p.path_name());
+            match &member.imports_rs {
+                Some(imports) => {
+                    if let Some(new) = ensure_line(imports, &glob_import) {
+                        plan.write(member.dir.join("src").join("imports.rs"), imports, new);
+                    }
+                }
+                None => plan.note(format!("'{}' has no src/imports.rs; add `{}` by hand", member.name, glob_import)),
+            }
+        }
+
+        // 3) the facade depends on, and re-exports, every member
+        if let Some(facade) = facade {
+            let path = facade.manifest_path();
+            let text = plan.text(&path, &facade.cargo_toml).to_string();
+            if let Some(new) = add_path_dependency(&path, &text, &member.name, &relative(member, facade), member.version.as_deref())? {
+                plan.write(path, &facade.cargo_toml, new);
+            }
+
+            let lib_rs_path = facade.dir.join("src").join("lib.rs");
+            let original    = facade.lib_rs.clone().unwrap_or_default();
+            let text        = plan.text(&lib_rs_path, &original).to_string();
+            if let Some(new) = ensure_line(&text, &format!("pub use {}::*;", member.path_name())) {
+                match &facade.lib_rs {
+                    Some(lib_rs) => plan.write(lib_rs_path, lib_rs, new),
+                    None         => plan.create(lib_rs_path, new, false),
+                }
+            }
+        }
+    }
+
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod test_plan_prefix_group_fix {
+    use super::*;
+
+    fn krate(root: &Path, name: &str, cargo_extra: &str, lib_rs: &str, imports_rs: Option<&str>) -> GroupCrateSources {
+        GroupCrateSourcesBuilder::default()
+            .name(name)
+            .dir(root.join(name))
+            .cargo_toml(format!("[package]\nname = \"{}\"\nversion = \"0.1.0\"\n{}", name, cargo_extra))
+            .version(Some("0.1.0".to_string()))
+            .lib_rs(Some(lib_rs.to_string()))
+            .imports_rs(imports_rs.map(str::to_string))
+            .build()
+            .unwrap()
+    }
+
+    #[traced_test]
+    fn wires_members_facade_and_membership() {
+        let root = Path::new("/ws");
+        let members = vec![
+            krate(root, "foo", "\n[dependencies.foo-good]\npath = \"../foo-good\"\n", "pub use foo_good::*;\n", None),
+            krate(root, "foo-3p", "", "", None),
+            krate(root, "foo-good", "\n[dependencies.foo-3p]\npath = \"../foo-3p\"\n", "", Some("pub(crate) use foo_3p::*;\n")),
+            krate(root, "foo-bad", "", "", Some("")),
+        ];
+        let unlisted = vec![krate(root, "foo-lost", "", "", Some(""))];
+
+        let plan = plan_prefix_group_fix(root, "foo", "[workspace]\nmembers = [\"foo\", \"foo-3p\", \"foo-good\", \"foo-bad\"]\n", &members, &unlisted).unwrap();
+        let edits = plan.edits();
+
+        let changed: Vec<&Path> = edits.keys().map(|p| p.strip_prefix(root).unwrap()).collect();
+        assert_eq!(changed, vec![
+            Path::new("Cargo.toml"),
+            Path::new("foo/Cargo.toml"),
+            Path::new("foo/src/lib.rs"),
+            Path::new("foo-bad/Cargo.toml"),
+            Path::new("foo-bad/src/imports.rs"),
+            Path::new("foo-lost/Cargo.toml"),
+            Path::new("foo-lost/src/imports.rs"),
+        ]);
+
+        assert!(edits[&root.join("Cargo.toml")].contents().contains("\"foo-bad\", \"foo-lost\"]"));
+        assert!(edits[&root.join("foo-bad/Cargo.toml")].contents().contains("[dependencies.foo-3p]\npath = \"../foo-3p\"\nversion = \"0.1.0\""));
+        assert_eq!(edits[&root.join("foo-bad/src/imports.rs")].contents(), "pub(crate) use foo_3p::*;\n");
+        assert_eq!(edits[&root.join("foo/src/lib.rs")].contents(), "pub use foo_good::*;\npub use foo_bad::*;\npub use foo_lost::*;\n");
+
+        let facade = edits[&root.join("foo/Cargo.toml")].contents();
+        assert!(facade.contains("[dependencies.foo-bad]") && facade.contains("[dependencies.foo-lost]"));
+        assert!(plan.notes().is_empty());
+    }
+
+    #[traced_test]
+    fn reports_what_it_cannot_fix() {
+        let root = Path::new("/ws");
+        let members = vec![krate(root, "bar-a", "", "", None), krate(root, "bar-b", "", "", None)];
+        let plan = plan_prefix_group_fix(root, "bar", "[workspace]\nmembers = [\"bar-a\", \"bar-b\"]\n", &members, &[]).unwrap();
+        assert!(plan.is_empty());
+        assert_eq!(plan.notes().len(), 2);
+
+        assert!(matches!(
+            plan_prefix_group_fix(root, "baz", "[workspace]\nmembers = []\n", &[], &[]),
+            Err(PrefixGroupError::NoSuchPrefixGroup { .. })
+        ));
+    }
+}
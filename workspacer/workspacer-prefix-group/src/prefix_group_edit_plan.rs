@@ -0,0 +1,112 @@
+// ---------------- [ File: workspacer-prefix-group/src/prefix_group_edit_plan.rs ]
+crate::ix!();
+
+/// One file a prefix-group command will write.
+#[derive(Getters,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct FileEdit {
+    /// Contents before the command; `None` for a file it creates
+    original:   Option<String>,
+    contents:   String,
+    executable: bool,
+}
+
+/// Every file `ws prefix-group new|fix` would write, computed up front so that `--dry-run`
+/// can show it as a diff.
+#[derive(Getters,Debug,Clone,Default,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct PrefixGroupEditPlan {
+    root:  PathBuf,
+    edits: BTreeMap<PathBuf, FileEdit>,
+
+    /// Problems the command reports but does not repair
+    notes: Vec<String>,
+}
+
+impl PrefixGroupEditPlan {
+
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into(), ..Default::default() }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edits.is_empty()
+    }
+
+    /// The contents `path` will have so far: the planned contents if an earlier step already
+    /// edited it, `original` otherwise. Lets several steps edit one file.
+    pub fn text<'a>(&'a self, path: &Path, original: &'a str) -> &'a str {
+        self.edits.get(path).map_or(original, |e| e.contents.as_str())
+    }
+
+    /// Records new contents for an existing file, keeping the first original seen.
+    pub fn write(&mut self, path: impl Into<PathBuf>, original: &str, contents: String) {
+        let path = path.into();
+        match self.edits.get_mut(&path) {
+            Some(edit) => edit.contents = contents,
+            None => {
+                self.edits.insert(path, FileEdit { original: Some(original.to_string()), contents, executable: false });
+            }
+        }
+    }
+
+    pub fn create(&mut self, path: impl Into<PathBuf>, contents: impl Into<String>, executable: bool) {
+        self.edits.insert(path.into(), FileEdit { original: None, contents: contents.into(), executable });
+    }
+
+    pub fn note(&mut self, note: impl Into<String>) {
+        self.notes.push(note.into());
+    }
+
+    fn display_path(&self, path: &Path) -> String {
+        path.strip_prefix(&self.root).unwrap_or(path).display().to_string()
+    }
+
+    /// All edits as one unified diff, paths relative to the workspace root.
+    pub fn diff(&self) -> String {
+        self.edits
+            .iter()
+            .map(|(path, edit)| unified_diff(&self.display_path(path), edit.original.as_deref(), &edit.contents))
+            .collect()
+    }
+
+    pub async fn apply(&self) -> Result<(), WorkspaceError> {
+        for (path, edit) in &self.edits {
+            if let Some(parent) = path.parent() {
+                tokio::fs::create_dir_all(parent).await.map_err(|io| WorkspaceError::IoError {
+                    io_error: Arc::new(io),
+                    context:  format!("creating {:?} for a prefix group", parent),
+                })?;
+            }
+            tokio::fs::write(path, &edit.contents).await.map_err(|io| WorkspaceError::IoError {
+                io_error: Arc::new(io),
+                context:  format!("writing {:?} for a prefix group", path),
+            })?;
+            if edit.executable {
+                tokio::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755)).await.map_err(|io| {
+                    WorkspaceError::IoError {
+                        io_error: Arc::new(io),
+                        context:  format!("making {:?} executable", path),
+                    }
+                })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for PrefixGroupEditPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.edits.is_empty() {
+            writeln!(f, "nothing to change")?;
+        }
+        for (path, edit) in &self.edits {
+            let verb = if edit.original.is_some() { "modify" } else { "create" };
+            writeln!(f, "{} {}", verb, self.display_path(path))?;
+        }
+        for note in &self.notes {
+            writeln!(f, "note: {}", note)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+// ---------------- [ File: workspacer-prefix-group/src/prefix_group_scaffolding.rs ]
+crate::ix!();
+
+/// `ws prefix-group new|fix`: both return a plan, applied with `PrefixGroupEditPlan::apply`
+/// unless the caller only wants the diff.
+#[async_trait]
+pub trait PrefixGroupScaffolding {
+    type Error;
+
+    async fn plan_new_prefix_group(&self, prefix: &str) -> Result<PrefixGroupEditPlan, Self::Error>;
+
+    async fn plan_prefix_group_fix(&self, prefix: &str) -> Result<PrefixGroupEditPlan, Self::Error>;
+}
+
+#[async_trait]
+impl<P,H> PrefixGroupScaffolding for Workspace<P,H>
+where
+    for<'async_trait> P: From<PathBuf> + AsRef<Path> + Send + Sync + 'async_trait,
+    H: CrateHandleInterface<P>,
+{
+    type Error = WorkspaceError;
+
+    async fn plan_new_prefix_group(&self, prefix: &str) -> Result<PrefixGroupEditPlan, WorkspaceError> {
+        let root          = self.as_ref().to_path_buf();
+        let root_manifest = read_root_manifest(&root).await?;
+        Ok(plan_new_prefix_group(&root, prefix, &root_manifest)?)
+    }
+
+    async fn plan_prefix_group_fix(&self, prefix: &str) -> Result<PrefixGroupEditPlan, WorkspaceError> {
+        validate_prefix(prefix)?;
+
+        let root          = self.as_ref().to_path_buf();
+        let root_manifest = read_root_manifest(&root).await?;
+
+        let mut members     = vec![];
+        let mut member_dirs = BTreeSet::new();
+        for crate_handle in self {
+            let guard = crate_handle.lock().await;
+            let dir   = guard.root_dir_path_buf();
+            member_dirs.insert(dir.canonicalize().unwrap_or_else(|_| dir.clone()));
+            if belongs_to_prefix(&guard.name(), prefix) {
+                members.push(GroupCrateSources::read(&dir).await?);
+            }
+        }
+
+        // crates of the group that exist on disk but are not workspace members
+        let mut unlisted = vec![];
+        let mut entries  = tokio::fs::read_dir(&root).await.map_err(|io| WorkspaceError::IoError {
+            io_error: Arc::new(io),
+            context:  format!("listing {:?} for unlisted prefix-group crates", root),
+        })?;
+        while let Some(entry) = entries.next_entry().await.map_err(|io| WorkspaceError::IoError {
+            io_error: Arc::new(io),
+            context:  format!("listing {:?} for unlisted prefix-group crates", root),
+        })? {
+            let dir = entry.path();
+            if !dir.join("Cargo.toml").is_file() || member_dirs.contains(&dir.canonicalize().unwrap_or_else(|_| dir.clone())) {
+                continue;
+            }
+            match GroupCrateSources::read(&dir).await {
+                Ok(sources) if belongs_to_prefix(sources.name(), prefix) => unlisted.push(sources),
+                Ok(_)       => {}
+                Err(e)      => debug!("ignoring {:?}, which does not look like a crate: {:?}", dir, e),
+            }
+        }
+        unlisted.sort_by(|a, b| a.name().cmp(b.name()));
+
+        Ok(plan_prefix_group_fix(&root, prefix, &root_manifest, &members, &unlisted)?)
+    }
+}
+
+async fn read_root_manifest(root: &Path) -> Result<String, WorkspaceError> {
+    let path = root.join("Cargo.toml");
+    tokio::fs::read_to_string(&path).await.map_err(|io| WorkspaceError::IoError {
+        io_error: Arc::new(io),
+        context:  format!("reading the workspace manifest {:?}", path),
+    })
+}
@@ -0,0 +1,139 @@
+// ---------------- [ File: workspacer-prefix-group/src/unified_diff.rs ]
+crate::ix!();
+
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+enum DiffOp {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// A `diff -u` style rendering of `old` => `new` (`None` for a file being created), three
+/// lines of context. Returns an empty string when nothing changed.
+pub fn unified_diff(display_path: &str, old: Option<&str>, new: &str) -> String {
+    let a: Vec<&str> = old.map(|o| o.lines().collect()).unwrap_or_default();
+    let b: Vec<&str> = new.lines().collect();
+
+    let ops = line_diff(&a, &b);
+    if ops.iter().all(|(op, _)| *op == DiffOp::Equal) && old.is_some() {
+        return String::new();
+    }
+
+    let mut out = String::new();
+    match old {
+        Some(_) => out.push_str(&format!("--- a/{}\n", display_path)),
+        None    => out.push_str("--- /dev/null\n"),
+    }
+    out.push_str(&format!("+++ b/{}\n", display_path));
+
+    const CONTEXT: usize = 3;
+
+    // (op, line, old position before op, new position before op)
+    let mut positioned = Vec::with_capacity(ops.len());
+    let (mut i, mut j) = (0, 0);
+    for (op, line) in &ops {
+        positioned.push((*op, *line, i, j));
+        match op {
+            DiffOp::Equal  => { i += 1; j += 1; }
+            DiffOp::Delete => { i += 1; }
+            DiffOp::Insert => { j += 1; }
+        }
+    }
+
+    let changes: Vec<usize> = (0..positioned.len()).filter(|&k| positioned[k].0 != DiffOp::Equal).collect();
+    let mut k = 0;
+    while k < changes.len() {
+        let start = changes[k].saturating_sub(CONTEXT);
+        let mut end = changes[k];
+        while k + 1 < changes.len() && changes[k + 1] - end <= 2 * CONTEXT {
+            k += 1;
+            end = changes[k];
+        }
+        let end = (end + CONTEXT + 1).min(positioned.len());
+        k += 1;
+
+        let hunk = &positioned[start..end];
+        let old_len = hunk.iter().filter(|h| h.0 != DiffOp::Insert).count();
+        let new_len = hunk.iter().filter(|h| h.0 != DiffOp::Delete).count();
+        let old_start = hunk[0].2 + usize::from(old_len > 0);
+        let new_start = hunk[0].3 + usize::from(new_len > 0);
+
+        out.push_str(&format!("@@ -{},{} +{},{} @@\n", old_start, old_len, new_start, new_len));
+        for (op, line, _, _) in hunk {
+            let sign = match op {
+                DiffOp::Equal  => ' ',
+                DiffOp::Delete => '-',
+                DiffOp::Insert => '+',
+            };
+            out.push(sign);
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Longest-common-subsequence line diff; the files we rewrite are small.
+fn line_diff<'a>(a: &[&'a str], b: &[&'a str]) -> Vec<(DiffOp, &'a str)> {
+    let (n, m) = (a.len(), b.len());
+    let mut lcs = vec![vec![0u32; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut ops = Vec::with_capacity(n + m);
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if a[i] == b[j] {
+            ops.push((DiffOp::Equal, a[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            ops.push((DiffOp::Delete, a[i]));
+            i += 1;
+        } else {
+            ops.push((DiffOp::Insert, b[j]));
+            j += 1;
+        }
+    }
+    ops.extend(a[i..].iter().map(|l| (DiffOp::Delete, *l)));
+    ops.extend(b[j..].iter().map(|l| (DiffOp::Insert, *l)));
+    ops
+}
+
+#[cfg(test)]
+mod test_unified_diff {
+    use super::*;
+
+    #[traced_test]
+    fn renders_changes_with_context() {
+        let old = "a\nb\nc\nd\ne\nf\ng\nh\n";
+        let new = "a\nb\nc\nd\nE\nf\ng\nh\ni\n";
+        assert_eq!(unified_diff("x/Cargo.toml", Some(old), new), "\
+--- a/x/Cargo.toml
++++ b/x/Cargo.toml
+@@ -2,7 +2,8 @@
+ b
+ c
+ d
+-e
++E
+ f
+ g
+ h
++i
+");
+    }
+
+    #[traced_test]
+    fn new_files_and_unchanged_files() {
+        assert_eq!(unified_diff("x/src/lib.rs", None, "one\n"), "--- /dev/null\n+++ b/x/src/lib.rs\n@@ -0,0 +1,1 @@\n+one\n");
+        assert_eq!(unified_diff("x/src/lib.rs", Some("same\n"), "same\n"), "");
+    }
+}
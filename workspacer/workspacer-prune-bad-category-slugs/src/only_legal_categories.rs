@@ -59,6 +59,24 @@ pub const LEGAL_CATEGORIES: &[&'static str] = &[
     "web-programming",
 ];
 
+/// crates.io rejects a manifest with more categories or keywords than this.
+pub const MAX_CRATES_IO_CATEGORIES: usize = 5;
+pub const MAX_CRATES_IO_KEYWORDS:   usize = 5;
+
+fn is_slug(x: &str) -> bool {
+    !x.is_empty() && x.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// A category crates.io knows about; anything else is pruned.
+pub fn is_legal_category(category: &str) -> bool {
+    is_slug(category) && LEGAL_CATEGORIES.contains(&category)
+}
+
+/// A keyword short enough and made of slug characters only; anything else is pruned.
+pub fn is_legal_keyword(keyword: &str) -> bool {
+    is_slug(keyword) && keyword.len() <= 19
+}
+
 /// TODO: make sure this is *exactly* what cratesio needs
 pub fn clean_cratesio_keyword(x: &str) -> String {
 
@@ -103,4 +121,16 @@ mod test_clean_keyword {
         // Expected: spaces removed, becomes "rustlanguage"
         assert_eq!(output, "rustlanguage");
     }
+
+    #[traced_test]
+    fn test_legal_categories_and_keywords() {
+        assert!(is_legal_category("development-tools"));
+        assert!(!is_legal_category("development tools"));
+        assert!(!is_legal_category("tooling"));
+
+        assert!(is_legal_keyword("workspace"));
+        assert!(!is_legal_keyword("two words"));
+        assert!(!is_legal_keyword("averyveryverylongkeyword"));
+        assert!(!is_legal_keyword(""));
+    }
 }
@@ -30,8 +30,6 @@ where
         let mut doc = cargo_guard.document_clone().await?;
         let mut removed_count = 0_usize;
 
-        let re = regex::Regex::new(r"^[A-Za-z0-9_-]+$").unwrap();
-
         if let Some(package_item) = doc.get_mut("package") {
             if let Some(pkg_table) = package_item.as_table_mut() {
                 // categories
@@ -40,9 +38,7 @@ where
                         let mut i = 0;
                         while i < arr.len() {
                             if let Some(cat_str) = arr.get(i).and_then(|v| v.as_str()) {
-                                let is_allowed = LEGAL_CATEGORIES.contains(&cat_str);
-                                let matches_pattern = re.is_match(cat_str);
-                                if !(is_allowed && matches_pattern) {
+                                if !is_legal_category(cat_str) {
                                     debug!("Removing invalid category '{cat_str}'");
                                     arr.remove(i);
                                     removed_count += 1;
@@ -59,7 +55,7 @@ where
                         let mut i = 0;
                         while i < arr.len() {
                             if let Some(kw_str) = arr.get(i).and_then(|v| v.as_str()) {
-                                if kw_str.len() > 19 || !re.is_match(kw_str) || kw_str.contains(' ') {
+                                if !is_legal_keyword(kw_str) {
                                     debug!("Removing invalid keyword '{kw_str}'");
                                     arr.remove(i);
                                     removed_count += 1;
@@ -7,3 +7,4 @@ pub(crate) use workspacer_detect_circular_deps::*;
 pub(crate) use workspacer_errors::*;
 pub(crate) use workspacer_toml_interface::*;
 pub(crate) use workspacer_workspace::*;
+pub(crate) use workspacer_config::*;
@@ -37,6 +37,11 @@ where
             name_to_handle.insert(crate_name, crate_handle.clone());
         }
 
+        // a misspelled skip entry would publish the crate it meant to hold back
+        let settings = WorkspaceSettings::load(self.as_ref()).await?;
+        let members: Vec<String> = name_to_handle.keys().cloned().collect();
+        settings.validate_against_members(&members)?;
+
         tracing::info!(
             "Topologically sorted crate list has length: {}",
             topo_order.len()
@@ -55,6 +60,16 @@ where
                     continue;
                 }
 
+                let crate_settings = settings.for_crate(crate_node_name);
+                if *crate_settings.skip_publish() {
+                    tracing::info!(
+                        "SKIP: crate '{}' is {} in the workspace settings.",
+                        crate_node_name,
+                        if *crate_settings.private() { "private" } else { "excluded from publishing" }
+                    );
+                    continue;
+                }
+
                 let crate_name    = guard.name();
                 let crate_version = guard.version()?;
 
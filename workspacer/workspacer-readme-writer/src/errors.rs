@@ -9,6 +9,11 @@ error_tree!{
         CrateError(CrateError),
         WorkspaceError(WorkspaceError),
         CargoTomlError(CargoTomlError),
+        WorkspaceSettingsError(WorkspaceSettingsError),
+        #[display("unknown language model '{name}' in the workspace settings")]
+        UnknownLanguageModel {
+            name: String,
+        },
         #[display("ReadmeWriteError: {0}")]
         ReadmeWriteError(ReadmeWriteError),
     }
@@ -21,8 +21,17 @@ pub struct AiReadmeWriter
 impl AiReadmeWriter
 {
     pub async fn default() -> Result<Self,AiReadmeWriterError> {
+        Self::for_model(None).await
+    }
+
+    /// Like `default`, with the model named in the workspace settings (`gpt-4o` when unset).
+    pub async fn for_model(model: Option<&str>) -> Result<Self,AiReadmeWriterError> {
+        let language_model_type = match model {
+            Some(name) => language_model_type_from_name(name)?,
+            None       => LanguageModelType::Gpt4o,
+        };
         let readme_dir = WorkspacerDir::local().ensure_subdir_exists("readme-writer-workspace")?;
-        Ok(AiReadmeWriter::new(&readme_dir, LanguageModelType::Gpt4o).await?)
+        Ok(AiReadmeWriter::new(&readme_dir, language_model_type).await?)
     }
 
     pub async fn new(
@@ -41,6 +50,15 @@ impl AiReadmeWriter
     }
 }
 
+/// Maps a model name as written in the settings (`gpt-4o`, `o1-mini`, ...) to its type.
+pub fn language_model_type_from_name(name: &str) -> Result<LanguageModelType,AiReadmeWriterError> {
+    use LanguageModelType::*;
+    [Gpt3_5Turbo, Gpt4o, Gpt4oMini, Gpt4Turbo, O1Preview, O1Mini, O1, O1Pro, Gpt4_5Preview]
+        .into_iter()
+        .find(|model| model.to_string() == name)
+        .ok_or_else(|| AiReadmeWriterError::UnknownLanguageModel { name: name.to_string() })
+}
+
 impl ComputeSystemMessage for AiReadmeWriter
 {
     fn system_message() -> String {
@@ -29,7 +29,7 @@ impl UpdateReadmeFiles for CrateHandle {
     {
         trace!("Entering CrateHandle::update_readme_files(...) with plant={}, force={}", plant, force);
 
-        {
+        let crate_settings = {
             // Quickly check if a README.md already exists:
             let guard = crate_handle.lock().await;
             let maybe_readme = guard.readme_path().await.map_err(AiReadmeWriterError::CrateError)?;
@@ -40,10 +40,19 @@ impl UpdateReadmeFiles for CrateHandle {
                 );
                 return Ok(());
             }
+
+            // settings live at the root of the workspace this crate belongs to
+            let settings = WorkspaceSettings::load_for_crate(guard.as_ref()).await?;
+            settings.for_crate(&guard.name())
+        };
+
+        if *crate_settings.skip_readme() {
+            info!("Skipping crate '{}' because the workspace settings exclude it from readme generation.", crate_settings.crate_name());
+            return Ok(());
         }
 
         // If not skipped, do the AI steps:
-        let mut writer = AiReadmeWriter::default().await?;
+        let mut writer = AiReadmeWriter::for_model(crate_settings.readme_model().as_deref()).await?;
         let request = AiReadmeWriterRequest::<PathBuf>::async_try_from::<CrateHandle>(
             crate_handle,
             config
@@ -73,29 +82,33 @@ where
     {
         trace!("Entering Workspace update_readme_files(...) with plant={}, force={}", plant, force);
 
-        let mut writer = AiReadmeWriter::default().await?;
-
-        let requests = {
+        // requests grouped by the model that should write them (`None`: the default model)
+        let requests_by_model = {
             let guard = workspace_arc.lock().await;
-            let mut reqs = Vec::new();
+            let settings = WorkspaceSettings::load(guard.as_ref()).await?;
+            let mut by_model = BTreeMap::<Option<String>, Vec<AiReadmeWriterRequest<PathBuf>>>::new();
 
             for item_arc in guard.crates() {
                 // 1) Lock once to check if we should skip
-                let skip_this = {
+                let (skip_this, crate_settings) = {
                     let item_guard = item_arc.lock().await;
                     let maybe_readme = item_guard.readme_path().await?;
+                    let crate_settings = settings.for_crate(&item_guard.name());
                     // Evaluate skip logic
-                    if maybe_readme.is_some() && !force {
-                        true
+                    if *crate_settings.skip_readme() {
+                        info!("Skipping crate '{}' because the workspace settings exclude it", item_guard.name());
+                        (true, crate_settings)
+                    } else if maybe_readme.is_some() && !force {
+                        info!("Skipping crate because README already exists, no --force");
+                        (true, crate_settings)
                     } else {
-                        false
+                        (false, crate_settings)
                     }
                     // item_guard is dropped here
                 };
 
                 // 2) If skipping, continue
                 if skip_this {
-                    info!("Skipping crate because README already exists, no --force");
                     continue;
                 }
 
@@ -104,17 +117,20 @@ where
                     item_arc.clone(),
                     config
                 ).await?;
-                reqs.push(request);
+                by_model.entry(crate_settings.readme_model().clone()).or_default().push(request);
             }
-            reqs
+            by_model
         };
 
-        if requests.is_empty() {
+        if requests_by_model.is_empty() {
             info!("No crates need README generation in this workspace.");
             return Ok(());
         }
 
-        execute_ai_readme_writer_requests(&mut writer, &requests, plant).await?;
+        for (model, requests) in &requests_by_model {
+            let mut writer = AiReadmeWriter::for_model(model.as_deref()).await?;
+            execute_ai_readme_writer_requests(&mut writer, requests, plant).await?;
+        }
 
         info!("Exiting Workspace update_readme_files(...) with success.");
         Ok(())
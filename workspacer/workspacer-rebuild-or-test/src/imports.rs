@@ -5,4 +5,5 @@ pub(crate) use workspacer_3p::*;
 pub(crate) use workspacer_crate::*;
 pub(crate) use workspacer_crate_interface::*;
 pub(crate) use workspacer_errors::*;
+pub(crate) use workspacer_topo::*;
 pub(crate) use workspacer_workspace::*;
@@ -2,11 +2,23 @@
 crate::ix!();
 
 #[async_trait]
-pub trait RebuildOrTest {
+pub trait RebuildOrTest: Send + Sync {
 
     type Error;
 
     async fn rebuild_or_test(&self, runner: &dyn CommandRunner) -> Result<(), Self::Error>;
+
+    /// Rebuilds and retests only what `changed_paths` can affect.
+    ///
+    /// The default has nothing finer-grained to go on and does a full `rebuild_or_test`.
+    async fn rebuild_or_test_changed(
+        &self,
+        runner:        &dyn CommandRunner,
+        changed_paths: &[PathBuf],
+    ) -> Result<(), Self::Error> {
+        trace!("rebuild_or_test_changed: {} changed paths, doing a full rebuild", changed_paths.len());
+        self.rebuild_or_test(runner).await
+    }
 }
 
 #[async_trait]
@@ -57,10 +69,34 @@ impl RebuildOrTest for CrateHandle {
 
 #[async_trait]
 impl<P,H:CrateHandleInterface<P>> RebuildOrTest for Workspace<P,H> 
-where for<'async_trait> P: From<PathBuf> + AsRef<Path> + Send + Sync + 'async_trait
+where 
+    for<'async_trait> P: From<PathBuf> + AsRef<Path> + Send + Sync + 'async_trait,
+    Self: ComputeAffectedCrates,
 {
     type Error = WorkspaceError;
 
+    /// Builds and tests only the crates owning `changed_paths` and their dependents.
+    /// A workspace-level change (root Cargo.toml, Cargo.lock, ...) still rebuilds everything.
+    async fn rebuild_or_test_changed(
+        &self,
+        runner:        &dyn CommandRunner,
+        changed_paths: &[PathBuf],
+    ) -> Result<(), Self::Error> {
+        let affected = self.affected_crates_for_files(changed_paths).await?;
+
+        if *affected.workspace_wide() {
+            info!("workspace-level file changed; rebuilding the whole workspace");
+            return self.rebuild_or_test(runner).await;
+        }
+
+        if affected.is_empty() {
+            info!("no crate is affected by {:?}; nothing to rebuild", changed_paths);
+            return Ok(());
+        }
+
+        rebuild_or_test_packages(runner, self.as_ref(), affected.affected()).await
+    }
+
     async fn rebuild_or_test(&self, runner: &dyn CommandRunner) -> Result<(), Self::Error> {
 
         let workspace_path = self.as_ref();
@@ -99,6 +135,51 @@ where for<'async_trait> P: From<PathBuf> + AsRef<Path> + Send + Sync + 'async_tr
     }
 }
 
+/// Runs `cargo build -p <each>` and then `cargo test -p <each>` from `workspace_path`.
+pub async fn rebuild_or_test_packages(
+    runner:         &dyn CommandRunner,
+    workspace_path: &Path,
+    crate_names:    &[String],
+) -> Result<(), WorkspaceError> {
+    info!("Running cargo build for {:?}...", crate_names);
+
+    let mut build_cmd = Command::new("cargo");
+    build_cmd.arg("build").current_dir(workspace_path);
+    for name in crate_names {
+        build_cmd.arg("-p").arg(name);
+    }
+
+    let output = runner.run_command(build_cmd).await??;
+
+    if !output.status.success() {
+        error!("Build failed: {}", String::from_utf8_lossy(&output.stderr));
+        return Err(WorkspaceError::from(BuildError::BuildFailed {
+            stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+        }));
+    }
+
+    info!("Rebuild succeeded, running tests for {:?}...", crate_names);
+
+    let mut test_cmd = Command::new("cargo");
+    test_cmd.arg("test").current_dir(workspace_path);
+    for name in crate_names {
+        test_cmd.arg("-p").arg(name);
+    }
+
+    let test_output = runner.run_command(test_cmd).await??;
+
+    if !test_output.status.success() {
+        let stdout = Some(String::from_utf8_lossy(&test_output.stdout).to_string());
+        let stderr = Some(String::from_utf8_lossy(&test_output.stderr).to_string());
+
+        error!("Tests failed: {:#?}", stderr);
+        return Err(WorkspaceError::from(TestFailure::UnknownError { stdout, stderr }));
+    }
+
+    info!("Tests passed for {:?}.", crate_names);
+    Ok(())
+}
+
 #[cfg(test)]
 mod test_rebuild_or_test_with_mock {
     use super::*;
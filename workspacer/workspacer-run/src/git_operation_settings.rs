@@ -0,0 +1,48 @@
+// ---------------- [ File: workspacer-run/src/git_operation_settings.rs ]
+crate::ix!();
+
+/// How the `run_with_*` helpers treat the working tree when they check git, plus the label
+/// their commits get. The `ws` binary builds one from `--stash`/`--commit-each` and the command
+/// line and hands it down to each command that checks git.
+#[derive(Getters,Clone,Debug,Default,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct GitOperationSettings {
+    policy: GitOperationPolicy,
+    label:  String,
+}
+
+impl GitOperationSettings {
+
+    pub fn new(policy: GitOperationPolicy, label: impl Into<String>) -> Self {
+        Self { policy, label: label.into() }
+    }
+}
+
+/// Starts the git side of a mutating command run in `dir`.
+pub(crate) async fn begin_git_operation(dir: &Path, settings: &GitOperationSettings) -> Result<GitOperation, WorkspaceError> {
+    info!("Ensuring Git working directory is clean (policy: {:?})...", settings.policy());
+    GitOperation::begin(dir, settings.label(), *settings.policy()).await.map_err(|git_err| {
+        error!("Git is not clean: {:?}", git_err);
+        WorkspaceError::GitError(git_err)
+    })
+}
+
+/// Finishes the operation if the command succeeded, abandons it otherwise.
+pub(crate) async fn conclude_git_operation<R>(
+    git_operation: Option<GitOperation>,
+    outcome:       Result<R, WorkspaceError>,
+) -> Result<R, WorkspaceError> {
+    let Some(git_operation) = git_operation else {
+        return outcome;
+    };
+    match outcome {
+        Ok(result) => {
+            git_operation.finish().await.map_err(WorkspaceError::GitError)?;
+            Ok(result)
+        }
+        Err(e) => {
+            git_operation.abandon().await;
+            Err(e)
+        }
+    }
+}
@@ -1,6 +1,7 @@
 // ---------------- [ File: workspacer-run/src/lib.rs ]
 #[macro_use] mod imports; use imports::*;
 
+x!{git_operation_settings}
 x!{run_with_workspace_and_crate_name}
 x!{run_with_workspace_and_two_crate_names}
 x!{run_with_workspace}
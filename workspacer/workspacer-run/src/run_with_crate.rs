@@ -4,8 +4,9 @@ crate::ix!();
 /// This new helper function `run_with_crate` parallels the existing
 /// `run_with_workspace` pattern but focuses on a single crate. It:
 ///
-/// 1) Builds a `CrateHandle` from the given `PathBuf`.
-/// 2) Optionally checks Git cleanliness or other invariants (if desired).
+/// 1) Unless `git_check` is `None`, requires the crate's directory to be clean, or stashes it
+///    (see [`GitOperation`]).
+/// 2) Builds a `CrateHandle` from the given `PathBuf`.
 /// 3) Hands the handle to the user-provided closure.
 /// 4) Optionally re-validates the crate afterward.
 ///
@@ -13,7 +14,7 @@ crate::ix!();
 #[tracing::instrument(level="trace", skip(operation))]
 pub async fn run_with_crate<R, F>(
     crate_path: PathBuf,
-    skip_git_check: bool,
+    git_check: Option<&GitOperationSettings>,
     operation: F,
 ) -> Result<R, WorkspaceError>
 where
@@ -28,42 +29,51 @@ where
       + Send
       + 'static,
 {
-    // 1) Create the CrateHandle from `crate_path`
-    debug!("Creating CrateHandle from path='{}'", crate_path.display());
-    let mut handle = CrateHandle::new(&crate_path).await.map_err(|crate_err| {
-        error!(
-            "Could not create CrateHandle from '{}': {:?}",
-            crate_path.display(),
-            crate_err
-        );
-        WorkspaceError::CrateError(crate_err)
-    })?;
+    // 1) Only the crate's own directory has to be clean (or gets stashed): the rest of the
+    //    repository is not this operation's business.
+    let git_operation = match git_check {
+        Some(settings) => Some(begin_git_operation(&crate_path, settings).await?),
+        None => {
+            debug!("Skipping single-crate Git check");
+            None
+        }
+    };
 
-    // 2) (Optional) If you want to ensure Git is clean for single crates, you'd do so here.
-    //    We might skip it or do something akin to:
-    if !skip_git_check {
-        // You could do something like `handle.ensure_git_clean()?` if that were in your API.
-        debug!("(Skipping or not) any single-crate-level Git checks as needed.");
-    }
+    let outcome: Result<R, WorkspaceError> = async {
+        // 2) Create the CrateHandle from `crate_path`
+        debug!("Creating CrateHandle from path='{}'", crate_path.display());
+        let mut handle = CrateHandle::new(&crate_path).await.map_err(|crate_err| {
+            error!(
+                "Could not create CrateHandle from '{}': {:?}",
+                crate_path.display(),
+                crate_err
+            );
+            WorkspaceError::CrateError(crate_err)
+        })?;
 
-    // 3) Hand control off to the user-provided closure.
-    info!(
-        "Running single-crate operation for crate path='{}'...",
-        crate_path.display()
-    );
-    let result = {
-        let future = operation(&mut handle);
-        future.await?
-    };
+        // 3) Hand control off to the user-provided closure.
+        info!(
+            "Running single-crate operation for crate path='{}'...",
+            crate_path.display()
+        );
+        let result = {
+            let future = operation(&mut handle);
+            future.await?
+        };
+
+        // 4) Optionally validate integrity after the operation
+        //    (similar to how `run_with_workspace` does `workspace.validate_integrity()`).
+        info!("Validating crate integrity post-operation...");
+        handle.validate_integrity().await.map_err(|err| {
+            error!("Crate integrity validation failed: {:?}", err);
+            WorkspaceError::CrateError(err)
+        })?;
+        debug!("Crate integrity is valid. Done.");
+        Ok(result)
+    }.await;
 
-    // 4) Optionally validate integrity after the operation
-    //    (similar to how `run_with_workspace` does `workspace.validate_integrity()`).
-    info!("Validating crate integrity post-operation...");
-    handle.validate_integrity().await.map_err(|err| {
-        error!("Crate integrity validation failed: {:?}", err);
-        WorkspaceError::CrateError(err)
-    })?;
-    debug!("Crate integrity is valid. Done.");
+    // Commit the changes with `--commit-each`, then restore anything stashed
+    let result = conclude_git_operation(git_operation, outcome).await?;
 
     // 5) Return whatever the closure returned
     Ok(result)
@@ -3,10 +3,14 @@ crate::ix!();
 
 /// This helper is analogous to `run_with_workspace_and_crate_name`, but it only loads
 /// the workspace (optionally checking Git). No crate name needed.
+///
+/// `git_check` is `None` to skip the Git check; otherwise the working tree is checked (or
+/// stashed) before the workspace is loaded, so the workspace is read from the tree the
+/// operation will actually change.
 #[tracing::instrument(level = "trace", skip(operation))]
 pub async fn run_with_workspace<R, F>(
     user_supplied_path: Option<PathBuf>,
-    skip_git_check: bool,
+    git_check: Option<&GitOperationSettings>,
     operation: F,
 ) -> Result<R, WorkspaceError>
 where
@@ -35,33 +39,37 @@ where
         }
     };
 
-    // 2) Load the workspace
-    info!("Opening workspace at '{}'", workspace_path.display());
-    let mut workspace = Workspace::<PathBuf, CrateHandle>::new(&workspace_path).await.map_err(|e| {
-        error!("Failed to open workspace: {:?}", e);
-        e
-    })?;
+    // 2) Optionally check Git; with `--stash`, set uncommitted work aside instead
+    let git_operation = match git_check {
+        Some(settings) => Some(begin_git_operation(&workspace_path, settings).await?),
+        None => {
+            debug!("Skipping git check");
+            None
+        }
+    };
 
-    // 3) Optionally check Git
-    if !skip_git_check {
-        info!("Ensuring Git working directory is clean...");
-        workspace.ensure_git_clean().await.map_err(|git_err| {
-            error!("Git is not clean: {:?}", git_err);
-            WorkspaceError::GitError(git_err)
+    let outcome: Result<R, WorkspaceError> = async {
+        // 3) Load the workspace
+        info!("Opening workspace at '{}'", workspace_path.display());
+        let mut workspace = Workspace::<PathBuf, CrateHandle>::new(&workspace_path).await.map_err(|e| {
+            error!("Failed to open workspace: {:?}", e);
+            e
         })?;
-    } else {
-        debug!("Skipping git check because skip_git_check=true");
-    }
 
-    // 4) Run the closure immediately
-    let result = {
-        let fut = operation(&mut workspace);
-        fut.await?
-    };
+        // 4) Run the closure immediately
+        let result = {
+            let fut = operation(&mut workspace);
+            fut.await?
+        };
+
+        // 5) Validate integrity
+        info!("Validating workspace integrity post-operation...");
+        workspace.validate_integrity().await?;
+        Ok(result)
+    }.await;
 
-    // 5) Validate integrity
-    info!("Validating workspace integrity post-operation...");
-    workspace.validate_integrity().await?;
+    // 6) Commit the changes with `--commit-each`, then restore anything stashed
+    let result = conclude_git_operation(git_operation, outcome).await?;
 
     info!("Done analyzing or operating on the workspace at '{}'", workspace_path.display());
     Ok(result)
@@ -7,7 +7,7 @@ crate::ix!();
 #[tracing::instrument(level = "trace", skip(operation))]
 pub async fn run_with_workspace_and_two_crate_names<R, F>(
     user_supplied_path: Option<PathBuf>,
-    skip_git_check: bool,
+    git_check: Option<&GitOperationSettings>,
     target_crate_name: String,
     dep_crate_name: String,
     operation: F,
@@ -40,44 +40,52 @@ where
         }
     };
 
-    // 2) Load/create the workspace
-    info!("Opening workspace at '{}'", workspace_path.display());
-    let mut workspace = Workspace::<PathBuf, CrateHandle>::new(&workspace_path).await.map_err(|e| {
-        error!("Failed to create/load workspace: {:?}", e);
-        e
-    })?;
+    // 2) Optionally check Git cleanliness; with `--stash`, set uncommitted work aside instead.
+    //    This comes before loading so the workspace is read from the tree being changed.
+    let git_operation = match git_check {
+        Some(settings) => {
+            let git_operation = begin_git_operation(&workspace_path, settings).await?;
+            debug!("Git working directory confirmed clean.");
+            Some(git_operation)
+        }
+        None => {
+            debug!("Skipping Git clean check.");
+            None
+        }
+    };
 
-    // 3) Optionally check Git cleanliness
-    if !skip_git_check {
-        info!("Ensuring Git working directory is clean...");
-        workspace.ensure_git_clean().await.map_err(|git_err| {
-            error!("Git is not clean: {:?}", git_err);
-            WorkspaceError::GitError(git_err)
+    let outcome: Result<R, WorkspaceError> = async {
+        // 3) Load/create the workspace
+        info!("Opening workspace at '{}'", workspace_path.display());
+        let mut workspace = Workspace::<PathBuf, CrateHandle>::new(&workspace_path).await.map_err(|e| {
+            error!("Failed to create/load workspace: {:?}", e);
+            e
         })?;
-        debug!("Git working directory confirmed clean.");
-    } else {
-        debug!("Skipping Git clean check because `skip_git_check` is true.");
-    }
 
-    // 4) Hand control off to the subcommand's closure
-    info!(
-        "Running subcommand-specific logic for target='{}' / dep='{}' ...",
-        target_crate_name, dep_crate_name
-    );
+        // 4) Hand control off to the subcommand's closure
+        info!(
+            "Running subcommand-specific logic for target='{}' / dep='{}' ...",
+            target_crate_name, dep_crate_name
+        );
 
-    // We call `operation(...)` right here and `.await` immediately.
-    let result = {
-        let future = operation(&mut workspace, &target_crate_name, &dep_crate_name);
-        future.await?
-    };
+        // We call `operation(...)` right here and `.await` immediately.
+        let result = {
+            let future = operation(&mut workspace, &target_crate_name, &dep_crate_name);
+            future.await?
+        };
+
+        // 5) Validate workspace integrity afterward
+        info!("Validating workspace integrity post-operation...");
+        workspace.validate_integrity().await.map_err(|err| {
+            error!("Workspace integrity validation failed: {:?}", err);
+            err
+        })?;
+        debug!("Workspace integrity is valid. Done.");
+        Ok(result)
+    }.await;
 
-    // 5) Validate workspace integrity afterward
-    info!("Validating workspace integrity post-operation...");
-    workspace.validate_integrity().await.map_err(|err| {
-        error!("Workspace integrity validation failed: {:?}", err);
-        err
-    })?;
-    debug!("Workspace integrity is valid. Done.");
+    // Commit the changes with `--commit-each`, then restore anything stashed
+    let result = conclude_git_operation(git_operation, outcome).await?;
 
     info!(
         "Successfully completed subcommand: target='{}', dep='{}' in workspace='{}'",
@@ -1,5 +1,6 @@
 // ---------------- [ File: workspacer-scan-for-prefix-groups/src/imports.rs ]
 pub(crate) use workspacer_3p::*;
+pub(crate) use workspacer_config::*;
 pub(crate) use workspacer_crate_interface::*;
 pub(crate) use workspacer_errors::*;
 pub(crate) use workspacer_workspace::*;
@@ -26,18 +26,28 @@ where
     ///    rather than a locked mutex.
     /// 6) Mark all involved crates as assigned. Repeat until all pairs are processed.
     ///
+    /// Crates listed in `[prefix-groups] ignore` of the workspace settings never take part, and
+    /// `min-members` (default 2) replaces the "at least 2 matches" rule of step 4.
+    ///
     async fn scan(&self) -> Result<Vec<PrefixGroup<P, H>>, Self::Error> {
         use std::collections::HashSet;
         use tracing::{debug, info};
 
         info!("Starting prefix group scan with 'longest facade' logic (Arc<AsyncMutex<H>> => Arc<H> snapshot).");
 
+        let settings    = WorkspaceSettings::load(self.as_ref()).await?;
+        let min_members = *settings.prefix_groups().min_members();
+
         // Collect (name, arc<mutex<h>>) for each crate by locking them once to get the name.
         let mut ephemeral = Vec::with_capacity(self.crates().len());
         for arc_handle in self.crates().iter().cloned() {
             let arc_handle_clone = arc_handle.clone();
             let locked = arc_handle_clone.lock().await; 
             let name = locked.name().to_string();
+            if settings.ignores_for_prefix_groups(&name) {
+                debug!("Ignoring crate '{}' for prefix groups, as the workspace settings ask", name);
+                continue;
+            }
             ephemeral.push((name, arc_handle));
         }
 
@@ -74,10 +84,10 @@ where
                 }
             }
 
-            if potential.len() < 2 {
+            if potential.len() < min_members {
                 debug!(
-                    "Skipping '{}' because it doesn't have at least 1 additional crate matching '{}-'",
-                    facade_name, facade_name
+                    "Skipping '{}' because it doesn't have at least {} additional crates matching '{}-'",
+                    facade_name, min_members - 1, facade_name
                 );
                 continue;
             }
@@ -7,3 +7,4 @@ pub(crate) use workspacer_workspace::*;
 pub(crate) use workspacer_crate::*;
 pub(crate) use workspacer_run::*;
 pub(crate) use workspacer_mock::*;
+pub(crate) use serde_derive::Serialize;
@@ -10,4 +10,5 @@ x!{show_all}
 x!{show_crate_tree}
 x!{show_crate}
 x!{show_workspace}
+x!{shown_crate_interface}
 x!{show}
@@ -7,6 +7,9 @@ pub trait ShowItem {
 
     type Error;
 
+    /// The consolidated interface `show` renders, with internal deps merged in if configured.
+    async fn consolidated_interface(&self, options: &ShowFlags) -> Result<ConsolidatedCrateInterface, Self::Error>;
+
     /// Render crate info (and optional crate-tree info) to a textual output.
     async fn show(&self, options: &ShowFlags) -> Result<String, Self::Error>;
 }
@@ -29,9 +32,9 @@ CrateError: From<<T as AsyncTryFrom<PathBuf>>::Error>
     type Error = CrateError;
 
     #[tracing::instrument(level = "trace", skip(self, options))]
-    async fn show(&self, options: &ShowFlags) -> Result<String, Self::Error> {
+    async fn consolidated_interface(&self, options: &ShowFlags) -> Result<ConsolidatedCrateInterface, Self::Error> {
 
-        trace!("Entering ShowCrate::show for CrateHandle at {:?} with options={:#?}", self.as_ref(), options);
+        trace!("Entering ShowCrate::consolidated_interface for CrateHandle at {:?} with options={:#?}", self.as_ref(), options);
 
         // 1) Validate if it’s actually a single-crate or part of a workspace:
         //    We'll do that logic at a higher level if needed. Here, we assume it's valid.
@@ -74,13 +77,18 @@ CrateError: From<<T as AsyncTryFrom<PathBuf>>::Error>
                 .await?;
                 merge_in_place(&mut base_cci, &dep_cci);
             }
-
-            let final_str = options.build_filtered_string(&base_cci, &self.name());
-            return Ok(final_str);
         }
 
-        // If not merging crates, just return the string for the single crate's interface
-        let out_str = options.build_filtered_string(&base_cci, &self.name());
+        Ok(base_cci)
+    }
+
+    #[tracing::instrument(level = "trace", skip(self, options))]
+    async fn show(&self, options: &ShowFlags) -> Result<String, Self::Error> {
+
+        trace!("Entering ShowCrate::show for CrateHandle at {:?} with options={:#?}", self.as_ref(), options);
+
+        let cci     = self.consolidated_interface(options).await?;
+        let out_str = options.build_filtered_string(&cci, &self.name());
         Ok(out_str)
     }
 }
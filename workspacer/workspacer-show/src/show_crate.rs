@@ -18,7 +18,7 @@ pub async fn show_crate(flags: &ShowFlags) -> Result<String, WorkspaceError> {
     let flags_clone = flags.clone();
 
     // Just run_with_crate
-    let output = run_with_crate(crate_path, false, move |handle| {
+    let output = run_with_crate(crate_path, None, move |handle| {
         Box::pin(async move {
             trace!("Inside run_with_crate closure for ShowSubcommand::Crate");
             let cci_str = handle.show(&flags_clone).await?;
@@ -6,37 +6,57 @@ crate::ix!();
 #[tracing::instrument(level = "trace", skip(flags))]
 pub async fn show_crate_tree(flags: &ShowFlags) -> Result<String, WorkspaceError> {
     trace!("User chose subcommand: ws show crate-tree");
+
+    let mut output = String::new();
+
+    for (i, shown) in crate_tree_interfaces(flags).await?.iter().enumerate() {
+        let (info_line, name) = match i {
+            0 => {
+                let name = if *flags.merge_crates() { "merged-many" } else { shown.crate_name().as_str() };
+                (format!("// ---------------- [ Main crate: {} ]\n", shown.crate_name()), name)
+            }
+            _ => (format!("// ---------------- [ Dep crate: {} ]\n", shown.crate_name()), shown.crate_name().as_str()),
+        };
+        info!("{}", info_line.trim());
+
+        let sub_out = flags.build_filtered_string(shown.interface(), name);
+        if !sub_out.trim().is_empty() {
+            output.push_str(&info_line);
+            output.push_str(&sub_out);
+            output.push('\n');
+        }
+    }
+
+    Ok(output)
+}
+
+/// The interfaces `ws show crate-tree` renders: the main crate first (with its internal deps
+/// merged in if `merge_crates`), then each internal dep on its own.
+#[tracing::instrument(level = "trace", skip(flags))]
+pub async fn crate_tree_interfaces(flags: &ShowFlags) -> Result<Vec<ShownCrateInterface>, WorkspaceError> {
     let path = flags
         .path()
         .clone()
         .unwrap_or_else(|| PathBuf::from("."));
     debug!("Expecting single crate at {:?}", path);
 
-    // We'll accumulate all output in a single string.
-    let mut output = String::new();
-
-    // We do a manual check for single crate vs workspace:
     match Workspace::<PathBuf, CrateHandle>::new(&path).await {
         Ok(_ws) => {
-            // This is actually a workspace, which we don't support for crate-tree
             let msg = format!(
                 "Found a workspace at {:?}, but subcommand=CrateTree requires a single crate\n",
                 path
             );
             error!("{}", msg.trim());
-            return Err(WorkspaceError::InvalidWorkspace {
+            Err(WorkspaceError::InvalidWorkspace {
                 invalid_workspace_path: path,
-            });
+            })
         }
         Err(WorkspaceError::ActuallyInSingleCrate { path: single_crate_path }) => {
             trace!("Confirmed single crate at {:?}", single_crate_path);
-            // We'll manually create a CrateHandle, gather the main crate interface, then gather deps.
             let mut main_crate =
                 CrateHandle::new(&path).await.map_err(WorkspaceError::CrateError)?;
 
-            let main_name = main_crate.name();
-            let info_line = format!("// ---------------- [ Main crate: {} ]\n", main_name);
-            info!("{}", info_line.trim());
+            let main_name = main_crate.name().to_string();
 
             let mut combined_cci = main_crate
                 .consolidate_crate_interface(&ConsolidationOptions::from(flags))
@@ -54,93 +74,47 @@ pub async fn show_crate_tree(flags: &ShowFlags) -> Result<String, WorkspaceError
                 dep_names
             );
 
+            let mut dep_interfaces = vec![];
+            for dep_name in &dep_names {
+                let dep_path = match main_crate.root_dir_path_buf().parent() {
+                    Some(par) => par.join(dep_name),
+                    None => {
+                        let msg = format!(
+                            "Cannot find parent dir for main crate path: {:?}",
+                            main_crate.root_dir_path_buf()
+                        );
+                        error!("{}", msg.trim());
+                        return Err(WorkspaceError::InvalidWorkspace {
+                            invalid_workspace_path: main_crate.root_dir_path_buf().clone(),
+                        });
+                    }
+                };
+                debug!("Attempting to load dep '{}' at {:?}", dep_name, dep_path);
+                let mut dep_crate =
+                    CrateHandle::new(&dep_path).await.map_err(WorkspaceError::CrateError)?;
+                let dep_cci = dep_crate
+                    .consolidate_crate_interface(&flags.crate_dependency_consolidation_options())
+                    .await
+                    .map_err(WorkspaceError::CrateError)?;
+                dep_interfaces.push(ShownCrateInterface::new(dep_crate.name(), dep_cci));
+            }
+
             if *flags.merge_crates() {
-                // Merge them all into one single combined interface
                 trace!("Merging internal crates into the main interface");
-                for dep_name in dep_names {
-                    let dep_path = match main_crate.root_dir_path_buf().parent() {
-                        Some(par) => par.join(&dep_name),
-                        None => {
-                            let msg = format!(
-                                "Cannot find parent dir for main crate path: {:?}",
-                                main_crate.root_dir_path_buf()
-                            );
-                            error!("{}", msg.trim());
-                            return Err(WorkspaceError::InvalidWorkspace {
-                                invalid_workspace_path: main_crate.root_dir_path_buf().clone(),
-                            });
-                        }
-                    };
-                    debug!("Attempting to load dep '{}' at {:?}", dep_name, dep_path);
-                    let mut dep_crate =
-                        CrateHandle::new(&dep_path).await.map_err(WorkspaceError::CrateError)?;
-                    let dep_cci = dep_crate
-                        .consolidate_crate_interface(&flags.crate_dependency_consolidation_options())
-                        .await
-                        .map_err(WorkspaceError::CrateError)?;
-                    merge_in_place(&mut combined_cci, &dep_cci);
-                    debug!("Merged interface for dep '{}' into combined_cci", dep_name);
-                }
-
-                // Build text for the merged interface
-                let sub_out = flags.build_filtered_string(&combined_cci, "merged-many");
-                if !sub_out.trim().is_empty() {
-                    output.push_str(&info_line);
-                    output.push_str(&sub_out);
-                    output.push('\n');
+                for dep in &dep_interfaces {
+                    merge_in_place(&mut combined_cci, dep.interface());
+                    debug!("Merged interface for dep '{}' into combined_cci", dep.crate_name());
                 }
+                Ok(vec![ShownCrateInterface::merged(main_name, dep_names, combined_cci)])
             } else {
-
-                let main_out = flags.build_filtered_string(&combined_cci, &main_name);
-
-                if !main_out.trim().is_empty() {
-                    // Show the main crate first
-                    output.push_str(&info_line);
-                    output.push_str(&main_out);
-                    output.push('\n');
-                }
-
-                // Then each dep crate separately
-                for dep_name in dep_names {
-                    let dep_path = match main_crate.root_dir_path_buf().parent() {
-                        Some(par) => par.join(&dep_name),
-                        None => {
-                            let msg = format!(
-                                "Cannot find parent dir for main crate path: {:?}",
-                                main_crate.root_dir_path_buf()
-                            );
-                            error!("{}", msg.trim());
-                            return Err(WorkspaceError::InvalidWorkspace {
-                                invalid_workspace_path: main_crate.root_dir_path_buf().clone(),
-                            });
-                        }
-                    };
-                    debug!("Attempting to load dep '{}' at {:?}", dep_name, dep_path);
-                    let mut dep_crate =
-                        CrateHandle::new(&dep_path).await.map_err(WorkspaceError::CrateError)?;
-                    let dep_cci = dep_crate
-                        .consolidate_crate_interface(&flags.crate_dependency_consolidation_options())
-                        .await
-                        .map_err(WorkspaceError::CrateError)?;
-
-                    let dname = dep_crate.name();
-                    let dep_info_line = format!("// ---------------- [ Dep crate: {} ]\n", dname);
-                    info!("{}", dep_info_line.trim());
-
-                    let dep_out = flags.build_filtered_string(&dep_cci, &dname);
-                    if !dep_out.trim().is_empty() {
-                        output.push_str(&dep_info_line);
-                        output.push_str(&dep_out);
-                        output.push('\n');
-                    }
-                }
+                let mut interfaces = vec![ShownCrateInterface::new(main_name, combined_cci)];
+                interfaces.extend(dep_interfaces);
+                Ok(interfaces)
             }
         }
         Err(e) => {
             error!("Could not interpret path {:?} as single crate: {:?}", path, e);
-            return Err(e);
+            Err(e)
         }
     }
-
-    Ok(output)
 }
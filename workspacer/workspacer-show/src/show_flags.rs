@@ -103,12 +103,15 @@ pub struct ShowFlags {
 
     // New flags:
     #[structopt(long = "full", conflicts_with_all = &["for_ai", "for_ai_no_tests"])]
+    #[builder(default="false")]
     full: bool,
 
     #[structopt(long = "for-ai", conflicts_with_all = &["full", "for_ai_no_tests"])]
+    #[builder(default="false")]
     for_ai: bool,
 
     #[structopt(long = "for-ai-no-tests", conflicts_with_all = &["full", "for_ai"])]
+    #[builder(default="false")]
     for_ai_no_tests: bool,
 }
 
@@ -6,35 +6,47 @@ crate::ix!();
 #[tracing::instrument(level = "trace", skip(flags))]
 pub async fn show_workspace(flags: &ShowFlags) -> Result<String, WorkspaceError> {
     info!("User chose subcommand: ws show workspace");
+
+    let mut output = String::new();
+
+    for shown in workspace_interfaces(flags).await? {
+        let info_line = format!("// ---------------- [ File: {} ]\n", shown.crate_name());
+        info!("{}", info_line.trim());
+
+        let sub_out = flags.build_filtered_string(shown.interface(), shown.crate_name());
+
+        if !sub_out.trim().is_empty() {
+            output.push_str(&info_line);
+            output.push_str(&sub_out);
+            output.push('\n');
+        }
+    }
+
+    Ok(output)
+}
+
+/// The interfaces `ws show workspace` renders, one per member crate in workspace order.
+#[tracing::instrument(level = "trace", skip(flags))]
+pub async fn workspace_interfaces(flags: &ShowFlags) -> Result<Vec<ShownCrateInterface>, WorkspaceError> {
     let path = flags
         .path()
         .clone()
         .unwrap_or_else(|| PathBuf::from("."));
     debug!("Expecting workspace at {:?}", path);
 
-    let mut output = String::new();
-
     match Workspace::<PathBuf, CrateHandle>::new(&path).await {
         Ok(workspace) => {
             trace!("Confirmed a valid workspace at {:?}", path);
+            let mut interfaces = vec![];
             for crate_handle in workspace.crates() {
                 let mut guard = crate_handle.lock().await;
                 let cci = guard
                     .consolidate_crate_interface(&ConsolidationOptions::from(flags))
                     .await
                     .map_err(WorkspaceError::CrateError)?;
-                let cname = guard.name();
-                let info_line = format!("// ---------------- [ File: {} ]\n", cname);
-                info!("{}", info_line.trim());
-
-                let sub_out = flags.build_filtered_string(&cci, &cname);
-
-                if !sub_out.trim().is_empty() {
-                    output.push_str(&info_line);
-                    output.push_str(&sub_out);
-                    output.push('\n');
-                }
+                interfaces.push(ShownCrateInterface::new(guard.name(), cci));
             }
+            Ok(interfaces)
         }
         Err(WorkspaceError::ActuallyInSingleCrate { path: single_crate_path }) => {
             let msg = format!(
@@ -42,15 +54,13 @@ pub async fn show_workspace(flags: &ShowFlags) -> Result<String, WorkspaceError>
                 single_crate_path
             );
             error!("{}", msg.trim());
-            return Err(WorkspaceError::InvalidWorkspace {
+            Err(WorkspaceError::InvalidWorkspace {
                 invalid_workspace_path: single_crate_path,
-            });
+            })
         }
         Err(e) => {
             error!("Could not interpret path {:?} as a workspace: {:?}", path, e);
-            return Err(e);
+            Err(e)
         }
     }
-
-    Ok(output)
 }
@@ -0,0 +1,78 @@
+// ---------------- [ File: workspacer-show/src/shown_crate_interface.rs ]
+crate::ix!();
+
+/// One crate's consolidated interface, as `ws show --format json` prints it.
+#[derive(Getters,Serialize,Clone,Debug)]
+#[getset(get="pub")]
+pub struct ShownCrateInterface {
+    crate_name: String,
+
+    /// Names of the internal deps merged into `interface` (`--merge-crates`)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    merged_crates: Vec<String>,
+
+    interface: ConsolidatedCrateInterface,
+}
+
+impl ShownCrateInterface {
+
+    pub fn new(crate_name: impl Into<String>, interface: ConsolidatedCrateInterface) -> Self {
+        Self { crate_name: crate_name.into(), merged_crates: vec![], interface }
+    }
+
+    pub fn merged(crate_name: impl Into<String>, merged_crates: Vec<String>, interface: ConsolidatedCrateInterface) -> Self {
+        Self { crate_name: crate_name.into(), merged_crates, interface }
+    }
+}
+
+/// The interface `ws show crate` renders, unrendered.
+#[tracing::instrument(level = "trace", skip(flags))]
+pub async fn crate_interface(flags: &ShowFlags) -> Result<ShownCrateInterface, WorkspaceError> {
+    let crate_path = flags.path().clone().unwrap_or_else(|| PathBuf::from("."));
+    let flags_clone = flags.clone();
+
+    run_with_crate(crate_path, None, move |handle| {
+        Box::pin(async move {
+            let merged_crates = if *flags_clone.merge_crates() {
+                handle.internal_dependencies().await?
+            } else {
+                vec![]
+            };
+            let cci = handle.consolidated_interface(&flags_clone).await?;
+            Ok(ShownCrateInterface::merged(handle.name(), merged_crates, cci))
+        })
+    })
+    .await
+}
+
+#[cfg(test)]
+mod test_shown_crate_interface {
+    use super::*;
+
+    #[traced_test]
+    async fn serializes_the_consolidated_interface_under_the_crate_name() {
+        let tmp  = tempdir().expect("Failed to create temp dir");
+        let root = tmp.path().to_path_buf();
+        tokio::fs::write(root.join("Cargo.toml"), b"[package]\nname = \"json_crate\"\nversion = \"0.1.0\"\n")
+            .await
+            .unwrap();
+        tokio::fs::create_dir_all(root.join("src")).await.unwrap();
+        tokio::fs::write(root.join("src").join("lib.rs"), "pub fn exported() {}\n").await.unwrap();
+
+        let flags = ShowFlagsBuilder::default()
+            .path(Some(root.clone()))
+            .full(false)
+            .for_ai(false)
+            .for_ai_no_tests(false)
+            .build()
+            .unwrap();
+        let handle = CrateHandle::new(&root).await.unwrap();
+        let cci    = handle.consolidated_interface(&flags).await.unwrap();
+        let shown  = ShownCrateInterface::new(handle.name(), cci);
+
+        let json = serde_json::to_value(&shown).unwrap();
+        assert_eq!(json["crate_name"], "json_crate");
+        assert!(json.get("merged_crates").is_none());
+        assert!(json["interface"].to_string().contains("exported"));
+    }
+}
@@ -0,0 +1,47 @@
+// ---------------- [ File: workspacer-symbol-index/src/build_symbol_index.rs ]
+crate::ix!();
+
+/// Parses every member crate's `src/` and `tests/` into one `WorkspaceSymbolIndex`.
+#[async_trait]
+pub trait BuildSymbolIndex {
+    async fn build_symbol_index(&self) -> Result<WorkspaceSymbolIndex, WorkspaceError>;
+}
+
+#[async_trait]
+impl<P,H> BuildSymbolIndex for Workspace<P,H>
+where
+    for<'async_trait> P: From<PathBuf> + AsRef<Path> + Send + Sync + 'async_trait,
+    H: CrateHandleInterface<P> + Send + Sync,
+{
+    async fn build_symbol_index(&self) -> Result<WorkspaceSymbolIndex, WorkspaceError> {
+        let mut index = WorkspaceSymbolIndex::default();
+
+        for crate_handle in self {
+            let guard      = crate_handle.lock().await;
+            let crate_name = guard.name().to_string();
+            let crate_root = guard.root_dir_path_buf();
+            index.add_crate(&crate_name);
+
+            let sources = guard.source_files_excluding(&[]).await?;
+            let tests   = if guard.has_tests_directory() { guard.test_files().await? } else { vec![] };
+
+            let files = sources.into_iter().map(|f| (f, false)).chain(tests.into_iter().map(|f| (f, true)));
+            for (path, all_test) in files {
+                let text = tokio::fs::read_to_string(&path).await.map_err(|io| WorkspaceError::IoError {
+                    io_error: Arc::new(io),
+                    context:  format!("reading {:?} to build the symbol index", path),
+                })?;
+                let relative = path.strip_prefix(&crate_root).unwrap_or(&path).to_string_lossy().to_string();
+                index.add_source_file(&crate_name, &relative, &text, all_test);
+            }
+        }
+
+        info!(
+            "symbol index: {} crates, {} definitions, {} references",
+            index.crate_names().len(),
+            index.definitions().len(),
+            index.references().len(),
+        );
+        Ok(index)
+    }
+}
@@ -0,0 +1,7 @@
+// ---------------- [ File: workspacer-symbol-index/src/imports.rs ]
+pub(crate) use workspacer_3p::*;
+pub(crate) use workspacer_consolidate::*;
+pub(crate) use workspacer_crate_interface::*;
+pub(crate) use workspacer_errors::*;
+pub(crate) use workspacer_workspace::*;
+pub(crate) use ::serde_derive::Serialize;
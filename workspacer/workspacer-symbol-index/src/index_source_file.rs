@@ -0,0 +1,346 @@
+// ---------------- [ File: workspacer-symbol-index/src/index_source_file.rs ]
+crate::ix!();
+
+/// Everything one source file contributes to the index.
+#[derive(Getters,Debug,Clone,Default,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct FileSymbols {
+    definitions:  Vec<SymbolDefinition>,
+    references:   Vec<SymbolReference>,
+    trait_impls:  Vec<TraitImplementation>,
+    re_exports:   Vec<ReExport>,
+}
+
+impl FileSymbols {
+    pub fn into_parts(self) -> (Vec<SymbolDefinition>, Vec<SymbolReference>, Vec<TraitImplementation>, Vec<ReExport>) {
+        (self.definitions, self.references, self.trait_impls, self.re_exports)
+    }
+}
+
+/// Indexes one file with the same `ra_ap_syntax` parse `ws show` consolidates from.
+///
+/// This is purely syntactic: paths are recorded as written, so a reference through a `use`
+/// alias or a glob import is found by its last segments, and a method call only by its name.
+/// Single-segment lowercase paths that are not calls (locals, parameters) are left out, and
+/// so is anything inside a macro invocation's token tree (`println!("{}", x.name())`).
+///
+/// `all_test` marks every entry as test code, for files under `tests/`.
+pub fn index_source_file(crate_name: &str, file: &str, text: &str, all_test: bool) -> FileSymbols {
+    let parse = SourceFile::parse(text, Edition::Edition2024);
+    let lines = LineIndex::new(crate_name, file, text);
+    let mut symbols = FileSymbols::default();
+
+    for node in parse.tree().syntax().descendants() {
+        let in_test  = || all_test || is_in_test_module(node.clone());
+        let location = || lines.location(node.text_range().start());
+
+        if let Some(def) = definition_at(&node) {
+            let (qualified_name, kind, is_pub) = def;
+            symbols.definitions.push(SymbolDefinition::new(qualified_name, kind, is_pub, in_test(), location()));
+        } else if let Some(imp) = ast::Impl::cast(node.clone()) {
+            if let Some(trait_impl) = trait_implementation(&imp) {
+                let (trait_name, self_type, is_blanket) = trait_impl;
+                symbols.trait_impls.push(TraitImplementation::new(trait_name, self_type, is_blanket, in_test(), location()));
+            }
+        } else if let Some(use_item) = ast::Use::cast(node.clone()) {
+            let is_reexport = use_item.visibility().is_some_and(|v| v.syntax().text() == "pub");
+            if is_reexport && !in_test() {
+                if let Some(tree) = use_item.use_tree() {
+                    flatten_use_tree(&tree, &[], &mut |segments, glob, alias| {
+                        symbols.re_exports.push(ReExport::new(segments, glob, alias, location()));
+                    });
+                }
+            }
+        } else if let Some(call) = ast::MethodCallExpr::cast(node.clone()) {
+            if let Some(name_ref) = call.name_ref() {
+                symbols.references.push(SymbolReference::new(
+                    vec![name_ref.text().to_string()],
+                    ReferenceKind::MethodCall,
+                    enclosing_fn(&node),
+                    in_test(),
+                    lines.location(name_ref.syntax().text_range().start()),
+                ));
+            }
+        } else if let Some(path) = ast::Path::cast(node.clone()) {
+            if let Some((segments, kind)) = reference_at(&path) {
+                symbols.references.push(SymbolReference::new(segments, kind, enclosing_fn(&node), in_test(), location()));
+            }
+        }
+    }
+
+    symbols
+}
+
+/// `(qualified name, kind, is_pub)` when `node` defines an item.
+fn definition_at(node: &SyntaxNode) -> Option<(String, SymbolKind, bool)> {
+    let item = ast::Item::cast(node.clone())?;
+    let (name, kind) = match &item {
+        ast::Item::Fn(x) => {
+            let kind = if assoc_owner(node).is_some() { SymbolKind::Method } else { SymbolKind::Fn };
+            (x.name()?, kind)
+        }
+        ast::Item::Struct(x)     => (x.name()?, SymbolKind::Struct),
+        ast::Item::Enum(x)       => (x.name()?, SymbolKind::Enum),
+        ast::Item::Union(x)      => (x.name()?, SymbolKind::Union),
+        ast::Item::Trait(x)      => (x.name()?, SymbolKind::Trait),
+        ast::Item::TypeAlias(x)  => (x.name()?, SymbolKind::TypeAlias),
+        ast::Item::Const(x)      => (x.name()?, SymbolKind::Const),
+        ast::Item::Static(x)     => (x.name()?, SymbolKind::Static),
+        ast::Item::MacroRules(x) => (x.name()?, SymbolKind::Macro),
+        _                        => return None,
+    };
+
+    let name = name.text().to_string();
+    let qualified_name = match assoc_owner(node) {
+        Some(owner) => format!("{}::{}", owner, name),
+        None        => name,
+    };
+
+    let is_pub = node.children().filter_map(ast::Visibility::cast).next().is_some()
+        // trait items are as visible as the trait
+        || assoc_owner_is_trait(node);
+
+    Some((qualified_name, kind, is_pub))
+}
+
+/// The type or trait an associated item belongs to: `impl Foo { fn a() }` => `Foo`.
+fn assoc_owner(node: &SyntaxNode) -> Option<String> {
+    let list = node.parent().filter(|p| p.kind() == SyntaxKind::ASSOC_ITEM_LIST)?;
+    let owner = list.parent()?;
+    if let Some(imp) = ast::Impl::cast(owner.clone()) {
+        return imp.self_ty().as_ref().and_then(type_path).and_then(|s| s.last().cloned());
+    }
+    ast::Trait::cast(owner)?.name().map(|n| n.text().to_string())
+}
+
+fn assoc_owner_is_trait(node: &SyntaxNode) -> bool {
+    node.parent()
+        .filter(|p| p.kind() == SyntaxKind::ASSOC_ITEM_LIST)
+        .and_then(|list| list.parent())
+        .is_some_and(|owner| owner.kind() == SyntaxKind::TRAIT)
+}
+
+/// The qualified name of the innermost fn around `node`: the caller in a call-graph edge.
+fn enclosing_fn(node: &SyntaxNode) -> Option<String> {
+    let fn_node = node.ancestors().skip(1).find(|a| a.kind() == SyntaxKind::FN)?;
+    definition_at(&fn_node).map(|(qualified_name, _, _)| qualified_name)
+}
+
+/// What `Self` stands for at `node`.
+fn self_type_name(node: &SyntaxNode) -> Option<String> {
+    node.ancestors().find_map(|a| {
+        if let Some(imp) = ast::Impl::cast(a.clone()) {
+            imp.self_ty().as_ref().and_then(type_path).and_then(|s| s.last().cloned())
+        } else {
+            ast::Trait::cast(a).and_then(|t| t.name()).map(|n| n.text().to_string())
+        }
+    })
+}
+
+/// `(trait path, self type as written, is_blanket)` for `impl Trait for Type`.
+fn trait_implementation(imp: &ast::Impl) -> Option<(String, String, bool)> {
+    let trait_ty  = imp.trait_()?;
+    let self_ty   = imp.self_ty()?;
+    let trait_name = type_path(&trait_ty)?.join("::");
+    let self_text  = self_ty.syntax().text().to_string().split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let type_params: Vec<String> = imp
+        .generic_param_list()
+        .map(|list| {
+            list.generic_params()
+                .filter_map(|p| match p {
+                    ast::GenericParam::TypeParam(tp) => tp.name().map(|n| n.text().to_string()),
+                    _                                => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let is_blanket = type_path(&self_ty).is_some_and(|s| s.len() == 1 && type_params.contains(&s[0]));
+
+    Some((trait_name, self_text, is_blanket))
+}
+
+/// The path segments naming a type, looking through references: `&'a Workspace<P,H>` =>
+/// `["Workspace"]`. Only used on impl headers, where `Self` cannot appear.
+fn type_path(ty: &ast::Type) -> Option<Vec<String>> {
+    match ty {
+        ast::Type::PathType(p) => {
+            let segments = path_segments(&p.path()?, None);
+            (!segments.is_empty()).then_some(segments)
+        }
+        ast::Type::RefType(r) => type_path(&r.ty()?),
+        _                     => None,
+    }
+}
+
+/// `crate::a::Foo::<T>::bar` => `["a", "Foo", "bar"]`, with `Self` replaced by `self_name`.
+fn path_segments(path: &ast::Path, self_name: Option<&str>) -> Vec<String> {
+    let mut segments = path.qualifier().map(|q| path_segments(&q, self_name)).unwrap_or_default();
+    if let Some(name_ref) = path.segment().and_then(|s| s.name_ref()) {
+        match name_ref.text().as_str() {
+            "crate" | "self" | "super" => {}
+            "Self" => segments.push(self_name.unwrap_or("Self").to_string()),
+            name   => segments.push(name.to_string()),
+        }
+    }
+    segments
+}
+
+/// The outermost path of a use site, classified; `None` for paths that are part of a longer
+/// path, sit in `use` declarations or attributes, or name locals.
+fn reference_at(path: &ast::Path) -> Option<(Vec<String>, ReferenceKind)> {
+    let parent = path.syntax().parent()?;
+    if parent.kind() == SyntaxKind::PATH {
+        return None;
+    }
+    if path.syntax().ancestors().any(|a| matches!(a.kind(), SyntaxKind::USE | SyntaxKind::ATTR)) {
+        return None;
+    }
+
+    let kind = match parent.kind() {
+        SyntaxKind::MACRO_CALL => ReferenceKind::MacroCall,
+        SyntaxKind::PATH_TYPE  => ReferenceKind::Type,
+        SyntaxKind::PATH_EXPR  => {
+            let called = parent
+                .parent()
+                .and_then(ast::CallExpr::cast)
+                .and_then(|call| call.expr())
+                .is_some_and(|callee| callee.syntax() == &parent);
+            if called { ReferenceKind::Call } else { ReferenceKind::Path }
+        }
+        _ => ReferenceKind::Path,
+    };
+
+    let segments = path_segments(path, self_type_name(path.syntax()).as_deref());
+    let is_local = kind == ReferenceKind::Path
+        && segments.len() == 1
+        && segments[0].starts_with(|c: char| c.is_lowercase() || c == '_');
+    if segments.is_empty() || is_local {
+        return None;
+    }
+    Some((segments, kind))
+}
+
+/// Calls `emit(segments, glob, alias)` for every leaf of a use tree.
+fn flatten_use_tree(tree: &ast::UseTree, prefix: &[String], emit: &mut dyn FnMut(Vec<String>, bool, Option<String>)) {
+    let mut segments = prefix.to_vec();
+    if let Some(path) = tree.path() {
+        segments.extend(path_segments(&path, None));
+    }
+
+    if let Some(list) = tree.use_tree_list() {
+        for child in list.use_trees() {
+            flatten_use_tree(&child, &segments, emit);
+        }
+    } else if tree.star_token().is_some() {
+        emit(segments, true, None);
+    } else if !segments.is_empty() {
+        let alias = tree.rename().and_then(|r| r.name()).map(|n| n.text().to_string());
+        emit(segments, false, alias);
+    }
+}
+
+#[cfg(test)]
+mod test_index_source_file {
+    use super::*;
+
+    const SOURCE: &str = r#"
+pub use workspacer_3p::*;
+pub use other::{Thing as Renamed, sub::*};
+pub(crate) use hidden::*;
+
+pub struct BatchWorkspace;
+
+impl BatchWorkspace {
+    pub fn new_temp() -> Self {
+        Self::setup();
+        BatchWorkspace
+    }
+    fn setup() {}
+}
+
+pub trait Named {
+    fn name(&self) -> String;
+}
+
+impl Named for BatchWorkspace {
+    fn name(&self) -> String { String::new() }
+}
+
+impl<T: Clone> Named for T {
+    fn name(&self) -> String { String::new() }
+}
+
+fn driver(ws: &BatchWorkspace) {
+    let w = BatchWorkspace::new_temp();
+    w.name();
+    println!("{}", ws.name());
+}
+
+#[cfg(test)]
+mod tests {
+    fn t() { crate::BatchWorkspace::new_temp(); }
+}
+"#;
+
+    #[traced_test]
+    fn records_definitions_with_owners() {
+        let symbols = index_source_file("batch", "src/lib.rs", SOURCE, false);
+        let defs: Vec<(String, SymbolKind, bool)> = symbols
+            .definitions()
+            .iter()
+            .map(|d| (d.qualified_name().clone(), *d.kind(), *d.in_test()))
+            .collect();
+
+        assert!(defs.contains(&("BatchWorkspace".into(), SymbolKind::Struct, false)));
+        assert!(defs.contains(&("BatchWorkspace::new_temp".into(), SymbolKind::Method, false)));
+        assert!(defs.contains(&("Named::name".into(), SymbolKind::Method, false)));
+        assert!(defs.contains(&("driver".into(), SymbolKind::Fn, false)));
+        assert!(defs.contains(&("t".into(), SymbolKind::Fn, true)));
+
+        let new_temp = symbols.definitions().iter().find(|d| d.qualified_name() == "BatchWorkspace::new_temp").unwrap();
+        assert!(*new_temp.is_pub());
+        assert_eq!(*new_temp.location().line(), 9);
+    }
+
+    #[traced_test]
+    fn records_calls_with_their_callers() {
+        let symbols = index_source_file("batch", "src/lib.rs", SOURCE, false);
+        let calls: Vec<(String, ReferenceKind, Option<String>, bool)> = symbols
+            .references()
+            .iter()
+            .filter(|r| r.kind().is_call())
+            .map(|r| (r.path(), *r.kind(), r.enclosing_fn().clone(), *r.in_test()))
+            .collect();
+
+        assert_eq!(calls, vec![
+            ("BatchWorkspace::setup".into(),    ReferenceKind::Call,       Some("BatchWorkspace::new_temp".into()), false),
+            ("String::new".into(),              ReferenceKind::Call,       Some("BatchWorkspace::name".into()),     false),
+            ("String::new".into(),              ReferenceKind::Call,       Some("T::name".into()),                  false),
+            ("BatchWorkspace::new_temp".into(), ReferenceKind::Call,       Some("driver".into()),                   false),
+            ("name".into(),                     ReferenceKind::MethodCall, Some("driver".into()),                   false),
+            ("BatchWorkspace::new_temp".into(), ReferenceKind::Call,       Some("t".into()),                        true),
+        ]);
+
+        assert!(symbols.references().iter().any(|r| r.path() == "println" && *r.kind() == ReferenceKind::MacroCall));
+        assert!(!symbols.references().iter().any(|r| r.path() == "ws"), "locals are not references");
+    }
+
+    #[traced_test]
+    fn records_trait_impls_and_reexports() {
+        let symbols = index_source_file("batch", "src/lib.rs", SOURCE, false);
+
+        let impls: Vec<(String, String, bool)> = symbols
+            .trait_impls()
+            .iter()
+            .map(|i| (i.trait_name().clone(), i.self_type().clone(), *i.is_blanket()))
+            .collect();
+        assert_eq!(impls, vec![
+            ("Named".into(), "BatchWorkspace".into(), false),
+            ("Named".into(), "T".into(),              true),
+        ]);
+
+        let uses: Vec<String> = symbols.re_exports().iter().map(ReExport::use_path).collect();
+        assert_eq!(uses, vec!["workspacer_3p::*", "other::Thing as Renamed", "other::sub::*"]);
+    }
+}
@@ -0,0 +1,14 @@
+// ---------------- [ File: workspacer-symbol-index/src/lib.rs ]
+#[macro_use] mod imports; use imports::*;
+
+x!{source_location}
+x!{symbol_kind}
+x!{symbol_definition}
+x!{symbol_reference}
+x!{trait_implementation}
+x!{re_export}
+x!{symbol_pattern}
+x!{index_source_file}
+x!{workspace_symbol_index}
+x!{symbol_query}
+x!{build_symbol_index}
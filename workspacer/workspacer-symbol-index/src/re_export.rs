@@ -0,0 +1,77 @@
+// ---------------- [ File: workspacer-symbol-index/src/re_export.rs ]
+crate::ix!();
+
+/// One leaf of a `pub use` tree: `pub use a::{b::*, C as D};` gives `a::b::*` and `a::C as D`.
+///
+/// Only plain `pub` counts; the `pub(crate) use dep::*;` lines in `imports.rs` make a
+/// dependency visible inside a crate without re-exporting it.
+#[derive(Getters,Serialize,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct ReExport {
+    segments: Vec<String>,
+    glob:     bool,
+    alias:    Option<String>,
+    location: SourceLocation,
+}
+
+impl ReExport {
+
+    pub fn new(segments: Vec<String>, glob: bool, alias: Option<String>, location: SourceLocation) -> Self {
+        Self { segments, glob, alias, location }
+    }
+
+    pub fn crate_name(&self) -> &str {
+        self.location.crate_name()
+    }
+
+    /// `pub use a::b::C as D;` => `a::b::C as D`
+    pub fn use_path(&self) -> String {
+        let mut path = self.segments.join("::");
+        if self.glob {
+            path.push_str("::*");
+        }
+        if let Some(alias) = &self.alias {
+            path.push_str(" as ");
+            path.push_str(alias);
+        }
+        path
+    }
+
+    /// The name this re-export makes visible, or `None` for a glob.
+    pub fn exported_name(&self) -> Option<&str> {
+        if self.glob {
+            return None;
+        }
+        self.alias.as_deref().or_else(|| self.segments.last().map(String::as_str))
+    }
+}
+
+/// One step of a re-export chain: `to_crate` makes `symbol` from `from_crate` visible through
+/// `via`.
+#[derive(Getters,Serialize,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct ReExportHop {
+    symbol:     String,
+    from_crate: String,
+    to_crate:   String,
+    via:        ReExport,
+}
+
+impl ReExportHop {
+    pub fn new(symbol: String, from_crate: String, to_crate: String, via: ReExport) -> Self {
+        Self { symbol, from_crate, to_crate, via }
+    }
+}
+
+impl fmt::Display for ReExportHop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} -> {}  pub use {}  ({})",
+            self.from_crate,
+            self.to_crate,
+            self.via.use_path(),
+            self.via.location(),
+        )
+    }
+}
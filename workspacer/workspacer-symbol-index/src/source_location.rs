@@ -0,0 +1,65 @@
+// ---------------- [ File: workspacer-symbol-index/src/source_location.rs ]
+crate::ix!();
+
+/// Where an indexed symbol sits: the member crate, the file relative to that crate's root, and
+/// a 1-based line and column.
+#[derive(Getters,Serialize,Debug,Clone,PartialEq,Eq,PartialOrd,Ord,Hash)]
+#[getset(get="pub")]
+pub struct SourceLocation {
+    crate_name: String,
+    file:       String,
+    line:       usize,
+    column:     usize,
+}
+
+impl SourceLocation {
+    pub fn new(crate_name: impl Into<String>, file: impl Into<String>, line: usize, column: usize) -> Self {
+        Self { crate_name: crate_name.into(), file: file.into(), line, column }
+    }
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}:{}:{}", self.crate_name, self.file, self.line, self.column)
+    }
+}
+
+/// Turns byte offsets from the syntax tree into `SourceLocation`s for one file.
+#[derive(Debug,Clone)]
+pub struct LineIndex {
+    crate_name:  String,
+    file:        String,
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+
+    pub fn new(crate_name: &str, file: &str, text: &str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self { crate_name: crate_name.to_string(), file: file.to_string(), line_starts }
+    }
+
+    pub fn location(&self, offset: TextSize) -> SourceLocation {
+        let offset = usize::from(offset);
+        let line   = self.line_starts.partition_point(|&start| start <= offset);
+        let column = offset - self.line_starts[line - 1] + 1;
+        SourceLocation::new(self.crate_name.clone(), self.file.clone(), line, column)
+    }
+}
+
+#[cfg(test)]
+mod test_line_index {
+    use super::*;
+
+    #[traced_test]
+    fn offsets_map_to_one_based_lines_and_columns() {
+        let index = LineIndex::new("a", "src/x.rs", "fn a() {}\n\nfn b() {}\n");
+
+        assert_eq!(index.location(TextSize::from(0)),  SourceLocation::new("a", "src/x.rs", 1, 1));
+        assert_eq!(index.location(TextSize::from(3)),  SourceLocation::new("a", "src/x.rs", 1, 4));
+        assert_eq!(index.location(TextSize::from(11)), SourceLocation::new("a", "src/x.rs", 3, 1));
+        assert_eq!(index.location(TextSize::from(11)).to_string(), "a/src/x.rs:3:1");
+    }
+}
@@ -0,0 +1,29 @@
+// ---------------- [ File: workspacer-symbol-index/src/symbol_definition.rs ]
+crate::ix!();
+
+/// One item definition. Methods are qualified by the type or trait they belong to
+/// (`BatchWorkspace::new_temp`); everything else by its own name, since `x!{}` re-exports each
+/// module's items at the crate root.
+#[derive(Getters,Serialize,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct SymbolDefinition {
+    qualified_name: String,
+    kind:           SymbolKind,
+    is_pub:         bool,
+    in_test:        bool,
+    location:       SourceLocation,
+}
+
+impl SymbolDefinition {
+    pub fn new(qualified_name: String, kind: SymbolKind, is_pub: bool, in_test: bool, location: SourceLocation) -> Self {
+        Self { qualified_name, kind, is_pub, in_test, location }
+    }
+}
+
+impl fmt::Display for SymbolDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let vis  = if self.is_pub { "pub " } else { "" };
+        let test = if self.in_test { " (test)" } else { "" };
+        write!(f, "{}  {}{} {}{}", self.location, vis, self.kind, self.qualified_name, test)
+    }
+}
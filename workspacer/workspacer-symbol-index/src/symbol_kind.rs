@@ -0,0 +1,37 @@
+// ---------------- [ File: workspacer-symbol-index/src/symbol_kind.rs ]
+crate::ix!();
+
+/// The item kinds the index records definitions for.
+#[derive(Serialize,Debug,Clone,Copy,PartialEq,Eq,PartialOrd,Ord,Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum SymbolKind {
+    Fn,
+    /// A fn inside an `impl` or `trait` block
+    Method,
+    Struct,
+    Enum,
+    Union,
+    Trait,
+    TypeAlias,
+    Const,
+    Static,
+    Macro,
+}
+
+impl fmt::Display for SymbolKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            SymbolKind::Fn        => "fn",
+            SymbolKind::Method    => "method",
+            SymbolKind::Struct    => "struct",
+            SymbolKind::Enum      => "enum",
+            SymbolKind::Union     => "union",
+            SymbolKind::Trait     => "trait",
+            SymbolKind::TypeAlias => "type",
+            SymbolKind::Const     => "const",
+            SymbolKind::Static    => "static",
+            SymbolKind::Macro     => "macro",
+        };
+        write!(f, "{}", s)
+    }
+}
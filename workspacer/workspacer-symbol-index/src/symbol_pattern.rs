@@ -0,0 +1,82 @@
+// ---------------- [ File: workspacer-symbol-index/src/symbol_pattern.rs ]
+crate::ix!();
+
+/// What the user asked about: `new_temp`, `BatchWorkspace::new_temp` or
+/// `workspacer_3p::BatchWorkspace`. A pattern matches a path when its segments are a suffix of
+/// the path's, so shorter patterns cast a wider net.
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct SymbolPattern {
+    segments: Vec<String>,
+}
+
+impl SymbolPattern {
+
+    pub fn parse(text: &str) -> Option<Self> {
+        let segments: Vec<String> = text
+            .trim()
+            .trim_start_matches("::")
+            .split("::")
+            .map(|s| s.trim().to_string())
+            .collect();
+        if segments.iter().any(|s| s.is_empty()) {
+            return None;
+        }
+        Some(Self { segments })
+    }
+
+    /// The last segment: the item's own name.
+    pub fn name(&self) -> &str {
+        self.segments.last().expect("a parsed pattern has at least one segment")
+    }
+
+    pub fn matches_segments(&self, segments: &[String]) -> bool {
+        segments.len() >= self.segments.len() && segments.ends_with(&self.segments)
+    }
+
+    pub fn matches_qualified(&self, qualified_name: &str) -> bool {
+        let segments: Vec<String> = qualified_name.split("::").map(str::to_string).collect();
+        self.matches_segments(&segments)
+    }
+
+    /// Method calls only carry the method name, so the rest of the pattern cannot be checked.
+    pub fn matches_reference(&self, reference: &SymbolReference) -> bool {
+        match reference.kind() {
+            ReferenceKind::MethodCall => reference.segments().last().map(String::as_str) == Some(self.name()),
+            _                         => self.matches_segments(reference.segments()),
+        }
+    }
+}
+
+impl fmt::Display for SymbolPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.segments.join("::"))
+    }
+}
+
+#[cfg(test)]
+mod test_symbol_pattern {
+    use super::*;
+
+    fn segs(path: &str) -> Vec<String> {
+        path.split("::").map(str::to_string).collect()
+    }
+
+    #[traced_test]
+    fn patterns_match_path_suffixes() {
+        let pattern = SymbolPattern::parse("BatchWorkspace::new_temp").unwrap();
+
+        assert!(pattern.matches_segments(&segs("BatchWorkspace::new_temp")));
+        assert!(pattern.matches_segments(&segs("workspacer_3p::BatchWorkspace::new_temp")));
+        assert!(!pattern.matches_segments(&segs("new_temp")));
+        assert!(!pattern.matches_segments(&segs("OtherWorkspace::new_temp")));
+        assert!(pattern.matches_qualified("BatchWorkspace::new_temp"));
+        assert_eq!(pattern.name(), "new_temp");
+    }
+
+    #[traced_test]
+    fn rejects_empty_segments() {
+        assert!(SymbolPattern::parse("").is_none());
+        assert!(SymbolPattern::parse("a::::b").is_none());
+        assert_eq!(SymbolPattern::parse("::a::b").unwrap().to_string(), "a::b");
+    }
+}
@@ -0,0 +1,132 @@
+// ---------------- [ File: workspacer-symbol-index/src/symbol_query.rs ]
+crate::ix!();
+
+/// The questions `ws query` can ask of a `WorkspaceSymbolIndex`.
+#[derive(Serialize,Debug,Clone,Copy,PartialEq,Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum SymbolQueryKind {
+    Definitions,
+    References,
+    Callers,
+    Callees,
+    Implementors,
+    ReExports,
+}
+
+impl fmt::Display for SymbolQueryKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            SymbolQueryKind::Definitions  => "definitions",
+            SymbolQueryKind::References   => "references",
+            SymbolQueryKind::Callers      => "callers",
+            SymbolQueryKind::Callees      => "callees",
+            SymbolQueryKind::Implementors => "implementors",
+            SymbolQueryKind::ReExports    => "re-exports",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// The hits of one query, in index order (workspace crate order, then file order).
+#[derive(Serialize,Debug,Clone,PartialEq,Eq)]
+#[serde(tag = "kind", content = "hits", rename_all = "kebab-case")]
+pub enum SymbolQueryHits {
+    Definitions(Vec<SymbolDefinition>),
+    References(Vec<SymbolReference>),
+    Implementors(Vec<TraitImplementation>),
+    ReExports(Vec<ReExportHop>),
+}
+
+impl SymbolQueryHits {
+    pub fn len(&self) -> usize {
+        match self {
+            SymbolQueryHits::Definitions(v)  => v.len(),
+            SymbolQueryHits::References(v)   => v.len(),
+            SymbolQueryHits::Implementors(v) => v.len(),
+            SymbolQueryHits::ReExports(v)    => v.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// `ws query <kind> <symbol>`'s answer; serializes to the JSON `--json` prints.
+#[derive(Getters,Serialize,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct SymbolQueryResult {
+    query:  SymbolQueryKind,
+    symbol: String,
+    hits:   SymbolQueryHits,
+}
+
+impl SymbolQueryResult {
+
+    pub fn to_json(&self) -> String {
+        // plain strings and numbers all the way down; serialization cannot fail
+        serde_json::to_string_pretty(self).expect("a query result always serializes")
+    }
+}
+
+impl fmt::Display for SymbolQueryResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn lines<T: fmt::Display>(f: &mut fmt::Formatter<'_>, hits: &[T]) -> fmt::Result {
+            hits.iter().try_for_each(|hit| writeln!(f, "{}", hit))
+        }
+
+        match &self.hits {
+            SymbolQueryHits::Definitions(v)  => lines(f, v)?,
+            SymbolQueryHits::References(v)   => lines(f, v)?,
+            SymbolQueryHits::Implementors(v) => lines(f, v)?,
+            SymbolQueryHits::ReExports(v)    => lines(f, v)?,
+        }
+        writeln!(f, "{} of {}: {}", self.query, self.symbol, self.hits.len())
+    }
+}
+
+impl WorkspaceSymbolIndex {
+
+    /// Runs one query; `include_tests = false` drops hits inside `#[cfg(test)]` modules and
+    /// `tests/`.
+    pub fn query(&self, kind: SymbolQueryKind, pattern: &SymbolPattern, include_tests: bool) -> SymbolQueryResult {
+        fn keep<'a, T: Clone + 'a>(hits: Vec<&'a T>, in_test: impl Fn(&T) -> bool, include_tests: bool) -> Vec<T> {
+            hits.into_iter().filter(|h| include_tests || !in_test(*h)).cloned().collect()
+        }
+
+        let hits = match kind {
+            SymbolQueryKind::Definitions  => SymbolQueryHits::Definitions(keep(self.definitions_of(pattern), |d| *d.in_test(), include_tests)),
+            SymbolQueryKind::References   => SymbolQueryHits::References(keep(self.references_to(pattern), |r| *r.in_test(), include_tests)),
+            SymbolQueryKind::Callers      => SymbolQueryHits::References(keep(self.callers_of(pattern), |r| *r.in_test(), include_tests)),
+            SymbolQueryKind::Callees      => SymbolQueryHits::References(keep(self.callees_of(pattern), |r| *r.in_test(), include_tests)),
+            SymbolQueryKind::Implementors => SymbolQueryHits::Implementors(keep(self.implementors_of(pattern), |i| *i.in_test(), include_tests)),
+            SymbolQueryKind::ReExports    => SymbolQueryHits::ReExports(self.re_export_chain(pattern)),
+        };
+
+        SymbolQueryResult { query: kind, symbol: pattern.to_string(), hits }
+    }
+}
+
+#[cfg(test)]
+mod test_symbol_query {
+    use super::*;
+
+    #[traced_test]
+    fn serializes_hits_under_their_kind() {
+        let mut index = WorkspaceSymbolIndex::default();
+        index.add_source_file("a", "src/lib.rs", "pub fn f() {}\n#[cfg(test)]\nmod tests { fn t() { crate::f(); } }\n", false);
+
+        let pattern = SymbolPattern::parse("f").unwrap();
+        assert!(index.query(SymbolQueryKind::Callers, &pattern, false).hits().is_empty());
+
+        let result = index.query(SymbolQueryKind::Callers, &pattern, true);
+        let json: serde_json::Value = serde_json::from_str(&result.to_json()).unwrap();
+        assert_eq!(json["query"], "callers");
+        assert_eq!(json["symbol"], "f");
+        assert_eq!(json["hits"]["kind"], "references");
+        assert_eq!(json["hits"]["hits"][0]["enclosing_fn"], "t");
+        assert_eq!(json["hits"]["hits"][0]["location"]["line"], 3);
+
+        assert!(result.to_string().ends_with("callers of f: 1\n"));
+    }
+}
@@ -0,0 +1,83 @@
+// ---------------- [ File: workspacer-symbol-index/src/symbol_reference.rs ]
+crate::ix!();
+
+/// How a path is used at a reference site.
+#[derive(Serialize,Debug,Clone,Copy,PartialEq,Eq,Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReferenceKind {
+    /// `Foo::bar(..)` or `bar(..)`
+    Call,
+    /// `x.bar(..)`; the receiver's type is unknown to a syntax-only index
+    MethodCall,
+    /// `foo!(..)`
+    MacroCall,
+    /// A path in type position
+    Type,
+    /// Any other path: constants, unit structs, fn pointers
+    Path,
+}
+
+impl ReferenceKind {
+    pub fn is_call(&self) -> bool {
+        matches!(self, ReferenceKind::Call | ReferenceKind::MethodCall)
+    }
+}
+
+impl fmt::Display for ReferenceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            ReferenceKind::Call       => "call",
+            ReferenceKind::MethodCall => "method call",
+            ReferenceKind::MacroCall  => "macro call",
+            ReferenceKind::Type       => "type",
+            ReferenceKind::Path       => "path",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// One use of a path outside `use` declarations and attributes.
+///
+/// `segments` is the path as written, with `crate`/`self`/`super` dropped and `Self` replaced
+/// by the enclosing impl's type; a method call has just the method name.
+#[derive(Getters,Serialize,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct SymbolReference {
+    segments:     Vec<String>,
+    kind:         ReferenceKind,
+
+    /// The qualified name of the fn the reference sits in, if any; the call-graph edge source
+    enclosing_fn: Option<String>,
+    in_test:      bool,
+    location:     SourceLocation,
+}
+
+impl SymbolReference {
+
+    pub fn new(
+        segments:     Vec<String>,
+        kind:         ReferenceKind,
+        enclosing_fn: Option<String>,
+        in_test:      bool,
+        location:     SourceLocation,
+    ) -> Self {
+        Self { segments, kind, enclosing_fn, in_test, location }
+    }
+
+    pub fn path(&self) -> String {
+        self.segments.join("::")
+    }
+}
+
+impl fmt::Display for SymbolReference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}  {} {}", self.location, self.kind, self.path())?;
+        if let Some(caller) = &self.enclosing_fn {
+            write!(f, " in {}", caller)?;
+        }
+        if self.in_test {
+            write!(f, " (test)")?;
+        }
+        Ok(())
+    }
+}
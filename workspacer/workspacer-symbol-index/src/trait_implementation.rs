@@ -0,0 +1,36 @@
+// ---------------- [ File: workspacer-symbol-index/src/trait_implementation.rs ]
+crate::ix!();
+
+/// `impl<..> Trait for SelfType`, with generic arguments stripped from the trait name so that
+/// `impl From<A> for B` is found under `From`.
+#[derive(Getters,Serialize,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct TraitImplementation {
+    trait_name: String,
+    self_type:  String,
+
+    /// A blanket impl (`impl<T: X> Trait for T`) implements the trait for every `T` satisfying
+    /// the bounds, not for a type called `T`
+    is_blanket: bool,
+    in_test:    bool,
+    location:   SourceLocation,
+}
+
+impl TraitImplementation {
+    pub fn new(trait_name: String, self_type: String, is_blanket: bool, in_test: bool, location: SourceLocation) -> Self {
+        Self { trait_name, self_type, is_blanket, in_test, location }
+    }
+}
+
+impl fmt::Display for TraitImplementation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}  impl {} for {}", self.location, self.trait_name, self.self_type)?;
+        if self.is_blanket {
+            write!(f, " (blanket)")?;
+        }
+        if self.in_test {
+            write!(f, " (test)")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,178 @@
+// ---------------- [ File: workspacer-symbol-index/src/workspace_symbol_index.rs ]
+crate::ix!();
+
+/// Definitions, references, trait impls and re-exports for every member crate, answering the
+/// queries behind `ws query`.
+#[derive(Getters,Serialize,Debug,Clone,Default,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct WorkspaceSymbolIndex {
+    crate_names: Vec<String>,
+    definitions: Vec<SymbolDefinition>,
+    references:  Vec<SymbolReference>,
+    trait_impls: Vec<TraitImplementation>,
+    re_exports:  Vec<ReExport>,
+}
+
+impl WorkspaceSymbolIndex {
+
+    pub fn add_crate(&mut self, crate_name: &str) {
+        if !self.crate_names.iter().any(|c| c == crate_name) {
+            self.crate_names.push(crate_name.to_string());
+        }
+    }
+
+    pub fn add_source_file(&mut self, crate_name: &str, file: &str, text: &str, all_test: bool) {
+        self.add_crate(crate_name);
+        let (definitions, references, trait_impls, re_exports) =
+            index_source_file(crate_name, file, text, all_test).into_parts();
+        self.definitions.extend(definitions);
+        self.references.extend(references);
+        self.trait_impls.extend(trait_impls);
+        self.re_exports.extend(re_exports);
+    }
+
+    pub fn definitions_of(&self, pattern: &SymbolPattern) -> Vec<&SymbolDefinition> {
+        self.definitions.iter().filter(|d| pattern.matches_qualified(d.qualified_name())).collect()
+    }
+
+    pub fn references_to(&self, pattern: &SymbolPattern) -> Vec<&SymbolReference> {
+        self.references.iter().filter(|r| pattern.matches_reference(r)).collect()
+    }
+
+    /// Call sites of the pattern, each carrying the fn it is called from.
+    pub fn callers_of(&self, pattern: &SymbolPattern) -> Vec<&SymbolReference> {
+        self.references_to(pattern).into_iter().filter(|r| r.kind().is_call()).collect()
+    }
+
+    /// Calls made from inside the fns the pattern names.
+    pub fn callees_of(&self, pattern: &SymbolPattern) -> Vec<&SymbolReference> {
+        self.references
+            .iter()
+            .filter(|r| r.kind().is_call())
+            .filter(|r| r.enclosing_fn().as_deref().is_some_and(|f| pattern.matches_qualified(f)))
+            .collect()
+    }
+
+    pub fn implementors_of(&self, pattern: &SymbolPattern) -> Vec<&TraitImplementation> {
+        self.trait_impls.iter().filter(|i| pattern.matches_qualified(i.trait_name())).collect()
+    }
+
+    /// Every crate the pattern's item reaches through `pub use`, breadth first from the crates
+    /// defining it. A method is followed through its type (`BatchWorkspace::new_temp` travels
+    /// with `BatchWorkspace`), and an alias is followed under its new name.
+    pub fn re_export_chain(&self, pattern: &SymbolPattern) -> Vec<ReExportHop> {
+        let mut frontier: VecDeque<(String, String)> = self
+            .definitions_of(pattern)
+            .into_iter()
+            .filter(|d| !d.in_test())
+            .map(|d| {
+                let item = d.qualified_name().split("::").next().unwrap_or_default().to_string();
+                (d.location().crate_name().clone(), item)
+            })
+            .collect();
+
+        let mut seen: HashSet<(String, String)> = frontier.iter().cloned().collect();
+        let mut hops = vec![];
+
+        while let Some((from_crate, symbol)) = frontier.pop_front() {
+            let from_path = from_crate.replace('-', "_");
+
+            for re_export in &self.re_exports {
+                if re_export.crate_name() == from_crate || re_export.segments().first() != Some(&from_path) {
+                    continue;
+                }
+                let exported = match re_export.exported_name() {
+                    None => symbol.clone(),
+                    Some(name) if re_export.segments().last() == Some(&symbol) => name.to_string(),
+                    Some(_) => continue,
+                };
+
+                hops.push(ReExportHop::new(symbol.clone(), from_crate.clone(), re_export.crate_name().to_string(), re_export.clone()));
+
+                let next = (re_export.crate_name().to_string(), exported);
+                if seen.insert(next.clone()) {
+                    frontier.push_back(next);
+                }
+            }
+        }
+
+        hops
+    }
+}
+
+#[cfg(test)]
+mod test_workspace_symbol_index {
+    use super::*;
+
+    fn index() -> WorkspaceSymbolIndex {
+        let mut index = WorkspaceSymbolIndex::default();
+        index.add_source_file("batch-core", "src/batch.rs", r#"
+pub trait CrateHandleInterface {}
+pub struct BatchWorkspace;
+impl BatchWorkspace {
+    pub fn new_temp() -> Self { Self::init(); BatchWorkspace }
+    fn init() {}
+}
+impl CrateHandleInterface for BatchWorkspace {}
+"#, false);
+        index.add_source_file("batch", "src/lib.rs", "pub use batch_core::*;\n", false);
+        index.add_source_file("app", "src/lib.rs", r#"
+pub use batch::{BatchWorkspace as Ws};
+struct Handle;
+impl batch::CrateHandleInterface for Handle {}
+fn run() { let w = Ws::new_temp(); w.go(); }
+"#, false);
+        index.add_source_file("app", "tests/it.rs", "fn it() { batch::BatchWorkspace::new_temp(); }\n", true);
+        index
+    }
+
+    fn pattern(text: &str) -> SymbolPattern {
+        SymbolPattern::parse(text).unwrap()
+    }
+
+    #[traced_test]
+    fn answers_definition_and_caller_queries() {
+        let index = index();
+        assert_eq!(index.crate_names(), &vec!["batch-core".to_string(), "batch".into(), "app".into()]);
+
+        let defs = index.definitions_of(&pattern("BatchWorkspace::new_temp"));
+        assert_eq!(defs.len(), 1);
+        assert_eq!(defs[0].location().crate_name(), "batch-core");
+
+        // `Ws::new_temp` goes through an alias a syntax index cannot see through
+        let callers: Vec<(String, Option<String>)> = index
+            .callers_of(&pattern("BatchWorkspace::new_temp"))
+            .into_iter()
+            .map(|r| (r.location().crate_name().clone(), r.enclosing_fn().clone()))
+            .collect();
+        assert_eq!(callers, vec![("app".to_string(), Some("it".to_string()))]);
+
+        let callees: Vec<String> = index.callees_of(&pattern("BatchWorkspace::new_temp")).iter().map(|r| r.path()).collect();
+        assert_eq!(callees, vec!["BatchWorkspace::init"]);
+    }
+
+    #[traced_test]
+    fn finds_implementors_across_crates() {
+        let index = index();
+        let implementors: Vec<String> = index
+            .implementors_of(&pattern("CrateHandleInterface"))
+            .iter()
+            .map(|i| format!("{} {}", i.location().crate_name(), i.self_type()))
+            .collect();
+        assert_eq!(implementors, vec!["batch-core BatchWorkspace", "app Handle"]);
+    }
+
+    #[traced_test]
+    fn follows_re_export_chains() {
+        let index = index();
+        let chain: Vec<String> = index
+            .re_export_chain(&pattern("BatchWorkspace"))
+            .iter()
+            .map(|h| format!("{} -> {} ({})", h.from_crate(), h.to_crate(), h.via().use_path()))
+            .collect();
+        assert_eq!(chain, vec![
+            "batch-core -> batch (batch_core::*)",
+            "batch -> app (batch::BatchWorkspace as Ws)",
+        ]);
+    }
+}
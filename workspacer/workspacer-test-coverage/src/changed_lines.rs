@@ -0,0 +1,116 @@
+// ---------------- [ File: workspacer-test-coverage/src/changed_lines.rs ]
+crate::ix!();
+
+/// The lines added or modified since some git ref, keyed by absolute file path.
+#[derive(Getters,Default,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct ChangedLines {
+    /// Inclusive (start, end) line ranges in the new version of each file
+    ranges: BTreeMap<PathBuf, Vec<(usize, usize)>>,
+}
+
+impl ChangedLines {
+
+    /// Runs `git diff --unified=0 <git_ref>` in the repository containing `dir`.
+    pub async fn since(dir: &Path, git_ref: &str) -> Result<Self, TestCoverageError> {
+        let diff_failed = |e| match e {
+            GitError::IoError { io, .. }           => TestCoverageError::CommandError { io },
+            GitError::CommandFailed { stderr, .. } => TestCoverageError::GitDiffFailed { git_ref: git_ref.to_string(), stderr },
+            other                                  => TestCoverageError::GitDiffFailed { git_ref: git_ref.to_string(), stderr: format!("{:?}", other) },
+        };
+
+        let repo_root = git_repo_root(dir).await.map_err(diff_failed)?;
+
+        let diff = run_git(&repo_root, &["diff", "--unified=0", "--no-color", "--no-ext-diff", git_ref, "--"])
+            .await
+            .map_err(diff_failed)?;
+
+        Ok(Self::from_unified_diff(&repo_root, &diff))
+    }
+
+    /// Parses the `+++ b/<file>` and `@@ -a,b +c,d @@` headers of a zero-context diff.
+    /// Pure deletions (`+c,0`) are recorded as the single line they were removed before.
+    pub fn from_unified_diff(repo_root: &Path, diff: &str) -> Self {
+        let mut ranges: BTreeMap<PathBuf, Vec<(usize, usize)>> = BTreeMap::new();
+        let mut current: Option<PathBuf> = None;
+
+        for line in diff.lines() {
+            if let Some(target) = line.strip_prefix("+++ ") {
+                current = target
+                    .strip_prefix("b/")
+                    .map(|rel| repo_root.join(rel));
+                continue;
+            }
+
+            let Some(header) = line.strip_prefix("@@ ") else { continue };
+            let Some(file) = &current else { continue };
+
+            let Some(new_side) = header.split_whitespace().find(|t| t.starts_with('+')) else { continue };
+            let mut parts = new_side[1..].splitn(2, ',');
+            let Some(Ok(start)) = parts.next().map(str::parse::<usize>) else { continue };
+            let len = parts.next().and_then(|l| l.parse::<usize>().ok()).unwrap_or(1);
+
+            let range = match len {
+                0 => (start.max(1), start.max(1)),
+                n => (start, start + n - 1),
+            };
+            ranges.entry(file.clone()).or_default().push(range);
+        }
+
+        Self { ranges }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains_line(&self, file: &Path, line: usize) -> bool {
+        self.ranges_for(file).iter().any(|(s, e)| *s <= line && line <= *e)
+    }
+
+    pub fn touches(&self, file: &Path, start: usize, end: usize) -> bool {
+        self.ranges_for(file).iter().any(|(s, e)| *s <= end && start <= *e)
+    }
+
+    fn ranges_for(&self, file: &Path) -> &[(usize, usize)] {
+        self.ranges.get(file).map(|v| v.as_slice()).unwrap_or(&[])
+    }
+}
+
+#[cfg(test)]
+mod test_changed_lines {
+    use super::*;
+
+    const DIFF: &str = "\
+diff --git a/demo/src/lib.rs b/demo/src/lib.rs
+index 111..222 100644
+--- a/demo/src/lib.rs
++++ b/demo/src/lib.rs
+@@ -3,0 +4,2 @@ fn a() {
++    let x = 1;
++    let y = 2;
+@@ -10 +12 @@ fn b() {
+-    old();
++    new();
+@@ -20,3 +22,0 @@ fn c() {
+diff --git a/gone.rs b/gone.rs
+deleted file mode 100644
+--- a/gone.rs
++++ /dev/null
+@@ -1,2 +0,0 @@
+";
+
+    #[test]
+    fn parses_hunks_into_new_side_ranges() {
+        let changed = ChangedLines::from_unified_diff(Path::new("/w"), DIFF);
+        let file = PathBuf::from("/w/demo/src/lib.rs");
+
+        assert_eq!(changed.ranges().get(&file).unwrap(), &vec![(4, 5), (12, 12), (22, 22)]);
+        assert_eq!(changed.ranges().len(), 1);
+
+        assert!(changed.contains_line(&file, 5));
+        assert!(!changed.contains_line(&file, 6));
+        assert!(changed.touches(&file, 1, 4));
+        assert!(!changed.touches(Path::new("/w/other.rs"), 1, 100));
+    }
+}
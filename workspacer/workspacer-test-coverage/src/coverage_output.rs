@@ -0,0 +1,229 @@
+// ---------------- [ File: workspacer-test-coverage/src/coverage_output.rs ]
+crate::ix!();
+
+impl DetailedCoverageReport {
+
+    /// Renders the report as an LCOV tracefile (one `SF:` record per file).
+    ///
+    /// Functions are reported with the highest hit count of any of their lines, since
+    /// tarpaulin does not count calls.
+    pub fn to_lcov(&self) -> String {
+        let mut out = String::new();
+
+        for c in self.crates() {
+            for file in c.files() {
+                out.push_str("TN:\n");
+                out.push_str(&format!("SF:{}\n", file.path().display()));
+
+                let fns: Vec<_> = c.items().iter().filter(|i| i.file() == file.path()).collect();
+                for item in &fns {
+                    out.push_str(&format!("FN:{},{}\n", item.line_start(), lcov_fn_name(item)));
+                }
+                let mut hit_fns = 0;
+                for item in &fns {
+                    let hits = file
+                        .line_hits()
+                        .range(*item.line_start()..=*item.line_end())
+                        .map(|(_, h)| *h)
+                        .max()
+                        .unwrap_or(0);
+                    if hits > 0 {
+                        hit_fns += 1;
+                    }
+                    out.push_str(&format!("FNDA:{},{}\n", hits, lcov_fn_name(item)));
+                }
+                out.push_str(&format!("FNF:{}\nFNH:{}\n", fns.len(), hit_fns));
+
+                for (line, hits) in file.line_hits() {
+                    out.push_str(&format!("DA:{},{}\n", line, hits));
+                }
+                out.push_str(&format!("LF:{}\nLH:{}\n", file.coverable_lines(), file.covered_lines()));
+                out.push_str("end_of_record\n");
+            }
+        }
+
+        out
+    }
+
+    pub async fn write_lcov(&self, path: &Path) -> Result<(), TestCoverageError> {
+        write_output(path, self.to_lcov()).await
+    }
+
+    /// Renders a single self-contained HTML page: a per-crate summary table, then each file with
+    /// its functions and its source, covered lines in green and missed lines in red.
+    ///
+    /// `sources` maps file paths to their text; files without an entry are listed without source.
+    pub fn to_html(&self, sources: &HashMap<PathBuf, String>) -> String {
+        let mut out = String::new();
+
+        out.push_str("<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\"><title>coverage</title>\n<style>\n");
+        out.push_str("body{font-family:sans-serif} table{border-collapse:collapse} td,th{padding:2px 8px;text-align:left}\n");
+        out.push_str("pre{margin:0} .hit{background:#dfd} .miss{background:#fdd} .num{color:#888;text-align:right}\n");
+        out.push_str("</style></head><body>\n");
+
+        let summary = self.summary();
+        out.push_str(&format!(
+            "<h1>Coverage {:.2}% ({}/{} lines)</h1>\n",
+            summary.total_coverage(), summary.covered_lines(), summary.total_lines()
+        ));
+        if let Some(git_ref) = self.changed_since() {
+            out.push_str(&format!("<p>restricted to changes since <code>{}</code></p>\n", html_escape(git_ref)));
+        }
+
+        out.push_str("<table><tr><th>crate</th><th>coverage</th><th>lines</th><th>uncovered pub fns</th></tr>\n");
+        for c in self.crates() {
+            out.push_str(&format!(
+                "<tr><td><a href=\"#{0}\">{0}</a></td><td>{1:.2}%</td><td>{2}/{3}</td><td>{4}</td></tr>\n",
+                html_escape(c.crate_name()), c.percent(), c.covered_lines(), c.coverable_lines(), c.uncovered_pub_fns().count()
+            ));
+        }
+        out.push_str("</table>\n");
+
+        for c in self.crates() {
+            out.push_str(&format!("<h2 id=\"{0}\">{0}</h2>\n", html_escape(c.crate_name())));
+
+            for file in c.files() {
+                out.push_str(&format!(
+                    "<h3>{} &mdash; {:.2}%</h3>\n<table>\n",
+                    html_escape(&file.path().display().to_string()),
+                    percent_of(file.covered_lines(), file.coverable_lines())
+                ));
+                for item in c.items().iter().filter(|i| i.file() == file.path()) {
+                    out.push_str(&format!(
+                        "<tr class=\"{}\"><td>{}</td><td>{:.2}%</td><td>lines {}-{}</td></tr>\n",
+                        if item.is_uncovered() { "miss" } else { "hit" },
+                        html_escape(&item.display_name()),
+                        item.percent(),
+                        item.line_start(),
+                        item.line_end()
+                    ));
+                }
+                out.push_str("</table>\n");
+
+                let Some(text) = sources.get(file.path()) else { continue };
+                out.push_str("<table>\n");
+                for (idx, line) in text.lines().enumerate() {
+                    let n = idx + 1;
+                    let class = match file.line_hits().get(&n) {
+                        Some(0) => "miss",
+                        Some(_) => "hit",
+                        None    => "",
+                    };
+                    out.push_str(&format!(
+                        "<tr class=\"{}\"><td class=\"num\">{}</td><td><pre>{}</pre></td></tr>\n",
+                        class, n, html_escape(line)
+                    ));
+                }
+                out.push_str("</table>\n");
+            }
+        }
+
+        out.push_str("</body></html>\n");
+        out
+    }
+
+    /// Reads every reported source file and writes the HTML page to `path`.
+    pub async fn write_html(&self, path: &Path) -> Result<(), TestCoverageError> {
+        let mut sources = HashMap::new();
+        for file in self.crates().iter().flat_map(|c| c.files()) {
+            match tokio::fs::read_to_string(file.path()).await {
+                Ok(text) => { sources.insert(file.path().clone(), text); }
+                Err(e)   => warn!("could not read {:?} for the html report: {}", file.path(), e),
+            }
+        }
+        write_output(path, self.to_html(&sources)).await
+    }
+}
+
+fn lcov_fn_name(item: &ItemCoverage) -> String {
+    // commas separate fields in FN/FNDA records
+    item.display_name().replace(',', ";")
+}
+
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+async fn write_output(path: &Path, contents: String) -> Result<(), TestCoverageError> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .map_err(|io| TestCoverageError::ReportIoError { path: parent.to_path_buf(), io: io.into() })?;
+    }
+    tokio::fs::write(path, contents)
+        .await
+        .map_err(|io| TestCoverageError::ReportIoError { path: path.to_path_buf(), io: io.into() })
+}
+
+#[cfg(test)]
+mod test_coverage_output {
+    use super::*;
+
+    fn report() -> DetailedCoverageReport {
+        let path = PathBuf::from("/w/demo/src/lib.rs");
+        let hits = [(2, 3), (6, 0)].into_iter().collect();
+        let item = |name: &str, start: usize, end: usize, covered| {
+            ItemCoverageBuilder::default()
+                .crate_name("demo")
+                .file(path.clone())
+                .name(name)
+                .is_pub(true)
+                .line_start(start)
+                .line_end(end)
+                .covered(covered)
+                .coverable(1usize)
+                .build()
+                .unwrap()
+        };
+        DetailedCoverageReport::new(vec![CrateCoverage::new(
+            "demo".to_string(),
+            vec![FileCoverage::new(path.clone(), hits)],
+            vec![item("a", 1, 3, 1usize), item("b", 5, 7, 0usize)],
+        )])
+    }
+
+    #[test]
+    fn lcov_records() {
+        let lcov = report().to_lcov();
+        let expected = "\
+TN:
+SF:/w/demo/src/lib.rs
+FN:1,a
+FN:5,b
+FNDA:3,a
+FNDA:0,b
+FNF:2
+FNH:1
+DA:2,3
+DA:6,0
+LF:2
+LH:1
+end_of_record
+";
+        assert_eq!(lcov, expected);
+    }
+
+    #[test]
+    fn html_marks_lines_and_escapes() {
+        let sources: HashMap<_, _> = [(
+            PathBuf::from("/w/demo/src/lib.rs"),
+            "pub fn a() {\n    x < y;\n}\n\nfn b() {\n    z();\n}\n".to_string(),
+        )].into_iter().collect();
+
+        let html = report().to_html(&sources);
+        assert!(html.contains("x &lt; y;"));
+        assert!(html.contains("<tr class=\"hit\"><td class=\"num\">2</td>"));
+        assert!(html.contains("<tr class=\"miss\"><td class=\"num\">6</td>"));
+    }
+
+    #[tokio::test]
+    async fn writes_into_missing_directories() {
+        let dir  = tempfile::tempdir().unwrap();
+        let path = dir.path().join("out").join("lcov.info");
+        report().write_lcov(&path).await.unwrap();
+        assert!(tokio::fs::read_to_string(&path).await.unwrap().contains("end_of_record"));
+    }
+}
@@ -0,0 +1,94 @@
+// ---------------- [ File: workspacer-test-coverage/src/coverage_thresholds.rs ]
+crate::ix!();
+
+/// Minimum line coverage per crate, taken from the workspace settings
+/// (`workspacer.toml` or `[workspace.metadata.workspacer]`):
+///
+/// ```toml
+/// [coverage]
+/// min-coverage = 60.0
+///
+/// [crates.my-core-crate]
+/// min-coverage = 85.0
+/// ```
+#[derive(Getters,Default,Debug,Clone,PartialEq)]
+#[getset(get="pub")]
+pub struct CoverageThresholds {
+    default_minimum: Option<f32>,
+    per_crate:       BTreeMap<String, f32>,
+}
+
+impl CoverageThresholds {
+
+    pub fn new(default_minimum: Option<f32>, per_crate: BTreeMap<String, f32>) -> Self {
+        Self { default_minimum, per_crate }
+    }
+
+    /// Loads the workspace settings under `workspace_root`; no settings means no thresholds.
+    pub async fn load(workspace_root: &Path) -> Result<Self, TestCoverageError> {
+        let settings = WorkspaceSettings::load(workspace_root)
+            .await
+            .map_err(|e| TestCoverageError::InvalidCoverageConfig {
+                path:    workspace_root.to_path_buf(),
+                message: e.to_string(),
+            })?;
+
+        Ok(Self::from_settings(&settings))
+    }
+
+    /// The range checks already happened when the settings were loaded.
+    pub fn from_settings(settings: &WorkspaceSettings) -> Self {
+        let per_crate = settings
+            .crates()
+            .iter()
+            .filter_map(|(name, o)| o.min_coverage().map(|min| (name.clone(), min)))
+            .collect();
+
+        Self { default_minimum: *settings.coverage().min_coverage(), per_crate }
+    }
+
+    pub fn minimum_for(&self, crate_name: &str) -> Option<f32> {
+        self.per_crate.get(crate_name).copied().or(self.default_minimum)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.default_minimum.is_none() && self.per_crate.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test_coverage_thresholds {
+    use super::*;
+
+    #[test]
+    fn per_crate_overrides_default() {
+        let settings = WorkspaceSettings::from_workspacer_toml(r#"
+            [coverage]
+            min-coverage = 50
+
+            [crates.b]
+            min-coverage = 90.5
+
+            [crates.c]
+            skip-bump = true
+        "#).unwrap();
+
+        let t = CoverageThresholds::from_settings(&settings);
+        assert_eq!(t.minimum_for("a"), Some(50.0));
+        assert_eq!(t.minimum_for("b"), Some(90.5));
+        assert_eq!(t.minimum_for("c"), Some(50.0));
+        assert_eq!(t.per_crate().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn absent_settings_and_bad_values() {
+        let dir = tempdir().unwrap();
+        assert!(CoverageThresholds::load(dir.path()).await.unwrap().is_empty());
+
+        tokio::fs::write(dir.path().join("Cargo.toml"), "[workspace.metadata.workspacer.coverage]\nmin-coverage = 150\n").await.unwrap();
+        assert!(matches!(
+            CoverageThresholds::load(dir.path()).await,
+            Err(TestCoverageError::InvalidCoverageConfig { .. })
+        ));
+    }
+}
@@ -0,0 +1,220 @@
+// ---------------- [ File: workspacer-test-coverage/src/detailed_coverage_report.rs ]
+crate::ix!();
+
+/// Per-file and per-function coverage for one crate.
+#[derive(Serialize,Getters,Debug,Clone)]
+#[getset(get="pub")]
+pub struct CrateCoverage {
+    crate_name: String,
+    files:      Vec<FileCoverage>,
+    items:      Vec<ItemCoverage>,
+}
+
+impl CrateCoverage {
+
+    pub fn new(crate_name: String, files: Vec<FileCoverage>, items: Vec<ItemCoverage>) -> Self {
+        Self { crate_name, files, items }
+    }
+
+    pub fn covered_lines(&self) -> usize {
+        self.files.iter().map(|f| f.covered_lines()).sum()
+    }
+
+    pub fn coverable_lines(&self) -> usize {
+        self.files.iter().map(|f| f.coverable_lines()).sum()
+    }
+
+    pub fn percent(&self) -> f32 {
+        percent_of(self.covered_lines(), self.coverable_lines())
+    }
+
+    pub fn uncovered_pub_fns(&self) -> impl Iterator<Item=&ItemCoverage> {
+        self.items.iter().filter(|i| *i.is_pub() && i.is_uncovered())
+    }
+}
+
+/// Coverage broken down by crate, file and function, built from tarpaulin's JSON report.
+#[derive(Serialize,Getters,Debug,Clone)]
+#[getset(get="pub")]
+pub struct DetailedCoverageReport {
+    crates: Vec<CrateCoverage>,
+
+    /// The git ref passed to `restrict_to_changed`, if any
+    changed_since: Option<String>,
+}
+
+impl DetailedCoverageReport {
+
+    pub fn new(crates: Vec<CrateCoverage>) -> Self {
+        Self { crates, changed_since: None }
+    }
+
+    pub fn covered_lines(&self) -> usize {
+        self.crates.iter().map(|c| c.covered_lines()).sum()
+    }
+
+    pub fn coverable_lines(&self) -> usize {
+        self.crates.iter().map(|c| c.coverable_lines()).sum()
+    }
+
+    /// The totals, in the same shape `RunTestsWithCoverage` reports.
+    pub fn summary(&self) -> TestCoverageReport {
+        let covered = self.covered_lines();
+        let total   = self.coverable_lines();
+        TestCoverageReport::new(percent_of(covered, total), covered, total - covered, total)
+    }
+
+    pub fn uncovered_pub_fns(&self) -> Vec<&ItemCoverage> {
+        self.crates.iter().flat_map(|c| c.uncovered_pub_fns()).collect()
+    }
+
+    /// Narrows the report to what changed: functions touching a changed line, and within each
+    /// file only the changed lines themselves.
+    pub fn restrict_to_changed(&mut self, git_ref: &str, changed: &ChangedLines) {
+        for c in &mut self.crates {
+            c.items.retain(|i| changed.touches(i.file(), *i.line_start(), *i.line_end()));
+            for f in &mut c.files {
+                let path = f.path().clone();
+                f.retain_lines(|line| changed.contains_line(&path, line));
+            }
+            c.files.retain(|f| f.coverable_lines() > 0);
+        }
+        self.crates.retain(|c| !c.files.is_empty() || !c.items.is_empty());
+        self.changed_since = Some(git_ref.to_string());
+    }
+
+    /// Fails with every crate that falls short of its configured minimum.
+    pub fn check_thresholds(&self, thresholds: &CoverageThresholds) -> Result<(), TestCoverageError> {
+        let mut failures = String::new();
+
+        for c in &self.crates {
+            let Some(minimum) = thresholds.minimum_for(c.crate_name()) else { continue };
+            let actual = c.percent();
+            if actual + f32::EPSILON < minimum {
+                failures.push_str(&format!(
+                    "crate '{}' has {:.2}% line coverage, below the minimum of {:.2}%\n",
+                    c.crate_name(), actual, minimum
+                ));
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(TestCoverageError::CoverageBelowThreshold { rendered: failures })
+        }
+    }
+}
+
+impl fmt::Display for DetailedCoverageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(git_ref) = &self.changed_since {
+            writeln!(f, "(restricted to changes since '{}')", git_ref)?;
+        }
+
+        for c in &self.crates {
+            writeln!(
+                f,
+                "== {} {:.2}% ({}/{} lines)",
+                c.crate_name(), c.percent(), c.covered_lines(), c.coverable_lines()
+            )?;
+
+            for file in c.files() {
+                writeln!(
+                    f,
+                    "   {:>6.2}%  {}",
+                    percent_of(file.covered_lines(), file.coverable_lines()),
+                    file.path().display()
+                )?;
+                for item in c.items().iter().filter(|i| i.file() == file.path()) {
+                    writeln!(
+                        f,
+                        "      {:>6.2}%  {}{} (lines {}-{})",
+                        item.percent(),
+                        if *item.is_pub() { "pub " } else { "" },
+                        item.display_name(),
+                        item.line_start(),
+                        item.line_end()
+                    )?;
+                }
+            }
+        }
+
+        let uncovered = self.uncovered_pub_fns();
+        if !uncovered.is_empty() {
+            writeln!(f, "\nuncovered pub functions:")?;
+            for item in uncovered {
+                writeln!(f, "   {} {} ({}:{})", item.crate_name(), item.display_name(), item.file().display(), item.line_start())?;
+            }
+        }
+
+        let summary = self.summary();
+        writeln!(
+            f,
+            "\ntotal => {:.2}% (covered: {}, missed: {}, total: {})",
+            summary.total_coverage(), summary.covered_lines(), summary.missed_lines(), summary.total_lines()
+        )
+    }
+}
+
+#[cfg(test)]
+mod test_detailed_coverage_report {
+    use super::*;
+
+    fn item(name: &str, is_pub: bool, lines: (usize, usize), covered: usize, coverable: usize) -> ItemCoverage {
+        ItemCoverageBuilder::default()
+            .crate_name("demo")
+            .file(PathBuf::from("/w/demo/src/lib.rs"))
+            .name(name)
+            .is_pub(is_pub)
+            .line_start(lines.0)
+            .line_end(lines.1)
+            .covered(covered)
+            .coverable(coverable)
+            .build()
+            .unwrap()
+    }
+
+    fn report() -> DetailedCoverageReport {
+        let hits = [(2, 1), (3, 1), (6, 0), (7, 0)].into_iter().collect();
+        let file = FileCoverage::new(PathBuf::from("/w/demo/src/lib.rs"), hits);
+        DetailedCoverageReport::new(vec![CrateCoverage::new(
+            "demo".to_string(),
+            vec![file],
+            vec![item("a", true, (1, 4), 2, 2), item("b", true, (5, 8), 0, 2), item("c", false, (9, 9), 0, 0)],
+        )])
+    }
+
+    #[test]
+    fn totals_and_uncovered_pub_fns() {
+        let r = report();
+        assert_eq!(r.summary().covered_lines(), 2);
+        assert_eq!(r.summary().total_lines(), 4);
+
+        let names: Vec<_> = r.uncovered_pub_fns().iter().map(|i| i.name().clone()).collect();
+        assert_eq!(names, vec!["b".to_string()]);
+    }
+
+    #[test]
+    fn thresholds_are_enforced_per_crate() {
+        let r = report();
+        let lenient = CoverageThresholds::new(Some(50.0), BTreeMap::new());
+        assert!(r.check_thresholds(&lenient).is_ok());
+
+        let strict = CoverageThresholds::new(Some(10.0), [("demo".to_string(), 75.0)].into_iter().collect());
+        assert!(matches!(r.check_thresholds(&strict), Err(TestCoverageError::CoverageBelowThreshold { .. })));
+    }
+
+    #[test]
+    fn restricting_to_changes_keeps_touched_items_and_lines() {
+        let mut r = report();
+        let diff = "+++ b/demo/src/lib.rs\n@@ -6 +6,2 @@\n";
+        r.restrict_to_changed("main", &ChangedLines::from_unified_diff(Path::new("/w"), diff));
+
+        let c = &r.crates()[0];
+        assert_eq!(c.items().len(), 1);
+        assert_eq!(c.items()[0].name(), "b");
+        assert_eq!(c.coverable_lines(), 2);
+        assert_eq!(c.covered_lines(), 0);
+    }
+}
@@ -0,0 +1,98 @@
+// ---------------- [ File: workspacer-test-coverage/src/detailed_test_coverage.rs ]
+crate::ix!();
+
+/// Like `RunTestsWithCoverage`, but keeps tarpaulin's per-line data and attributes it to the
+/// functions of each crate.
+#[async_trait]
+pub trait RunDetailedTestCoverage {
+    type Error;
+
+    async fn run_detailed_test_coverage(&self) -> Result<DetailedCoverageReport, Self::Error>;
+}
+
+#[async_trait]
+impl RunDetailedTestCoverage for CrateHandle {
+    type Error = WorkspaceError;
+
+    async fn run_detailed_test_coverage(&self) -> Result<DetailedCoverageReport, Self::Error> {
+        // same assumption as `run_tests_with_coverage`: the workspace is the crate's parent dir
+        let workspace_root = self
+            .root_dir_path_buf()
+            .parent()
+            .map(Path::to_path_buf)
+            .ok_or_else(|| WorkspaceError::IoError {
+                io_error: Arc::new(std::io::Error::new(std::io::ErrorKind::NotFound, "No parent directory")),
+                context:  "finding workspace root from crate path".to_string(),
+            })?;
+
+        TestCoverageCommand::run_with_package(&workspace_root, &self.name()).await?;
+
+        let files = load_tarpaulin_file_coverage(&tarpaulin_output_dir(&workspace_root)).await?;
+        let files_by_path = index_by_path(files);
+
+        let crate_coverage = crate_coverage_for(self, &files_by_path).await?;
+        Ok(DetailedCoverageReport::new(vec![crate_coverage]))
+    }
+}
+
+#[async_trait]
+impl<P,H:CrateHandleInterface<P>> RunDetailedTestCoverage for Workspace<P,H>
+where for<'async_trait> P: From<PathBuf> + AsRef<Path> + Send + Sync + 'async_trait
+{
+    type Error = WorkspaceError;
+
+    async fn run_detailed_test_coverage(&self) -> Result<DetailedCoverageReport, Self::Error> {
+        let workspace_path = self.as_ref();
+
+        TestCoverageCommand::run_in(workspace_path).await?;
+
+        let files = load_tarpaulin_file_coverage(&tarpaulin_output_dir(workspace_path)).await?;
+        let files_by_path = index_by_path(files);
+
+        let mut crates = Vec::new();
+        for crate_arc in self.crates() {
+            let handle = crate_arc.lock().await;
+            crates.push(crate_coverage_for(&*handle, &files_by_path).await?);
+        }
+
+        Ok(DetailedCoverageReport::new(crates))
+    }
+}
+
+fn index_by_path(files: Vec<FileCoverage>) -> HashMap<PathBuf, FileCoverage> {
+    files.into_iter().map(|f| (f.path().clone(), f)).collect()
+}
+
+/// Picks the files under the crate's `src/` out of the tarpaulin data and attributes them.
+async fn crate_coverage_for<H>(
+    handle:        &H,
+    files_by_path: &HashMap<PathBuf, FileCoverage>,
+) -> Result<CrateCoverage, WorkspaceError>
+where
+    H: GetSourceFilesWithExclusions + RootDirPathBuf + Named + Sync,
+{
+    let crate_name = handle.name().to_string();
+    let crate_path = handle.root_dir_path_buf();
+
+    let mut files = Vec::new();
+    let mut items = Vec::new();
+
+    for source_path in handle.source_files_excluding(&[]).await? {
+        // tarpaulin reports canonical absolute paths
+        let canonical = tokio::fs::canonicalize(&source_path).await.unwrap_or(source_path.clone());
+
+        let Some(coverage) = files_by_path.get(&canonical) else {
+            trace!("no coverage data for {:?}", canonical);
+            continue;
+        };
+
+        match tokio::fs::read_to_string(&source_path).await {
+            Ok(text) => items.extend(attribute_file_coverage(&crate_name, &crate_path, &text, coverage)),
+            Err(e)   => warn!("could not read {:?} to attribute coverage: {}", source_path, e),
+        }
+        files.push(coverage.clone());
+    }
+
+    debug!("crate='{}': {} files, {} functions with coverage", crate_name, files.len(), items.len());
+    Ok(CrateCoverage::new(crate_name, files, items))
+}
@@ -0,0 +1,151 @@
+// ---------------- [ File: workspacer-test-coverage/src/file_coverage.rs ]
+crate::ix!();
+
+/// The file tarpaulin writes for `--out Json`, inside `--output-dir`.
+pub const TARPAULIN_JSON_REPORT_FILE_NAME: &str = "tarpaulin-report.json";
+
+/// Where our tarpaulin runs put their reports, relative to the directory they run in.
+pub fn tarpaulin_output_dir(run_dir: &Path) -> PathBuf {
+    run_dir.join("target").join("tarpaulin")
+}
+
+/// Line hit counts for one source file. Only coverable lines appear in `line_hits`.
+#[derive(Serialize,Getters,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct FileCoverage {
+    path:      PathBuf,
+    line_hits: BTreeMap<usize, u64>,
+}
+
+impl FileCoverage {
+
+    pub fn new(path: PathBuf, line_hits: BTreeMap<usize, u64>) -> Self {
+        Self { path, line_hits }
+    }
+
+    pub fn coverable_lines(&self) -> usize {
+        self.line_hits.len()
+    }
+
+    pub fn covered_lines(&self) -> usize {
+        self.line_hits.values().filter(|hits| **hits > 0).count()
+    }
+
+    /// (covered, coverable) for the inclusive line range `start..=end`.
+    pub fn counts_in_lines(&self, start: usize, end: usize) -> (usize, usize) {
+        let mut covered   = 0;
+        let mut coverable = 0;
+        for (_, hits) in self.line_hits.range(start..=end) {
+            coverable += 1;
+            if *hits > 0 {
+                covered += 1;
+            }
+        }
+        (covered, coverable)
+    }
+
+    /// Keeps only the lines for which `keep` returns true.
+    pub fn retain_lines(&mut self, mut keep: impl FnMut(usize) -> bool) {
+        self.line_hits.retain(|line, _| keep(*line));
+    }
+}
+
+/// Parses the `files` array of a tarpaulin JSON report.
+///
+/// Tarpaulin stores each path as a list of components (`["/", "work", "src", "lib.rs"]`) and
+/// each coverable line as a trace `{ "line": 12, "stats": { "Line": 3 } }`.
+pub fn parse_tarpaulin_file_coverage(report: &serde_json::Value) -> Result<Vec<FileCoverage>, TestCoverageError> {
+    let files = report
+        .get("files")
+        .and_then(|f| f.as_array())
+        .ok_or(TestCoverageError::CoverageParseError)?;
+
+    let mut out = Vec::with_capacity(files.len());
+
+    for file in files {
+        let path: PathBuf = match file.get("path") {
+            Some(serde_json::Value::Array(components)) => components
+                .iter()
+                .filter_map(|c| c.as_str())
+                .collect(),
+            Some(serde_json::Value::String(s)) => PathBuf::from(s),
+            _ => {
+                warn!("skipping tarpaulin file entry without a path");
+                continue;
+            }
+        };
+
+        let mut line_hits = BTreeMap::new();
+        for trace in file.get("traces").and_then(|t| t.as_array()).into_iter().flatten() {
+            let Some(line) = trace.get("line").and_then(|l| l.as_u64()) else { continue };
+            let hits = trace
+                .get("stats")
+                .and_then(|s| s.get("Line"))
+                .and_then(|h| h.as_u64())
+                .unwrap_or(0);
+            *line_hits.entry(line as usize).or_insert(0) += hits;
+        }
+
+        out.push(FileCoverage::new(path, line_hits));
+    }
+
+    Ok(out)
+}
+
+/// Reads and parses `tarpaulin-report.json` from `output_dir`.
+pub async fn load_tarpaulin_file_coverage(output_dir: &Path) -> Result<Vec<FileCoverage>, TestCoverageError> {
+    let path = output_dir.join(TARPAULIN_JSON_REPORT_FILE_NAME);
+
+    let text = tokio::fs::read_to_string(&path)
+        .await
+        .map_err(|io| TestCoverageError::ReportIoError { path: path.clone(), io: io.into() })?;
+
+    let value: serde_json::Value = serde_json::from_str(&text).map_err(|e| {
+        error!("Failed to parse {:?}: {}", path, e);
+        TestCoverageError::CoverageParseError
+    })?;
+
+    parse_tarpaulin_file_coverage(&value)
+}
+
+#[cfg(test)]
+mod test_file_coverage {
+    use super::*;
+
+    const SAMPLE: &str = r#"{
+        "files": [
+            {
+                "path": ["/", "w", "demo", "src", "lib.rs"],
+                "content": "",
+                "traces": [
+                    { "line": 2, "address": [1], "length": 1, "stats": { "Line": 4 } },
+                    { "line": 3, "address": [2], "length": 1, "stats": { "Line": 0 } },
+                    { "line": 7, "address": [3], "length": 1, "stats": { "Line": 1 } }
+                ],
+                "covered": 2,
+                "coverable": 3
+            }
+        ],
+        "coverage": 66.6,
+        "covered": 2,
+        "coverable": 3
+    }"#;
+
+    #[test]
+    fn parses_paths_and_traces() {
+        let value: serde_json::Value = serde_json::from_str(SAMPLE).unwrap();
+        let files = parse_tarpaulin_file_coverage(&value).unwrap();
+
+        assert_eq!(files.len(), 1);
+        let f = &files[0];
+        assert_eq!(f.path(), &PathBuf::from("/w/demo/src/lib.rs"));
+        assert_eq!((f.covered_lines(), f.coverable_lines()), (2, 3));
+        assert_eq!(f.counts_in_lines(1, 3), (1, 2));
+    }
+
+    #[test]
+    fn missing_files_array_is_a_parse_error() {
+        let value = serde_json::json!({ "coverage": 10.0 });
+        assert!(parse_tarpaulin_file_coverage(&value).is_err());
+    }
+}
@@ -1,7 +1,11 @@
 // ---------------- [ File: workspacer-test-coverage/src/imports.rs ]
 pub(crate) use generate_report_trait::*;
 pub(crate) use workspacer_3p::*;
+pub(crate) use workspacer_config::*;
+pub(crate) use workspacer_consolidate::*;
 pub(crate) use workspacer_crate::*;
 pub(crate) use workspacer_crate_interface::*;
 pub(crate) use workspacer_errors::*;
+pub(crate) use workspacer_git::*;
 pub(crate) use workspacer_workspace::*;
+pub(crate) use serde_derive::Serialize;
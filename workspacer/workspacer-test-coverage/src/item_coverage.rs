@@ -0,0 +1,230 @@
+// ---------------- [ File: workspacer-test-coverage/src/item_coverage.rs ]
+crate::ix!();
+
+/// Coverage of a single function, as found by `workspacer-consolidate`.
+#[derive(Serialize,Builder,Getters,Debug,Clone,PartialEq)]
+#[getset(get="pub")]
+#[builder(setter(into))]
+pub struct ItemCoverage {
+    crate_name: String,
+    file:       PathBuf,
+
+    /// The function name, qualified by any enclosing inline modules
+    name:       String,
+
+    /// The signature of the impl block the function lives in, if any (e.g. `impl Foo for Bar`)
+    #[builder(default)]
+    container:  Option<String>,
+
+    /// Only a bare `pub`; `pub(crate)` and friends do not count
+    is_pub:     bool,
+
+    line_start: usize,
+    line_end:   usize,
+    covered:    usize,
+    coverable:  usize,
+}
+
+impl ItemCoverage {
+
+    pub fn percent(&self) -> f32 {
+        percent_of(self.covered, self.coverable)
+    }
+
+    /// Coverable but never executed.
+    pub fn is_uncovered(&self) -> bool {
+        self.coverable > 0 && self.covered == 0
+    }
+
+    pub fn display_name(&self) -> String {
+        match &self.container {
+            Some(sig) => format!("{} :: {}", sig, self.name),
+            None      => self.name.clone(),
+        }
+    }
+
+    pub fn overlaps_lines(&self, start: usize, end: usize) -> bool {
+        self.line_start <= end && start <= self.line_end
+    }
+}
+
+pub(crate) fn percent_of(covered: usize, coverable: usize) -> f32 {
+    if coverable == 0 {
+        100.0
+    } else {
+        covered as f32 * 100.0 / coverable as f32
+    }
+}
+
+/// Attributes the line hits of one file to every function (free, in impl blocks, in inline
+/// modules) that `gather_crate_items` finds in `source`. Test-only items are skipped.
+pub fn attribute_file_coverage(
+    crate_name: &str,
+    crate_path: &Path,
+    source:     &str,
+    coverage:   &FileCoverage,
+) -> Vec<ItemCoverage> {
+    let parse   = SourceFile::parse(source, Edition::Edition2021);
+    let sf      = parse.tree();
+    let options = ConsolidationOptions::new().with_private_items();
+
+    let items = gather_crate_items(
+        &sf,
+        &options,
+        &coverage.path().to_path_buf(),
+        &crate_path.to_path_buf(),
+    );
+
+    let lines = LineIndex::new(source);
+    let mut out = Vec::new();
+    collect_fn_coverage(crate_name, coverage, &lines, &items, "", None, &mut out);
+    out
+}
+
+fn collect_fn_coverage(
+    crate_name: &str,
+    coverage:   &FileCoverage,
+    lines:      &LineIndex,
+    items:      &[ConsolidatedItem],
+    mod_prefix: &str,
+    container:  Option<&str>,
+    out:        &mut Vec<ItemCoverage>,
+) {
+    for item in items {
+        match item {
+            ConsolidatedItem::Fn(f) => {
+                if let Some(ic) = fn_coverage(crate_name, coverage, lines, f, mod_prefix, container) {
+                    out.push(ic);
+                }
+            }
+            ConsolidatedItem::ImplBlock(ib) => {
+                for m in ib.methods() {
+                    if let Some(ic) = fn_coverage(crate_name, coverage, lines, m, mod_prefix, Some(ib.signature_text())) {
+                        out.push(ic);
+                    }
+                }
+            }
+            ConsolidatedItem::Module(mi) => {
+                let prefix = format!("{}{}::", mod_prefix, mi.mod_name());
+                collect_fn_coverage(crate_name, coverage, lines, mi.items(), &prefix, container, out);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn fn_coverage(
+    crate_name: &str,
+    coverage:   &FileCoverage,
+    lines:      &LineIndex,
+    item:       &CrateInterfaceItem<ast::Fn>,
+    mod_prefix: &str,
+    container:  Option<&str>,
+) -> Option<ItemCoverage> {
+    let func = item.item();
+    let name = func.name()?.text().to_string();
+
+    let is_pub = func
+        .visibility()
+        .map(|v| v.syntax().text().to_string() == "pub")
+        .unwrap_or(false);
+
+    let range      = item.text_range();
+    let line_start = lines.line_of(range.start().into());
+    let line_end   = lines.line_of(range.end().into());
+
+    let (covered, coverable) = coverage.counts_in_lines(line_start, line_end);
+
+    ItemCoverageBuilder::default()
+        .crate_name(crate_name)
+        .file(coverage.path().clone())
+        .name(format!("{}{}", mod_prefix, name))
+        .container(container.map(str::to_string))
+        .is_pub(is_pub)
+        .line_start(line_start)
+        .line_end(line_end)
+        .covered(covered)
+        .coverable(coverable)
+        .build()
+        .ok()
+}
+
+/// Maps byte offsets to 1-based line numbers.
+struct LineIndex {
+    newline_offsets: Vec<usize>,
+}
+
+impl LineIndex {
+
+    fn new(text: &str) -> Self {
+        Self {
+            newline_offsets: text.match_indices('\n').map(|(i, _)| i).collect(),
+        }
+    }
+
+    fn line_of(&self, offset: usize) -> usize {
+        self.newline_offsets.partition_point(|nl| *nl < offset) + 1
+    }
+}
+
+#[cfg(test)]
+mod test_attribute_file_coverage {
+    use super::*;
+
+    const SOURCE: &str = "\
+pub fn covered() {
+    let _ = 1;
+}
+
+fn private_uncovered() {
+    let _ = 2;
+}
+
+pub struct S;
+
+impl S {
+    pub fn method(&self) {
+        let _ = 3;
+    }
+}
+
+pub mod inner {
+    pub fn nested() {
+        let _ = 4;
+    }
+}
+";
+
+    fn coverage() -> FileCoverage {
+        let hits = [(2, 5), (6, 0), (13, 0), (19, 2)].into_iter().collect();
+        FileCoverage::new(PathBuf::from("/w/demo/src/lib.rs"), hits)
+    }
+
+    #[test]
+    fn attributes_lines_to_functions() {
+        let items = attribute_file_coverage("demo", Path::new("/w/demo"), SOURCE, &coverage());
+        let by_name: HashMap<_, _> = items.iter().map(|i| (i.name().as_str(), i)).collect();
+
+        let covered = by_name["covered"];
+        assert!(*covered.is_pub());
+        assert_eq!((*covered.line_start(), *covered.line_end()), (1, 3));
+        assert_eq!((*covered.covered(), *covered.coverable()), (1, 1));
+
+        assert!(!*by_name["private_uncovered"].is_pub());
+        assert!(by_name["private_uncovered"].is_uncovered());
+
+        let method = by_name["method"];
+        assert!(method.container().as_deref().unwrap_or_default().contains("impl S"));
+        assert!(method.is_uncovered());
+
+        assert!(!by_name["inner::nested"].is_uncovered());
+    }
+
+    #[test]
+    fn line_index_is_one_based() {
+        let idx = LineIndex::new("a\nb\nc");
+        assert_eq!(idx.line_of(0), 1);
+        assert_eq!(idx.line_of(2), 2);
+        assert_eq!(idx.line_of(4), 3);
+    }
+}
@@ -5,3 +5,10 @@ x!{test_coverage_report}
 x!{test_coverage}
 x!{test_coverage_command}
 x!{generate_report}
+x!{file_coverage}
+x!{item_coverage}
+x!{changed_lines}
+x!{coverage_thresholds}
+x!{detailed_coverage_report}
+x!{coverage_output}
+x!{detailed_test_coverage}
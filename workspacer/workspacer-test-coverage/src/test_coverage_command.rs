@@ -16,6 +16,8 @@ impl TestCoverageCommand {
             .arg("tarpaulin")
             .arg("--out")
             .arg("Json")
+            .arg("--output-dir")
+            .arg(tarpaulin_output_dir(workspace_path))
             .arg("--package")
             .arg(crate_name)
             .arg("--quiet")
@@ -83,11 +85,15 @@ impl TestCoverageCommand {
     pub async fn run_in(workspace_path: impl AsRef<Path>) 
         -> Result<Self,TestCoverageError> 
     {
+        let workspace_path = workspace_path.as_ref();
+
         // Run `cargo tarpaulin` in the workspace directory to collect test coverage
         let output = tokio::process::Command::new("cargo")
             .arg("tarpaulin")
             .arg("--out")
             .arg("Json")
+            .arg("--output-dir")
+            .arg(tarpaulin_output_dir(workspace_path))
             .arg("--")
             .arg("--quiet")
             .current_dir(workspace_path)
@@ -1,7 +1,7 @@
 // ---------------- [ File: workspacer-test-coverage/src/test_coverage_report.rs ]
 crate::ix!();
 
-#[derive(Debug)]
+#[derive(Serialize,Debug)]
 pub struct TestCoverageReport {
     total_coverage: f32,   // Coverage percentage
     covered_lines:  usize, // Total lines covered
@@ -0,0 +1,270 @@
+// ---------------- [ File: workspacer-topo/src/affected_crates.rs ]
+crate::ix!();
+
+/// The crates that must be rebuilt and retested after some set of files changed.
+#[derive(Getters,Debug,Clone,Default,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct AffectedCrates {
+    /// Crates owning at least one of the changed files
+    directly_changed: BTreeSet<String>,
+
+    /// `directly_changed` plus every workspace crate depending on them (also through
+    /// dev-dependencies), in topological order: dependencies first
+    affected: Vec<String>,
+
+    /// A workspace-level file changed (root Cargo.toml, Cargo.lock, toolchain, .cargo/),
+    /// so every crate is affected
+    workspace_wide: bool,
+
+    /// Changed files outside every crate and not workspace-level (docs, scripts, ...)
+    unowned_files: Vec<PathBuf>,
+}
+
+impl AffectedCrates {
+    pub fn is_empty(&self) -> bool {
+        self.affected.is_empty()
+    }
+}
+
+impl fmt::Display for AffectedCrates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.workspace_wide {
+            writeln!(f, "(workspace-level file changed: every crate is affected)")?;
+        }
+        for name in &self.affected {
+            let marker = if self.directly_changed.contains(name) { "*" } else { " " };
+            writeln!(f, "{} {}", marker, name)?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+pub trait ComputeAffectedCrates {
+
+    /// Maps `changed_files` (absolute, or relative to the workspace root) to their crates and
+    /// walks the reverse-dependency graph from there.
+    async fn affected_crates_for_files(&self, changed_files: &[PathBuf]) -> Result<AffectedCrates, WorkspaceError>;
+
+    /// Same, for the files of a git diff range (`main..HEAD`, or a single ref vs the working tree).
+    async fn affected_crates_since(&self, git_range: &str) -> Result<AffectedCrates, WorkspaceError>;
+}
+
+#[async_trait]
+impl<P, H> ComputeAffectedCrates for Workspace<P, H>
+where
+    for<'a> P: From<PathBuf> + AsRef<Path> + Send + Sync + 'a,
+    for<'a> H: CrateHandleInterface<P> + Send + Sync + 'static,
+    Self: GenerateDependencyTree<Tree = Graph<String, ()>, Error = WorkspaceError>,
+{
+    async fn affected_crates_for_files(&self, changed_files: &[PathBuf]) -> Result<AffectedCrates, WorkspaceError> {
+        let workspace_root = canonical_or_same(self.as_ref()).await;
+
+        let mut crate_roots = Vec::new();
+        for crate_arc in self.crates() {
+            let guard = crate_arc.lock().await;
+            crate_roots.push((guard.name().to_string(), canonical_or_same(&guard.root_dir_path_buf()).await));
+        }
+
+        let mut files = Vec::with_capacity(changed_files.len());
+        for file in changed_files {
+            let absolute = if file.is_absolute() { file.clone() } else { workspace_root.join(file) };
+            files.push(canonical_or_same(&absolute).await);
+        }
+
+        let graph = self.generate_dependency_tree().await?;
+
+        let affected = compute_affected_crates(&workspace_root, &crate_roots, &files, &graph);
+        debug!("affected crates for {} changed files: {:?}", files.len(), affected.affected());
+        Ok(affected)
+    }
+
+    async fn affected_crates_since(&self, git_range: &str) -> Result<AffectedCrates, WorkspaceError> {
+        let files = git_changed_files(self.as_ref(), git_range).await?;
+        info!("{} files changed in '{}'", files.len(), git_range);
+        self.affected_crates_for_files(&files).await
+    }
+}
+
+/// Deleted files cannot be canonicalized; for those the path is kept as given.
+async fn canonical_or_same(path: &Path) -> PathBuf {
+    tokio::fs::canonicalize(path).await.unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// The pure part of `ComputeAffectedCrates`.
+///
+/// `graph` is the one `GenerateDependencyTree` produces: an edge A -> B means A depends on B,
+/// and it may contain external packages, which never appear in the result.
+pub fn compute_affected_crates(
+    workspace_root: &Path,
+    crate_roots:    &[(String, PathBuf)],
+    changed_files:  &[PathBuf],
+    graph:          &Graph<String, ()>,
+) -> AffectedCrates {
+    let members: BTreeSet<&str> = crate_roots.iter().map(|(n, _)| n.as_str()).collect();
+
+    let mut directly_changed = BTreeSet::new();
+    let mut unowned_files    = Vec::new();
+    let mut workspace_wide   = false;
+
+    for file in changed_files {
+        if let Some(name) = owning_crate(crate_roots, file) {
+            directly_changed.insert(name.to_string());
+        } else if is_workspace_level_file(workspace_root, file) {
+            workspace_wide = true;
+        } else {
+            unowned_files.push(file.clone());
+        }
+    }
+
+    let seeds: BTreeSet<String> = if workspace_wide {
+        members.iter().map(|n| n.to_string()).collect()
+    } else {
+        directly_changed.clone()
+    };
+
+    let closure = dependents_closure(graph, &seeds);
+
+    // dependents come before their dependencies in a toposort of A -> B edges; reverse it.
+    // dev-dependency cycles are legal for cargo, so a cycle only costs us the ordering.
+    let order: Vec<NodeIndex> = match toposort(graph, None) {
+        Ok(order) => order.into_iter().rev().collect(),
+        Err(cycle) => {
+            warn!("dependency graph has a cycle at {:?}; affected crates are listed unordered", cycle.node_id());
+            graph.node_indices().collect()
+        }
+    };
+
+    let mut affected: Vec<String> = order
+        .into_iter()
+        .map(|idx| graph[idx].clone())
+        .filter(|name| closure.contains(name) && members.contains(name.as_str()))
+        .collect();
+
+    // members cargo metadata did not report still count if they changed
+    for name in &seeds {
+        if !affected.contains(name) && members.contains(name.as_str()) {
+            affected.push(name.clone());
+        }
+    }
+
+    AffectedCrates { directly_changed, affected, workspace_wide, unowned_files }
+}
+
+/// The crate whose root most specifically contains `file` (crates may be nested).
+pub fn owning_crate<'a>(crate_roots: &'a [(String, PathBuf)], file: &Path) -> Option<&'a str> {
+    crate_roots
+        .iter()
+        .filter(|(_, root)| file.starts_with(root))
+        .max_by_key(|(_, root)| root.components().count())
+        .map(|(name, _)| name.as_str())
+}
+
+fn is_workspace_level_file(workspace_root: &Path, file: &Path) -> bool {
+    let Ok(relative) = file.strip_prefix(workspace_root) else {
+        return false;
+    };
+
+    if relative.starts_with(".cargo") {
+        return true;
+    }
+
+    matches!(
+        relative.to_str(),
+        Some("Cargo.toml" | "Cargo.lock" | "rust-toolchain" | "rust-toolchain.toml")
+    )
+}
+
+/// `seeds` plus everything that transitively depends on any of them.
+fn dependents_closure(graph: &Graph<String, ()>, seeds: &BTreeSet<String>) -> BTreeSet<String> {
+    let mut seen: BTreeSet<String> = seeds.clone();
+    let mut queue: VecDeque<NodeIndex> = graph
+        .node_indices()
+        .filter(|idx| seeds.contains(&graph[*idx]))
+        .collect();
+
+    while let Some(idx) = queue.pop_front() {
+        for dependent in graph.neighbors_directed(idx, Incoming) {
+            if seen.insert(graph[dependent].clone()) {
+                queue.push_back(dependent);
+            }
+        }
+    }
+
+    seen
+}
+
+#[cfg(test)]
+mod test_compute_affected_crates {
+    use super::*;
+
+    /// core <- util <- app, core <- other, app -> serde (external)
+    fn fixture() -> (Vec<(String, PathBuf)>, Graph<String, ()>) {
+        let roots = ["core", "util", "app", "other"]
+            .iter()
+            .map(|n| (n.to_string(), PathBuf::from("/w").join(n)))
+            .collect();
+
+        let mut g = Graph::new();
+        let core  = g.add_node("core".to_string());
+        let util  = g.add_node("util".to_string());
+        let app   = g.add_node("app".to_string());
+        let other = g.add_node("other".to_string());
+        let serde = g.add_node("serde".to_string());
+        g.add_edge(util, core, ());
+        g.add_edge(app, util, ());
+        g.add_edge(other, core, ());
+        g.add_edge(app, serde, ());
+
+        (roots, g)
+    }
+
+    #[test]
+    fn leaf_change_only_affects_itself() {
+        let (roots, g) = fixture();
+        let a = compute_affected_crates(Path::new("/w"), &roots, &[PathBuf::from("/w/app/src/main.rs")], &g);
+        assert_eq!(a.affected(), &vec!["app".to_string()]);
+        assert!(!a.workspace_wide());
+    }
+
+    #[test]
+    fn core_change_affects_dependents_in_dependency_order() {
+        let (roots, g) = fixture();
+        let a = compute_affected_crates(Path::new("/w"), &roots, &[PathBuf::from("/w/util/src/lib.rs")], &g);
+        assert_eq!(a.affected(), &vec!["util".to_string(), "app".to_string()]);
+
+        let a = compute_affected_crates(Path::new("/w"), &roots, &[PathBuf::from("/w/core/Cargo.toml")], &g);
+        assert_eq!(a.affected().len(), 4);
+        assert_eq!(a.affected()[0], "core");
+        let pos = |n: &str| a.affected().iter().position(|x| x == n).unwrap();
+        assert!(pos("util") < pos("app"));
+    }
+
+    #[test]
+    fn workspace_level_and_unowned_files() {
+        let (roots, g) = fixture();
+        let a = compute_affected_crates(
+            Path::new("/w"),
+            &roots,
+            &[PathBuf::from("/w/Cargo.lock"), PathBuf::from("/w/docs/notes.md")],
+            &g,
+        );
+        assert!(a.workspace_wide());
+        assert_eq!(a.affected().len(), 4);
+        assert_eq!(a.unowned_files(), &vec![PathBuf::from("/w/docs/notes.md")]);
+
+        let none = compute_affected_crates(Path::new("/w"), &roots, &[PathBuf::from("/w/README.md")], &g);
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn nested_crates_pick_the_innermost_root() {
+        let roots = vec![
+            ("outer".to_string(), PathBuf::from("/w/outer")),
+            ("inner".to_string(), PathBuf::from("/w/outer/inner")),
+        ];
+        assert_eq!(owning_crate(&roots, Path::new("/w/outer/inner/src/lib.rs")), Some("inner"));
+        assert_eq!(owning_crate(&roots, Path::new("/w/outer/src/lib.rs")), Some("outer"));
+        assert_eq!(owning_crate(&roots, Path::new("/w/elsewhere.rs")), None);
+    }
+}
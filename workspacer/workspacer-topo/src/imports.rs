@@ -10,3 +10,4 @@ pub(crate) use workspacer_interface::*;
 pub(crate) use workspacer_detect_circular_deps::*;
 pub(crate) use workspacer_crate_mock::*;
 pub(crate) use workspacer_mock::*;
+pub(crate) use workspacer_git::*;
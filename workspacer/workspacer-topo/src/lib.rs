@@ -38,3 +38,4 @@ x!{topological_sort_internal_deps}
 x!{traits}
 x!{add_crate_and_deps}
 x!{mock}
+x!{affected_crates}
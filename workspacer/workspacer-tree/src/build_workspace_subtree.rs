@@ -135,8 +135,8 @@ mod test_build_workspace_subtree {
 
     /// Helper that creates a mock workspace directory with two crates:
     /// crateA depends on crateB.
-    /// Then returns the path so we can build the workspace from it.
-    async fn create_mock_workspace_with_deps() -> PathBuf {
+    /// Then returns the temp dir (which must outlive the test) and its path.
+    async fn create_mock_workspace_with_deps() -> (TempDir, PathBuf) {
         let tmp = tempdir().expect("Failed to create temp dir");
         let root_path = tmp.path().to_path_buf();
 
@@ -188,12 +188,12 @@ mod test_build_workspace_subtree {
                 .expect("Failed to write crateA Cargo.toml");
         }
 
-        root_path
+        (tmp, root_path)
     }
 
     #[traced_test]
     async fn test_build_workspace_subtree_crateA() {
-        let ws_path = create_mock_workspace_with_deps().await;
+        let (_tmp, ws_path) = create_mock_workspace_with_deps().await;
         let workspace = Workspace::<PathBuf, CrateHandle>::new(&ws_path)
             .await
             .expect("Should build workspace from mock directory");
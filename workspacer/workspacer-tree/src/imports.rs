@@ -4,3 +4,4 @@ pub(crate) use workspacer_crate_interface::*;
 pub(crate) use workspacer_errors::*;
 pub(crate) use workspacer_workspace::*;
 pub(crate) use workspacer_crate::*;
+pub(crate) use serde_derive::Serialize;
@@ -1,7 +1,7 @@
 // ---------------- [ File: workspacer-tree/src/workspace_dependency_tree.rs ]
 crate::ix!();
 
-#[derive(Debug)]
+#[derive(Serialize,Debug)]
 pub struct WorkspaceDependencyTree {
     // The top-level crates with no incoming edges (roots).
     // Not public; we’ll provide only internal constructor & printing.
@@ -1,7 +1,7 @@
 // ---------------- [ File: workspacer-tree/src/workspace_tree_node.rs ]
 crate::ix!();
 
-#[derive(Debug)]
+#[derive(Serialize,Debug)]
 pub struct WorkspaceTreeNode {
     crate_name:   String,
     crate_version: Option<SemverVersion>,
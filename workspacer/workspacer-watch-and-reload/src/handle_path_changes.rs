@@ -1,12 +1,15 @@
-// ---------------- [ File: workspacer-watch-and-reload/src/handle_path_change.rs ]
+// ---------------- [ File: workspacer-watch-and-reload/src/handle_path_changes.rs ]
 crate::ix!();
 
 // ------------------------------------------------------------------------
-// Subroutine #4: Handle an individual changed path
+// Subroutine #4: Handle the changed paths of one event
 // ------------------------------------------------------------------------
-pub async fn handle_path_change<'a,X,E>(
+/// Rebuilds or tests once for every relevant path in `paths`, so that a save
+/// touching several files does not rebuild the same crates several times.
+/// Only the crates affected by those paths are rebuilt.
+pub async fn handle_path_changes<'a,X,E>(
     watched:   &X,
-    path:      &Path,
+    paths:     &[PathBuf],
     tx:        Option<&mpsc::Sender<Result<(), E>>>,
     runner:    &Arc<dyn CommandRunner + Send + Sync + 'a>,
 ) -> Result<(), E>
@@ -14,17 +17,23 @@ where
     X: WatchAndReload<Error=E> + RebuildOrTest<Error=E>,
     E: From<WatchError>,
 {
-    if watched.is_relevant_change(path).await {
-        info!("Detected relevant change in file: {:?}", path);
+    let mut relevant = Vec::new();
+    for path in paths {
+        if watched.is_relevant_change(path).await {
+            relevant.push(path.clone());
+        }
+    }
 
-        let rebuild_result = watched.rebuild_or_test(runner.as_ref()).await;
+    if !relevant.is_empty() {
+        info!("Detected relevant changes in files: {:?}", relevant);
+        let rebuild_result = watched.rebuild_or_test_changed(runner.as_ref(), &relevant).await;
         notify_rebuild_result(tx, rebuild_result).await;
     }
     Ok(())
 }
 
 #[cfg(test)]
-mod test_handle_path_change {
+mod test_handle_path_changes {
     use super::*;
     use std::path::{Path, PathBuf};
     use tracing::{info, error};
@@ -121,7 +130,7 @@ mod test_handle_path_change {
         }
     }
 
-    // “Function-like” traits to replicate handle_path_change usage
+    // “Function-like” traits to replicate handle_path_changes usage
     #[async_trait]
     trait FnRebuildOrTest {
         async fn rebuild_or_test(&self, runner: &dyn CommandRunner) -> Result<(), WorkspaceError>;
@@ -154,17 +163,17 @@ mod test_handle_path_change {
         }
     }
 
-    // A local mock version of handle_path_change that calls is_relevant_change + rebuild_or_test
-    async fn handle_path_change_mock<W: Send + Sync>(
+    // A local mock version of handle_path_changes that calls is_relevant_change + one rebuild_or_test
+    async fn handle_path_changes_mock<W: Send + Sync>(
         workspace: &W,
-        path: &Path,
+        paths: &[PathBuf],
         tx: Option<&mpsc::Sender<Result<(), WorkspaceError>>>,
         runner: &Arc<dyn CommandRunner>,
     ) -> Result<(), WorkspaceError>
     where
         W: FnIsRelevant + FnRebuildOrTest
     {
-        if workspace.is_relevant_change(path) {
+        if paths.iter().any(|p| workspace.is_relevant_change(p)) {
             let result = workspace.rebuild_or_test(runner.as_ref()).await;
             if let Some(ch) = tx {
                 let _ = ch.send(result).await;
@@ -179,8 +188,8 @@ mod test_handle_path_change {
         let runner: Arc<dyn CommandRunner> = Arc::new(MockCommandRunner::default());
         let (tx, mut rx) = mpsc::channel::<Result<(), WorkspaceError>>(1);
 
-        let path = Path::new("Cargo.toml");
-        handle_path_change_mock(&workspace, path, Some(&tx), &runner)
+        let paths = vec![PathBuf::from("Cargo.toml"), PathBuf::from("src/lib.rs")];
+        handle_path_changes_mock(&workspace, &paths, Some(&tx), &runner)
             .await
             .expect("Should not fail for relevant path");
 
@@ -189,6 +198,7 @@ mod test_handle_path_change {
         } else {
             panic!("No message sent for relevant path");
         }
+        assert!(rx.try_recv().is_err(), "One rebuild for all paths of the event");
     }
 
     #[traced_test]
@@ -197,8 +207,8 @@ mod test_handle_path_change {
         let runner: Arc<dyn CommandRunner> = Arc::new(MockCommandRunner::default());
         let (tx, mut rx) = mpsc::channel::<Result<(), WorkspaceError>>(1);
 
-        let path = Path::new("random_file.txt");
-        handle_path_change_mock(&workspace, path, Some(&tx), &runner)
+        let paths = vec![PathBuf::from("random_file.txt")];
+        handle_path_changes_mock(&workspace, &paths, Some(&tx), &runner)
             .await
             .expect("Should not fail for irrelevant path");
 
@@ -1,7 +1,7 @@
 // ---------------- [ File: workspacer-watch-and-reload/src/lib.rs ]
 #[macro_use] mod imports; use imports::*;
 
-x!{handle_path_change}
+x!{handle_path_changes}
 x!{notify_rebuild_result}
 x!{process_notify_event}
 x!{setup_file_watching}
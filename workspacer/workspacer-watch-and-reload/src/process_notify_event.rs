@@ -16,9 +16,7 @@ where
 {
     match event {
         Ok(ev) => {
-            for path in ev.paths.iter() {
-                handle_path_change(watched, path, tx, runner).await?;
-            }
+            handle_path_changes(watched, &ev.paths, tx, runner).await?;
         }
         Err(e) => {
             error!("File watch error: {:?}", e);
@@ -105,7 +103,7 @@ mod test_process_notify_event {
         }
     }
 
-    // Local traits same as handle_path_change
+    // Local traits same as handle_path_changes
     #[async_trait]
     trait FnRebuildOrTest {
         async fn rebuild_or_test(&self, runner: &dyn CommandRunner) -> Result<(), WorkspaceError>;
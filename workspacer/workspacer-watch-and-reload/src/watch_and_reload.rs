@@ -73,6 +73,7 @@ where
       + CrateHandleInterface<P>
       + Send
       + Sync,
+    Self: RebuildOrTest<Error=WorkspaceError>,
 {
     type Error = WorkspaceError;
 
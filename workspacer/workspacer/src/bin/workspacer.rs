@@ -14,6 +14,21 @@ pub struct WsCli {
     #[structopt(long = "trace")]
     trace: bool,
 
+    /// `text` (default) or `json`. Reports go to stdout as JSON, and a failure goes to stderr
+    /// as a `{"error": {..}}` object.
+    #[structopt(long = "format", global = true, default_value = "text", possible_values = &["text", "json"])]
+    format: OutputFormat,
+
+    /// Commands that rewrite files stash uncommitted changes first and restore them afterwards,
+    /// instead of refusing to run on a dirty tree. Staged and unstaged changes stay apart.
+    #[structopt(long = "stash", global = true)]
+    stash: bool,
+
+    /// Commands that rewrite files commit their changes on their own, with a generated message.
+    /// `ws git undo` reverts the last such commit.
+    #[structopt(long = "commit-each", global = true)]
+    commit_each: bool,
+
     /// The actual subcommand (Add, Analyze, Show, etc.)
     #[structopt(subcommand)]
     cmd: WsCliSubcommand,
@@ -28,7 +43,13 @@ impl WsCli {
             configure_tracing();  // your existing tracing setup
         }
 
-        Ok(self.cmd.run().await?)
+        set_output_format(self.format);
+
+        let mut git_policy = GitOperationPolicy::default();
+        git_policy.set_stash(self.stash).set_commit_each(self.commit_each);
+        let git = GitOperationSettings::new(git_policy, operation_label(std::env::args().skip(1)));
+
+        Ok(self.cmd.run(&git).await?)
     }
 }
 
@@ -41,9 +62,11 @@ pub enum WsCliSubcommand {
     Bump              { #[structopt(subcommand)] subcommand: BumpSubcommand,              } ,
     CheckPublishReady { #[structopt(subcommand)] subcommand: CheckPublishReadySubcommand, } ,
     Cleanup           { #[structopt(subcommand)] subcommand: CleanupSubcommand,           } ,
+    Config            { #[structopt(subcommand)] subcommand: ConfigSubcommand,            } ,
     Coverage          { #[structopt(subcommand)] subcommand: CoverageSubcommand,          } ,
     DetectCycles      { #[structopt(subcommand)] subcommand: DetectCyclesSubcommand,      } ,
     Document          { #[structopt(subcommand)] subcommand: DocumentSubcommand,          } ,
+    Edition           { #[structopt(subcommand)] subcommand: EditionSubcommand,           } ,
     Format            { #[structopt(subcommand)] subcommand: FormatSubcommand,            } ,
     Get               { #[structopt(subcommand)] subcommand: GetSubcommand,               } ,
     Git               { #[structopt(subcommand)] subcommand: GitSubcommand,               } ,
@@ -53,14 +76,23 @@ pub enum WsCliSubcommand {
     Name              { #[structopt(subcommand)] subcommand: NameSubcommand,              } ,
     Organize          { #[structopt(subcommand)] subcommand: OrganizeSubcommand,          } ,
     Pin               { #[structopt(subcommand)] subcommand: PinSubcommand,               } ,
+    PrefixGroup       { #[structopt(subcommand)] subcommand: PrefixGroupSubcommand,       } ,
     Publish           { #[structopt(subcommand)] subcommand: PublishSubcommand,           } ,
+    Query             { #[structopt(subcommand)] subcommand: QuerySubcommand,             } ,
     Register          { #[structopt(subcommand)] subcommand: RegisterSubcommand,          } ,
+    Template          { #[structopt(subcommand)] subcommand: TemplateSubcommand,          } ,
     Upgrade           { #[structopt(subcommand)] subcommand: UpgradeSubcommand,           } ,
     Validate          { #[structopt(subcommand)] subcommand: ValidateSubcommand,          } ,
     Watch             { #[structopt(subcommand)] subcommand: WatchSubcommand,             } ,
     Prune             { #[structopt(subcommand)] subcommand: PruneSubcommand,             } ,
     Show              { #[structopt(subcommand)] subcommand: ShowSubcommand,              } ,
 
+    Affected(AffectedSubcommand),
+    Audit(AuditCommand),
+    MoveItem(MoveItemCommand),
+    Msrv(MsrvCommand),
+    SplitCrate(SplitCrateCommand),
+    PublishReady(PublishReadyCommand),
     Topo(TopoSubcommand),
     Write(ReadmeWriterCli),
     Filter(FileFilterCli),
@@ -69,33 +101,44 @@ pub enum WsCliSubcommand {
 
 impl WsCliSubcommand {
 
-    pub async fn run(self) -> Result<(), WorkspaceError> {
+    pub async fn run(self, git: &GitOperationSettings) -> Result<(), WorkspaceError> {
         match self {
-            WsCliSubcommand::Add               { subcommand } => { subcommand.run().await },
-            WsCliSubcommand::Analyze           { subcommand } => { subcommand.run().await },
-            WsCliSubcommand::Bump              { subcommand } => { subcommand.run().await },
-            WsCliSubcommand::CheckPublishReady { subcommand } => { subcommand.run().await },
-            WsCliSubcommand::Cleanup           { subcommand } => { subcommand.run().await },
-            WsCliSubcommand::Coverage          { subcommand } => { subcommand.run().await },
-            WsCliSubcommand::DetectCycles      { subcommand } => { subcommand.run().await },
+            WsCliSubcommand::Add               { subcommand } => { subcommand.run(git).await },
+            WsCliSubcommand::Analyze           { subcommand } => { subcommand.run(git).await },
+            WsCliSubcommand::Bump              { subcommand } => { subcommand.run(git).await },
+            WsCliSubcommand::CheckPublishReady { subcommand } => { subcommand.run(git).await },
+            WsCliSubcommand::Cleanup           { subcommand } => { subcommand.run(git).await },
+            WsCliSubcommand::Config            { subcommand } => { subcommand.run().await },
+            WsCliSubcommand::Coverage          { subcommand } => { subcommand.run(git).await },
+            WsCliSubcommand::DetectCycles      { subcommand } => { subcommand.run(git).await },
             WsCliSubcommand::Document          { subcommand } => { subcommand.run().await },
-            WsCliSubcommand::Format            { subcommand } => { subcommand.run().await },
-            WsCliSubcommand::Get               { subcommand } => { subcommand.run().await },
+            WsCliSubcommand::Edition           { subcommand } => { subcommand.run(git).await },
+            WsCliSubcommand::Format            { subcommand } => { subcommand.run(git).await },
+            WsCliSubcommand::Get               { subcommand } => { subcommand.run(git).await },
             WsCliSubcommand::Git               { subcommand } => { subcommand.run().await },
             WsCliSubcommand::Info              { subcommand } => { subcommand.run().await },
-            WsCliSubcommand::Lint              { subcommand } => { subcommand.run().await },
+            WsCliSubcommand::Lint              { subcommand } => { subcommand.run(git).await },
             WsCliSubcommand::Meta              { subcommand } => { subcommand.run().await },
-            WsCliSubcommand::Name              { subcommand } => { subcommand.run().await },
+            WsCliSubcommand::Name              { subcommand } => { subcommand.run(git).await },
             WsCliSubcommand::Organize          { subcommand } => { subcommand.run().await },
-            WsCliSubcommand::Pin               { subcommand } => { subcommand.run().await },
-            WsCliSubcommand::Publish           { subcommand } => { subcommand.run().await },
-            WsCliSubcommand::Register          { subcommand } => { subcommand.run().await },
+            WsCliSubcommand::Pin               { subcommand } => { subcommand.run(git).await },
+            WsCliSubcommand::PrefixGroup       { subcommand } => { subcommand.run().await },
+            WsCliSubcommand::Publish           { subcommand } => { subcommand.run(git).await },
+            WsCliSubcommand::Query             { subcommand } => { subcommand.run().await },
+            WsCliSubcommand::Register          { subcommand } => { subcommand.run(git).await },
+            WsCliSubcommand::Template          { subcommand } => { subcommand.run().await },
             WsCliSubcommand::Upgrade           { subcommand } => { subcommand.run().await },
             WsCliSubcommand::Validate          { subcommand } => { subcommand.run().await },
             WsCliSubcommand::Watch             { subcommand } => { subcommand.run().await },
-            WsCliSubcommand::Prune             { subcommand } => { subcommand.run().await },
+            WsCliSubcommand::Prune             { subcommand } => { subcommand.run(git).await },
             WsCliSubcommand::Show              { subcommand } => { subcommand.run().await },
 
+            WsCliSubcommand::Affected(cmd) => { cmd.run().await },
+            WsCliSubcommand::Audit(cmd)    => { cmd.run().await },
+            WsCliSubcommand::MoveItem(cmd) => { cmd.run(git).await },
+            WsCliSubcommand::Msrv(cmd)     => { cmd.run().await },
+            WsCliSubcommand::SplitCrate(cmd) => { cmd.run(git).await },
+            WsCliSubcommand::PublishReady(cmd) => { cmd.run().await },
             WsCliSubcommand::Topo(cmd)   => { cmd.run().await },
             WsCliSubcommand::Write(cmd)  => { cmd.run().await },
             WsCliSubcommand::Filter(cmd) => { cmd.run().await },
@@ -104,12 +147,28 @@ impl WsCliSubcommand {
     }
 }
 
+/// The command line minus the global flags, e.g. `format imports --path .`, for commit messages.
+fn operation_label(args: impl Iterator<Item = String>) -> String {
+    let mut words = vec![];
+    let mut args  = args.peekable();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--trace" | "--stash" | "--commit-each" => {}
+            "--format" => { args.next(); }
+            _ if arg.starts_with("--format=") => {}
+            _ => words.push(arg),
+        }
+    }
+    words.join(" ")
+}
+
 #[tokio::main]
-async fn main() -> Result<(),WorkspaceError> {
+async fn main() {
 
     let cli = WsCli::from_args();
 
-    cli.run().await?;
-
-    Ok(())
+    if let Err(e) = cli.run().await {
+        emit_error(&e);
+        std::process::exit(1);
+    }
 }
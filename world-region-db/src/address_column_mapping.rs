@@ -0,0 +1,90 @@
+// ---------------- [ File: src/address_column_mapping.rs ]
+crate::ix!();
+
+/// Which input column (CSV header or JSONL key) holds each address field.
+/// Unmapped fields use the field's own name; the region and house number
+/// columns may be missing from the input altogether.
+#[derive(Builder,Getters,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+#[builder(setter(into))]
+pub struct AddressColumnMapping {
+    #[builder(default = "\"region\".to_string()")]
+    region:       String,
+
+    #[builder(default = "\"postal_code\".to_string()")]
+    postal_code:  String,
+
+    #[builder(default = "\"city\".to_string()")]
+    city:         String,
+
+    #[builder(default = "\"street\".to_string()")]
+    street:       String,
+
+    #[builder(default = "\"house_number\".to_string()")]
+    house_number: String,
+}
+
+impl Default for AddressColumnMapping {
+    fn default() -> Self {
+        AddressColumnMappingBuilder::default().build().unwrap()
+    }
+}
+
+impl AddressColumnMapping {
+
+    /// Builds a mapping from `field=column` pairs, e.g.
+    /// `["postal_code=zip", "street=addr_line_1"]`.
+    pub fn from_specs<S: AsRef<str>>(specs: &[S]) -> Result<Self,BulkValidationError> {
+        let mut mapping = Self::default();
+        for spec in specs {
+            let spec = spec.as_ref();
+            let invalid = || BulkValidationError::InvalidColumnMapping { spec: spec.to_string() };
+
+            let (field, column) = spec.split_once('=').ok_or_else(invalid)?;
+            let column = column.trim();
+            if column.is_empty() {
+                return Err(invalid());
+            }
+            let slot = match field.trim() {
+                "region"       => &mut mapping.region,
+                "postal_code"  => &mut mapping.postal_code,
+                "city"         => &mut mapping.city,
+                "street"       => &mut mapping.street,
+                "house_number" => &mut mapping.house_number,
+                _              => return Err(invalid()),
+            };
+            *slot = column.to_string();
+        }
+        Ok(mapping)
+    }
+}
+
+#[cfg(test)]
+mod address_column_mapping_tests {
+    use super::*;
+
+    #[traced_test]
+    fn test_defaults_are_field_names() {
+        let mapping = AddressColumnMapping::default();
+        assert_eq!(mapping.postal_code(), "postal_code");
+        assert_eq!(mapping.house_number(), "house_number");
+    }
+
+    #[traced_test]
+    fn test_from_specs_overrides_some_fields() {
+        let mapping = AddressColumnMapping::from_specs(&["postal_code=zip", " street = addr1 "]).unwrap();
+        assert_eq!(mapping.postal_code(), "zip");
+        assert_eq!(mapping.street(), "addr1");
+        assert_eq!(mapping.city(), "city");
+    }
+
+    #[traced_test]
+    fn test_from_specs_rejects_bad_specs() {
+        for bad in ["zip", "country=cc", "city="] {
+            assert_eq!(
+                AddressColumnMapping::from_specs(&[bad]),
+                Err(BulkValidationError::InvalidColumnMapping { spec: bad.to_string() })
+            );
+        }
+    }
+}
@@ -0,0 +1,323 @@
+// ---------------- [ File: src/address_interpolation.rs ]
+crate::ix!();
+
+/// The value of an `addr:interpolation` way: which house numbers exist
+/// between two numbered nodes on the line.
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum InterpolationKind {
+    Odd,
+    Even,
+    All,
+    Alphabetic,
+}
+
+impl InterpolationKind {
+
+    /// `None` for values we do not interpolate (numeric steps, free text).
+    pub fn from_tag(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "odd"        => Some(InterpolationKind::Odd),
+            "even"       => Some(InterpolationKind::Even),
+            "all" | "1"  => Some(InterpolationKind::All),
+            "alphabetic" => Some(InterpolationKind::Alphabetic),
+            _            => None,
+        }
+    }
+
+    /// The range implied between two consecutive numbered nodes, in either
+    /// order. `None` when the endpoints do not bracket anything of this
+    /// kind, e.g. `12` to `12` for odd/even or `12A` to `14B` for
+    /// alphabetic.
+    pub fn range_between(&self, a: &HouseNumber, b: &HouseNumber) -> Option<HouseNumberRange> {
+        let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+        let (lo_num, hi_num) = (*lo.number(), *hi.number());
+
+        let parity = match self {
+            InterpolationKind::Alphabetic => {
+                return match (lo_num == hi_num, lo.suffix(), hi.suffix()) {
+                    (true, Some(x), Some(y)) => Some(HouseNumberRange::lettered(lo_num, *x, *y)),
+                    _                        => None,
+                };
+            }
+            InterpolationKind::All  => HouseNumberParity::All,
+            InterpolationKind::Odd  => HouseNumberParity::Odd,
+            InterpolationKind::Even => HouseNumberParity::Even,
+        };
+
+        if lo_num == hi_num {
+            return None;
+        }
+
+        // Mapped endpoints are sometimes off-parity; clip to the numbers
+        // that actually belong to this side.
+        let start = if parity.matches(lo_num) { lo_num } else { lo_num + 1 };
+        let end   = if parity.matches(hi_num) { hi_num } else { hi_num - 1 };
+        (start <= end).then(|| HouseNumberRange::with_parity(start, end, parity))
+    }
+}
+
+/// What the second pass needs from a node on an interpolation line.
+#[derive(Getters,Debug,Clone,PartialEq)]
+#[getset(get="pub")]
+pub struct InterpolationNode {
+    house_number: HouseNumber,
+    street:       Option<StreetName>,
+    location:     Option<GeoPoint>,
+}
+
+impl InterpolationNode {
+    pub fn new(house_number: HouseNumber, street: Option<StreetName>) -> Self {
+        Self { house_number, street, location: None }
+    }
+
+    pub fn with_location(mut self, location: Option<GeoPoint>) -> Self {
+        self.location = location;
+        self
+    }
+}
+
+#[derive(Debug)]
+struct PendingInterpolation {
+    way_id: i64,
+    kind:   InterpolationKind,
+    refs:   Vec<i64>,
+    street: Option<StreetName>,
+}
+
+impl PendingInterpolation {
+
+    /// The way's own street, else the first one found on its nodes.
+    fn street(&self, nodes: &HashMap<i64, InterpolationNode>) -> Option<StreetName> {
+        self.street.clone().or_else(|| {
+            self.refs.iter().filter_map(|id| nodes.get(id)).find_map(|n| n.street().clone())
+        })
+    }
+}
+
+/// `addr:interpolation` ways carry no numbers themselves: those sit on the
+/// line's nodes, which usually appear earlier in the file. As with
+/// [`PendingWayCentroids`], we note the ways during the first pass and
+/// resolve them with a second, targeted pass over the file.
+#[derive(Debug,Default)]
+pub struct PendingInterpolations {
+    pending: Vec<PendingInterpolation>,
+}
+
+impl PendingInterpolations {
+
+    /// `street` is the way's own `addr:street`, if it has one; otherwise the
+    /// street is taken from the numbered nodes.
+    pub fn push(
+        &mut self,
+        way_id: i64,
+        kind:   InterpolationKind,
+        refs:   Vec<i64>,
+        street: Option<StreetName>,
+    ) {
+        self.pending.push(PendingInterpolation { way_id, kind, refs, street });
+    }
+
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Every node id that may carry a house number for a pending way.
+    pub fn needed_node_ids(&self) -> HashSet<i64> {
+        self.pending
+            .iter()
+            .flat_map(|p| p.refs.iter().copied())
+            .collect()
+    }
+
+    /// The street of each pending way whose street is known, either from
+    /// the way itself or from its numbered nodes.
+    pub fn streets(&self, nodes: &HashMap<i64, InterpolationNode>) -> HashSet<StreetName> {
+        self.pending
+            .iter()
+            .filter_map(|way| way.street(nodes))
+            .collect()
+    }
+
+    /// Adds a range to `aggregator` for each pair of consecutive numbered
+    /// nodes along every pending way, on the side of the street `geometry`
+    /// puts the pair's midpoint. Returns how many ranges were added.
+    pub fn resolve(
+        self,
+        nodes:      &HashMap<i64, InterpolationNode>,
+        geometry:   &StreetGeometry,
+        aggregator: &mut HouseNumberAggregator,
+    ) -> usize {
+        let mut added = 0;
+        for way in self.pending {
+            let numbered: Vec<&InterpolationNode> = way.refs.iter().filter_map(|id| nodes.get(id)).collect();
+
+            let Some(street) = way.street(nodes) else {
+                debug!("PendingInterpolations::resolve: no street for interpolation way {}", way.way_id);
+                continue;
+            };
+
+            for pair in numbered.windows(2) {
+                match way.kind.range_between(pair[0].house_number(), pair[1].house_number()) {
+                    Some(range) => {
+                        let ends: Vec<GeoPoint> = pair.iter().filter_map(|n| *n.location()).collect();
+                        let side = match GeoPoint::centroid(&ends) {
+                            Some(midpoint) if ends.len() == 2 => geometry.side_of(&street, &midpoint),
+                            _                                 => StreetSide::Unknown,
+                        };
+                        let range = range.with_side(side);
+                        trace!("PendingInterpolations::resolve: way {} => {:?} on {}", way.way_id, range, street);
+                        aggregator.entry(street.clone()).or_default().push(range);
+                        added += 1;
+                    }
+                    None => debug!(
+                        "PendingInterpolations::resolve: nothing to interpolate between {} and {} on way {}",
+                        pair[0].house_number(),
+                        pair[1].house_number(),
+                        way.way_id
+                    ),
+                }
+            }
+        }
+        added
+    }
+}
+
+/// Second pass over an OSM PBF file, collecting the house number, street
+/// and location of exactly the node ids in `wanted`. Nodes without a parseable
+/// `addr:housenumber` are left out.
+pub fn collect_interpolation_nodes<R: Read + Send + Sync>(
+    reader:  ElementReader<R>,
+    wanted:  &HashSet<i64>,
+    country: &Country,
+) -> Result<HashMap<i64, InterpolationNode>, OsmPbfParseError> {
+    trace!("collect_interpolation_nodes: looking for {} nodes", wanted.len());
+
+    let mut nodes = HashMap::new();
+    reader.for_each(|element| {
+        let (id, tags): (i64, Vec<(&str, &str)>) = match &element {
+            Element::Node(node)       => (node.id(), node.tags().collect()),
+            Element::DenseNode(dense) => (dense.id(), dense.tags().collect()),
+            _                         => return,
+        };
+        if !wanted.contains(&id) {
+            return;
+        }
+        let tag = |key: &str| tags.iter().find(|(k, _)| *k == key).map(|(_, v)| *v);
+
+        if let Some(house_number) = tag("addr:housenumber").and_then(HouseNumber::parse) {
+            let street = tag("addr:street").and_then(|s| StreetName::new_for_country(s, *country).ok());
+            let node   = InterpolationNode::new(house_number, street).with_location(element_location(&element));
+            nodes.insert(id, node);
+        }
+    })?;
+
+    debug!("collect_interpolation_nodes: found {} numbered nodes of {}", nodes.len(), wanted.len());
+    Ok(nodes)
+}
+
+#[cfg(test)]
+mod address_interpolation_tests {
+    use super::*;
+
+    fn hn(raw: &str) -> HouseNumber {
+        HouseNumber::parse(raw).unwrap()
+    }
+
+    fn street(name: &str) -> StreetName {
+        StreetName::new_for_country(name, Country::USA).unwrap()
+    }
+
+    #[traced_test]
+    fn test_kind_from_tag() {
+        assert_eq!(InterpolationKind::from_tag("Odd"), Some(InterpolationKind::Odd));
+        assert_eq!(InterpolationKind::from_tag("alphabetic"), Some(InterpolationKind::Alphabetic));
+        assert_eq!(InterpolationKind::from_tag("2"), None);
+    }
+
+    #[traced_test]
+    fn test_range_between_clips_to_parity() {
+        let even = InterpolationKind::Even.range_between(&hn("20"), &hn("3"));
+        assert_eq!(even, Some(HouseNumberRange::with_parity(4, 20, HouseNumberParity::Even)));
+
+        let odd = InterpolationKind::Odd.range_between(&hn("1"), &hn("9"));
+        assert_eq!(odd, Some(HouseNumberRange::with_parity(1, 9, HouseNumberParity::Odd)));
+
+        assert_eq!(InterpolationKind::Odd.range_between(&hn("7"), &hn("7")), None);
+    }
+
+    #[traced_test]
+    fn test_alphabetic_range_needs_same_number() {
+        assert_eq!(
+            InterpolationKind::Alphabetic.range_between(&hn("12D"), &hn("12A")),
+            Some(HouseNumberRange::lettered(12, 'A', 'D'))
+        );
+        assert_eq!(InterpolationKind::Alphabetic.range_between(&hn("12A"), &hn("14B")), None);
+    }
+
+    #[traced_test]
+    fn test_resolve_adds_ranges_for_consecutive_numbered_nodes() {
+        let mut pending = PendingInterpolations::default();
+        pending.push(500, InterpolationKind::Odd, vec![1, 2, 3, 4], None);
+        assert_eq!(pending.needed_node_ids(), [1, 2, 3, 4].into_iter().collect());
+
+        // node 2 is an unnumbered bend in the line
+        let mut nodes = HashMap::new();
+        nodes.insert(1, InterpolationNode::new(hn("1"),  Some(street("Howard Street"))));
+        nodes.insert(3, InterpolationNode::new(hn("21"), None));
+        nodes.insert(4, InterpolationNode::new(hn("41"), None));
+
+        let mut aggregator = HouseNumberAggregator::default();
+        assert_eq!(pending.resolve(&nodes, &StreetGeometry::default(), &mut aggregator), 2);
+
+        let ranges = aggregator.get(&street("Howard Street")).unwrap();
+        assert_eq!(ranges, &vec![
+            HouseNumberRange::with_parity(1,  21, HouseNumberParity::Odd),
+            HouseNumberRange::with_parity(21, 41, HouseNumberParity::Odd),
+        ]);
+    }
+
+    #[traced_test]
+    fn test_resolve_without_street_adds_nothing() {
+        let mut pending = PendingInterpolations::default();
+        pending.push(501, InterpolationKind::All, vec![1, 2], None);
+
+        let mut nodes = HashMap::new();
+        nodes.insert(1, InterpolationNode::new(hn("1"),  None));
+        nodes.insert(2, InterpolationNode::new(hn("10"), None));
+
+        let mut aggregator = HouseNumberAggregator::default();
+        assert_eq!(pending.resolve(&nodes, &StreetGeometry::default(), &mut aggregator), 0);
+        assert!(aggregator.is_empty());
+    }
+
+    #[traced_test]
+    fn test_resolve_takes_side_from_street_geometry() {
+        let mut pending = PendingInterpolations::default();
+        pending.push(502, InterpolationKind::Even, vec![1, 2], Some(street("Howard Street")));
+        pending.push(503, InterpolationKind::Odd,  vec![3, 4], Some(street("Howard Street")));
+
+        // Howard Street runs north along -76.62: evens to the east, odds to the west
+        let mut nodes = HashMap::new();
+        nodes.insert(1, InterpolationNode::new(hn("2"),  None).with_location(Some(mock_point(39.281, -76.6195))));
+        nodes.insert(2, InterpolationNode::new(hn("20"), None).with_location(Some(mock_point(39.289, -76.6195))));
+        nodes.insert(3, InterpolationNode::new(hn("1"),  None).with_location(Some(mock_point(39.281, -76.6205))));
+        nodes.insert(4, InterpolationNode::new(hn("19"), None));
+        assert_eq!(pending.streets(&nodes), [street("Howard Street")].into_iter().collect());
+
+        let mut geometry = StreetGeometry::default();
+        geometry.add_line(street("Howard Street"), vec![mock_point(39.28, -76.62), mock_point(39.29, -76.62)]);
+
+        let mut aggregator = HouseNumberAggregator::default();
+        assert_eq!(pending.resolve(&nodes, &geometry, &mut aggregator), 2);
+
+        // the odd pair is missing a location, so its side stays unknown
+        assert_eq!(aggregator.get(&street("Howard Street")).unwrap(), &vec![
+            HouseNumberRange::with_parity(2, 20, HouseNumberParity::Even).with_side(StreetSide::Right),
+            HouseNumberRange::with_parity(1, 19, HouseNumberParity::Odd),
+        ]);
+    }
+}
@@ -0,0 +1,99 @@
+// ---------------- [ File: src/address_link.rs ]
+crate::ix!();
+
+/// One two-way association between address parts that the indexes store
+/// (see [`InMemoryIndexes`]): each link appears under two keys, one per
+/// direction.
+#[derive(Clone,Debug,Hash,PartialEq,Eq,PartialOrd,Ord)]
+pub enum AddressLink {
+    /// `Z2C:{pc}` contains the city, `C2Z:{city}` contains the postal code.
+    PostalCodeCity(PostalCode, CityName),
+    /// `S:{pc}` contains the street, `S2Z:{street}` contains the postal code.
+    PostalCodeStreet(PostalCode, StreetName),
+    /// `C2S:{city}` contains the street, `S2C:{street}` contains the city.
+    CityStreet(CityName, StreetName),
+}
+
+impl AddressLink {
+
+    /// Every link a record supports: one for each pair of fields it has.
+    pub fn all_for(record: &AddressRecord) -> Vec<AddressLink> {
+        let mut links = Vec::new();
+        if let (Some(pc), Some(city)) = (record.postcode(), record.city()) {
+            links.push(AddressLink::PostalCodeCity(pc.clone(), city.clone()));
+        }
+        if let (Some(pc), Some(street)) = (record.postcode(), record.street()) {
+            links.push(AddressLink::PostalCodeStreet(pc.clone(), street.clone()));
+        }
+        if let (Some(city), Some(street)) = (record.city(), record.street()) {
+            links.push(AddressLink::CityStreet(city.clone(), street.clone()));
+        }
+        links
+    }
+
+    /// Inverse of the `Display` form. Postal codes are read as `country`'s,
+    /// streets come back in their canonical form.
+    pub fn parse(text: &str, country: Country) -> Option<AddressLink> {
+        let (kind, rest)    = text.split_once(':')?;
+        let (first, second) = rest.split_once(':')?;
+        match kind {
+            "zc" => Some(AddressLink::PostalCodeCity(
+                PostalCode::new(country, first).ok()?,
+                CityName::new(second).ok()?,
+            )),
+            "zs" => Some(AddressLink::PostalCodeStreet(
+                PostalCode::new(country, first).ok()?,
+                StreetName::new_for_country(second, country).ok()?,
+            )),
+            "cs" => Some(AddressLink::CityStreet(
+                CityName::new(first).ok()?,
+                StreetName::new_for_country(second, country).ok()?,
+            )),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for AddressLink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AddressLink::PostalCodeCity(pc, city)     => write!(f, "zc:{}:{}", pc.code(), city.name()),
+            AddressLink::PostalCodeStreet(pc, street) => write!(f, "zs:{}:{}", pc.code(), street.canonical()),
+            AddressLink::CityStreet(city, street)     => write!(f, "cs:{}:{}", city.name(), street.canonical()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod address_link_tests {
+    use super::*;
+
+    #[traced_test]
+    fn test_complete_record_has_three_links() {
+        let record = AddressRecord::new(
+            CityName::new("Baltimore").unwrap(),
+            StreetName::new_for_country("North Avenue", Country::USA).unwrap(),
+            PostalCode::new(Country::USA, "21201").unwrap(),
+        );
+        let links = AddressLink::all_for(&record);
+        assert_eq!(links.len(), 3);
+        assert_eq!(links[0].to_string(), "zc:21201:baltimore");
+        assert_eq!(links[1].to_string(), "zs:21201:n ave");
+        assert_eq!(links[2].to_string(), "cs:baltimore:n ave");
+
+        for link in links {
+            assert_eq!(AddressLink::parse(&link.to_string(), Country::USA), Some(link));
+        }
+        assert_eq!(AddressLink::parse("xx:1:2", Country::USA), None);
+    }
+
+    #[traced_test]
+    fn test_partial_record_links() {
+        let mut record = AddressRecord::default();
+        record.set_city(Some(CityName::new("Baltimore").unwrap()));
+        assert!(AddressLink::all_for(&record).is_empty());
+
+        record.set_postcode(Some(PostalCode::new(Country::USA, "21201").unwrap()));
+        assert_eq!(AddressLink::all_for(&record).len(), 1);
+    }
+}
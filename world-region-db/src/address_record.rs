@@ -3,13 +3,20 @@ crate::ix!();
 
 /// A simple structure to hold address info extracted from OSM.
 /// Not all OSM ways/nodes have addresses, we only store those that do.
-#[derive(PartialEq,Eq,Clone, Default, Builder, Getters, Setters, Debug)]
+#[derive(PartialEq,Eq,Clone, Default, Builder, Getters, Setters, Debug, Serialize, Deserialize)]
 #[getset(get = "pub", set = "pub")]
 #[builder(default, setter(into))]
 pub struct AddressRecord {
-    city:     Option<CityName>,
-    street:   Option<StreetName>,
-    postcode: Option<PostalCode>,
+    city:        Option<CityName>,
+    street:      Option<StreetName>,
+    postcode:    Option<PostalCode>,
+    /// `addr:housenumber`, when the element carried one.
+    housenumber: Option<HouseNumberRange>,
+    /// The node position, or the centroid of a way's nodes.
+    location:    Option<GeoPoint>,
+    /// The element this record was read from, so that OSM change files can
+    /// later find and replace it.
+    osm_element: Option<OsmElementRef>,
 }
 
 /// Helper to create an AddressRecord easily
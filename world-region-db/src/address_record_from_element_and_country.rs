@@ -33,11 +33,11 @@ impl<'a> TryFrom<(&osmpbf::Element<'a>, &Country)> for AddressRecord {
 ///
 /// - `fn_name` is the desired function identifier.
 /// - `ElementType` is any type offering:
-///      - `.id()` -> i64
-///      - `.tags()` -> Iterator<(K, V)>
+///   - `.id()` -> i64
+///   - `.tags()` -> Iterator<(K, V)>
 /// - `"DescriptiveLabel"` is a textual label included in log messages.
 /// - `error_mapping` is a closure of the form:
-///      `|id, node_err| { <transform> }`
+///   `|id, node_err| { <transform> }`
 ///   which transforms `IncompatibleOsmPbfElement::IncompatibleOsmPbfNode(...)` into the desired variant.
 ///   If no transformation is needed (i.e. for Node), simply pass an identity closure.
 ///
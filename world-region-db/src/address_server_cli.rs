@@ -0,0 +1,47 @@
+// ---------------- [ File: src/address_server_cli.rs ]
+crate::ix!();
+
+/// Flags for the address validation server
+#[derive(StructOpt, Debug)]
+#[structopt(name = "world_region_address_server")]
+pub struct AddressServerCli {
+    /// Database built by `world-city-and-street-db-builder`; opened read-only,
+    /// so several servers (and the builder) can share it
+    #[structopt(long, default_value = "rocksdb_world", parse(from_os_str))]
+    db: PathBuf,
+
+    /// Address to listen on
+    #[structopt(long, default_value = "127.0.0.1:8080")]
+    listen: SocketAddr,
+}
+
+impl AddressServerCli {
+    pub async fn run(&self) -> Result<(), AddressServiceError> {
+        info!("opening {:?} read-only", self.db);
+        let db      = Database::open_read_only(&self.db)?;
+        let db      = into_unlocked_store(db).ok_or(AddressServiceError::DbLockError)?;
+        let service = AddressService::new(db);
+        serve_address_service(service, self.listen).await
+    }
+}
+
+#[cfg(test)]
+mod address_server_cli_tests {
+    use super::*;
+
+    #[traced_test]
+    fn test_defaults() {
+        let cli = AddressServerCli::from_iter_safe(&["address-server"]).unwrap();
+        assert_eq!(cli.db, PathBuf::from("rocksdb_world"));
+        assert_eq!(cli.listen, "127.0.0.1:8080".parse::<SocketAddr>().unwrap());
+    }
+
+    #[traced_test]
+    fn test_flags() {
+        let cli = AddressServerCli::from_iter_safe(&[
+            "address-server", "--db", "/tmp/world", "--listen", "0.0.0.0:9000",
+        ]).unwrap();
+        assert_eq!(cli.db, PathBuf::from("/tmp/world"));
+        assert_eq!(cli.listen.port(), 9000);
+    }
+}
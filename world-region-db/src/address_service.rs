@@ -0,0 +1,424 @@
+// ---------------- [ File: src/address_service.rs ]
+crate::ix!();
+
+/// How many autocomplete matches or suggestions we return when the caller
+/// does not say.
+pub const DEFAULT_SUGGESTION_LIMIT: usize = 10;
+
+/// The queries behind the HTTP address service (see
+/// [`address_service_router`]). Each method takes a wire request, resolves
+/// region and names the way the builder stored them, and answers straight
+/// from the store.
+///
+/// Queries take no lock: the store is only read, and every clone of the
+/// service shares the one handle, so concurrent requests run side by side.
+pub struct AddressService<I:StorageInterface> {
+    db:      Arc<I>,
+    matcher: Arc<SkimMatcherV2>,
+}
+
+impl<I:StorageInterface> Clone for AddressService<I> {
+    fn clone(&self) -> Self {
+        Self {
+            db:      self.db.clone(),
+            matcher: self.matcher.clone(),
+        }
+    }
+}
+
+impl<I:StorageInterface> AddressService<I> {
+
+    /// Serves queries from `db`, typically opened with
+    /// [`open_read_only`](OpenDatabaseAtPath::open_read_only).
+    pub fn new(db: I) -> Self {
+        Self {
+            db:      Arc::new(db),
+            matcher: Arc::new(SkimMatcherV2::default()),
+        }
+    }
+
+    /// Validates postal code, city and street together, and on failure
+    /// suggests replacements for the field that did not fit.
+    pub fn validate(&self, req: &ValidateRequest) -> Result<ValidateResponse,AddressServiceError> {
+        trace!("AddressService::validate: {:?}", req);
+
+        let region      = parse_region(req.region())?;
+        let postal_code = PostalCode::new(Country::try_from(region)?, req.postal_code())?;
+        let city        = CityName::new(req.city())?;
+        let street      = StreetName::new_for_region(req.street(), &region)?;
+
+        let address = WorldAddressBuilder::default()
+            .region(region)
+            .postal_code(postal_code.clone())
+            .city(city.clone())
+            .street(street.clone())
+            .build()
+            .expect("all WorldAddress fields are set");
+
+        if let Err(invalid) = address.validate_with(&*self.db) {
+            debug!("AddressService::validate: {:?}", invalid);
+            let problem     = AddressProblem::from(&invalid);
+            let suggestions = self.suggestions_for(problem, &region, &postal_code, &city, req);
+            return Ok(ValidateResponseBuilder::default()
+                .valid(false)
+                .problem(Some(problem))
+                .field(Some(problem.field()))
+                .suggestions(suggestions)
+                .build()
+                .expect("valid is set"));
+        }
+
+        let house_number_known = match req.house_number() {
+            Some(number) => Some(self.house_number_in_ranges(&region, &street, number)?.0),
+            None         => None,
+        };
+
+        Ok(ValidateResponseBuilder::default()
+            .valid(true)
+            .house_number_known(house_number_known)
+            .build()
+            .expect("valid is set"))
+    }
+
+    /// Cities in the region whose normalized name starts with the prefix.
+    /// A city is stored under that one name, so it is also what we show.
+    pub fn autocomplete_cities(&self, req: &AutocompleteRequest) -> Result<AutocompleteResponse,AddressServiceError> {
+        let region = parse_region(req.region())?;
+        let prefix = normalize(req.prefix());
+        if prefix.is_empty() {
+            return Err(AddressServiceError::EmptyPrefix);
+        }
+
+        let scan_prefix = format!("{}{}", DbKeyFamily::CityPostalCodes.region_prefix(&region), prefix);
+        let matches = self.scan_key_names(&scan_prefix, req.limit().unwrap_or(DEFAULT_SUGGESTION_LIMIT), |key| match key {
+            DbKey::CityPostalCodes { city, .. } => Some(city.name().clone()),
+            _                                   => None,
+        })?;
+        Ok(AutocompleteResponse::new(matches))
+    }
+
+    /// Streets in the region whose canonical name starts with the
+    /// canonicalized prefix, so `"N Char"` finds `"North Charles Street"`.
+    /// Matches are the streets' stored names, not their canonical keys.
+    pub fn autocomplete_streets(&self, req: &AutocompleteRequest) -> Result<AutocompleteResponse,AddressServiceError> {
+        let region = parse_region(req.region())?;
+        if normalize(req.prefix()).is_empty() {
+            return Err(AddressServiceError::EmptyPrefix);
+        }
+        let prefix = StreetName::new_for_region(req.prefix(), &region)?;
+
+        let scan_prefix = format!("{}{}", DbKeyFamily::StreetCities.region_prefix(&region), prefix.canonical());
+        let matches = self.scan_key_names(&scan_prefix, req.limit().unwrap_or(DEFAULT_SUGGESTION_LIMIT), |key| match key {
+            DbKey::StreetCities { street, .. } => Some(self.stored_street_name(&region, &street)),
+            _                                  => None,
+        })?;
+        Ok(AutocompleteResponse::new(matches))
+    }
+
+    pub fn cities_for_postal_code(&self, req: &CitiesForPostalCodeRequest) -> Result<CitiesForPostalCodeResponse,AddressServiceError> {
+        let region      = parse_region(req.region())?;
+        let postal_code = PostalCode::new(Country::try_from(region)?, req.postal_code())?;
+
+        Ok(match self.db.cities_for_postal_code(&region, &postal_code) {
+            Some(cities) => CitiesForPostalCodeResponse::new(true, names(cities.iter().map(|c| c.name()))),
+            None         => CitiesForPostalCodeResponse::new(false, vec![]),
+        })
+    }
+
+    pub fn house_number(&self, req: &HouseNumberRequest) -> Result<HouseNumberResponse,AddressServiceError> {
+        self.check_house_number(req.region(), req.street(), req.house_number())
+    }
+
+    /// [`house_number`](Self::house_number) without building a request.
+    pub fn check_house_number(
+        &self,
+        region:       &str,
+        street:       &str,
+        house_number: &HouseNumber,
+    ) -> Result<HouseNumberResponse,AddressServiceError> {
+        let region = parse_region(region)?;
+        let street = StreetName::new_for_region(street, &region)?;
+
+        let (in_range, ranges) = self.house_number_in_ranges(&region, &street, house_number)?;
+        Ok(HouseNumberResponse::new(in_range, ranges))
+    }
+
+    fn house_number_in_ranges(
+        &self,
+        region:       &WorldRegion,
+        street:       &StreetName,
+        house_number: &HouseNumber,
+    ) -> Result<(bool,Vec<HouseNumberRange>),AddressServiceError> {
+        let ranges   = self.db.load_house_number_ranges(region, street)?.unwrap_or_default();
+        let in_range = ranges.iter().any(|rng| rng.contains(house_number));
+        Ok((in_range, ranges))
+    }
+
+    /// The name `street` was stored with. Street keys only hold the
+    /// canonical form, so we look the street up in the street list of one
+    /// of its cities, falling back to the canonical form if none has it.
+    fn stored_street_name(&self, region: &WorldRegion, street: &StreetName) -> String {
+        let cities = self.db.get_city_set(&s2c_key(region, street)).unwrap_or_default();
+        cities
+            .iter()
+            .filter_map(|city| self.db.street_names_for_city_in_region(region, city))
+            .find_map(|streets| streets.get(street).map(|s| s.name().clone()))
+            .unwrap_or_else(|| street.canonical().clone())
+    }
+
+    /// Walks keys under `scan_prefix` with the prefix iterator, collecting
+    /// up to `limit` distinct names picked out of the decoded keys. A zero
+    /// `limit` asks for nothing.
+    fn scan_key_names(
+        &self,
+        scan_prefix: &str,
+        limit:       usize,
+        name_of:     impl Fn(DbKey) -> Option<String>,
+    ) -> Result<Vec<String>,AddressServiceError> {
+        if limit == 0 {
+            return Ok(vec![]);
+        }
+
+        let mut names = BTreeSet::new();
+        for item in self.db.prefix_iterator(scan_prefix.as_bytes()) {
+            let (key_bytes, _) = item.map_err(DataAccessError::RocksDB)?;
+            if !key_bytes.starts_with(scan_prefix.as_bytes()) {
+                break;
+            }
+            let key = String::from_utf8_lossy(&key_bytes);
+            match DbKey::decode(&key) {
+                Ok(decoded) => {
+                    if let Some(name) = name_of(decoded) {
+                        names.insert(name);
+                    }
+                }
+                Err(e) => warn!("AddressService: skipping undecodable key {}: {:?}", key, e),
+            }
+            if names.len() >= limit {
+                break;
+            }
+        }
+        Ok(names.into_iter().collect())
+    }
+
+    fn suggestions_for(
+        &self,
+        problem:     AddressProblem,
+        region:      &WorldRegion,
+        postal_code: &PostalCode,
+        city:        &CityName,
+        req:         &ValidateRequest,
+    ) -> Vec<String> {
+        let db = &*self.db;
+        let (candidates, input) = match problem {
+            AddressProblem::UnknownPostalCode => (
+                db.postal_codes_for_city_in_region(region, city)
+                    .map(|codes| names(codes.iter().map(|pc| pc.code())))
+                    .unwrap_or_default(),
+                req.postal_code(),
+            ),
+            AddressProblem::CityNotInPostalCode | AddressProblem::UnknownCity => (
+                db.cities_for_postal_code(region, postal_code)
+                    .map(|cities| names(cities.iter().map(|c| c.name())))
+                    .unwrap_or_default(),
+                req.city(),
+            ),
+            AddressProblem::StreetNotInPostalCode => (
+                db.street_names_for_postal_code_in_region(region, postal_code)
+                    .map(|streets| names(streets.iter().map(|s| s.name())))
+                    .unwrap_or_default(),
+                req.street(),
+            ),
+            AddressProblem::StreetNotInCity => (
+                db.street_names_for_city_in_region(region, city)
+                    .map(|streets| names(streets.iter().map(|s| s.name())))
+                    .unwrap_or_default(),
+                req.street(),
+            ),
+        };
+        rank_suggestions(&self.matcher, input, candidates, DEFAULT_SUGGESTION_LIMIT)
+    }
+}
+
+fn parse_region(abbreviation: &str) -> Result<WorldRegion,AddressServiceError> {
+    WorldRegion::try_from_abbreviation(abbreviation.trim()).map_err(|_| {
+        AddressServiceError::UnknownRegion { abbreviation: abbreviation.to_string() }
+    })
+}
+
+fn names<S: ToString>(it: impl Iterator<Item = S>) -> Vec<String> {
+    it.map(|s| s.to_string()).collect()
+}
+
+/// Fuzzy matches against `input`, best first. When nothing matches at all
+/// the candidates are returned as stored, so the caller still sees what
+/// would have been valid.
+fn rank_suggestions(matcher: &SkimMatcherV2, input: &str, candidates: Vec<String>, limit: usize) -> Vec<String> {
+    let ranked = best_fuzzy_matches(matcher, &normalize(input), &candidates, limit);
+    if ranked.is_empty() {
+        return candidates.into_iter().take(limit).collect();
+    }
+    ranked
+}
+
+#[cfg(test)]
+mod address_service_tests {
+    use super::*;
+
+    fn md() -> WorldRegion {
+        USRegion::UnitedState(UnitedState::Maryland).into()
+    }
+
+    /// Maryland data with streets canonicalized the way the builder does it.
+    fn service_with_md_data() -> (AddressService<Database>, Arc<Mutex<Database>>, TempDir) {
+        let (db, tmp) = create_temp_db::<Database>();
+        let region = md();
+
+        let baltimore = CityName::new("Baltimore").unwrap();
+        let bethesda  = CityName::new("Bethesda").unwrap();
+        let north_ave = StreetName::new_for_region("North Avenue", &region).unwrap();
+        let howard_st = StreetName::new_for_region("Howard Street", &region).unwrap();
+        let wisconsin = StreetName::new_for_region("Wisconsin Avenue", &region).unwrap();
+        let pc21201   = PostalCode::new(Country::USA, "21201").unwrap();
+        let pc20814   = PostalCode::new(Country::USA, "20814").unwrap();
+
+        let records = RegionalRecordsBuilder::default()
+            .region(region)
+            .records(vec![
+                address_record!(baltimore, north_ave, pc21201),
+                address_record!(baltimore, howard_st, pc21201),
+                address_record!(bethesda,  wisconsin, pc20814),
+            ])
+            .build()
+            .unwrap();
+        {
+            let mut guard = db.lock().unwrap();
+            records.write_to_storage(&mut *guard).unwrap();
+            guard.store_house_number_ranges(&region, &howard_st, &[HouseNumberRange::new(100, 200)]).unwrap();
+        }
+
+        let service = AddressService::new(db.lock().unwrap().clone());
+        (service, db, tmp)
+    }
+
+    fn validate_request(postal_code: &str, city: &str, street: &str) -> ValidateRequestBuilder {
+        let mut b = ValidateRequestBuilder::default();
+        b.region("MD").postal_code(postal_code).city(city).street(street);
+        b
+    }
+
+    #[traced_test]
+    fn test_validate_accepts_known_address_and_checks_house_number() {
+        let (service, _db, _tmp) = service_with_md_data();
+
+        let req = validate_request("21201", "Baltimore", "Howard St").house_number(Some(HouseNumber::from(150))).build().unwrap();
+        let resp = service.validate(&req).unwrap();
+        assert!(resp.valid());
+        assert_eq!(*resp.house_number_known(), Some(true));
+
+        let req = validate_request("21201", "Baltimore", "Howard St").house_number(Some(HouseNumber::from(999))).build().unwrap();
+        assert_eq!(*service.validate(&req).unwrap().house_number_known(), Some(false));
+    }
+
+    #[traced_test]
+    fn test_validate_suggests_city_for_postal_code() {
+        let (service, _db, _tmp) = service_with_md_data();
+
+        let req  = validate_request("21201", "Baltmore", "Howard Street").build().unwrap();
+        let resp = service.validate(&req).unwrap();
+        assert!(!resp.valid());
+        assert_eq!(*resp.problem(), Some(AddressProblem::CityNotInPostalCode));
+        assert_eq!(*resp.field(), Some(AddressField::City));
+        assert_eq!(resp.suggestions(), &vec!["baltimore".to_string()]);
+    }
+
+    #[traced_test]
+    fn test_validate_suggests_street_for_postal_code() {
+        let (service, _db, _tmp) = service_with_md_data();
+
+        let req  = validate_request("21201", "Baltimore", "Hward Street").build().unwrap();
+        let resp = service.validate(&req).unwrap();
+        assert_eq!(*resp.problem(), Some(AddressProblem::StreetNotInPostalCode));
+        assert_eq!(resp.suggestions().first().map(String::as_str), Some("howard street"));
+    }
+
+    #[traced_test]
+    fn test_validate_rejects_unknown_region() {
+        let (service, _db, _tmp) = service_with_md_data();
+
+        let req = ValidateRequestBuilder::default()
+            .region("ZZ").postal_code("21201").city("Baltimore").street("Howard Street")
+            .build().unwrap();
+        assert!(matches!(service.validate(&req), Err(AddressServiceError::UnknownRegion { .. })));
+    }
+
+    #[traced_test]
+    fn test_autocomplete_cities_and_streets() {
+        let (service, _db, _tmp) = service_with_md_data();
+
+        let req = AutocompleteRequestBuilder::default().region("MD").prefix("Ba").build().unwrap();
+        assert_eq!(service.autocomplete_cities(&req).unwrap().matches(), &vec!["baltimore".to_string()]);
+
+        let req = AutocompleteRequestBuilder::default().region("MD").prefix("North Av").build().unwrap();
+        assert_eq!(service.autocomplete_streets(&req).unwrap().matches(), &vec!["north avenue".to_string()]);
+
+        let req = AutocompleteRequestBuilder::default().region("MD").prefix("  ").build().unwrap();
+        assert!(matches!(service.autocomplete_cities(&req), Err(AddressServiceError::EmptyPrefix)));
+    }
+
+    #[traced_test]
+    fn test_autocomplete_respects_limit() {
+        let (service, _db, _tmp) = service_with_md_data();
+
+        let req = AutocompleteRequestBuilder::default().region("MD").prefix("b").limit(Some(1)).build().unwrap();
+        assert_eq!(service.autocomplete_cities(&req).unwrap().matches().len(), 1);
+
+        let req = AutocompleteRequestBuilder::default().region("MD").prefix("b").limit(Some(0)).build().unwrap();
+        assert!(service.autocomplete_cities(&req).unwrap().matches().is_empty());
+    }
+
+    #[traced_test]
+    fn test_cities_for_postal_code() {
+        let (service, _db, _tmp) = service_with_md_data();
+
+        let req  = CitiesForPostalCodeRequestBuilder::default().region("MD").postal_code("20814").build().unwrap();
+        let resp = service.cities_for_postal_code(&req).unwrap();
+        assert!(resp.known());
+        assert_eq!(resp.cities(), &vec!["bethesda".to_string()]);
+
+        let req = CitiesForPostalCodeRequestBuilder::default().region("MD").postal_code("99999").build().unwrap();
+        assert!(!service.cities_for_postal_code(&req).unwrap().known());
+    }
+
+    #[traced_test]
+    fn test_house_number_returns_ranges() {
+        let (service, _db, _tmp) = service_with_md_data();
+
+        let req  = HouseNumberRequestBuilder::default().region("MD").street("Howard Street").house_number(120u32).build().unwrap();
+        let resp = service.house_number(&req).unwrap();
+        assert!(resp.in_range());
+        assert_eq!(resp.ranges(), &vec![HouseNumberRange::new(100, 200)]);
+    }
+
+    #[traced_test]
+    fn test_check_house_number_with_letter_suffix() {
+        let (service, db, _tmp) = service_with_md_data();
+        let region: WorldRegion = USRegion::UnitedState(UnitedState::Maryland).into();
+        let north_ave = StreetName::new_for_region("North Avenue", &region).unwrap();
+        db.lock().unwrap()
+            .store_house_number_ranges(&region, &north_ave, &[HouseNumberRange::lettered(221, 'A', 'C')])
+            .unwrap();
+
+        let check = |raw: &str| {
+            let number = HouseNumber::parse(raw).unwrap();
+            *service.check_house_number("MD", "North Avenue", &number).unwrap().in_range()
+        };
+        assert!(check("221B"));
+        assert!(!check("221D"));
+        assert!(!check("221"));
+
+        // plain ranges accept any suffix
+        let number = HouseNumber::parse("150Z").unwrap();
+        assert!(service.check_house_number("MD", "Howard Street", &number).unwrap().in_range());
+    }
+}
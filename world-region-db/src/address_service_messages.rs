@@ -0,0 +1,172 @@
+// ---------------- [ File: src/address_service_messages.rs ]
+crate::ix!();
+
+/// Regions are given by abbreviation (`"MD"`, `"DC"`), names as written.
+#[derive(Builder,Getters,Debug,Clone,PartialEq,Eq,Serialize,Deserialize)]
+#[getset(get="pub")]
+#[builder(setter(into))]
+pub struct ValidateRequest {
+    region:       String,
+    postal_code:  String,
+    city:         String,
+    street:       String,
+
+    /// Checked against the street's stored house-number ranges when given;
+    /// `"12A"` and `12` are both accepted.
+    #[builder(default)]
+    #[serde(default)]
+    house_number: Option<HouseNumber>,
+}
+
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Serialize,Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AddressField {
+    PostalCode,
+    City,
+    Street,
+}
+
+/// Why an address failed validation, one per [`InvalidWorldAddress`] case.
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Serialize,Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AddressProblem {
+    UnknownPostalCode,
+    CityNotInPostalCode,
+    StreetNotInPostalCode,
+    UnknownCity,
+    StreetNotInCity,
+}
+
+impl AddressField {
+
+    /// The name used on the wire.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AddressField::PostalCode => "postal_code",
+            AddressField::City       => "city",
+            AddressField::Street     => "street",
+        }
+    }
+}
+
+impl AddressProblem {
+
+    /// The field the suggestions for this problem replace.
+    pub fn field(&self) -> AddressField {
+        match self {
+            AddressProblem::UnknownPostalCode     => AddressField::PostalCode,
+            AddressProblem::CityNotInPostalCode
+                | AddressProblem::UnknownCity     => AddressField::City,
+            AddressProblem::StreetNotInPostalCode
+                | AddressProblem::StreetNotInCity => AddressField::Street,
+        }
+    }
+}
+
+impl From<&InvalidWorldAddress> for AddressProblem {
+    fn from(e: &InvalidWorldAddress) -> Self {
+        match e {
+            InvalidWorldAddress::CityNotFoundForPostalCodeInRegion       { .. } => AddressProblem::CityNotInPostalCode,
+            InvalidWorldAddress::PostalCodeToCityKeyNotFoundForRegion    { .. } => AddressProblem::UnknownPostalCode,
+            InvalidWorldAddress::StreetNotFoundForPostalCodeInRegion     { .. } => AddressProblem::StreetNotInPostalCode,
+            InvalidWorldAddress::PostalCodeToStreetKeyNotFoundForRegion  { .. } => AddressProblem::UnknownPostalCode,
+            InvalidWorldAddress::StreetNotFoundForCityInRegion           { .. } => AddressProblem::StreetNotInCity,
+            InvalidWorldAddress::CityToStreetsKeyNotFoundForCityInRegion { .. } => AddressProblem::UnknownCity,
+        }
+    }
+}
+
+/// `valid` covers postal code, city and street. The house number is
+/// reported separately in `house_number_known`, since OSM range coverage is
+/// patchy and a miss there does not make the address wrong.
+#[derive(Builder,Getters,Debug,Clone,PartialEq,Eq,Serialize,Deserialize)]
+#[getset(get="pub")]
+#[builder(setter(into))]
+pub struct ValidateResponse {
+    valid:              bool,
+
+    #[builder(default)]
+    problem:            Option<AddressProblem>,
+
+    #[builder(default)]
+    field:              Option<AddressField>,
+
+    /// Replacements for `field`, best first.
+    #[builder(default)]
+    suggestions:        Vec<String>,
+
+    #[builder(default)]
+    house_number_known: Option<bool>,
+}
+
+#[derive(Builder,Getters,Debug,Clone,PartialEq,Eq,Serialize,Deserialize)]
+#[getset(get="pub")]
+#[builder(setter(into))]
+pub struct AutocompleteRequest {
+    region: String,
+    prefix: String,
+
+    #[builder(default)]
+    #[serde(default)]
+    limit:  Option<usize>,
+}
+
+#[derive(Getters,Debug,Clone,PartialEq,Eq,Serialize,Deserialize)]
+#[getset(get="pub")]
+pub struct AutocompleteResponse {
+    matches: Vec<String>,
+}
+
+impl AutocompleteResponse {
+    pub fn new(matches: Vec<String>) -> Self {
+        Self { matches }
+    }
+}
+
+#[derive(Builder,Getters,Debug,Clone,PartialEq,Eq,Serialize,Deserialize)]
+#[getset(get="pub")]
+#[builder(setter(into))]
+pub struct CitiesForPostalCodeRequest {
+    region:      String,
+    postal_code: String,
+}
+
+/// `known` is false when the postal code has no entry in the region at all.
+#[derive(Getters,Debug,Clone,PartialEq,Eq,Serialize,Deserialize)]
+#[getset(get="pub")]
+pub struct CitiesForPostalCodeResponse {
+    known:  bool,
+    cities: Vec<String>,
+}
+
+impl CitiesForPostalCodeResponse {
+    pub fn new(known: bool, cities: Vec<String>) -> Self {
+        Self { known, cities }
+    }
+}
+
+#[derive(Builder,Getters,Debug,Clone,PartialEq,Eq,Serialize,Deserialize)]
+#[getset(get="pub")]
+#[builder(setter(into))]
+pub struct HouseNumberRequest {
+    region:       String,
+    street:       String,
+
+    /// `"12A"` and `12` are both accepted.
+    house_number: HouseNumber,
+}
+
+/// `ranges` is everything stored for the street, so callers can show the
+/// nearest valid numbers when `in_range` is false.
+#[derive(Getters,Debug,Clone,PartialEq,Eq,Serialize,Deserialize)]
+#[getset(get="pub")]
+pub struct HouseNumberResponse {
+    in_range: bool,
+    ranges:   Vec<HouseNumberRange>,
+}
+
+impl HouseNumberResponse {
+    pub fn new(in_range: bool, ranges: Vec<HouseNumberRange>) -> Self {
+        Self { in_range, ranges }
+    }
+}
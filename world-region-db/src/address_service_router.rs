@@ -0,0 +1,178 @@
+// ---------------- [ File: src/address_service_router.rs ]
+crate::ix!();
+
+/// JSON routes over [`AddressService`]:
+///
+/// - `GET /validate?region=MD&postal_code=21201&city=Baltimore&street=...`
+///   (or `POST /validate` with the same fields as a JSON body)
+/// - `GET /autocomplete/cities?region=MD&prefix=balt[&limit=5]`
+/// - `GET /autocomplete/streets?region=MD&prefix=n char[&limit=5]`
+/// - `GET /cities?region=MD&postal_code=21201`
+/// - `GET /house-number?region=MD&street=howard street&house_number=120A`
+pub fn address_service_router<I:StorageInterface + 'static>(service: AddressService<I>) -> Router {
+    Router::new()
+        .route("/validate",             routing::get(validate_query::<I>).post(validate_json::<I>))
+        .route("/autocomplete/cities",  routing::get(autocomplete_cities::<I>))
+        .route("/autocomplete/streets", routing::get(autocomplete_streets::<I>))
+        .route("/cities",               routing::get(cities_for_postal_code::<I>))
+        .route("/house-number",         routing::get(house_number::<I>))
+        .with_state(service)
+}
+
+/// Serves [`address_service_router`] on `addr` until the process is stopped.
+pub async fn serve_address_service<I:StorageInterface + 'static>(
+    service: AddressService<I>,
+    addr:    SocketAddr,
+) -> Result<(),AddressServiceError> {
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    info!("address service listening on {}", listener.local_addr()?);
+    axum::serve(listener, address_service_router(service)).await?;
+    Ok(())
+}
+
+/// RocksDB reads block, so every query runs on the blocking pool.
+async fn run_blocking<I,T>(
+    service: AddressService<I>,
+    query:   impl FnOnce(&AddressService<I>) -> Result<T,AddressServiceError> + Send + 'static,
+) -> Result<Json<T>,AddressServiceError>
+where
+    I: StorageInterface + 'static,
+    T: Send + 'static,
+{
+    tokio::task::spawn_blocking(move || query(&service))
+        .await
+        .map_err(|e| AddressServiceError::BackgroundTaskFailed { msg: e.to_string() })?
+        .map(Json)
+}
+
+async fn validate_query<I:StorageInterface + 'static>(
+    State(service): State<AddressService<I>>,
+    Query(req):     Query<ValidateRequest>,
+) -> Result<Json<ValidateResponse>,AddressServiceError> {
+    run_blocking(service, move |s| s.validate(&req)).await
+}
+
+async fn validate_json<I:StorageInterface + 'static>(
+    State(service): State<AddressService<I>>,
+    Json(req):      Json<ValidateRequest>,
+) -> Result<Json<ValidateResponse>,AddressServiceError> {
+    run_blocking(service, move |s| s.validate(&req)).await
+}
+
+async fn autocomplete_cities<I:StorageInterface + 'static>(
+    State(service): State<AddressService<I>>,
+    Query(req):     Query<AutocompleteRequest>,
+) -> Result<Json<AutocompleteResponse>,AddressServiceError> {
+    run_blocking(service, move |s| s.autocomplete_cities(&req)).await
+}
+
+async fn autocomplete_streets<I:StorageInterface + 'static>(
+    State(service): State<AddressService<I>>,
+    Query(req):     Query<AutocompleteRequest>,
+) -> Result<Json<AutocompleteResponse>,AddressServiceError> {
+    run_blocking(service, move |s| s.autocomplete_streets(&req)).await
+}
+
+async fn cities_for_postal_code<I:StorageInterface + 'static>(
+    State(service): State<AddressService<I>>,
+    Query(req):     Query<CitiesForPostalCodeRequest>,
+) -> Result<Json<CitiesForPostalCodeResponse>,AddressServiceError> {
+    run_blocking(service, move |s| s.cities_for_postal_code(&req)).await
+}
+
+async fn house_number<I:StorageInterface + 'static>(
+    State(service): State<AddressService<I>>,
+    Query(req):     Query<HouseNumberRequest>,
+) -> Result<Json<HouseNumberResponse>,AddressServiceError> {
+    run_blocking(service, move |s| s.house_number(&req)).await
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: String,
+}
+
+impl AddressServiceError {
+
+    /// Bad input is the caller's problem (400); anything else is ours (500).
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            AddressServiceError::UnknownRegion { .. }
+            | AddressServiceError::EmptyPrefix
+            | AddressServiceError::WorldRegionConversionError(_)
+            | AddressServiceError::PostalCodeConstructionError(_)
+            | AddressServiceError::CityNameConstructionError(_)
+            | AddressServiceError::StreetNameConstructionError(_) => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for AddressServiceError {
+    fn into_response(self) -> Response {
+        let status = self.status_code();
+        if status.is_server_error() {
+            error!("address service: {:?}", self);
+        }
+        (status, Json(ErrorBody { error: format!("{:?}", self) })).into_response()
+    }
+}
+
+#[cfg(test)]
+mod address_service_router_tests {
+    use super::*;
+
+    #[traced_test]
+    fn test_input_errors_are_bad_requests() {
+        assert_eq!(AddressServiceError::EmptyPrefix.status_code(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            AddressServiceError::UnknownRegion { abbreviation: "ZZ".into() }.status_code(),
+            StatusCode::BAD_REQUEST
+        );
+        assert_eq!(AddressServiceError::DbLockError.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[traced_test]
+    async fn test_serves_json_over_http() {
+        let (db, _tmp) = create_temp_db::<Database>();
+        let region: WorldRegion = USRegion::UnitedState(UnitedState::Maryland).into();
+        {
+            let mut guard = db.lock().unwrap();
+            RegionalRecords::mock_for_region(&region).write_to_storage(&mut *guard).unwrap();
+        }
+        let service = AddressService::new(db.lock().unwrap().clone());
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr     = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, address_service_router(service)).await.unwrap();
+        });
+
+        let body = reqwest::get(format!("http://{}/cities?region=MD&postal_code=21201", addr))
+            .await.unwrap()
+            .json::<CitiesForPostalCodeResponse>()
+            .await.unwrap();
+        assert!(body.known());
+        assert_eq!(body.cities(), &vec!["baltimore".to_string()]);
+
+        // lettered house numbers are accepted in queries and JSON bodies alike
+        let resp = reqwest::get(format!("http://{}/house-number?region=MD&street=north avenue&house_number=221B", addr))
+            .await.unwrap();
+        assert_eq!(resp.status().as_u16(), 200);
+
+        let body = reqwest::Client::new()
+            .post(format!("http://{}/validate", addr))
+            .json(&serde_json::json!({
+                "region": "MD", "postal_code": "21201", "city": "Baltimore",
+                "street": "North Avenue", "house_number": "12A",
+            }))
+            .send().await.unwrap()
+            .json::<ValidateResponse>()
+            .await.unwrap();
+        assert!(body.valid());
+
+        let resp = reqwest::get(format!("http://{}/autocomplete/cities?region=ZZ&prefix=b", addr))
+            .await.unwrap();
+        assert_eq!(resp.status().as_u16(), 400);
+    }
+}
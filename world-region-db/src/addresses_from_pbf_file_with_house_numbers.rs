@@ -258,7 +258,7 @@ mod addresses_from_pbf_file_with_house_numbers_tests {
 
         // aggregator was never used => no house number => let's see if aggregator got stored in DB or not
         let db_guard = db.lock().unwrap();
-        let possible_key = format!("HNR:{}:{}", region.abbreviation(), "north ave");
+        let possible_key = format!("HNR:{}:{}", region.abbreviation(), "n ave");
         let hnr = db_guard.get(possible_key).unwrap();
         assert!(hnr.is_none(), "No housenumber => aggregator empty => no DB entry");
     }
@@ -296,7 +296,7 @@ mod addresses_from_pbf_file_with_house_numbers_tests {
 
         // aggregator => must have house number range => [100..=110]
         let db_guard = db.lock().unwrap();
-        let hnr_key = format!("HNR:{}:{}", region.abbreviation(), "catlett road");
+        let hnr_key = format!("HNR:{}:{}", region.abbreviation(), "catlett rd");
         let hnr_val_opt = db_guard.get(hnr_key.as_bytes()).unwrap();
         assert!(hnr_val_opt.is_some(), "Expect aggregator was stored => found DB entry for housenumber range");
 
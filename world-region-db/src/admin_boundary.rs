@@ -0,0 +1,227 @@
+// ---------------- [ File: src/admin_boundary.rs ]
+crate::ix!();
+
+/// Lowest and highest `admin_level` we read. In most countries 6 is the
+/// county (or district), 7 the township and 8 the city or municipality.
+pub const MIN_BOUNDARY_ADMIN_LEVEL: u8 = 6;
+pub const MAX_BOUNDARY_ADMIN_LEVEL: u8 = 8;
+
+/// What an OSM boundary relation encloses.
+#[derive(Copy,Clone,Debug,Hash,PartialEq,Eq,PartialOrd,Ord,Serialize,Deserialize)]
+pub enum BoundaryKind {
+    /// `boundary=administrative` at the given `admin_level`.
+    Administrative { level: u8 },
+    /// `boundary=postal_code`.
+    PostalCode,
+}
+
+impl BoundaryKind {
+
+    /// Reads the kind from a relation's tags; `None` for anything that is
+    /// not a boundary we keep.
+    pub fn from_tags(tags: &HashMap<String,String>) -> Option<Self> {
+        match tags.get("boundary").map(String::as_str) {
+            Some("administrative") => {
+                let level = tags.get("admin_level")?.trim().parse::<u8>().ok()?;
+                (MIN_BOUNDARY_ADMIN_LEVEL..=MAX_BOUNDARY_ADMIN_LEVEL)
+                    .contains(&level)
+                    .then_some(BoundaryKind::Administrative { level })
+            }
+            Some("postal_code") => Some(BoundaryKind::PostalCode),
+            _                   => None,
+        }
+    }
+
+    /// Administrative boundaries that name a city: the municipality level,
+    /// with townships as a fallback. Counties never do.
+    pub fn names_city(&self) -> bool {
+        matches!(self, BoundaryKind::Administrative { level: 7 | 8 })
+    }
+}
+
+/// A closed ring of points; the last point repeats the first.
+pub type BoundaryRing = Vec<GeoPoint>;
+
+/// A boundary polygon read from an OSM relation, stored per
+/// [`WorldRegion`] under `BND:{abbr}:{relation_id}`.
+#[derive(Builder,Getters,Clone,Debug,PartialEq,Eq,Serialize,Deserialize)]
+#[getset(get="pub")]
+#[builder(setter(into), build_fn(name="build_without_bounding_box", private))]
+pub struct AdminBoundary {
+    region:      WorldRegion,
+    relation_id: i64,
+    kind:        BoundaryKind,
+    /// The `name` tag, or the postal code itself for postal boundaries.
+    name:        String,
+    outer:       Vec<BoundaryRing>,
+    #[builder(default)]
+    inner:       Vec<BoundaryRing>,
+    /// `(south-west, north-east)` corners of the outer rings, worked out
+    /// once by the builder.
+    #[builder(setter(skip))]
+    bounding_box: Option<(GeoPoint, GeoPoint)>,
+}
+
+impl AdminBoundaryBuilder {
+
+    pub fn build(&self) -> Result<AdminBoundary, AdminBoundaryBuilderError> {
+        let mut boundary = self.build_without_bounding_box()?;
+        boundary.bounding_box = bounding_box_of(&boundary.outer);
+        Ok(boundary)
+    }
+}
+
+impl AdminBoundary {
+
+    /// Whether `point` lies inside an outer ring and outside every inner
+    /// ring. Points exactly on an edge may fall either way.
+    pub fn contains(&self, point: &GeoPoint) -> bool {
+        match &self.bounding_box {
+            Some((sw, ne)) if point_in_box(point, sw, ne) => {}
+            _ => return false,
+        }
+        self.outer.iter().any(|ring| ring_contains(ring, point))
+            && !self.inner.iter().any(|ring| ring_contains(ring, point))
+    }
+
+    /// The [`BOUNDARY_INDEX_PRECISION`] geohash cells the bounding box
+    /// overlaps; a point inside the boundary falls in one of them.
+    pub fn index_cells(&self) -> Vec<String> {
+        match &self.bounding_box {
+            Some((sw, ne)) => geohash_cells_covering(sw, ne, BOUNDARY_INDEX_PRECISION),
+            None           => vec![],
+        }
+    }
+}
+
+fn bounding_box_of(rings: &[BoundaryRing]) -> Option<(GeoPoint, GeoPoint)> {
+    let mut points = rings.iter().flatten();
+    let first = points.next()?;
+    let (mut min_lat, mut min_lon) = (*first.lat_e7(), *first.lon_e7());
+    let (mut max_lat, mut max_lon) = (min_lat, min_lon);
+    for p in points {
+        min_lat = min_lat.min(*p.lat_e7());
+        min_lon = min_lon.min(*p.lon_e7());
+        max_lat = max_lat.max(*p.lat_e7());
+        max_lon = max_lon.max(*p.lon_e7());
+    }
+    Some((GeoPoint::from_e7(min_lat, min_lon), GeoPoint::from_e7(max_lat, max_lon)))
+}
+
+fn point_in_box(p: &GeoPoint, sw: &GeoPoint, ne: &GeoPoint) -> bool {
+    (*sw.lat_e7()..=*ne.lat_e7()).contains(p.lat_e7())
+        && (*sw.lon_e7()..=*ne.lon_e7()).contains(p.lon_e7())
+}
+
+/// Even-odd ray casting on the raw coordinates. Boundaries are small
+/// enough next to the earth that treating degrees as planar is fine.
+pub fn ring_contains(ring: &[GeoPoint], point: &GeoPoint) -> bool {
+    let (py, px) = (*point.lat_e7() as f64, *point.lon_e7() as f64);
+    let mut inside = false;
+    for edge in ring.windows(2) {
+        let (ay, ax) = (*edge[0].lat_e7() as f64, *edge[0].lon_e7() as f64);
+        let (by, bx) = (*edge[1].lat_e7() as f64, *edge[1].lon_e7() as f64);
+        if (ay > py) != (by > py) {
+            let crossing = ax + (py - ay) * (bx - ax) / (by - ay);
+            if px < crossing {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+/// Joins the node lists of a relation's member ways into closed rings.
+/// Ways may be listed in any order and direction; pieces that never close
+/// (a boundary cut off at the edge of an extract) are dropped.
+pub fn assemble_rings(mut ways: Vec<Vec<i64>>) -> Vec<Vec<i64>> {
+    ways.retain(|w| w.len() >= 2);
+
+    let mut rings = Vec::new();
+    while let Some(mut ring) = ways.pop() {
+        while ring.first() != ring.last() {
+            let tail = *ring.last().unwrap();
+            let next = ways.iter().position(|w| w.first() == Some(&tail) || w.last() == Some(&tail));
+            let Some(idx) = next else {
+                break;
+            };
+            let mut piece = ways.swap_remove(idx);
+            if piece.first() != Some(&tail) {
+                piece.reverse();
+            }
+            ring.extend(piece.into_iter().skip(1));
+        }
+        if ring.len() >= 4 && ring.first() == ring.last() {
+            rings.push(ring);
+        } else {
+            debug!("assemble_rings: dropping unclosed piece of {} nodes", ring.len());
+        }
+    }
+    rings
+}
+
+#[cfg(test)]
+mod admin_boundary_tests {
+    use super::*;
+
+    #[traced_test]
+    fn test_kind_from_tags() {
+        let tags = |pairs: &[(&str, &str)]| -> HashMap<String,String> {
+            pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+        };
+        assert_eq!(
+            BoundaryKind::from_tags(&tags(&[("boundary", "administrative"), ("admin_level", "8")])),
+            Some(BoundaryKind::Administrative { level: 8 })
+        );
+        assert_eq!(BoundaryKind::from_tags(&tags(&[("boundary", "administrative"), ("admin_level", "4")])), None);
+        assert_eq!(BoundaryKind::from_tags(&tags(&[("boundary", "postal_code")])), Some(BoundaryKind::PostalCode));
+        assert_eq!(BoundaryKind::from_tags(&tags(&[("boundary", "national_park")])), None);
+    }
+
+    #[traced_test]
+    fn test_contains_respects_holes() {
+        let boundary = AdminBoundaryBuilder::default()
+            .region(example_region())
+            .relation_id(1)
+            .kind(BoundaryKind::Administrative { level: 8 })
+            .name("Baltimore")
+            .outer(vec![mock_square_ring(39.0, -77.0, 40.0, -76.0)])
+            .inner(vec![mock_square_ring(39.4, -76.6, 39.6, -76.4)])
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            boundary.bounding_box(),
+            &Some((mock_point(39.0, -77.0), mock_point(40.0, -76.0)))
+        );
+        assert!(boundary.contains(&mock_point(39.2, -76.8)));
+        assert!(!boundary.contains(&mock_point(39.5, -76.5)));
+        assert!(!boundary.contains(&mock_point(41.0, -76.5)));
+    }
+
+    #[traced_test]
+    fn test_index_cells_cover_the_interior() {
+        let boundary = mock_square_boundary(1, BoundaryKind::Administrative { level: 6 }, "County", 39.0, -77.0, 40.0, -76.0);
+        let cells = boundary.index_cells();
+        for p in [mock_point(39.1, -76.9), mock_point(39.5, -76.5), mock_point(39.9, -76.1)] {
+            assert!(cells.contains(&encode_geohash(&p, BOUNDARY_INDEX_PRECISION)));
+        }
+    }
+
+    #[traced_test]
+    fn test_assemble_rings_joins_reversed_pieces() {
+        let ways = vec![
+            vec![1, 2, 3],
+            vec![5, 4, 3],
+            vec![5, 6, 1],
+            vec![10, 11],
+        ];
+        let rings = assemble_rings(ways);
+        assert_eq!(rings.len(), 1);
+        let ring = &rings[0];
+        assert_eq!(ring.first(), ring.last());
+        let mut nodes: Vec<i64> = ring[1..].to_vec();
+        nodes.sort();
+        assert_eq!(nodes, vec![1, 2, 3, 4, 5, 6]);
+    }
+}
@@ -0,0 +1,546 @@
+// ---------------- [ File: src/apply_osm_change.rs ]
+crate::ix!();
+
+/// What applying one or more OSM change files did to a region.
+#[derive(Getters,Clone,Debug,Default,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct OsmChangeSummary {
+    files_applied:     usize,
+    last_sequence:     Option<u64>,
+    addresses_added:   usize,
+    addresses_removed: usize,
+    /// City/street/postal code links that appeared for the first time.
+    links_added:       usize,
+    /// Links whose last supporting element went away.
+    links_removed:     usize,
+}
+
+impl OsmChangeSummary {
+
+    pub(crate) fn record_file(&mut self, sequence: u64, file: OsmChangeSummary) {
+        self.files_applied     += 1;
+        self.last_sequence      = Some(sequence);
+        self.addresses_added   += file.addresses_added;
+        self.addresses_removed += file.addresses_removed;
+        self.links_added       += file.links_added;
+        self.links_removed     += file.links_removed;
+    }
+}
+
+pub trait ApplyOsmChangeForRegion {
+
+    fn apply_osm_change_for_region(
+        &mut self,
+        region: &WorldRegion,
+        change: &OsmChange,
+    ) -> Result<OsmChangeSummary, OsmChangeError>;
+}
+
+impl ApplyOsmChangeForRegion for Database {
+
+    /// Replaces, element by element, the address each changed element
+    /// contributed (looked up under its `OSM:` key) with the address it
+    /// carries now:
+    ///
+    ///   - link counts (`REF:`) go down for the old address and up for the
+    ///     new one, leaving alone links both versions have; a link is removed
+    ///     from both of its index keys when its count reaches zero and added
+    ///     when it leaves zero.
+    ///   - the located address moves between `GEO:`/`LOC:` lists.
+    ///   - the new house number is merged into the street's `HNR:` ranges.
+    ///
+    /// A city or postal code the element's tags lack is taken from the
+    /// stored `BND:` boundaries around it, as the full build does.
+    ///
+    /// House-number ranges only ever widen here: they are aggregated per
+    /// street, so a deleted number cannot be taken back out without
+    /// rebuilding the region. Emptied index sets are left in place.
+    ///
+    /// A way is positioned at the centroid of those of its nodes that appear
+    /// in the same change; if none do, it keeps its previous position.
+    ///
+    /// Everything one element changes, its `OSM:` key included, is written
+    /// in a single batch: an interrupted run never leaves link counts bumped
+    /// for an element whose `OSM:` key still holds the old address, which a
+    /// re-run would count a second time.
+    fn apply_osm_change_for_region(
+        &mut self,
+        region: &WorldRegion,
+        change: &OsmChange,
+    ) -> Result<OsmChangeSummary, OsmChangeError> {
+
+        let country        = Country::try_from(*region)?;
+        let node_locations = change.node_locations();
+        let mut summary    = OsmChangeSummary::default();
+
+        for element in change.elements() {
+            let key        = osm_element_key(region, element.element());
+            let mut staged = StagedWrites::new(self);
+
+            let old: Option<AddressRecord> = match staged.get(&key)? {
+                Some(bytes) => Some(serde_cbor::from_slice(&bytes).map_err(|e| OsmChangeError::SerdeError {
+                    msg: format!("Failed to decode indexed address under '{}': {}", key, e),
+                })?),
+                None => None,
+            };
+
+            let mut new = match element.action() {
+                OsmChangeAction::Delete => None,
+                _ => address_record_from_change_element(element, country, &node_locations),
+            };
+
+            if let (Some(new), Some(old)) = (new.as_mut(), old.as_ref())
+                && new.location().is_none() {
+                new.set_location(*old.location());
+            }
+
+            // The build fills a missing city or postal code from the
+            // boundaries around the address; do the same, or an untouched
+            // boundary-assigned field would read as changed.
+            if let Some(new) = new.as_mut() {
+                assign_fields_from_stored_boundaries(&staged, region, new, country);
+            }
+
+            if old == new {
+                continue;
+            }
+
+            trace!("apply_osm_change_for_region: {} {:?} => {:?}", element.element(), old, new);
+
+            // links both versions support keep their count and index entries
+            let new_links    = new.as_ref().map(AddressLink::all_for).unwrap_or_default();
+            let kept: Vec<_> = old
+                .as_ref()
+                .map(AddressLink::all_for)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|link| new_links.contains(link))
+                .collect();
+
+            if let Some(old) = &old {
+                remove_indexed_address(&mut staged, region, old, &kept, &mut summary)?;
+            }
+
+            match &new {
+                Some(new) => {
+                    add_indexed_address(&mut staged, region, new, &kept, &mut summary)?;
+                    staged.put(&key, serialize_indexed_address(new)?)?;
+                }
+                None => staged.delete(&key)?,
+            }
+
+            staged.commit()?;
+        }
+
+        debug!("apply_osm_change_for_region: {:?} => {:?}", region, summary);
+        Ok(summary)
+    }
+}
+
+/// The address a created or modified element carries, built the same way
+/// the full `.osm.pbf` parse builds it. Relations are skipped, as they are
+/// there.
+fn address_record_from_change_element(
+    element:        &OsmChangeElement,
+    country:        Country,
+    node_locations: &HashMap<i64, GeoPoint>,
+) -> Option<AddressRecord> {
+
+    let id = element.element().id();
+
+    let location = match element.element() {
+        OsmElementRef::Node(_)     => *element.location(),
+        OsmElementRef::Way(_)      => {
+            let points: Vec<GeoPoint> = element
+                .node_refs()
+                .iter()
+                .filter_map(|node| node_locations.get(node).copied())
+                .collect();
+            GeoPoint::centroid(&points)
+        }
+        OsmElementRef::Relation(_) => return None,
+    };
+
+    let mut record = try_build_address_record_from_tags(element.tag_iter(), country, id).ok()?;
+    if record.is_empty() {
+        return None;
+    }
+    if let Ok(Some(hnr)) = extract_house_number_range_from_tags(element.tag_iter(), id) {
+        record.set_housenumber(Some(hnr));
+    }
+    record.set_location(location);
+    record.set_osm_element(Some(*element.element()));
+    Some(record)
+}
+
+/// [`assign_fields_from_containing_boundaries`] against the boundaries
+/// stored for `region`, found through their `BNDX:` cell.
+fn assign_fields_from_stored_boundaries<D: DatabaseGet>(
+    db:      &D,
+    region:  &WorldRegion,
+    record:  &mut AddressRecord,
+    country: Country,
+) {
+    let Some(location) = *record.location() else {
+        return;
+    };
+    if record.city().is_some() && record.postcode().is_some() {
+        return;
+    }
+    let containing = stored_boundaries_containing_point(db, region, &location);
+    let containing: Vec<&AdminBoundary> = containing.iter().collect();
+    assign_fields_from_containing_boundaries(record, &containing, country);
+}
+
+/// Takes `record` out of the indexes, except for the `kept` links, which
+/// the element's new version still supports.
+fn remove_indexed_address(
+    db:      &mut StagedWrites<'_>,
+    region:  &WorldRegion,
+    record:  &AddressRecord,
+    kept:    &[AddressLink],
+    summary: &mut OsmChangeSummary,
+) -> Result<(), DatabaseConstructionError> {
+
+    for link in AddressLink::all_for(record).into_iter().filter(|l| !kept.contains(l)) {
+        let count = read_address_link_count(&*db, region, &link)?;
+        let count = count.saturating_sub(1);
+        write_address_link_count(db, region, &link, count)?;
+        if count == 0 {
+            unlink(db, region, &link)?;
+            summary.links_removed += 1;
+        }
+    }
+
+    if let Some(located) = LocatedAddress::from_record(region, record) {
+        for key in located_address_keys(region, record, &located) {
+            edit_located_list(db, &key, |list| {
+                if let Some(pos) = list.iter().position(|l| l == &located) {
+                    list.remove(pos);
+                }
+            })?;
+        }
+    }
+
+    summary.addresses_removed += 1;
+    Ok(())
+}
+
+/// Puts `record` into the indexes; the `kept` links were never taken out.
+fn add_indexed_address(
+    db:      &mut StagedWrites<'_>,
+    region:  &WorldRegion,
+    record:  &AddressRecord,
+    kept:    &[AddressLink],
+    summary: &mut OsmChangeSummary,
+) -> Result<(), DatabaseConstructionError> {
+
+    for link in AddressLink::all_for(record).into_iter().filter(|l| !kept.contains(l)) {
+        let count = read_address_link_count(&*db, region, &link)?;
+        write_address_link_count(db, region, &link, count + 1)?;
+        if count == 0 {
+            link_both_ways(db, region, &link)?;
+            summary.links_added += 1;
+        }
+    }
+
+    // Same presence keys the full build writes for lone fields
+    if let Some(city) = record.city() {
+        edit_cbor_set::<StreetName>(db, &c2s_key(region, city), |_| {})?;
+    }
+    if let Some(street) = record.street() {
+        edit_cbor_set::<CityName>(db, &s2c_key(region, street), |_| {})?;
+    }
+    if let Some(pc) = record.postcode() {
+        edit_cbor_set::<CityName>(db, &z2c_key(region, pc), |_| {})?;
+        edit_cbor_set::<StreetName>(db, &s_key(region, pc), |_| {})?;
+    }
+
+    if let Some(located) = LocatedAddress::from_record(region, record) {
+        for key in located_address_keys(region, record, &located) {
+            edit_located_list(db, &key, |list| list.push(located.clone()))?;
+        }
+    }
+
+    if let (Some(street), Some(hnr)) = (record.street(), record.housenumber()) {
+        let key      = house_number_ranges_key(region, street);
+        let existing = db.get(&key)?.map(|bytes| decompress_cbor_to_list(&bytes)).unwrap_or_default();
+        let merged   = merge_house_number_range(existing, hnr);
+        let bytes    = serde_cbor::to_vec(&CompressedList::from(merged)).map_err(|e| {
+            OsmPbfParseError::HouseNumberRangeSerdeError {
+                msg: format!("Failed to serialize HouseNumberRanges for street '{}': {}", street.name(), e),
+            }
+        })?;
+        db.put(&key, bytes)?;
+    }
+
+    summary.addresses_added += 1;
+    Ok(())
+}
+
+fn link_both_ways(db: &mut StagedWrites<'_>, region: &WorldRegion, link: &AddressLink) -> Result<(), DatabaseConstructionError> {
+    match link {
+        AddressLink::PostalCodeCity(pc, city) => {
+            edit_cbor_set(db, &z2c_key(region, pc), |set| { set.insert(city.clone()); })?;
+            edit_cbor_set(db, &c2z_key(region, city), |set| { set.insert(pc.clone()); })
+        }
+        AddressLink::PostalCodeStreet(pc, street) => {
+            edit_cbor_set(db, &s_key(region, pc), |set| { set.insert(street.clone()); })?;
+            edit_cbor_set(db, &s2z_key(region, street), |set| { set.insert(pc.clone()); })
+        }
+        AddressLink::CityStreet(city, street) => {
+            edit_cbor_set(db, &c2s_key(region, city), |set| { set.insert(street.clone()); })?;
+            edit_cbor_set(db, &s2c_key(region, street), |set| { set.insert(city.clone()); })
+        }
+    }
+}
+
+fn unlink(db: &mut StagedWrites<'_>, region: &WorldRegion, link: &AddressLink) -> Result<(), DatabaseConstructionError> {
+    match link {
+        AddressLink::PostalCodeCity(pc, city) => {
+            edit_cbor_set(db, &z2c_key(region, pc), |set: &mut BTreeSet<CityName>| { set.remove(city); })?;
+            edit_cbor_set(db, &c2z_key(region, city), |set: &mut BTreeSet<PostalCode>| { set.remove(pc); })
+        }
+        AddressLink::PostalCodeStreet(pc, street) => {
+            edit_cbor_set(db, &s_key(region, pc), |set: &mut BTreeSet<StreetName>| { set.remove(street); })?;
+            edit_cbor_set(db, &s2z_key(region, street), |set: &mut BTreeSet<PostalCode>| { set.remove(pc); })
+        }
+        AddressLink::CityStreet(city, street) => {
+            edit_cbor_set(db, &c2s_key(region, city), |set: &mut BTreeSet<StreetName>| { set.remove(street); })?;
+            edit_cbor_set(db, &s2c_key(region, street), |set: &mut BTreeSet<CityName>| { set.remove(city); })
+        }
+    }
+}
+
+/// `GEO:` always, `LOC:` when the record is a complete address.
+fn located_address_keys(region: &WorldRegion, record: &AddressRecord, located: &LocatedAddress) -> Vec<String> {
+    let cell = encode_geohash(located.location(), GEO_INDEX_PRECISION);
+    let mut keys = vec![geo_key(region, &cell)];
+    if let (Some(city), Some(street), Some(pc)) = (record.city(), record.street(), record.postcode()) {
+        keys.push(loc_key(region, pc, city, street));
+    }
+    keys
+}
+
+/// Loads the CBOR set under `key` (empty if missing), applies `edit`, and
+/// writes it back.
+fn edit_cbor_set<T>(
+    db:   &mut StagedWrites<'_>,
+    key:  &str,
+    edit: impl FnOnce(&mut BTreeSet<T>),
+) -> Result<(), DatabaseConstructionError>
+where
+    T: Serialize + DeserializeOwned + Ord + Clone,
+{
+    let existing = db.get(key)?;
+    let unchanged_presence = existing.is_some();
+    let mut set: BTreeSet<T> = existing
+        .map(|bytes| decompress_cbor_to_list(&bytes).into_iter().collect())
+        .unwrap_or_default();
+
+    let before = set.len();
+    edit(&mut set);
+    if unchanged_presence && set.len() == before {
+        return Ok(());
+    }
+    db.put(key, compress_set_to_cbor(&set))
+}
+
+/// Like [`edit_cbor_set`] for the `GEO:`/`LOC:` lists; an emptied list is
+/// deleted.
+fn edit_located_list(
+    db:   &mut StagedWrites<'_>,
+    key:  &str,
+    edit: impl FnOnce(&mut Vec<LocatedAddress>),
+) -> Result<(), DatabaseConstructionError> {
+    let mut list: Vec<LocatedAddress> = db
+        .get(key)?
+        .map(|bytes| decompress_cbor_to_list(&bytes))
+        .unwrap_or_default();
+
+    edit(&mut list);
+    if list.is_empty() {
+        return db.delete(key);
+    }
+
+    let bytes = serde_cbor::to_vec(&CompressedList::from(list)).map_err(|e| {
+        DatabaseConstructionError::LocationSerdeError {
+            msg: format!("Failed to serialize located addresses for '{}': {}", key, e),
+        }
+    })?;
+    db.put(key, bytes)
+}
+
+#[cfg(test)]
+mod test_apply_osm_change {
+    use super::*;
+
+    fn region_md() -> WorldRegion {
+        USRegion::UnitedState(UnitedState::Maryland).into()
+    }
+
+    fn baltimore_record(element: OsmElementRef, street: &str, housenumber: u32) -> AddressRecord {
+        let mut record = AddressRecord::new(
+            CityName::new("Baltimore").unwrap(),
+            StreetName::new_for_region(street, &region_md()).unwrap(),
+            PostalCode::new(Country::USA, "21201").unwrap(),
+        );
+        record.set_housenumber(Some(HouseNumberRange::new(housenumber, housenumber)));
+        record.set_location(Some(GeoPoint::from_degrees(39.31, -76.62).unwrap()));
+        record.set_osm_element(Some(element));
+        record
+    }
+
+    /// Builds a region the way `RegionalRecords::write_to_storage` does.
+    fn build_region(db: &mut Database, records: Vec<AddressRecord>) {
+        let rr = RegionalRecordsBuilder::default()
+            .region(region_md())
+            .records(records)
+            .build()
+            .unwrap();
+        rr.write_to_storage(db).unwrap();
+    }
+
+    fn cities_for_zip(db: &Database, zip: &str) -> Vec<CityName> {
+        let pc = PostalCode::new(Country::USA, zip).unwrap();
+        db.get(z2c_key(&region_md(), &pc)).unwrap().map(|b| decompress_cbor_to_list(&b)).unwrap_or_default()
+    }
+
+    fn streets_for_zip(db: &Database, zip: &str) -> Vec<StreetName> {
+        let pc = PostalCode::new(Country::USA, zip).unwrap();
+        db.get(s_key(&region_md(), &pc)).unwrap().map(|b| decompress_cbor_to_list(&b)).unwrap_or_default()
+    }
+
+    fn parse(xml: &str) -> OsmChange {
+        OsmChange::parse(xml.as_bytes()).unwrap()
+    }
+
+    #[traced_test]
+    fn test_create_adds_links_locations_and_house_numbers() {
+        let (db_arc, _tmp) = create_temp_db::<Database>();
+        let mut db = db_arc.lock().unwrap();
+        build_region(&mut db, vec![baltimore_record(OsmElementRef::Node(1), "North Avenue", 10)]);
+
+        let change = parse(r#"<osmChange><create>
+            <node id="50" lat="39.2900000" lon="-76.6100000">
+              <tag k="addr:city" v="Baltimore"/>
+              <tag k="addr:street" v="Charles Street"/>
+              <tag k="addr:postcode" v="21202"/>
+              <tag k="addr:housenumber" v="300"/>
+            </node>
+        </create></osmChange>"#);
+
+        let summary = db.apply_osm_change_for_region(&region_md(), &change).unwrap();
+        assert_eq!(*summary.addresses_added(), 1);
+        assert_eq!(*summary.links_added(), 3);
+
+        assert!(cities_for_zip(&db, "21202").iter().any(|c| c.name() == "baltimore"));
+        let charles = StreetName::new_for_region("Charles St", &region_md()).unwrap();
+        assert!(streets_for_zip(&db, "21202").contains(&charles));
+
+        let ranges = db.load_house_number_ranges(&region_md(), &charles).unwrap().unwrap();
+        assert!(ranges.iter().any(|r| r.contains(&HouseNumber::new(300, None))));
+
+        let loc = loc_key(
+            &region_md(),
+            &PostalCode::new(Country::USA, "21202").unwrap(),
+            &CityName::new("Baltimore").unwrap(),
+            &charles,
+        );
+        assert!(db.get(&loc).unwrap().is_some());
+        assert!(db.get(osm_element_key(&region_md(), &OsmElementRef::Node(50))).unwrap().is_some());
+    }
+
+    #[traced_test]
+    fn test_delete_keeps_links_still_supported_by_other_elements() {
+        let (db_arc, _tmp) = create_temp_db::<Database>();
+        let mut db = db_arc.lock().unwrap();
+        build_region(&mut db, vec![
+            baltimore_record(OsmElementRef::Node(1), "North Avenue", 10),
+            baltimore_record(OsmElementRef::Node(2), "North Avenue", 12),
+            baltimore_record(OsmElementRef::Node(3), "Charles Street", 300),
+        ]);
+
+        let summary = db.apply_osm_change_for_region(
+            &region_md(),
+            &parse(r#"<osmChange><delete><node id="1"/><node id="3"/></delete></osmChange>"#),
+        ).unwrap();
+        assert_eq!(*summary.addresses_removed(), 2);
+
+        let streets = streets_for_zip(&db, "21201");
+        let north   = StreetName::new_for_region("North Avenue", &region_md()).unwrap();
+        let charles = StreetName::new_for_region("Charles Street", &region_md()).unwrap();
+        assert!(streets.contains(&north), "node 2 still supports North Avenue");
+        assert!(!streets.contains(&charles), "Charles Street lost its only element");
+        assert!(cities_for_zip(&db, "21201").iter().any(|c| c.name() == "baltimore"));
+
+        assert!(db.get(osm_element_key(&region_md(), &OsmElementRef::Node(3))).unwrap().is_none());
+    }
+
+    #[traced_test]
+    fn test_modify_moves_address_to_new_street() {
+        let (db_arc, _tmp) = create_temp_db::<Database>();
+        let mut db = db_arc.lock().unwrap();
+        build_region(&mut db, vec![baltimore_record(OsmElementRef::Node(1), "North Avenue", 10)]);
+
+        let change = parse(r#"<osmChange><modify>
+            <node id="1" lat="39.3100000" lon="-76.6200000">
+              <tag k="addr:city" v="Baltimore"/>
+              <tag k="addr:street" v="Howard Street"/>
+              <tag k="addr:postcode" v="21201"/>
+              <tag k="addr:housenumber" v="10"/>
+            </node>
+        </modify></osmChange>"#);
+        let summary = db.apply_osm_change_for_region(&region_md(), &change).unwrap();
+        assert_eq!((*summary.addresses_removed(), *summary.addresses_added()), (1, 1));
+
+        let streets = streets_for_zip(&db, "21201");
+        assert_eq!(streets, vec![StreetName::new_for_region("Howard St", &region_md()).unwrap()]);
+    }
+
+    #[traced_test]
+    fn test_modify_keeps_boundary_assigned_city() {
+        let (db_arc, _tmp) = create_temp_db::<Database>();
+        let mut db = db_arc.lock().unwrap();
+
+        // the build gave node 1 its city from the Towson boundary
+        let mut record = baltimore_record(OsmElementRef::Node(1), "York Road", 10);
+        record.set_city(Some(CityName::new("Towson").unwrap()));
+        build_region(&mut db, vec![record]);
+        db.write_boundaries_for_region(&region_md(), &[
+            mock_square_boundary(3, BoundaryKind::Administrative { level: 8 }, "Towson", 39.2, -76.7, 39.4, -76.5),
+        ]).unwrap();
+
+        let change = parse(r#"<osmChange><modify>
+            <node id="1" lat="39.3100000" lon="-76.6200000">
+              <tag k="addr:street" v="York Road"/>
+              <tag k="addr:postcode" v="21201"/>
+              <tag k="addr:housenumber" v="12"/>
+            </node>
+        </modify></osmChange>"#);
+        let summary = db.apply_osm_change_for_region(&region_md(), &change).unwrap();
+        assert_eq!((*summary.addresses_removed(), *summary.addresses_added()), (1, 1));
+        assert_eq!((*summary.links_removed(), *summary.links_added()), (0, 0));
+
+        assert_eq!(cities_for_zip(&db, "21201"), vec![CityName::new("Towson").unwrap()]);
+
+        let bytes  = db.get(osm_element_key(&region_md(), &OsmElementRef::Node(1))).unwrap().unwrap();
+        let stored: AddressRecord = serde_cbor::from_slice(&bytes).unwrap();
+        assert_eq!(stored.city().as_ref().unwrap().name(), "towson");
+        assert_eq!(stored.housenumber(), &Some(HouseNumberRange::new(12, 12)));
+    }
+
+    #[traced_test]
+    fn test_unchanged_and_untagged_elements_are_ignored() {
+        let (db_arc, _tmp) = create_temp_db::<Database>();
+        let mut db = db_arc.lock().unwrap();
+        build_region(&mut db, vec![baltimore_record(OsmElementRef::Node(1), "North Avenue", 10)]);
+
+        let change = parse(r#"<osmChange>
+            <create><node id="77" lat="39.0" lon="-76.0"/></create>
+            <delete><way id="99"/></delete>
+        </osmChange>"#);
+        let summary = db.apply_osm_change_for_region(&region_md(), &change).unwrap();
+        assert_eq!(summary, OsmChangeSummary::default());
+    }
+}
@@ -0,0 +1,168 @@
+// ---------------- [ File: src/apply_osm_change_directory.rs ]
+crate::ix!();
+
+/// Applies, in sequence order, every `.osc`/`.osc.gz` file under `dir` that
+/// is newer than the last one applied to `region`, recording each sequence
+/// number as soon as its file is in.
+///
+/// `dir` is laid out like an OSM replication tree (`000/123/456.osc.gz` is
+/// sequence 123456) or holds flat numbered files (`123456.osc`).
+pub fn apply_osm_change_directory<I: StorageInterface>(
+    db:     &mut I,
+    region: &WorldRegion,
+    dir:    impl AsRef<Path>,
+) -> Result<OsmChangeSummary, OsmChangeError> {
+
+    if !db.region_done(region)? {
+        return Err(OsmChangeError::RegionNotBuilt { region: *region });
+    }
+
+    let last_applied = db.last_applied_sequence(region)?;
+    let mut summary  = OsmChangeSummary::default();
+
+    for (sequence, path) in osm_change_sequence_files(dir)? {
+        if last_applied.is_some_and(|last| sequence <= last) {
+            trace!("apply_osm_change_directory: skipping already applied {:?}", path);
+            continue;
+        }
+
+        info!("applying OSM change {} ({:?}) to region {:?}", sequence, path, region);
+        let change  = OsmChange::from_path(&path)?;
+        let applied = db.apply_osm_change_for_region(region, &change)?;
+        db.set_last_applied_sequence(region, sequence)?;
+        summary.record_file(sequence, applied);
+    }
+
+    info!("region {:?}: {:?}", region, summary);
+    Ok(summary)
+}
+
+/// Every change file under `dir`, with its sequence number (the digits of
+/// its path relative to `dir`), sorted by sequence.
+pub fn osm_change_sequence_files(dir: impl AsRef<Path>) -> Result<Vec<(u64, PathBuf)>, OsmChangeError> {
+    let dir = dir.as_ref();
+
+    let mut paths = Vec::new();
+    collect_osm_change_files(dir, &mut paths)?;
+
+    let mut files = Vec::with_capacity(paths.len());
+    for path in paths {
+        let relative = path.strip_prefix(dir).unwrap_or(&path);
+        let digits: String = relative
+            .to_string_lossy()
+            .chars()
+            .filter(char::is_ascii_digit)
+            .collect();
+        let sequence = digits
+            .parse::<u64>()
+            .map_err(|_| OsmChangeError::InvalidSequenceFileName { path: path.clone() })?;
+        files.push((sequence, path));
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+fn collect_osm_change_files(dir: &Path, out: &mut Vec<PathBuf>) -> Result<(), OsmChangeError> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_osm_change_files(&path, out)?;
+            continue;
+        }
+        let name = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+        if name.ends_with(".osc") || name.ends_with(".osc.gz") {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test_apply_osm_change_directory {
+    use super::*;
+
+    fn region_md() -> WorldRegion {
+        USRegion::UnitedState(UnitedState::Maryland).into()
+    }
+
+    fn node_change(action: &str, id: i64, street: &str) -> String {
+        format!(
+            r#"<osmChange><{action}>
+                <node id="{id}" lat="39.3" lon="-76.6">
+                  <tag k="addr:city" v="Baltimore"/>
+                  <tag k="addr:street" v="{street}"/>
+                  <tag k="addr:postcode" v="21201"/>
+                </node>
+            </{action}></osmChange>"#
+        )
+    }
+
+    fn write_file(root: &Path, relative: &str, contents: &str) {
+        let path = root.join(relative);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, contents).unwrap();
+    }
+
+    #[traced_test]
+    fn test_sequence_numbers_from_replication_tree() {
+        let tmp = TempDir::new().unwrap();
+        write_file(tmp.path(), "000/001/002.osc", "");
+        write_file(tmp.path(), "000/000/999.osc.gz", "");
+        write_file(tmp.path(), "000/001/state.txt", "");
+
+        let files = osm_change_sequence_files(tmp.path()).unwrap();
+        let sequences: Vec<u64> = files.iter().map(|(s, _)| *s).collect();
+        assert_eq!(sequences, vec![999, 1002]);
+    }
+
+    #[traced_test]
+    fn test_file_without_digits_is_rejected() {
+        let tmp = TempDir::new().unwrap();
+        write_file(tmp.path(), "latest.osc", "");
+        match osm_change_sequence_files(tmp.path()) {
+            Err(OsmChangeError::InvalidSequenceFileName { .. }) => {}
+            other => panic!("expected InvalidSequenceFileName, got {:?}", other),
+        }
+    }
+
+    #[traced_test]
+    fn test_requires_built_region() {
+        let (db_arc, _tmp) = create_temp_db::<Database>();
+        let mut db = db_arc.lock().unwrap();
+        let diffs = TempDir::new().unwrap();
+
+        match apply_osm_change_directory(&mut *db, &region_md(), diffs.path()) {
+            Err(OsmChangeError::RegionNotBuilt { region }) => assert_eq!(region, region_md()),
+            other => panic!("expected RegionNotBuilt, got {:?}", other),
+        }
+    }
+
+    #[traced_test]
+    fn test_applies_new_files_once_in_order() {
+        let (db_arc, _tmp) = create_temp_db::<Database>();
+        let mut db = db_arc.lock().unwrap();
+        db.mark_region_done(&region_md()).unwrap();
+
+        let diffs = TempDir::new().unwrap();
+        write_file(diffs.path(), "10.osc", &node_change("create", 5, "North Avenue"));
+        write_file(diffs.path(), "11.osc", &node_change("modify", 5, "Charles Street"));
+
+        let summary = apply_osm_change_directory(&mut *db, &region_md(), diffs.path()).unwrap();
+        assert_eq!(*summary.files_applied(), 2);
+        assert_eq!(*summary.last_sequence(), Some(11));
+        assert_eq!(db.last_applied_sequence(&region_md()).unwrap(), Some(11));
+
+        let pc = PostalCode::new(Country::USA, "21201").unwrap();
+        let streets: Vec<StreetName> = decompress_cbor_to_list(&db.get(s_key(&region_md(), &pc)).unwrap().unwrap());
+        assert_eq!(streets, vec![StreetName::new_for_region("Charles St", &region_md()).unwrap()]);
+
+        // A second run finds nothing new
+        let again = apply_osm_change_directory(&mut *db, &region_md(), diffs.path()).unwrap();
+        assert_eq!(*again.files_applied(), 0);
+
+        write_file(diffs.path(), "12.osc", &node_change("delete", 5, "Charles Street"));
+        let third = apply_osm_change_directory(&mut *db, &region_md(), diffs.path()).unwrap();
+        assert_eq!((*third.files_applied(), *third.addresses_removed()), (1, 1));
+    }
+}
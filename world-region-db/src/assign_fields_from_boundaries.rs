@@ -0,0 +1,133 @@
+// ---------------- [ File: src/assign_fields_from_boundaries.rs ]
+crate::ix!();
+
+/// How many records [`assign_fields_from_boundaries`] filled in.
+#[derive(Getters,Clone,Copy,Debug,Default,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct BoundaryAssignmentCounts {
+    cities:       usize,
+    postal_codes: usize,
+}
+
+/// Fills in the city and postal code of located records that lack them,
+/// from the boundaries containing their location. The city comes from the
+/// most local municipal boundary (admin level 8, then 7); tagged values are
+/// never replaced. Each record is only tested against the boundaries of its
+/// [`BoundaryIndex`] cell.
+pub fn assign_fields_from_boundaries(
+    records:    &mut [AddressRecord],
+    boundaries: &[AdminBoundary],
+    country:    Country,
+) -> BoundaryAssignmentCounts {
+    let mut counts = BoundaryAssignmentCounts::default();
+    if boundaries.is_empty() {
+        return counts;
+    }
+
+    let index = BoundaryIndex::new(boundaries);
+
+    for record in records.iter_mut() {
+        let Some(location) = *record.location() else {
+            continue;
+        };
+        if record.city().is_some() && record.postcode().is_some() {
+            continue;
+        }
+        let filled = assign_fields_from_containing_boundaries(record, &index.containing(&location), country);
+        counts.cities       += filled.cities;
+        counts.postal_codes += filled.postal_codes;
+    }
+
+    debug!("assign_fields_from_boundaries: {:?}", counts);
+    counts
+}
+
+/// Fills in the city and postal code `record` lacks from `containing`, the
+/// boundaries around its location; the per-record step of
+/// [`assign_fields_from_boundaries`], also used when applying OSM changes.
+pub fn assign_fields_from_containing_boundaries(
+    record:     &mut AddressRecord,
+    containing: &[&AdminBoundary],
+    country:    Country,
+) -> BoundaryAssignmentCounts {
+    let mut counts = BoundaryAssignmentCounts::default();
+
+    if record.city().is_none() {
+        let city = containing
+            .iter()
+            .filter(|b| b.kind().names_city())
+            .min_by_key(|b| std::cmp::Reverse(*b.kind()))
+            .and_then(|b| CityName::new(b.name()).ok());
+        if let Some(city) = city {
+            record.set_city(Some(city));
+            counts.cities += 1;
+        }
+    }
+
+    if record.postcode().is_none() {
+        let postal_code = containing
+            .iter()
+            .find(|b| *b.kind() == BoundaryKind::PostalCode)
+            .and_then(|b| PostalCode::new(country, b.name()).ok());
+        if let Some(postal_code) = postal_code {
+            record.set_postcode(Some(postal_code));
+            counts.postal_codes += 1;
+        }
+    }
+
+    counts
+}
+
+#[cfg(test)]
+mod assign_fields_from_boundaries_tests {
+    use super::*;
+
+    fn boundaries() -> Vec<AdminBoundary> {
+        vec![
+            mock_square_boundary(1, BoundaryKind::Administrative { level: 6 }, "Baltimore County", 39.0, -77.0, 40.0, -76.0),
+            mock_square_boundary(2, BoundaryKind::Administrative { level: 7 }, "Some Township",    39.0, -77.0, 40.0, -76.0),
+            mock_square_boundary(3, BoundaryKind::Administrative { level: 8 }, "Towson",           39.3, -76.7, 39.5, -76.5),
+            mock_square_boundary(4, BoundaryKind::PostalCode,                  "21204",            39.3, -76.7, 39.5, -76.5),
+        ]
+    }
+
+    fn located(street: &str, city: Option<&str>, lat: f64, lon: f64) -> AddressRecord {
+        AddressRecordBuilder::default()
+            .street(Some(StreetName::new(street).unwrap()))
+            .city(city.map(|c| CityName::new(c).unwrap()))
+            .location(Some(mock_point(lat, lon)))
+            .build()
+            .unwrap()
+    }
+
+    #[traced_test]
+    fn test_assigns_most_local_city_and_postal_code() {
+        let mut records = vec![
+            located("York Road",   None,              39.4, -76.6),
+            located("Main Street", None,              39.1, -76.9),
+            located("Elm Street",  Some("Lutherville"), 39.4, -76.6),
+        ];
+
+        let counts = assign_fields_from_boundaries(&mut records, &boundaries(), Country::USA);
+        assert_eq!(*counts.cities(), 2);
+        assert_eq!(*counts.postal_codes(), 2);
+
+        assert_eq!(records[0].city().as_ref().unwrap().name(), "towson");
+        assert_eq!(records[0].postcode().as_ref().unwrap().code(), "21204");
+        assert_eq!(records[1].city().as_ref().unwrap().name(), "some township");
+        assert!(records[1].postcode().is_none());
+        assert_eq!(records[2].city().as_ref().unwrap().name(), "lutherville");
+    }
+
+    #[traced_test]
+    fn test_unlocated_records_are_left_alone() {
+        let mut records = vec![AddressRecordBuilder::default()
+            .street(Some(StreetName::new("York Road").unwrap()))
+            .build()
+            .unwrap()];
+
+        let counts = assign_fields_from_boundaries(&mut records, &boundaries(), Country::USA);
+        assert_eq!(counts, BoundaryAssignmentCounts::default());
+        assert!(records[0].city().is_none());
+    }
+}
@@ -123,8 +123,7 @@ mod attempt_storing_house_number_aggregator_in_db_tests {
             _world_region: &WorldRegion,
             _aggregator:   HouseNumberAggregator,
         ) -> Result<(), OsmPbfParseError> {
-            Err(OsmPbfParseError::IoError(std::io::Error::new(
-                std::io::ErrorKind::Other,
+            Err(OsmPbfParseError::IoError(std::io::Error::other(
                 "Simulated store error",
             )))
         }
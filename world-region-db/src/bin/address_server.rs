@@ -0,0 +1,12 @@
+// ---------------- [ File: src/bin/address_server.rs ]
+use world_region_db::*;
+
+use tracing_setup::*;
+use structopt::*;
+
+#[tokio::main]
+async fn main() -> Result<(),AddressServiceError> {
+    configure_tracing();
+    let cli = AddressServerCli::from_args();
+    cli.run().await
+}
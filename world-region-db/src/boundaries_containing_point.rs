@@ -0,0 +1,146 @@
+// ---------------- [ File: src/boundaries_containing_point.rs ]
+crate::ix!();
+
+/// The named areas around a point, from the region down to the
+/// municipality. Any level the region's OSM data has no boundary for is
+/// `None`.
+#[derive(Getters,Clone,Debug,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct AdministrativeAreas {
+    region:      WorldRegion,
+    /// admin level 6
+    county:      Option<String>,
+    /// admin level 7
+    township:    Option<String>,
+    /// admin level 8
+    city:        Option<String>,
+    postal_code: Option<String>,
+}
+
+impl AdministrativeAreas {
+
+    /// Picks one name per level out of `boundaries`, all of which contain
+    /// the same point.
+    pub fn from_boundaries(region: &WorldRegion, boundaries: &[AdminBoundary]) -> Self {
+        let name_of = |kind: BoundaryKind| {
+            boundaries.iter().find(|b| *b.kind() == kind).map(|b| b.name().clone())
+        };
+        Self {
+            region:      *region,
+            county:      name_of(BoundaryKind::Administrative { level: 6 }),
+            township:    name_of(BoundaryKind::Administrative { level: 7 }),
+            city:        name_of(BoundaryKind::Administrative { level: 8 }),
+            postal_code: name_of(BoundaryKind::PostalCode),
+        }
+    }
+}
+
+pub trait BoundariesContainingPoint {
+
+    fn boundaries_containing_point(
+        &self,
+        region: &WorldRegion,
+        point:  &GeoPoint,
+    ) -> Vec<AdminBoundary>;
+
+    fn administrative_areas_for_point(
+        &self,
+        region: &WorldRegion,
+        point:  &GeoPoint,
+    ) -> AdministrativeAreas {
+        AdministrativeAreas::from_boundaries(region, &self.boundaries_containing_point(region, point))
+    }
+}
+
+impl<I:StorageInterface> BoundariesContainingPoint for DataAccess<I> {
+
+    /// Every stored boundary of `region` that contains `point`, counties
+    /// first and postal codes last.
+    ///
+    /// Only the boundaries listed under the point's `BNDX:` cell are read
+    /// and decoded.
+    fn boundaries_containing_point(
+        &self,
+        region: &WorldRegion,
+        point:  &GeoPoint,
+    ) -> Vec<AdminBoundary> {
+        match self.db().lock() {
+            Ok(guard) => stored_boundaries_containing_point(&*guard, region, point),
+            Err(_) => {
+                warn!("boundaries_containing_point: could not get DB lock");
+                vec![]
+            }
+        }
+    }
+}
+
+/// Every boundary of `region` stored in `db` that contains `point`,
+/// counties first and postal codes last; see
+/// [`BoundariesContainingPoint::boundaries_containing_point`].
+pub fn stored_boundaries_containing_point<D: DatabaseGet>(
+    db:     &D,
+    region: &WorldRegion,
+    point:  &GeoPoint,
+) -> Vec<AdminBoundary> {
+    let cell_key = boundary_cell_key(region, &encode_geohash(point, BOUNDARY_INDEX_PRECISION));
+    let relation_ids: Vec<i64> = match db.get(&cell_key) {
+        Ok(Some(bytes)) => decompress_cbor_to_list(&bytes),
+        Ok(None)        => return vec![],
+        Err(e)          => {
+            warn!("boundaries_containing_point: could not read {}: {:?}", cell_key, e);
+            return vec![];
+        }
+    };
+
+    let mut found = Vec::new();
+    for relation_id in relation_ids {
+        let key = boundary_key(region, relation_id);
+        match db.get(&key) {
+            Ok(Some(bytes)) => match serde_cbor::from_slice::<AdminBoundary>(&bytes) {
+                Ok(boundary) if boundary.contains(point) => found.push(boundary),
+                Ok(_)        => {}
+                Err(e)       => warn!("boundaries_containing_point: undecodable boundary {}: {}", key, e),
+            },
+            Ok(None) => warn!("boundaries_containing_point: {} lists missing boundary {}", cell_key, key),
+            Err(e)   => warn!("boundaries_containing_point: could not read {}: {:?}", key, e),
+        }
+    }
+
+    found.sort_by_key(|b| *b.kind());
+    found
+}
+
+#[cfg(test)]
+mod test_boundaries_containing_point {
+    use super::*;
+
+    #[traced_test]
+    fn test_lookup_returns_containing_boundaries_by_level() {
+        let (data_access, db, _tmp) = create_data_access::<Database>();
+        {
+            let mut guard = db.lock().unwrap();
+            guard.write_boundaries_for_region(&example_region(), &[
+                mock_square_boundary(3, BoundaryKind::Administrative { level: 8 }, "Towson",           39.3, -76.7, 39.5, -76.5),
+                mock_square_boundary(1, BoundaryKind::Administrative { level: 6 }, "Baltimore County", 39.0, -77.0, 40.0, -76.0),
+                mock_square_boundary(4, BoundaryKind::PostalCode,                  "21204",            39.3, -76.7, 39.5, -76.5),
+                mock_square_boundary(5, BoundaryKind::Administrative { level: 8 }, "Elsewhere",        38.0, -75.0, 38.5, -74.5),
+            ]).unwrap();
+        }
+
+        let found = data_access.boundaries_containing_point(&example_region(), &mock_point(39.4, -76.6));
+        let names: Vec<&str> = found.iter().map(|b| b.name().as_str()).collect();
+        assert_eq!(names, vec!["Baltimore County", "Towson", "21204"]);
+
+        let areas = data_access.administrative_areas_for_point(&example_region(), &mock_point(39.4, -76.6));
+        assert_eq!(areas.county().as_deref(), Some("Baltimore County"));
+        assert_eq!(areas.township(), &None);
+        assert_eq!(areas.city().as_deref(), Some("Towson"));
+        assert_eq!(areas.postal_code().as_deref(), Some("21204"));
+    }
+
+    #[traced_test]
+    fn test_region_without_boundaries_returns_nothing() {
+        let (data_access, _db, _tmp) = create_data_access::<Database>();
+        assert!(data_access.boundaries_containing_point(&example_region(), &mock_point(39.4, -76.6)).is_empty());
+    }
+}
@@ -0,0 +1,57 @@
+// ---------------- [ File: src/boundary_index.rs ]
+crate::ix!();
+
+/// Boundaries grouped by the [`BOUNDARY_INDEX_PRECISION`] geohash cells
+/// their bounding boxes overlap, so a point is only tested against the few
+/// boundaries near it. The in-memory twin of the `BNDX:` keys.
+#[derive(Debug)]
+pub struct BoundaryIndex<'a> {
+    boundaries: &'a [AdminBoundary],
+    /// geohash cell => indices into `boundaries`, ascending
+    cells:      HashMap<String, Vec<usize>>,
+}
+
+impl<'a> BoundaryIndex<'a> {
+
+    pub fn new(boundaries: &'a [AdminBoundary]) -> Self {
+        let mut cells: HashMap<String, Vec<usize>> = HashMap::new();
+        for (idx, boundary) in boundaries.iter().enumerate() {
+            for cell in boundary.index_cells() {
+                cells.entry(cell).or_default().push(idx);
+            }
+        }
+        Self { boundaries, cells }
+    }
+
+    /// Every boundary containing `point`, in the order they were given.
+    pub fn containing(&self, point: &GeoPoint) -> Vec<&'a AdminBoundary> {
+        let cell = encode_geohash(point, BOUNDARY_INDEX_PRECISION);
+        let boundaries = self.boundaries;
+        self.cells
+            .get(&cell)
+            .into_iter()
+            .flatten()
+            .map(|&idx| &boundaries[idx])
+            .filter(|b| b.contains(point))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod boundary_index_tests {
+    use super::*;
+
+    #[traced_test]
+    fn test_containing_keeps_input_order() {
+        let boundaries = vec![
+            mock_square_boundary(1, BoundaryKind::Administrative { level: 8 }, "Towson",           39.3, -76.7, 39.5, -76.5),
+            mock_square_boundary(2, BoundaryKind::Administrative { level: 6 }, "Baltimore County", 39.0, -77.0, 40.0, -76.0),
+            mock_square_boundary(3, BoundaryKind::Administrative { level: 8 }, "Elsewhere",        38.0, -75.0, 38.5, -74.5),
+        ];
+        let index = BoundaryIndex::new(&boundaries);
+
+        let names: Vec<&str> = index.containing(&mock_point(39.4, -76.6)).iter().map(|b| b.name().as_str()).collect();
+        assert_eq!(names, vec!["Towson", "Baltimore County"]);
+        assert!(index.containing(&mock_point(10.0, 10.0)).is_empty());
+    }
+}
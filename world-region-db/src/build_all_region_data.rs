@@ -33,8 +33,8 @@ pub fn build_all_region_data<I: StorageInterface>(
         // 3) Next, gather any additional streets from region_postal_code_streets:
         //    This ensures “zip‐only” or “street+zip” partial addresses also appear.
         {
-            let prefix = format!("S2Z:{}:", region.abbreviation());
-            let zip_st_prefix = format!("S:{}:", region.abbreviation());
+            let prefix = DbKeyFamily::StreetPostalCodes.region_prefix(region);
+            let zip_st_prefix = DbKeyFamily::PostalCodeStreets.region_prefix(region);
             // or we can do a direct iteration over region_postal_code_streets if you have a direct query function
             // e.g. gather_streets_via_zip(db, region).
             let more_streets = load_extra_streets_from_zip_prefix(db, region);
@@ -0,0 +1,321 @@
+// ---------------- [ File: src/build_regions_in_parallel.rs ]
+crate::ix!();
+
+use std::future::Future;
+use std::time::Duration;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+#[derive(Builder,Getters,Clone,Debug)]
+#[getset(get="pub")]
+#[builder(setter(into))]
+pub struct ParallelBuildOptions {
+    /// Regions downloaded and parsed at the same time; `None` means one per
+    /// available core. Each parse holds its whole region in memory, so this
+    /// is also the memory bound: at most this many parsed regions, plus the
+    /// one being written, exist at once.
+    #[builder(default)]
+    parallel_parses:   Option<usize>,
+    /// How often the progress of unfinished regions is logged.
+    #[builder(default = "Duration::from_secs(30)")]
+    progress_interval: Duration,
+}
+
+impl ParallelBuildOptions {
+
+    fn parse_threads(&self) -> usize {
+        self.parallel_parses
+            .unwrap_or_else(|| std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1))
+            .max(1)
+    }
+}
+
+/// What [`build_regions_in_parallel`] did with each region it was given.
+#[derive(Getters,Debug,Default)]
+#[getset(get="pub")]
+pub struct ParallelBuildSummary {
+    built:   Vec<WorldRegion>,
+    /// Already done before the build started.
+    skipped: Vec<WorldRegion>,
+    /// Regions whose download or parse failed, with the reason. Nothing of
+    /// theirs was written, so the next run retries them.
+    failed:  Vec<(WorldRegion,String)>,
+}
+
+/// Builds every region of `regions` that is not done yet. Each region is
+/// downloaded (if its PBF file is missing) and then parsed straight away,
+/// several regions at once, while a single writer stores each parsed
+/// region in `db` as soon as it is ready.
+///
+/// A region that fails to download or parse is reported in the summary and
+/// the others carry on. A write error stops the build; the region it
+/// stopped in is cleaned up and rebuilt by the next run (see
+/// [`RegionBuildMarker`]).
+pub async fn build_regions_in_parallel<I:StorageInterface + Clone + 'static>(
+    regions:    &[WorldRegion],
+    target_dir: impl AsRef<Path> + Send + Sync,
+    db:         &mut I,
+    options:    &ParallelBuildOptions,
+) -> Result<ParallelBuildSummary, WorldCityAndStreetDbBuilderError> {
+    let mut summary = ParallelBuildSummary::default();
+
+    let mut pending = Vec::new();
+    for region in regions {
+        if db.region_done(region)? {
+            info!("Region {:?} is already built. Skipping.", region);
+            summary.skipped.push(*region);
+            continue;
+        }
+        pending.push(*region);
+    }
+
+    let target_dir = target_dir.as_ref().to_path_buf();
+    let fetch = move |region: WorldRegion| {
+        let target_dir = target_dir.clone();
+        async move { obtain_pbf_file_for_region(&region, &target_dir).await }
+    };
+    fetch_parse_and_write_regions(&pending, fetch, db, options, &mut summary).await?;
+
+    info!(
+        "build_regions_in_parallel: built {}, skipped {}, failed {}",
+        summary.built.len(),
+        summary.skipped.len(),
+        summary.failed.len()
+    );
+    Ok(summary)
+}
+
+/// A parsed region (or why it could not be had), together with the parse
+/// slot it holds until the writer takes it.
+type ParsedRegion = (usize, Result<RegionalRecords,String>, OwnedSemaphorePermit);
+
+/// Runs one task per region: `fetch` gives its PBF file, which is parsed on
+/// the blocking pool as soon as it is there. Each result is written from
+/// this task, on the blocking pool too, in the order the parses finish.
+///
+/// A region takes one of the parse slots before its download and gives it
+/// back when the writer takes its result, which keeps the number of parsed
+/// regions in memory bounded.
+pub async fn fetch_parse_and_write_regions<I,F,Fut>(
+    regions: &[WorldRegion],
+    fetch:   F,
+    db:      &mut I,
+    options: &ParallelBuildOptions,
+    summary: &mut ParallelBuildSummary,
+) -> Result<(), WorldCityAndStreetDbBuilderError>
+where
+    I:   StorageInterface + Clone + 'static,
+    F:   Fn(WorldRegion) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<PathBuf,WorldCityAndStreetDbBuilderError>> + Send + 'static,
+{
+    if regions.is_empty() {
+        return Ok(());
+    }
+    let threads  = options.parse_threads().min(regions.len());
+    let progress: Arc<Vec<RegionBuildProgress>> = Arc::new(regions.iter().map(|_| RegionBuildProgress::default()).collect());
+    let slots    = Arc::new(Semaphore::new(threads));
+    let fetch    = Arc::new(fetch);
+
+    info!("fetch_parse_and_write_regions: {} regions, {} at a time", regions.len(), threads);
+
+    let (tx, mut rx) = tokio::sync::mpsc::channel::<ParsedRegion>(threads);
+    let mut tasks = Vec::with_capacity(regions.len());
+    for (idx, region) in regions.iter().copied().enumerate() {
+        let (tx, progress, slots, fetch) = (tx.clone(), progress.clone(), slots.clone(), fetch.clone());
+        tasks.push(tokio::spawn(async move {
+            let Ok(slot) = slots.acquire_owned().await else {
+                return;
+            };
+            progress[idx].set_stage(RegionBuildStage::Downloading);
+            let parsed = match fetch(region).await {
+                Ok(pbf_file) => {
+                    progress[idx].set_stage(RegionBuildStage::Parsing);
+                    let progress = progress.clone();
+                    tokio::task::spawn_blocking(move || {
+                        RegionalRecords::from_osm_pbf_file_with_progress(region, &pbf_file, &progress[idx])
+                            .map_err(|e| {
+                                error!("Failed to parse .pbf for region {:?}: {:?}", region, e);
+                                format!("{:?}", e)
+                            })
+                    })
+                    .await
+                    .unwrap_or_else(|e| Err(format!("parse task failed: {}", e)))
+                }
+                Err(e) => {
+                    error!("Could not obtain PBF file for region {:?}: {:?}", region, e);
+                    Err(format!("{:?}", e))
+                }
+            };
+            progress[idx].set_stage(RegionBuildStage::WaitingForWriter);
+            // An error means the writer gave up; the region is left alone.
+            let _ = tx.send((idx, parsed, slot)).await;
+        }));
+    }
+    drop(tx);
+
+    let mut outcome = Ok(());
+    loop {
+        let (idx, parsed, slot) = match tokio::time::timeout(*options.progress_interval(), rx.recv()).await {
+            Ok(Some(received)) => received,
+            Ok(None)           => break,
+            Err(_elapsed)      => {
+                log_unfinished_regions(regions, &progress);
+                continue;
+            }
+        };
+        // The next region may start downloading while this one is written.
+        drop(slot);
+        let region = regions[idx];
+
+        let records = match parsed {
+            Ok(records) => records,
+            Err(e) => {
+                progress[idx].set_stage(RegionBuildStage::Failed);
+                summary.failed.push((region, e));
+                continue;
+            }
+        };
+
+        progress[idx].set_stage(RegionBuildStage::Writing);
+        let mut writer = db.clone();
+        let written = tokio::task::spawn_blocking(move || records.write_to_storage(&mut writer))
+            .await
+            .map_err(|e| WorldCityAndStreetDbBuilderError::BackgroundTaskFailed { msg: e.to_string() })
+            .and_then(|result| result.map_err(WorldCityAndStreetDbBuilderError::from));
+        if let Err(e) = written {
+            error!("Could not store region {:?} data into DB: {:?}", region, e);
+            progress[idx].set_stage(RegionBuildStage::Failed);
+            outcome = Err(e);
+            break;
+        }
+        progress[idx].set_stage(RegionBuildStage::Done);
+        info!("Region {:?} built: {}", region, progress[idx].snapshot());
+        summary.built.push(region);
+    }
+
+    // Stops regions that have not started yet and unblocks any task still
+    // waiting to hand over a region.
+    slots.close();
+    drop(rx);
+    for task in tasks {
+        let _ = task.await;
+    }
+    outcome
+}
+
+fn log_unfinished_regions(regions: &[WorldRegion], progress: &[RegionBuildProgress]) {
+    for (region, progress) in regions.iter().zip(progress) {
+        let snapshot = progress.snapshot();
+        if !matches!(snapshot.stage(), RegionBuildStage::Done | RegionBuildStage::Failed) {
+            info!("build progress {:?}: {}", region, snapshot);
+        }
+    }
+}
+
+#[cfg(test)]
+mod build_regions_in_parallel_tests {
+    use super::*;
+
+    fn md() -> WorldRegion {
+        USRegion::UnitedState(UnitedState::Maryland).into()
+    }
+
+    fn va() -> WorldRegion {
+        USRegion::UnitedState(UnitedState::Virginia).into()
+    }
+
+    /// Hands out the already written PBF file of each region.
+    fn local_files(
+        files: Vec<(WorldRegion, PathBuf)>,
+    ) -> impl Fn(WorldRegion) -> std::future::Ready<Result<PathBuf,WorldCityAndStreetDbBuilderError>> + Send + Sync + 'static {
+        move |region| {
+            let found = files.iter().find(|(r, _)| *r == region).map(|(_, path)| path.clone());
+            std::future::ready(found.ok_or(WorldCityAndStreetDbBuilderError::SimulatedDownloadFailure))
+        }
+    }
+
+    fn options(parallel_parses: usize) -> ParallelBuildOptions {
+        ParallelBuildOptionsBuilder::default()
+            .parallel_parses(Some(parallel_parses))
+            .progress_interval(Duration::from_millis(10))
+            .build()
+            .unwrap()
+    }
+
+    #[traced_test]
+    async fn test_parses_regions_concurrently_and_writes_each() {
+        let pbf_dir = TempDir::new().unwrap();
+        let md_pbf  = pbf_dir.path().join("maryland-latest.osm.pbf");
+        let va_pbf  = pbf_dir.path().join("virginia-latest.osm.pbf");
+        create_tiny_osm_pbf(&md_pbf).await.unwrap();
+        create_small_osm_pbf_file(
+            &va_pbf,
+            (-78_000_000_000, -77_000_000_000, 39_000_000_000, 38_000_000_000),
+            "Arlington",
+            "Wilson Blvd",
+            "22201",
+            None,
+            38.89,
+            -77.08,
+            2001,
+        ).await.unwrap();
+
+        let (db_arc, _tmp) = create_temp_db::<Database>();
+        let mut db = db_arc.lock().unwrap().clone();
+
+        let files       = local_files(vec![(md(), md_pbf), (va(), va_pbf)]);
+        let mut summary = ParallelBuildSummary::default();
+        fetch_parse_and_write_regions(&[md(), va()], files, &mut db, &options(2), &mut summary).await.unwrap();
+
+        let mut built: Vec<String> = summary.built().iter().map(|r| r.abbreviation().to_string()).collect();
+        built.sort();
+        assert_eq!(built, vec!["MD".to_string(), "VA".to_string()]);
+        assert!(summary.failed().is_empty());
+
+        assert!(db.region_done(&md()).unwrap());
+        assert!(db.region_done(&va()).unwrap());
+        assert!(db.get(c2s_key(&va(), &CityName::new("Arlington").unwrap())).unwrap().is_some());
+    }
+
+    #[traced_test]
+    async fn test_failed_parse_is_reported_and_others_still_built() {
+        let pbf_dir = TempDir::new().unwrap();
+        let md_pbf  = pbf_dir.path().join("maryland-latest.osm.pbf");
+        let va_pbf  = pbf_dir.path().join("virginia-latest.osm.pbf");
+        create_tiny_osm_pbf(&md_pbf).await.unwrap();
+        std::fs::write(&va_pbf, b"not a real pbf file").unwrap();
+
+        let (db_arc, _tmp) = create_temp_db::<Database>();
+        let mut db = db_arc.lock().unwrap().clone();
+
+        let files       = local_files(vec![(md(), md_pbf), (va(), va_pbf)]);
+        let mut summary = ParallelBuildSummary::default();
+        fetch_parse_and_write_regions(&[md(), va()], files, &mut db, &options(1), &mut summary).await.unwrap();
+
+        assert_eq!(summary.built(), &vec![md()]);
+        assert_eq!(summary.failed().len(), 1);
+        assert_eq!(summary.failed()[0].0, va());
+
+        assert!(db.region_done(&md()).unwrap());
+        assert!(!db.region_done(&va()).unwrap());
+        assert!(!db.region_build_interrupted(&va()).unwrap());
+    }
+
+    #[traced_test]
+    async fn test_failed_download_is_reported_and_others_still_built() {
+        let pbf_dir = TempDir::new().unwrap();
+        let md_pbf  = pbf_dir.path().join("maryland-latest.osm.pbf");
+        create_tiny_osm_pbf(&md_pbf).await.unwrap();
+
+        let (db_arc, _tmp) = create_temp_db::<Database>();
+        let mut db = db_arc.lock().unwrap().clone();
+
+        let files       = local_files(vec![(md(), md_pbf)]);
+        let mut summary = ParallelBuildSummary::default();
+        fetch_parse_and_write_regions(&[va(), md()], files, &mut db, &options(2), &mut summary).await.unwrap();
+
+        assert_eq!(summary.built(), &vec![md()]);
+        assert_eq!(summary.failed().len(), 1);
+        assert_eq!(summary.failed()[0].0, va());
+        assert!(!db.region_done(&va()).unwrap());
+    }
+}
@@ -10,7 +10,7 @@ pub fn build_world_address_if_possible(
     let (city_opt, street_opt, postcode_opt) = (record.city(), record.street(), record.postcode());
 
     if let (Some(city), Some(street), Some(postcode)) = (city_opt, street_opt, postcode_opt) {
-        match build_world_address(region, &city, &street, &postcode) {
+        match build_world_address(region, city, street, postcode) {
             Ok(addr) => {
                 debug!(
                     "build_world_address_if_possible: built WorldAddress => {:?}",
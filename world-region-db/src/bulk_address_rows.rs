@@ -0,0 +1,291 @@
+// ---------------- [ File: src/bulk_address_rows.rs ]
+crate::ix!();
+
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum BulkAddressFormat {
+    Csv,
+    Jsonl,
+}
+
+impl std::str::FromStr for BulkAddressFormat {
+    type Err = BulkValidationError;
+
+    fn from_str(s: &str) -> Result<Self,Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "csv"              => Ok(BulkAddressFormat::Csv),
+            "jsonl" | "ndjson" => Ok(BulkAddressFormat::Jsonl),
+            other              => Err(BulkValidationError::UnknownFormat { format: other.to_string() }),
+        }
+    }
+}
+
+impl BulkAddressFormat {
+
+    /// Guesses the format from the file extension.
+    pub fn from_path(path: &Path) -> Result<Self,BulkValidationError> {
+        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        ext.parse()
+    }
+}
+
+/// One input row, with its fields in input order. CSV cells are strings;
+/// JSONL values keep their JSON type so they are written back unchanged.
+#[derive(Getters,Debug,Clone,PartialEq)]
+#[getset(get="pub")]
+pub struct BulkAddressRow {
+    /// 1-based data row (CSV) or line (JSONL) number, for error reports.
+    line:   usize,
+    fields: Vec<(String, serde_json::Value)>,
+}
+
+impl BulkAddressRow {
+
+    pub fn new(line: usize, fields: Vec<(String, serde_json::Value)>) -> Self {
+        Self { line, fields }
+    }
+
+    /// The value in `column` as text; `None` if absent, null or blank.
+    pub fn get(&self, column: &str) -> Option<String> {
+        let value = self.fields.iter().find(|(name, _)| name == column).map(|(_, v)| v)?;
+        let text = match value {
+            serde_json::Value::Null      => return None,
+            serde_json::Value::String(s) => s.trim().to_string(),
+            other                        => other.to_string(),
+        };
+        (!text.is_empty()).then_some(text)
+    }
+
+    /// The value in `column` exactly as it was read, for writing back;
+    /// empty if absent or null.
+    pub fn raw(&self, column: &str) -> String {
+        match self.fields.iter().find(|(name, _)| name == column).map(|(_, v)| v) {
+            None | Some(serde_json::Value::Null) => String::new(),
+            Some(serde_json::Value::String(s))   => s.clone(),
+            Some(other)                          => other.to_string(),
+        }
+    }
+}
+
+/// A lazily read sequence of input rows.
+pub type BulkAddressRows = Box<dyn Iterator<Item = Result<BulkAddressRow,BulkValidationError>>>;
+
+/// Opens `path` and reads its rows one at a time, so a file of any size
+/// can be validated in bounded memory.
+pub fn bulk_address_rows(
+    path:   &Path,
+    format: BulkAddressFormat,
+) -> Result<BulkAddressRows,BulkValidationError> {
+    trace!("bulk_address_rows: {:?} as {:?}", path, format);
+    match format {
+        BulkAddressFormat::Csv   => csv_rows(path),
+        BulkAddressFormat::Jsonl => jsonl_rows(path),
+    }
+}
+
+/// Every row of `path`, read at once.
+pub fn read_bulk_address_rows(
+    path:   &Path,
+    format: BulkAddressFormat,
+) -> Result<Vec<BulkAddressRow>,BulkValidationError> {
+    bulk_address_rows(path, format)?.collect()
+}
+
+fn csv_rows(path: &Path) -> Result<BulkAddressRows,BulkValidationError> {
+    let mut reader  = csv::Reader::from_path(path)?;
+    let headers     = reader.headers()?.clone();
+
+    Ok(Box::new(reader.into_records().enumerate().map(move |(idx, record)| {
+        let record = record?;
+        let fields = headers
+            .iter()
+            .zip(record.iter())
+            .map(|(h, v)| (h.to_string(), serde_json::Value::String(v.to_string())))
+            .collect();
+        Ok(BulkAddressRow::new(idx + 1, fields))
+    })))
+}
+
+fn jsonl_rows(path: &Path) -> Result<BulkAddressRows,BulkValidationError> {
+    let reader = std::io::BufReader::new(std::fs::File::open(path)?);
+
+    Ok(Box::new(std::io::BufRead::lines(reader).enumerate().filter_map(|(idx, line)| {
+        let line = match line {
+            Ok(line) => line,
+            Err(e)   => return Some(Err(e.into())),
+        };
+        if line.trim().is_empty() {
+            return None;
+        }
+        let row = serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(&line)
+            .map(|object| BulkAddressRow::new(idx + 1, object.into_iter().collect()))
+            .map_err(|e| BulkValidationError::InvalidJsonLine { line: idx + 1, msg: e.to_string() });
+        Some(row)
+    })))
+}
+
+/// Writes every row with its outcome appended as the `status`,
+/// `correction_field`, `correction` and `detail` columns (CSV) or keys
+/// (JSONL).
+pub fn write_bulk_validation_report(
+    path:     &Path,
+    format:   BulkAddressFormat,
+    rows:     &[BulkAddressRow],
+    outcomes: &[BulkRowOutcome],
+) -> Result<(),BulkValidationError> {
+    trace!("write_bulk_validation_report: {} rows to {:?}", rows.len(), path);
+    let mut report = BulkReportWriter::create(path, format)?;
+    for (row, outcome) in rows.iter().zip(outcomes) {
+        report.write_row(row, outcome)?;
+    }
+    report.finish()
+}
+
+/// Writes the report of [`write_bulk_validation_report`] one row at a time.
+/// Input cells are written back as they were read.
+pub struct BulkReportWriter {
+    sink: BulkReportSink,
+}
+
+enum BulkReportSink {
+    /// The input header comes from the first row written.
+    Csv {
+        writer:        Box<csv::Writer<std::fs::File>>,
+        input_headers: Option<Vec<String>>,
+    },
+    Jsonl {
+        out: std::io::BufWriter<std::fs::File>,
+    },
+}
+
+const REPORT_HEADERS: [&str; 4] = ["status", "correction_field", "correction", "detail"];
+
+impl BulkReportWriter {
+
+    pub fn create(path: &Path, format: BulkAddressFormat) -> Result<Self,BulkValidationError> {
+        let sink = match format {
+            BulkAddressFormat::Csv => BulkReportSink::Csv {
+                writer:        Box::new(csv::Writer::from_path(path)?),
+                input_headers: None,
+            },
+            BulkAddressFormat::Jsonl => BulkReportSink::Jsonl {
+                out: std::io::BufWriter::new(std::fs::File::create(path)?),
+            },
+        };
+        Ok(Self { sink })
+    }
+
+    pub fn write_row(&mut self, row: &BulkAddressRow, outcome: &BulkRowOutcome) -> Result<(),BulkValidationError> {
+        match &mut self.sink {
+            BulkReportSink::Csv { writer, input_headers } => {
+                if input_headers.is_none() {
+                    let headers: Vec<String> = row.fields().iter().map(|(name, _)| name.clone()).collect();
+                    writer.write_record(headers.iter().map(String::as_str).chain(REPORT_HEADERS))?;
+                    *input_headers = Some(headers);
+                }
+                let headers = input_headers.as_ref().expect("set by the first row");
+                let cells = headers
+                    .iter()
+                    .map(|h| row.raw(h))
+                    .chain(outcome_columns(outcome).into_iter().map(|(_, v)| v));
+                writer.write_record(cells)?;
+            }
+            BulkReportSink::Jsonl { out } => {
+                let mut object: serde_json::Map<String, serde_json::Value> = row.fields().iter().cloned().collect();
+                for (name, value) in outcome_columns(outcome) {
+                    let value = if value.is_empty() {
+                        serde_json::Value::Null
+                    } else {
+                        serde_json::Value::String(value)
+                    };
+                    object.insert(name.to_string(), value);
+                }
+                serde_json::to_writer(&mut *out, &object)
+                    .map_err(|e| BulkValidationError::InvalidJsonLine { line: *row.line(), msg: e.to_string() })?;
+                out.write_all(b"\n")?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Flushes the report; a CSV report with no rows still gets its header.
+    pub fn finish(self) -> Result<(),BulkValidationError> {
+        match self.sink {
+            BulkReportSink::Csv { mut writer, input_headers } => {
+                if input_headers.is_none() {
+                    writer.write_record(REPORT_HEADERS)?;
+                }
+                writer.flush()?;
+            }
+            BulkReportSink::Jsonl { mut out } => out.flush()?,
+        }
+        Ok(())
+    }
+}
+
+fn outcome_columns(outcome: &BulkRowOutcome) -> [(&'static str, String); 4] {
+    [
+        ("status",           outcome.status().as_str().to_string()),
+        ("correction_field", outcome.correction_field().as_ref().map(|f| f.as_str().to_string()).unwrap_or_default()),
+        ("correction",       outcome.correction().clone().unwrap_or_default()),
+        ("detail",           outcome.detail().clone().unwrap_or_default()),
+    ]
+}
+
+#[cfg(test)]
+mod bulk_address_rows_tests {
+    use super::*;
+
+    #[traced_test]
+    fn test_format_from_path() {
+        assert_eq!(BulkAddressFormat::from_path(Path::new("a.csv")).unwrap(), BulkAddressFormat::Csv);
+        assert_eq!(BulkAddressFormat::from_path(Path::new("a.JSONL")).unwrap(), BulkAddressFormat::Jsonl);
+        assert!(BulkAddressFormat::from_path(Path::new("a.txt")).is_err());
+    }
+
+    #[traced_test]
+    fn test_read_csv_and_jsonl_rows() {
+        let tmp = TempDir::new().unwrap();
+
+        let csv_path = tmp.path().join("in.csv");
+        std::fs::write(&csv_path, "zip,city\n21201,Baltimore\n20814, \n").unwrap();
+        let rows = read_bulk_address_rows(&csv_path, BulkAddressFormat::Csv).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].get("zip").as_deref(), Some("21201"));
+        assert_eq!(rows[1].get("city"), None);
+
+        let jsonl_path = tmp.path().join("in.jsonl");
+        std::fs::write(&jsonl_path, "{\"zip\":\"21201\",\"house_number\":12}\n\n{\"zip\":null}\n").unwrap();
+        let rows = read_bulk_address_rows(&jsonl_path, BulkAddressFormat::Jsonl).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].get("house_number").as_deref(), Some("12"));
+        assert_eq!(*rows[1].line(), 3);
+        assert_eq!(rows[1].get("zip"), None);
+    }
+
+    #[traced_test]
+    fn test_csv_report_keeps_raw_cells() {
+        let tmp    = TempDir::new().unwrap();
+        let input  = tmp.path().join("in.csv");
+        let output = tmp.path().join("out.csv");
+        std::fs::write(&input, "zip,city\n 21201 ,  \n").unwrap();
+
+        let rows    = read_bulk_address_rows(&input, BulkAddressFormat::Csv).unwrap();
+        let outcome = BulkRowOutcomeBuilder::default().status(BulkRowStatus::Valid).build().unwrap();
+        write_bulk_validation_report(&output, BulkAddressFormat::Csv, &rows, &[outcome]).unwrap();
+
+        let report = std::fs::read_to_string(&output).unwrap();
+        assert_eq!(report, "zip,city,status,correction_field,correction,detail\n 21201 ,  ,valid,,,\n");
+    }
+
+    #[traced_test]
+    fn test_invalid_jsonl_reports_line() {
+        let tmp  = TempDir::new().unwrap();
+        let path = tmp.path().join("in.jsonl");
+        std::fs::write(&path, "{\"zip\":\"21201\"}\nnot json\n").unwrap();
+
+        match read_bulk_address_rows(&path, BulkAddressFormat::Jsonl) {
+            Err(BulkValidationError::InvalidJsonLine { line, .. }) => assert_eq!(line, 2),
+            other => panic!("expected InvalidJsonLine, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+// ---------------- [ File: src/candidate_coordinates_for_address.rs ]
+crate::ix!();
+
+pub trait CandidateCoordinatesForAddress {
+
+    fn candidate_coordinates_for_address(
+        &self,
+        address:      &WorldAddress,
+        house_number: Option<&HouseNumber>,
+    ) -> Option<Vec<LocatedAddress>>;
+}
+
+impl<I:StorageInterface> CandidateCoordinatesForAddress for DataAccess<I> {
+
+    /// Forward geocoding: every located point we know for this address.
+    ///
+    /// When `house_number` is given and some points carry a matching
+    /// `addr:housenumber`, only those are returned; otherwise all points on
+    /// the street (within this postal code and city) are candidates.
+    fn candidate_coordinates_for_address(
+        &self,
+        address:      &WorldAddress,
+        house_number: Option<&HouseNumber>,
+    ) -> Option<Vec<LocatedAddress>> {
+        let key = loc_key(address.region(), address.postal_code(), address.city(), address.street());
+
+        let bytes = match self.db().lock() {
+            Ok(db_guard) => match db_guard.get(&key) {
+                Ok(opt) => opt?,
+                Err(e) => {
+                    warn!("DB get error for key {}: {}", key, e);
+                    return None;
+                }
+            },
+            Err(_) => {
+                warn!("Could not get DB lock for key: {}", key);
+                return None;
+            }
+        };
+
+        let candidates: Vec<LocatedAddress> = decompress_cbor_to_list(&bytes);
+        if candidates.is_empty() {
+            return None;
+        }
+
+        if let Some(house_number) = house_number {
+            let exact: Vec<LocatedAddress> = candidates
+                .iter()
+                .filter(|c| c.covers_house_number(house_number))
+                .cloned()
+                .collect();
+            if !exact.is_empty() {
+                return Some(exact);
+            }
+        }
+        Some(candidates)
+    }
+}
+
+#[cfg(test)]
+mod test_candidate_coordinates_for_address {
+    use super::*;
+
+    fn region_md() -> WorldRegion {
+        USRegion::UnitedState(UnitedState::Maryland).into()
+    }
+
+    fn north_avenue(house_numbers: (u32, u32), lat: f64, lon: f64) -> AddressRecord {
+        let mut record = AddressRecord::new(
+            CityName::new("Baltimore").unwrap(),
+            StreetName::new("North Avenue").unwrap(),
+            PostalCode::new(Country::USA, "21201").unwrap(),
+        );
+        record.set_location(Some(GeoPoint::from_degrees(lat, lon).unwrap()));
+        record.set_housenumber(Some(HouseNumberRange::new(house_numbers.0, house_numbers.1)));
+        record
+    }
+
+    fn address() -> WorldAddress {
+        WorldAddressBuilder::default()
+            .region(region_md())
+            .postal_code(PostalCode::new(Country::USA, "21201").unwrap())
+            .city(CityName::new("Baltimore").unwrap())
+            .street(StreetName::new("North Avenue").unwrap())
+            .build()
+            .unwrap()
+    }
+
+    #[traced_test]
+    fn test_unknown_address_returns_none() {
+        let (data_access, _db, _tmp) = create_data_access::<Database>();
+        assert!(data_access.candidate_coordinates_for_address(&address(), None).is_none());
+    }
+
+    #[traced_test]
+    fn test_house_number_narrows_candidates() {
+        let (data_access, db, _tmp) = create_data_access::<Database>();
+        {
+            let mut guard = db.lock().unwrap();
+            guard.write_address_locations_for_region(&region_md(), &[
+                north_avenue((100, 110), 39.3110, -76.6190),
+                north_avenue((200, 210), 39.3115, -76.6210),
+            ]).unwrap();
+        }
+
+        let all = data_access.candidate_coordinates_for_address(&address(), None).unwrap();
+        assert_eq!(all.len(), 2);
+
+        let exact = data_access.candidate_coordinates_for_address(&address(), Some(&HouseNumber::from(205))).unwrap();
+        assert_eq!(exact.len(), 1);
+        assert!(exact[0].covers_house_number(&HouseNumber::from(205)));
+
+        // No matching range => fall back to every point on the street
+        let fallback = data_access.candidate_coordinates_for_address(&address(), Some(&HouseNumber::from(999))).unwrap();
+        assert_eq!(fallback.len(), 2);
+    }
+}
@@ -0,0 +1,237 @@
+// ---------------- [ File: src/canonicalize_street_name.rs ]
+crate::ix!();
+
+/// `(canonical, variants)`: every variant, and the canonical form itself,
+/// canonicalizes to `canonical`.
+type StreetTypeTable = &'static [(&'static str, &'static [&'static str])];
+
+/// USPS Publication 28 street suffixes (the common ones).
+const USPS_STREET_SUFFIXES: StreetTypeTable = &[
+    ("aly",  &["alley", "allee", "ally"]),
+    ("ave",  &["avenue", "av", "aven", "avenu", "avn", "avnue"]),
+    ("blvd", &["boulevard", "boul", "boulv"]),
+    ("cir",  &["circle", "circ", "circl", "crcl", "crcle"]),
+    ("ct",   &["court"]),
+    ("cv",   &["cove"]),
+    ("dr",   &["drive", "driv", "drv"]),
+    ("expy", &["expressway", "exp", "expr", "express", "expw"]),
+    ("fwy",  &["freeway", "freewy", "frway", "frwy"]),
+    ("hts",  &["heights", "ht"]),
+    ("hwy",  &["highway", "highwy", "hiway", "hiwy", "hway"]),
+    ("ln",   &["lane"]),
+    ("pkwy", &["parkway", "parkwy", "pkway", "pky"]),
+    ("pl",   &["place"]),
+    ("plz",  &["plaza", "plza"]),
+    ("pt",   &["point"]),
+    ("rd",   &["road"]),
+    ("sq",   &["square", "sqr", "sqre", "squ"]),
+    ("st",   &["street", "strt", "str"]),
+    ("ter",  &["terrace", "terr"]),
+    ("tpke", &["turnpike", "trnpk", "turnpk"]),
+    ("trl",  &["trail", "trails", "trls"]),
+    ("way",  &["wy"]),
+    ("xing", &["crossing", "crssng"]),
+];
+
+/// German street types, usually written as the tail of a compound word.
+const GERMAN_STREET_TYPES: StreetTypeTable = &[
+    ("str", &["strasse"]),
+    ("pl",  &["platz"]),
+];
+
+/// Dutch street types, usually written as the tail of a compound word.
+const DUTCH_STREET_TYPES: StreetTypeTable = &[
+    ("str", &["straat"]),
+    ("ln",  &["laan"]),
+    ("pln", &["plein"]),
+    ("gr",  &["gracht"]),
+];
+
+/// French street types, written before the name.
+const FRENCH_STREET_TYPES: StreetTypeTable = &[
+    ("all", &["allee"]),
+    ("av",  &["avenue", "ave"]),
+    ("bd",  &["boulevard", "boul", "bld", "blvd"]),
+    ("ch",  &["chemin", "chem"]),
+    ("crs", &["cours"]),
+    ("fg",  &["faubourg", "fbg"]),
+    ("imp", &["impasse"]),
+    ("pl",  &["place"]),
+    ("r",   &["rue"]),
+    ("rte", &["route"]),
+    ("sq",  &["square"]),
+];
+
+/// French saints, which may appear anywhere in the name.
+const FRENCH_SAINTS: StreetTypeTable = &[
+    ("st",  &["saint"]),
+    ("ste", &["sainte"]),
+];
+
+/// Spanish street types, written before the name.
+const SPANISH_STREET_TYPES: StreetTypeTable = &[
+    ("av",   &["avenida", "avda", "avd"]),
+    ("c",    &["calle", "cl"]),
+    ("cno",  &["camino"]),
+    ("ctra", &["carretera", "crta"]),
+    ("pl",   &["plaza", "pza", "plz"]),
+    ("po",   &["paseo", "pso"]),
+    ("trav", &["travesia"]),
+];
+
+/// The form used for keys and comparisons: normalized (see [`normalize`]),
+/// diacritic-folded, and with the street types and directionals of
+/// `convention` reduced to one abbreviation each.
+///
+/// With [`StreetNameConvention::Usps`], `"N Charles St"`, `"North Charles
+/// Street"` and `"Charles St N"` all become `"n charles st"`. With
+/// [`StreetNameConvention::German`], `"Hauptstr."` and `"Hauptstraße"` both
+/// become `"hauptstr"`.
+pub fn canonicalize_street_name(raw: &str, convention: StreetNameConvention) -> String {
+    let folded = fold_diacritics(&normalize(raw));
+    let mut tokens: Vec<String> = folded.split_whitespace().map(str::to_string).collect();
+
+    match convention {
+        StreetNameConvention::Usps => canonicalize_usps_tokens(&mut tokens),
+        StreetNameConvention::German => {
+            tokens.iter_mut().for_each(|t| abbreviate_compound_street_type(t, GERMAN_STREET_TYPES));
+        }
+        StreetNameConvention::Dutch => {
+            tokens.iter_mut().for_each(|t| abbreviate_compound_street_type(t, DUTCH_STREET_TYPES));
+        }
+        StreetNameConvention::French => {
+            if let Some(first) = tokens.first_mut() {
+                abbreviate_street_type(first, FRENCH_STREET_TYPES);
+            }
+            tokens.iter_mut().for_each(|t| abbreviate_street_type(t, FRENCH_SAINTS));
+        }
+        StreetNameConvention::Spanish => {
+            if let Some(first) = tokens.first_mut() {
+                abbreviate_street_type(first, SPANISH_STREET_TYPES);
+            }
+        }
+        StreetNameConvention::Generic => {}
+    }
+
+    tokens.join(" ")
+}
+
+/// Directionals on either end are abbreviated and a trailing one is moved to
+/// the front (`"charles st n"` => `"n charles st"`); then the last token is
+/// treated as the street suffix.
+fn canonicalize_usps_tokens(tokens: &mut Vec<String>) {
+    if tokens.len() >= 2 {
+        let last = tokens.len() - 1;
+        let leading = StreetDirectional::from_token(&tokens[0]);
+        if let Some(dir) = leading {
+            tokens[0] = dir.abbreviation().to_string();
+        }
+        if let Some(dir) = StreetDirectional::from_token(&tokens[last]) {
+            tokens[last] = dir.abbreviation().to_string();
+            // Keep two-token names like "avenue n" as they are: there is
+            // nothing left to call the street if we move the directional.
+            if leading.is_none() && tokens.len() >= 3 {
+                let trailing = tokens.remove(last);
+                tokens.insert(0, trailing);
+            }
+        }
+    }
+    if let Some(last) = tokens.last_mut() {
+        abbreviate_street_type(last, USPS_STREET_SUFFIXES);
+    }
+}
+
+/// Replaces a whole token that is a known street type.
+fn abbreviate_street_type(token: &mut String, table: StreetTypeTable) {
+    for (canonical, variants) in table {
+        if variants.contains(&token.as_str()) {
+            *token = canonical.to_string();
+            return;
+        }
+    }
+}
+
+/// Like [`abbreviate_street_type`], but also shortens a spelled-out street
+/// type at the end of a compound word (`"hauptstrasse"` => `"hauptstr"`).
+fn abbreviate_compound_street_type(token: &mut String, table: StreetTypeTable) {
+    for (canonical, variants) in table {
+        for variant in variants.iter() {
+            if let Some(stem) = token.strip_suffix(variant) {
+                *token = format!("{}{}", stem, canonical);
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod canonicalize_street_name_tests {
+    use super::*;
+
+    fn usps(s: &str) -> String {
+        canonicalize_street_name(s, StreetNameConvention::Usps)
+    }
+
+    #[traced_test]
+    fn test_usps_directional_and_suffix_variants_agree() {
+        assert_eq!(usps("N Charles St"), "n charles st");
+        assert_eq!(usps("North Charles Street"), "n charles st");
+        assert_eq!(usps("Charles St N"), "n charles st");
+        assert_eq!(usps("Charles Street, North"), "n charles st");
+    }
+
+    #[traced_test]
+    fn test_usps_two_letter_directionals() {
+        assert_eq!(usps("M St NW"), "nw m st");
+        assert_eq!(usps("M Street Northwest"), "nw m st");
+    }
+
+    #[traced_test]
+    fn test_usps_only_last_token_is_a_suffix() {
+        assert_eq!(usps("Old Georgetown Road"), "old georgetown rd");
+        assert_eq!(usps("Street Road"), "street rd");
+        assert_eq!(usps("Broadway"), "broadway");
+        assert_eq!(usps("Avenue North"), "avenue n");
+    }
+
+    #[traced_test]
+    fn test_german_compound_street_types() {
+        let german = |s: &str| canonicalize_street_name(s, StreetNameConvention::German);
+        assert_eq!(german("Hauptstraße"), "hauptstr");
+        assert_eq!(german("Hauptstr."), "hauptstr");
+        assert_eq!(german("HAUPTSTRASSE"), "hauptstr");
+        assert_eq!(german("Berliner Straße"), "berliner str");
+        assert_eq!(german("Marktplatz"), "marktpl");
+    }
+
+    #[traced_test]
+    fn test_french_leading_types_and_saints() {
+        let french = |s: &str| canonicalize_street_name(s, StreetNameConvention::French);
+        assert_eq!(french("Boulevard Saint-Michel"), "bd st michel");
+        assert_eq!(french("Bd St Michel"), "bd st michel");
+        assert_eq!(french("Avenue des Champs-Élysées"), "av des champs elysees");
+    }
+
+    #[traced_test]
+    fn test_spanish_leading_types() {
+        let spanish = |s: &str| canonicalize_street_name(s, StreetNameConvention::Spanish);
+        assert_eq!(spanish("Calle Mayor"), "c mayor");
+        assert_eq!(spanish("C/ Mayor"), "c mayor");
+        assert_eq!(spanish("Avda. de la Constitución"), "av de la constitucion");
+    }
+
+    #[traced_test]
+    fn test_generic_only_folds() {
+        let generic = |s: &str| canonicalize_street_name(s, StreetNameConvention::Generic);
+        assert_eq!(generic("North Charles Street"), "north charles street");
+        assert_eq!(generic("Hauptstraße"), "hauptstrasse");
+    }
+
+    #[traced_test]
+    fn test_canonicalization_is_idempotent() {
+        for s in ["N Charles St", "Charles Street North", "M St NW", "Avenue North"] {
+            let once = usps(s);
+            assert_eq!(usps(&once), once);
+        }
+    }
+}
@@ -233,7 +233,7 @@ mod chain_addresses_across_files_tests {
 
         // Now consume the iterator to trigger the actual parse:
         let chain_it = chain_result.unwrap();
-        let mut all_items: Vec<Result<WorldAddress, OsmPbfParseError>> = chain_it.collect();
+        let all_items: Vec<Result<WorldAddress, OsmPbfParseError>> = chain_it.collect();
 
         // The *first* file in the chain is corrupted => the *first* item in `all_items`
         // should be an Err(OsmPbfParseError::OsmPbf(...)).
@@ -10,7 +10,7 @@ pub trait CityNamesForPostalCodeInRegion {
     ) -> Option<BTreeSet<CityName>>;
 }
 
-impl<I:StorageInterface> CityNamesForPostalCodeInRegion for DataAccess<I> {
+impl<D:GetCitySetForKey> CityNamesForPostalCodeInRegion for D {
 
     fn cities_for_postal_code(&self, region: &WorldRegion, postal_code: &PostalCode) -> Option<BTreeSet<CityName>> {
         let key = z2c_key(region,postal_code);
@@ -1,7 +1,7 @@
 // ---------------- [ File: src/cli.rs ]
 crate::ix!();
 
-/// The CLI struct with the three flags
+/// The CLI struct with the builder's flags
 #[derive(StructOpt, Debug)]
 #[structopt(name = "world_city_and_street_db_builder")]
 pub struct Cli {
@@ -16,6 +16,79 @@ pub struct Cli {
     /// Whether to write to storage after parsing each region
     #[structopt(long)]
     write_to_storage: bool,
+
+    /// Download and parse this many regions at once, writing each as it
+    /// finishes (requires `--write-to-storage`, an error otherwise);
+    /// without it regions go one by one
+    #[structopt(long)]
+    jobs: Option<usize>,
+
+    /// Apply OSM change files (`.osc`/`.osc.gz`) from `<dir>/<region
+    /// abbreviation>/` to each region that is already built
+    #[structopt(long, parse(from_os_str))]
+    apply_diffs: Option<PathBuf>,
+
+    #[structopt(subcommand)]
+    command: Option<CliCommand>,
+}
+
+#[derive(StructOpt, Debug)]
+pub enum CliCommand {
+    /// Rewrite an existing database in place into the current key layout
+    Migrate,
+
+    /// Validate a CSV or JSONL address list against the database and write a
+    /// report with a status and best correction per row
+    ValidateFile {
+        #[structopt(parse(from_os_str))]
+        input: PathBuf,
+
+        #[structopt(long, parse(from_os_str))]
+        output: PathBuf,
+
+        /// `csv` or `jsonl`; guessed from the input's extension by default
+        #[structopt(long)]
+        format: Option<BulkAddressFormat>,
+
+        /// Input column for an address field, as `field=column` (e.g.
+        /// `postal_code=zip`); repeat for each field that is named differently
+        #[structopt(long = "column")]
+        columns: Vec<String>,
+
+        /// Region abbreviation for rows without a region column
+        #[structopt(long)]
+        region: Option<String>,
+
+        /// Worker threads (default: one per core)
+        #[structopt(long)]
+        threads: Option<usize>,
+    },
+}
+
+impl CliCommand {
+
+    /// The options for `validate-file`, or `None` for other commands.
+    fn bulk_validation_options(&self) -> Result<Option<BulkValidationOptions>,BulkValidationError> {
+        let CliCommand::ValidateFile { input, output, format, columns, region, threads } = self else {
+            return Ok(None);
+        };
+        let default_region = match region {
+            Some(abbr) => Some(WorldRegion::try_from_abbreviation(abbr).map_err(|_| {
+                BulkValidationError::UnknownRegion { abbreviation: abbr.clone() }
+            })?),
+            None => None,
+        };
+        let options = BulkValidationOptionsBuilder::default()
+            .input(input.clone())
+            .output(output.clone())
+            .format(*format)
+            .mapping(AddressColumnMapping::from_specs(columns)?)
+            .default_region(default_region)
+            .threads(*threads)
+            .build()
+            .unwrap();
+        Ok(Some(options))
+    }
 }
 
 impl Cli {
@@ -34,7 +107,10 @@ impl Cli {
     }
 
     /// Main driver, accepting four trait‐object closures for testing/injection.
-    pub async fn run_with_injection<I: StorageInterface>(
+    /// The database stays locked while regions are parsed into it, so nothing
+    /// else writes to it mid-build.
+    #[allow(clippy::await_holding_lock)]
+    pub async fn run_with_injection<I: StorageInterface + Clone + 'static>(
         &self,
         regions_fn:        WorldRegionSupplier,
         db_open_fn:        DatabaseOpener<I>,
@@ -43,6 +119,26 @@ impl Cli {
         db_path_str:       &str,
         pbf_dir_str:       &str,
     ) -> Result<(), WorldCityAndStreetDbBuilderError> {
+        if self.jobs.is_some() && !self.write_to_storage {
+            return Err(WorldCityAndStreetDbBuilderError::JobsRequireWriteToStorage);
+        }
+
+        // 0) Subcommands do their one job and nothing else. `migrate` works on
+        //    the database files directly.
+        if let Some(CliCommand::Migrate) = self.command {
+            let report = migrate_database_at_path(db_path_str)?;
+            println!("{:#?}", report);
+            return Ok(());
+        }
+        if let Some(options) = self.command.as_ref().map(CliCommand::bulk_validation_options).transpose()?.flatten() {
+            // Read-only, like the address server, so a build can keep running
+            let db      = I::open_read_only(Path::new(db_path_str))?;
+            let db      = into_unlocked_store(db).ok_or(WorldCityAndStreetDbBuilderError::DbLockError)?;
+            let summary = validate_address_file(db, &options)?;
+            println!("{:#?}", summary);
+            return Ok(());
+        }
+
         // 1) Gather regions
         let regions = (regions_fn)();
 
@@ -57,19 +153,54 @@ impl Cli {
             return Ok(());
         }
 
-        // 4) Otherwise parse each region
-        for region in regions {
+        // 4) Otherwise parse each region, several at once if asked to
+        match self.jobs {
+            Some(jobs) => {
+                let options = ParallelBuildOptionsBuilder::default()
+                    .parallel_parses(Some(jobs))
+                    .build()
+                    .unwrap();
+                let mut db_guard = db.lock().map_err(|_| WorldCityAndStreetDbBuilderError::DbLockError)?;
+                let summary = build_regions_in_parallel(&regions, &pbf_dir, &mut *db_guard, &options).await?;
+                if !summary.failed().is_empty() {
+                    return Err(WorldCityAndStreetDbBuilderError::RegionsFailedToBuild {
+                        regions: summary.failed().iter().map(|(r, _)| r.abbreviation().to_string()).collect(),
+                    });
+                }
+            }
+            _ => {
+                for region in &regions {
+                    let mut db_guard = db.lock().map_err(|_| WorldCityAndStreetDbBuilderError::DbLockError)?;
+                    (download_parse_fn)(region, &pbf_dir, &mut *db_guard, self.write_to_storage).await?;
+                }
+            }
+        }
+
+        // 5) Optional incremental updates for regions already built
+        if let Some(diff_dir) = &self.apply_diffs {
             let mut db_guard = db.lock().map_err(|_| WorldCityAndStreetDbBuilderError::DbLockError)?;
-            (download_parse_fn)(&region, &pbf_dir, &mut *db_guard, self.write_to_storage).await?;
+            for region in &regions {
+                let region_dir = diff_dir.join(region.abbreviation());
+                if !region_dir.is_dir() {
+                    debug!("no OSM change directory for region {:?} at {:?}", region, region_dir);
+                    continue;
+                }
+                match apply_osm_change_directory(&mut *db_guard, region, &region_dir) {
+                    Err(OsmChangeError::RegionNotBuilt { region }) => {
+                        warn!("region {:?} is not built yet; not applying OSM changes", region);
+                    }
+                    other => { other?; }
+                }
+            }
         }
 
-        // 5) Optional dump
+        // 6) Optional dump
         if self.dump {
             let db_guard = db.lock().map_err(|_| WorldCityAndStreetDbBuilderError::DbLockError)?;
             db_guard.dump_entire_database_contents();
         }
 
-        // 6) Final validate
+        // 7) Final validate
         (validate_all_fn)(db.clone(), &pbf_dir)?;
         Ok(())
     }
@@ -131,6 +262,89 @@ mod cli_tests {
         assert!(cli.dump);
         assert!(!cli.just_validate);
         assert!(cli.write_to_storage);
+        assert!(cli.command.is_none());
+    }
+
+    #[traced_test]
+    fn test_cli_parsing_migrate() {
+        let cli = Cli::from_iter(&["progname", "migrate"]);
+        assert!(matches!(cli.command, Some(CliCommand::Migrate)));
+    }
+
+    #[traced_test]
+    fn test_cli_parsing_validate_file() {
+        let cli = Cli::from_iter(&[
+            "progname", "validate-file", "in.csv", "--output", "out.csv",
+            "--column", "postal_code=zip", "--column", "street=addr1", "--region", "MD",
+        ]);
+        let options = cli.command.as_ref().unwrap().bulk_validation_options().unwrap().unwrap();
+        assert_eq!(options.input(), &PathBuf::from("in.csv"));
+        assert_eq!(options.mapping().postal_code(), "zip");
+        assert_eq!(options.mapping().street(), "addr1");
+        assert_eq!(*options.default_region(), Some(USRegion::UnitedState(UnitedState::Maryland).into()));
+        assert_eq!(*options.format(), None);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_run_with_injection_validate_file() {
+        let flags = Arc::new(TestFlags::default());
+        let tmp   = TempDir::new().unwrap();
+        let input = tmp.path().join("in.csv");
+        let output = tmp.path().join("out.csv");
+        let db_path = tmp.path().join("db");
+        std::fs::write(&input, "postal_code,city,street\n21201,Baltimore,Howard Street\n21201,Baltimore,Nowhere Lane\n").unwrap();
+
+        let cli = Cli {
+            dump: false,
+            just_validate: false,
+            write_to_storage: false,
+            jobs: None,
+            apply_diffs: None,
+            command: Some(CliCommand::ValidateFile {
+                input:   input.clone(),
+                output:  output.clone(),
+                format:  None,
+                columns: vec![],
+                region:  Some("MD".to_string()),
+                threads: Some(2),
+            }),
+        };
+
+        {
+            let db = Database::open(&db_path).unwrap();
+            let region: WorldRegion = USRegion::UnitedState(UnitedState::Maryland).into();
+            let baltimore = CityName::new("Baltimore").unwrap();
+            let howard    = StreetName::new_for_region("Howard Street", &region).unwrap();
+            let pc21201   = PostalCode::new(Country::USA, "21201").unwrap();
+            RegionalRecordsBuilder::default()
+                .region(region)
+                .records(vec![address_record!(baltimore, howard, pc21201)])
+                .build()
+                .unwrap()
+                .write_to_storage(&mut *db.lock().unwrap())
+                .unwrap();
+        }
+
+        let result = cli.run_with_injection(
+            Box::new(make_regions_one),
+            make_db_opener::<Database>(flags.clone()),
+            make_download_and_parse::<Database>(flags.clone()),
+            make_validate::<Database>(flags.clone()),
+            db_path.to_str().unwrap(),
+            "fake_pbf_dir",
+        ).await;
+
+        assert!(result.is_ok(), "{:?}", result);
+        assert!(!flags.db_opened.load(Ordering::SeqCst), "validate-file opens the database read-only itself");
+        assert!(!flags.downloaded.load(Ordering::SeqCst));
+        assert!(!flags.validated.load(Ordering::SeqCst));
+
+        let report = std::fs::read_to_string(&output).unwrap();
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(lines[0], "postal_code,city,street,status,correction_field,correction,detail");
+        assert!(lines[1].contains(",valid,"), "{}", lines[1]);
+        assert!(lines[2].contains(",street_not_in_postal_code,street,howard street,"), "{}", lines[2]);
     }
 
     #[tokio::test]
@@ -142,6 +356,9 @@ mod cli_tests {
             dump: false,
             just_validate: false,
             write_to_storage: false,
+            jobs: None,
+            apply_diffs: None,
+            command: None,
         };
 
         let opener     = make_db_opener::<Database>(flags.clone());
@@ -164,6 +381,34 @@ mod cli_tests {
         assert!(flags.validated.load(Ordering::SeqCst), "Should still validate at end");
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn test_run_with_injection_rejects_jobs_without_write_to_storage() {
+        let flags = Arc::new(TestFlags::default());
+
+        let cli = Cli {
+            dump: false,
+            just_validate: false,
+            write_to_storage: false,
+            jobs: Some(2),
+            apply_diffs: None,
+            command: None,
+        };
+
+        let result = cli.run_with_injection(
+            Box::new(make_regions_one),
+            make_db_opener::<Database>(flags.clone()),
+            make_download_and_parse::<Database>(flags.clone()),
+            make_validate::<Database>(flags.clone()),
+            "fake_db_path",
+            "fake_pbf_dir",
+        ).await;
+
+        assert!(matches!(result, Err(WorldCityAndStreetDbBuilderError::JobsRequireWriteToStorage)), "{:?}", result);
+        assert!(!flags.db_opened.load(Ordering::SeqCst), "Nothing opened for a rejected flag combination");
+        assert!(!flags.downloaded.load(Ordering::SeqCst));
+    }
+
     #[tokio::test]
     #[serial]
     async fn test_run_with_injection_just_validate() {
@@ -173,6 +418,9 @@ mod cli_tests {
             dump: false,
             just_validate: true,
             write_to_storage: false,
+            jobs: None,
+            apply_diffs: None,
+            command: None,
         };
 
         let opener     = make_db_opener::<Database>(flags.clone());
@@ -203,6 +451,9 @@ mod cli_tests {
             dump: false,
             just_validate: false,
             write_to_storage: true,
+            jobs: None,
+            apply_diffs: None,
+            command: None,
         };
 
         let opener     = make_db_opener::<Database>(flags.clone());
@@ -233,6 +484,9 @@ mod cli_tests {
             dump: true,
             just_validate: false,
             write_to_storage: false,
+            jobs: None,
+            apply_diffs: None,
+            command: None,
         };
 
         let opener     = make_db_opener::<Database>(flags.clone());
@@ -255,6 +509,98 @@ mod cli_tests {
         assert!(flags.validated.load(Ordering::SeqCst));
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn test_run_with_injection_apply_diffs() {
+        let flags = Arc::new(TestFlags::default());
+        let md: WorldRegion = USRegion::UnitedState(UnitedState::Maryland).into();
+
+        let diffs = TempDir::new().unwrap();
+        let md_dir = diffs.path().join(md.abbreviation());
+        std::fs::create_dir_all(&md_dir).unwrap();
+        std::fs::write(md_dir.join("7.osc"), r#"<osmChange><create>
+            <node id="1" lat="39.3" lon="-76.6">
+              <tag k="addr:city" v="Baltimore"/>
+              <tag k="addr:postcode" v="21201"/>
+            </node>
+        </create></osmChange>"#).unwrap();
+
+        let cli = Cli {
+            dump: false,
+            just_validate: false,
+            write_to_storage: false,
+            jobs: None,
+            apply_diffs: Some(diffs.path().to_path_buf()),
+            command: None,
+        };
+
+        // "Build" the region by just marking it done
+        let download_flags = flags.clone();
+        let downloader: DownloadAndParseHook<Database> = Box::new(move |region, _pbf, db, _w| {
+            download_flags.downloaded.store(true, Ordering::SeqCst);
+            db.mark_region_done(region).unwrap();
+            Box::pin(async move { Ok(()) })
+        });
+
+        let validate_flags = flags.clone();
+        let validator: ValidateHook<Database> = Box::new(move |db, _pbf| {
+            let md: WorldRegion = USRegion::UnitedState(UnitedState::Maryland).into();
+            let applied = db.lock().unwrap().last_applied_sequence(&md).unwrap();
+            validate_flags.validated.store(applied == Some(7), Ordering::SeqCst);
+            Ok(())
+        });
+
+        let result = cli.run_with_injection(
+            Box::new(make_regions_one),
+            make_db_opener::<Database>(flags.clone()),
+            downloader,
+            validator,
+            "fake_db_path",
+            "fake_pbf_dir",
+        ).await;
+
+        assert!(result.is_ok(), "{:?}", result);
+        assert!(flags.downloaded.load(Ordering::SeqCst));
+        assert!(flags.validated.load(Ordering::SeqCst), "sequence 7 should be recorded before validation");
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_run_with_injection_migrate() {
+        let flags = Arc::new(TestFlags::default());
+
+        let tmp = TempDir::new().unwrap();
+        {
+            let mut legacy = open_database_without_schema_check(tmp.path()).unwrap();
+            legacy.put("Z2C:MD:21201", b"x").unwrap();
+        }
+        assert!(Database::open(tmp.path()).is_err());
+
+        let cli = Cli {
+            dump: false,
+            just_validate: false,
+            write_to_storage: false,
+            jobs: None,
+            apply_diffs: None,
+            command: Some(CliCommand::Migrate),
+        };
+
+        let result = cli.run_with_injection(
+            Box::new(make_regions_one),
+            make_db_opener::<Database>(flags.clone()),
+            make_download_and_parse::<Database>(flags.clone()),
+            make_validate::<Database>(flags.clone()),
+            tmp.path().to_str().unwrap(),
+            "fake_pbf_dir",
+        ).await;
+
+        assert!(result.is_ok(), "{:?}", result);
+        assert!(!flags.db_opened.load(Ordering::SeqCst), "migrate opens the database itself");
+        assert!(!flags.downloaded.load(Ordering::SeqCst));
+        assert!(!flags.validated.load(Ordering::SeqCst));
+        assert!(Database::open(tmp.path()).is_ok(), "migrated database should open");
+    }
+
     #[traced_test]
     #[serial]
     fn test_mock_scenario() {
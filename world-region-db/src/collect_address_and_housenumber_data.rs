@@ -3,23 +3,54 @@ crate::ix!();
 
 /// Iterates through all OSM elements in the file, extracting both addresses
 /// and house‐number ranges. The results are appended to `addresses` and
-/// `street_hnr_map`.
-pub fn collect_address_and_housenumber_data(
-    reader: osmpbf::ElementReader<std::io::BufReader<std::fs::File>>,
+/// `street_hnr_map`. Addresses that came from ways are noted in
+/// `pending_ways` so their centroid can be resolved afterwards, and
+/// `addr:interpolation` ways in `pending_interpolations` so their ranges can
+/// be filled in from their numbered nodes. Boundary relations go to
+/// `pending_boundaries`. Element and address counts go to `progress` as
+/// they are found.
+#[allow(clippy::too_many_arguments)]
+pub fn collect_address_and_housenumber_data<R: Read + Send + Sync>(
+    reader: osmpbf::ElementReader<R>,
     country: &Country,
     addresses: &mut Vec<AddressRecord>,
     street_hnr_map: &mut HouseNumberAggregator,
+    pending_ways: &mut PendingWayCentroids,
+    pending_interpolations: &mut PendingInterpolations,
+    pending_boundaries: &mut PendingBoundaries,
+    progress: &RegionBuildProgress,
 ) -> Result<(), OsmPbfParseError> {
     trace!("collect_address_and_housenumber_data: starting iteration");
 
     let mut count = 0usize;
     reader.for_each(|element| {
+        let before = addresses.len();
         process_single_osm_element(&element, country, addresses, street_hnr_map)
             .expect("could not process single osm element");
+
+        if let Element::Way(way) = &element {
+            if addresses.len() > before {
+                pending_ways.push(before, way.refs().collect());
+            }
+
+            let interpolation = way.tags().find(|(k, _)| *k == "addr:interpolation");
+            if let Some(kind) = interpolation.and_then(|(_, v)| InterpolationKind::from_tag(v)) {
+                let street = way
+                    .tags()
+                    .find(|(k, _)| *k == "addr:street")
+                    .and_then(|(_, v)| StreetName::new_for_country(v, *country).ok());
+                pending_interpolations.push(way.id(), kind, way.refs().collect(), street);
+            }
+        }
+        if let Element::Relation(relation) = &element {
+            pending_boundaries.push_relation(relation);
+        }
         count += 1;
+        progress.add_elements(1);
+        progress.add_addresses((addresses.len() - before) as u64);
 
         // Periodic log to observe progress
-        if count % 100_000 == 0 {
+        if count.is_multiple_of(100_000) {
             info!(
                 "collect_address_and_housenumber_data: processed {} elements so far...",
                 count
@@ -53,6 +84,7 @@ mod collect_address_and_housenumber_data_tests {
     /// Creates a `.pbf` with 2 nodes:
     ///    - Node #1 => has complete `addr:city`, `addr:street`, `addr:postcode`.
     ///    - Node #2 => has partial missing `addr:street` => skip in addresses.
+    ///
     /// Optionally set `addr:housenumber` => aggregator usage in Node #1.
     ///
     /// For brevity, we only build a single `PrimitiveBlock` with 2 nodes.
@@ -194,7 +226,7 @@ mod collect_address_and_housenumber_data_tests {
         let mut addresses = Vec::new();
         let mut aggregator = HouseNumberAggregator::new(&region);
 
-        let res = collect_address_and_housenumber_data(reader, &country, &mut addresses, &mut aggregator);
+        let res = collect_address_and_housenumber_data(reader, &country, &mut addresses, &mut aggregator, &mut PendingWayCentroids::default(), &mut PendingInterpolations::default(), &mut PendingBoundaries::default(), &RegionBuildProgress::default());
         assert!(res.is_ok(), "Empty file => no parse error, just 0 elements");
         assert!(addresses.is_empty());
         assert!(aggregator.is_empty());
@@ -212,7 +244,7 @@ mod collect_address_and_housenumber_data_tests {
         let mut addresses = Vec::new();
         let mut aggregator = HouseNumberAggregator::new(&region);
 
-        let res = collect_address_and_housenumber_data(reader, &country, &mut addresses, &mut aggregator);
+        let res = collect_address_and_housenumber_data(reader, &country, &mut addresses, &mut aggregator, &mut PendingWayCentroids::default(), &mut PendingInterpolations::default(), &mut PendingBoundaries::default(), &RegionBuildProgress::default());
         assert!(res.is_err(), "Corrupted => parse error => Err(...)");
         match res.err().unwrap() {
             OsmPbfParseError::OsmPbf(_) => {
@@ -239,8 +271,11 @@ mod collect_address_and_housenumber_data_tests {
         let mut aggregator = HouseNumberAggregator::new(&region);
         let country = Country::USA;
 
-        let result = collect_address_and_housenumber_data(reader, &country, &mut addresses, &mut aggregator);
+        let progress = RegionBuildProgress::default();
+        let result = collect_address_and_housenumber_data(reader, &country, &mut addresses, &mut aggregator, &mut PendingWayCentroids::default(), &mut PendingInterpolations::default(), &mut PendingBoundaries::default(), &progress);
         assert!(result.is_ok());
+        assert_eq!(*progress.snapshot().elements(), 2);
+        assert_eq!(*progress.snapshot().addresses(), 2);
 
         // Now we expect BOTH Node #1 and Node #2 to yield an AddressRecord 
         // because partial is accepted. So, addresses.len() should be 2.
@@ -280,7 +315,7 @@ mod collect_address_and_housenumber_data_tests {
         let mut aggregator = HouseNumberAggregator::new(&region);
         let country = Country::USA;
 
-        let res = collect_address_and_housenumber_data(reader, &country, &mut addresses, &mut aggregator);
+        let res = collect_address_and_housenumber_data(reader, &country, &mut addresses, &mut aggregator, &mut PendingWayCentroids::default(), &mut PendingInterpolations::default(), &mut PendingBoundaries::default(), &RegionBuildProgress::default());
         assert!(res.is_ok());
 
         // Now we accept partial => we get 2 addresses, not just 1
@@ -26,11 +26,8 @@ where
 {
     let list: Vec<T> = set.iter().cloned().collect();
     let clist = CompressedList::from(list);
-    // Handle errors explicitly rather than `unwrap_or_else` with empty Vec
-    match serde_cbor::to_vec(&clist) {
-        Ok(bytes) => bytes,
-        Err(_) => Vec::new(),
-    }
+    // A list that fails to serialize is stored as empty
+    serde_cbor::to_vec(&clist).unwrap_or_default()
 }
 
 pub fn decompress_cbor_to_list<T>(bytes: &[u8]) -> Vec<T> 
@@ -69,7 +66,7 @@ mod compressed_list_tests {
     #[traced_test]
     fn test_compress_and_decompress_strings_with_duplicates() {
         // BTreeSet automatically removes duplicates, so final set is "Hello", "Hello", "World" => "Hello", "World"
-        let input = vec!["Hello","Hello","World","World"];
+        let input = ["Hello","Hello","World","World"];
         let set: BTreeSet<String> = input.iter().map(|s| s.to_string()).collect();
         assert_eq!(set.len(), 2);
 
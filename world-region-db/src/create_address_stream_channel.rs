@@ -1,12 +1,15 @@
 // ---------------- [ File: src/create_address_stream_channel.rs ]
 crate::ix!();
 
+/// One item of an address stream.
+pub type AddressStreamItem = Result<WorldAddress, OsmPbfParseError>;
+
 /// Creates a bounded sync channel for streaming address results.
 /// Returns `(SyncSender, Receiver)`.
 pub fn create_address_stream_channel(
 ) -> (
-    std::sync::mpsc::SyncSender<Result<WorldAddress, OsmPbfParseError>>,
-    std::sync::mpsc::Receiver<Result<WorldAddress, OsmPbfParseError>>
+    std::sync::mpsc::SyncSender<AddressStreamItem>,
+    std::sync::mpsc::Receiver<AddressStreamItem>
 ) {
     // Capacity of 1000 is arbitrary; can be tweaked depending on performance needs.
     std::sync::mpsc::sync_channel(1000)
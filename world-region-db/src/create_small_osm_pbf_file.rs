@@ -16,7 +16,7 @@ crate::ix!();
 ///
 /// * `path` - Filesystem path for the `.osm.pbf` file to be created.
 /// * `bbox` - (left, right, top, bottom) bounding box in "nano-degrees"
-///            (1e-9 degrees). E.g., -77_000_000_000 for -77.0.
+///   (1e-9 degrees). E.g., -77_000_000_000 for -77.0.
 /// * `city`         - The `addr:city` value to store.
 /// * `street`       - The `addr:street` value to store.
 /// * `housenumber`  - Optional `addr:housenumber` value, e.g. "100-110".
@@ -27,6 +27,7 @@ crate::ix!();
 ///
 /// * `Ok(())` on success.
 /// * `Err(std::io::Error)` if I/O or serialization fails.
+#[allow(clippy::too_many_arguments)]
 pub async fn create_small_osm_pbf_file(
     path:        &Path,
     bbox:        (i64, i64, i64, i64),
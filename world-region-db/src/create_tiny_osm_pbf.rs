@@ -1,14 +1,14 @@
 // ---------------- [ File: src/create_tiny_osm_pbf.rs ]
-/// Creates a very small .osm.pbf file with:
-///   - A single OSMHeader blob
-///   - A single OSMData blob that contains one node with two address tags
-///
-/// The resulting file should be enough for a test fixture in your integration tests.
-///
-/// Note: This uses the `osmpbf::proto::{fileformat,osmformat}` modules,
-///       which `osmpbf` normally uses internally for reading. They’re not officially
-///       documented for writing, but you can still access them in your own code.
-/// ---------------- [ File: src/create_tiny_osm_pbf.rs ]
+// ---------------- [ File: src/create_tiny_osm_pbf.rs ]
+// Creates a very small .osm.pbf file with:
+//   - A single OSMHeader blob
+//   - A single OSMData blob that contains one node with two address tags
+//
+// The resulting file should be enough for a test fixture in your integration tests.
+//
+// Note: This uses the `osmpbf::proto::{fileformat,osmformat}` modules,
+//       which `osmpbf` normally uses internally for reading. They’re not officially
+//       documented for writing, but you can still access them in your own code.
 crate::ix!();
 
 // Pull in the generated protobuf structs from the `osmpbf` crate
@@ -12,7 +12,7 @@ impl<I:StorageInterface> DataAccess<I> {
 
     /// Creates a new DataAccess that wraps the given Database (thread-safe).
     pub fn with_db(db: Arc<Mutex<I>>) -> Self {
-        info!("creating DataAccess object");
+        trace!("creating DataAccess object");
         DataAccess { db }
     }
 }
@@ -20,7 +20,8 @@ impl<I:StorageInterface> DataAccess<I> {
 impl<I:StorageInterface> DataAccessInterface for DataAccess<I> {}
 
 pub trait DataAccessInterface
-: CityNamesForPostalCodeInRegion
+: CandidateCoordinatesForAddress
++ CityNamesForPostalCodeInRegion
 + GatherAllZipsInRegion
 + GetCborSetTyped
 + GetCitySetForKey
@@ -33,6 +34,8 @@ pub trait DataAccessInterface
 + StreetNamesForCityInRegion
 + StreetNamesForPostalCodeInRegion
 + LoadHouseNumberRanges
++ NearestAddressesToPoint
++ ParseFreeTextAddress
 {}
 
 #[cfg(test)]
@@ -46,7 +49,7 @@ mod data_access_tests {
     fn create_db_and_da<I:StorageInterface>() 
         -> Result<(Arc<Mutex<I>>, DataAccess<I>), WorldCityAndStreetDbBuilderError> 
     {
-        let tmp = TempDir::new().map_err(|e| DataAccessError::Io(e))?;      // => DataAccessError::Io if fails
+        let tmp = TempDir::new().map_err(DataAccessError::Io)?;      // => DataAccessError::Io if fails
         let db  = I::open(tmp.path())?; // => DataAccessError::DatabaseConstructionError if fails
         let da  = DataAccess::with_db(db.clone());
         Ok((db, da))
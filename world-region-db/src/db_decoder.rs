@@ -20,23 +20,7 @@ pub trait DatabaseValueDecoder {
 impl DatabaseValueDecoder for Database {
     fn decode_value_for_key(&self, key: &str, val: &[u8]) {
         trace!("decode_value_for_key: key={}", key);
-        if key.starts_with("Z2C:") {
-            self.try_decode_as::<CityName>(val, "Cities");
-        } else if key.starts_with("C2Z:") {
-            self.try_decode_as::<PostalCode>(val, "Postal codes");
-        } else if key.starts_with("C2S:") {
-            self.try_decode_as::<StreetName>(val, "Streets");
-        } else if key.starts_with("S:") {
-            self.try_decode_as::<StreetName>(val, "Streets");
-        } else if key.starts_with("S2C:") {
-            self.try_decode_as::<CityName>(val, "Cities");
-        } else if key.starts_with("S2Z:") {
-            self.try_decode_as::<PostalCode>(val, "Postal codes");
-        } else if key.starts_with("META:REGION_DONE:") {
-            println!("Value: REGION DONE MARKER");
-        } else {
-            println!("Value: [Unknown key pattern]");
-        }
+        println!("{}", describe_value_for_key(key, val));
     }
 
     fn try_decode_as<T>(&self, val: &[u8], label: &str)
@@ -44,14 +28,22 @@ impl DatabaseValueDecoder for Database {
         T: Serialize + DeserializeOwned + Debug,
     {
         trace!("try_decode_as: Attempting decode as '{}'", label);
-        match serde_cbor::from_slice::<crate::CompressedList<T>>(val) {
-            Ok(clist) => {
-                let items = clist.items();
-                println!("Decoded as {}: {:?}", label, items);
-            }
-            Err(e) => {
-                println!("Failed to decode as {}: {}", label, e);
-            }
-        }
+        println!("{}", describe_cbor_list::<T>(val, label));
+    }
+}
+
+/// The value under `key`, decoded according to the key's [`DbKeyFamily`].
+pub fn describe_value_for_key(key: &str, val: &[u8]) -> String {
+    match DbKeyFamily::of_key(key) {
+        Some(family) => family.describe_value(val),
+        None         => "Value: [Unknown key pattern]".to_string(),
+    }
+}
+
+/// The typed form of `key` for display, or why it could not be decoded.
+pub fn describe_key(key: &str) -> String {
+    match DbKey::decode(key) {
+        Ok(decoded) => format!("Key: {} => {:?}", key, decoded),
+        Err(e)      => format!("Key: {} (undecoded: {:?})", key, e),
     }
 }
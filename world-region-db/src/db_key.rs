@@ -0,0 +1,503 @@
+// ---------------- [ File: src/db_key.rs ]
+crate::ix!();
+
+/// Every kind of key the database stores, identified by its tag (the text
+/// before the region abbreviation).
+#[derive(Copy,Clone,Debug,Hash,PartialEq,Eq)]
+pub enum DbKeyFamily {
+    /// `Z2C:{abbr}:{postal_code}` => set of [`CityName`]
+    PostalCodeCities,
+    /// `S:{abbr}:{postal_code}` => set of [`StreetName`]
+    PostalCodeStreets,
+    /// `C2Z:{abbr}:{city}` => set of [`PostalCode`]
+    CityPostalCodes,
+    /// `C2S:{abbr}:{city}` => set of [`StreetName`]
+    CityStreets,
+    /// `S2C:{abbr}:{street}` => set of [`CityName`]
+    StreetCities,
+    /// `S2Z:{abbr}:{street}` => set of [`PostalCode`]
+    StreetPostalCodes,
+    /// `HNR:{abbr}:{street}` => list of [`HouseNumberRange`]
+    HouseNumberRanges,
+    /// `LOC:{abbr}:{postal_code}:{city}:{street}` => list of [`LocatedAddress`]
+    LocatedAddresses,
+    /// `GEO:{abbr}:{geohash}` => list of [`LocatedAddress`]
+    GeoCell,
+    /// `OSM:{abbr}:{n|w|r}{id}` => one CBOR [`AddressRecord`]
+    OsmElement,
+    /// `REF:{abbr}:{link}` => u32 count of supporting elements
+    AddressLinkCount,
+    /// `BND:{abbr}:{relation_id}` => one CBOR [`AdminBoundary`]
+    Boundary,
+    /// `BNDX:{abbr}:{geohash}` => set of relation ids whose boundary's
+    /// bounding box overlaps the cell
+    BoundaryCell,
+    /// `META:REGION_DONE:{abbr}` => marker
+    RegionDone,
+    /// `META:REGION_BUILDING:{abbr}` => marker, while a build is writing
+    RegionBuilding,
+    /// `META:REGION_SEQ:{abbr}` => u64 last applied OSM change sequence
+    ReplicationSequence,
+    /// `META:SCHEMA_VERSION` => u32 layout version of the whole database
+    SchemaVersion,
+}
+
+impl DbKeyFamily {
+
+    pub const ALL: [DbKeyFamily; 17] = [
+        DbKeyFamily::PostalCodeCities,
+        DbKeyFamily::PostalCodeStreets,
+        DbKeyFamily::CityPostalCodes,
+        DbKeyFamily::CityStreets,
+        DbKeyFamily::StreetCities,
+        DbKeyFamily::StreetPostalCodes,
+        DbKeyFamily::HouseNumberRanges,
+        DbKeyFamily::LocatedAddresses,
+        DbKeyFamily::GeoCell,
+        DbKeyFamily::OsmElement,
+        DbKeyFamily::AddressLinkCount,
+        DbKeyFamily::Boundary,
+        DbKeyFamily::BoundaryCell,
+        DbKeyFamily::RegionDone,
+        DbKeyFamily::RegionBuilding,
+        DbKeyFamily::ReplicationSequence,
+        DbKeyFamily::SchemaVersion,
+    ];
+
+    pub fn tag(&self) -> &'static str {
+        match self {
+            DbKeyFamily::PostalCodeCities    => "Z2C:",
+            DbKeyFamily::PostalCodeStreets   => "S:",
+            DbKeyFamily::CityPostalCodes     => "C2Z:",
+            DbKeyFamily::CityStreets         => "C2S:",
+            DbKeyFamily::StreetCities        => "S2C:",
+            DbKeyFamily::StreetPostalCodes   => "S2Z:",
+            DbKeyFamily::HouseNumberRanges   => "HNR:",
+            DbKeyFamily::LocatedAddresses    => "LOC:",
+            DbKeyFamily::GeoCell             => "GEO:",
+            DbKeyFamily::OsmElement          => "OSM:",
+            DbKeyFamily::AddressLinkCount    => "REF:",
+            DbKeyFamily::Boundary            => "BND:",
+            DbKeyFamily::BoundaryCell        => "BNDX:",
+            DbKeyFamily::RegionDone          => "META:REGION_DONE:",
+            DbKeyFamily::RegionBuilding      => "META:REGION_BUILDING:",
+            DbKeyFamily::ReplicationSequence => "META:REGION_SEQ:",
+            DbKeyFamily::SchemaVersion       => "META:SCHEMA_VERSION",
+        }
+    }
+
+    /// The family `key` belongs to, judged by its tag alone.
+    pub fn of_key(key: &str) -> Option<DbKeyFamily> {
+        Self::ALL.iter().copied().find(|family| key.starts_with(family.tag()))
+    }
+
+    /// Text every key of this family for `region` starts with. For the
+    /// one-key-per-region meta families this is the whole key, and the
+    /// schema version key is not per region at all.
+    pub fn region_prefix(&self, region: &WorldRegion) -> String {
+        match self {
+            DbKeyFamily::SchemaVersion => self.tag().to_string(),
+            DbKeyFamily::RegionDone
+                | DbKeyFamily::RegionBuilding
+                | DbKeyFamily::ReplicationSequence => {
+                format!("{}{}", self.tag(), region.abbreviation())
+            }
+            _ => format!("{}{}:", self.tag(), region.abbreviation()),
+        }
+    }
+
+    /// A one-line, human readable rendering of a value stored under this
+    /// family, as printed by `dump`.
+    pub fn describe_value(&self, val: &[u8]) -> String {
+        match self {
+            DbKeyFamily::PostalCodeCities
+                | DbKeyFamily::StreetCities      => describe_cbor_list::<CityName>(val, "Cities"),
+            DbKeyFamily::CityPostalCodes
+                | DbKeyFamily::StreetPostalCodes => describe_cbor_list::<PostalCode>(val, "Postal codes"),
+            DbKeyFamily::PostalCodeStreets
+                | DbKeyFamily::CityStreets       => describe_cbor_list::<StreetName>(val, "Streets"),
+            DbKeyFamily::HouseNumberRanges       => describe_cbor_list::<HouseNumberRange>(val, "House number ranges"),
+            DbKeyFamily::LocatedAddresses
+                | DbKeyFamily::GeoCell           => describe_cbor_list::<LocatedAddress>(val, "Located addresses"),
+            DbKeyFamily::OsmElement => match serde_cbor::from_slice::<AddressRecord>(val) {
+                Ok(record) => format!("Decoded as Address record: {:?}", record),
+                Err(e)     => format!("Failed to decode as Address record: {}", e),
+            },
+            DbKeyFamily::Boundary => match serde_cbor::from_slice::<AdminBoundary>(val) {
+                Ok(b)  => format!(
+                    "Decoded as boundary: {:?} {:?}, {} outer / {} inner rings",
+                    b.kind(),
+                    b.name(),
+                    b.outer().len(),
+                    b.inner().len()
+                ),
+                Err(e) => format!("Failed to decode as boundary: {}", e),
+            },
+            DbKeyFamily::BoundaryCell => describe_cbor_list::<i64>(val, "Boundary relation ids"),
+            DbKeyFamily::AddressLinkCount if val.len() == 4 => {
+                format!("Value: {} supporting elements", BigEndian::read_u32(val))
+            }
+            DbKeyFamily::ReplicationSequence if val.len() == 8 => {
+                format!("Value: last applied OSM change sequence {}", BigEndian::read_u64(val))
+            }
+            DbKeyFamily::SchemaVersion if val.len() == 4 => {
+                format!("Value: schema version {}", BigEndian::read_u32(val))
+            }
+            DbKeyFamily::RegionDone     => "Value: REGION DONE MARKER".to_string(),
+            DbKeyFamily::RegionBuilding => "Value: REGION BUILD IN PROGRESS MARKER".to_string(),
+            _ => format!("Value: [{} unexpected bytes]", val.len()),
+        }
+    }
+}
+
+/// `Decoded as {label}: [...]` for a CBOR [`CompressedList`], or the
+/// reason it could not be decoded.
+pub fn describe_cbor_list<T>(val: &[u8], label: &str) -> String
+where
+    T: Serialize + DeserializeOwned + Debug,
+{
+    match serde_cbor::from_slice::<CompressedList<T>>(val) {
+        Ok(clist) => format!("Decoded as {}: {:?}", label, clist.items()),
+        Err(e)    => format!("Failed to decode as {}: {}", label, e),
+    }
+}
+
+/// A fully parsed database key. `encode` and `decode` are inverses for
+/// every key the builder writes, so this is the one place the layout of
+/// each family is spelled out.
+///
+/// Street parts of keys are always [`StreetName::canonical`]; a decoded
+/// street therefore carries its canonical form as its name too.
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub enum DbKey {
+    PostalCodeCities    { region: WorldRegion, postal_code: PostalCode },
+    PostalCodeStreets   { region: WorldRegion, postal_code: PostalCode },
+    CityPostalCodes     { region: WorldRegion, city: CityName },
+    CityStreets         { region: WorldRegion, city: CityName },
+    StreetCities        { region: WorldRegion, street: StreetName },
+    StreetPostalCodes   { region: WorldRegion, street: StreetName },
+    HouseNumberRanges   { region: WorldRegion, street: StreetName },
+    LocatedAddresses    { region: WorldRegion, postal_code: PostalCode, city: CityName, street: StreetName },
+    GeoCell             { region: WorldRegion, geohash: String },
+    OsmElement          { region: WorldRegion, element: OsmElementRef },
+    AddressLinkCount    { region: WorldRegion, link: AddressLink },
+    Boundary            { region: WorldRegion, relation_id: i64 },
+    BoundaryCell        { region: WorldRegion, geohash: String },
+    RegionDone          { region: WorldRegion },
+    RegionBuilding      { region: WorldRegion },
+    ReplicationSequence { region: WorldRegion },
+    SchemaVersion,
+}
+
+impl DbKey {
+
+    pub fn family(&self) -> DbKeyFamily {
+        match self {
+            DbKey::PostalCodeCities    { .. } => DbKeyFamily::PostalCodeCities,
+            DbKey::PostalCodeStreets   { .. } => DbKeyFamily::PostalCodeStreets,
+            DbKey::CityPostalCodes     { .. } => DbKeyFamily::CityPostalCodes,
+            DbKey::CityStreets         { .. } => DbKeyFamily::CityStreets,
+            DbKey::StreetCities        { .. } => DbKeyFamily::StreetCities,
+            DbKey::StreetPostalCodes   { .. } => DbKeyFamily::StreetPostalCodes,
+            DbKey::HouseNumberRanges   { .. } => DbKeyFamily::HouseNumberRanges,
+            DbKey::LocatedAddresses    { .. } => DbKeyFamily::LocatedAddresses,
+            DbKey::GeoCell             { .. } => DbKeyFamily::GeoCell,
+            DbKey::OsmElement          { .. } => DbKeyFamily::OsmElement,
+            DbKey::AddressLinkCount    { .. } => DbKeyFamily::AddressLinkCount,
+            DbKey::Boundary            { .. } => DbKeyFamily::Boundary,
+            DbKey::BoundaryCell        { .. } => DbKeyFamily::BoundaryCell,
+            DbKey::RegionDone          { .. } => DbKeyFamily::RegionDone,
+            DbKey::RegionBuilding      { .. } => DbKeyFamily::RegionBuilding,
+            DbKey::ReplicationSequence { .. } => DbKeyFamily::ReplicationSequence,
+            DbKey::SchemaVersion              => DbKeyFamily::SchemaVersion,
+        }
+    }
+
+    /// The region the key belongs to; `None` only for the schema version.
+    pub fn region(&self) -> Option<&WorldRegion> {
+        match self {
+            DbKey::PostalCodeCities    { region, .. }
+            | DbKey::PostalCodeStreets   { region, .. }
+            | DbKey::CityPostalCodes     { region, .. }
+            | DbKey::CityStreets         { region, .. }
+            | DbKey::StreetCities        { region, .. }
+            | DbKey::StreetPostalCodes   { region, .. }
+            | DbKey::HouseNumberRanges   { region, .. }
+            | DbKey::LocatedAddresses    { region, .. }
+            | DbKey::GeoCell             { region, .. }
+            | DbKey::OsmElement          { region, .. }
+            | DbKey::AddressLinkCount    { region, .. }
+            | DbKey::Boundary            { region, .. }
+            | DbKey::BoundaryCell        { region, .. }
+            | DbKey::RegionDone          { region }
+            | DbKey::RegionBuilding      { region }
+            | DbKey::ReplicationSequence { region } => Some(region),
+            DbKey::SchemaVersion => None,
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        self.to_string()
+    }
+
+    /// Parses a raw key back into its typed form.
+    pub fn decode(key: &str) -> Result<DbKey, DbKeyError> {
+        let family = DbKeyFamily::of_key(key)
+            .ok_or_else(|| DbKeyError::UnknownFamily { key: key.to_string() })?;
+
+        let rest = &key[family.tag().len()..];
+
+        if family == DbKeyFamily::SchemaVersion {
+            if !rest.is_empty() {
+                return Err(malformed(key, "trailing text after the schema version key"));
+            }
+            return Ok(DbKey::SchemaVersion);
+        }
+
+        let (abbr, body) = match family {
+            DbKeyFamily::RegionDone
+                | DbKeyFamily::RegionBuilding
+                | DbKeyFamily::ReplicationSequence => (rest, ""),
+            _ => rest.split_once(':').ok_or_else(|| malformed(key, "missing region abbreviation"))?,
+        };
+
+        let region = WorldRegion::try_from_abbreviation(abbr)
+            .map_err(|_| malformed(key, format!("unknown region abbreviation {:?}", abbr)))?;
+
+        let decoded = match family {
+            DbKeyFamily::PostalCodeCities => DbKey::PostalCodeCities {
+                region,
+                postal_code: parse_postal_code(key, &region, body)?,
+            },
+            DbKeyFamily::PostalCodeStreets => DbKey::PostalCodeStreets {
+                region,
+                postal_code: parse_postal_code(key, &region, body)?,
+            },
+            DbKeyFamily::CityPostalCodes => DbKey::CityPostalCodes {
+                region,
+                city: parse_city(key, body)?,
+            },
+            DbKeyFamily::CityStreets => DbKey::CityStreets {
+                region,
+                city: parse_city(key, body)?,
+            },
+            DbKeyFamily::StreetCities => DbKey::StreetCities {
+                region,
+                street: parse_street(key, body)?,
+            },
+            DbKeyFamily::StreetPostalCodes => DbKey::StreetPostalCodes {
+                region,
+                street: parse_street(key, body)?,
+            },
+            DbKeyFamily::HouseNumberRanges => DbKey::HouseNumberRanges {
+                region,
+                street: parse_street(key, body)?,
+            },
+            DbKeyFamily::LocatedAddresses => {
+                let mut parts = body.splitn(3, ':');
+                let (Some(pc), Some(city), Some(street)) = (parts.next(), parts.next(), parts.next()) else {
+                    return Err(malformed(key, "expected postal_code:city:street"));
+                };
+                DbKey::LocatedAddresses {
+                    region,
+                    postal_code: parse_postal_code(key, &region, pc)?,
+                    city:        parse_city(key, city)?,
+                    street:      parse_street(key, street)?,
+                }
+            }
+            DbKeyFamily::GeoCell => DbKey::GeoCell {
+                region,
+                geohash: parse_geohash(key, body)?,
+            },
+            DbKeyFamily::OsmElement => DbKey::OsmElement {
+                region,
+                element: OsmElementRef::parse(body).ok_or_else(|| malformed(key, "invalid OSM element"))?,
+            },
+            DbKeyFamily::AddressLinkCount => {
+                let country = Country::try_from(region)
+                    .map_err(|_| malformed(key, "region has no country"))?;
+                DbKey::AddressLinkCount {
+                    region,
+                    link: AddressLink::parse(body, country).ok_or_else(|| malformed(key, "invalid address link"))?,
+                }
+            }
+            DbKeyFamily::Boundary => DbKey::Boundary {
+                region,
+                relation_id: body.parse().map_err(|_| malformed(key, "invalid relation id"))?,
+            },
+            DbKeyFamily::BoundaryCell => DbKey::BoundaryCell {
+                region,
+                geohash: parse_geohash(key, body)?,
+            },
+            DbKeyFamily::RegionDone          => DbKey::RegionDone { region },
+            DbKeyFamily::RegionBuilding      => DbKey::RegionBuilding { region },
+            DbKeyFamily::ReplicationSequence => DbKey::ReplicationSequence { region },
+            DbKeyFamily::SchemaVersion       => unreachable!("handled above"),
+        };
+
+        Ok(decoded)
+    }
+}
+
+impl fmt::Display for DbKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tag = self.family().tag();
+        match self {
+            DbKey::PostalCodeCities  { region, postal_code }
+            | DbKey::PostalCodeStreets { region, postal_code } => {
+                write!(f, "{}{}:{}", tag, region.abbreviation(), postal_code.code())
+            }
+            DbKey::CityPostalCodes { region, city }
+            | DbKey::CityStreets     { region, city } => {
+                write!(f, "{}{}:{}", tag, region.abbreviation(), city.name())
+            }
+            DbKey::StreetCities      { region, street }
+            | DbKey::StreetPostalCodes { region, street }
+            | DbKey::HouseNumberRanges { region, street } => {
+                write!(f, "{}{}:{}", tag, region.abbreviation(), street.canonical())
+            }
+            DbKey::LocatedAddresses { region, postal_code, city, street } => write!(
+                f,
+                "{}{}:{}:{}:{}",
+                tag,
+                region.abbreviation(),
+                postal_code.code(),
+                city.name(),
+                street.canonical()
+            ),
+            DbKey::GeoCell          { region, geohash }
+            | DbKey::BoundaryCell     { region, geohash } => write!(f, "{}{}:{}", tag, region.abbreviation(), geohash),
+            DbKey::OsmElement       { region, element } => write!(f, "{}{}:{}", tag, region.abbreviation(), element),
+            DbKey::AddressLinkCount { region, link }    => write!(f, "{}{}:{}", tag, region.abbreviation(), link),
+            DbKey::Boundary { region, relation_id }     => write!(f, "{}{}:{}", tag, region.abbreviation(), relation_id),
+            DbKey::RegionDone          { region }
+            | DbKey::RegionBuilding      { region }
+            | DbKey::ReplicationSequence { region } => write!(f, "{}{}", tag, region.abbreviation()),
+            DbKey::SchemaVersion => write!(f, "{}", tag),
+        }
+    }
+}
+
+impl From<DbKey> for String {
+    fn from(key: DbKey) -> String {
+        key.encode()
+    }
+}
+
+fn malformed(key: &str, reason: impl Display) -> DbKeyError {
+    DbKeyError::MalformedKey {
+        key:    key.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+fn parse_postal_code(key: &str, region: &WorldRegion, text: &str) -> Result<PostalCode, DbKeyError> {
+    let country = Country::try_from(*region).map_err(|_| malformed(key, "region has no country"))?;
+    PostalCode::new(country, text).map_err(|e| malformed(key, format!("invalid postal code: {:?}", e)))
+}
+
+fn parse_city(key: &str, text: &str) -> Result<CityName, DbKeyError> {
+    CityName::new(text).map_err(|e| malformed(key, format!("invalid city: {:?}", e)))
+}
+
+fn parse_geohash(key: &str, text: &str) -> Result<String, DbKeyError> {
+    if text.is_empty() || !text.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(malformed(key, "invalid geohash"));
+    }
+    Ok(text.to_string())
+}
+
+fn parse_street(key: &str, text: &str) -> Result<StreetName, DbKeyError> {
+    StreetName::new(text).map_err(|e| malformed(key, format!("invalid street: {:?}", e)))
+}
+
+#[cfg(test)]
+mod db_key_tests {
+    use super::*;
+
+    fn md() -> WorldRegion {
+        USRegion::UnitedState(UnitedState::Maryland).into()
+    }
+
+    fn sample_keys() -> Vec<DbKey> {
+        let region = md();
+        let pc     = PostalCode::new(Country::USA, "21201").unwrap();
+        let city   = CityName::new("Baltimore").unwrap();
+        let street = StreetName::new_for_region("North Avenue", &region).unwrap();
+
+        vec![
+            DbKey::PostalCodeCities    { region, postal_code: pc.clone() },
+            DbKey::PostalCodeStreets   { region, postal_code: pc.clone() },
+            DbKey::CityPostalCodes     { region, city: city.clone() },
+            DbKey::CityStreets         { region, city: city.clone() },
+            DbKey::StreetCities        { region, street: street.clone() },
+            DbKey::StreetPostalCodes   { region, street: street.clone() },
+            DbKey::HouseNumberRanges   { region, street: street.clone() },
+            DbKey::LocatedAddresses    { region, postal_code: pc.clone(), city: city.clone(), street: street.clone() },
+            DbKey::GeoCell             { region, geohash: "dqcjq".to_string() },
+            DbKey::OsmElement          { region, element: OsmElementRef::Way(77) },
+            DbKey::AddressLinkCount    { region, link: AddressLink::PostalCodeStreet(pc.clone(), street.clone()) },
+            DbKey::Boundary            { region, relation_id: 1_234_567 },
+            DbKey::BoundaryCell        { region, geohash: "dqcj".to_string() },
+            DbKey::RegionDone          { region },
+            DbKey::RegionBuilding      { region },
+            DbKey::ReplicationSequence { region },
+            DbKey::SchemaVersion,
+        ]
+    }
+
+    #[traced_test]
+    fn test_every_family_round_trips() {
+        let keys = sample_keys();
+        assert_eq!(keys.len(), DbKeyFamily::ALL.len());
+
+        for key in keys {
+            let encoded = key.encode();
+            assert_eq!(DbKeyFamily::of_key(&encoded), Some(key.family()), "{}", encoded);
+            assert_eq!(DbKey::decode(&encoded).unwrap(), key, "{}", encoded);
+            assert_eq!(DbKey::decode(&encoded).unwrap().encode(), encoded);
+        }
+    }
+
+    #[traced_test]
+    fn test_encoding_matches_key_helpers() {
+        let region = md();
+        let city   = CityName::new("Baltimore").unwrap();
+        let street = StreetName::new_for_region("North Avenue", &region).unwrap();
+
+        assert_eq!(DbKey::CityStreets { region, city: city.clone() }.encode(), c2s_key(&region, &city));
+        assert_eq!(DbKey::StreetCities { region, street: street.clone() }.encode(), s2c_key(&region, &street));
+        assert_eq!(DbKey::RegionDone { region }.encode(), MetaKeyForRegion::from(region).key().clone());
+    }
+
+    #[traced_test]
+    fn test_region_prefix_covers_family_keys() {
+        let region = md();
+        for key in sample_keys() {
+            let prefix = key.family().region_prefix(&region);
+            assert!(key.encode().starts_with(&prefix), "{} / {}", key, prefix);
+        }
+    }
+
+    #[traced_test]
+    fn test_decode_errors() {
+        assert!(matches!(DbKey::decode("XYZ:MD:1"), Err(DbKeyError::UnknownFamily { .. })));
+        assert!(matches!(DbKey::decode("Z2C:??:21201"), Err(DbKeyError::MalformedKey { .. })));
+        assert!(matches!(DbKey::decode("Z2C:MD"), Err(DbKeyError::MalformedKey { .. })));
+        assert!(matches!(DbKey::decode("OSM:MD:x12"), Err(DbKeyError::MalformedKey { .. })));
+        assert!(matches!(DbKey::decode("LOC:MD:21201:baltimore"), Err(DbKeyError::MalformedKey { .. })));
+        assert!(matches!(DbKey::decode("META:SCHEMA_VERSIONS"), Err(DbKeyError::MalformedKey { .. })));
+    }
+
+    #[traced_test]
+    fn test_describe_values() {
+        let mut buf = [0u8; 4];
+        BigEndian::write_u32(&mut buf, 2);
+        assert_eq!(DbKeyFamily::SchemaVersion.describe_value(&buf), "Value: schema version 2");
+        assert_eq!(DbKeyFamily::RegionDone.describe_value(b"done"), "Value: REGION DONE MARKER");
+
+        let cities: BTreeSet<CityName> = [CityName::new("Baltimore").unwrap()].into_iter().collect();
+        let described = DbKeyFamily::StreetCities.describe_value(&compress_set_to_cbor(&cities));
+        assert!(described.starts_with("Decoded as Cities: [CityName"), "{}", described);
+    }
+}
@@ -103,6 +103,7 @@ pub async fn download_and_parse_region<I: StorageInterface>(
 
 
 #[cfg(test)]
+#[allow(clippy::await_holding_lock)]
 mod download_and_parse_all_regions_tests {
 
     use super::*;
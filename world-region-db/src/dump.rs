@@ -13,8 +13,7 @@ pub trait DatabaseDump {
     /// each value.
     fn dump_keys_with_prefix(&self, prefix: &str);
 
-    /// Dump all region-related keys by using the region's abbreviation
-    /// as a prefix.
+    /// Dump every key stored for the region.
     fn dump_region_data(&self, region: &WorldRegion);
 }
 
@@ -31,6 +30,7 @@ impl DatabaseDump for Database {
                 Ok((key_bytes, val_bytes)) => {
                     let key_str = String::from_utf8_lossy(&key_bytes);
                     debug!("dump_entire_database_contents: found key={}", key_str);
+                    println!("{}", describe_key(&key_str));
                     self.decode_value_for_key(&key_str, &val_bytes);
                     println!();
                 }
@@ -52,6 +52,7 @@ impl DatabaseDump for Database {
                 Ok((key_bytes, val_bytes)) => {
                     let key_str = String::from_utf8_lossy(&key_bytes);
                     debug!("dump_keys_with_prefix: matched key={}", key_str);
+                    println!("{}", describe_key(&key_str));
                     self.decode_value_for_key(&key_str, &val_bytes);
                     println!();
                 }
@@ -62,11 +63,15 @@ impl DatabaseDump for Database {
         }
     }
 
-    /// Dump all region-related keys by using its abbreviation as a prefix.
+    /// Dump all region-related keys, one key family at a time.
     fn dump_region_data(&self, region: &WorldRegion) {
-        let prefix = format!("{}:", region.abbreviation());
-        trace!("dump_region_data: region={:?}, prefix={}", region, prefix);
-        self.dump_keys_with_prefix(&prefix);
+        trace!("dump_region_data: region={:?}", region);
+        for family in DbKeyFamily::ALL {
+            if family == DbKeyFamily::SchemaVersion {
+                continue;
+            }
+            self.dump_keys_with_prefix(&family.region_prefix(region));
+        }
     }
 }
 
@@ -107,7 +112,6 @@ mod dump_tests {
     /// in your real code. We'll demonstrate the concept.
     /// 
     /// If you cannot refactor, you might rely on end-to-end tests or logging checks.
-
     impl Database {
         pub fn dump_entire_database_contents_to<W: Write>(&self, mut out: W) {
             writeln!(out, "---- DUMPING ENTIRE DATABASE CONTENTS ----").ok();
@@ -116,7 +120,7 @@ mod dump_tests {
                 match item {
                     Ok((key, val)) => {
                         let key_str = String::from_utf8_lossy(&key);
-                        writeln!(out, "Key: {}", key_str).ok();
+                        writeln!(out, "{}", describe_key(&key_str)).ok();
                         self.dump_value_for_key_to(&key_str, &val, &mut out);
                         writeln!(out).ok();
                     }
@@ -128,39 +132,7 @@ mod dump_tests {
         }
 
         fn dump_value_for_key_to<W: Write>(&self, key: &str, val: &[u8], mut out: W) {
-            if key.starts_with("Z2C:") {
-                self.try_decode_as_to::<CityName, _>(val, "Cities", &mut out);
-            } else if key.starts_with("C2Z:") {
-                self.try_decode_as_to::<PostalCode, _>(val, "Postal codes", &mut out);
-            } else if key.starts_with("C2S:") {
-                self.try_decode_as_to::<StreetName, _>(val, "Streets", &mut out);
-            } else if key.starts_with("S:") {
-                self.try_decode_as_to::<StreetName, _>(val, "Streets", &mut out);
-            } else if key.starts_with("S2C:") {
-                self.try_decode_as_to::<CityName, _>(val, "Cities", &mut out);
-            } else if key.starts_with("S2Z:") {
-                self.try_decode_as_to::<PostalCode, _>(val, "Postal codes", &mut out);
-            } else if key.starts_with("META:REGION_DONE:") {
-                writeln!(out, "Value: REGION DONE MARKER").ok();
-            } else {
-                writeln!(out, "Value: [Unknown key pattern]").ok();
-            }
-        }
-
-        fn try_decode_as_to<T, W>(&self, val: &[u8], label: &str, out: &mut W)
-        where
-            T: serde::Serialize + DeserializeOwned + std::fmt::Debug,
-            W: Write,
-        {
-            match serde_cbor::from_slice::<crate::CompressedList<T>>(val) {
-                Ok(clist) => {
-                    let items = clist.items();
-                    writeln!(out, "Decoded as {}: {:?}", label, items).ok();
-                }
-                Err(e) => {
-                    writeln!(out, "Failed to decode as {}: {}", label, e).ok();
-                }
-            }
+            writeln!(out, "{}", describe_value_for_key(key, val)).ok();
         }
     }
 
@@ -175,8 +147,10 @@ mod dump_tests {
         let output = String::from_utf8_lossy(&buffer);
 
         assert!(output.contains("---- DUMPING ENTIRE DATABASE CONTENTS ----"));
-        // no "Key:" lines
-        assert!(!output.contains("Key: "));
+        // only the schema version a new database is stamped with
+        assert_eq!(output.matches("Key: ").count(), 1);
+        assert!(output.contains("Key: META:SCHEMA_VERSION"));
+        assert!(output.contains(&format!("Value: schema version {}", DB_SCHEMA_VERSION)));
     }
 
     #[traced_test]
@@ -253,6 +227,25 @@ mod dump_tests {
                 "Should see street set for S: key");
     }
 
+    #[traced_test]
+    fn test_dump_shows_typed_key() {
+        let (db, _td) = create_db::<Database>();
+        {
+            let mut db_guard = db.lock().unwrap();
+            let mut city_set = BTreeSet::new();
+            city_set.insert(CityName::new("Baltimore").unwrap());
+            put_set_into_db(&mut *db_guard, "Z2C:MD:21201", &city_set);
+        }
+
+        let db_guard = db.lock().unwrap();
+        let mut buffer = Vec::new();
+        db_guard.dump_entire_database_contents_to(&mut buffer);
+        let output = String::from_utf8_lossy(&buffer);
+
+        assert!(output.contains("Key: Z2C:MD:21201 => PostalCodeCities {"), "{}", output);
+        assert!(output.contains("Decoded as Cities: [CityName { name: \"baltimore\""));
+    }
+
     #[traced_test]
     fn test_dump_corrupted_cbor_for_recognized_prefix() {
         let (db, _td) = create_db::<Database>();
@@ -101,6 +101,129 @@ error_tree!{
         DataAccessError(DataAccessError),
         OsmPbfParseError(OsmPbfParseError),
         RocksDB(rocksdb::Error),
+        LocationSerdeError {
+            msg: String,
+        },
+        OsmElementIndexSerdeError {
+            msg: String,
+        },
+        BoundarySerdeError {
+            msg: String,
+        },
+    }
+
+    #[derive(PartialEq)]
+    pub enum FreeTextAddressError {
+        EmptyInput,
+
+        #[cmp_neq]
+        WorldRegionConversionError(WorldRegionConversionError),
+    }
+
+    #[derive(PartialEq)]
+    pub enum OsmChangeError {
+        #[cmp_neq]
+        Io(io::Error),
+        InvalidXml {
+            msg: String,
+        },
+        InvalidAttribute {
+            element:   String,
+            attribute: String,
+            value:     String,
+        },
+        InvalidSequenceFileName {
+            path: PathBuf,
+        },
+        RegionNotBuilt {
+            region: WorldRegion,
+        },
+        SerdeError {
+            msg: String,
+        },
+
+        #[cmp_neq]
+        WorldRegionConversionError(WorldRegionConversionError),
+        DataAccessError(DataAccessError),
+        DatabaseConstructionError(DatabaseConstructionError),
+    }
+
+    #[derive(PartialEq)]
+    pub enum DbKeyError {
+        UnknownFamily {
+            key: String,
+        },
+        MalformedKey {
+            key:    String,
+            reason: String,
+        },
+    }
+
+    #[derive(PartialEq)]
+    pub enum DatabaseSchemaError {
+        Outdated {
+            found:    u32,
+            expected: u32,
+        },
+        NewerThanSupported {
+            found:     u32,
+            supported: u32,
+        },
+    }
+
+    #[derive(PartialEq)]
+    pub enum AddressServiceError {
+        UnknownRegion {
+            abbreviation: String,
+        },
+        EmptyPrefix,
+        BackgroundTaskFailed {
+            msg: String,
+        },
+        DbLockError,
+
+        #[cmp_neq]
+        WorldRegionConversionError(WorldRegionConversionError),
+        PostalCodeConstructionError(PostalCodeConstructionError),
+        CityNameConstructionError(CityNameConstructionError),
+        StreetNameConstructionError(StreetNameConstructionError),
+        DataAccessError(DataAccessError),
+
+        #[cmp_neq]
+        Io(io::Error),
+
+        WorldCityAndStreetDbBuilderError(WorldCityAndStreetDbBuilderError),
+    }
+
+    #[derive(PartialEq)]
+    pub enum BulkValidationError {
+        InvalidColumnMapping {
+            spec: String,
+        },
+        UnknownFormat {
+            format: String,
+        },
+        UnknownRegion {
+            abbreviation: String,
+        },
+        InvalidJsonLine {
+            line: usize,
+            msg:  String,
+        },
+
+        #[cmp_neq]
+        CsvError(csv::Error),
+
+        #[cmp_neq]
+        Io(io::Error),
+    }
+
+    #[derive(PartialEq)]
+    pub enum GeoPointError {
+        InvalidCoordinates {
+            lat: f64,
+            lon: f64,
+        },
     }
 
     #[derive(PartialEq)]
@@ -115,8 +238,19 @@ error_tree!{
         DatabaseConstructionError(DatabaseConstructionError),
         OsmPbfParseError(OsmPbfParseError),
         DataAccessError(DataAccessError),
+        OsmChangeError(OsmChangeError),
+        DatabaseSchemaError(DatabaseSchemaError),
+        BulkValidationError(BulkValidationError),
         DbLockError,
         NotAllAddressesValidatedSuccessfully,
+        /// A parallel build finished, but these regions (by abbreviation)
+        /// could not be downloaded or parsed.
+        RegionsFailedToBuild { regions: Vec<String> },
+        /// `--jobs` only applies to builds that write to storage.
+        JobsRequireWriteToStorage,
+        BackgroundTaskFailed {
+            msg: String,
+        },
     }
 
     /// Error types for city and street name construction
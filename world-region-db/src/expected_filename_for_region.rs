@@ -57,7 +57,7 @@ mod expected_filename_for_region_tests {
     #[test]
     fn test_filename_dir_is_dot_success() {
         let link = "http://download.geofabrik.de/north-america/us/maryland-latest.osm.pbf";
-        let result = expected_filename_for_region(".", &link);
+        let result = expected_filename_for_region(".", link);
         assert!(result.is_ok(), "Should succeed with a normal link");
         let path = result.unwrap();
         assert_eq!(path.to_str().unwrap(), "maryland-latest.osm.pbf");
@@ -66,7 +66,7 @@ mod expected_filename_for_region_tests {
     #[test]
     fn test_filename_custom_dir() {
         let link = "http://download.geofabrik.de/north-america/us/maryland-latest.osm.pbf";
-        let result = expected_filename_for_region("/some/path", &link);
+        let result = expected_filename_for_region("/some/path", link);
         assert!(result.is_ok());
         let path = result.unwrap();
         assert_eq!(path.to_str().unwrap(), "/some/path/maryland-latest.osm.pbf");
@@ -77,7 +77,7 @@ mod expected_filename_for_region_tests {
         // entire link is "just_a_name_without_slash", no slash => that's the final segment
         // => dir="." => we yield "just_a_name_without_slash"
         let link = "just_a_name_without_slash";
-        let result = expected_filename_for_region(".", &link);
+        let result = expected_filename_for_region(".", link);
         assert!(result.is_ok());
         assert_eq!(
             result.unwrap().to_str().unwrap(),
@@ -90,7 +90,7 @@ mod expected_filename_for_region_tests {
         // "maryland-latest.osm.pbf?v=123" => suffix is "maryland-latest.osm.pbf?v=123"
         // => we do not parse the query; we keep it in the filename
         let link = "http://fakehost/maryland-latest.osm.pbf?v=123";
-        let result = expected_filename_for_region(".", &link);
+        let result = expected_filename_for_region(".", link);
         assert!(result.is_ok());
         assert_eq!(
             result.unwrap().to_str().unwrap(),
@@ -102,7 +102,7 @@ mod expected_filename_for_region_tests {
     fn test_empty_link_error() {
         // link="" => no valid filename => error
         let link = "";
-        let result = expected_filename_for_region(".", &link);
+        let result = expected_filename_for_region(".", link);
         assert!(result.is_err(), "Should be an error for empty link");
         match result.err().unwrap() {
             ExpectedFilenameError::NoValidFilename => {
@@ -115,7 +115,7 @@ mod expected_filename_for_region_tests {
     fn test_link_ends_with_slash_error() {
         // e.g. "http://host/dir/" => suffix = "" => error
         let link = "http://host/dir/";
-        let result = expected_filename_for_region(".", &link);
+        let result = expected_filename_for_region(".", link);
         assert!(result.is_err(), "Ending slash => no valid final segment => error");
         match result.err().unwrap() {
             ExpectedFilenameError::NoValidFilename => {
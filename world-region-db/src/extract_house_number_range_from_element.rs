@@ -96,8 +96,8 @@ macro_rules! generate_house_number_extractors {
     }
 }
 
-/// Example usage of the macro. Here we define four specialized
-/// extraction functions plus the unified `extract_house_number_range_from_element`.
+// Example usage of the macro. Here we define four specialized
+// extraction functions plus the unified `extract_house_number_range_from_element`.
 generate_house_number_extractors!(
     extract_house_number_range_from_node,
     osmpbf::Node,
@@ -183,7 +183,7 @@ mod extract_house_number_range_from_element_integration_tests {
                 Ok(None) => {}, // This element had no housenumber
                 Err(e) => panic!("Unexpected error: {:?}", e),
             }
-        });
+        }).expect("Failed to read the test file");
         assert!(found, "Expected at least one Node with a valid house number range");
     }
 
@@ -208,7 +208,7 @@ mod extract_house_number_range_from_element_integration_tests {
                 Ok(Some(_)) => panic!("Did not expect a house number range"),
                 Err(e) => panic!("Unexpected error: {:?}", e),
             }
-        });
+        }).expect("Failed to read the test file");
 
         assert!(seen_none, "Expected a Node with no housenumber to yield None");
     }
@@ -240,7 +240,7 @@ mod extract_house_number_range_from_element_integration_tests {
                     }
                 },
             }
-        });
+        }).expect("Failed to read the test file");
         assert!(saw_error, "Expected at least one error for invalid housenumber");
     }
 }
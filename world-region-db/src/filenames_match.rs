@@ -7,6 +7,7 @@ crate::ix!();
 /// Example:
 /// - expected = "maryland-latest.osm.pbf"
 /// - actual   = "MaRyLaNd-LaTeSt.1c2d3f4g.oSm.PbF"
+///
 /// => returns true
 pub fn filenames_match(expected: &str, actual: &str) -> bool {
     // Because of the possibility that `expected` is "./maryland-latest.osm.pbf"
@@ -15,7 +16,7 @@ pub fn filenames_match(expected: &str, actual: &str) -> bool {
     let actual   = strip_leading_dot_slash(actual);
 
     // Quick check: if ignoring ASCII case they match exactly, done.
-    if actual.eq_ignore_ascii_case(&expected) {
+    if actual.eq_ignore_ascii_case(expected) {
         return true;
     }
 
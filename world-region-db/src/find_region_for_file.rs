@@ -76,7 +76,7 @@ mod find_region_for_file_tests {
             let invalid_bytes = b"maryland-latest.osm.pbf\xFF\xFE";
             let path = PathBuf::from(std::ffi::OsStr::from_bytes(invalid_bytes));
             let regions = known_test_regions();
-            let result = find_region_for_file(&path, &regions, ".");
+            let result = find_region_for_file(&path, &regions, ".").expect("expected a region for the file");
             assert!(result.is_none(), "Non-UTF8 filename => None");
         }
         
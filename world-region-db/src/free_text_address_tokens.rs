@@ -0,0 +1,159 @@
+// ---------------- [ File: src/free_text_address_tokens.rs ]
+crate::ix!();
+
+/// One whitespace/comma separated piece of a free-text address.
+#[derive(Getters,Clone,Debug,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct AddressToken {
+    /// As typed, minus surrounding commas and periods (`"N."` => `"N"`).
+    raw:        String,
+    /// Lowercased with punctuation folded, as used for name matching.
+    normalized: String,
+}
+
+/// Splits free text like `"1600 N. Charles St, Baltimore MD 21201"` into
+/// tokens. Empty pieces (stray punctuation) are dropped.
+pub fn tokenize_free_text_address(text: &str) -> Vec<AddressToken> {
+    text.split(|c: char| c.is_whitespace() || c == ',' || c == ';')
+        .filter_map(|piece| {
+            let raw = piece.trim_matches(|c: char| c == '.' || c == ',');
+            let normalized = normalize(raw);
+            if normalized.is_empty() {
+                None
+            } else {
+                Some(AddressToken { raw: raw.to_string(), normalized })
+            }
+        })
+        .collect()
+}
+
+/// Interprets a token as a house number: plain digits (`"1600"`) or digits
+/// with a single trailing letter (`"12A"`).
+pub fn parse_house_number_token(raw: &str) -> Option<HouseNumber> {
+    let digits_end = raw.find(|c: char| !c.is_ascii_digit()).unwrap_or(raw.len());
+    if digits_end == 0 {
+        return None;
+    }
+    let suffix = &raw[digits_end..];
+    if suffix.chars().count() > 1 || !suffix.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let number = raw[..digits_end].parse().ok()?;
+    Some(HouseNumber::new(number, suffix.chars().next()))
+}
+
+/// Every token span that forms a valid postal code for `country`, as
+/// `(first_token, last_token, code)`. Single tokens and adjacent pairs are
+/// tried so that formats like `"K1A 0B1"` or `"SW1A 1AA"` are found; the
+/// country's postal-code regex decides what counts.
+pub fn detect_postal_code_spans(
+    tokens:  &[AddressToken],
+    country: Country,
+) -> Vec<(usize, usize, PostalCode)> {
+    let mut spans = Vec::new();
+    for i in 0..tokens.len() {
+        let single = tokens[i].raw().to_uppercase();
+        if let Ok(pc) = PostalCode::new(country, &single) {
+            spans.push((i, i, pc));
+        }
+        if let Some(next) = tokens.get(i + 1) {
+            let pair = format!("{} {}", single, next.raw().to_uppercase());
+            if let Ok(pc) = PostalCode::new(country, &pair) {
+                spans.push((i, i + 1, pc));
+            }
+        }
+    }
+    spans
+}
+
+/// Drops tokens naming the region (`"MD"`, matched as `region_abbr`) where
+/// a state sits in an address: next to a postal code or at the very end.
+/// Elsewhere the same letters are ordinary words (`"Walk In Way"`,
+/// `"Co Rd 12"`) and are kept.
+pub fn drop_region_abbreviation(
+    tokens:      Vec<AddressToken>,
+    region_abbr: &str,
+    country:     Country,
+) -> Vec<AddressToken> {
+    let spans = detect_postal_code_spans(&tokens, country);
+    let in_span = |i: usize| spans.iter().any(|(a, b, _)| (*a..=*b).contains(&i));
+    let next_to_postal_code = |i: usize| {
+        spans.iter().any(|(a, b, _)| *a == i + 1 || *b + 1 == i)
+    };
+    let last = tokens.len().saturating_sub(1);
+
+    tokens
+        .into_iter()
+        .enumerate()
+        .filter(|(i, t)| {
+            let state_position = *i == last || next_to_postal_code(*i);
+            !(*t.normalized() == region_abbr && state_position && !in_span(*i))
+        })
+        .map(|(_, t)| t)
+        .collect()
+}
+
+#[cfg(test)]
+mod free_text_address_tokens_tests {
+    use super::*;
+
+    fn raws(tokens: &[AddressToken]) -> Vec<&str> {
+        tokens.iter().map(|t| t.raw().as_str()).collect()
+    }
+
+    #[traced_test]
+    fn test_tokenize_strips_commas_and_periods() {
+        let tokens = tokenize_free_text_address("1600 N. Charles St, Baltimore MD 21201");
+        assert_eq!(raws(&tokens), vec!["1600", "N", "Charles", "St", "Baltimore", "MD", "21201"]);
+        assert_eq!(tokens[2].normalized(), "charles");
+    }
+
+    #[traced_test]
+    fn test_tokenize_drops_empty_pieces() {
+        let tokens = tokenize_free_text_address("  ,, Main   St , . ");
+        assert_eq!(raws(&tokens), vec!["Main", "St"]);
+    }
+
+    #[traced_test]
+    fn test_parse_house_number_token() {
+        assert_eq!(parse_house_number_token("1600"), Some(HouseNumber::from(1600)));
+        assert_eq!(parse_house_number_token("12A"), Some(HouseNumber::new(12, Some('A'))));
+        assert_eq!(parse_house_number_token("12AB"), None);
+        assert_eq!(parse_house_number_token("5th"), None);
+        assert_eq!(parse_house_number_token("Main"), None);
+    }
+
+    #[traced_test]
+    fn test_detect_us_zip_including_plus_four() {
+        let tokens = tokenize_free_text_address("100 Main St Rockville 20850-1234");
+        let spans = detect_postal_code_spans(&tokens, Country::USA);
+        assert_eq!(spans.len(), 1);
+        assert_eq!((spans[0].0, spans[0].1), (4, 4));
+        assert_eq!(spans[0].2.code(), "20850-1234");
+    }
+
+    #[traced_test]
+    fn test_detect_two_token_canadian_code() {
+        let tokens = tokenize_free_text_address("24 Sussex Dr Ottawa ON k1a 0b1");
+        let spans = detect_postal_code_spans(&tokens, Country::Canada);
+        assert!(spans.iter().any(|(a, b, pc)| (*a, *b) == (5, 6) && pc.code() == "K1A 0B1"));
+    }
+
+    #[traced_test]
+    fn test_region_abbreviation_is_dropped_only_in_state_position() {
+        let tokens = tokenize_free_text_address("12 Walk In Way, Indianapolis IN 46204");
+        assert_eq!(
+            raws(&drop_region_abbreviation(tokens, "in", Country::USA)),
+            vec!["12", "Walk", "In", "Way", "Indianapolis", "46204"]
+        );
+
+        let tokens = tokenize_free_text_address("46204 IN");
+        assert_eq!(raws(&drop_region_abbreviation(tokens, "in", Country::USA)), vec!["46204"]);
+
+        let tokens = tokenize_free_text_address("1 Or Street, Portland, OR");
+        assert_eq!(
+            raws(&drop_region_abbreviation(tokens, "or", Country::USA)),
+            vec!["1", "Or", "Street", "Portland"]
+        );
+    }
+}
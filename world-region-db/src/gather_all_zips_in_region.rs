@@ -13,27 +13,19 @@ impl<I:StorageInterface> GatherAllZipsInRegion for DataAccess<I> {
     // -----------------------------------------------------------
     //
     // This iterates over the DB keys that start with `Z2C:<region_abbr>:` 
-    // and extracts the postal code substring from each key. 
-    // If the code is valid, we add it to a Vec.
+    // and decodes the postal code from each key, in the region's country.
     //
     fn gather_all_zips_in_region(&self, region: &WorldRegion) -> Vec<PostalCode> {
-        let prefix = format!("Z2C:{}:", region.abbreviation());
+        let prefix = DbKeyFamily::PostalCodeCities.region_prefix(region);
         match self.db().lock() {
             Ok(db_guard) => {
                 let iter = db_guard.prefix_iterator(prefix.as_bytes());
                 let mut out = Vec::new();
-                for kv in iter {
-                    if let Ok((key_bytes, _val_bytes)) = kv {
-                        let key_str = String::from_utf8_lossy(&key_bytes);
-                        // e.g. "Z2C:US:21201"
-                        let parts: Vec<&str> = key_str.splitn(3, ':').collect();
-                        if parts.len() < 3 {
-                            continue;
-                        }
-                        let zip_str = parts[2];
-                        if let Ok(pc) = PostalCode::new(Country::USA, zip_str) {
-                            out.push(pc);
-                        }
+                for (key_bytes, _val_bytes) in iter.flatten() {
+                    let key_str = String::from_utf8_lossy(&key_bytes);
+                    // e.g. "Z2C:MD:21201"
+                    if let Ok(DbKey::PostalCodeCities { postal_code, .. }) = DbKey::decode(&key_str) {
+                        out.push(postal_code);
                     }
                 }
                 out
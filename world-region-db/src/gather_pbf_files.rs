@@ -10,7 +10,7 @@ crate::ix!();
 pub fn gather_pbf_files(pbf_dir: &Path) -> Result<Vec<PathBuf>, OsmPbfParseError> {
     trace!("gather_pbf_files: scanning directory {:?}", pbf_dir);
     let entries = std::fs::read_dir(pbf_dir)
-        .map_err(|io_err| OsmPbfParseError::IoError(io_err))?;
+        .map_err(OsmPbfParseError::IoError)?;
 
     let mut pbf_files = Vec::new();
     for entry_result in entries {
@@ -39,7 +39,7 @@ mod gather_pbf_files_tests {
     async fn create_fake_pbf_file(path: &std::path::Path) {
         let mut file = File::create(path).await.expect("failed to create file");
         // Write minimal or even empty data, just so the file exists
-        file.write_all(b"fake pbf data");
+        file.write_all(b"fake pbf data").await.expect("failed to write file");
     }
 
     #[traced_test]
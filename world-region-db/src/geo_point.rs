@@ -0,0 +1,130 @@
+// ---------------- [ File: src/geo_point.rs ]
+crate::ix!();
+
+/// Mean earth radius in meters, used for great-circle distances.
+pub const EARTH_RADIUS_METERS: f64 = 6_371_008.8;
+
+/// A WGS84 coordinate stored as fixed-point integers (1e-7 degrees, the
+/// same resolution OSM uses). Keeping the representation integral lets
+/// [`AddressRecord`] and [`LocatedAddress`] stay `Eq`/`Ord`.
+#[derive(Copy,Clone,Debug,Hash,PartialEq,Eq,PartialOrd,Ord,Serialize,Deserialize,Getters)]
+#[getset(get="pub")]
+pub struct GeoPoint {
+    lat_e7: i32,
+    lon_e7: i32,
+}
+
+impl fmt::Display for GeoPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({:.7}, {:.7})", self.lat(), self.lon())
+    }
+}
+
+impl GeoPoint {
+
+    /// Builds a point from decimal degrees, rejecting anything outside
+    /// the valid latitude/longitude ranges (or NaN).
+    pub fn from_degrees(lat: f64, lon: f64) -> Result<Self, GeoPointError> {
+        if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&lon) {
+            return Err(GeoPointError::InvalidCoordinates { lat, lon });
+        }
+        Ok(Self {
+            lat_e7: (lat * 1e7).round() as i32,
+            lon_e7: (lon * 1e7).round() as i32,
+        })
+    }
+
+    /// Builds a point straight from fixed-point coordinates, as already
+    /// validated by an earlier [`from_degrees`](Self::from_degrees).
+    pub fn from_e7(lat_e7: i32, lon_e7: i32) -> Self {
+        Self { lat_e7, lon_e7 }
+    }
+
+    pub fn lat(&self) -> f64 {
+        self.lat_e7 as f64 / 1e7
+    }
+
+    pub fn lon(&self) -> f64 {
+        self.lon_e7 as f64 / 1e7
+    }
+
+    /// Great-circle (haversine) distance to `other`, in meters.
+    pub fn distance_meters(&self, other: &GeoPoint) -> f64 {
+        let (lat1, lat2) = (self.lat().to_radians(), other.lat().to_radians());
+        let dlat = lat2 - lat1;
+        let dlon = (other.lon() - self.lon()).to_radians();
+
+        let a = (dlat / 2.0).sin().powi(2)
+            + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+
+        2.0 * EARTH_RADIUS_METERS * a.sqrt().atan2((1.0 - a).sqrt())
+    }
+
+    /// Arithmetic mean of the given points. Good enough for the building
+    /// outlines and short ways we compute centroids for; returns `None`
+    /// when `points` is empty.
+    pub fn centroid(points: &[GeoPoint]) -> Option<GeoPoint> {
+        if points.is_empty() {
+            return None;
+        }
+        let n = points.len() as i64;
+        let lat_sum: i64 = points.iter().map(|p| p.lat_e7 as i64).sum();
+        let lon_sum: i64 = points.iter().map(|p| p.lon_e7 as i64).sum();
+        Some(GeoPoint {
+            lat_e7: (lat_sum / n) as i32,
+            lon_e7: (lon_sum / n) as i32,
+        })
+    }
+}
+
+/// The coordinate carried directly by an OSM element. Only nodes have one;
+/// ways are resolved to a centroid afterwards (see [`PendingWayCentroids`]).
+pub fn element_location(element: &osmpbf::Element) -> Option<GeoPoint> {
+    match element {
+        osmpbf::Element::Node(node)       => GeoPoint::from_degrees(node.lat(), node.lon()).ok(),
+        osmpbf::Element::DenseNode(dense) => GeoPoint::from_degrees(dense.lat(), dense.lon()).ok(),
+        osmpbf::Element::Way(_)           => None,
+        osmpbf::Element::Relation(_)      => None,
+    }
+}
+
+#[cfg(test)]
+mod geo_point_tests {
+    use super::*;
+
+    #[traced_test]
+    fn test_from_degrees_round_trip() {
+        let p = GeoPoint::from_degrees(39.2903848, -76.6121893).unwrap();
+        assert!((p.lat() - 39.2903848).abs() < 1e-7);
+        assert!((p.lon() - -76.6121893).abs() < 1e-7);
+    }
+
+    #[traced_test]
+    fn test_from_degrees_rejects_out_of_range() {
+        assert!(GeoPoint::from_degrees(91.0, 0.0).is_err());
+        assert!(GeoPoint::from_degrees(0.0, -180.5).is_err());
+        assert!(GeoPoint::from_degrees(f64::NAN, 0.0).is_err());
+    }
+
+    #[traced_test]
+    fn test_distance_baltimore_to_washington() {
+        let baltimore  = GeoPoint::from_degrees(39.2904, -76.6122).unwrap();
+        let washington = GeoPoint::from_degrees(38.9072, -77.0369).unwrap();
+        let d = baltimore.distance_meters(&washington);
+        // roughly 56km apart
+        assert!((55_000.0..58_000.0).contains(&d), "unexpected distance {}", d);
+        assert_eq!(baltimore.distance_meters(&baltimore), 0.0);
+    }
+
+    #[traced_test]
+    fn test_centroid() {
+        let pts = vec![
+            GeoPoint::from_degrees(39.0, -76.0).unwrap(),
+            GeoPoint::from_degrees(39.2, -76.2).unwrap(),
+        ];
+        let c = GeoPoint::centroid(&pts).unwrap();
+        assert!((c.lat() - 39.1).abs() < 1e-7);
+        assert!((c.lon() - -76.1).abs() < 1e-7);
+        assert!(GeoPoint::centroid(&[]).is_none());
+    }
+}
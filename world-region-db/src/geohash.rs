@@ -0,0 +1,194 @@
+// ---------------- [ File: src/geohash.rs ]
+crate::ix!();
+
+const GEOHASH_BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// Precision (in characters) of the geohash cells used for `GEO:` keys.
+/// Nine characters is roughly a 5m x 5m cell.
+pub const GEO_INDEX_PRECISION: usize = 9;
+
+/// Precision of the cells boundaries are indexed by (`BNDX:` keys).
+/// Four characters is roughly a 39km x 20km cell, so a county spans a
+/// handful of cells and a city one or two.
+pub const BOUNDARY_INDEX_PRECISION: usize = 4;
+
+/// Encodes `point` as a geohash string of `precision` characters.
+pub fn encode_geohash(point: &GeoPoint, precision: usize) -> String {
+    let (mut lat_lo, mut lat_hi) = (-90.0_f64, 90.0_f64);
+    let (mut lon_lo, mut lon_hi) = (-180.0_f64, 180.0_f64);
+
+    let mut out     = String::with_capacity(precision);
+    let mut even    = true;
+    let mut bit     = 0;
+    let mut ch: u8  = 0;
+
+    while out.len() < precision {
+        if even {
+            let mid = (lon_lo + lon_hi) / 2.0;
+            if point.lon() >= mid {
+                ch = (ch << 1) | 1;
+                lon_lo = mid;
+            } else {
+                ch <<= 1;
+                lon_hi = mid;
+            }
+        } else {
+            let mid = (lat_lo + lat_hi) / 2.0;
+            if point.lat() >= mid {
+                ch = (ch << 1) | 1;
+                lat_lo = mid;
+            } else {
+                ch <<= 1;
+                lat_hi = mid;
+            }
+        }
+        even = !even;
+        bit += 1;
+
+        if bit == 5 {
+            out.push(GEOHASH_BASE32[ch as usize] as char);
+            bit = 0;
+            ch  = 0;
+        }
+    }
+    out
+}
+
+/// Returns `(min_lat, max_lat, min_lon, max_lon)` for a geohash cell,
+/// or `None` if `hash` contains characters outside the geohash alphabet.
+pub fn geohash_bounds(hash: &str) -> Option<(f64, f64, f64, f64)> {
+    let (mut lat_lo, mut lat_hi) = (-90.0_f64, 90.0_f64);
+    let (mut lon_lo, mut lon_hi) = (-180.0_f64, 180.0_f64);
+    let mut even = true;
+
+    for c in hash.bytes() {
+        let idx = GEOHASH_BASE32.iter().position(|&b| b == c)?;
+        for shift in (0..5).rev() {
+            let on = (idx >> shift) & 1 == 1;
+            if even {
+                let mid = (lon_lo + lon_hi) / 2.0;
+                if on { lon_lo = mid } else { lon_hi = mid }
+            } else {
+                let mid = (lat_lo + lat_hi) / 2.0;
+                if on { lat_lo = mid } else { lat_hi = mid }
+            }
+            even = !even;
+        }
+    }
+    Some((lat_lo, lat_hi, lon_lo, lon_hi))
+}
+
+/// The geohash cell containing `point` plus its (up to) eight neighbours,
+/// all at `precision`. Scanning these cells covers every point within one
+/// cell-width of `point`, regardless of where inside its cell it falls.
+pub fn geohash_neighborhood(point: &GeoPoint, precision: usize) -> Vec<String> {
+    let center = encode_geohash(point, precision);
+    let (lat_lo, lat_hi, lon_lo, lon_hi) = geohash_bounds(&center)
+        .expect("encode_geohash only emits valid characters");
+
+    let height  = lat_hi - lat_lo;
+    let width   = lon_hi - lon_lo;
+    let mid_lat = (lat_lo + lat_hi) / 2.0;
+    let mid_lon = (lon_lo + lon_hi) / 2.0;
+
+    let mut cells = vec![center];
+    for dlat in [-1.0, 0.0, 1.0] {
+        for dlon in [-1.0, 0.0, 1.0] {
+            let lat = mid_lat + dlat * height;
+            if !(-90.0..=90.0).contains(&lat) {
+                continue;
+            }
+            let mut lon = mid_lon + dlon * width;
+            if lon > 180.0 {
+                lon -= 360.0;
+            } else if lon < -180.0 {
+                lon += 360.0;
+            }
+            if let Ok(p) = GeoPoint::from_degrees(lat, lon) {
+                let cell = encode_geohash(&p, precision);
+                if !cells.contains(&cell) {
+                    cells.push(cell);
+                }
+            }
+        }
+    }
+    cells
+}
+
+/// Every geohash cell at `precision` that overlaps the box with corners
+/// `sw` and `ne`, row by row from the south-west.
+pub fn geohash_cells_covering(sw: &GeoPoint, ne: &GeoPoint, precision: usize) -> Vec<String> {
+    let (lat_lo, lat_hi, lon_lo, lon_hi) = geohash_bounds(&encode_geohash(sw, precision))
+        .expect("encode_geohash only emits valid characters");
+
+    let height = lat_hi - lat_lo;
+    let width  = lon_hi - lon_lo;
+
+    let mut cells = Vec::new();
+    let mut row_lo = lat_lo;
+    while row_lo <= ne.lat() {
+        let lat = (row_lo + height / 2.0).min(90.0);
+        let mut col_lo = lon_lo;
+        while col_lo <= ne.lon() {
+            let lon = (col_lo + width / 2.0).min(180.0);
+            if let Ok(p) = GeoPoint::from_degrees(lat, lon) {
+                cells.push(encode_geohash(&p, precision));
+            }
+            col_lo += width;
+        }
+        row_lo += height;
+    }
+    cells
+}
+
+#[cfg(test)]
+mod geohash_tests {
+    use super::*;
+
+    fn pt(lat: f64, lon: f64) -> GeoPoint {
+        GeoPoint::from_degrees(lat, lon).unwrap()
+    }
+
+    #[traced_test]
+    fn test_encode_known_values() {
+        assert_eq!(encode_geohash(&pt(57.64911, 10.40744), 11), "u4pruydqqvj");
+        assert_eq!(encode_geohash(&pt(42.6, -5.6), 5), "ezs42");
+    }
+
+    #[traced_test]
+    fn test_bounds_contain_encoded_point() {
+        let p = pt(39.2904, -76.6122);
+        let hash = encode_geohash(&p, 7);
+        let (lat_lo, lat_hi, lon_lo, lon_hi) = geohash_bounds(&hash).unwrap();
+        assert!(lat_lo <= p.lat() && p.lat() <= lat_hi);
+        assert!(lon_lo <= p.lon() && p.lon() <= lon_hi);
+        assert!(geohash_bounds("not-a-hash").is_none());
+    }
+
+    #[traced_test]
+    fn test_neighborhood_has_nine_distinct_cells() {
+        let cells = geohash_neighborhood(&pt(39.2904, -76.6122), 6);
+        assert_eq!(cells.len(), 9);
+        assert_eq!(cells[0], encode_geohash(&pt(39.2904, -76.6122), 6));
+        assert!(cells.iter().all(|c| c.len() == 6));
+    }
+
+    #[traced_test]
+    fn test_neighborhood_at_pole_skips_missing_rows() {
+        let cells = geohash_neighborhood(&pt(89.99, 0.0), 3);
+        assert_eq!(cells.len(), 6);
+    }
+
+    #[traced_test]
+    fn test_cells_covering_box_include_both_corners() {
+        let (sw, ne) = (pt(39.0, -77.0), pt(40.0, -76.0));
+        let cells = geohash_cells_covering(&sw, &ne, 4);
+        assert!(cells.contains(&encode_geohash(&sw, 4)));
+        assert!(cells.contains(&encode_geohash(&ne, 4)));
+        assert!(cells.contains(&encode_geohash(&pt(39.5, -76.5), 4)));
+        assert!(!cells.contains(&encode_geohash(&pt(41.0, -76.5), 4)));
+
+        let p = pt(39.2904, -76.6122);
+        assert_eq!(geohash_cells_covering(&p, &p, 4), vec![encode_geohash(&p, 4)]);
+    }
+}
@@ -21,22 +21,7 @@ impl<I:StorageInterface> GetCborSetTyped for DataAccess<I> {
         T: Serialize + DeserializeOwned + Ord,
     {
         match self.db().lock() {
-            Ok(db_guard) => {
-                let val = match db_guard.get(key) {
-                    Ok(opt) => opt,
-                    Err(e) => {
-                        warn!("DB get error for key {}: {}", key, e);
-                        return None;
-                    }
-                };
-                let bytes = val?;
-                let list: Vec<T> = decompress_cbor_to_list(&bytes);
-                if list.is_empty() {
-                    None
-                } else {
-                    Some(list.into_iter().collect())
-                }
-            }
+            Ok(db_guard) => (*db_guard).get_cbor_set_typed(key),
             Err(_) => {
                 warn!("Could not get DB lock for key: {}", key);
                 None
@@ -45,6 +30,31 @@ impl<I:StorageInterface> GetCborSetTyped for DataAccess<I> {
     }
 }
 
+/// Reads straight from the store, without a lock, so every query trait built
+/// on [`GetCborSetTyped`] also works on a shared `&Database`.
+impl<D:DatabaseGet> GetCborSetTyped for D {
+
+    fn get_cbor_set_typed<T>(&self, key: &str) -> Option<BTreeSet<T>>
+    where
+        T: Serialize + DeserializeOwned + Ord,
+    {
+        let val = match self.get(key) {
+            Ok(opt) => opt,
+            Err(e) => {
+                warn!("DB get error for key {}: {}", key, e);
+                return None;
+            }
+        };
+        let bytes = val?;
+        let list: Vec<T> = decompress_cbor_to_list(&bytes);
+        if list.is_empty() {
+            None
+        } else {
+            Some(list.into_iter().collect())
+        }
+    }
+}
+
 #[cfg(test)]
 mod get_cbor_set_typed_tests {
     use super::*;
@@ -206,4 +216,20 @@ mod get_cbor_set_typed_tests {
         assert!(s.contains("a"));
         assert!(s.contains("b"));
     }
+
+    #[traced_test]
+    fn test_get_cbor_set_typed_reads_store_without_lock() {
+        // A plain store handle answers the same query, even while the
+        // DataAccess mutex is held elsewhere.
+        let (db_arc, _da, _tempdir) = create_db_and_da::<Database>();
+        let mut sset = BTreeSet::new();
+        sset.insert("hello".to_string());
+
+        let mut db_guard = db_arc.lock().unwrap();
+        db_guard.put("my_string_key", compress_set_to_cbor(&sset)).unwrap();
+        let store = db_guard.clone();
+
+        assert_eq!(store.get_cbor_set_typed::<String>("my_string_key"), Some(sset));
+        assert!(store.get_cbor_set_typed::<String>("NONEXISTENT").is_none());
+    }
 }
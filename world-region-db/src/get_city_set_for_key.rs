@@ -7,7 +7,7 @@ pub trait GetCitySetForKey {
     fn get_city_set(&self, key: &str) -> Option<BTreeSet<CityName>>;
 }
 
-impl<I:StorageInterface> GetCitySetForKey for DataAccess<I> {
+impl<D:GetCborSetTyped> GetCitySetForKey for D {
 
     fn get_city_set(&self, key: &str) -> Option<BTreeSet<CityName>> {
         self.get_cbor_set_typed::<CityName>(key)
@@ -56,7 +56,7 @@ mod get_iterator_traits_tests {
                 String::from_utf8_lossy(&v).to_string(),
             ));
         }
-        assert_eq!(found.len(), 5, "we inserted 5 key-value pairs");
+        assert_eq!(found.len(), 6, "we inserted 5 key-value pairs next to META:SCHEMA_VERSION");
     }
 
     /// Demonstrates *reverse iteration* using the raw iterator.
@@ -93,11 +93,12 @@ mod get_iterator_traits_tests {
             raw_iter.prev(); // move to the previous entry
         }
 
-        // Now reversed_pairs should contain all 5 pairs in descending key order
+        // Now reversed_pairs should contain all 5 pairs, plus the schema
+        // version, in descending key order
         assert_eq!(
             reversed_pairs.len(),
-            5,
-            "Expected to see all 5 key-value pairs in reverse order"
+            6,
+            "Expected to see all 6 key-value pairs in reverse order"
         );
 
         // For demonstration, let's see the last key we encountered
@@ -7,7 +7,7 @@ pub trait GetPostalCodeSetForKey {
     fn get_postal_code_set(&self, key: &str) -> Option<BTreeSet<PostalCode>>;
 }
 
-impl<I:StorageInterface> GetPostalCodeSetForKey for DataAccess<I> {
+impl<D:GetCborSetTyped> GetPostalCodeSetForKey for D {
 
     /// Returns a set of PostalCode objects for the given string key, if present.
     fn get_postal_code_set(&self, key: &str) -> Option<BTreeSet<PostalCode>> {
@@ -7,7 +7,7 @@ pub trait GetStreetSetForKey {
     fn get_street_set(&self, key: &str) -> Option<BTreeSet<StreetName>>;
 }
 
-impl<I:StorageInterface> GetStreetSetForKey for DataAccess<I> {
+impl<D:GetCborSetTyped> GetStreetSetForKey for D {
 
     /// Returns a set of StreetName objects for the given string key, if present.
     fn get_street_set(&self, key: &str) -> Option<BTreeSet<StreetName>> {
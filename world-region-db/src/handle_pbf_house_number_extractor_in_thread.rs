@@ -88,8 +88,8 @@ mod handle_pbf_house_number_extractor_in_thread_tests {
     /// Helper: set up a fresh real DB in a temp dir + sync_channel.
     fn setup_db_and_channel()
         -> (Arc<Mutex<Database>>,
-            SyncSender<Result<WorldAddress, OsmPbfParseError>>,
-            Receiver<Result<WorldAddress, OsmPbfParseError>>,
+            SyncSender<AddressStreamItem>,
+            Receiver<AddressStreamItem>,
             TempDir)
     {
         let tmp_dir = TempDir::new().expect("tempdir creation");
@@ -101,8 +101,8 @@ mod handle_pbf_house_number_extractor_in_thread_tests {
     /// Same, but returning a DB that fails on store.
     fn setup_failing_db_and_channel()
         -> (Arc<Mutex<FailingDb>>,
-            SyncSender<Result<WorldAddress, OsmPbfParseError>>,
-            Receiver<Result<WorldAddress, OsmPbfParseError>>,
+            SyncSender<AddressStreamItem>,
+            Receiver<AddressStreamItem>,
             TempDir)
     {
         let tmp_dir = TempDir::new().expect("tempdir creation");
@@ -214,9 +214,9 @@ mod handle_pbf_house_number_extractor_in_thread_tests {
         // aggregator won't store any house-number ranges => no more messages
         assert!(rx.try_recv().is_err(), "no more messages => aggregator done");
 
-        // confirm aggregator wrote no subranges => "HNR:MD:north ave" not present
+        // confirm aggregator wrote no subranges => "HNR:MD:n ave" not present
         let db_guard = db_arc.lock().unwrap();
-        let hnr_key = b"HNR:MD:north ave";
+        let hnr_key = b"HNR:MD:n ave";
         let existing = db_guard.get(hnr_key).unwrap();
         assert!(existing.is_none(), "No aggregator data => no DB entry for housenumbers");
     }
@@ -265,9 +265,9 @@ mod handle_pbf_house_number_extractor_in_thread_tests {
         // aggregator => [100..110] stored => no more items
         assert!(rx.try_recv().is_err(), "no more messages => aggregator done storing");
 
-        // confirm aggregator data in DB => "HNR:VA:catlett road"
+        // confirm aggregator data in DB => "HNR:VA:catlett rd" (canonical street form)
         let db_guard = db_arc.lock().unwrap();
-        let key = b"HNR:VA:catlett road";
+        let key = b"HNR:VA:catlett rd";
         let hnr_val_opt = db_guard.get(key).unwrap();
         assert!(hnr_val_opt.is_some(), "Should have aggregator data");
         
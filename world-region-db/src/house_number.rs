@@ -0,0 +1,129 @@
+// ---------------- [ File: src/house_number.rs ]
+crate::ix!();
+
+/// A single house number as written on the building: the numeric part and
+/// an optional one-letter suffix (`"12A"`, `"221 b"` => `221B`).
+///
+/// On the wire it is the written form (`"12A"`); a bare JSON number (`12`)
+/// is read too.
+#[derive(Getters,Debug,Clone,Copy,PartialEq,Eq,Hash,PartialOrd,Ord)]
+#[getset(get="pub")]
+pub struct HouseNumber {
+    number: u32,
+    suffix: Option<char>,
+}
+
+impl HouseNumber {
+
+    pub fn new(number: u32, suffix: Option<char>) -> Self {
+        Self { number, suffix: suffix.map(|c| c.to_ascii_uppercase()) }
+    }
+
+    /// Reads the leading digits and, if all that follows is a single
+    /// letter (optionally after a space), that letter as the suffix. Any
+    /// other trailing text (`"12 1/2"`, `"5bis"`) is ignored, as it always
+    /// has been for the numeric part. `None` if there are no leading digits.
+    pub fn parse(raw: &str) -> Option<Self> {
+        let s = raw.trim();
+        let digits_end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let number = s[..digits_end].parse::<u32>().ok()?;
+
+        let mut rest = s[digits_end..].trim().chars();
+        let suffix = match (rest.next(), rest.next()) {
+            (Some(c), None) if c.is_ascii_alphabetic() => Some(c),
+            _                                          => None,
+        };
+        Some(Self::new(number, suffix))
+    }
+}
+
+impl From<u32> for HouseNumber {
+    fn from(number: u32) -> Self {
+        Self::new(number, None)
+    }
+}
+
+impl fmt::Display for HouseNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.suffix {
+            Some(c) => write!(f, "{}{}", self.number, c),
+            None    => write!(f, "{}", self.number),
+        }
+    }
+}
+
+impl Serialize for HouseNumber {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for HouseNumber {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct HouseNumberVisitor;
+
+        impl serde::de::Visitor<'_> for HouseNumberVisitor {
+            type Value = HouseNumber;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a house number such as 12 or \"12A\"")
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<HouseNumber, E> {
+                u32::try_from(v)
+                    .map(HouseNumber::from)
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Unsigned(v), &self))
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<HouseNumber, E> {
+                u32::try_from(v)
+                    .map(HouseNumber::from)
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Signed(v), &self))
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<HouseNumber, E> {
+                HouseNumber::parse(v).ok_or_else(|| E::invalid_value(serde::de::Unexpected::Str(v), &self))
+            }
+        }
+
+        deserializer.deserialize_any(HouseNumberVisitor)
+    }
+}
+
+#[cfg(test)]
+mod house_number_tests {
+    use super::*;
+
+    #[traced_test]
+    fn test_parse_plain_and_suffixed() {
+        assert_eq!(HouseNumber::parse("12"),    Some(HouseNumber::new(12, None)));
+        assert_eq!(HouseNumber::parse("12A"),   Some(HouseNumber::new(12, Some('A'))));
+        assert_eq!(HouseNumber::parse(" 221 b"), Some(HouseNumber::new(221, Some('B'))));
+        assert_eq!(HouseNumber::parse("5bis"),  Some(HouseNumber::new(5, None)));
+        assert_eq!(HouseNumber::parse("B12"),   None);
+        assert_eq!(HouseNumber::parse(""),      None);
+    }
+
+    #[traced_test]
+    fn test_display_round_trips() {
+        for raw in ["7", "12A", "221B"] {
+            assert_eq!(HouseNumber::parse(raw).unwrap().to_string(), raw);
+        }
+    }
+
+    #[traced_test]
+    fn test_serde_reads_written_form_and_bare_numbers() {
+        assert_eq!(serde_json::from_str::<HouseNumber>("\"12a\"").unwrap(), HouseNumber::new(12, Some('A')));
+        assert_eq!(serde_json::from_str::<HouseNumber>("12").unwrap(), HouseNumber::from(12));
+        assert!(serde_json::from_str::<HouseNumber>("\"B12\"").is_err());
+        assert_eq!(serde_json::to_string(&HouseNumber::new(221, Some('B'))).unwrap(), "\"221B\"");
+    }
+
+    #[traced_test]
+    fn test_ordering_puts_suffixes_after_plain_number() {
+        let mut numbers = [HouseNumber::parse("12B").unwrap(), HouseNumber::parse("12").unwrap(), HouseNumber::parse("12A").unwrap()];
+        numbers.sort();
+        let shown: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+        assert_eq!(shown, vec!["12", "12A", "12B"]);
+    }
+}
@@ -26,7 +26,7 @@ impl HouseNumberAggregator {
     pub fn new(world_region: &WorldRegion) -> Self {
         trace!("HouseNumberAggregator::new => constructing empty aggregator");
         Self {
-            world_region: world_region.clone(),
+            world_region: *world_region,
             country: Country::try_from(*world_region)
                 .expect("expected a valid Country from WorldRegion"),
             map: HashMap::new(),
@@ -38,7 +38,7 @@ impl HouseNumberAggregator {
         map: HashMap<StreetName, Vec<HouseNumberRange>>
     ) -> Self {
         Self {
-            world_region: world_region.clone(),
+            world_region: *world_region,
             country: Country::try_from(*world_region)
                 .expect("expected a valid Country"),
             map,
@@ -68,17 +68,17 @@ impl HouseNumberAggregator {
     pub fn entry(
         &mut self,
         street: StreetName
-    ) -> std::collections::hash_map::Entry<StreetName, Vec<HouseNumberRange>> {
+    ) -> std::collections::hash_map::Entry<'_, StreetName, Vec<HouseNumberRange>> {
         self.map.entry(street)
     }
 
     /// Iterate all (StreetName, Vec<HouseNumberRange>) pairs.
-    pub fn iter(&self) -> std::collections::hash_map::Iter<StreetName, Vec<HouseNumberRange>> {
+    pub fn iter(&self) -> std::collections::hash_map::Iter<'_, StreetName, Vec<HouseNumberRange>> {
         self.map.iter()
     }
 
     /// Just the keys (i.e. the StreetNames).
-    pub fn keys(&self) -> std::collections::hash_map::Keys<StreetName, Vec<HouseNumberRange>> {
+    pub fn keys(&self) -> std::collections::hash_map::Keys<'_, StreetName, Vec<HouseNumberRange>> {
         self.map.keys()
     }
 
@@ -180,12 +180,11 @@ impl HouseNumberAggregator {
     fn try_infer_subrange_without_full_record(&mut self, element: Element) {
         match extract_house_number_range_from_element(&element) {
             Ok(Some(rng)) => {
-                if let Ok(partial_rec) = AddressRecord::try_from((&element, &self.country)) {
-                    if let Some(street) = partial_rec.street() {
-                        self.map.entry(street.clone())
-                            .or_default()
-                            .push(rng);
-                    }
+                if let Ok(partial_rec) = AddressRecord::try_from((&element, &self.country))
+                    && let Some(street) = partial_rec.street() {
+                    self.map.entry(street.clone())
+                        .or_default()
+                        .push(rng);
                 }
             }
             Ok(None) => { /* no subrange => skip */ }
@@ -454,7 +453,7 @@ mod house_number_aggregator_tests {
     fn test_store_aggregator_results_empty() {
 
         let region         = example_region();
-        let mut aggregator = HouseNumberAggregator::new(&region);
+        let aggregator = HouseNumberAggregator::new(&region);
         let tmp_dir        = TempDir::new().unwrap();
         let db             = Database::open(tmp_dir.path()).unwrap();
         let mut db_guard   = db.lock().unwrap();
@@ -5,9 +5,9 @@ pub trait HouseNumberInAnyRange {
 
     fn house_number_in_any_range(
         &self,
-        region:      &WorldRegion,
-        street:      &StreetName,
-        house_num:   u32,
+        region:       &WorldRegion,
+        street:       &StreetName,
+        house_number: &HouseNumber,
     ) -> Result<bool, DataAccessError>;
 }
 
@@ -17,14 +17,14 @@ impl HouseNumberInAnyRange for Database {
     /// in any of the sub-ranges for a region+street.
     fn house_number_in_any_range(
         &self,
-        region:      &WorldRegion,
-        street:      &StreetName,
-        house_num:   u32,
+        region:       &WorldRegion,
+        street:       &StreetName,
+        house_number: &HouseNumber,
     ) -> Result<bool, DataAccessError> {
 
         if let Some(ranges) = self.load_house_number_ranges(region, street)? {
             for rng in ranges {
-                if rng.contains(house_num) {
+                if rng.contains(house_number) {
                     return Ok(true);
                 }
             }
@@ -76,7 +76,7 @@ mod house_number_in_any_range_tests {
         let region = example_region();
         let street = StreetName::new("Imaginary Road").unwrap();
         
-        let result = db_guard.house_number_in_any_range(&region, &street, 123);
+        let result = db_guard.house_number_in_any_range(&region, &street, &HouseNumber::from(123));
         assert!(result.is_ok());
         let in_range = result.unwrap();
         assert!(!in_range, "No data => definitely false");
@@ -97,7 +97,7 @@ mod house_number_in_any_range_tests {
             let guard = db_arc.lock().unwrap();
             let region = example_region();
             let street = StreetName::new("Empty Road").unwrap();
-            let result = guard.house_number_in_any_range(&region, &street, 50);
+            let result = guard.house_number_in_any_range(&region, &street, &HouseNumber::from(50));
             assert!(result.is_ok());
             assert!(!result.unwrap(), "No sub-ranges => always false");
         }
@@ -118,8 +118,8 @@ mod house_number_in_any_range_tests {
 
         {
             let guard = db_arc.lock().unwrap();
-            assert!(!guard.house_number_in_any_range(&region, &street, 99).unwrap());
-            assert!(!guard.house_number_in_any_range(&region, &street, 111).unwrap());
+            assert!(!guard.house_number_in_any_range(&region, &street, &HouseNumber::from(99)).unwrap());
+            assert!(!guard.house_number_in_any_range(&region, &street, &HouseNumber::from(111)).unwrap());
         }
     }
 
@@ -138,9 +138,9 @@ mod house_number_in_any_range_tests {
 
         {
             let guard = db_arc.lock().unwrap();
-            assert!(guard.house_number_in_any_range(&region, &street, 100).unwrap());
-            assert!(guard.house_number_in_any_range(&region, &street, 105).unwrap());
-            assert!(guard.house_number_in_any_range(&region, &street, 110).unwrap());
+            assert!(guard.house_number_in_any_range(&region, &street, &HouseNumber::from(100)).unwrap());
+            assert!(guard.house_number_in_any_range(&region, &street, &HouseNumber::from(105)).unwrap());
+            assert!(guard.house_number_in_any_range(&region, &street, &HouseNumber::from(110)).unwrap());
         }
     }
 
@@ -165,11 +165,11 @@ mod house_number_in_any_range_tests {
         {
             let guard = db_arc.lock().unwrap();
             // in first range
-            assert!(guard.house_number_in_any_range(&region, &street, 5).unwrap());
+            assert!(guard.house_number_in_any_range(&region, &street, &HouseNumber::from(5)).unwrap());
             // between first and second => false
-            assert!(!guard.house_number_in_any_range(&region, &street, 15).unwrap());
+            assert!(!guard.house_number_in_any_range(&region, &street, &HouseNumber::from(15)).unwrap());
             // in third range
-            assert!(guard.house_number_in_any_range(&region, &street, 45).unwrap());
+            assert!(guard.house_number_in_any_range(&region, &street, &HouseNumber::from(45)).unwrap());
         }
     }
 
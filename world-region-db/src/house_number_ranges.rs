@@ -2,15 +2,54 @@
 // [ File: src/house_number_ranges.rs ]
 crate::ix!();
 
+/// Which house numbers inside `start..=end` a range covers. Streets are
+/// usually numbered odd on one side and even on the other, so one side is
+/// stored as `Odd` or `Even` rather than as every number in between.
+#[derive(Debug,Default,Clone,Copy,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub enum HouseNumberParity {
+    #[default]
+    All,
+    Odd,
+    Even,
+}
+
+impl HouseNumberParity {
+
+    pub fn matches(&self, house_num: u32) -> bool {
+        match self {
+            HouseNumberParity::All  => true,
+            HouseNumberParity::Odd  => house_num % 2 == 1,
+            HouseNumberParity::Even => house_num.is_multiple_of(2),
+        }
+    }
+
+    /// Distance between consecutive numbers of this parity.
+    pub fn step(&self) -> u32 {
+        match self {
+            HouseNumberParity::All => 1,
+            _                      => 2,
+        }
+    }
+}
+
+/// Side of the street a range lies on, relative to the street's drawing
+/// direction. Interpolated ranges take it from the street's line (see
+/// [`StreetGeometry`]); single tagged numbers stay `Unknown`.
+#[derive(Debug,Default,Clone,Copy,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub enum StreetSide {
+    #[default]
+    Unknown,
+    Left,
+    Right,
+}
+
 /// Represents a range of house numbers, e.g. from `start` up to `end` inclusive.
 /// For instance, (1..=100), or (140..=260). 
 ///
-/// In production you might also store:
-///   - a "step" for even/odd-only sequences,
-///   - explicit "skipped" sets,
-///   - or subdivide partial ranges. 
-/// This example keeps it simple: if you skip 101..139, 
-/// just store multiple disjoint ranges. 
+/// A range may be restricted to odd or even numbers (`parity`) and tagged
+/// with the street side it lies on. Lettered numbers (`12A..=12F`) are a
+/// range with `start == end` and the suffix span in `letters`.
+/// If you skip 101..139, just store multiple disjoint ranges.
 #[derive(Getters,Setters,Clone,Serialize,Deserialize,PartialEq,Eq)]
 #[getset(get="pub",set="pub")]
 pub struct HouseNumberRange {
@@ -18,11 +57,31 @@ pub struct HouseNumberRange {
     start: u32,
     /// last house number in the sub-range (inclusive)
     end:   u32,
+
+    #[serde(default)]
+    parity:  HouseNumberParity,
+
+    #[serde(default)]
+    side:    StreetSide,
+
+    /// inclusive suffix span, only meaningful when `start == end`
+    #[serde(default)]
+    letters: Option<(char,char)>,
 }
 
 impl fmt::Debug for HouseNumberRange {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "[{}..={}]", self.start(), self.end())
+        match self.letters {
+            Some((a, b)) => write!(f, "[{}{}..={}{}]", self.start, a, self.end, b)?,
+            None         => write!(f, "[{}..={}]", self.start(), self.end())?,
+        }
+        if self.parity != HouseNumberParity::All {
+            write!(f, " {:?}", self.parity)?;
+        }
+        if self.side != StreetSide::Unknown {
+            write!(f, " {:?}", self.side)?;
+        }
+        Ok(())
     }
 }
 
@@ -33,12 +92,49 @@ impl HouseNumberRange {
         Self {
             start,
             end,
+            parity:  HouseNumberParity::All,
+            side:    StreetSide::Unknown,
+            letters: None,
         }
     }
 
-    /// Checks whether a given `house_num` is contained in this sub-range.
-    pub fn contains(&self, house_num: u32) -> bool {
-        house_num >= self.start && house_num <= self.end
+    pub fn with_parity(start: u32, end: u32, parity: HouseNumberParity) -> Self {
+        Self { parity, ..Self::new(start, end) }
+    }
+
+    /// `number` with every suffix from `first` to `last`, e.g. `12A..=12F`.
+    pub fn lettered(number: u32, first: char, last: char) -> Self {
+        let (a, b) = (first.to_ascii_uppercase(), last.to_ascii_uppercase());
+        Self { letters: Some((a.min(b), a.max(b))), ..Self::new(number, number) }
+    }
+
+    /// The range holding exactly `house_number`, suffix included.
+    pub fn single(house_number: &HouseNumber) -> Self {
+        match house_number.suffix() {
+            Some(c) => Self::lettered(*house_number.number(), *c, *c),
+            None    => Self::new(*house_number.number(), *house_number.number()),
+        }
+    }
+
+    pub fn with_side(mut self, side: StreetSide) -> Self {
+        self.side = side;
+        self
+    }
+
+    /// Checks whether `house_number` is covered by this sub-range. A lettered
+    /// range only holds numbers with a suffix inside its span, so `12` is not
+    /// in `12A..=12F`. Unlettered ranges accept any suffix, since OSM rarely
+    /// lists every `12A` next to `12`.
+    pub fn contains(&self, house_number: &HouseNumber) -> bool {
+        let number = *house_number.number();
+        if number < self.start || number > self.end || !self.parity.matches(number) {
+            return false;
+        }
+        match (self.letters, house_number.suffix()) {
+            (None, _)               => true,
+            (Some((a, b)), Some(c)) => (a..=b).contains(c),
+            (Some(_), None)         => false,
+        }
     }
 }
 
@@ -74,9 +170,9 @@ mod house_number_range_storage_tests {
 
         // 3) define some sub-ranges
         let ranges = vec![
-            HouseNumberRange { start: 1,   end: 100 },
-            HouseNumberRange { start: 140, end: 260 },
-            HouseNumberRange { start: 300, end: 400 },
+            HouseNumberRange::new(1, 100),
+            HouseNumberRange::new(140, 260),
+            HouseNumberRange::new(300, 400),
         ];
 
         // 4) store them
@@ -100,19 +196,19 @@ mod house_number_range_storage_tests {
         let region = region_maryland();
         let street = make_street("North Avenue");
         let ranges = vec![
-            HouseNumberRange { start: 1,   end: 100 },
-            HouseNumberRange { start: 140, end: 260 },
-            HouseNumberRange { start: 300, end: 400 },
+            HouseNumberRange::new(1, 100),
+            HouseNumberRange::new(140, 260),
+            HouseNumberRange::new(300, 400),
         ];
 
-        let _ = db.store_house_number_ranges(&region, &street, &ranges).unwrap();
+        db.store_house_number_ranges(&region, &street, &ranges).unwrap();
 
         // check some values
-        assert!(db.house_number_in_any_range(&region, &street, 1).unwrap());    // in [1..100]
-        assert!(!db.house_number_in_any_range(&region, &street, 120).unwrap()); // missing 101..139
-        assert!(db.house_number_in_any_range(&region, &street, 200).unwrap());  // in [140..260]
-        assert!(db.house_number_in_any_range(&region, &street, 399).unwrap());  // in [300..400]
-        assert!(!db.house_number_in_any_range(&region, &street, 999).unwrap());
+        assert!(db.house_number_in_any_range(&region, &street, &HouseNumber::from(1)).unwrap());    // in [1..100]
+        assert!(!db.house_number_in_any_range(&region, &street, &HouseNumber::from(120)).unwrap()); // missing 101..139
+        assert!(db.house_number_in_any_range(&region, &street, &HouseNumber::from(200)).unwrap());  // in [140..260]
+        assert!(db.house_number_in_any_range(&region, &street, &HouseNumber::from(399)).unwrap());  // in [300..400]
+        assert!(!db.house_number_in_any_range(&region, &street, &HouseNumber::from(999)).unwrap());
     }
 
     #[traced_test]
@@ -128,11 +224,50 @@ mod house_number_range_storage_tests {
 
     #[traced_test]
     fn test_house_number_range_contains() {
-        let r = HouseNumberRange { start: 10, end: 20 };
-        assert!(r.contains(10));
-        assert!(r.contains(15));
-        assert!(r.contains(20));
-        assert!(!r.contains(9));
-        assert!(!r.contains(21));
+        let r = HouseNumberRange::new(10, 20);
+        let n = |n: u32| HouseNumber::new(n, None);
+        assert!(r.contains(&n(10)));
+        assert!(r.contains(&n(15)));
+        assert!(r.contains(&n(20)));
+        assert!(!r.contains(&n(9)));
+        assert!(!r.contains(&n(21)));
+    }
+
+    #[traced_test]
+    fn test_parity_range_contains_only_matching_numbers() {
+        let n   = |n: u32| HouseNumber::new(n, None);
+        let odd = HouseNumberRange::with_parity(1, 99, HouseNumberParity::Odd);
+        assert!(odd.contains(&n(1)));
+        assert!(odd.contains(&n(57)));
+        assert!(!odd.contains(&n(56)));
+        assert!(!odd.contains(&n(101)));
+
+        let even = HouseNumberRange::with_parity(2, 100, HouseNumberParity::Even);
+        assert!(even.contains(&n(56)));
+        assert!(!even.contains(&n(57)));
+    }
+
+    #[traced_test]
+    fn test_lettered_range_contains_house_number() {
+        let r = HouseNumberRange::lettered(12, 'a', 'c');
+        assert!(r.contains(&HouseNumber::parse("12B").unwrap()));
+        assert!(!r.contains(&HouseNumber::parse("12D").unwrap()));
+        assert!(!r.contains(&HouseNumber::parse("12").unwrap()));
+        assert!(!r.contains(&HouseNumber::parse("13").unwrap()));
+
+        let plain = HouseNumberRange::new(10, 20);
+        assert!(plain.contains(&HouseNumber::parse("12D").unwrap()));
+    }
+
+    #[traced_test]
+    fn test_old_serialized_ranges_still_load() {
+        // ranges written before parity/side/letters existed
+        #[derive(Serialize)]
+        struct OldRange { start: u32, end: u32 }
+
+        let bytes = serde_cbor::to_vec(&OldRange { start: 5, end: 9 }).unwrap();
+        let r: HouseNumberRange = serde_cbor::from_slice(&bytes).unwrap();
+        assert_eq!(r, HouseNumberRange::new(5, 9));
+        assert_eq!(format!("{:?}", r), "[5..=9]");
     }
 }
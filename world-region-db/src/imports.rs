@@ -54,3 +54,7 @@ pub(crate) use strum::IntoEnumIterator;
 pub(crate) use disable_macro::disable;
 pub(crate) use byteorder::{ByteOrder,BigEndian, WriteBytesExt}; // for writing the 4-byte length prefix
 pub(crate) use protobuf::{Message,MessageField};
+pub(crate) use quick_xml::{Reader as XmlReader, events::{BytesStart, Event as XmlEvent}};
+pub(crate) use flate2::read::GzDecoder;
+pub(crate) use axum::{Router, Json, routing, extract::{Query,State}, http::StatusCode, response::{IntoResponse,Response}};
+pub(crate) use std::net::SocketAddr;
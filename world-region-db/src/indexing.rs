@@ -63,7 +63,7 @@ impl From<&RegionalRecords> for InMemoryIndexes {
         let mut street_cities              = BTreeMap::new();
 
         // Initialize an empty map for the region => { postal_code => set_of_streets }.
-        region_postal_code_streets.insert(region.clone(), BTreeMap::new());
+        region_postal_code_streets.insert(*region, BTreeMap::new());
 
         for rec in records {
             trace!("Processing AddressRecord: {:?}", rec);
@@ -103,7 +103,7 @@ impl From<&RegionalRecords> for InMemoryIndexes {
             if let Some(pc) = &postal_code_obj {
                 postal_code_cities.entry(pc.clone()).or_insert_with(BTreeSet::new);
                 region_postal_code_streets
-                    .get_mut(&region)
+                    .get_mut(region)
                     .unwrap()
                     .entry(pc.clone())
                     .or_insert_with(BTreeSet::new);
@@ -117,7 +117,7 @@ impl From<&RegionalRecords> for InMemoryIndexes {
             // (A) If we have region + postal_code + street => region_postal_code_streets
             if let (Some(pc), Some(st)) = (postal_code_obj.clone(), street_obj.clone()) {
                 region_postal_code_streets
-                    .get_mut(&region).unwrap()
+                    .get_mut(region).unwrap()
                     .entry(pc.clone())
                     .or_insert_with(BTreeSet::new)
                     .insert(st.clone());
@@ -178,7 +178,7 @@ impl From<&RegionalRecords> for InMemoryIndexes {
                - street_postal_codes count={}, 
                - street_cities count={}",
             region,
-            region_postal_code_streets.get(&region).map(|m| m.len()).unwrap_or(0),
+            region_postal_code_streets.get(region).map(|m| m.len()).unwrap_or(0),
             postal_code_cities.len(),
             city_postal_codes.len(),
             city_streets.len(),
@@ -229,9 +229,9 @@ mod in_memory_indexes_tests {
         postal_code: Option<&str>
     ) -> AddressRecord {
         AddressRecordBuilder::default()
-            .city(city.map(|c| make_city(c)))
-            .street(street.map(|s| make_street(s)))
-            .postcode(postal_code.map(|p| make_postal_code(p)))
+            .city(city.map(make_city))
+            .street(street.map(make_street))
+            .postcode(postal_code.map(make_postal_code))
             .build()
             .unwrap()
     }
@@ -250,7 +250,7 @@ mod in_memory_indexes_tests {
             &PostalCode::new(Country::USA,"21201").unwrap()
         ).unwrap();
 
-        assert!(streets_for_21201.contains(&StreetName::new("North Avenue").unwrap()));
+        assert!(streets_for_21201.contains(&StreetName::new_for_country("North Avenue", Country::USA).unwrap()));
     }
 
     #[traced_test]
@@ -110,7 +110,7 @@ mod test_integrate_house_number_subranges_for_street {
 
     #[traced_test]
     fn test_no_existing_ranges_new_ranges_simple() {
-        let (mut db, _td) = create_temp_db::<Database>();
+        let (db, _td) = create_temp_db::<Database>();
         let mut db_guard = db.lock().unwrap();
 
         let (region, street) = test_region_and_street();
@@ -127,7 +127,7 @@ mod test_integrate_house_number_subranges_for_street {
 
     #[traced_test]
     fn test_existing_ranges_empty_new_ranges() {
-        let (mut db, _td) = create_temp_db::<Database>();
+        let (db, _td) = create_temp_db::<Database>();
 
         let mut db_guard = db.lock().unwrap();
 
@@ -146,7 +146,7 @@ mod test_integrate_house_number_subranges_for_street {
 
     #[traced_test]
     fn test_merge_overlapping_ranges() {
-        let (mut db, _td) = create_temp_db::<Database>();
+        let (db, _td) = create_temp_db::<Database>();
 
         let mut db_guard = db.lock().unwrap();
 
@@ -170,7 +170,7 @@ mod test_integrate_house_number_subranges_for_street {
 
     #[traced_test]
     fn test_merge_completely_overlapping_new_range() {
-        let (mut db, _td) = create_temp_db::<Database>();
+        let (db, _td) = create_temp_db::<Database>();
 
         let mut db_guard = db.lock().unwrap();
 
@@ -191,7 +191,7 @@ mod test_integrate_house_number_subranges_for_street {
     #[traced_test]
     fn test_load_error_logs_warning_but_succeeds() {
 
-        let mut failing_db = FailingLoadDatabase::new().unwrap();
+        let failing_db = FailingLoadDatabase::new().unwrap();
 
         let region = USRegion::UnitedState(UnitedState::Maryland).into();
         let street = StreetName::new("Example Street").unwrap();
@@ -212,7 +212,7 @@ mod test_integrate_house_number_subranges_for_street {
 
     #[traced_test]
     fn test_store_error_logs_warning_but_succeeds() {
-        let mut failing_db = FailingStoreDatabase::new().unwrap();
+        let failing_db = FailingStoreDatabase::new().unwrap();
         {
             let mut guard = failing_db.lock().unwrap();
 
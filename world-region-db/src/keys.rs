@@ -2,41 +2,105 @@
 crate::ix!();
 
 pub fn z2c_key(region: &WorldRegion, postal_code: &PostalCode) -> String {
-    format!("Z2C:{}:{}", region.abbreviation(), postal_code.code())
+    DbKey::PostalCodeCities { region: *region, postal_code: postal_code.clone() }.encode()
 }
 
 pub fn s_key(region: &WorldRegion, postal_code: &PostalCode) -> String {
-    format!("S:{}:{}", region.abbreviation(), postal_code.code())
-}
-
-pub fn c_key(region: &WorldRegion, city: &CityName) -> String {
-    format!("C2S:{}:{}", region.abbreviation(), city.name())
+    DbKey::PostalCodeStreets { region: *region, postal_code: postal_code.clone() }.encode()
 }
 
 pub fn c2z_key(region: &WorldRegion, city: &CityName) -> String {
-    format!("C2Z:{}:{}", region.abbreviation(), city.name())
+    DbKey::CityPostalCodes { region: *region, city: city.clone() }.encode()
 }
 
 pub fn c2s_key(region: &WorldRegion, city: &CityName) -> String {
-    format!("C2S:{}:{}", region.abbreviation(), city.name())
+    DbKey::CityStreets { region: *region, city: city.clone() }.encode()
 }
 
 pub fn s2c_key(region: &WorldRegion, street: &StreetName) -> String {
-    format!("S2C:{}:{}", region.abbreviation(), street.name())
+    DbKey::StreetCities { region: *region, street: street.clone() }.encode()
 }
 
 pub fn s2z_key(region: &WorldRegion, street: &StreetName) -> String {
-    format!("S2Z:{}:{}", region.abbreviation(), street.name())
+    DbKey::StreetPostalCodes { region: *region, street: street.clone() }.encode()
 }
 
 /// Builds the RocksDB key for house-number ranges on a particular street in a region.
 ///
 /// For example: 
 ///    `HNR:{region_abbr}:{street_name}`
-/// where `street_name` has already been normalized to lowercase, 
-/// but we rely on `StreetName::name()` for that.
+/// where `street_name` is the canonical form from `StreetName::canonical()`,
+/// so every spelling of the street shares one set of ranges.
 pub fn house_number_ranges_key(region: &WorldRegion, street: &StreetName) -> String {
-    format!("HNR:{}:{}", region.abbreviation(), street.name())
+    DbKey::HouseNumberRanges { region: *region, street: street.clone() }.encode()
+}
+
+/// Forward geocoding index: every located address for a complete
+/// postal code + city + street triple.
+///
+///    `LOC:{region_abbr}:{postal_code}:{city}:{street}`
+pub fn loc_key(region: &WorldRegion, postal_code: &PostalCode, city: &CityName, street: &StreetName) -> String {
+    DbKey::LocatedAddresses {
+        region:      *region,
+        postal_code: postal_code.clone(),
+        city:        city.clone(),
+        street:      street.clone(),
+    }.encode()
+}
+
+/// Spatial index cell holding every located address inside a geohash cell.
+///
+///    `GEO:{region_abbr}:{geohash}`
+///
+/// Passing a shorter geohash yields the prefix for all finer cells inside it.
+pub fn geo_key(region: &WorldRegion, geohash: &str) -> String {
+    DbKey::GeoCell { region: *region, geohash: geohash.to_string() }.encode()
+}
+
+/// The address an OSM element contributed when it was last built or
+/// updated, so change files can undo it.
+///
+///    `OSM:{region_abbr}:{n|w|r}{id}`
+pub fn osm_element_key(region: &WorldRegion, element: &OsmElementRef) -> String {
+    DbKey::OsmElement { region: *region, element: *element }.encode()
+}
+
+/// How many OSM elements currently support one address link; the link is
+/// removed when this drops to zero.
+///
+///    `REF:{region_abbr}:{link}`
+pub fn address_link_count_key(region: &WorldRegion, link: &AddressLink) -> String {
+    DbKey::AddressLinkCount { region: *region, link: link.clone() }.encode()
+}
+
+/// One administrative or postal code boundary polygon of a region.
+///
+///    `BND:{region_abbr}:{relation_id}`
+pub fn boundary_key(region: &WorldRegion, relation_id: i64) -> String {
+    DbKey::Boundary { region: *region, relation_id }.encode()
+}
+
+/// The relation ids of the boundaries overlapping one geohash cell at
+/// [`BOUNDARY_INDEX_PRECISION`].
+///
+///    `BNDX:{region_abbr}:{geohash}`
+pub fn boundary_cell_key(region: &WorldRegion, geohash: &str) -> String {
+    DbKey::BoundaryCell { region: *region, geohash: geohash.to_string() }.encode()
+}
+
+/// Present while a region's records are being written, so a build cut
+/// short can be told apart from one that never started.
+///
+///    `META:REGION_BUILDING:{region_abbr}`
+pub fn region_building_key(region: &WorldRegion) -> String {
+    DbKey::RegionBuilding { region: *region }.encode()
+}
+
+/// The last OSM replication sequence number applied to a region.
+///
+///    `META:REGION_SEQ:{region_abbr}`
+pub fn replication_sequence_key(region: &WorldRegion) -> String {
+    DbKey::ReplicationSequence { region: *region }.encode()
 }
 
 #[cfg(test)]
@@ -79,10 +143,6 @@ mod keys_tests {
         let s_k = s_key(&region, &postal_code);
         assert_eq!(s_k, format!("S:{}:{}", region_abbrev(&region), "21201"));
 
-        let c_k = c_key(&region, &city_name);
-        // note c_key => "C2S:{}:{}"
-        assert_eq!(c_k, format!("C2S:{}:{}", region_abbrev(&region), "baltimore"));
-
         let c2z = c2z_key(&region, &city_name);
         assert_eq!(c2z, format!("C2Z:{}:{}", region_abbrev(&region), "baltimore"));
 
@@ -94,6 +154,25 @@ mod keys_tests {
 
         let s2z = s2z_key(&region, &street_name);
         assert_eq!(s2z, format!("S2Z:{}:{}", region_abbrev(&region), "north avenue"));
+
+        let loc = loc_key(&region, &postal_code, &city_name, &street_name);
+        assert_eq!(loc, format!("LOC:{}:21201:baltimore:north avenue", region_abbrev(&region)));
+
+        let geo = geo_key(&region, "dqcjq");
+        assert_eq!(geo, format!("GEO:{}:dqcjq", region_abbrev(&region)));
+
+        let osm = osm_element_key(&region, &OsmElementRef::Way(77));
+        assert_eq!(osm, format!("OSM:{}:w77", region_abbrev(&region)));
+
+        let link = AddressLink::CityStreet(city_name.clone(), street_name.clone());
+        let refs = address_link_count_key(&region, &link);
+        assert_eq!(refs, format!("REF:{}:cs:baltimore:north avenue", region_abbrev(&region)));
+
+        let seq = replication_sequence_key(&region);
+        assert_eq!(seq, format!("META:REGION_SEQ:{}", region_abbrev(&region)));
+
+        let building = region_building_key(&region);
+        assert_eq!(building, format!("META:REGION_BUILDING:{}", region_abbrev(&region)));
     }
 
     #[traced_test]
@@ -111,9 +190,6 @@ mod keys_tests {
         let s_k = s_key(&region, &postal_code);
         assert_eq!(s_k, format!("S:{}:{}", region_abbrev(&region), "20138-9997"));
 
-        let c_k = c_key(&region, &city_name);
-        assert_eq!(c_k, format!("C2S:{}:{}", region_abbrev(&region), "calverton"));
-
         let c2z = c2z_key(&region, &city_name);
         assert_eq!(c2z, format!("C2Z:{}:{}", region_abbrev(&region), "calverton"));
 
@@ -150,7 +226,7 @@ mod keys_tests {
         let s2z = s2z_key(&region, &street_name);
         // street => "route 66 ???" => normal might remove ??? => "route 66"? 
         // Actually check what your StreetName normalizer does. We'll assume it leaves question marks or spaces?
-        let expected_street = street_name.name();
+        let expected_street = street_name.canonical();
         assert_eq!(s2z, format!("S2Z:{}:{}", region_abbrev(&region), expected_street));
     }
 
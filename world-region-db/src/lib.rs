@@ -1,5 +1,4 @@
 // ---------------- [ File: src/lib.rs ]
-#![feature(more_qualified_paths)]
 #![feature(type_alias_impl_trait)]
 #![allow(unused_imports)]
 #![allow(unreachable_code)]
@@ -17,15 +16,35 @@ x!{mock_tag_iter}
 x!{assert_address_record_matches_raw}
 x!{assert_street_house_number_map_contains}
 x!{mock_address}
+x!{mock_boundaries}
+x!{address_column_mapping}
+x!{address_interpolation}
+x!{street_geometry}
+x!{address_link}
 x!{address_record_from_element_and_country}
 x!{address_record}
+x!{address_server_cli}
+x!{address_service}
+x!{address_service_messages}
+x!{address_service_router}
+x!{admin_boundary}
 x!{mock_failing_db}
 x!{addresses_from_pbf_file_with_house_numbers}
 x!{attempt_storing_house_number_aggregator_in_db}
+x!{apply_osm_change}
+x!{apply_osm_change_directory}
+x!{assign_fields_from_boundaries}
+x!{best_fuzzy_matches}
+x!{boundaries_containing_point}
+x!{boundary_index}
 x!{build_all_region_data}
 x!{build_city_search_prefix}
+x!{build_regions_in_parallel}
 x!{build_world_address_if_possible}
 x!{build_world_address}
+x!{bulk_address_rows}
+x!{candidate_coordinates_for_address}
+x!{canonicalize_street_name}
 x!{capture_stdout}
 x!{chain_addresses_across_files}
 x!{city_names_for_postal_code_in_region}
@@ -41,6 +60,7 @@ x!{create_tiny_osm_pbf_with_housenumber}
 x!{create_tiny_osm_pbf}
 x!{data_access}
 x!{db_decoder}
+x!{db_key}
 x!{dmv}
 x!{download_and_parse_all_regions}
 x!{dump}
@@ -53,9 +73,13 @@ x!{filenames_match}
 x!{filenames}
 x!{finalize_address_validation}
 x!{find_region_for_file}
+x!{fold_diacritics}
+x!{free_text_address_tokens}
 x!{gather_all_zips_in_region}
 x!{gather_city_key_value_pairs}
 x!{gather_pbf_files}
+x!{geo_point}
+x!{geohash}
 x!{get_cbor_set_typed}
 x!{get_city_set_for_key}
 x!{get_element_id}
@@ -63,6 +87,7 @@ x!{get_postal_code_set_for_key}
 x!{get_prefix_iterator}
 x!{get_street_set_for_key}
 x!{handle_pbf_house_number_extractor_in_thread}
+x!{house_number}
 x!{house_number_in_any_range}
 x!{house_number_parsing_and_storage}
 x!{house_number_ranges}
@@ -76,20 +101,28 @@ x!{load_all_streets_for_region}
 x!{load_done_regions}
 x!{load_existing_street_ranges}
 x!{load_house_number_ranges}
+x!{located_address}
+x!{longest_known_city_span}
 x!{merge_house_number_range}
 x!{merge_new_subranges}
 x!{meta_key}
+x!{migrate_database}
 x!{mock}
+x!{nearest_addresses_to_point}
 x!{normalize}
 x!{obtain_pbf_file_for_region}
 x!{open_database_at_path}
 x!{open_osm_pbf_reader}
 x!{open_pbf_reader_or_report_error}
+x!{osm_change}
+x!{osm_element_ref}
 x!{parse_address_record_if_any}
 x!{parse_city_names}
+x!{parse_free_text_address}
 x!{parse_housenumber_value}
 x!{parse_integer}
 x!{parse_osm_pbf_and_build_house_number_ranges}
+x!{pending_boundaries}
 x!{postal_codes_for_city_in_region}
 x!{postal_codes_for_street_in_region}
 x!{prefix_transform}
@@ -98,24 +131,31 @@ x!{prepare_single_node_primitive_block}
 x!{process_and_validate_addresses}
 x!{process_single_osm_element}
 x!{putget}
+x!{region_build_marker}
+x!{region_build_progress}
 x!{region_data}
 x!{region_done_traits}
 x!{regional_records}
 x!{regions}
 x!{remote_data}
+x!{replication_sequence}
 x!{retrieve_housenumber_value}
+x!{schema_version}
 x!{serialize_primitive_block}
 x!{seriallize_osm_header_block}
+x!{staged_writes}
 x!{stdout_backup}
 x!{storage}
 x!{store_house_number_ranges}
 x!{store_merged_house_number_ranges}
+x!{street_directional}
 x!{street_exists_globally}
 x!{street_exists_in_city_in_region}
 x!{street_exists_in_postal_code_in_region}
 x!{street_names_for_city_in_region}
 x!{street_names_for_postal_code_in_region}
 x!{street_name}
+x!{street_name_convention}
 x!{strip_leading_dot_slash}
 x!{traits}
 x!{try_assemble_address_record}
@@ -130,18 +170,23 @@ x!{unify_new_and_existing_ranges}
 x!{update_aggregator_with_housenumber}
 x!{update_street_house_numbers}
 x!{validate_address}
+x!{validate_address_file}
 x!{validate_all_addresses}
 x!{validate_city_for_postal_code}
 x!{validate_not_dir}
 x!{validate_pbf_filename}
 x!{validate_street_for_city}
 x!{validate_street_for_postal_code}
+x!{way_centroids}
 x!{world_address}
+x!{write_address_locations}
 x!{write_be_u32}
+x!{write_boundaries}
 x!{write_cities_to_region_and_postal_code}
 x!{write_cities_to_region_and_street}
 x!{write_house_number_ranges_into_storage}
 x!{write_indices}
+x!{write_osm_change_baseline}
 x!{write_osm_pbf_file}
 x!{write_postal_codes_to_region_and_city}
 x!{write_postal_codes_to_region_and_street}
@@ -16,6 +16,7 @@ pub type WorldAddressIterator = impl Iterator<Item=Result<WorldAddress,OsmPbfPar
 ///
 /// * `Ok(impl Iterator<Item = Result<WorldAddress, OsmPbfParseError>>)` on success.
 /// * `Err(OsmPbfParseError)` if reading the directory or chaining file iterators fails.
+#[define_opaque(WorldAddressIterator)]
 pub fn list_all_addresses_in_pbf_dir<I:StorageInterface + 'static>(
     pbf_dir: impl AsRef<Path>,
     db: Arc<Mutex<I>>,
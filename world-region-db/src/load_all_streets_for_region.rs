@@ -11,7 +11,7 @@ pub fn load_all_streets_for_region<I: StorageInterface>(
     use tracing::{trace, debug, warn, error};
 
     trace!("load_all_streets_for_region_corrected: start for region={:?}", region);
-    let prefix = format!("S2C:{}:", region.abbreviation());
+    let prefix = DbKeyFamily::StreetCities.region_prefix(region);
     debug!("searching DB with prefix='{}'", prefix);
 
     let mut results = Vec::new();
@@ -3,26 +3,18 @@ crate::ix!();
 
 /// (5) Helper function to load the set of “done” regions by scanning for `META:REGION_DONE:<abbrev>`.
 pub fn load_done_regions<I:StorageInterface>(db: &I) -> Vec<WorldRegion> {
-    let prefix = b"META:REGION_DONE:";
+    let prefix = DbKeyFamily::RegionDone.tag();
     let mut out = Vec::new();
 
-    let it = db.prefix_iterator(prefix);
-    for kv in it {
-        if let Ok((k, _v)) = kv {
-            let key_str = String::from_utf8_lossy(&k).to_string();
-            // key_str might be "META:REGION_DONE:US" or "META:REGION_DONE:MD", etc.
-            // We parse after the 2nd colon
-            if let Some(abbr) = key_str.splitn(3, ':').nth(2) {
-                // Attempt to convert abbreviation -> WorldRegion
-                // If your code can do `WorldRegion::from_abbreviation(abbr)`, do so.
-                // If not, you might store it in your DB or do a custom match. 
-                // Here’s a pseudo approach:
-                match WorldRegion::try_from_abbreviation(abbr) {
-                    Ok(r) => out.push(r),
-                    Err(e) => {
-                        eprintln!("Could not parse region from abbr '{}': {:?}", abbr, e);
-                    }
-                }
+    let it = db.prefix_iterator(prefix.as_bytes());
+    for (k, _v) in it.flatten() {
+        let key_str = String::from_utf8_lossy(&k).to_string();
+        // key_str might be "META:REGION_DONE:US" or "META:REGION_DONE:MD", etc.
+        match DbKey::decode(&key_str) {
+            Ok(DbKey::RegionDone { region }) => out.push(region),
+            Ok(other) => trace!("load_done_regions: skipping {:?}", other),
+            Err(e) => {
+                eprintln!("Could not parse region from key '{}': {:?}", key_str, e);
             }
         }
     }
@@ -101,8 +101,7 @@ mod test_load_existing_street_ranges {
                 _world_region: &WorldRegion,
                 _street: &StreetName,
             ) -> Result<Option<Vec<HouseNumberRange>>, DataAccessError> {
-                Err(DataAccessError::Io(std::io::Error::new(
-                    std::io::ErrorKind::Other,
+                Err(DataAccessError::Io(std::io::Error::other(
                     "Simulated read error",
                 )))
             }
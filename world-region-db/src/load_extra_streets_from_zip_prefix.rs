@@ -10,7 +10,7 @@ pub fn load_extra_streets_from_zip_prefix<I: StorageInterface>(
 
     // The prefix we use to store zip→streets is typically `S:{region}:{zip}` 
     // or region_postal_code_streets => "S2Z:REGION_ABBR:..."
-    let prefix = format!("S:{}:", region.abbreviation());
+    let prefix = DbKeyFamily::PostalCodeStreets.region_prefix(region);
     trace!("load_extra_streets_from_zip_prefix: prefix='{}'", prefix);
 
     let iter = db.prefix_iterator(prefix.as_bytes());
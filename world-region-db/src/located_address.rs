@@ -0,0 +1,88 @@
+// ---------------- [ File: src/located_address.rs ]
+crate::ix!();
+
+/// An address (possibly partial) pinned to a coordinate. This is the value
+/// type stored under both the `GEO:` spatial index and the `LOC:` forward
+/// index.
+#[derive(Builder,Getters,Clone,Debug,PartialEq,Eq,Serialize,Deserialize)]
+#[getset(get="pub")]
+#[builder(setter(into))]
+pub struct LocatedAddress {
+    region:      WorldRegion,
+    #[builder(default)]
+    city:        Option<CityName>,
+    #[builder(default)]
+    street:      Option<StreetName>,
+    #[builder(default)]
+    postal_code: Option<PostalCode>,
+    #[builder(default)]
+    housenumber: Option<HouseNumberRange>,
+    location:    GeoPoint,
+}
+
+impl LocatedAddress {
+
+    /// Pins `record` to its coordinate. Returns `None` for records we never
+    /// resolved a location for.
+    pub fn from_record(region: &WorldRegion, record: &AddressRecord) -> Option<Self> {
+        let location = (*record.location())?;
+        Some(Self {
+            region:      *region,
+            city:        record.city().clone(),
+            street:      record.street().clone(),
+            postal_code: record.postcode().clone(),
+            housenumber: record.housenumber().clone(),
+            location,
+        })
+    }
+
+    pub fn covers_house_number(&self, house_number: &HouseNumber) -> bool {
+        self.housenumber
+            .as_ref()
+            .map(|range| range.contains(house_number))
+            .unwrap_or(false)
+    }
+}
+
+/// A [`LocatedAddress`] returned by a reverse lookup, with its distance from
+/// the query point.
+#[derive(Getters,Clone,Debug,PartialEq)]
+#[getset(get="pub")]
+pub struct NearbyAddress {
+    address:         LocatedAddress,
+    distance_meters: f64,
+}
+
+impl NearbyAddress {
+    pub fn new(address: LocatedAddress, distance_meters: f64) -> Self {
+        Self { address, distance_meters }
+    }
+}
+
+#[cfg(test)]
+mod located_address_tests {
+    use super::*;
+
+    fn region_md() -> WorldRegion {
+        USRegion::UnitedState(UnitedState::Maryland).into()
+    }
+
+    #[traced_test]
+    fn test_from_record_requires_location() {
+        let mut record = AddressRecord::new(
+            CityName::new("Baltimore").unwrap(),
+            StreetName::new("North Avenue").unwrap(),
+            PostalCode::new(Country::USA, "21201").unwrap(),
+        );
+        assert!(LocatedAddress::from_record(&region_md(), &record).is_none());
+
+        record.set_location(Some(GeoPoint::from_degrees(39.31, -76.62).unwrap()));
+        record.set_housenumber(Some(HouseNumberRange::new(100, 110)));
+
+        let located = LocatedAddress::from_record(&region_md(), &record).unwrap();
+        assert_eq!(located.region(), &region_md());
+        assert_eq!(located.street().as_ref().unwrap().name(), "north avenue");
+        assert!(located.covers_house_number(&HouseNumber::from(105)));
+        assert!(!located.covers_house_number(&HouseNumber::from(111)));
+    }
+}
@@ -0,0 +1,68 @@
+// ---------------- [ File: src/longest_known_city_span.rs ]
+crate::ix!();
+
+/// Starting at `tokens[start]`, extends one token at a time and returns the
+/// last index whose joined (normalized) text is a member of `known_cities`.
+/// A purely numeric token ends the search, since it can only be a house
+/// number or postal code.
+///
+/// This is what lets `"potomac river road"` resolve to city `"potomac"` and
+/// street `"river road"`: `"potomac"` is known, `"potomac river"` is not, so the
+/// longest known span is the single token.
+pub fn longest_known_city_span(
+    tokens:       &[&str],
+    start:        usize,
+    known_cities: &HashSet<String>,
+) -> Option<usize> {
+    let mut potential_city = String::new();
+    let mut last_match_index = None;
+
+    for (j, tk) in tokens.iter().enumerate().skip(start) {
+        if tk.parse::<u32>().is_ok() {
+            break;
+        }
+        if !potential_city.is_empty() {
+            potential_city.push(' ');
+        }
+        potential_city.push_str(tk);
+
+        if known_cities.contains(&normalize(&potential_city)) {
+            last_match_index = Some(j);
+        }
+    }
+    last_match_index
+}
+
+#[cfg(test)]
+mod longest_known_city_span_tests {
+    use super::*;
+
+    fn cities(names: &[&str]) -> HashSet<String> {
+        names.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[traced_test]
+    fn test_prefers_longest_known_prefix() {
+        let known = cities(&["potomac", "potomac heights"]);
+        let tokens = ["potomac", "heights", "river", "road"];
+        assert_eq!(longest_known_city_span(&tokens, 0, &known), Some(1));
+
+        let tokens = ["potomac", "river", "road"];
+        assert_eq!(longest_known_city_span(&tokens, 0, &known), Some(0));
+    }
+
+    #[traced_test]
+    fn test_stops_at_numeric_token() {
+        let known = cities(&["north potomac"]);
+        let tokens = ["north", "123", "potomac"];
+        assert_eq!(longest_known_city_span(&tokens, 0, &known), None);
+    }
+
+    #[traced_test]
+    fn test_respects_start_and_normalization() {
+        let known = cities(&["baltimore"]);
+        let tokens = ["charles", "st", "Baltimore,"];
+        assert_eq!(longest_known_city_span(&tokens, 2, &known), Some(2));
+        assert_eq!(longest_known_city_span(&tokens, 0, &known), None);
+    }
+}
@@ -18,10 +18,9 @@ impl AsRef<[u8]> for MetaKeyForRegion {
 impl From<WorldRegion> for MetaKeyForRegion {
 
     fn from(region: WorldRegion) -> Self {
-        let region_name = region.abbreviation();
         Self {
             region,
-            key: format!("META:REGION_DONE:{}", region_name),
+            key: DbKey::RegionDone { region }.encode(),
         }
     }
 }
@@ -0,0 +1,406 @@
+// ---------------- [ File: src/migrate_database.rs ]
+crate::ix!();
+
+/// What [`migrate_database_at_path`] did.
+#[derive(Getters,Clone,Debug,Default,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct SchemaMigrationReport {
+    from_version:   u32,
+    to_version:     u32,
+    /// Keys written under a new key or with rewritten values.
+    keys_rewritten: usize,
+    /// Old keys removed because their family now stores them elsewhere.
+    keys_removed:   usize,
+    /// Keys or values that could not be decoded and were left untouched.
+    keys_skipped:   usize,
+    /// Built regions with no `OSM:` change baseline; they must be rebuilt
+    /// before OSM change files can be applied to them.
+    regions_without_change_baseline: Vec<WorldRegion>,
+}
+
+/// Rewrites the database at `path` in place into the [`DB_SCHEMA_VERSION`]
+/// layout and stamps it with that version. A database already at the
+/// current version is left alone.
+///
+/// Each step commits its rewrites in batches of at most
+/// [`MIGRATION_BATCH_KEYS`] keys and stamps its version only after the last
+/// one. A rewrite merges into whatever its target key holds by then, with
+/// merges that ignore repeated values, so an interrupted migration can
+/// simply be run again.
+///
+/// v1 => v2: street-keyed families (`S2C:`, `S2Z:`, `HNR:`, `LOC:`) move
+/// to the street's canonical form for its region, merging the values of
+/// spellings that now share a key, and every stored [`StreetName`] gets
+/// its canonical form filled in.
+pub fn migrate_database_at_path(path: impl AsRef<Path>)
+    -> Result<SchemaMigrationReport, WorldCityAndStreetDbBuilderError>
+{
+    let mut db = open_database_without_schema_check(path)?;
+
+    let from_version = detect_schema_version(&db)?.unwrap_or(DB_SCHEMA_VERSION);
+    let mut report = SchemaMigrationReport {
+        from_version,
+        to_version: DB_SCHEMA_VERSION,
+        ..Default::default()
+    };
+
+    if from_version > DB_SCHEMA_VERSION {
+        return Err(DatabaseSchemaError::NewerThanSupported {
+            found:     from_version,
+            supported: DB_SCHEMA_VERSION,
+        }.into());
+    }
+
+    if from_version < 2 {
+        info!("migrating database from schema v{} to v2", from_version);
+        migrate_v1_to_v2(&db, &mut report)?;
+        db.set_schema_version(2)?;
+    }
+
+    db.set_schema_version(DB_SCHEMA_VERSION)?;
+    info!("database migration finished: {:?}", report);
+    Ok(report)
+}
+
+/// Keys a migration step stages before committing them.
+pub const MIGRATION_BATCH_KEYS: usize = 10_000;
+
+fn migrate_v1_to_v2(db: &Database, report: &mut SchemaMigrationReport)
+    -> Result<(), WorldCityAndStreetDbBuilderError>
+{
+    rewrite_list_family::<CityName>(db, DbKeyFamily::StreetCities, report, |_, items| items, union_sorted)?;
+    rewrite_list_family::<PostalCode>(db, DbKeyFamily::StreetPostalCodes, report, |_, items| items, union_sorted)?;
+    rewrite_list_family(db, DbKeyFamily::PostalCodeStreets, report, canonical_street_set, union_sorted)?;
+    rewrite_list_family(db, DbKeyFamily::CityStreets, report, canonical_street_set, union_sorted)?;
+    rewrite_list_family::<HouseNumberRange>(db, DbKeyFamily::HouseNumberRanges, report, |_, items| items, |ours, theirs| {
+        theirs.iter().fold(ours, merge_house_number_range)
+    })?;
+    for family in [DbKeyFamily::LocatedAddresses, DbKeyFamily::GeoCell] {
+        rewrite_list_family(db, family, report, canonical_located_addresses, union_located)?;
+    }
+
+    for region in load_done_regions(db) {
+        if !has_keys_with_prefix(db, &DbKeyFamily::OsmElement.region_prefix(&region)) {
+            warn!("region {:?} has no OSM change baseline; rebuild it before applying OSM changes", region);
+            report.regions_without_change_baseline.push(region);
+        }
+    }
+    Ok(())
+}
+
+/// Re-keys and rewrites every `CompressedList<T>` value of `family`,
+/// committing every [`MIGRATION_BATCH_KEYS`] keys. `fix_items` updates one
+/// value for its region and must be safe to run on an already fixed value;
+/// `merge` combines the value a key is moved onto with the value already
+/// there (its own legacy value, another spelling's, or an interrupted
+/// run's), and must ignore values both sides hold.
+fn rewrite_list_family<T>(
+    db:        &Database,
+    family:    DbKeyFamily,
+    report:    &mut SchemaMigrationReport,
+    fix_items: impl Fn(&WorldRegion, Vec<T>) -> Vec<T>,
+    merge:     impl Fn(Vec<T>, Vec<T>) -> Vec<T>,
+) -> Result<(), WorldCityAndStreetDbBuilderError>
+where
+    T: Serialize + DeserializeOwned + Clone,
+{
+    let tag  = family.tag();
+    let mode = rocksdb::IteratorMode::From(tag.as_bytes(), rocksdb::Direction::Forward);
+
+    let mut staged  = StagedWrites::new(db);
+    let mut batches = 0;
+
+    // the iterator reads a snapshot: keys written below are not visited again
+    for item in db.iterator(mode) {
+        let (key, val) = match item {
+            Ok(entry) => entry,
+            Err(e) => {
+                error!("migration: error reading {:?} keys: {}", family, e);
+                continue;
+            }
+        };
+        let key = String::from_utf8_lossy(&key).into_owned();
+        if !key.starts_with(tag) {
+            break;
+        }
+
+        let decoded = match DbKey::decode(&key) {
+            Ok(decoded) => decoded,
+            Err(e) => {
+                warn!("migration: leaving undecodable key {:?}: {:?}", key, e);
+                report.keys_skipped += 1;
+                continue;
+            }
+        };
+        let Some(items) = decode_migrated_list::<T>(&key, &val, report) else {
+            continue;
+        };
+        let Some(region) = decoded.region().copied() else {
+            continue;
+        };
+        let new_key = recanonicalize_key(decoded).encode();
+
+        // merge with what `new_key` holds now, not with the snapshot; both
+        // sides are fixed first, since legacy values only compare equal once
+        // their streets have a canonical form
+        let items = fix_items(&region, items);
+        let items = match staged.get(&new_key)? {
+            Some(bytes) => match decode_migrated_list::<T>(&new_key, &bytes, report) {
+                Some(existing) => merge(fix_items(&region, existing), items),
+                None           => continue,
+            },
+            None => items,
+        };
+
+        let bytes = serde_cbor::to_vec(&CompressedList::from(items)).map_err(|e| {
+            DatabaseConstructionError::LocationSerdeError {
+                msg: format!("Failed to serialize migrated value for '{}': {}", new_key, e),
+            }
+        })?;
+        staged.put(&new_key, bytes)?;
+        report.keys_rewritten += 1;
+
+        if new_key != key {
+            staged.delete(&key)?;
+            report.keys_removed += 1;
+        }
+
+        if staged.staged_len() >= MIGRATION_BATCH_KEYS {
+            std::mem::replace(&mut staged, StagedWrites::new(db)).commit()?;
+            batches += 1;
+        }
+    }
+
+    staged.commit()?;
+    debug!("migration: rewrote {:?} in {} batches", family, batches + 1);
+    Ok(())
+}
+
+fn decode_migrated_list<T: Serialize + DeserializeOwned + Clone>(key: &str, bytes: &[u8], report: &mut SchemaMigrationReport) -> Option<Vec<T>> {
+    match serde_cbor::from_slice::<CompressedList<T>>(bytes) {
+        Ok(clist) => Some(clist.items().clone()),
+        Err(e) => {
+            warn!("migration: leaving undecodable value under {:?}: {}", key, e);
+            report.keys_skipped += 1;
+            None
+        }
+    }
+}
+
+fn has_keys_with_prefix(db: &Database, prefix: &str) -> bool {
+    let mode = rocksdb::IteratorMode::From(prefix.as_bytes(), rocksdb::Direction::Forward);
+    matches!(db.iterator(mode).next(), Some(Ok((key, _))) if key.starts_with(prefix.as_bytes()))
+}
+
+/// The street as `region` canonicalizes it. v1 keys hold the normalized
+/// street name, which is what decoding put in `name`.
+fn recanonicalize_street(street: &StreetName, region: &WorldRegion) -> StreetName {
+    StreetName::new_for_region(street.name(), region).unwrap_or_else(|_| street.clone())
+}
+
+fn recanonicalize_key(key: DbKey) -> DbKey {
+    match key {
+        DbKey::StreetCities { region, street } => DbKey::StreetCities {
+            street: recanonicalize_street(&street, &region),
+            region,
+        },
+        DbKey::StreetPostalCodes { region, street } => DbKey::StreetPostalCodes {
+            street: recanonicalize_street(&street, &region),
+            region,
+        },
+        DbKey::HouseNumberRanges { region, street } => DbKey::HouseNumberRanges {
+            street: recanonicalize_street(&street, &region),
+            region,
+        },
+        DbKey::LocatedAddresses { region, postal_code, city, street } => DbKey::LocatedAddresses {
+            street: recanonicalize_street(&street, &region),
+            region,
+            postal_code,
+            city,
+        },
+        other => other,
+    }
+}
+
+fn canonical_street_set(region: &WorldRegion, streets: Vec<StreetName>) -> Vec<StreetName> {
+    let set: BTreeSet<StreetName> = streets.iter().map(|street| recanonicalize_street(street, region)).collect();
+    set.into_iter().collect()
+}
+
+fn canonical_located_addresses(region: &WorldRegion, located: Vec<LocatedAddress>) -> Vec<LocatedAddress> {
+    located
+        .into_iter()
+        .map(|address| {
+            let street = address.street().as_ref().map(|street| recanonicalize_street(street, region));
+            LocatedAddressBuilder::default()
+                .region(*address.region())
+                .city(address.city().clone())
+                .street(street)
+                .postal_code(address.postal_code().clone())
+                .housenumber(address.housenumber().clone())
+                .location(*address.location())
+                .build()
+                .expect("all fields are set")
+        })
+        .collect()
+}
+
+fn union_sorted<T: Ord>(ours: Vec<T>, theirs: Vec<T>) -> Vec<T> {
+    let set: BTreeSet<T> = ours.into_iter().chain(theirs).collect();
+    set.into_iter().collect()
+}
+
+/// `union_sorted` for located addresses, which sort by location only.
+fn union_located(ours: Vec<LocatedAddress>, theirs: Vec<LocatedAddress>) -> Vec<LocatedAddress> {
+    let mut all: Vec<LocatedAddress> = ours.into_iter().chain(theirs).collect();
+    all.sort_by_key(|address| *address.location());
+
+    let mut out: Vec<LocatedAddress> = Vec::with_capacity(all.len());
+    for address in all {
+        // equal addresses share a location, so only that run can hold a repeat
+        let run = out.iter().rposition(|o| o.location() != address.location()).map_or(0, |i| i + 1);
+        if !out[run..].contains(&address) {
+            out.push(address);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod test_migrate_database {
+    use super::*;
+
+    fn region_md() -> WorldRegion {
+        USRegion::UnitedState(UnitedState::Maryland).into()
+    }
+
+    /// A v1 street value: serialized before `canonical` existed.
+    #[derive(Serialize,Deserialize)]
+    struct LegacyStreetName {
+        name: String,
+    }
+
+    fn legacy_streets(names: &[&str]) -> Vec<u8> {
+        let list: Vec<LegacyStreetName> = names.iter().map(|n| LegacyStreetName { name: n.to_string() }).collect();
+        serde_cbor::to_vec(&CompressedList::from(list)).unwrap()
+    }
+
+    fn cities(names: &[&str]) -> Vec<u8> {
+        let set: BTreeSet<CityName> = names.iter().map(|n| CityName::new(n).unwrap()).collect();
+        compress_set_to_cbor(&set)
+    }
+
+    fn write_legacy_db(path: &Path) {
+        let mut db = open_database_without_schema_check(path).unwrap();
+        db.mark_region_done(&region_md()).unwrap();
+        db.put("S:MD:21201", legacy_streets(&["north charles street", "n charles st"])).unwrap();
+        db.put("S2C:MD:north charles street", cities(&["Baltimore"])).unwrap();
+        db.put("S2C:MD:n charles st", cities(&["Towson"])).unwrap();
+    }
+
+    #[traced_test]
+    fn test_migrates_legacy_street_keys() {
+        let tmp = TempDir::new().unwrap();
+        write_legacy_db(tmp.path());
+        assert!(Database::open(tmp.path()).is_err(), "legacy layout must be refused before migrating");
+
+        let report = migrate_database_at_path(tmp.path()).unwrap();
+        assert_eq!(*report.from_version(), LEGACY_SCHEMA_VERSION);
+        assert_eq!(*report.to_version(), DB_SCHEMA_VERSION);
+        assert_eq!(*report.keys_removed(), 1, "both spellings share one key now");
+        assert_eq!(report.regions_without_change_baseline(), &vec![region_md()]);
+
+        let db_arc = Database::open(tmp.path()).expect("migrated database opens");
+        let db     = db_arc.lock().unwrap();
+
+        let street = StreetName::new_for_region("North Charles Street", &region_md()).unwrap();
+        let merged: Vec<CityName> = decompress_cbor_to_list(&db.get(s2c_key(&region_md(), &street)).unwrap().unwrap());
+        assert_eq!(merged, vec![CityName::new("Baltimore").unwrap(), CityName::new("Towson").unwrap()]);
+        assert!(db.get("S2C:MD:north charles street").unwrap().is_none());
+
+        let pc = PostalCode::new(Country::USA, "21201").unwrap();
+        let streets: Vec<StreetName> = decompress_cbor_to_list(&db.get(s_key(&region_md(), &pc)).unwrap().unwrap());
+        assert_eq!(streets, vec![street.clone()]);
+        assert_eq!(streets[0].canonical(), street.canonical());
+    }
+
+    #[traced_test]
+    fn test_keeps_every_distinct_legacy_street() {
+        let tmp = TempDir::new().unwrap();
+        {
+            let mut db = open_database_without_schema_check(tmp.path()).unwrap();
+            db.mark_region_done(&region_md()).unwrap();
+            db.put("S:MD:21201", legacy_streets(&["north charles street", "york road"])).unwrap();
+            db.put("C2S:MD:baltimore", legacy_streets(&["york road", "north charles street", "n charles st"])).unwrap();
+        }
+
+        migrate_database_at_path(tmp.path()).unwrap();
+
+        let db_arc = Database::open(tmp.path()).unwrap();
+        let db     = db_arc.lock().unwrap();
+        let mut expected = vec![
+            StreetName::new_for_region("North Charles Street", &region_md()).unwrap(),
+            StreetName::new_for_region("York Road", &region_md()).unwrap(),
+        ];
+        expected.sort();
+
+        let pc = PostalCode::new(Country::USA, "21201").unwrap();
+        let by_postal_code: Vec<StreetName> = decompress_cbor_to_list(&db.get(s_key(&region_md(), &pc)).unwrap().unwrap());
+        assert_eq!(by_postal_code, expected);
+
+        let city = CityName::new("Baltimore").unwrap();
+        let by_city: Vec<StreetName> = decompress_cbor_to_list(&db.get(c2s_key(&region_md(), &city)).unwrap().unwrap());
+        assert_eq!(by_city, expected);
+    }
+
+    #[traced_test]
+    fn test_interrupted_migration_can_run_again() {
+        let tmp = TempDir::new().unwrap();
+        write_legacy_db(tmp.path());
+        {
+            // a run that stopped after its rewrites, before stamping the version
+            let db = open_database_without_schema_check(tmp.path()).unwrap();
+            migrate_v1_to_v2(&db, &mut SchemaMigrationReport::default()).unwrap();
+        }
+
+        let report = migrate_database_at_path(tmp.path()).unwrap();
+        assert_eq!(*report.from_version(), LEGACY_SCHEMA_VERSION);
+        assert_eq!(*report.keys_removed(), 0);
+
+        let db_arc = Database::open(tmp.path()).unwrap();
+        let db     = db_arc.lock().unwrap();
+        let street = StreetName::new_for_region("North Charles Street", &region_md()).unwrap();
+        let merged: Vec<CityName> = decompress_cbor_to_list(&db.get(s2c_key(&region_md(), &street)).unwrap().unwrap());
+        assert_eq!(merged, vec![CityName::new("Baltimore").unwrap(), CityName::new("Towson").unwrap()]);
+    }
+
+    #[traced_test]
+    fn test_located_union_drops_repeats() {
+        let at = |lat: f64, street: &str| LocatedAddressBuilder::default()
+            .region(region_md())
+            .street(Some(StreetName::new(street).unwrap()))
+            .location(GeoPoint::from_degrees(lat, -76.6).unwrap())
+            .build()
+            .unwrap();
+
+        let merged = union_located(
+            vec![at(39.3, "York Road"), at(39.2, "Main Street")],
+            vec![at(39.2, "Elm Street"), at(39.3, "York Road"), at(39.2, "Main Street")],
+        );
+        assert_eq!(merged, vec![at(39.2, "Main Street"), at(39.2, "Elm Street"), at(39.3, "York Road")]);
+    }
+
+    #[traced_test]
+    fn test_current_database_is_left_alone() {
+        let tmp = TempDir::new().unwrap();
+        {
+            let db_arc = Database::open(tmp.path()).unwrap();
+            let mut db = db_arc.lock().unwrap();
+            db.put("Z2C:MD:21201", cities(&["Baltimore"])).unwrap();
+        }
+
+        let report = migrate_database_at_path(tmp.path()).unwrap();
+        assert_eq!(*report.from_version(), DB_SCHEMA_VERSION);
+        assert_eq!(*report.keys_rewritten(), 0);
+    }
+}
@@ -14,7 +14,7 @@ impl Mock for WorldAddress {
             .region(region)
             .postal_code(PostalCode::new(Country::USA, "20138-9997").unwrap())
             .city(CityName::new("Calverton").unwrap())
-            .street(StreetName::new("Catlett Road").unwrap())
+            .street(StreetName::new_for_country("Catlett Road", Country::USA).unwrap())
             .build()
             .unwrap()
     }
@@ -57,13 +57,13 @@ fn maryland_mock_records() -> Vec<AddressRecord> {
     let bethesda         = CityName::new("Bethesda").unwrap();
     let rockville        = CityName::new("Rockville").unwrap();
 
-    let north_avenue     = StreetName::new("North Avenue").unwrap();
-    let greenmount_avenue= StreetName::new("Greenmount Avenue").unwrap();
-    let howard_street    = StreetName::new("Howard Street").unwrap();
-    let wisconsin_avenue = StreetName::new("Wisconsin Avenue").unwrap();
-    let old_georgetown   = StreetName::new("Old Georgetown Road").unwrap();
-    let rockville_pike   = StreetName::new("Rockville Pike").unwrap();
-    let veirs_mill_road  = StreetName::new("Veirs Mill Road").unwrap();
+    let north_avenue     = StreetName::new_for_country("North Avenue", Country::USA).unwrap();
+    let greenmount_avenue= StreetName::new_for_country("Greenmount Avenue", Country::USA).unwrap();
+    let howard_street    = StreetName::new_for_country("Howard Street", Country::USA).unwrap();
+    let wisconsin_avenue = StreetName::new_for_country("Wisconsin Avenue", Country::USA).unwrap();
+    let old_georgetown   = StreetName::new_for_country("Old Georgetown Road", Country::USA).unwrap();
+    let rockville_pike   = StreetName::new_for_country("Rockville Pike", Country::USA).unwrap();
+    let veirs_mill_road  = StreetName::new_for_country("Veirs Mill Road", Country::USA).unwrap();
 
     let postalcode21201         = PostalCode::new(Country::USA,"21201").unwrap();
     let postalcode20814         = PostalCode::new(Country::USA,"20814").unwrap();
@@ -92,19 +92,19 @@ fn virginia_mock_records() -> Vec<AddressRecord> {
     let alexandria        = CityName::new("Alexandria").unwrap();
     let reston            = CityName::new("Reston").unwrap();
 
-    let wilson_blvd       = StreetName::new("Wilson Blvd").unwrap();
-    let clarendon_blvd    = StreetName::new("Clarendon Blvd").unwrap();
-    let king_st           = StreetName::new("King St").unwrap();
-    let mount_vernon_ave  = StreetName::new("Mount Vernon Ave").unwrap();
-    let reston_pkwy       = StreetName::new("Reston Pkwy").unwrap();
-    let sunrise_valley    = StreetName::new("Sunrise Valley Dr").unwrap();
+    let wilson_blvd       = StreetName::new_for_country("Wilson Blvd", Country::USA).unwrap();
+    let clarendon_blvd    = StreetName::new_for_country("Clarendon Blvd", Country::USA).unwrap();
+    let king_st           = StreetName::new_for_country("King St", Country::USA).unwrap();
+    let mount_vernon_ave  = StreetName::new_for_country("Mount Vernon Ave", Country::USA).unwrap();
+    let reston_pkwy       = StreetName::new_for_country("Reston Pkwy", Country::USA).unwrap();
+    let sunrise_valley    = StreetName::new_for_country("Sunrise Valley Dr", Country::USA).unwrap();
 
     let postalcode22201          = PostalCode::new(Country::USA,"22201").unwrap();
     let postalcode22301          = PostalCode::new(Country::USA,"22301").unwrap();
     let postalcode20190          = PostalCode::new(Country::USA,"20190").unwrap();
 
     let calverton       = CityName::new("Calverton").unwrap();
-    let catlett_road = StreetName::new("Catlett Road").unwrap();
+    let catlett_road = StreetName::new_for_country("Catlett Road", Country::USA).unwrap();
     let pc20138_9997       = PostalCode::new(Country::USA, "20138-9997").unwrap();
 
     vec![
@@ -128,10 +128,10 @@ fn dc_mock_records() -> Vec<AddressRecord> {
 
     let washington_dc    = CityName::new("Washington, DC").unwrap();
 
-    let maryland_ave     = StreetName::new("Maryland Ave").unwrap();
-    let pennsylvania_ave = StreetName::new("Pennsylvania Ave").unwrap();
-    let wisconsin_avenue = StreetName::new("Wisconsin Avenue").unwrap();
-    let m_st_nw          = StreetName::new("M St NW").unwrap();
+    let maryland_ave     = StreetName::new_for_country("Maryland Ave", Country::USA).unwrap();
+    let pennsylvania_ave = StreetName::new_for_country("Pennsylvania Ave", Country::USA).unwrap();
+    let wisconsin_avenue = StreetName::new_for_country("Wisconsin Avenue", Country::USA).unwrap();
+    let m_st_nw          = StreetName::new_for_country("M St NW", Country::USA).unwrap();
 
     let postalcode20001         = PostalCode::new(Country::USA,"20001").unwrap();
     let postalcode20007         = PostalCode::new(Country::USA,"20007").unwrap();
@@ -163,8 +163,8 @@ mod test_mock {
         assert_eq!(
             *region, 
             expected_region, 
-            "{}", 
-            format!("Expected region = Virginia, got region = {:?}", region)
+            "Expected region = Virginia, got region = {:?}", 
+            region
         );
 
         // Postal code => "20138-9997"
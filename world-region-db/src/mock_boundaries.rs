@@ -0,0 +1,38 @@
+// ---------------- [ File: src/mock_boundaries.rs ]
+crate::ix!();
+
+/// A [`GeoPoint`] from degrees that are known to be valid.
+pub fn mock_point(lat: f64, lon: f64) -> GeoPoint {
+    GeoPoint::from_degrees(lat, lon).unwrap()
+}
+
+/// A closed, axis-aligned square ring.
+pub fn mock_square_ring(south: f64, west: f64, north: f64, east: f64) -> BoundaryRing {
+    vec![
+        mock_point(south, west),
+        mock_point(south, east),
+        mock_point(north, east),
+        mock_point(north, west),
+        mock_point(south, west),
+    ]
+}
+
+/// A square [`AdminBoundary`] in the [`example_region`], without holes.
+pub fn mock_square_boundary(
+    relation_id: i64,
+    kind:        BoundaryKind,
+    name:        &str,
+    south:       f64,
+    west:        f64,
+    north:       f64,
+    east:        f64,
+) -> AdminBoundary {
+    AdminBoundaryBuilder::default()
+        .region(example_region())
+        .relation_id(relation_id)
+        .kind(kind)
+        .name(name)
+        .outer(vec![mock_square_ring(south, west, north, east)])
+        .build()
+        .unwrap()
+}
@@ -6,12 +6,15 @@
 // to control whether addresses pass/fail validation.
 crate::ix!();
 
+/// (regionAbbrev, postalCode) -> set of valid cityNames
+type PostalToCityMap = HashMap<(String, String), HashSet<String>>;
+
 #[derive(Clone, Default)]
 pub struct MockDataAccess {
     // 1) Addresses that should fail no matter what
     invalid_addresses: Arc<Mutex<Vec<WorldAddress>>>,
     // 2) A map from (regionAbbrev, postalCode) -> set of valid cityNames
-    postal_to_city_map: Arc<Mutex<HashMap<(String, String), HashSet<String>>>>,
+    postal_to_city_map: Arc<Mutex<PostalToCityMap>>,
 }
 
 impl MockDataAccess {
@@ -38,7 +41,7 @@ impl MockDataAccess {
     ) {
         let mut map_lock = self.postal_to_city_map.lock().unwrap();
         let key = (region_abbrev.to_string(), postal_code.to_string());
-        let entry = map_lock.entry(key).or_insert_with(HashSet::new);
+        let entry = map_lock.entry(key).or_default();
         for c in cities {
             entry.insert(c.to_string());
         }
@@ -150,9 +150,16 @@ impl WriteIndicesForRegion for FailingDbStub {
 
 // Minimal stub that updates the first street but fails on the second
 pub struct FailingUpdateStub {
+    #[allow(dead_code)] // only read by tests
     pub(crate) calls: std::cell::RefCell<usize>,
 }
 
+impl Default for FailingUpdateStub {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl FailingUpdateStub {
     pub fn new() -> Self {
         FailingUpdateStub {
@@ -198,8 +205,7 @@ impl LoadExistingStreetRanges for FailingLoadDatabase {
         _r: &WorldRegion,
         _s: &StreetName,
     ) -> Result<Option<Vec<HouseNumberRange>>, DataAccessError> {
-        Err(DataAccessError::Io(std::io::Error::new(
-                    std::io::ErrorKind::Other,
+        Err(DataAccessError::Io(std::io::Error::other(
                     "Simulated load error",
         )))
     }
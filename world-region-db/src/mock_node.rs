@@ -43,8 +43,8 @@ impl MockNode {
         let data = build_mock_node_pbf_bytes(id, tags);
 
         // 2) Parse the array via osmpbf::BlobReader
-        let mut reader = BlobReader::new(Cursor::new(data));
-        while let Some(blob_res) = reader.next() {
+        let reader = BlobReader::new(Cursor::new(data));
+        for blob_res in reader {
             let blob = blob_res.expect("Error reading mock data blob");
             if let BlobType::OsmData = blob.get_type() {
                 let block = blob
@@ -138,7 +138,8 @@ fn build_mock_node_pbf_bytes(id: i64, tags: &[(&str, &str)]) -> Vec<u8> {
     // 7) Frame as <4-byte header-len><header><blob>
     use byteorder::{BigEndian, WriteBytesExt};
     let mut out = Vec::new();
-    byteorder::WriteBytesExt::write_u32::<BigEndian>(&mut out, header_bytes.len() as u32);
+    byteorder::WriteBytesExt::write_u32::<BigEndian>(&mut out, header_bytes.len() as u32)
+        .expect("writing to a Vec cannot fail");
     out.extend_from_slice(&header_bytes);
     out.extend_from_slice(&blob_bytes);
 
@@ -0,0 +1,193 @@
+// ---------------- [ File: src/nearest_addresses_to_point.rs ]
+crate::ix!();
+
+/// The finest geohash precision we start a reverse lookup at (~150m cells).
+const NEAREST_SEARCH_START_PRECISION: usize = 7;
+
+pub trait NearestAddressesToPoint {
+
+    fn nearest_addresses_to_point(
+        &self,
+        region: &WorldRegion,
+        point:  &GeoPoint,
+        limit:  usize,
+    ) -> Vec<NearbyAddress>;
+}
+
+impl<I:StorageInterface> NearestAddressesToPoint for DataAccess<I> {
+
+    /// Reverse geocoding: up to `limit` located addresses in `region`, closest
+    /// first.
+    ///
+    /// We scan the geohash cell around `point` plus its neighbours, starting
+    /// small and widening one precision level at a time until we have at
+    /// least `limit` candidates, then rank them by distance.
+    ///
+    /// A neighbourhood only covers one cell-width around `point`, while the
+    /// candidates it yields can lie up to about two cell-diagonals away, so a
+    /// closer address may sit just outside it. Once `limit` is reached we
+    /// therefore scan one level coarser, whose cells are at least four times
+    /// wider and so cover everything as close as the candidates found.
+    fn nearest_addresses_to_point(
+        &self,
+        region: &WorldRegion,
+        point:  &GeoPoint,
+        limit:  usize,
+    ) -> Vec<NearbyAddress> {
+        if limit == 0 {
+            return vec![];
+        }
+
+        let db_guard = match self.db().lock() {
+            Ok(guard) => guard,
+            Err(_) => {
+                warn!("nearest_addresses_to_point: could not get DB lock");
+                return vec![];
+            }
+        };
+
+        let scan = |precision: usize| {
+            let candidates = geohash_neighborhood(point, precision)
+                .iter()
+                .flat_map(|cell| load_located_addresses_in_cell(&*db_guard, region, cell))
+                .collect::<Vec<_>>();
+
+            trace!(
+                "nearest_addresses_to_point: precision={} => {} candidates",
+                precision,
+                candidates.len()
+            );
+            candidates
+        };
+
+        let mut candidates = Vec::new();
+        for precision in (1..=NEAREST_SEARCH_START_PRECISION).rev() {
+            candidates = scan(precision);
+            if candidates.len() >= limit {
+                if precision > 1 {
+                    candidates = scan(precision - 1);
+                }
+                break;
+            }
+        }
+
+        let mut nearby: Vec<NearbyAddress> = candidates
+            .into_iter()
+            .map(|a| {
+                let d = point.distance_meters(a.location());
+                NearbyAddress::new(a, d)
+            })
+            .collect();
+
+        nearby.sort_by(|a, b| a.distance_meters().total_cmp(b.distance_meters()));
+        nearby.truncate(limit);
+        nearby
+    }
+}
+
+/// Every located address stored under `GEO:{region}:{cell}*`.
+fn load_located_addresses_in_cell<I:StorageInterface>(
+    db:     &I,
+    region: &WorldRegion,
+    cell:   &str,
+) -> Vec<LocatedAddress> {
+    let prefix = geo_key(region, cell);
+    let mut out = Vec::new();
+
+    let mut iter = db.prefix_iterator(prefix.as_bytes());
+    while let Some(Ok((k, v))) = iter.next() {
+        if !k.starts_with(prefix.as_bytes()) {
+            break;
+        }
+        out.extend(decompress_cbor_to_list::<LocatedAddress>(&v));
+    }
+    out
+}
+
+#[cfg(test)]
+mod test_nearest_addresses_to_point {
+    use super::*;
+
+    fn region_md() -> WorldRegion {
+        USRegion::UnitedState(UnitedState::Maryland).into()
+    }
+
+    fn located(street: &str, lat: f64, lon: f64) -> AddressRecord {
+        let mut record = AddressRecord::new(
+            CityName::new("Baltimore").unwrap(),
+            StreetName::new(street).unwrap(),
+            PostalCode::new(Country::USA, "21201").unwrap(),
+        );
+        record.set_location(Some(GeoPoint::from_degrees(lat, lon).unwrap()));
+        record
+    }
+
+    #[traced_test]
+    fn test_empty_db_returns_nothing() {
+        let (data_access, _db, _tmp) = create_data_access::<Database>();
+        let p = GeoPoint::from_degrees(39.31, -76.62).unwrap();
+        assert!(data_access.nearest_addresses_to_point(&region_md(), &p, 5).is_empty());
+    }
+
+    #[traced_test]
+    fn test_results_are_sorted_by_distance() {
+        let (data_access, db, _tmp) = create_data_access::<Database>();
+        {
+            let mut guard = db.lock().unwrap();
+            guard.write_address_locations_for_region(&region_md(), &[
+                located("Far Street",   39.4000, -76.7000),
+                located("Near Street",  39.3101, -76.6201),
+                located("Close Street", 39.3120, -76.6220),
+            ]).unwrap();
+        }
+
+        let p = GeoPoint::from_degrees(39.3100, -76.6200).unwrap();
+        let nearest = data_access.nearest_addresses_to_point(&region_md(), &p, 2);
+
+        assert_eq!(nearest.len(), 2);
+        assert_eq!(nearest[0].address().street().as_ref().unwrap().name(), "near street");
+        assert_eq!(nearest[1].address().street().as_ref().unwrap().name(), "close street");
+        assert!(nearest[0].distance_meters() <= nearest[1].distance_meters());
+    }
+
+    #[traced_test]
+    fn test_closer_address_just_outside_the_first_neighbourhood_wins() {
+        let (data_access, db, _tmp) = create_data_access::<Database>();
+
+        // `p` sits at the west edge of its precision-7 cell. "Outside Street" is
+        // ~130m west, one step past the western neighbour; "Inside Street" is
+        // ~230m east, at the far side of the eastern neighbour.
+        let p = GeoPoint::from_degrees(39.30977, -76.62000).unwrap();
+        let outside = GeoPoint::from_degrees(39.30977, -76.62155).unwrap();
+        let inside  = GeoPoint::from_degrees(39.30977, -76.61730).unwrap();
+        assert!(!geohash_neighborhood(&p, 7).contains(&encode_geohash(&outside, 7)));
+        assert!(geohash_neighborhood(&p, 7).contains(&encode_geohash(&inside, 7)));
+        {
+            let mut guard = db.lock().unwrap();
+            guard.write_address_locations_for_region(&region_md(), &[
+                located("Outside Street", 39.30977, -76.62155),
+                located("Inside Street",  39.30977, -76.61730),
+            ]).unwrap();
+        }
+
+        let nearest = data_access.nearest_addresses_to_point(&region_md(), &p, 1);
+        assert_eq!(nearest.len(), 1);
+        assert_eq!(nearest[0].address().street().as_ref().unwrap().name(), "outside street");
+    }
+
+    #[traced_test]
+    fn test_search_widens_until_limit_is_met() {
+        let (data_access, db, _tmp) = create_data_access::<Database>();
+        {
+            let mut guard = db.lock().unwrap();
+            guard.write_address_locations_for_region(&region_md(), &[
+                located("Far Street", 39.4000, -76.7000),
+            ]).unwrap();
+        }
+
+        let p = GeoPoint::from_degrees(39.3100, -76.6200).unwrap();
+        let nearest = data_access.nearest_addresses_to_point(&region_md(), &p, 1);
+        assert_eq!(nearest.len(), 1);
+        assert!(*nearest[0].distance_meters() > 5_000.0);
+    }
+}
@@ -79,7 +79,7 @@ mod test_obtain_pbf_file_for_region {
         mock_region: &MockRegion,
         target_dir: &Path,
     ) -> Result<PathBuf, WorldCityAndStreetDbBuilderError> {
-        Ok(mock_region.find_file_locally_or_download_into(target_dir).await?)
+        mock_region.find_file_locally_or_download_into(target_dir).await
     }
 
     #[traced_test]
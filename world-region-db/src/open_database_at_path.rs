@@ -4,36 +4,93 @@ crate::ix!();
 pub trait OpenDatabaseAtPath {
     fn open(path: impl AsRef<std::path::Path>) 
         -> Result<Arc<Mutex<Self>>, WorldCityAndStreetDbBuilderError>;
+
+    /// Opens without write access. Stores that have no such mode (test
+    /// doubles, mostly) just open normally.
+    fn open_read_only(path: impl AsRef<std::path::Path>) 
+        -> Result<Arc<Mutex<Self>>, WorldCityAndStreetDbBuilderError>
+    where
+        Self: Sized,
+    {
+        Self::open(path)
+    }
 }
 
 impl OpenDatabaseAtPath for Database {
 
+    /// Opens (or creates) the database and checks its key layout: a new
+    /// database is stamped with [`DB_SCHEMA_VERSION`], an older one must go
+    /// through [`migrate_database_at_path`] first.
     fn open(path: impl AsRef<std::path::Path>)
         -> Result<Arc<Mutex<Self>>, WorldCityAndStreetDbBuilderError>
     {
-        let mut opts = Options::default();
-        opts.create_if_missing(true);
-        opts.set_compression_type(DBCompressionType::Zstd);
-
-        // 1) Use the “colon prefix” transform so that RocksDB
-        //    stores an extracted prefix up to the second colon.
-        let st = create_colon_prefix_transform();
-        opts.set_prefix_extractor(st);
-
-        // Optionally enable prefix bloom filters
-        opts.set_memtable_prefix_bloom_ratio(0.1);
+        let mut db = open_database_without_schema_check(path)?;
+        ensure_current_schema(&mut db)?;
+        Ok(Arc::new(Mutex::new(db)))
+    }
 
-        let db = DB::open(&opts, path).map_err(|e| DataAccessError::RocksDB(e))?;
+    /// Opens an existing database without taking RocksDB's write lock, so
+    /// any number of processes can serve queries from one database (and
+    /// alongside a writer). Writes fail, and data written by others after
+    /// opening is not seen.
+    fn open_read_only(path: impl AsRef<std::path::Path>)
+        -> Result<Arc<Mutex<Self>>, WorldCityAndStreetDbBuilderError>
+    {
+        let db = DB::open_for_read_only(&database_options(), path, false)
+            .map_err(DataAccessError::RocksDB)?;
 
         let db = DatabaseBuilder::default()
             .db(Arc::new(db))
             .build()
             .unwrap();
 
+        ensure_readable_schema(&db)?;
         Ok(Arc::new(Mutex::new(db)))
     }
 }
 
+/// Takes a store just opened through [`OpenDatabaseAtPath`] out of its
+/// mutex, for callers that only read it and share it without a lock (see
+/// [`AddressService`]). `None` if the handle is still shared or the lock is
+/// poisoned.
+pub fn into_unlocked_store<I>(db: Arc<Mutex<I>>) -> Option<I> {
+    Arc::try_unwrap(db).ok()?.into_inner().ok()
+}
+
+/// Opens (or creates) the RocksDB at `path` with our options, whatever
+/// layout its keys are in. Only migration should need this directly.
+pub fn open_database_without_schema_check(path: impl AsRef<std::path::Path>)
+    -> Result<Database, WorldCityAndStreetDbBuilderError>
+{
+    let mut opts = database_options();
+    opts.create_if_missing(true);
+
+    let db = DB::open(&opts, path).map_err(DataAccessError::RocksDB)?;
+
+    let db = DatabaseBuilder::default()
+        .db(Arc::new(db))
+        .build()
+        .unwrap();
+
+    Ok(db)
+}
+
+/// Options every handle on the database must agree on.
+fn database_options() -> Options {
+    let mut opts = Options::default();
+    opts.set_compression_type(DBCompressionType::Zstd);
+
+    // 1) Use the “colon prefix” transform so that RocksDB
+    //    stores an extracted prefix up to the second colon.
+    let st = create_colon_prefix_transform();
+    opts.set_prefix_extractor(st);
+
+    // Optionally enable prefix bloom filters
+    opts.set_memtable_prefix_bloom_ratio(0.1);
+
+    opts
+}
+
 #[cfg(test)]
 mod test_open_database_at_path {
     use super::*;
@@ -147,6 +204,27 @@ mod test_open_database_at_path {
         }
     }
 
+    #[traced_test]
+    fn test_open_read_only_next_to_writer() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let writer = Database::open(temp_dir.path()).unwrap();
+        writer.lock().unwrap().put("Z2C:MD:21201", b"cities").unwrap();
+        writer.lock().unwrap().db().flush().unwrap();
+
+        // Neither reader takes the lock the writer holds
+        let reader_a = Database::open_read_only(temp_dir.path()).expect("first reader");
+        let reader_b = Database::open_read_only(temp_dir.path()).expect("second reader");
+
+        assert_eq!(reader_a.lock().unwrap().get("Z2C:MD:21201").unwrap().unwrap(), b"cities");
+        assert!(reader_b.lock().unwrap().put("Z2C:MD:21202", b"x").is_err(), "readers cannot write");
+    }
+
+    #[traced_test]
+    fn test_open_read_only_missing_database_fails() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        assert!(Database::open_read_only(temp_dir.path().join("absent")).is_err());
+    }
+
     /// Verifies that the prefix transform and bloom filter options are set without error.
     /// We can't easily introspect RocksDB's internal state to confirm, but we can open
     /// the DB to ensure no panic or config error arises from these advanced options.
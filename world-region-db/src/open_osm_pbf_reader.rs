@@ -8,8 +8,7 @@ pub fn open_osm_pbf_reader(
 
     // If `p` is a directory => produce an IO error wrapped in an OsmPbf(...) variant
     if p.is_dir() {
-        let io_err = std::io::Error::new(
-            std::io::ErrorKind::Other,
+        let io_err = std::io::Error::other(
             format!("{:?} is a directory, not a file", p),
         );
         // Then return OsmPbfParseError::OsmPbf(...)
@@ -23,6 +22,30 @@ pub fn open_osm_pbf_reader(
     }
 }
 
+/// Like [`open_osm_pbf_reader`], but every byte read from the file is
+/// counted into `progress`, whose total is set to the file size.
+pub fn open_osm_pbf_reader_with_progress<'a>(
+    path:     impl AsRef<std::path::Path>,
+    progress: &'a RegionBuildProgress,
+) -> Result<ElementReader<std::io::BufReader<ProgressReader<'a, std::fs::File>>>, OsmPbfParseError> {
+    let p = path.as_ref();
+
+    if p.is_dir() {
+        let io_err = std::io::Error::other(
+            format!("{:?} is a directory, not a file", p),
+        );
+        return Err(OsmPbfParseError::IoError(io_err));
+    }
+
+    // Same error as `ElementReader::from_path` gives for a missing file.
+    let file = std::fs::File::open(p).map_err(|e| OsmPbfParseError::OsmPbf(osmpbf::Error::from(e)))?;
+    if let Ok(meta) = file.metadata() {
+        progress.set_total_bytes(meta.len());
+    }
+
+    Ok(ElementReader::new(std::io::BufReader::new(ProgressReader::new(file, progress))))
+}
+
 #[cfg(test)]
 mod open_osm_pbf_reader_tests {
     use super::*;
@@ -166,6 +166,7 @@ mod test_open_pbf_reader_or_report_error {
         let mut file = OpenOptions::new()
             .write(true)
             .create(true)
+            .truncate(true)
             .open(&file_path)
             .expect("Failed to create file");
         writeln!(file, "O").expect("Write 1 byte to file");
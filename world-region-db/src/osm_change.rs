@@ -0,0 +1,290 @@
+// ---------------- [ File: src/osm_change.rs ]
+crate::ix!();
+
+/// Which `<create>`, `<modify>` or `<delete>` block an element appeared in.
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+pub enum OsmChangeAction {
+    Create,
+    Modify,
+    Delete,
+}
+
+/// One node, way or relation from an osmChange (`.osc`) file.
+#[derive(Getters,Clone,Debug,PartialEq)]
+#[getset(get="pub")]
+pub struct OsmChangeElement {
+    action:    OsmChangeAction,
+    element:   OsmElementRef,
+    tags:      Vec<(String, String)>,
+    /// Nodes only; deleted nodes usually carry none.
+    location:  Option<GeoPoint>,
+    /// Ways only: the `<nd ref=".."/>` list.
+    node_refs: Vec<i64>,
+}
+
+impl OsmChangeElement {
+
+    pub fn tag_iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.tags.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+}
+
+/// The parsed contents of one OSM replication diff, in file order.
+#[derive(Getters,Clone,Debug,Default,PartialEq)]
+#[getset(get="pub")]
+pub struct OsmChange {
+    elements: Vec<OsmChangeElement>,
+}
+
+impl OsmChange {
+
+    /// Reads a `.osc` file, or a `.osc.gz` file (by extension).
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, OsmChangeError> {
+        let path = path.as_ref();
+        let file = std::fs::File::open(path)?;
+        let gzipped = path.extension().map(|e| e == "gz").unwrap_or(false);
+
+        trace!("OsmChange::from_path: reading {:?} (gzipped={})", path, gzipped);
+        if gzipped {
+            Self::parse(std::io::BufReader::new(GzDecoder::new(file)))
+        } else {
+            Self::parse(std::io::BufReader::new(file))
+        }
+    }
+
+    /// Parses osmChange XML. Elements outside a create/modify/delete block,
+    /// and any tags we don't use, are ignored.
+    pub fn parse<R: std::io::BufRead>(reader: R) -> Result<Self, OsmChangeError> {
+        let mut xml = XmlReader::from_reader(reader);
+        xml.trim_text(true);
+
+        let mut state = OsmChangeParseState::default();
+        let mut buf   = Vec::new();
+        loop {
+            let event = xml.read_event_into(&mut buf).map_err(|e| OsmChangeError::InvalidXml {
+                msg: format!("at byte {}: {}", xml.buffer_position(), e),
+            })?;
+            match event {
+                XmlEvent::Start(e) => state.open(&e, false)?,
+                XmlEvent::Empty(e) => state.open(&e, true)?,
+                XmlEvent::End(e)   => state.close(e.name().as_ref()),
+                XmlEvent::Eof      => break,
+                _ => {}
+            }
+            buf.clear();
+        }
+
+        debug!("OsmChange::parse: {} elements", state.elements.len());
+        Ok(Self { elements: state.elements })
+    }
+
+    /// Coordinates of every node in this change that has them, for
+    /// computing the centroid of ways created or modified alongside.
+    pub fn node_locations(&self) -> HashMap<i64, GeoPoint> {
+        self.elements
+            .iter()
+            .filter_map(|el| match (el.element, el.location) {
+                (OsmElementRef::Node(id), Some(p)) => Some((id, p)),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[derive(Default)]
+struct OsmChangeParseState {
+    action:   Option<OsmChangeAction>,
+    current:  Option<OsmChangeElement>,
+    elements: Vec<OsmChangeElement>,
+}
+
+impl OsmChangeParseState {
+
+    fn open(&mut self, e: &BytesStart, self_closing: bool) -> Result<(), OsmChangeError> {
+        let name = e.name();
+        match name.as_ref() {
+            b"create" if !self_closing => self.action = Some(OsmChangeAction::Create),
+            b"modify" if !self_closing => self.action = Some(OsmChangeAction::Modify),
+            b"delete" if !self_closing => self.action = Some(OsmChangeAction::Delete),
+
+            kind @ (b"node" | b"way" | b"relation") => {
+                let Some(action) = self.action else {
+                    return Ok(());
+                };
+                let label = String::from_utf8_lossy(kind).into_owned();
+                let attrs = xml_attributes(e)?;
+                let id: i64 = parse_xml_attribute(&attrs, &label, "id")?;
+
+                let element = match kind {
+                    b"node" => OsmElementRef::Node(id),
+                    b"way"  => OsmElementRef::Way(id),
+                    _       => OsmElementRef::Relation(id),
+                };
+                let location = match (attrs.get("lat"), attrs.get("lon")) {
+                    (Some(_), Some(_)) => {
+                        let lat = parse_xml_attribute(&attrs, &label, "lat")?;
+                        let lon = parse_xml_attribute(&attrs, &label, "lon")?;
+                        GeoPoint::from_degrees(lat, lon).ok()
+                    }
+                    _ => None,
+                };
+
+                let parsed = OsmChangeElement {
+                    action,
+                    element,
+                    tags: vec![],
+                    location,
+                    node_refs: vec![],
+                };
+                if self_closing {
+                    self.elements.push(parsed);
+                } else {
+                    self.current = Some(parsed);
+                }
+            }
+
+            b"tag" => {
+                if let Some(current) = self.current.as_mut() {
+                    let mut attrs = xml_attributes(e)?;
+                    if let (Some(k), Some(v)) = (attrs.remove("k"), attrs.remove("v")) {
+                        current.tags.push((k, v));
+                    }
+                }
+            }
+
+            b"nd" => {
+                if let Some(current) = self.current.as_mut() {
+                    let attrs = xml_attributes(e)?;
+                    current.node_refs.push(parse_xml_attribute(&attrs, "nd", "ref")?);
+                }
+            }
+
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn close(&mut self, name: &[u8]) {
+        match name {
+            b"create" | b"modify" | b"delete" => self.action = None,
+            b"node" | b"way" | b"relation" => {
+                if let Some(done) = self.current.take() {
+                    self.elements.push(done);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn xml_attributes(e: &BytesStart) -> Result<HashMap<String, String>, OsmChangeError> {
+    let mut out = HashMap::new();
+    for attr in e.attributes() {
+        let attr = attr.map_err(|err| OsmChangeError::InvalidXml { msg: err.to_string() })?;
+        let key = String::from_utf8_lossy(attr.key.as_ref()).into_owned();
+        let value = attr
+            .unescape_value()
+            .map_err(|err| OsmChangeError::InvalidXml { msg: err.to_string() })?
+            .into_owned();
+        out.insert(key, value);
+    }
+    Ok(out)
+}
+
+fn parse_xml_attribute<T: std::str::FromStr>(
+    attrs:     &HashMap<String, String>,
+    element:   &str,
+    attribute: &str,
+) -> Result<T, OsmChangeError> {
+    let value = attrs.get(attribute).map(String::as_str).unwrap_or_default();
+    value.parse().map_err(|_| OsmChangeError::InvalidAttribute {
+        element:   element.to_string(),
+        attribute: attribute.to_string(),
+        value:     value.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod osm_change_tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<osmChange version="0.6" generator="test">
+  <create>
+    <node id="1" version="1" lat="39.3100000" lon="-76.6200000">
+      <tag k="addr:city" v="Baltimore"/>
+      <tag k="addr:street" v="North Avenue"/>
+      <tag k="addr:postcode" v="21201"/>
+      <tag k="addr:housenumber" v="10"/>
+    </node>
+    <node id="2" version="1" lat="39.3110000" lon="-76.6210000"/>
+  </create>
+  <modify>
+    <way id="5" version="3">
+      <nd ref="2"/>
+      <nd ref="3"/>
+      <tag k="building" v="yes"/>
+    </way>
+  </modify>
+  <delete>
+    <node id="9" version="4"/>
+  </delete>
+</osmChange>"#;
+
+    #[traced_test]
+    fn test_parse_actions_tags_and_refs() {
+        let change = OsmChange::parse(SAMPLE.as_bytes()).unwrap();
+        let els = change.elements();
+        assert_eq!(els.len(), 4);
+
+        assert_eq!(els[0].action(), &OsmChangeAction::Create);
+        assert_eq!(els[0].element(), &OsmElementRef::Node(1));
+        assert_eq!(els[0].tags().len(), 4);
+        assert!(els[0].tag_iter().any(|(k, v)| k == "addr:street" && v == "North Avenue"));
+
+        assert_eq!(els[1].element(), &OsmElementRef::Node(2));
+        assert!(els[1].tags().is_empty());
+
+        assert_eq!(els[2].action(), &OsmChangeAction::Modify);
+        assert_eq!(els[2].element(), &OsmElementRef::Way(5));
+        assert_eq!(els[2].node_refs(), &vec![2, 3]);
+        assert!(els[2].location().is_none());
+
+        assert_eq!(els[3].action(), &OsmChangeAction::Delete);
+        assert_eq!(els[3].element(), &OsmElementRef::Node(9));
+        assert!(els[3].location().is_none());
+    }
+
+    #[traced_test]
+    fn test_node_locations() {
+        let change = OsmChange::parse(SAMPLE.as_bytes()).unwrap();
+        let locs = change.node_locations();
+        assert_eq!(locs.len(), 2);
+        assert_eq!(locs[&2], GeoPoint::from_degrees(39.311, -76.621).unwrap());
+    }
+
+    #[traced_test]
+    fn test_bad_id_is_reported() {
+        let xml = r#"<osmChange><create><node id="x" lat="1" lon="1"/></create></osmChange>"#;
+        match OsmChange::parse(xml.as_bytes()) {
+            Err(OsmChangeError::InvalidAttribute { element, attribute, value }) => {
+                assert_eq!((element.as_str(), attribute.as_str(), value.as_str()), ("node", "id", "x"));
+            }
+            other => panic!("expected InvalidAttribute, got {:?}", other),
+        }
+    }
+
+    #[traced_test]
+    fn test_reads_gzipped_file() {
+        let tmp  = TempDir::new().unwrap();
+        let path = tmp.path().join("000123.osc.gz");
+        {
+            let file = std::fs::File::create(&path).unwrap();
+            let mut gz = flate2::write::GzEncoder::new(file, flate2::Compression::default());
+            gz.write_all(SAMPLE.as_bytes()).unwrap();
+            gz.finish().unwrap();
+        }
+        let change = OsmChange::from_path(&path).unwrap();
+        assert_eq!(change.elements().len(), 4);
+    }
+}
@@ -0,0 +1,75 @@
+// ---------------- [ File: src/osm_element_ref.rs ]
+crate::ix!();
+
+/// Identifies the OSM element an address came from. Node ids, way ids and
+/// relation ids are separate number spaces, so the kind is part of the id.
+#[derive(Copy,Clone,Debug,Hash,PartialEq,Eq,PartialOrd,Ord,Serialize,Deserialize)]
+pub enum OsmElementRef {
+    Node(i64),
+    Way(i64),
+    Relation(i64),
+}
+
+impl OsmElementRef {
+
+    pub fn of(element: &osmpbf::Element) -> Self {
+        match element {
+            osmpbf::Element::Node(n)       => OsmElementRef::Node(n.id()),
+            osmpbf::Element::DenseNode(dn) => OsmElementRef::Node(dn.id()),
+            osmpbf::Element::Way(w)        => OsmElementRef::Way(w.id()),
+            osmpbf::Element::Relation(r)   => OsmElementRef::Relation(r.id()),
+        }
+    }
+
+    pub fn id(&self) -> i64 {
+        match self {
+            OsmElementRef::Node(id) | OsmElementRef::Way(id) | OsmElementRef::Relation(id) => *id,
+        }
+    }
+
+    /// Inverse of the `Display` form: `n123`, `w456`, `r789`.
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut chars = text.chars();
+        let kind = chars.next()?;
+        let id   = chars.as_str().parse::<i64>().ok()?;
+        match kind {
+            'n' => Some(OsmElementRef::Node(id)),
+            'w' => Some(OsmElementRef::Way(id)),
+            'r' => Some(OsmElementRef::Relation(id)),
+            _   => None,
+        }
+    }
+}
+
+/// `n123`, `w456`, `r789`, as used in `OSM:` keys.
+impl fmt::Display for OsmElementRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OsmElementRef::Node(id)     => write!(f, "n{}", id),
+            OsmElementRef::Way(id)      => write!(f, "w{}", id),
+            OsmElementRef::Relation(id) => write!(f, "r{}", id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod osm_element_ref_tests {
+    use super::*;
+
+    #[traced_test]
+    fn test_display_and_id() {
+        assert_eq!(OsmElementRef::Node(42).to_string(), "n42");
+        assert_eq!(OsmElementRef::Way(7).to_string(), "w7");
+        assert_eq!(OsmElementRef::Relation(-3).id(), -3);
+        assert_ne!(OsmElementRef::Node(1), OsmElementRef::Way(1));
+    }
+
+    #[traced_test]
+    fn test_parse_inverts_display() {
+        for element in [OsmElementRef::Node(42), OsmElementRef::Way(7), OsmElementRef::Relation(-3)] {
+            assert_eq!(OsmElementRef::parse(&element.to_string()), Some(element));
+        }
+        assert_eq!(OsmElementRef::parse("x1"), None);
+        assert_eq!(OsmElementRef::parse("n"), None);
+    }
+}
@@ -82,7 +82,6 @@ mod test_parse_city_names {
             ("C2Z:US:annapolis".to_string(), make_valid_cbor()),
         ]
         .into_iter()
-        .map(|(k, v)| (k, v))
         .collect::<Vec<_>>();
 
         let mut result = parse_city_names(kv_pairs);
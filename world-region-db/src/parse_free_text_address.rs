@@ -0,0 +1,341 @@
+// ---------------- [ File: src/parse_free_text_address.rs ]
+crate::ix!();
+
+/// How many ranked candidates a free-text parse returns at most.
+pub const MAX_FREE_TEXT_CANDIDATES: usize = 10;
+
+/// Fuzzy street matches scoring below this (relative to a perfect match)
+/// are not offered as candidates.
+const MIN_FUZZY_STREET_SCORE: f64 = 0.3;
+
+/// One interpretation of a free-text address.
+///
+/// `confidence` is in `0.0..=1.0` and is built from how each component was
+/// found: a postal code typed in the text and known to the DB, a city span
+/// known for that postal code, an exact (or fuzzy) street match, and a house
+/// number that falls inside a known range for the street.
+#[derive(Getters,Clone,Debug,PartialEq)]
+#[getset(get="pub")]
+pub struct AddressCandidate {
+    address:      WorldAddress,
+    house_number: Option<HouseNumber>,
+    confidence:   f64,
+}
+
+pub trait ParseFreeTextAddress {
+
+    fn parse_free_text_address(
+        &self,
+        region: &WorldRegion,
+        text:   &str,
+    ) -> Result<Vec<AddressCandidate>, FreeTextAddressError>;
+}
+
+impl<I:StorageInterface> ParseFreeTextAddress for DataAccess<I> {
+
+    /// Parses raw text like `"1600 N. Charles St, Baltimore MD 21201"` into
+    /// ranked [`WorldAddress`] candidates, best first.
+    ///
+    /// 1. Tokenize, and drop the region itself (`"MD"`) where a state goes:
+    ///    next to the postal code or at the end.
+    /// 2. Find postal codes with the country's postal-code rules.
+    /// 3. Take the first house-number-looking token that is not the postal code.
+    /// 4. Find the longest token span naming a city known for that postal code
+    ///    (or, with no postal code, anywhere in the region).
+    /// 5. Match the remaining tokens on the other side of the city against the
+    ///    known streets, exactly or fuzzily.
+    fn parse_free_text_address(
+        &self,
+        region: &WorldRegion,
+        text:   &str,
+    ) -> Result<Vec<AddressCandidate>, FreeTextAddressError> {
+        trace!("parse_free_text_address: region={:?}, text={:?}", region, text);
+
+        let country = Country::try_from(*region)?;
+        let region_abbr = region.abbreviation().to_lowercase();
+
+        let tokens = drop_region_abbreviation(tokenize_free_text_address(text), &region_abbr, country);
+
+        if tokens.is_empty() {
+            return Err(FreeTextAddressError::EmptyInput);
+        }
+
+        let mut postal_spans: Vec<Option<(usize, usize, PostalCode)>> =
+            detect_postal_code_spans(&tokens, country).into_iter().map(Some).collect();
+        if postal_spans.is_empty() {
+            postal_spans.push(None);
+        }
+
+        let mut candidates = Vec::new();
+        for span in postal_spans {
+            candidates.extend(self.candidates_for_postal_span(region, &tokens, span));
+        }
+
+        Ok(rank_address_candidates(candidates))
+    }
+}
+
+impl<I:StorageInterface> DataAccess<I> {
+
+    fn candidates_for_postal_span(
+        &self,
+        region: &WorldRegion,
+        tokens: &[AddressToken],
+        span:   Option<(usize, usize, PostalCode)>,
+    ) -> Vec<AddressCandidate> {
+        let in_span = |i: usize| matches!(&span, Some((a, b, _)) if (*a..=*b).contains(&i));
+
+        // house number: first numeric-looking token outside the postal code
+        let house_idx = (0..tokens.len())
+            .find(|&i| !in_span(i) && parse_house_number_token(tokens[i].raw()).is_some());
+        let house_number = house_idx.and_then(|i| parse_house_number_token(tokens[i].raw()));
+
+        let words: Vec<&str> = (0..tokens.len())
+            .filter(|&i| !in_span(i) && Some(i) != house_idx)
+            .map(|i| tokens[i].normalized().as_str())
+            .collect();
+
+        let postal_code = span.map(|(_, _, pc)| pc);
+
+        let known_cities: HashSet<String> = match &postal_code {
+            Some(pc) => self.cities_for_postal_code(region, pc)
+                .unwrap_or_default()
+                .iter()
+                .map(|c| c.name().to_string())
+                .collect(),
+            None => match self.db().lock() {
+                Ok(guard) => load_all_cities_for_region(&*guard, region).into_iter().collect(),
+                Err(_) => {
+                    warn!("parse_free_text_address: could not get DB lock");
+                    HashSet::new()
+                }
+            },
+        };
+
+        // (city, city_score, street words)
+        let mut interpretations: Vec<(CityName, f64, Vec<&str>)> = Vec::new();
+        for start in 0..words.len() {
+            if let Some(end) = longest_known_city_span(&words, start, &known_cities) {
+                let Ok(city) = CityName::new(&words[start..=end].join(" ")) else {
+                    continue;
+                };
+                let street_words = if start > 0 {
+                    words[..start].to_vec()
+                } else {
+                    words[end + 1..].to_vec()
+                };
+                interpretations.push((city, 1.0, street_words));
+            }
+        }
+
+        // No city named in the text => every city of the postal code is possible
+        if interpretations.is_empty()
+            && let Some(pc) = &postal_code {
+            for city in self.cities_for_postal_code(region, pc).unwrap_or_default() {
+                interpretations.push((city, 0.3, words.clone()));
+            }
+        }
+
+        let mut out = Vec::new();
+        for (city, city_score, street_words) in interpretations {
+            if street_words.is_empty() {
+                continue;
+            }
+            let street_text = street_words.join(" ");
+
+            let postal_options: Vec<(PostalCode, f64)> = match &postal_code {
+                Some(pc) => vec![(pc.clone(), 1.0)],
+                None => self.postal_codes_for_city_in_region(region, &city)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|pc| (pc, 0.4))
+                    .collect(),
+            };
+
+            for (pc, postal_score) in postal_options {
+                let known_streets = self.street_names_for_postal_code_in_region(region, &pc)
+                    .unwrap_or_default();
+
+                for (street, street_score) in match_street_text(&street_text, region, &known_streets) {
+                    let house_score = self.house_number_score(region, &street, house_number.as_ref());
+
+                    let confidence = (0.25 * postal_score
+                        + 0.30 * city_score
+                        + 0.35 * street_score
+                        + house_score).min(1.0);
+
+                    let address = WorldAddressBuilder::default()
+                        .region(*region)
+                        .postal_code(pc.clone())
+                        .city(city.clone())
+                        .street(street)
+                        .build();
+
+                    if let Ok(address) = address {
+                        out.push(AddressCandidate { address, house_number, confidence });
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    /// 0.10 when the house number lies in a known range for the street,
+    /// 0.05 when it can't be checked (none typed, or no range data), and
+    /// nothing when the street's known ranges exclude it.
+    fn house_number_score(&self, region: &WorldRegion, street: &StreetName, house_number: Option<&HouseNumber>) -> f64 {
+        let Some(house_number) = house_number else {
+            return 0.05;
+        };
+        match self.load_house_number_ranges(region, street) {
+            Ok(Some(ranges)) if !ranges.is_empty() => {
+                if ranges.iter().any(|r| r.contains(house_number)) { 0.10 } else { 0.0 }
+            }
+            _ => 0.05,
+        }
+    }
+}
+
+/// Known streets matching `text`, with a score in `0.0..=1.0`: 1.0 for an
+/// exact (canonical) match, otherwise the fuzzy score relative to a perfect
+/// match. Only the top few fuzzy matches are kept.
+fn match_street_text(text: &str, region: &WorldRegion, known: &BTreeSet<StreetName>) -> Vec<(StreetName, f64)> {
+    let query = canonicalize_street_name(text, StreetNameConvention::for_region(region));
+    if let Some(exact) = known.iter().find(|s| *s.canonical() == query) {
+        return vec![(exact.clone(), 1.0)];
+    }
+
+    let matcher = SkimMatcherV2::default();
+    let Some(perfect) = matcher.fuzzy_match(&query, &query) else {
+        return vec![];
+    };
+
+    let mut scored: Vec<(StreetName, f64)> = known
+        .iter()
+        .filter_map(|s| {
+            let score = matcher.fuzzy_match(s.canonical(), &query)? as f64 / perfect as f64;
+            (score >= MIN_FUZZY_STREET_SCORE).then(|| (s.clone(), score.min(0.9)))
+        })
+        .collect();
+
+    scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+    scored.truncate(3);
+    scored
+}
+
+/// Sorts by confidence (best first), drops duplicate interpretations and
+/// keeps at most [`MAX_FREE_TEXT_CANDIDATES`].
+fn rank_address_candidates(mut candidates: Vec<AddressCandidate>) -> Vec<AddressCandidate> {
+    candidates.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+
+    let mut seen = BTreeSet::new();
+    candidates.retain(|c| seen.insert((c.address.clone(), c.house_number)));
+    candidates.truncate(MAX_FREE_TEXT_CANDIDATES);
+    candidates
+}
+
+#[cfg(test)]
+mod parse_free_text_address_tests {
+    use super::*;
+
+    fn region_md() -> WorldRegion {
+        USRegion::UnitedState(UnitedState::Maryland).into()
+    }
+
+    fn pc(code: &str) -> PostalCode {
+        PostalCode::new(Country::USA, code).unwrap()
+    }
+
+    /// Baltimore 21201 with North Charles Street (house numbers 1500..=1700)
+    /// and North Avenue; Potomac 20854 with River Road.
+    fn seeded_data_access() -> (DataAccess<Database>, TempDir) {
+        let (da, db, tmp) = create_data_access::<Database>();
+        {
+            let mut guard = db.lock().unwrap();
+            let region = region_md();
+
+            let baltimore = CityName::new("Baltimore").unwrap();
+            let potomac   = CityName::new("Potomac").unwrap();
+            let charles   = StreetName::new_for_region("North Charles Street", &region).unwrap();
+            let avenue    = StreetName::new_for_region("North Avenue", &region).unwrap();
+            let river     = StreetName::new_for_region("River Road", &region).unwrap();
+
+            let set = |items: &[CityName]| items.iter().cloned().collect::<BTreeSet<_>>();
+            guard.put(z2c_key(&region, &pc("21201")), compress_set_to_cbor(&set(std::slice::from_ref(&baltimore)))).unwrap();
+            guard.put(z2c_key(&region, &pc("20854")), compress_set_to_cbor(&set(std::slice::from_ref(&potomac)))).unwrap();
+
+            let streets = |items: &[StreetName]| items.iter().cloned().collect::<BTreeSet<_>>();
+            guard.put(s_key(&region, &pc("21201")), compress_set_to_cbor(&streets(&[charles.clone(), avenue]))).unwrap();
+            guard.put(s_key(&region, &pc("20854")), compress_set_to_cbor(&streets(&[river]))).unwrap();
+
+            let zips = [pc("21201")].into_iter().collect::<BTreeSet<_>>();
+            guard.put(c2z_key(&region, &baltimore), compress_set_to_cbor(&zips)).unwrap();
+            let zips = [pc("20854")].into_iter().collect::<BTreeSet<_>>();
+            guard.put(c2z_key(&region, &potomac), compress_set_to_cbor(&zips)).unwrap();
+
+            guard.store_house_number_ranges(&region, &charles, &[HouseNumberRange::new(1500, 1700)]).unwrap();
+        }
+        (da, tmp)
+    }
+
+    #[traced_test]
+    fn test_empty_input_is_an_error() {
+        let (da, _tmp) = seeded_data_access();
+        let res = da.parse_free_text_address(&region_md(), " , MD ");
+        assert_eq!(res.unwrap_err(), FreeTextAddressError::EmptyInput);
+    }
+
+    #[traced_test]
+    fn test_full_us_address_ranks_charles_street_first() {
+        let (da, _tmp) = seeded_data_access();
+        let candidates = da
+            .parse_free_text_address(&region_md(), "1600 N. Charles St, Baltimore MD 21201")
+            .unwrap();
+
+        assert!(!candidates.is_empty());
+        let best = &candidates[0];
+        assert_eq!(best.address().postal_code().code(), "21201");
+        assert_eq!(best.address().city().name(), "baltimore");
+        assert_eq!(best.address().street().name(), "north charles street");
+        assert_eq!(best.house_number(), &Some(HouseNumber::from(1600)));
+
+        for pair in candidates.windows(2) {
+            assert!(pair[0].confidence() >= pair[1].confidence());
+        }
+    }
+
+    #[traced_test]
+    fn test_exact_match_scores_higher_than_fuzzy() {
+        let (da, _tmp) = seeded_data_access();
+        let exact = da.parse_free_text_address(&region_md(), "1600 North Charles Street Baltimore 21201").unwrap();
+        let fuzzy = da.parse_free_text_address(&region_md(), "1600 N Charls St Baltimore 21201").unwrap();
+        assert!(exact[0].confidence() > fuzzy[0].confidence());
+        assert!(*exact[0].confidence() <= 1.0);
+    }
+
+    #[traced_test]
+    fn test_abbreviated_street_is_an_exact_match() {
+        let (da, _tmp) = seeded_data_access();
+        let long  = da.parse_free_text_address(&region_md(), "1600 North Charles Street Baltimore 21201").unwrap();
+        let short = da.parse_free_text_address(&region_md(), "1600 Charles St N Baltimore 21201").unwrap();
+        assert_eq!(short[0].address(), long[0].address());
+        assert_eq!(short[0].confidence(), long[0].confidence());
+    }
+
+    #[traced_test]
+    fn test_city_without_postal_code_is_inferred() {
+        let (da, _tmp) = seeded_data_access();
+        let candidates = da.parse_free_text_address(&region_md(), "Potomac River Road").unwrap();
+        let best = &candidates[0];
+        assert_eq!(best.address().city().name(), "potomac");
+        assert_eq!(best.address().street().name(), "river road");
+        assert_eq!(best.address().postal_code().code(), "20854");
+    }
+
+    #[traced_test]
+    fn test_unknown_address_yields_no_candidates() {
+        let (da, _tmp) = seeded_data_access();
+        let candidates = da.parse_free_text_address(&region_md(), "1 Nowhere Lane Springfield 99999").unwrap();
+        assert!(candidates.is_empty());
+    }
+}
@@ -2,7 +2,10 @@
 crate::ix!();
 
 /// Parses a non-empty housenumber string as either a single number or a range,
-/// allowing trailing non-digit text. For example, "2801 B" is parsed as 2801.
+/// allowing trailing non-digit text. A single-letter suffix is kept: "2801 B"
+/// is parsed as the lettered range `2801B..=2801B`, and "12A-12F" as
+/// `12A..=12F`. A range between different numbers ("100A-200B") keeps only
+/// the numbers.
 ///
 /// # Returns
 ///
@@ -33,9 +36,12 @@ pub fn parse_housenumber_value(
     let parts: Vec<&str> = s.split('-').map(str::trim).collect();
     match parts.len() {
         1 => {
-            // No dash => parse one integer prefix
+            // No dash => parse one integer prefix, plus any letter suffix
             let number = parse_integer_prefix(parts[0], element_id)?;
-            let range = HouseNumberRange::new(number, number);
+            let range = match HouseNumber::parse(parts[0]).and_then(|hn| *hn.suffix()) {
+                Some(letter) => HouseNumberRange::lettered(number, letter, letter),
+                None         => HouseNumberRange::new(number, number),
+            };
             debug!(
                 "parse_housenumber_value: parsed single '{}' => {:?} (element_id={})",
                 s, range, element_id
@@ -47,6 +53,25 @@ pub fn parse_housenumber_value(
             let start_num = parse_integer_prefix(parts[0], element_id)?;
             let end_num = parse_integer_prefix(parts[1], element_id)?;
 
+            let start_letter = HouseNumber::parse(parts[0]).and_then(|hn| *hn.suffix());
+            let end_letter   = HouseNumber::parse(parts[1]).and_then(|hn| *hn.suffix());
+
+            if let (true, Some(a), Some(b)) = (start_num == end_num, start_letter, end_letter) {
+                if a > b {
+                    debug!(
+                        "parse_housenumber_value: reversed letter range '{}' => ignoring (element_id={})",
+                        s, element_id
+                    );
+                    return Ok(None);
+                }
+                let range = HouseNumberRange::lettered(start_num, a, b);
+                debug!(
+                    "parse_housenumber_value: parsed lettered range '{}' => {:?} (element_id={})",
+                    s, range, element_id
+                );
+                return Ok(Some(range));
+            }
+
             if start_num > end_num {
                 debug!(
                     "parse_housenumber_value: reversed or invalid range '{}-{}' => ignoring (element_id={})",
@@ -269,4 +294,18 @@ mod test_parse_housenumber_value_with_prefix {
         let res = parse_housenumber_value("100A - 200B", 12);
         assert_ok_some(res, 100, 200);
     }
+
+    #[traced_test]
+    fn test_single_lettered_number() {
+        let range = parse_housenumber_value("221B", 13).unwrap().unwrap();
+        assert_eq!(range, HouseNumberRange::lettered(221, 'B', 'B'));
+    }
+
+    #[traced_test]
+    fn test_lettered_range_on_same_number() {
+        let range = parse_housenumber_value("12a-12f", 14).unwrap().unwrap();
+        assert_eq!(range, HouseNumberRange::lettered(12, 'A', 'F'));
+
+        assert_ok_none(parse_housenumber_value("12F-12A", 15));
+    }
 }
@@ -138,7 +138,7 @@ mod test_parse_integer {
         let result = parse_integer("4294967295", element_id);
         // 4294967295 = 2^32 - 1
         // This is exactly the maximum u32, so it should parse successfully.
-        assert_ok_eq(result, std::u32::MAX);
+        assert_ok_eq(result, u32::MAX);
     }
 
     #[traced_test]
@@ -1,8 +1,8 @@
 // ---------------- [ File: src/parse_osm_pbf_and_build_house_number_ranges.rs ]
 crate::ix!();
 
-/// Loads an OSM PBF file, extracting all [`AddressRecord`]s and accumulating
-/// [`HouseNumberRange`] objects in memory. This function is suitable for smaller
+/// Loads an OSM PBF file, extracting all [`AddressRecord`]s (with their
+/// coordinates) and accumulating [`HouseNumberRange`] objects in memory. This function is suitable for smaller
 /// to medium‐sized data sets that fit into RAM.
 ///
 /// **If the data is massive**, consider a streaming approach where intermediate
@@ -23,6 +23,33 @@ pub fn load_osm_data_with_housenumbers(
     path: impl AsRef<Path>,
     region: &WorldRegion,
 ) -> Result<(Vec<AddressRecord>, HouseNumberAggregator), OsmPbfParseError> {
+    load_osm_data_with_housenumbers_and_progress(path, region, &RegionBuildProgress::default())
+}
+
+/// [`load_osm_data_with_housenumbers`], reporting the bytes, elements and
+/// addresses of the main pass to `progress` as it goes.
+pub fn load_osm_data_with_housenumbers_and_progress(
+    path: impl AsRef<Path>,
+    region: &WorldRegion,
+    progress: &RegionBuildProgress,
+) -> Result<(Vec<AddressRecord>, HouseNumberAggregator), OsmPbfParseError> {
+    let (addresses, street_hnr_map, _boundaries) =
+        load_osm_data_with_housenumbers_and_boundaries(path, region, progress)?;
+    Ok((addresses, street_hnr_map))
+}
+
+/// [`load_osm_data_with_housenumbers_and_progress`], also returning the
+/// administrative and postal code boundaries of the file, each tagged with
+/// `region`.
+///
+/// Boundary relations are noted during the main pass. Relations come last
+/// in a PBF file, so their member ways take one extra pass; the ring node
+/// coordinates are picked up by the same pass that resolves way centroids.
+pub fn load_osm_data_with_housenumbers_and_boundaries(
+    path: impl AsRef<Path>,
+    region: &WorldRegion,
+    progress: &RegionBuildProgress,
+) -> Result<(Vec<AddressRecord>, HouseNumberAggregator, Vec<AdminBoundary>), OsmPbfParseError> {
 
     trace!(
         "load_osm_data_with_housenumbers: start path={:?}, region={:?}",
@@ -34,14 +61,86 @@ pub fn load_osm_data_with_housenumbers(
     let country = infer_country_from_region(region)?;
 
     // Step 2: Open the OSM PBF file for reading.
-    let reader = open_osm_pbf_reader(&path)?;
+    let reader = open_osm_pbf_reader_with_progress(&path, progress)?;
 
     // Step 3: We’ll accumulate addresses and house‐number ranges in memory.
     let mut street_hnr_map = HouseNumberAggregator::new(region);
     let mut addresses = Vec::new();
 
     // Step 4: Process the PBF file’s elements in a single pass.
-    collect_address_and_housenumber_data(reader, &country, &mut addresses, &mut street_hnr_map)?;
+    let mut pending_ways           = PendingWayCentroids::default();
+    let mut pending_interpolations = PendingInterpolations::default();
+    let mut pending_boundaries     = PendingBoundaries::default();
+    collect_address_and_housenumber_data(
+        reader,
+        &country,
+        &mut addresses,
+        &mut street_hnr_map,
+        &mut pending_ways,
+        &mut pending_interpolations,
+        &mut pending_boundaries,
+        progress,
+    )?;
+
+    // Step 5: Boundary relations only list their member ways, which came
+    // before them in the file; one targeted pass picks up their node lists.
+    let boundary_way_refs = if pending_boundaries.is_empty() {
+        HashMap::new()
+    } else {
+        collect_way_node_refs(open_osm_pbf_reader(&path)?, &pending_boundaries.needed_way_ids())?
+    };
+
+    // Step 6: Addresses tagged on ways, and the boundary rings, only know
+    // their node ids; one more targeted pass picks up those coordinates.
+    let mut boundaries = Vec::new();
+    if !pending_ways.is_empty() || !boundary_way_refs.is_empty() {
+        let mut wanted = pending_ways.needed_node_ids();
+        wanted.extend(boundary_way_refs.values().flatten().copied());
+        let coords = collect_node_coordinates(open_osm_pbf_reader(&path)?, &wanted)?;
+
+        let pending  = pending_ways.len();
+        let resolved = pending_ways.resolve(&coords, &mut addresses);
+        debug!(
+            "load_osm_data_with_housenumbers: resolved centroids for {} of {} way addresses",
+            resolved,
+            pending
+        );
+
+        let found  = pending_boundaries.len();
+        boundaries = pending_boundaries.resolve(region, &boundary_way_refs, &coords);
+        debug!(
+            "load_osm_data_with_housenumbers: built {} of {} boundaries",
+            boundaries.len(),
+            found
+        );
+    }
+
+    // Step 7: `addr:interpolation` ways likewise only know their node ids;
+    // the numbered end points give the ranges in between. Which side of the
+    // street each range is on comes from the lines of those streets, which
+    // take two more passes: one for their node ids, one for coordinates.
+    if !pending_interpolations.is_empty() {
+        let wanted  = pending_interpolations.needed_node_ids();
+        let nodes   = collect_interpolation_nodes(open_osm_pbf_reader(&path)?, &wanted, &country)?;
+
+        let streets     = pending_interpolations.streets(&nodes);
+        let street_ways = collect_street_way_refs(open_osm_pbf_reader(&path)?, &streets, &country)?;
+        let geometry    = if street_ways.is_empty() {
+            StreetGeometry::default()
+        } else {
+            let wanted = street_ways.iter().flat_map(|(_, refs)| refs.iter().copied()).collect();
+            let coords = collect_node_coordinates(open_osm_pbf_reader(&path)?, &wanted)?;
+            StreetGeometry::from_ways(street_ways, &coords)
+        };
+
+        let pending = pending_interpolations.len();
+        let added   = pending_interpolations.resolve(&nodes, &geometry, &mut street_hnr_map);
+        debug!(
+            "load_osm_data_with_housenumbers: {} interpolated ranges from {} interpolation ways",
+            added,
+            pending
+        );
+    }
 
     info!(
         "load_osm_data_with_housenumbers: completed. Found {} addresses; {} streets with house‐number data",
@@ -49,7 +148,7 @@ pub fn load_osm_data_with_housenumbers(
         street_hnr_map.len()
     );
 
-    Ok((addresses, street_hnr_map))
+    Ok((addresses, street_hnr_map, boundaries))
 }
 
 #[cfg(test)]
@@ -17,6 +17,7 @@ use crate::proto::osmformat;
 /// * `postal_code` - An optional `addr:postcode` like `"21201"`.
 /// * `lat`/`lon`   - Node coordinates in floating degrees.
 /// * `node_id`     - The Node's OSM ID.
+#[allow(clippy::too_many_arguments)]
 pub async fn create_small_osm_pbf_file_in_bbox(
     pbf_path: &std::path::Path,
     city: &str,
@@ -54,6 +55,7 @@ pub async fn create_small_osm_pbf_file_in_bbox(
 /// Creates a single-node `.osm.pbf` while optionally including `addr:housenumber`
 /// and `addr:postcode`. This is just a fork of your existing `create_small_osm_pbf_file`,
 /// augmented to handle `postal_code`.
+#[allow(clippy::too_many_arguments)]
 pub async fn create_small_osm_pbf_file_with_postcode(
     path: &std::path::Path,
     bbox: (i64, i64, i64, i64),
@@ -0,0 +1,194 @@
+// ---------------- [ File: src/pending_boundaries.rs ]
+crate::ix!();
+
+#[derive(Debug)]
+struct PendingBoundary {
+    relation_id: i64,
+    kind:        BoundaryKind,
+    name:        String,
+    outer_ways:  Vec<i64>,
+    inner_ways:  Vec<i64>,
+}
+
+/// Boundary relations only list their member ways, and those ways only
+/// list node ids. Relations come last in a PBF file, so we note them during
+/// the main pass, pick up the member ways with [`collect_way_node_refs`],
+/// and take their node coordinates from the pass that resolves way
+/// centroids.
+#[derive(Debug,Default)]
+pub struct PendingBoundaries {
+    pending: Vec<PendingBoundary>,
+}
+
+impl PendingBoundaries {
+
+    pub fn push(
+        &mut self,
+        relation_id: i64,
+        kind:        BoundaryKind,
+        name:        String,
+        outer_ways:  Vec<i64>,
+        inner_ways:  Vec<i64>,
+    ) {
+        self.pending.push(PendingBoundary { relation_id, kind, name, outer_ways, inner_ways });
+    }
+
+    /// Notes `relation` if it is a boundary we keep. Members without a
+    /// role count as outer, as many older boundaries are tagged that way.
+    pub fn push_relation(&mut self, relation: &osmpbf::Relation) -> bool {
+        let tags = collect_tags(relation.tags());
+        let Some(kind) = BoundaryKind::from_tags(&tags) else {
+            return false;
+        };
+        let name = match kind {
+            BoundaryKind::PostalCode => tags.get("postal_code").or_else(|| tags.get("name")),
+            _                        => tags.get("name"),
+        };
+        let Some(name) = name.map(|n| n.trim().to_string()).filter(|n| !n.is_empty()) else {
+            debug!("PendingBoundaries: relation {} has no name, skipping", relation.id());
+            return false;
+        };
+
+        let (mut outer_ways, mut inner_ways) = (vec![], vec![]);
+        for member in relation.members() {
+            if member.member_type != osmpbf::RelMemberType::Way {
+                continue;
+            }
+            match member.role() {
+                Ok("inner") => inner_ways.push(member.member_id),
+                Ok("outer") | Ok("") => outer_ways.push(member.member_id),
+                _ => {}
+            }
+        }
+        self.push(relation.id(), kind, name, outer_ways, inner_ways);
+        true
+    }
+
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Every member way id of a pending boundary.
+    pub fn needed_way_ids(&self) -> HashSet<i64> {
+        self.pending
+            .iter()
+            .flat_map(|b| b.outer_ways.iter().chain(&b.inner_ways).copied())
+            .collect()
+    }
+
+    /// Builds the polygon of each pending boundary from its member ways'
+    /// node lists and the node coordinates. Boundaries left without a
+    /// closed outer ring are dropped.
+    pub fn resolve(
+        self,
+        region:    &WorldRegion,
+        way_refs:  &HashMap<i64, Vec<i64>>,
+        coords:    &HashMap<i64, GeoPoint>,
+    ) -> Vec<AdminBoundary> {
+        let rings = |ways: &[i64]| -> Vec<BoundaryRing> {
+            let pieces = ways.iter().filter_map(|id| way_refs.get(id).cloned()).collect();
+            assemble_rings(pieces)
+                .into_iter()
+                .filter_map(|ring| {
+                    let mut points: BoundaryRing = ring.iter().filter_map(|id| coords.get(id).copied()).collect();
+                    if points.first() != points.last() {
+                        points.push(points[0]);
+                    }
+                    (points.len() >= 4).then_some(points)
+                })
+                .collect()
+        };
+
+        let mut boundaries = Vec::with_capacity(self.pending.len());
+        for pending in self.pending {
+            let outer = rings(&pending.outer_ways[..]);
+            if outer.is_empty() {
+                debug!(
+                    "PendingBoundaries::resolve: no closed outer ring for relation {} ({})",
+                    pending.relation_id,
+                    pending.name
+                );
+                continue;
+            }
+            let boundary = AdminBoundaryBuilder::default()
+                .region(*region)
+                .relation_id(pending.relation_id)
+                .kind(pending.kind)
+                .name(pending.name)
+                .outer(outer)
+                .inner(rings(&pending.inner_ways[..]))
+                .build()
+                .unwrap();
+            boundaries.push(boundary);
+        }
+        boundaries
+    }
+}
+
+/// One pass over the file, collecting the node lists of exactly the way
+/// ids in `wanted`.
+pub fn collect_way_node_refs<R: Read + Send + Sync>(
+    reader: ElementReader<R>,
+    wanted: &HashSet<i64>,
+) -> Result<HashMap<i64, Vec<i64>>, OsmPbfParseError> {
+    trace!("collect_way_node_refs: looking for {} ways", wanted.len());
+
+    let mut refs = HashMap::new();
+    reader.for_each(|element| {
+        if let Element::Way(way) = &element
+            && wanted.contains(&way.id()) {
+            refs.insert(way.id(), way.refs().collect());
+        }
+    })?;
+
+    debug!("collect_way_node_refs: found {} of {} ways", refs.len(), wanted.len());
+    Ok(refs)
+}
+
+#[cfg(test)]
+mod pending_boundaries_tests {
+    use super::*;
+
+    fn coords() -> HashMap<i64, GeoPoint> {
+        [
+            (1, (39.0, -77.0)),
+            (2, (39.0, -76.0)),
+            (3, (40.0, -76.0)),
+            (4, (40.0, -77.0)),
+        ]
+        .into_iter()
+        .map(|(id, (lat, lon))| (id, mock_point(lat, lon)))
+        .collect()
+    }
+
+    #[traced_test]
+    fn test_resolve_builds_polygon_from_split_ways() {
+        let mut pending = PendingBoundaries::default();
+        pending.push(900, BoundaryKind::Administrative { level: 8 }, "Baltimore".to_string(), vec![10, 11], vec![]);
+        assert_eq!(pending.needed_way_ids(), [10, 11].into_iter().collect());
+
+        let way_refs: HashMap<i64, Vec<i64>> = [
+            (10, vec![1, 2, 3]),
+            (11, vec![1, 4, 3]),
+        ].into_iter().collect();
+
+        let boundaries = pending.resolve(&example_region(), &way_refs, &coords());
+        assert_eq!(boundaries.len(), 1);
+        assert_eq!(boundaries[0].name(), "Baltimore");
+        assert!(boundaries[0].contains(&mock_point(39.5, -76.5)));
+        assert!(!boundaries[0].contains(&mock_point(38.5, -76.5)));
+    }
+
+    #[traced_test]
+    fn test_resolve_drops_boundary_without_closed_ring() {
+        let mut pending = PendingBoundaries::default();
+        pending.push(901, BoundaryKind::PostalCode, "21201".to_string(), vec![10], vec![]);
+
+        let way_refs: HashMap<i64, Vec<i64>> = [(10, vec![1, 2, 3])].into_iter().collect();
+        assert!(pending.resolve(&example_region(), &way_refs, &coords()).is_empty());
+    }
+}
@@ -10,16 +10,12 @@ pub trait PostalCodesForCityInRegion {
     ) -> Option<BTreeSet<PostalCode>>;
 }
 
-impl<I:StorageInterface> PostalCodesForCityInRegion for DataAccess<I> {
+impl<D:GetPostalCodeSetForKey> PostalCodesForCityInRegion for D {
 
     // Example query: given city name, get associated PostalCode codes
     fn postal_codes_for_city_in_region(&self, region: &WorldRegion, city: &CityName) -> Option<BTreeSet<PostalCode>> {
         let key = c2z_key(region,city);
-        if let Some(postal_codes) = self.get_postal_code_set(&key) {
-            Some(postal_codes)
-        } else {
-            None
-        }
+        self.get_postal_code_set(&key)
     }
 }
 
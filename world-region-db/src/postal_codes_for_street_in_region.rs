@@ -10,7 +10,7 @@ pub trait PostalCodesForStreetInRegion {
     ) -> Option<BTreeSet<PostalCode>>;
 }
 
-impl<I: StorageInterface> PostalCodesForStreetInRegion for DataAccess<I> {
+impl<D: GetPostalCodeSetForKey> PostalCodesForStreetInRegion for D {
     fn postal_codes_for_street_in_region(
         &self,
         region: &WorldRegion,
@@ -11,6 +11,7 @@ use tracing::{trace, debug, warn};
 ///   - street (required)
 ///   - optional housenumber
 ///   - postal code (always)
+///
 /// Also sets lat/lon, granularity=100, etc.
 pub fn prepare_single_node_primitive_block(
     city:        &str,
@@ -224,7 +225,7 @@ mod test_prepare_single_node_primitive_block {
         // 8 => postcode
         assert_eq!(str_table.s.len(), 9);
         assert_eq!(&str_table.s[5], b"addr:housenumber");
-        assert_eq!(&str_table.s[6], housenumber.unwrap().as_bytes());
+        assert_eq!(&str_table.s[6], b"100-110");
 
         let groups = &block.primitivegroup;
         assert_eq!(groups.len(), 1);
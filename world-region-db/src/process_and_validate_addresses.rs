@@ -21,7 +21,7 @@ where
                 if let Err(e) = addr.validate_with(data_access) {
                     warn!("process_and_validate_addresses: Address invalid => {:#?}\nerr={:#?}", addr, e);
                     all_valid = false;
-                } else if count % 100 == 0 {
+                } else if count.is_multiple_of(100) {
                     info!("process_and_validate_addresses: {}th address validated => {:#?} is valid", count, addr);
                 }
             }
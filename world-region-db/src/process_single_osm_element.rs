@@ -4,7 +4,8 @@ crate::ix!();
 /// For one OSM element, we:
 ///   1. Attempt to parse an [`AddressRecord`] via `AddressRecord::try_from(...)`.
 ///   2. Extract a [`HouseNumberRange`] if present.
-///   3. If both a street name and house‐number range exist, store them in `street_hnr_map`.
+///   3. Keep the element's id, coordinate (nodes only) and house number on the record.
+///   4. If both a street name and house‐number range exist, store them in `street_hnr_map`.
 pub fn process_single_osm_element(
     element:        &osmpbf::Element,
     country:        &Country,
@@ -17,14 +18,21 @@ pub fn process_single_osm_element(
 
     // Attempt an AddressRecord
     let record_result = AddressRecord::try_from((element, country));
-    if let Ok(addr) = &record_result {
-        debug!("process_single_osm_element: got AddressRecord => pushing to addresses");
-        addresses.push(addr.clone());
-    }
 
     // Attempt a HouseNumberRange
     let hnr_result = extract_house_number_range_from_element(element);
 
+    if let Ok(addr) = &record_result {
+        debug!("process_single_osm_element: got AddressRecord => pushing to addresses");
+        let mut located = addr.clone();
+        located.set_location(element_location(element));
+        located.set_osm_element(Some(OsmElementRef::of(element)));
+        if let Ok(Some(hnr)) = &hnr_result {
+            located.set_housenumber(Some(hnr.clone()));
+        }
+        addresses.push(located);
+    }
+
     // If we found a HNR and we have a valid street, record it
     if let Ok(Some(hnr)) = hnr_result {
         let maybe_street = match &record_result {
@@ -96,6 +104,9 @@ mod test_process_single_osm_element {
         assert_eq!(record.city().as_ref().unwrap().name(), "baltimore");
         assert_eq!(record.street().as_ref().unwrap().name(), "north avenue");
         assert_eq!(record.postcode().as_ref().unwrap().code(), "21201");
+        assert_eq!(record.housenumber(), &Some(HouseNumberRange::new(10, 20)));
+        assert!(record.location().is_some(), "Nodes carry their own coordinate");
+        assert_eq!(record.osm_element(), &Some(OsmElementRef::Node(111)));
 
         // 2) Check that the HNR was extracted and assigned under street "north avenue"
         assert_eq!(street_hnr_map.len(), 1, "Should have exactly one street in the map");
@@ -27,6 +27,20 @@ impl DatabaseGet for Database {
     }
 }
 
+//--------------------------------
+pub trait DatabaseDelete {
+    fn delete(&mut self, key: impl AsRef<[u8]>) 
+        -> Result<(),DatabaseConstructionError>;
+}
+
+impl DatabaseDelete for Database {
+
+    fn delete(&mut self, key: impl AsRef<[u8]>) -> Result<(),DatabaseConstructionError> {
+        self.db().delete(key)?;
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test_database_put_get {
     use super::*;
@@ -85,6 +99,19 @@ mod test_database_put_get {
         assert_eq!(val2, updated_val, "Value should be overwritten");
     }
 
+    #[traced_test]
+    fn test_delete_removes_key() {
+        let (db_arc, _tmp_dir) = create_temp_db::<Database>();
+        let mut db_guard = db_arc.lock().unwrap();
+
+        db_guard.put(b"doomed", b"value").expect("Should succeed");
+        db_guard.delete(b"doomed").expect("Delete should succeed");
+        assert!(db_guard.get(b"doomed").unwrap().is_none(), "Deleted key should be gone");
+
+        // Deleting a missing key is not an error
+        db_guard.delete(b"never_there").expect("Delete of missing key should succeed");
+    }
+
     #[traced_test]
     fn test_empty_value() {
         // Confirm that storing an empty value is valid.
@@ -0,0 +1,149 @@
+// ---------------- [ File: src/region_build_marker.rs ]
+crate::ix!();
+
+/// Tracks a region build between its first write and its done marker, so
+/// that a build cut short can be cleaned up and redone from scratch.
+pub trait RegionBuildMarker {
+
+    fn region_build_interrupted(&self, region: &WorldRegion)
+        -> Result<bool,DataAccessError>;
+
+    fn mark_region_build_started(&mut self, region: &WorldRegion)
+        -> Result<(),DatabaseConstructionError>;
+
+    fn finish_region_build(&mut self, region: &WorldRegion)
+        -> Result<(),DatabaseConstructionError>;
+
+    fn discard_partial_region(&mut self, region: &WorldRegion)
+        -> Result<usize,DatabaseConstructionError>;
+}
+
+impl RegionBuildMarker for Database {
+
+    /// Whether a build of `region` started writing and never finished.
+    /// [`finish_region_build`](RegionBuildMarker::finish_region_build)
+    /// drops the marker in the same write that marks the region done, so the
+    /// marker alone answers this.
+    fn region_build_interrupted(&self, region: &WorldRegion) -> Result<bool,DataAccessError> {
+        Ok(self.get(region_building_key(region))?.is_some())
+    }
+
+    fn mark_region_build_started(&mut self, region: &WorldRegion)
+        -> Result<(),DatabaseConstructionError>
+    {
+        self.put(region_building_key(region), b"building")?;
+        Ok(())
+    }
+
+    /// Marks `region` done and drops its in-progress marker atomically.
+    fn finish_region_build(&mut self, region: &WorldRegion)
+        -> Result<(),DatabaseConstructionError>
+    {
+        let mut batch = rocksdb::WriteBatch::default();
+        batch.put(MetaKeyForRegion::from(*region), b"done");
+        batch.delete(region_building_key(region));
+        self.db().write(batch)?;
+        Ok(())
+    }
+
+    /// Deletes every key a partial build of `region` may have written,
+    /// then the in-progress marker; returns how many keys went. Other
+    /// regions and the schema version are untouched.
+    fn discard_partial_region(&mut self, region: &WorldRegion)
+        -> Result<usize,DatabaseConstructionError>
+    {
+        let mut removed = 0;
+        for family in DbKeyFamily::ALL {
+            match family {
+                DbKeyFamily::SchemaVersion
+                    | DbKeyFamily::RegionDone
+                    | DbKeyFamily::RegionBuilding => continue,
+                // One key per region: delete it exactly rather than by a
+                // prefix that another abbreviation could extend.
+                DbKeyFamily::ReplicationSequence => {
+                    self.delete(replication_sequence_key(region))?;
+                    continue;
+                }
+                _ => {}
+            }
+
+            let prefix = family.region_prefix(region);
+            for item in self.db().prefix_iterator(prefix.as_bytes()) {
+                let (key, _) = item?;
+                if !key.starts_with(prefix.as_bytes()) {
+                    break;
+                }
+                self.db().delete(&key)?;
+                removed += 1;
+            }
+        }
+
+        self.delete(region_building_key(region))?;
+        info!("discard_partial_region: removed {} keys of region {:?}", removed, region);
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod region_build_marker_tests {
+    use super::*;
+
+    fn md() -> WorldRegion {
+        USRegion::UnitedState(UnitedState::Maryland).into()
+    }
+
+    fn va() -> WorldRegion {
+        USRegion::UnitedState(UnitedState::Virginia).into()
+    }
+
+    fn records_for(region: WorldRegion, city: &str, street: &str, postal_code: &str) -> RegionalRecords {
+        RegionalRecordsBuilder::default()
+            .region(region)
+            .records(vec![AddressRecord::new(
+                CityName::new(city).unwrap(),
+                StreetName::new(street).unwrap(),
+                PostalCode::new(Country::USA, postal_code).unwrap(),
+            )])
+            .build()
+            .unwrap()
+    }
+
+    #[traced_test]
+    fn test_finish_clears_marker_and_marks_done() {
+        let (db_arc, _tmp) = create_temp_db::<Database>();
+        let mut db = db_arc.lock().unwrap();
+
+        assert!(!db.region_build_interrupted(&md()).unwrap());
+        db.mark_region_build_started(&md()).unwrap();
+        assert!(db.region_build_interrupted(&md()).unwrap());
+        assert!(!db.region_done(&md()).unwrap());
+
+        db.finish_region_build(&md()).unwrap();
+        assert!(!db.region_build_interrupted(&md()).unwrap());
+        assert!(db.region_done(&md()).unwrap());
+    }
+
+    #[traced_test]
+    fn test_interrupted_build_is_discarded_and_rebuilt() {
+        let (db_arc, _tmp) = create_temp_db::<Database>();
+        let mut db = db_arc.lock().unwrap();
+
+        records_for(va(), "Arlington", "Wilson Blvd", "22201").write_to_storage(&mut *db).unwrap();
+
+        // Simulate a Maryland build that died after writing its indices.
+        let stale_city = CityName::new("Stale Town").unwrap();
+        db.mark_region_build_started(&md()).unwrap();
+        db.put(c2s_key(&md(), &stale_city), b"partial").unwrap();
+
+        records_for(md(), "Baltimore", "North Avenue", "21201").write_to_storage(&mut *db).unwrap();
+
+        assert!(db.region_done(&md()).unwrap());
+        assert!(!db.region_build_interrupted(&md()).unwrap());
+        assert!(db.get(c2s_key(&md(), &stale_city)).unwrap().is_none());
+        assert!(db.get(c2s_key(&md(), &CityName::new("Baltimore").unwrap())).unwrap().is_some());
+
+        // The other region is untouched.
+        assert!(db.region_done(&va()).unwrap());
+        assert!(db.get(c2s_key(&va(), &CityName::new("Arlington").unwrap())).unwrap().is_some());
+    }
+}
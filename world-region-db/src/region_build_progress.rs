@@ -0,0 +1,146 @@
+// ---------------- [ File: src/region_build_progress.rs ]
+crate::ix!();
+
+use std::sync::atomic::AtomicU64;
+
+/// Where a region is in a multi-region build.
+#[derive(Copy,Clone,Debug,Default,PartialEq,Eq)]
+pub enum RegionBuildStage {
+    #[default]
+    Queued,
+    /// Fetching the PBF file, unless it is already on disk.
+    Downloading,
+    Parsing,
+    /// Parsed and held in memory until the writer takes it.
+    WaitingForWriter,
+    Writing,
+    Done,
+    Failed,
+}
+
+/// Live counters of one region's build, shared between the thread parsing
+/// it and whoever reports progress. All counts only ever grow.
+#[derive(Debug,Default)]
+pub struct RegionBuildProgress {
+    stage:       Mutex<RegionBuildStage>,
+    elements:    AtomicU64,
+    addresses:   AtomicU64,
+    bytes_read:  AtomicU64,
+    total_bytes: AtomicU64,
+}
+
+impl RegionBuildProgress {
+
+    pub fn set_stage(&self, stage: RegionBuildStage) {
+        if let Ok(mut guard) = self.stage.lock() {
+            *guard = stage;
+        }
+    }
+
+    pub fn stage(&self) -> RegionBuildStage {
+        self.stage.lock().map(|guard| *guard).unwrap_or(RegionBuildStage::Failed)
+    }
+
+    /// Size of the file being parsed, for "x of y bytes" reporting.
+    pub fn set_total_bytes(&self, total: u64) {
+        self.total_bytes.store(total, Ordering::Relaxed);
+    }
+
+    pub fn add_elements(&self, n: u64) {
+        self.elements.fetch_add(n, Ordering::Relaxed);
+    }
+
+    pub fn add_addresses(&self, n: u64) {
+        self.addresses.fetch_add(n, Ordering::Relaxed);
+    }
+
+    pub fn add_bytes_read(&self, n: u64) {
+        self.bytes_read.fetch_add(n, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> RegionBuildProgressSnapshot {
+        RegionBuildProgressSnapshot {
+            stage:       self.stage(),
+            elements:    self.elements.load(Ordering::Relaxed),
+            addresses:   self.addresses.load(Ordering::Relaxed),
+            bytes_read:  self.bytes_read.load(Ordering::Relaxed),
+            total_bytes: self.total_bytes.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// A point-in-time copy of a [`RegionBuildProgress`].
+#[derive(Getters,Copy,Clone,Debug,Default,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct RegionBuildProgressSnapshot {
+    stage:       RegionBuildStage,
+    /// OSM elements seen in the main pass over the file.
+    elements:    u64,
+    addresses:   u64,
+    /// Bytes of the main pass; the later targeted passes are not counted.
+    bytes_read:  u64,
+    total_bytes: u64,
+}
+
+impl fmt::Display for RegionBuildProgressSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const MIB: f64 = 1024.0 * 1024.0;
+        write!(
+            f,
+            "{:?}: {} elements, {} addresses, {:.1} of {:.1} MiB read in the main pass",
+            self.stage,
+            self.elements,
+            self.addresses,
+            self.bytes_read as f64 / MIB,
+            self.total_bytes as f64 / MIB
+        )
+    }
+}
+
+/// Counts every byte read through it into a [`RegionBuildProgress`].
+pub struct ProgressReader<'a, R> {
+    inner:    R,
+    progress: &'a RegionBuildProgress,
+}
+
+impl<'a, R> ProgressReader<'a, R> {
+    pub fn new(inner: R, progress: &'a RegionBuildProgress) -> Self {
+        Self { inner, progress }
+    }
+}
+
+impl<R: Read> Read for ProgressReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.progress.add_bytes_read(n as u64);
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod region_build_progress_tests {
+    use super::*;
+
+    #[traced_test]
+    fn test_progress_reader_counts_bytes() {
+        let progress = RegionBuildProgress::default();
+        progress.set_total_bytes(11);
+
+        let mut reader = ProgressReader::new(&b"hello world"[..], &progress);
+        let mut out    = Vec::new();
+        reader.read_to_end(&mut out).unwrap();
+
+        progress.add_elements(3);
+        progress.add_addresses(1);
+        progress.set_stage(RegionBuildStage::Parsing);
+
+        let snapshot = progress.snapshot();
+        assert_eq!(*snapshot.bytes_read(), 11);
+        assert_eq!(*snapshot.total_bytes(), 11);
+        assert_eq!(*snapshot.elements(), 3);
+        assert_eq!(*snapshot.addresses(), 1);
+        assert_eq!(*snapshot.stage(), RegionBuildStage::Parsing);
+        assert!(snapshot.to_string().starts_with("Parsing: 3 elements, 1 addresses"), "{}", snapshot);
+        assert!(snapshot.to_string().ends_with("MiB read in the main pass"), "{}", snapshot);
+    }
+}
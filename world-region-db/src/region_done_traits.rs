@@ -26,7 +26,7 @@ impl MarkRegionAsDone for Database {
     fn mark_region_done(&mut self, region: &WorldRegion) 
         -> Result<(),DatabaseConstructionError> 
     {
-        self.db().put(&MetaKeyForRegion::from(*region), b"done")?;
+        self.db().put(MetaKeyForRegion::from(*region), b"done")?;
         Ok(())
     }
 }
@@ -9,6 +9,9 @@ pub struct RegionalRecords {
     records: Vec<AddressRecord>,
     #[builder(default)]
     house_number_ranges: HouseNumberAggregator,
+    /// Administrative and postal code boundaries read from the same file.
+    #[builder(default)]
+    boundaries: Vec<AdminBoundary>,
 }
 
 impl RegionalRecords {
@@ -21,19 +24,44 @@ impl RegionalRecords {
         self.records.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
     pub fn from_osm_pbf_file(region: WorldRegion, pbf_file: impl AsRef<Path>) 
         -> Result<Self,OsmPbfParseError> 
+    {
+        Self::from_osm_pbf_file_with_progress(region, pbf_file, &RegionBuildProgress::default())
+    }
+
+    pub fn from_osm_pbf_file_with_progress(
+        region:   WorldRegion,
+        pbf_file: impl AsRef<Path>,
+        progress: &RegionBuildProgress,
+    ) -> Result<Self,OsmPbfParseError> 
     {
         let pbf_path = pbf_file.as_ref();
 
         validate_pbf_filename(&region, pbf_path)?;
-        let (records, house_number_ranges) 
-            = load_osm_data_with_housenumbers(pbf_path,&region)?;
+        let (mut records, house_number_ranges, boundaries) 
+            = load_osm_data_with_housenumbers_and_boundaries(pbf_path,&region,progress)?;
+
+        // Many addresses carry no `addr:city` (or `addr:postcode`); the
+        // boundaries they fall inside fill those in.
+        let country    = infer_country_from_region(&region)?;
+        let assigned   = assign_fields_from_boundaries(&mut records, &boundaries, country);
+        info!(
+            "assigned {} cities and {} postal codes from boundaries for region {:?}",
+            assigned.cities(),
+            assigned.postal_codes(),
+            region
+        );
 
         Ok(Self {
             region,
             records,
             house_number_ranges,
+            boundaries,
         })
     }
 
@@ -48,11 +76,26 @@ impl RegionalRecords {
             return Ok(());
         }
 
+        // A build that died part way through leaves keys behind without the
+        // done marker. Some writers merge with what is stored (house number
+        // ranges, address link counts), so those keys must go first.
+        if db.region_build_interrupted(&self.region)? {
+            let removed = db.discard_partial_region(&self.region)?;
+            warn!("discarded {} keys of an interrupted build of region {:?}", removed, self.region);
+        }
+        db.mark_region_build_started(&self.region)?;
+
         db.write_indices_for_region(&self.region, &InMemoryIndexes::from(self))?;
 
+        db.write_address_locations_for_region(&self.region, &self.records)?;
+
+        db.write_osm_change_baseline_for_region(&self.region, &self.records)?;
+
+        db.write_boundaries_for_region(&self.region, &self.boundaries)?;
+
         write_house_number_ranges_into_storage(&self.house_number_ranges,&self.region,db)?;
 
-        db.mark_region_done(&self.region)?;
+        db.finish_region_build(&self.region)?;
 
         Ok(())
     }
@@ -114,7 +157,8 @@ mod test_regional_records {
         let rr = RegionalRecords {
             region,
             records: recs,
-            house_number_ranges: HouseNumberAggregator::new(&region)
+            house_number_ranges: HouseNumberAggregator::new(&region),
+            boundaries: vec![],
         };
         let c = rr.country();
         assert_eq!(c, Country::USA, "Expected region=MD => country=USA");
@@ -141,7 +185,8 @@ mod test_regional_records {
         let rr = RegionalRecords {
             region,
             records,
-            house_number_ranges: HouseNumberAggregator::new(&region)
+            house_number_ranges: HouseNumberAggregator::new(&region),
+            boundaries: vec![],
         };
 
         assert_eq!(rr.len(), 2, "Expected exactly 2 records.");
@@ -288,7 +333,8 @@ mod test_regional_records {
         let rr = RegionalRecords {
             region,
             records: recs,
-            house_number_ranges: HouseNumberAggregator::new(&region)
+            house_number_ranges: HouseNumberAggregator::new(&region),
+            boundaries: vec![],
         };
 
         // Now attempt to store
@@ -322,7 +368,8 @@ mod test_regional_records {
         let rr = RegionalRecords {
             region,
             records: recs,
-            house_number_ranges: HouseNumberAggregator::new(&region)
+            house_number_ranges: HouseNumberAggregator::new(&region),
+            boundaries: vec![],
         };
 
         let result = rr.write_to_storage(&mut *db_guard);
@@ -8,6 +8,7 @@ mod download_tests {
     use tokio::runtime::Runtime;
     use std::io::Write;
 
+    #[traced_test]
     fn verify_md5_checksum_mismatch() {
         // Create a tokio runtime without using `unwrap()`.
         let rt = match Runtime::new() {
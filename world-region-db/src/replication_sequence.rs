@@ -0,0 +1,54 @@
+// ---------------- [ File: src/replication_sequence.rs ]
+crate::ix!();
+
+pub trait ReplicationSequenceForRegion {
+
+    fn last_applied_sequence(&self, region: &WorldRegion)
+        -> Result<Option<u64>,DataAccessError>;
+
+    fn set_last_applied_sequence(&mut self, region: &WorldRegion, sequence: u64)
+        -> Result<(),DatabaseConstructionError>;
+}
+
+impl ReplicationSequenceForRegion for Database {
+
+    /// The last OSM change file applied to `region`, or `None` if the region
+    /// is still exactly as it was built from its `.osm.pbf`.
+    fn last_applied_sequence(&self, region: &WorldRegion) -> Result<Option<u64>,DataAccessError> {
+        let raw = self.get(replication_sequence_key(region))?;
+        Ok(raw.filter(|bytes| bytes.len() == 8).map(|bytes| BigEndian::read_u64(&bytes)))
+    }
+
+    /// Stored as 8 big-endian bytes under `META:REGION_SEQ:{abbr}`.
+    fn set_last_applied_sequence(&mut self, region: &WorldRegion, sequence: u64)
+        -> Result<(),DatabaseConstructionError>
+    {
+        let mut buf = [0u8; 8];
+        BigEndian::write_u64(&mut buf, sequence);
+        self.put(replication_sequence_key(region), buf)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_replication_sequence {
+    use super::*;
+
+    #[traced_test]
+    fn test_sequence_round_trip_per_region() {
+        let (db_arc, _tmp) = create_temp_db::<Database>();
+        let mut db = db_arc.lock().unwrap();
+
+        let md = WorldRegion::try_from_abbreviation("MD").unwrap();
+        let va = WorldRegion::try_from_abbreviation("VA").unwrap();
+
+        assert_eq!(db.last_applied_sequence(&md).unwrap(), None);
+
+        db.set_last_applied_sequence(&md, 4_123_456).unwrap();
+        assert_eq!(db.last_applied_sequence(&md).unwrap(), Some(4_123_456));
+        assert_eq!(db.last_applied_sequence(&va).unwrap(), None);
+
+        db.set_last_applied_sequence(&md, 4_123_457).unwrap();
+        assert_eq!(db.last_applied_sequence(&md).unwrap(), Some(4_123_457));
+    }
+}
@@ -31,6 +31,7 @@ pub fn retrieve_housenumber_value(
 }
 
 #[cfg(test)]
+#[allow(unused_attributes, clippy::duplicated_attributes)] // traced_test repeats `#[ignore]`
 mod test_retrieve_housenumber_value {
     use super::*;
     use std::collections::HashMap;
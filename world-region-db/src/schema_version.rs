@@ -0,0 +1,128 @@
+// ---------------- [ File: src/schema_version.rs ]
+crate::ix!();
+
+/// Key layout written by this build. Version 2 keys every street family by
+/// [`StreetName::canonical`] and adds the `OSM:`/`REF:` change baseline.
+pub const DB_SCHEMA_VERSION: u32 = 2;
+
+/// What a database without a `META:SCHEMA_VERSION` key, but with data, was
+/// written as.
+pub const LEGACY_SCHEMA_VERSION: u32 = 1;
+
+pub trait DatabaseSchemaVersion {
+
+    fn schema_version(&self) -> Result<Option<u32>,DataAccessError>;
+
+    fn set_schema_version(&mut self, version: u32) -> Result<(),DatabaseConstructionError>;
+}
+
+impl<I: DatabaseGet + DatabasePut> DatabaseSchemaVersion for I {
+
+    /// The stored layout version, or `None` if it was never written.
+    fn schema_version(&self) -> Result<Option<u32>,DataAccessError> {
+        let raw = self.get(DbKey::SchemaVersion.encode())?;
+        Ok(raw.filter(|bytes| bytes.len() == 4).map(|bytes| BigEndian::read_u32(&bytes)))
+    }
+
+    /// Stored as 4 big-endian bytes under `META:SCHEMA_VERSION`.
+    fn set_schema_version(&mut self, version: u32) -> Result<(),DatabaseConstructionError> {
+        let mut buf = [0u8; 4];
+        BigEndian::write_u32(&mut buf, version);
+        self.put(DbKey::SchemaVersion.encode(), buf)
+    }
+}
+
+/// The layout `db` is in: the stored version, [`LEGACY_SCHEMA_VERSION`] for
+/// unversioned data, or `None` for an empty database.
+pub fn detect_schema_version<I>(db: &I) -> Result<Option<u32>,DataAccessError>
+where
+    I: DatabaseSchemaVersion + GetIterator,
+{
+    if let Some(version) = db.schema_version()? {
+        return Ok(Some(version));
+    }
+    let has_data = db.iterator(rocksdb::IteratorMode::Start).next().is_some();
+    Ok(has_data.then_some(LEGACY_SCHEMA_VERSION))
+}
+
+/// Stamps an empty database with [`DB_SCHEMA_VERSION`] and refuses one
+/// written in any other layout.
+pub fn ensure_current_schema<I>(db: &mut I) -> Result<(),WorldCityAndStreetDbBuilderError>
+where
+    I: DatabaseSchemaVersion + GetIterator,
+{
+    match detect_schema_version(db)? {
+        None => {
+            debug!("ensure_current_schema: new database, writing schema v{}", DB_SCHEMA_VERSION);
+            db.set_schema_version(DB_SCHEMA_VERSION)?;
+            Ok(())
+        }
+        Some(found) => Ok(check_schema_version(found)?),
+    }
+}
+
+/// Like [`ensure_current_schema`] for a database we may not write to: an
+/// empty database is accepted as is.
+pub fn ensure_readable_schema<I>(db: &I) -> Result<(),WorldCityAndStreetDbBuilderError>
+where
+    I: DatabaseSchemaVersion + GetIterator,
+{
+    match detect_schema_version(db)? {
+        None        => Ok(()),
+        Some(found) => Ok(check_schema_version(found)?),
+    }
+}
+
+fn check_schema_version(found: u32) -> Result<(),DatabaseSchemaError> {
+    if found < DB_SCHEMA_VERSION {
+        return Err(DatabaseSchemaError::Outdated { found, expected: DB_SCHEMA_VERSION });
+    }
+    if found > DB_SCHEMA_VERSION {
+        return Err(DatabaseSchemaError::NewerThanSupported { found, supported: DB_SCHEMA_VERSION });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test_schema_version {
+    use super::*;
+
+    #[traced_test]
+    fn test_new_database_is_stamped_current() {
+        let (db_arc, _tmp) = create_temp_db::<Database>();
+        let db = db_arc.lock().unwrap();
+        assert_eq!(db.schema_version().unwrap(), Some(DB_SCHEMA_VERSION));
+    }
+
+    #[traced_test]
+    fn test_unversioned_data_is_outdated() {
+        let tmp = TempDir::new().unwrap();
+        {
+            let mut db = open_database_without_schema_check(tmp.path()).unwrap();
+            db.put("Z2C:MD:21201", b"x").unwrap();
+        }
+
+        match Database::open(tmp.path()) {
+            Err(WorldCityAndStreetDbBuilderError::DatabaseSchemaError(DatabaseSchemaError::Outdated { found, expected })) => {
+                assert_eq!((found, expected), (LEGACY_SCHEMA_VERSION, DB_SCHEMA_VERSION));
+            }
+            other => panic!("expected Outdated, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[traced_test]
+    fn test_newer_version_is_refused() {
+        let tmp = TempDir::new().unwrap();
+        {
+            let mut db = open_database_without_schema_check(tmp.path()).unwrap();
+            db.set_schema_version(DB_SCHEMA_VERSION + 1).unwrap();
+        }
+
+        match Database::open(tmp.path()) {
+            Err(WorldCityAndStreetDbBuilderError::DatabaseSchemaError(DatabaseSchemaError::NewerThanSupported { found, .. })) => {
+                assert_eq!(found, DB_SCHEMA_VERSION + 1);
+            }
+            other => panic!("expected NewerThanSupported, got {:?}", other.map(|_| ())),
+        }
+    }
+}
@@ -11,7 +11,7 @@ pub fn serialize_primitive_block(
 
     let block_bytes = primitive_block.write_to_bytes().map_err(|e| {
         error!("serialize_primitive_block: protobuf error: {:?}", e);
-        std::io::Error::new(std::io::ErrorKind::Other, "PrimitiveBlock serialization failed")
+        std::io::Error::other("PrimitiveBlock serialization failed")
     })?;
 
     let mut blob = fileformat::Blob::new();
@@ -20,7 +20,7 @@ pub fn serialize_primitive_block(
 
     let blob_bytes = blob.write_to_bytes().map_err(|e| {
         error!("serialize_primitive_block: Blob serialization error: {:?}", e);
-        std::io::Error::new(std::io::ErrorKind::Other, "Data Blob serialization failed")
+        std::io::Error::other("Data Blob serialization failed")
     })?;
 
     let mut blob_header = fileformat::BlobHeader::new();
@@ -29,7 +29,7 @@ pub fn serialize_primitive_block(
 
     let blob_header_bytes = blob_header.write_to_bytes().map_err(|e| {
         error!("serialize_primitive_block: BlobHeader serialization error: {:?}", e);
-        std::io::Error::new(std::io::ErrorKind::Other, "BlobHeader serialization failed")
+        std::io::Error::other("BlobHeader serialization failed")
     })?;
 
     debug!("serialize_primitive_block: Blob + BlobHeader ready");
@@ -117,7 +117,7 @@ mod test_serialize_primitive_block {
         ) -> protobuf::Result<()> {
             // We can't construct `Error(Box::new(ProtobufError::...))` directly,
             // so we do `Error::from(io::Error)`.
-            let io_err = IoError::new(ErrorKind::Other, "Simulated PrimitiveBlock write_to_bytes failure");
+            let io_err = IoError::other("Simulated PrimitiveBlock write_to_bytes failure");
             Err(protobuf::Error::from(io_err))
         }
 
@@ -160,7 +160,7 @@ mod test_serialize_primitive_block {
             &self, 
             _os: &mut CodedOutputStream<'_>
         ) -> protobuf::Result<()> {
-            let io_err = IoError::new(ErrorKind::Other, "Simulated data blob write_to_bytes failure");
+            let io_err = IoError::other("Simulated data blob write_to_bytes failure");
             Err(protobuf::Error::from(io_err))
         }
 
@@ -203,7 +203,7 @@ mod test_serialize_primitive_block {
             &self,
             _os: &mut CodedOutputStream<'_>
         ) -> protobuf::Result<()> {
-            let io_err = IoError::new(ErrorKind::Other, "Simulated BlobHeader write_to_bytes failure");
+            let io_err = IoError::other("Simulated BlobHeader write_to_bytes failure");
             Err(protobuf::Error::from(io_err))
         }
 
@@ -242,7 +242,7 @@ mod test_serialize_primitive_block {
 
         // We check that the final .to_string() matches the 
         // "PrimitiveBlock serialization failed" the function would produce
-        let expected = IoError::new(ErrorKind::Other, "PrimitiveBlock serialization failed");
+        let expected = IoError::other("PrimitiveBlock serialization failed");
         assert_eq!(expected.kind(), ErrorKind::Other);
         assert_eq!(expected.to_string(), "PrimitiveBlock serialization failed");
     }
@@ -257,7 +257,7 @@ mod test_serialize_primitive_block {
             "Expected the mock data Blob to fail on write_to_bytes"
         );
 
-        let expected = IoError::new(ErrorKind::Other, "Data Blob serialization failed");
+        let expected = IoError::other("Data Blob serialization failed");
         assert_eq!(expected.kind(), ErrorKind::Other);
         assert_eq!(expected.to_string(), "Data Blob serialization failed");
     }
@@ -272,7 +272,7 @@ mod test_serialize_primitive_block {
             "Expected the mock BlobHeader to fail on write_to_bytes"
         );
 
-        let expected = IoError::new(ErrorKind::Other, "BlobHeader serialization failed");
+        let expected = IoError::other("BlobHeader serialization failed");
         assert_eq!(expected.kind(), ErrorKind::Other);
         assert_eq!(expected.to_string(), "BlobHeader serialization failed");
     }
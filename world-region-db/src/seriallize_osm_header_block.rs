@@ -11,7 +11,7 @@ pub fn serialize_osm_header_block(
 
     let header_block_bytes = header_block.write_to_bytes().map_err(|e| {
         error!("serialize_osm_header_block: protobuf error: {:?}", e);
-        std::io::Error::new(std::io::ErrorKind::Other, "HeaderBlock serialization failed")
+        std::io::Error::other("HeaderBlock serialization failed")
     })?;
 
     let mut blob = fileformat::Blob::new();
@@ -20,7 +20,7 @@ pub fn serialize_osm_header_block(
 
     let blob_bytes = blob.write_to_bytes().map_err(|e| {
         error!("serialize_osm_header_block: blob error: {:?}", e);
-        std::io::Error::new(std::io::ErrorKind::Other, "Blob serialization failed")
+        std::io::Error::other("Blob serialization failed")
     })?;
 
     let mut blob_header = fileformat::BlobHeader::new();
@@ -29,7 +29,7 @@ pub fn serialize_osm_header_block(
 
     let blob_header_bytes = blob_header.write_to_bytes().map_err(|e| {
         error!("serialize_osm_header_block: blob header error: {:?}", e);
-        std::io::Error::new(std::io::ErrorKind::Other, "BlobHeader serialization failed")
+        std::io::Error::other("BlobHeader serialization failed")
     })?;
 
     debug!("serialize_osm_header_block: Blob and BlobHeader ready");
@@ -121,8 +121,7 @@ mod test_serialize_osm_header_block {
             _os: &mut CodedOutputStream<'_>
         ) -> protobuf::Result<()> {
             Err(protobuf::Error::from(
-                    std::io::Error::new(
-                        std::io::ErrorKind::Other,
+                    std::io::Error::other(
                         "Simulated Blob write_to_bytes failure"
                     )
             ))
@@ -170,8 +169,7 @@ mod test_serialize_osm_header_block {
             _os: &mut CodedOutputStream<'_>
         ) -> protobuf::Result<()> {
             Err(protobuf::Error::from(
-                    std::io::Error::new(
-                        std::io::ErrorKind::Other,
+                    std::io::Error::other(
                         "Simulated Blob write_to_bytes failure"
                     )
             ))
@@ -200,8 +198,10 @@ mod test_serialize_osm_header_block {
     /// (replicating that part of `serialize_osm_header_block`).
     #[test]
     fn test_mock_blob_serialization_failure() {
-        let mut mock_blob = FailingBlobForTest::default();
-        mock_blob.raw_data = vec![1, 2, 3];
+        let mock_blob = FailingBlobForTest {
+            raw_data: vec![1, 2, 3],
+            ..Default::default()
+        };
 
         // Attempt to produce .write_to_bytes => should fail with an IoError
         let result = mock_blob.write_to_bytes();
@@ -212,8 +212,7 @@ mod test_serialize_osm_header_block {
 
         // This is the same error that `serialize_osm_header_block` would 
         // produce if the real Blob .write_to_bytes() failed.
-        let expected = IoError::new(
-            ErrorKind::Other,
+        let expected = IoError::other(
             "Blob serialization failed"
         );
         assert_eq!(expected.kind(), ErrorKind::Other);
@@ -232,8 +231,7 @@ mod test_serialize_osm_header_block {
             "Expected the mock BlobHeader to fail on write_to_bytes"
         );
 
-        let expected = IoError::new(
-            ErrorKind::Other,
+        let expected = IoError::other(
             "BlobHeader serialization failed"
         );
         assert_eq!(expected.kind(), ErrorKind::Other);
@@ -0,0 +1,89 @@
+// ---------------- [ File: src/staged_writes.rs ]
+crate::ix!();
+
+/// Puts and deletes collected against a [`Database`] and written in one
+/// `WriteBatch`, so they land together or not at all. Reads see what has
+/// been staged so far, then the database.
+pub(crate) struct StagedWrites<'a> {
+    db:     &'a Database,
+    staged: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+}
+
+impl<'a> StagedWrites<'a> {
+
+    pub(crate) fn new(db: &'a Database) -> Self {
+        Self { db, staged: BTreeMap::new() }
+    }
+
+    /// Number of keys staged so far.
+    pub(crate) fn staged_len(&self) -> usize {
+        self.staged.len()
+    }
+
+    /// Writes everything staged in a single batch.
+    pub(crate) fn commit(self) -> Result<(), DatabaseConstructionError> {
+        let mut batch = rocksdb::WriteBatch::default();
+        for (key, value) in self.staged {
+            match value {
+                Some(value) => batch.put(key, value),
+                None        => batch.delete(key),
+            }
+        }
+        self.db.db().write(batch)?;
+        Ok(())
+    }
+}
+
+impl DatabaseGet for StagedWrites<'_> {
+
+    fn get(&self, key: impl AsRef<[u8]>) -> Result<Option<Vec<u8>>,DataAccessError> {
+        match self.staged.get(key.as_ref()) {
+            Some(staged) => Ok(staged.clone()),
+            None         => self.db.get(key),
+        }
+    }
+}
+
+impl DatabasePut for StagedWrites<'_> {
+
+    fn put(&mut self, key: impl AsRef<[u8]>, val: impl AsRef<[u8]>) -> Result<(),DatabaseConstructionError> {
+        self.staged.insert(key.as_ref().to_vec(), Some(val.as_ref().to_vec()));
+        Ok(())
+    }
+}
+
+impl DatabaseDelete for StagedWrites<'_> {
+
+    fn delete(&mut self, key: impl AsRef<[u8]>) -> Result<(),DatabaseConstructionError> {
+        self.staged.insert(key.as_ref().to_vec(), None);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod staged_writes_tests {
+    use super::*;
+
+    #[traced_test]
+    fn test_reads_see_staged_writes_and_nothing_lands_before_commit() {
+        let (db_arc, _tmp) = create_temp_db::<Database>();
+        let mut db = db_arc.lock().unwrap();
+        db.put("a", b"old").unwrap();
+        db.put("b", b"keep").unwrap();
+
+        let mut staged = StagedWrites::new(&db);
+        staged.put("a", b"new").unwrap();
+        staged.delete("b").unwrap();
+        staged.put("c", b"added").unwrap();
+
+        assert_eq!(staged.get("a").unwrap(), Some(b"new".to_vec()));
+        assert_eq!(staged.get("b").unwrap(), None);
+        assert_eq!(db.get("a").unwrap(), Some(b"old".to_vec()));
+        assert_eq!(db.get("c").unwrap(), None);
+
+        staged.commit().unwrap();
+        assert_eq!(db.get("a").unwrap(), Some(b"new".to_vec()));
+        assert_eq!(db.get("b").unwrap(), None);
+        assert_eq!(db.get("c").unwrap(), Some(b"added".to_vec()));
+    }
+}
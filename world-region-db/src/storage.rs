@@ -2,7 +2,10 @@
 crate::ix!();
 
 /// A simple "Database" wrapper that sets up the dynamic prefix transform.
-#[derive(Builder,Getters)]
+///
+/// Cloning is cheap and the clones share the one RocksDB handle, which is
+/// safe to use from many threads at once.
+#[derive(Builder,Getters,Clone)]
 #[getset(get="pub(crate)")]
 #[builder(setter(into))]
 pub struct Database {
@@ -34,11 +37,14 @@ unsafe impl Send for Database {}
 unsafe impl Sync for Database {}
 
 pub trait StorageInterface
-: CheckIfRegionDone
+: ApplyOsmChangeForRegion
++ CheckIfRegionDone
 + Send
 + Sync
 + Debug
++ DatabaseDelete
 + DatabaseDump
++ DatabaseSchemaVersion
 + GetIterator
 + DatabaseGet
 + DatabasePut
@@ -50,10 +56,15 @@ pub trait StorageInterface
 + LoadHouseNumberRanges 
 + MarkRegionAsDone
 + OpenDatabaseAtPath
++ RegionBuildMarker
++ ReplicationSequenceForRegion
 + StoreHouseNumberRanges
++ WriteAddressLocationsForRegion
++ WriteBoundariesForRegion
 + WriteCitiesToRegionAndPostalCode
 + WriteCitiesToRegionAndStreet
 + WriteIndicesForRegion
++ WriteOsmChangeBaselineForRegion
 + WritePostalCodesToRegionAndCity
 + WritePostalCodesToRegionAndStreet
 + WriteStreetsToRegionAndCity
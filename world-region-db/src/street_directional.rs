@@ -0,0 +1,64 @@
+// ---------------- [ File: src/street_directional.rs ]
+crate::ix!();
+
+/// USPS street directionals (Publication 28, appendix B), as they appear
+/// before or after a street name: `"N Charles St"`, `"M St NW"`.
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Hash)]
+pub enum StreetDirectional {
+    North,
+    South,
+    East,
+    West,
+    Northeast,
+    Northwest,
+    Southeast,
+    Southwest,
+}
+
+impl Abbreviation for StreetDirectional {
+    fn abbreviation(&self) -> &'static str {
+        match self {
+            StreetDirectional::North     => "n",
+            StreetDirectional::South     => "s",
+            StreetDirectional::East      => "e",
+            StreetDirectional::West      => "w",
+            StreetDirectional::Northeast => "ne",
+            StreetDirectional::Northwest => "nw",
+            StreetDirectional::Southeast => "se",
+            StreetDirectional::Southwest => "sw",
+        }
+    }
+}
+
+impl StreetDirectional {
+
+    /// Recognizes a single normalized (lowercase) token, spelled out or
+    /// abbreviated.
+    pub fn from_token(token: &str) -> Option<Self> {
+        let dir = match token {
+            "n" | "north"                  => StreetDirectional::North,
+            "s" | "south"                  => StreetDirectional::South,
+            "e" | "east"                   => StreetDirectional::East,
+            "w" | "west"                   => StreetDirectional::West,
+            "ne" | "northeast"             => StreetDirectional::Northeast,
+            "nw" | "northwest"             => StreetDirectional::Northwest,
+            "se" | "southeast"             => StreetDirectional::Southeast,
+            "sw" | "southwest"             => StreetDirectional::Southwest,
+            _ => return None,
+        };
+        Some(dir)
+    }
+}
+
+#[cfg(test)]
+mod street_directional_tests {
+    use super::*;
+
+    #[traced_test]
+    fn test_long_and_short_forms_share_an_abbreviation() {
+        assert_eq!(StreetDirectional::from_token("north"), Some(StreetDirectional::North));
+        assert_eq!(StreetDirectional::from_token("n"), Some(StreetDirectional::North));
+        assert_eq!(StreetDirectional::from_token("northwest").unwrap().abbreviation(), "nw");
+        assert_eq!(StreetDirectional::from_token("charles"), None);
+    }
+}
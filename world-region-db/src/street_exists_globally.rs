@@ -10,7 +10,7 @@ pub trait StreetExistsGlobally {
     ) -> bool;
 }
 
-impl<I:StorageInterface> StreetExistsGlobally for DataAccess<I> {
+impl<D:GetCitySetForKey + GetPostalCodeSetForKey> StreetExistsGlobally for D {
 
     // street_exists_globally in a region:
     fn street_exists_globally(&self, region: &WorldRegion, street: &StreetName) -> bool {
@@ -11,7 +11,7 @@ pub trait StreetExistsInCityInRegion {
     ) -> bool;
 }
 
-impl<I:StorageInterface> StreetExistsInCityInRegion for DataAccess<I> {
+impl<D:StreetNamesForCityInRegion> StreetExistsInCityInRegion for D {
 
     fn street_exists_in_city(
         &self, 
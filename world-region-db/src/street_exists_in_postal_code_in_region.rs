@@ -11,7 +11,7 @@ pub trait StreetExistsInPostalCodeInRegion {
     ) -> bool;
 }
 
-impl<I:StorageInterface> StreetExistsInPostalCodeInRegion for DataAccess<I> {
+impl<D:StreetNamesForPostalCodeInRegion> StreetExistsInPostalCodeInRegion for D {
 
     fn street_exists_in_postal_code(&self, region: &WorldRegion, postal_code: &PostalCode, street: &StreetName) -> bool {
         if let Some(sts) = self.street_names_for_postal_code_in_region(region, postal_code) {
@@ -0,0 +1,144 @@
+// ---------------- [ File: src/street_geometry.rs ]
+crate::ix!();
+
+/// The drawn lines of a few named streets, kept to tell which side of its
+/// street an interpolation line runs along. A street mapped as several ways
+/// has several lines.
+#[derive(Debug,Default)]
+pub struct StreetGeometry {
+    lines: HashMap<StreetName, Vec<Vec<GeoPoint>>>,
+}
+
+impl StreetGeometry {
+
+    /// Builds the lines of `ways` from whichever of their nodes appear in
+    /// `coords`. Ways left with fewer than two points are dropped.
+    pub fn from_ways(ways: Vec<(StreetName, Vec<i64>)>, coords: &HashMap<i64, GeoPoint>) -> Self {
+        let mut geometry = Self::default();
+        for (street, refs) in ways {
+            let line: Vec<GeoPoint> = refs.iter().filter_map(|id| coords.get(id).copied()).collect();
+            geometry.add_line(street, line);
+        }
+        geometry
+    }
+
+    pub fn add_line(&mut self, street: StreetName, line: Vec<GeoPoint>) {
+        if line.len() >= 2 {
+            self.lines.entry(street).or_default().push(line);
+        }
+    }
+
+    /// Which side of `street`'s nearest segment `point` lies on, looking
+    /// along the way's drawing direction. `Unknown` when we have no line
+    /// for the street or `point` sits on it.
+    pub fn side_of(&self, street: &StreetName, point: &GeoPoint) -> StreetSide {
+        let Some(lines) = self.lines.get(street) else {
+            return StreetSide::Unknown;
+        };
+
+        // a local plane around `point`: longitude shrinks with latitude
+        let scale = point.lat().to_radians().cos();
+        let plane = |p: &GeoPoint| (p.lon() * scale, p.lat());
+        let (px, py) = plane(point);
+
+        let mut nearest: Option<(f64, f64)> = None; // (squared distance, cross product)
+        for segment in lines.iter().flat_map(|line| line.windows(2)) {
+            let ((ax, ay), (bx, by)) = (plane(&segment[0]), plane(&segment[1]));
+            let (dx, dy) = (bx - ax, by - ay);
+            let length2 = dx * dx + dy * dy;
+            if length2 == 0.0 {
+                continue;
+            }
+            let t = (((px - ax) * dx + (py - ay) * dy) / length2).clamp(0.0, 1.0);
+            let (cx, cy) = (ax + t * dx - px, ay + t * dy - py);
+            let distance2 = cx * cx + cy * cy;
+            let cross = dx * (py - ay) - dy * (px - ax);
+
+            if nearest.is_none_or(|(best, _)| distance2 < best) {
+                nearest = Some((distance2, cross));
+            }
+        }
+
+        match nearest {
+            Some((_, cross)) if cross > 0.0 => StreetSide::Left,
+            Some((_, cross)) if cross < 0.0 => StreetSide::Right,
+            _                               => StreetSide::Unknown,
+        }
+    }
+}
+
+/// Another pass over an OSM PBF file, collecting the node ids of every
+/// `highway` way whose `name` is one of `streets`.
+pub fn collect_street_way_refs<R: Read + Send + Sync>(
+    reader:  ElementReader<R>,
+    streets: &HashSet<StreetName>,
+    country: &Country,
+) -> Result<Vec<(StreetName, Vec<i64>)>, OsmPbfParseError> {
+    trace!("collect_street_way_refs: looking for {} streets", streets.len());
+
+    let mut ways = Vec::new();
+    reader.for_each(|element| {
+        let Element::Way(way) = &element else {
+            return;
+        };
+        if !way.tags().any(|(k, _)| k == "highway") {
+            return;
+        }
+        let street = way
+            .tags()
+            .find(|(k, _)| *k == "name")
+            .and_then(|(_, v)| StreetName::new_for_country(v, *country).ok());
+        if let Some(street) = street.filter(|s| streets.contains(s)) {
+            ways.push((street, way.refs().collect()));
+        }
+    })?;
+
+    debug!("collect_street_way_refs: found {} ways for {} streets", ways.len(), streets.len());
+    Ok(ways)
+}
+
+#[cfg(test)]
+mod street_geometry_tests {
+    use super::*;
+
+    fn street(name: &str) -> StreetName {
+        StreetName::new_for_country(name, Country::USA).unwrap()
+    }
+
+    /// Howard Street drawn northward along -76.62, in two ways.
+    fn howard_street() -> StreetGeometry {
+        let mut geometry = StreetGeometry::default();
+        geometry.add_line(street("Howard Street"), vec![mock_point(39.28, -76.62), mock_point(39.29, -76.62)]);
+        geometry.add_line(street("Howard Street"), vec![mock_point(39.29, -76.62), mock_point(39.30, -76.62)]);
+        geometry
+    }
+
+    #[traced_test]
+    fn test_side_follows_drawing_direction() {
+        let geometry = howard_street();
+        let howard   = street("Howard Street");
+
+        // heading north, west is on the left
+        assert_eq!(geometry.side_of(&howard, &mock_point(39.285, -76.621)), StreetSide::Left);
+        assert_eq!(geometry.side_of(&howard, &mock_point(39.295, -76.619)), StreetSide::Right);
+    }
+
+    #[traced_test]
+    fn test_unknown_without_a_line_or_on_the_line() {
+        let geometry = howard_street();
+        assert_eq!(geometry.side_of(&street("Main Street"), &mock_point(39.285, -76.621)), StreetSide::Unknown);
+        assert_eq!(geometry.side_of(&street("Howard Street"), &mock_point(39.285, -76.62)), StreetSide::Unknown);
+    }
+
+    #[traced_test]
+    fn test_from_ways_drops_lines_without_two_points() {
+        let coords: HashMap<i64, GeoPoint> = [(1, mock_point(39.28, -76.62)), (2, mock_point(39.29, -76.62))].into_iter().collect();
+        let geometry = StreetGeometry::from_ways(vec![
+            (street("Howard Street"), vec![1, 2]),
+            (street("Main Street"),   vec![1, 3]),
+        ], &coords);
+
+        assert_eq!(geometry.side_of(&street("Howard Street"), &mock_point(39.285, -76.63)), StreetSide::Left);
+        assert_eq!(geometry.side_of(&street("Main Street"), &mock_point(39.285, -76.63)), StreetSide::Unknown);
+    }
+}
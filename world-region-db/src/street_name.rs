@@ -2,11 +2,24 @@
 crate::ix!();
 
 /// StreetName struct
-#[derive(Builder, Debug, Hash, Clone, Serialize, Deserialize, Getters, PartialEq, Eq, PartialOrd, Ord)]
+///
+/// Two forms are kept: `name` is what we show (normalized, but otherwise as
+/// written), while `canonical` has street types, directionals and diacritics
+/// folded (see [`canonicalize_street_name`]). Equality, ordering, hashing and
+/// storage keys all use `canonical`, so `"N Charles St"` and `"North Charles
+/// Street"` are the same street when built with the same convention.
+#[derive(Builder, Debug, Clone, Serialize, Deserialize, Getters)]
 #[builder(build_fn(error = "StreetNameConstructionError", validate = "Self::validate"))]
 pub struct StreetName {
     #[getset(get = "pub")]
     name: String,
+
+    /// Empty when read from a database written before canonical forms
+    /// were stored; `migrate` fills it in.
+    #[getset(get = "pub")]
+    #[builder(default)]
+    #[serde(default)]
+    canonical: String,
 }
 
 impl StreetNameBuilder {
@@ -46,9 +59,18 @@ impl StreetNameBuilder {
     /// Called from your `StreetName::new(...)` to finalize building,
     /// ensuring we apply normalization to the internal field.
     fn finalize(&self) -> Result<StreetName, StreetNameConstructionError> {
+        self.finalize_with_convention(StreetNameConvention::Generic)
+    }
+
+    /// Like `finalize`, canonicalizing with the given country's abbreviations.
+    fn finalize_with_convention(
+        &self,
+        convention: StreetNameConvention,
+    ) -> Result<StreetName, StreetNameConstructionError> {
         let mut s = self.build()?;
         // If you want to store the normalized version:
-        s.name = normalize(&s.name);
+        s.name      = normalize(&s.name);
+        s.canonical = canonicalize_street_name(&s.name, convention);
         Ok(s)
     }
 }
@@ -56,11 +78,56 @@ impl StreetNameBuilder {
 impl StreetName {
     /// Creates a new StreetName from a &str, applying normalization (e.g. lowercase).
     /// Returns an error if the resulting normalized name is empty or fails a custom rule.
+    ///
+    /// No country-specific abbreviations are applied; use
+    /// [`StreetName::new_for_region`] for names that must match stored data.
     pub fn new(name: &str) -> Result<Self, StreetNameConstructionError> {
         StreetNameBuilder::default()
             .name(name.to_string())
             .finalize()
     }
+
+    /// Creates a StreetName canonicalized with `country`'s street-type and
+    /// directional abbreviations. This is what the database builder uses.
+    pub fn new_for_country(name: &str, country: Country) -> Result<Self, StreetNameConstructionError> {
+        StreetNameBuilder::default()
+            .name(name.to_string())
+            .finalize_with_convention(country.into())
+    }
+
+    /// Creates a StreetName for a query against `region`, canonicalized the
+    /// same way the region's data was when it was built.
+    pub fn new_for_region(name: &str, region: &WorldRegion) -> Result<Self, StreetNameConstructionError> {
+        StreetNameBuilder::default()
+            .name(name.to_string())
+            .finalize_with_convention(StreetNameConvention::for_region(region))
+    }
+}
+
+impl PartialEq for StreetName {
+    fn eq(&self, other: &Self) -> bool {
+        self.canonical == other.canonical
+    }
+}
+
+impl Eq for StreetName {}
+
+impl std::hash::Hash for StreetName {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.canonical.hash(state);
+    }
+}
+
+impl PartialOrd for StreetName {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for StreetName {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.canonical.cmp(&other.canonical)
+    }
 }
 
 impl fmt::Display for StreetName {
@@ -212,6 +279,46 @@ mod street_name_tests {
         assert_eq!(st.name(), "route 66");
     }
 
+    // ------------------------------------------------
+    // Canonical form vs display form
+    // ------------------------------------------------
+    #[traced_test]
+    fn street_name_spellings_share_canonical_form() {
+        let a = StreetName::new_for_country("N Charles St", Country::USA).unwrap();
+        let b = StreetName::new_for_country("North Charles Street", Country::USA).unwrap();
+        let c = StreetName::new_for_country("Charles St N", Country::USA).unwrap();
+
+        assert_eq!(a.canonical(), "n charles st");
+        assert_eq!(a, b);
+        assert_eq!(b, c);
+
+        // The display form is left as written
+        assert_eq!(b.name(), "north charles street");
+        assert_eq!(format!("{}", c), "charles st n");
+
+        let mut set = BTreeSet::new();
+        set.insert(a);
+        assert!(set.contains(&b));
+    }
+
+    #[traced_test]
+    fn street_name_german_abbreviation_and_diacritics() {
+        let long  = StreetName::new_for_country("Hauptstraße", Country::Germany).unwrap();
+        let short = StreetName::new_for_country("Hauptstr.", Country::Germany).unwrap();
+        assert_eq!(long, short);
+        assert_eq!(long.name(), "hauptstraße");
+        assert_eq!(long.canonical(), "hauptstr");
+    }
+
+    #[traced_test]
+    fn street_name_for_region_matches_country() {
+        let md: WorldRegion = USRegion::UnitedState(UnitedState::Maryland).into();
+        let queried = StreetName::new_for_region("North Avenue", &md).unwrap();
+        let built   = StreetName::new_for_country("N Ave", Country::USA).unwrap();
+        assert_eq!(queried, built);
+        assert_ne!(StreetName::new("North Avenue").unwrap(), built, "plain new() applies no abbreviations");
+    }
+
     #[traced_test]
     fn street_name_extreme_length() {
         // e.g. 500 chars of "A"
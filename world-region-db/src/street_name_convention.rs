@@ -0,0 +1,81 @@
+// ---------------- [ File: src/street_name_convention.rs ]
+crate::ix!();
+
+/// Which street-type and directional abbreviations apply when canonicalizing
+/// a street name. Chosen per country; see [`canonicalize_street_name`].
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Hash,Default)]
+pub enum StreetNameConvention {
+    /// USPS suffixes and directionals: `"North Charles Street"` => `"n charles st"`.
+    Usps,
+    /// Compound street types: `"Hauptstraße"` => `"hauptstr"`.
+    German,
+    /// Compound street types: `"Kalverstraat"` => `"kalverstr"`.
+    Dutch,
+    /// Leading street types and saints: `"Boulevard Saint-Michel"` => `"bd st michel"`.
+    French,
+    /// Leading street types: `"Avenida de la Constitución"` => `"av de la constitucion"`.
+    Spanish,
+    /// Case and diacritic folding only.
+    #[default]
+    Generic,
+}
+
+impl From<Country> for StreetNameConvention {
+    fn from(country: Country) -> Self {
+        match country {
+            Country::USA
+            | Country::Canada        => StreetNameConvention::Usps,
+
+            Country::Germany
+            | Country::Austria
+            | Country::Switzerland
+            | Country::Liechtenstein => StreetNameConvention::German,
+
+            Country::Netherlands     => StreetNameConvention::Dutch,
+
+            Country::France
+            | Country::Belgium
+            | Country::Luxembourg
+            | Country::Monaco        => StreetNameConvention::French,
+
+            Country::Spain
+            | Country::Mexico        => StreetNameConvention::Spanish,
+
+            _                        => StreetNameConvention::Generic,
+        }
+    }
+}
+
+impl StreetNameConvention {
+
+    /// The convention for the country `region` belongs to, falling back to
+    /// [`StreetNameConvention::Generic`] when the region has no country.
+    pub fn for_region(region: &WorldRegion) -> Self {
+        match Country::try_from(*region) {
+            Ok(country) => country.into(),
+            Err(e) => {
+                debug!("StreetNameConvention::for_region: no country for {:?}: {:?}", region, e);
+                StreetNameConvention::Generic
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod street_name_convention_tests {
+    use super::*;
+
+    #[traced_test]
+    fn test_conventions_by_country() {
+        assert_eq!(StreetNameConvention::from(Country::USA), StreetNameConvention::Usps);
+        assert_eq!(StreetNameConvention::from(Country::Austria), StreetNameConvention::German);
+        assert_eq!(StreetNameConvention::from(Country::Belgium), StreetNameConvention::French);
+        assert_eq!(StreetNameConvention::from(Country::Japan), StreetNameConvention::Generic);
+    }
+
+    #[traced_test]
+    fn test_us_region_uses_usps() {
+        let md: WorldRegion = USRegion::UnitedState(UnitedState::Maryland).into();
+        assert_eq!(StreetNameConvention::for_region(&md), StreetNameConvention::Usps);
+    }
+}
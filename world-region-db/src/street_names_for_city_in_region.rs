@@ -11,7 +11,7 @@ pub trait StreetNamesForCityInRegion {
     ) -> Option<BTreeSet<StreetName>>;
 }
 
-impl<I:StorageInterface> StreetNamesForCityInRegion for DataAccess<I> {
+impl<D:GetStreetSetForKey> StreetNamesForCityInRegion for D {
 
     // Similarly for other queries:
     fn street_names_for_city_in_region(&self, region: &WorldRegion, city: &CityName) -> Option<BTreeSet<StreetName>> {
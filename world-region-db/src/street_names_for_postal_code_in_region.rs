@@ -11,7 +11,7 @@ pub trait StreetNamesForPostalCodeInRegion {
 }
 
 
-impl<I:StorageInterface> StreetNamesForPostalCodeInRegion for DataAccess<I> {
+impl<D:GetStreetSetForKey> StreetNamesForPostalCodeInRegion for D {
 
     fn street_names_for_postal_code_in_region(
         &self, 
@@ -18,7 +18,7 @@ pub fn try_build_address_record_from_tags<'a>(
     let (city_raw, street_raw, postcode_raw) = try_extract_address_tags(&tags, element_id)?;
 
     let city_obj     = try_construct_city_name(city_raw, element_id)?;
-    let street_obj   = try_construct_street_name(street_raw, element_id)?;
+    let street_obj   = try_construct_street_name(country, street_raw, element_id)?;
     let postcode_obj = try_construct_postal_code(country, postcode_raw, element_id)?;
 
     // 3) Use the new relaxed assembly
@@ -53,17 +53,6 @@ mod test_try_build_address_record_from_tags {
         tags.iter().map(|&(k, v)| (k, v))
     }
 
-    /// Utility function for constructing an OSM-style key-value map from pairs.
-    /// In actual usage, `collect_tags(...)` is used internally by the function,
-    /// but we can replicate for testing or simply pass the iterator.
-    fn build_tags_map(tags: &[(&str, &str)]) -> HashMap<String, String> {
-        let mut map = HashMap::new();
-        for (k, v) in tags {
-            map.insert(k.to_string(), v.to_string());
-        }
-        map
-    }
-
     fn test_country() -> Country {
         // For this test, we'll consistently use the USA
         Country::USA
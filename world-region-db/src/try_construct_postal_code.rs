@@ -44,14 +44,14 @@ pub fn try_construct_postal_code(
             "try_construct_postal_code: all sub‐codes invalid. Original='{}' (element_id={})",
             raw_value, element_id
         );
-        return Err(IncompatibleOsmPbfElement::IncompatibleOsmPbfNode(
+        Err(IncompatibleOsmPbfElement::IncompatibleOsmPbfNode(
             IncompatibleOsmPbfNode::PostalCodeConstructionError(
                 crate::PostalCodeConstructionError::InvalidFormat {
                     attempted_code: raw_value.to_string(),
                     attempted_country: Some(country),
                 }
             )
-        ));
+        ))
     } else {
         // If no postcode tag at all, this is not an error; just "None".
         Ok(None)
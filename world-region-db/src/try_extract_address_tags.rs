@@ -5,10 +5,13 @@ crate::ix!();
 /// If at least one of `addr:city`, `addr:street`, or `addr:postcode` is present,
 /// returns a tuple of optional string slices corresponding to these tags.
 /// Returns an error if none of these tags are present.
+/// The `(city, street, postcode)` tag values of an element.
+pub type AddressTags<'a> = (Option<&'a str>, Option<&'a str>, Option<&'a str>);
+
 pub fn try_extract_address_tags(
     tags: &HashMap<String, String>,
     element_id: i64,
-) -> Result<(Option<&str>, Option<&str>, Option<&str>), IncompatibleOsmPbfElement> {
+) -> Result<AddressTags<'_>, IncompatibleOsmPbfElement> {
 
     let city_opt     = tags.get("addr:city").map(|s| s.as_str());
     let street_opt   = tags.get("addr:street").map(|s| s.as_str());
@@ -43,10 +43,8 @@ mod test_update_street_house_numbers {
         region: &WorldRegion,
         street: &StreetName,
     ) -> Option<Vec<HouseNumberRange>> {
-        match db.load_house_number_ranges(region, street) {
-            Ok(ranges) => ranges,
-            Err(_) => None, // If any error arises, we simplify to None
-        }
+        // If any error arises, we simplify to None
+        db.load_house_number_ranges(region, street).unwrap_or_default()
     }
 
     #[traced_test]
@@ -129,7 +127,7 @@ mod test_update_street_house_numbers {
     fn test_load_error_returns_data_access_error() {
 
         // We'll pass this failing DB into the function
-        let mut db     = FailingLoadDatabase::new().unwrap();
+        let db     = FailingLoadDatabase::new().unwrap();
         let mut db_guard = db.lock().unwrap();
         let region       = WorldRegion::try_from_abbreviation("MD").unwrap();
         let street       = StreetName::new("Fail Street").unwrap();
@@ -153,7 +151,7 @@ mod test_update_street_house_numbers {
     #[traced_test]
     fn test_store_error_returns_error() {
 
-        let mut db = FailingStoreDatabase::new().unwrap();
+        let db = FailingStoreDatabase::new().unwrap();
 
         let mut db_guard = db.lock().unwrap();
         db_guard.set_existing(vec![hnr(10,20)]);
@@ -0,0 +1,404 @@
+// ---------------- [ File: src/validate_address_file.rs ]
+crate::ix!();
+
+/// Per-row result written to the report's `status` column.
+#[derive(Debug,Clone,Copy,PartialEq,Eq,PartialOrd,Ord,Hash,Serialize,Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkRowStatus {
+    Valid,
+    UnknownPostalCode,
+    CityPostalCodeMismatch,
+    StreetNotInPostalCode,
+    UnknownCity,
+    StreetNotInCity,
+    HouseNumberOutOfRange,
+    /// Missing columns, unknown region, or a value that cannot be a postal
+    /// code, city, street or house number; see the row's `detail`.
+    InvalidInput,
+}
+
+impl BulkRowStatus {
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BulkRowStatus::Valid                  => "valid",
+            BulkRowStatus::UnknownPostalCode      => "unknown_postal_code",
+            BulkRowStatus::CityPostalCodeMismatch => "city_postal_code_mismatch",
+            BulkRowStatus::StreetNotInPostalCode  => "street_not_in_postal_code",
+            BulkRowStatus::UnknownCity            => "unknown_city",
+            BulkRowStatus::StreetNotInCity        => "street_not_in_city",
+            BulkRowStatus::HouseNumberOutOfRange  => "house_number_out_of_range",
+            BulkRowStatus::InvalidInput           => "invalid_input",
+        }
+    }
+}
+
+impl From<AddressProblem> for BulkRowStatus {
+    fn from(problem: AddressProblem) -> Self {
+        match problem {
+            AddressProblem::UnknownPostalCode     => BulkRowStatus::UnknownPostalCode,
+            AddressProblem::CityNotInPostalCode   => BulkRowStatus::CityPostalCodeMismatch,
+            AddressProblem::StreetNotInPostalCode => BulkRowStatus::StreetNotInPostalCode,
+            AddressProblem::UnknownCity           => BulkRowStatus::UnknownCity,
+            AddressProblem::StreetNotInCity       => BulkRowStatus::StreetNotInCity,
+        }
+    }
+}
+
+/// What validating one row produced. `correction` is the best fuzzy match
+/// for `correction_field`, when the database has one.
+#[derive(Builder,Getters,Debug,Clone,PartialEq,Eq)]
+#[getset(get="pub")]
+#[builder(setter(into))]
+pub struct BulkRowOutcome {
+    status:           BulkRowStatus,
+
+    #[builder(default)]
+    correction_field: Option<AddressField>,
+
+    #[builder(default)]
+    correction:       Option<String>,
+
+    #[builder(default)]
+    detail:           Option<String>,
+}
+
+impl BulkRowOutcome {
+
+    fn with_status(status: BulkRowStatus) -> Self {
+        BulkRowOutcomeBuilder::default().status(status).build().unwrap()
+    }
+
+    fn invalid_input(detail: impl Into<String>) -> Self {
+        BulkRowOutcomeBuilder::default()
+            .status(BulkRowStatus::InvalidInput)
+            .detail(Some(detail.into()))
+            .build()
+            .unwrap()
+    }
+}
+
+#[derive(Builder,Getters,Debug,Clone)]
+#[getset(get="pub")]
+#[builder(setter(into))]
+pub struct BulkValidationOptions {
+    input:          PathBuf,
+    output:         PathBuf,
+
+    /// Guessed from the input's extension when not given; the report is
+    /// written in the same format.
+    #[builder(default)]
+    format:         Option<BulkAddressFormat>,
+
+    #[builder(default)]
+    mapping:        AddressColumnMapping,
+
+    /// Used for rows without a region column or value.
+    #[builder(default)]
+    default_region: Option<WorldRegion>,
+
+    /// Worker threads; `None` means one per available core.
+    #[builder(default)]
+    threads:        Option<usize>,
+}
+
+#[derive(Getters,Debug,Clone,Default,PartialEq,Eq)]
+#[getset(get="pub")]
+pub struct BulkValidationSummary {
+    rows:      usize,
+    by_status: BTreeMap<BulkRowStatus,usize>,
+}
+
+impl BulkValidationSummary {
+
+    fn record(&mut self, outcome: &BulkRowOutcome) {
+        self.rows += 1;
+        *self.by_status.entry(*outcome.status()).or_insert(0) += 1;
+    }
+
+    pub fn count(&self, status: BulkRowStatus) -> usize {
+        self.by_status.get(&status).copied().unwrap_or(0)
+    }
+}
+
+/// Rows each worker thread gets per batch. Input is read and the report
+/// written one batch at a time, so memory does not grow with the file.
+pub const BULK_ROWS_PER_THREAD: usize = 256;
+
+/// Validates every address in a CSV or JSONL file against `db` and writes
+/// the report described in [`write_bulk_validation_report`].
+pub fn validate_address_file<I:StorageInterface>(
+    db:      I,
+    options: &BulkValidationOptions,
+) -> Result<BulkValidationSummary,BulkValidationError> {
+    let format = match options.format() {
+        Some(format) => *format,
+        None         => BulkAddressFormat::from_path(options.input())?,
+    };
+    info!("validate_address_file: {:?} ({:?}) => {:?}", options.input(), format, options.output());
+
+    let service = AddressService::new(db);
+    let threads = options.threads().unwrap_or_else(|| {
+        std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+    });
+
+    let mut rows    = bulk_address_rows(options.input(), format)?;
+    let mut report  = BulkReportWriter::create(options.output(), format)?;
+    let mut summary = BulkValidationSummary::default();
+    loop {
+        let batch = rows.by_ref().take(threads.max(1) * BULK_ROWS_PER_THREAD).collect::<Result<Vec<_>,_>>()?;
+        if batch.is_empty() {
+            break;
+        }
+        let outcomes = validate_bulk_rows(&service, &batch, options.mapping(), options.default_region().as_ref(), threads);
+        for (row, outcome) in batch.iter().zip(&outcomes) {
+            report.write_row(row, outcome)?;
+            summary.record(outcome);
+        }
+    }
+    report.finish()?;
+
+    info!("validate_address_file: {:?}", summary);
+    Ok(summary)
+}
+
+/// Validates `rows` on up to `threads` scoped threads, each taking a
+/// contiguous chunk; outcomes come back in row order. The service gives
+/// every query its own handle, so the threads never queue on a lock.
+pub fn validate_bulk_rows<I:StorageInterface>(
+    service:        &AddressService<I>,
+    rows:           &[BulkAddressRow],
+    mapping:        &AddressColumnMapping,
+    default_region: Option<&WorldRegion>,
+    threads:        usize,
+) -> Vec<BulkRowOutcome> {
+    if rows.is_empty() {
+        return vec![];
+    }
+    let chunk_size = rows.len().div_ceil(threads.max(1));
+
+    thread::scope(|scope| {
+        let workers: Vec<_> = rows
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|row| validate_bulk_row(service, row, mapping, default_region))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("bulk validation worker panicked"))
+            .collect()
+    })
+}
+
+fn validate_bulk_row<I:StorageInterface>(
+    service:        &AddressService<I>,
+    row:            &BulkAddressRow,
+    mapping:        &AddressColumnMapping,
+    default_region: Option<&WorldRegion>,
+) -> BulkRowOutcome {
+    trace!("validate_bulk_row: line {}", row.line());
+
+    let region = match (row.get(mapping.region()), default_region) {
+        (Some(region), _)    => region,
+        (None, Some(region)) => region.abbreviation().to_string(),
+        (None, None)         => return BulkRowOutcome::invalid_input(format!("no {} and no default region", mapping.region())),
+    };
+
+    let mut missing = vec![];
+    let mut required = |column: &String| {
+        let value = row.get(column);
+        if value.is_none() {
+            missing.push(column.clone());
+        }
+        value.unwrap_or_default()
+    };
+    let postal_code = required(mapping.postal_code());
+    let city        = required(mapping.city());
+    let street      = required(mapping.street());
+    if !missing.is_empty() {
+        return BulkRowOutcome::invalid_input(format!("missing {}", missing.join(", ")));
+    }
+
+    let house_number = match row.get(mapping.house_number()) {
+        None      => None,
+        Some(raw) => match HouseNumber::parse(&raw) {
+            Some(number) => Some(number),
+            None         => return BulkRowOutcome::invalid_input(format!("unreadable house number {:?}", raw)),
+        },
+    };
+
+    let request = ValidateRequestBuilder::default()
+        .region(region.clone())
+        .postal_code(postal_code)
+        .city(city)
+        .street(street.clone())
+        .build()
+        .unwrap();
+
+    let response = match service.validate(&request) {
+        Ok(response) => response,
+        Err(e)       => return BulkRowOutcome::invalid_input(e.to_string()),
+    };
+
+    if let Some(problem) = response.problem() {
+        return BulkRowOutcomeBuilder::default()
+            .status(BulkRowStatus::from(*problem))
+            .correction_field(*response.field())
+            .correction(response.suggestions().first().cloned())
+            .build()
+            .unwrap();
+    }
+
+    let Some(number) = house_number else {
+        return BulkRowOutcome::with_status(BulkRowStatus::Valid);
+    };
+
+    // A street with no stored ranges cannot be checked, so it passes.
+    match service.check_house_number(&region, &street, &number) {
+        Ok(resp) if resp.ranges().is_empty() || *resp.in_range() => BulkRowOutcome::with_status(BulkRowStatus::Valid),
+        Ok(resp) => BulkRowOutcomeBuilder::default()
+            .status(BulkRowStatus::HouseNumberOutOfRange)
+            .detail(Some(format!("known ranges: {:?}", resp.ranges())))
+            .build()
+            .unwrap(),
+        Err(e) => BulkRowOutcome::invalid_input(e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod validate_address_file_tests {
+    use super::*;
+
+    fn md() -> WorldRegion {
+        USRegion::UnitedState(UnitedState::Maryland).into()
+    }
+
+    fn md_db() -> (Arc<Mutex<Database>>, TempDir) {
+        let (db, tmp) = create_temp_db::<Database>();
+        let region = md();
+
+        let baltimore = CityName::new("Baltimore").unwrap();
+        let howard_st = StreetName::new_for_region("Howard Street", &region).unwrap();
+        let north_ave = StreetName::new_for_region("North Avenue", &region).unwrap();
+        let pc21201   = PostalCode::new(Country::USA, "21201").unwrap();
+
+        let records = RegionalRecordsBuilder::default()
+            .region(region)
+            .records(vec![
+                address_record!(baltimore, howard_st, pc21201),
+                address_record!(baltimore, north_ave, pc21201),
+            ])
+            .build()
+            .unwrap();
+        {
+            let mut guard = db.lock().unwrap();
+            records.write_to_storage(&mut *guard).unwrap();
+            guard.store_house_number_ranges(&region, &howard_st, &[HouseNumberRange::new(100, 200)]).unwrap();
+        }
+        (db, tmp)
+    }
+
+    #[traced_test]
+    fn test_csv_report_statuses_and_corrections() {
+        let (db, tmp) = md_db();
+        let input  = tmp.path().join("addresses.csv");
+        let output = tmp.path().join("report.csv");
+        std::fs::write(&input, "\
+zip,city,street,number
+21201,Baltimore,Howard St,150
+21201,Baltimore,Howard St,999
+21201,Baltmore,Howard St,
+99999,Baltimore,Howard St,
+21201,Baltimore,Hward Street,
+21201,Baltimore,,
+").unwrap();
+
+        let options = BulkValidationOptionsBuilder::default()
+            .input(input)
+            .output(output.clone())
+            .mapping(AddressColumnMapping::from_specs(&["postal_code=zip", "house_number=number"]).unwrap())
+            .default_region(Some(md()))
+            .threads(Some(3))
+            .build()
+            .unwrap();
+
+        let summary = validate_address_file(db.lock().unwrap().clone(), &options).unwrap();
+        assert_eq!(*summary.rows(), 6);
+        assert_eq!(summary.count(BulkRowStatus::Valid), 1);
+        assert_eq!(summary.count(BulkRowStatus::HouseNumberOutOfRange), 1);
+        assert_eq!(summary.count(BulkRowStatus::InvalidInput), 1);
+
+        let report = read_bulk_address_rows(&output, BulkAddressFormat::Csv).unwrap();
+        let column = |i: usize, name: &str| report[i].get(name);
+
+        assert_eq!(column(0, "status").as_deref(), Some("valid"));
+        assert_eq!(column(1, "status").as_deref(), Some("house_number_out_of_range"));
+        assert_eq!(column(2, "status").as_deref(), Some("city_postal_code_mismatch"));
+        assert_eq!(column(2, "correction_field").as_deref(), Some("city"));
+        assert_eq!(column(2, "correction").as_deref(), Some("baltimore"));
+        assert_eq!(column(3, "status").as_deref(), Some("unknown_postal_code"));
+        assert_eq!(column(3, "correction").as_deref(), Some("21201"));
+        assert_eq!(column(4, "status").as_deref(), Some("street_not_in_postal_code"));
+        assert_eq!(column(4, "correction").as_deref(), Some("howard street"));
+        assert_eq!(column(5, "status").as_deref(), Some("invalid_input"));
+        assert_eq!(column(5, "detail").as_deref(), Some("missing street"));
+    }
+
+    #[traced_test]
+    fn test_jsonl_report_keeps_input_fields() {
+        let (db, tmp) = md_db();
+        let input  = tmp.path().join("addresses.jsonl");
+        let output = tmp.path().join("report.jsonl");
+        std::fs::write(&input, "\
+{\"id\":7,\"region\":\"MD\",\"postal_code\":\"21201\",\"city\":\"Baltimore\",\"street\":\"North Ave\"}
+{\"id\":8,\"postal_code\":\"21201\",\"city\":\"Baltimore\",\"street\":\"North Ave\"}
+").unwrap();
+
+        let options = BulkValidationOptionsBuilder::default()
+            .input(input)
+            .output(output.clone())
+            .build()
+            .unwrap();
+
+        let summary = validate_address_file(db.lock().unwrap().clone(), &options).unwrap();
+        assert_eq!(summary.count(BulkRowStatus::Valid), 1);
+        assert_eq!(summary.count(BulkRowStatus::InvalidInput), 1);
+
+        let report = read_bulk_address_rows(&output, BulkAddressFormat::Jsonl).unwrap();
+        assert_eq!(report[0].get("id").as_deref(), Some("7"));
+        assert_eq!(report[0].get("status").as_deref(), Some("valid"));
+        assert_eq!(report[0].get("correction"), None);
+        assert_eq!(report[1].get("status").as_deref(), Some("invalid_input"));
+    }
+
+    #[traced_test]
+    fn test_parallel_outcomes_keep_row_order() {
+        let (db, _tmp) = md_db();
+        let service = AddressService::new(db.lock().unwrap().clone());
+
+        let rows: Vec<BulkAddressRow> = (0..25)
+            .map(|i| {
+                let zip = if i % 2 == 0 { "21201" } else { "99999" };
+                BulkAddressRow::new(i + 1, vec![
+                    ("postal_code".to_string(), serde_json::json!(zip)),
+                    ("city".to_string(),        serde_json::json!("Baltimore")),
+                    ("street".to_string(),      serde_json::json!("Howard Street")),
+                ])
+            })
+            .collect();
+
+        let outcomes = validate_bulk_rows(&service, &rows, &AddressColumnMapping::default(), Some(&md()), 4);
+        assert_eq!(outcomes.len(), rows.len());
+        for (i, outcome) in outcomes.iter().enumerate() {
+            let expected = if i % 2 == 0 { BulkRowStatus::Valid } else { BulkRowStatus::UnknownPostalCode };
+            assert_eq!(*outcome.status(), expected, "row {}", i);
+        }
+    }
+}
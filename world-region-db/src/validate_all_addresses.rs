@@ -86,7 +86,7 @@ mod validate_all_addresses_tests {
     fn test_validate_all_addresses_empty_dir() -> Result<(), AddressValidationError> {
         let (db, tmp) = create_temp_db::<Database>();
         // No .pbf files in directory.
-        let r = validate_all_addresses(db, &tmp.path());
+        let r = validate_all_addresses(db, tmp.path());
         assert!(r.is_ok());
         Ok(())
     }
@@ -111,7 +111,7 @@ mod validate_all_addresses_tests {
         let pbf_file = tmp.path().join("maryland-latest.osm.pbf");
         write_fake_pbf(&pbf_file, b"").await?;
 
-        let r = validate_all_addresses(db, &tmp.path());
+        let r = validate_all_addresses(db, tmp.path());
         assert!(r.is_ok());
         Ok(())
     }
@@ -122,7 +122,7 @@ mod validate_all_addresses_tests {
         let pbf_file = tmp.path().join("maryland-latest.osm.pbf");
         // Write mock data that simulates an incomplete address.
         write_mock_addresses(&pbf_file, &["incomplete city/street"]).await?;
-        let r = validate_all_addresses(db, &tmp.path());
+        let r = validate_all_addresses(db, tmp.path());
         assert!(matches!(
             r,
             Err(WorldCityAndStreetDbBuilderError::NotAllAddressesValidatedSuccessfully)
@@ -141,7 +141,7 @@ mod validate_all_addresses_tests {
             &["some line that yields an address: city=Baltimore, street=North Avenue, postal=21201"],
         )
         .await?;
-        let r = validate_all_addresses(db, &tmp.path());
+        let r = validate_all_addresses(db, tmp.path());
         assert!(matches!(
             r,
             Err(WorldCityAndStreetDbBuilderError::NotAllAddressesValidatedSuccessfully)
@@ -155,7 +155,7 @@ mod validate_all_addresses_tests {
         let pbf_file = tmp.path().join("maryland-latest.osm.pbf");
         let random_bytes = [0u8, 1, 2, 3, 255];
         write_fake_pbf(&pbf_file, &random_bytes).await?;
-        let r = validate_all_addresses(db, &tmp.path());
+        let r = validate_all_addresses(db, tmp.path());
         assert!(matches!(
             r,
             Err(WorldCityAndStreetDbBuilderError::NotAllAddressesValidatedSuccessfully)
@@ -187,7 +187,7 @@ mod validate_all_addresses_tests {
         let pbf_file = tmp.path().join("maryland-latest.osm.pbf");
         // Write an empty PBF file so that no additional addresses are yielded.
         write_fake_pbf(&pbf_file, b"").await?;
-        let r = validate_all_addresses(db, &tmp.path());
+        let r = validate_all_addresses(db, tmp.path());
         assert!(r.is_ok());
         Ok(())
     }
@@ -209,7 +209,7 @@ mod validate_all_addresses_tests {
         let pbf_file = tmp.path().join("maryland-latest.osm.pbf");
         // Write an empty file to avoid parse errors.
         write_fake_pbf(&pbf_file, b"").await?;
-        let r = validate_all_addresses(db, &tmp.path());
+        let r = validate_all_addresses(db, tmp.path());
         assert!(r.is_ok());
         Ok(())
     }
@@ -76,7 +76,7 @@ mod test_validate_city_for_postal_code {
     fn test_no_city_set_exists_returns_error() {
 
         let (data_access, db_arc, _temp_dir) = create_data_access::<Database>();
-        let mut db_guard = db_arc.lock().unwrap();
+        let db_guard = db_arc.lock().unwrap();
 
         let region      = WorldRegion::try_from_abbreviation("MD").unwrap();
         let city        = CityName::new("Baltimore").unwrap();
@@ -109,7 +109,7 @@ mod test_validate_city_for_postal_code {
         // We'll store a set that contains [Annapolis], but not Baltimore
         let mut city_set = BTreeSet::new();
         city_set.insert(city_annapolis);
-        put_z2c_data(&mut *db_guard, &addr.region(), &addr.postal_code(), &city_set);
+        put_z2c_data(&mut *db_guard, addr.region(), addr.postal_code(), &city_set);
         drop(db_guard);
 
         let result = validate_city_for_postal_code(&addr, &data_access);
@@ -138,7 +138,7 @@ mod test_validate_city_for_postal_code {
         // Store a set that DOES contain Baltimore
         let mut city_set = BTreeSet::new();
         city_set.insert(city_baltimore.clone());
-        put_z2c_data(&mut *db_guard, &addr.region(), &addr.postal_code(), &city_set);
+        put_z2c_data(&mut *db_guard, addr.region(), addr.postal_code(), &city_set);
         drop(db_guard);
 
         let result = validate_city_for_postal_code(&addr, &data_access);
@@ -158,7 +158,7 @@ mod test_validate_city_for_postal_code {
         let addr        = make_world_address(region, city, postal_code);
 
         // Insert invalid data
-        let z2c_k = z2c_key(&addr.region(), &addr.postal_code());
+        let z2c_k = z2c_key(addr.region(), addr.postal_code());
         db_guard.put(z2c_k.clone(), b"not valid cbor").unwrap();
         drop(db_guard);
 
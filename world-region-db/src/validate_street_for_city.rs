@@ -13,7 +13,7 @@ where
     + PostalCodesForStreetInRegion,
 {
     let region = addr.region();
-    let city_key = c_key(region, addr.city());
+    let city_key = c2s_key(region, addr.city());
     trace!(
         "validate_street_for_city: region={:?}, city={}, street={}, zip={}",
         region,
@@ -31,7 +31,7 @@ where
                     addr.street().name(),
                     addr.city().name()
                 );
-                return Ok(());
+                Ok(())
             } else {
                 warn!(
                     "validate_street_for_city: street='{}' not found in city='{}' => attempting fallback check",
@@ -78,29 +78,29 @@ where
                             addr.street().name(),
                             addr.postal_code().code()
                         );
-                        return Ok(());
+                        Ok(())
                     } else {
                         warn!(
                             "fallback: street='{}' does NOT contain zip='{}'; cannot validate",
                             addr.street().name(),
                             addr.postal_code().code()
                         );
-                        return Err(InvalidWorldAddress::StreetNotFoundForCityInRegion {
+                        Err(InvalidWorldAddress::StreetNotFoundForCityInRegion {
                             street: addr.street().clone(),
                             city: addr.city().clone(),
                             region: *region,
-                        });
+                        })
                     }
                 } else {
                     warn!(
                         "fallback: no street_zips found for street='{}' => fallback fails",
                         addr.street().name()
                     );
-                    return Err(InvalidWorldAddress::StreetNotFoundForCityInRegion {
+                    Err(InvalidWorldAddress::StreetNotFoundForCityInRegion {
                         street: addr.street().clone(),
                         city: addr.city().clone(),
                         region: *region,
-                    });
+                    })
                 }
             }
         }
@@ -109,11 +109,11 @@ where
                 "validate_street_for_city: no city->streets data for city='{}' => error",
                 addr.city().name()
             );
-            return Err(InvalidWorldAddress::CityToStreetsKeyNotFoundForCityInRegion {
+            Err(InvalidWorldAddress::CityToStreetsKeyNotFoundForCityInRegion {
                 c_key: city_key,
                 region: *region,
                 city: addr.city().clone(),
-            });
+            })
         }
     }
 }
@@ -138,14 +138,14 @@ mod test_validate_street_for_city {
             .unwrap()
     }
 
-    /// Helper to store a set of streets under the `c_key(region, city)` used by the code.
+    /// Helper to store a set of streets under the `c2s_key(region, city)` used by the code.
     fn put_c_key_streets<I:StorageInterface>(
         db:      &mut I,
         region:  &WorldRegion,
         city:    &CityName,
         streets: &BTreeSet<StreetName>,
     ) {
-        let key = c_key(region, city);
+        let key = c2s_key(region, city);
         let val = compress_set_to_cbor(streets);
         db.put(key, val).unwrap();
     }
@@ -239,7 +239,7 @@ mod test_validate_street_for_city {
         let city = CityName::new("GlitchCity").unwrap();
         let street = StreetName::new("GlitchStreet").unwrap();
 
-        let c_k = c_key(&region, &city);
+        let c_k = c2s_key(&region, &city);
         db_guard.put(c_k.clone(), b"invalid cbor").unwrap();
         drop(db_guard);
 
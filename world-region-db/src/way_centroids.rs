@@ -0,0 +1,125 @@
+// ---------------- [ File: src/way_centroids.rs ]
+crate::ix!();
+
+/// Addresses parsed from OSM ways only know their node ids, and those nodes
+/// usually appear earlier in the file than the way itself. Rather than
+/// holding every node coordinate in memory during the first pass, we note
+/// which addresses need a centroid here and resolve them with a second,
+/// targeted pass over the file.
+#[derive(Debug,Default)]
+pub struct PendingWayCentroids {
+    /// `(index into the address vector, node refs of the way)`
+    pending: Vec<(usize, Vec<i64>)>,
+}
+
+impl PendingWayCentroids {
+
+    pub fn push(&mut self, address_index: usize, node_refs: Vec<i64>) {
+        self.pending.push((address_index, node_refs));
+    }
+
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Every node id we need a coordinate for.
+    pub fn needed_node_ids(&self) -> HashSet<i64> {
+        self.pending
+            .iter()
+            .flat_map(|(_, refs)| refs.iter().copied())
+            .collect()
+    }
+
+    /// Assigns each pending address the centroid of whichever of its nodes
+    /// appear in `coords`. Returns how many addresses received a location.
+    pub fn resolve(
+        self,
+        coords:    &HashMap<i64, GeoPoint>,
+        addresses: &mut [AddressRecord],
+    ) -> usize {
+        let mut resolved = 0;
+        for (idx, refs) in self.pending {
+            let points: Vec<GeoPoint> = refs.iter().filter_map(|id| coords.get(id).copied()).collect();
+            match (GeoPoint::centroid(&points), addresses.get_mut(idx)) {
+                (Some(centroid), Some(record)) => {
+                    record.set_location(Some(centroid));
+                    resolved += 1;
+                }
+                _ => debug!("PendingWayCentroids::resolve: no coordinates for address #{}", idx),
+            }
+        }
+        resolved
+    }
+}
+
+/// Second pass over an OSM PBF file, collecting coordinates for exactly the
+/// node ids in `wanted`.
+pub fn collect_node_coordinates<R: Read + Send + Sync>(
+    reader: ElementReader<R>,
+    wanted: &HashSet<i64>,
+) -> Result<HashMap<i64, GeoPoint>, OsmPbfParseError> {
+    trace!("collect_node_coordinates: looking for {} nodes", wanted.len());
+
+    let mut coords = HashMap::new();
+    reader.for_each(|element| {
+        let id = match &element {
+            Element::Node(node)       => node.id(),
+            Element::DenseNode(dense) => dense.id(),
+            _                         => return,
+        };
+        if wanted.contains(&id)
+            && let Some(point) = element_location(&element) {
+            coords.insert(id, point);
+        }
+    })?;
+
+    debug!("collect_node_coordinates: found {} of {} nodes", coords.len(), wanted.len());
+    Ok(coords)
+}
+
+#[cfg(test)]
+mod way_centroids_tests {
+    use super::*;
+
+    fn record(street: &str) -> AddressRecord {
+        AddressRecordBuilder::default()
+            .street(Some(StreetName::new(street).unwrap()))
+            .build()
+            .unwrap()
+    }
+
+    #[traced_test]
+    fn test_resolve_assigns_centroid() {
+        let mut addresses = vec![record("North Avenue"), record("Main Street")];
+
+        let mut pending = PendingWayCentroids::default();
+        pending.push(1, vec![10, 11, 12]);
+        assert_eq!(pending.needed_node_ids(), [10, 11, 12].into_iter().collect());
+
+        let mut coords = HashMap::new();
+        coords.insert(10, GeoPoint::from_degrees(39.0, -76.0).unwrap());
+        coords.insert(11, GeoPoint::from_degrees(39.2, -76.2).unwrap());
+        // node 12 is missing from the extract => ignored
+
+        assert_eq!(pending.resolve(&coords, &mut addresses), 1);
+        assert!(addresses[0].location().is_none());
+
+        let loc = addresses[1].location().unwrap();
+        assert!((loc.lat() - 39.1).abs() < 1e-7);
+        assert!((loc.lon() - -76.1).abs() < 1e-7);
+    }
+
+    #[traced_test]
+    fn test_resolve_without_coordinates_leaves_location_empty() {
+        let mut addresses = vec![record("North Avenue")];
+        let mut pending = PendingWayCentroids::default();
+        pending.push(0, vec![99]);
+
+        assert_eq!(pending.resolve(&HashMap::new(), &mut addresses), 0);
+        assert!(addresses[0].location().is_none());
+    }
+}
@@ -0,0 +1,122 @@
+// ---------------- [ File: src/write_address_locations.rs ]
+crate::ix!();
+
+pub trait WriteAddressLocationsForRegion {
+
+    fn write_address_locations_for_region(
+        &mut self,
+        region:  &WorldRegion,
+        records: &[AddressRecord],
+    ) -> Result<(), DatabaseConstructionError>;
+}
+
+impl WriteAddressLocationsForRegion for Database {
+
+    /// Writes both coordinate indexes for every record with a known location:
+    ///
+    ///   - `GEO:{region}:{geohash}` => all located addresses in that geohash cell
+    ///     (used for nearest-address queries).
+    ///   - `LOC:{region}:{postal_code}:{city}:{street}` => all located addresses
+    ///     for that complete address (used for forward geocoding).
+    fn write_address_locations_for_region(
+        &mut self,
+        region:  &WorldRegion,
+        records: &[AddressRecord],
+    ) -> Result<(), DatabaseConstructionError> {
+
+        let mut geo_cells: BTreeMap<String, Vec<LocatedAddress>> = BTreeMap::new();
+        let mut forward:   BTreeMap<String, Vec<LocatedAddress>> = BTreeMap::new();
+
+        for record in records {
+            let Some(located) = LocatedAddress::from_record(region, record) else {
+                continue;
+            };
+
+            if let (Some(city), Some(street), Some(postal_code)) = (record.city(), record.street(), record.postcode()) {
+                forward
+                    .entry(loc_key(region, postal_code, city, street))
+                    .or_default()
+                    .push(located.clone());
+            }
+
+            let cell = encode_geohash(located.location(), GEO_INDEX_PRECISION);
+            geo_cells.entry(geo_key(region, &cell)).or_default().push(located);
+        }
+
+        info!(
+            "writing {} geohash cells and {} forward-geocoding keys for region {:?}",
+            geo_cells.len(),
+            forward.len(),
+            region
+        );
+
+        for (key, located) in geo_cells.into_iter().chain(forward) {
+            let clist = CompressedList::from(located);
+            let bytes = serde_cbor::to_vec(&clist).map_err(|e| {
+                DatabaseConstructionError::LocationSerdeError {
+                    msg: format!("Failed to serialize located addresses for '{}': {}", key, e),
+                }
+            })?;
+            self.put(&key, bytes)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_write_address_locations_for_region {
+    use super::*;
+
+    fn region_md() -> WorldRegion {
+        USRegion::UnitedState(UnitedState::Maryland).into()
+    }
+
+    fn located_record(street: &str, lat: f64, lon: f64) -> AddressRecord {
+        let mut record = AddressRecord::new(
+            CityName::new("Baltimore").unwrap(),
+            StreetName::new(street).unwrap(),
+            PostalCode::new(Country::USA, "21201").unwrap(),
+        );
+        record.set_location(Some(GeoPoint::from_degrees(lat, lon).unwrap()));
+        record
+    }
+
+    #[traced_test]
+    fn test_writes_geo_and_loc_keys() {
+        let (db_arc, _tmp) = create_temp_db::<Database>();
+        let mut db = db_arc.lock().unwrap();
+
+        let region = region_md();
+        let record = located_record("North Avenue", 39.3113, -76.6200);
+        let unlocated = AddressRecord::new(
+            CityName::new("Baltimore").unwrap(),
+            StreetName::new("Charles Street").unwrap(),
+            PostalCode::new(Country::USA, "21201").unwrap(),
+        );
+
+        db.write_address_locations_for_region(&region, &[record.clone(), unlocated.clone()]).unwrap();
+
+        let key = loc_key(
+            &region,
+            record.postcode().as_ref().unwrap(),
+            record.city().as_ref().unwrap(),
+            record.street().as_ref().unwrap(),
+        );
+        let stored: Vec<LocatedAddress> = decompress_cbor_to_list(&db.get(&key).unwrap().unwrap());
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].location(), record.location().as_ref().unwrap());
+
+        let cell = encode_geohash(record.location().as_ref().unwrap(), GEO_INDEX_PRECISION);
+        assert!(db.get(geo_key(&region, &cell)).unwrap().is_some());
+
+        // No location => no forward key
+        let missing = loc_key(
+            &region,
+            unlocated.postcode().as_ref().unwrap(),
+            unlocated.city().as_ref().unwrap(),
+            unlocated.street().as_ref().unwrap(),
+        );
+        assert!(db.get(&missing).unwrap().is_none());
+    }
+}
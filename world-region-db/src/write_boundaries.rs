@@ -0,0 +1,48 @@
+// ---------------- [ File: src/write_boundaries.rs ]
+crate::ix!();
+
+pub trait WriteBoundariesForRegion {
+
+    fn write_boundaries_for_region(
+        &mut self,
+        region:     &WorldRegion,
+        boundaries: &[AdminBoundary],
+    ) -> Result<(), DatabaseConstructionError>;
+}
+
+impl WriteBoundariesForRegion for Database {
+
+    /// Writes each boundary polygon under `BND:{region}:{relation_id}`, and
+    /// for every geohash cell its bounding box overlaps the relation ids
+    /// under `BNDX:{region}:{geohash}`, for [`BoundariesContainingPoint`]
+    /// lookups.
+    fn write_boundaries_for_region(
+        &mut self,
+        region:     &WorldRegion,
+        boundaries: &[AdminBoundary],
+    ) -> Result<(), DatabaseConstructionError> {
+
+        info!("writing {} boundaries for region {:?}", boundaries.len(), region);
+
+        let mut cells: BTreeMap<String, BTreeSet<i64>> = BTreeMap::new();
+        for boundary in boundaries {
+            let key   = boundary_key(region, *boundary.relation_id());
+            let bytes = serde_cbor::to_vec(boundary).map_err(|e| {
+                DatabaseConstructionError::BoundarySerdeError {
+                    msg: format!("Failed to serialize boundary '{}': {}", key, e),
+                }
+            })?;
+            self.put(&key, bytes)?;
+
+            for cell in boundary.index_cells() {
+                cells.entry(cell).or_default().insert(*boundary.relation_id());
+            }
+        }
+
+        for (cell, relation_ids) in &cells {
+            self.put(boundary_cell_key(region, cell), compress_set_to_cbor(relation_ids))?;
+        }
+
+        Ok(())
+    }
+}
@@ -60,10 +60,7 @@ mod test_write_house_number_ranges_into_storage {
         region: &WorldRegion,
         street: &StreetName
     ) -> Option<Vec<HouseNumberRange>> {
-        match db.load_house_number_ranges(region, street) {
-            Ok(ranges) => ranges,
-            Err(_) => None,
-        }
+        db.load_house_number_ranges(region, street).unwrap_or_default()
     }
 
     #[traced_test]
@@ -0,0 +1,146 @@
+// ---------------- [ File: src/write_osm_change_baseline.rs ]
+crate::ix!();
+
+pub trait WriteOsmChangeBaselineForRegion {
+
+    fn write_osm_change_baseline_for_region(
+        &mut self,
+        region:  &WorldRegion,
+        records: &[AddressRecord],
+    ) -> Result<(), DatabaseConstructionError>;
+}
+
+impl WriteOsmChangeBaselineForRegion for Database {
+
+    /// Writes what [`ApplyOsmChangeForRegion`] needs to update a built region
+    /// later, since change files do not say what a deleted or modified
+    /// element used to contain:
+    ///
+    ///   - `OSM:{region}:{element}` => the [`AddressRecord`] each element contributed.
+    ///   - `REF:{region}:{link}`    => how many elements support each [`AddressLink`].
+    fn write_osm_change_baseline_for_region(
+        &mut self,
+        region:  &WorldRegion,
+        records: &[AddressRecord],
+    ) -> Result<(), DatabaseConstructionError> {
+
+        let mut link_counts: BTreeMap<AddressLink, u32> = BTreeMap::new();
+        let mut indexed = 0usize;
+
+        for record in records {
+            for link in AddressLink::all_for(record) {
+                *link_counts.entry(link).or_insert(0) += 1;
+            }
+
+            let Some(element) = record.osm_element() else {
+                continue;
+            };
+            self.put(osm_element_key(region, element), serialize_indexed_address(record)?)?;
+            indexed += 1;
+        }
+
+        info!(
+            "writing {} element index entries and {} link counts for region {:?}",
+            indexed,
+            link_counts.len(),
+            region
+        );
+
+        for (link, count) in link_counts {
+            write_address_link_count(self, region, &link, count)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) fn serialize_indexed_address(record: &AddressRecord) -> Result<Vec<u8>, DatabaseConstructionError> {
+    serde_cbor::to_vec(record).map_err(|e| DatabaseConstructionError::OsmElementIndexSerdeError {
+        msg: format!("Failed to serialize address record for {:?}: {}", record.osm_element(), e),
+    })
+}
+
+pub(crate) fn read_address_link_count<I: DatabaseGet>(
+    db:     &I,
+    region: &WorldRegion,
+    link:   &AddressLink,
+) -> Result<u32, DataAccessError> {
+    let raw = db.get(address_link_count_key(region, link))?;
+    Ok(raw.filter(|bytes| bytes.len() == 4).map(|bytes| BigEndian::read_u32(&bytes)).unwrap_or(0))
+}
+
+/// Counts are 4 big-endian bytes; a zero count removes the key.
+pub(crate) fn write_address_link_count<I: DatabasePut + DatabaseDelete>(
+    db:     &mut I,
+    region: &WorldRegion,
+    link:   &AddressLink,
+    count:  u32,
+) -> Result<(), DatabaseConstructionError> {
+    let key = address_link_count_key(region, link);
+    if count == 0 {
+        return db.delete(key);
+    }
+    let mut buf = [0u8; 4];
+    BigEndian::write_u32(&mut buf, count);
+    db.put(key, buf)
+}
+
+#[cfg(test)]
+mod test_write_osm_change_baseline {
+    use super::*;
+
+    fn region_md() -> WorldRegion {
+        USRegion::UnitedState(UnitedState::Maryland).into()
+    }
+
+    fn record_from(element: OsmElementRef, street: &str) -> AddressRecord {
+        let mut record = AddressRecord::new(
+            CityName::new("Baltimore").unwrap(),
+            StreetName::new(street).unwrap(),
+            PostalCode::new(Country::USA, "21201").unwrap(),
+        );
+        record.set_osm_element(Some(element));
+        record
+    }
+
+    #[traced_test]
+    fn test_indexes_elements_and_counts_links() {
+        let (db_arc, _tmp) = create_temp_db::<Database>();
+        let mut db = db_arc.lock().unwrap();
+        let region = region_md();
+
+        let a = record_from(OsmElementRef::Node(1), "North Avenue");
+        let b = record_from(OsmElementRef::Way(2), "North Avenue");
+        let c = record_from(OsmElementRef::Node(3), "Charles Street");
+
+        db.write_osm_change_baseline_for_region(&region, &[a.clone(), b, c]).unwrap();
+
+        let raw = db.get(osm_element_key(&region, &OsmElementRef::Node(1))).unwrap().unwrap();
+        let stored: AddressRecord = serde_cbor::from_slice(&raw).unwrap();
+        assert_eq!(stored, a);
+
+        let city_pc = AddressLink::PostalCodeCity(a.postcode().clone().unwrap(), a.city().clone().unwrap());
+        assert_eq!(read_address_link_count(&*db, &region, &city_pc).unwrap(), 3);
+
+        let north = AddressLink::CityStreet(a.city().clone().unwrap(), a.street().clone().unwrap());
+        assert_eq!(read_address_link_count(&*db, &region, &north).unwrap(), 2);
+    }
+
+    #[traced_test]
+    fn test_zero_count_removes_key() {
+        let (db_arc, _tmp) = create_temp_db::<Database>();
+        let mut db = db_arc.lock().unwrap();
+        let region = region_md();
+
+        let link = AddressLink::PostalCodeCity(
+            PostalCode::new(Country::USA, "21201").unwrap(),
+            CityName::new("Baltimore").unwrap(),
+        );
+        write_address_link_count(&mut *db, &region, &link, 2).unwrap();
+        assert_eq!(read_address_link_count(&*db, &region, &link).unwrap(), 2);
+
+        write_address_link_count(&mut *db, &region, &link, 0).unwrap();
+        assert!(db.get(address_link_count_key(&region, &link)).unwrap().is_none());
+        assert_eq!(read_address_link_count(&*db, &region, &link).unwrap(), 0);
+    }
+}
@@ -27,12 +27,11 @@ type TestResult = Result<(), Box<dyn std::error::Error>>;
 fn main() {
     let mut args = env::args();
     let _bin_name = args.next(); // skip path
-    if let Some(subcmd) = args.next() {
-        if subcmd == "__child_test" {
-            let test_name = args.next().unwrap_or_default();
-            let exit_code = child_main_for_test(&test_name);
-            std::process::exit(exit_code);
-        }
+    if let Some(subcmd) = args.next()
+        && subcmd == "__child_test" {
+        let test_name = args.next().unwrap_or_default();
+        let exit_code = child_main_for_test(&test_name);
+        std::process::exit(exit_code);
     }
 
     harness_main();
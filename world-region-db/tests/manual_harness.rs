@@ -8,11 +8,11 @@
 
 use std::process;
 
-/// If your capture_stdout tests are in `crate::capture_stdout_tests`, import them here:
-/// (You might need `pub mod capture_stdout_tests;` or so, or just define
-/// test-like functions that we call manually.)
-///
-/// We'll assume you've moved or re-exported the functions we want to test.
+// If your capture_stdout tests are in `crate::capture_stdout_tests`, import them here:
+// (You might need `pub mod capture_stdout_tests;` or so, or just define
+// test-like functions that we call manually.)
+//
+// We'll assume you've moved or re-exported the functions we want to test.
 
 use world_region_db::*;
 use capture_stdout_tests::*; 
@@ -1,17 +1,16 @@
 // ---------------- [ File: tests/mini_pbf_integration_test.rs ]
-/// This file demonstrates an **integration test** that uses a small `.osm.pbf` fixture
-/// to test the full pipeline:
-///   1. Loading the file with `osmpbf::ElementReader`
-///   2. Iterating each element
-///   3. Attempting `AddressRecord::try_from((element, &Country::USA))`
-///   4. Checking the resulting addresses
-///
-/// Create a minimal "tiny.osm.pbf" fixture in `tests/fixtures/tiny.osm.pbf`.
+//! This file demonstrates an **integration test** that uses a small `.osm.pbf` fixture
+//! to test the full pipeline:
+//!   1. Loading the file with `osmpbf::ElementReader`
+//!   2. Iterating each element
+//!   3. Attempting `AddressRecord::try_from((element, &Country::USA))`
+//!   4. Checking the resulting addresses
+//!
+//! Create a minimal "tiny.osm.pbf" fixture in `tests/fixtures/tiny.osm.pbf`.
 
 use world_region_db::*;
 use country::*;
 use osmpbf::ElementReader;
-use std::path::Path;
 
 #[tokio::test]
 async fn test_parse_tiny_osm_pbf_fixture() {
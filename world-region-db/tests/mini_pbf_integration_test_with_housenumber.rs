@@ -1,11 +1,8 @@
 // ---------------- [ File: tests/mini_pbf_integration_test_with_housenumber.rs ]
 use world_region_db::*;
-use country::*;
 use usa::*;
 use world_region::*;
 use tempfile::*;
-use osmpbf::ElementReader;
-use std::path::Path;
 
 #[tokio::test]
 async fn test_parse_osm_with_house_range_and_store_in_db() {
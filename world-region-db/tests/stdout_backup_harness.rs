@@ -242,12 +242,11 @@ fn main() {
     // Are we in "child mode"? If so, run child_main_for_test(...) and exit.
     let mut args = env::args();
     let first_arg = args.nth(1); // skip binary path
-    if let Some(subcmd) = first_arg {
-        if subcmd == "__child_test" {
-            let test_name = args.next().unwrap_or_default();
-            let exit_code = child_main_for_test(&test_name);
-            process::exit(exit_code);
-        }
+    if let Some(subcmd) = first_arg
+        && subcmd == "__child_test" {
+        let test_name = args.next().unwrap_or_default();
+        let exit_code = child_main_for_test(&test_name);
+        process::exit(exit_code);
     }
 
     // Otherwise, we are the "parent harness"
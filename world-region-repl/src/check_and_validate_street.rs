@@ -17,7 +17,7 @@ pub fn check_and_validate_street<I:StorageInterface>(
 
     // a) parse street
     let street_str = street_parts.into_iter().collect::<Vec<String>>().join(" ");
-    let street_obj = match StreetName::new(&street_str) {
+    let street_obj = match StreetName::new_for_region(&street_str, region) {
         Ok(s) => s,
         Err(e) => {
             lines.push(format!("Invalid street '{}': {:?}", street_str, e));
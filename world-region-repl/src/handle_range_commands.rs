@@ -11,7 +11,7 @@ pub fn handle_range_command<I:StorageInterface>(args: Vec<&str>, st: &mut ReplSt
     }
 
     let last_arg = args[args.len() - 1];
-    let maybe_num = last_arg.parse::<u32>().ok();
+    let maybe_num = last_arg.parse::<u32>().ok().map(|n| HouseNumber::new(n, None));
     let street_tokens = if maybe_num.is_some() {
         &args[..args.len() - 1]
     } else {
@@ -19,7 +19,7 @@ pub fn handle_range_command<I:StorageInterface>(args: Vec<&str>, st: &mut ReplSt
     };
     let street_lc = street_tokens.join(" ").to_lowercase();
 
-    let street_obj = match StreetName::new(&street_lc) {
+    let street_obj = match StreetName::new_for_region(&street_lc, st.current_region()) {
         Ok(s) => s,
         Err(e) => {
             lines.push(format!("Invalid street name: {:?}", e));
@@ -46,7 +46,7 @@ pub fn handle_range_command<I:StorageInterface>(args: Vec<&str>, st: &mut ReplSt
 
                     // If user typed a house number, check it:
                     if let Some(hn) = maybe_num {
-                        let in_any = ranges.iter().any(|r| r.contains(hn));
+                        let in_any = ranges.iter().any(|r| r.contains(&hn));
                         if in_any {
                             lines.push(format!(
                                     "House number {} IS within a known sub-range.",
@@ -159,7 +159,7 @@ pub fn handle_typed_entry<I: StorageInterface>(
     // 2) If typed is recognized as a known street in the region
     // -------------------------------------------------------------
     if reg_data.streets().iter().any(|s| s.eq_ignore_ascii_case(&typed_lc)) {
-        let street_obj = match StreetName::new(&typed_lc) {
+        let street_obj = match StreetName::new_for_region(&typed_lc, st.current_region()) {
             Ok(s) => s,
             Err(e) => {
                 warn!(
@@ -24,7 +24,7 @@ pub fn run_interactive_repl<I: StorageInterface>(db_arc: Arc<Mutex<I>>)
     for region in &done_regions {
         if let Some(rd) = regions_map.get(region) {
             for street_str in rd.streets() {
-                let st_obj = match StreetName::new(street_str) {
+                let st_obj = match StreetName::new_for_region(street_str, region) {
                     Ok(s) => s,
                     Err(_) => continue,
                 };
@@ -1,7 +1,6 @@
 // ---------------- [ File: src/lib.rs ]
 #[macro_use] mod imports; use imports::*;
 
-x!{best_fuzzy_matches}
 x!{byzip}
 x!{check_and_parse_zip}
 x!{check_and_validate_street}
@@ -130,37 +130,10 @@ pub fn parse_validate_tokens_longest_city<I:StorageInterface>(
         .map(|cx| cx.name().to_string())
         .collect();
 
-    // We'll accumulate tokens one-by-one:
-    //   potential_city = "potomac"
-    //   check if in city_strings => yes => best_city_match_index = i
-    //   then potential_city = "potomac river"
-    //   check => if not in city_strings => break
-    //   or we can keep going to see if "potomac river road" is also recognized.
-    //   We'll do a while loop. 
-    //   Then the "best_city_match_index" is the last index for which we had a recognized city string.
-    let mut potential_city = String::new();
-    let mut last_match_index: Option<usize> = None;
-
-    let mut j = i; // local index
-    while j < n {
-        let tk = tokens[j];
-        // if we see a numeric house number => break from city
-        if tk.parse::<u32>().is_ok() {
-            break;
-        }
-        // add token
-        if potential_city.is_empty() {
-            potential_city = tk.to_string().to_lowercase();
-        } else {
-            potential_city.push(' ');
-            potential_city.push_str(&tk.to_lowercase());
-        }
-        if city_strings.contains(&potential_city) {
-            // record last match index as j
-            last_match_index = Some(j);
-        }
-        j += 1;
-    }
+    // Longest run of tokens (from index 2, stopping at a numeric house number)
+    // that names a city known for this ZIP. The same span search backs
+    // world-region-db's free-text address parser.
+    let last_match_index = longest_known_city_span(tokens, i, &city_strings);
 
     // If last_match_index is None => means we never found a recognized city prefix
     // => fallback to the first token as city or partial. 